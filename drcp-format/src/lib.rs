@@ -4,7 +4,8 @@
 //!
 //! To read DRCP files see [`reader::ProofReader`], and to write DRCP files see
 //! [`writer::ProofWriter`]. Literal definitions (`.lits`) files can be read and parsed with
-//! [`LiteralDefinitions`].
+//! [`LiteralDefinitions`]. Proofs can be reduced in size with [`trimming::trim`], which removes
+//! the steps that are not needed to derive the conclusion.
 
 mod atomic;
 mod format;
@@ -12,6 +13,7 @@ mod literal_definitions;
 
 pub mod reader;
 pub mod steps;
+pub mod trimming;
 pub mod writer;
 
 pub use atomic::*;
@@ -22,8 +22,8 @@ use nom::combinator::map_opt;
 use nom::combinator::opt;
 use nom::combinator::recognize;
 use nom::combinator::value;
+use nom::multi::many0;
 use nom::multi::many0_count;
-use nom::multi::separated_list0;
 use nom::sequence::pair;
 use nom::sequence::preceded;
 use nom::sequence::tuple;
@@ -211,7 +211,7 @@ where
 ///
 /// `input` is assumed to be a single line, with leading and trailing whitespace removed. If this
 /// is not the case, the parser will fail.
-fn proof_step(input: &str) -> IResult<&str, ReadStep<'_, NonZero<i32>>> {
+pub(crate) fn proof_step(input: &str) -> IResult<&str, ReadStep<'_, NonZero<i32>>> {
     all_consuming(alt((
         map(inference_step, Step::Inference),
         map(nogood_step, Step::Nogood),
@@ -226,13 +226,12 @@ fn inference_step(input: &str) -> IResult<&str, Inference<'_, Vec<NonZero<i32>>,
         tuple((
             tag("i "),
             step_id,
-            tag(" "),
             literal_list,
             opt(preceded(tag(" 0 "), literal)),
             opt(preceded(tag(" c:"), constraint_id)),
             opt(preceded(tag(" l:"), identifier)),
         )),
-        |(_, id, _, premises, propagated, hint_constraint_id, hint_label)| Inference {
+        |(_, id, premises, propagated, hint_constraint_id, hint_label)| Inference {
             id,
             hint_constraint_id,
             hint_label,
@@ -248,11 +247,10 @@ fn nogood_step(input: &str) -> IResult<&str, Nogood<Vec<NonZero<i32>>, Vec<StepI
         tuple((
             tag("n "),
             step_id,
-            tag(" "),
             literal_list,
-            opt(preceded(tag(" 0 "), separated_list0(tag(" "), step_id))),
+            opt(preceded(tag(" 0"), many0(preceded(tag(" "), step_id)))),
         )),
-        |(_, id, _, literals, hints)| Nogood {
+        |(_, id, literals, hints)| Nogood {
             id,
             hints,
             literals,
@@ -276,9 +274,9 @@ fn conclusion_step(input: &str) -> IResult<&str, Conclusion<NonZero<i32>>> {
     )(input)
 }
 
-/// Parses a space-separated list of non-zero signed integers.
+/// Parses a (possibly empty) list of non-zero signed integers, each preceded by a space.
 fn literal_list(input: &str) -> IResult<&str, Vec<NonZero<i32>>> {
-    separated_list0(tag(" "), literal)(input)
+    many0(preceded(tag(" "), literal))(input)
 }
 
 /// Parses a single non-zero signed integer.
@@ -339,4 +337,18 @@ mod tests {
         };
         assert_eq!(Some(Step::Inference(expected_inference)), inference_step);
     }
+
+    #[test]
+    fn empty_nogood_with_hints() {
+        let source = "n 6 0 2 5\n";
+        let mut reader = ProofReader::new(source.as_bytes(), std::convert::identity);
+
+        let nogood_step = reader.next_step().expect("valid drcp nogood step");
+        let expected_nogood = Nogood {
+            id: NonZero::new(6).unwrap(),
+            hints: Some(vec![NonZero::new(2).unwrap(), NonZero::new(5).unwrap()]),
+            literals: vec![],
+        };
+        assert_eq!(Some(Step::Nogood(expected_nogood)), nogood_step);
+    }
 }
@@ -0,0 +1,298 @@
+//! Implements the trimming of DRCP proofs.
+//!
+//! See [`trim`] for more information on which steps are removed from a proof.
+
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::io::ErrorKind;
+use std::io::Seek;
+use std::io::Write;
+
+use crate::reader::proof_step;
+use crate::reader::DrcpError;
+use crate::steps::Step;
+use crate::steps::StepId;
+
+/// The number of steps before and after trimming a proof.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TrimmingStatistics {
+    /// The number of steps in the original proof.
+    pub num_steps: usize,
+    /// The number of steps which were removed from the proof.
+    pub num_removed_steps: usize,
+}
+
+/// The information of a proof step which is needed to decide whether it is kept.
+#[derive(Debug)]
+enum StepDependencies {
+    Inference(StepId),
+    Nogood(StepId, Option<Vec<StepId>>),
+    Delete(StepId),
+    Conclusion,
+}
+
+/// Trim a (textual) DRCP proof, writing the steps which are kept to `sink`.
+///
+/// The last nogood of the proof is the one which the conclusion relies on (e.g. the empty nogood
+/// for an unsatisfiability proof). Starting from that nogood, the propagation hints of the
+/// nogoods are followed backwards; any inference or nogood which is not reached is not on a path
+/// to the final nogood and is removed, together with its deletion.
+///
+/// A nogood without hints may rely on any step which precedes it, so all steps before a kept
+/// nogood without hints are kept. Trimming is therefore only effective on proofs which contain
+/// hints for every nogood.
+///
+/// The source is read twice, once to determine which steps are kept and once to copy them to the
+/// sink, which is why it has to implement [`Seek`]. If the second read yields a different number
+/// of steps than the first (e.g. because the file was modified in the meantime), then an error
+/// with [`ErrorKind::InvalidData`] is returned.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+///
+/// use drcp_format::trimming::trim;
+///
+/// let source = "
+/// i 1 1 0 2
+/// n 2 -1 0 1
+/// i 3 3 0 4
+/// n 4 -3 0 3
+/// i 5 1 0 -2
+/// n 6 0 2 5
+/// d 4
+/// c UNSAT
+/// ";
+///
+/// let mut trimmed: Vec<u8> = Vec::new();
+/// let statistics = trim(Cursor::new(source), &mut trimmed).unwrap();
+///
+/// let expected = "
+/// i 1 1 0 2
+/// n 2 -1 0 1
+/// i 5 1 0 -2
+/// n 6 0 2 5
+/// c UNSAT
+/// ";
+/// assert_eq!(
+///     std::str::from_utf8(&trimmed).unwrap(),
+///     expected.trim_start()
+/// );
+/// assert_eq!(statistics.num_removed_steps, 3);
+/// ```
+pub fn trim<R: BufRead + Seek, W: Write>(
+    mut source: R,
+    mut sink: W,
+) -> Result<TrimmingStatistics, DrcpError> {
+    let steps = read_dependencies(&mut source)?;
+    let is_kept = compute_kept_steps(&steps);
+
+    source.rewind()?;
+
+    let mut line = String::new();
+    let mut step_index = 0;
+    loop {
+        line.clear();
+        if source.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let step = line.trim();
+        if step.is_empty() {
+            continue;
+        }
+
+        let Some(&is_step_kept) = is_kept.get(step_index) else {
+            return Err(changed_source_error());
+        };
+        if is_step_kept {
+            writeln!(sink, "{step}")?;
+        }
+        step_index += 1;
+    }
+
+    if step_index != is_kept.len() {
+        return Err(changed_source_error());
+    }
+
+    sink.flush()?;
+
+    Ok(TrimmingStatistics {
+        num_steps: steps.len(),
+        num_removed_steps: is_kept.iter().filter(|&&is_kept| !is_kept).count(),
+    })
+}
+
+/// The error which is returned when the source yields different steps when it is read again.
+fn changed_source_error() -> DrcpError {
+    DrcpError::Io(std::io::Error::new(
+        ErrorKind::InvalidData,
+        "the number of proof steps changed between reading the dependencies and trimming",
+    ))
+}
+
+/// Parse the steps of the proof, only retaining the information needed for trimming.
+fn read_dependencies(source: &mut impl BufRead) -> Result<Vec<StepDependencies>, DrcpError> {
+    let mut steps = vec![];
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if source.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (_, step) = proof_step(line.trim())?;
+        steps.push(match step {
+            Step::Inference(inference) => StepDependencies::Inference(inference.id),
+            Step::Nogood(nogood) => StepDependencies::Nogood(nogood.id, nogood.hints),
+            Step::Delete(deletion) => StepDependencies::Delete(deletion.id),
+            Step::Conclusion(_) => StepDependencies::Conclusion,
+        });
+    }
+
+    Ok(steps)
+}
+
+/// Determine for every step whether it is kept in the trimmed proof.
+fn compute_kept_steps(steps: &[StepDependencies]) -> Vec<bool> {
+    let mut is_kept = vec![false; steps.len()];
+    let mut required: HashSet<StepId> = HashSet::new();
+    let mut kept_ids: HashSet<StepId> = HashSet::new();
+
+    // Whether all preceding steps are kept, because a kept nogood does not have hints.
+    let mut keep_all = false;
+    let mut found_final_nogood = false;
+
+    for (index, step) in steps.iter().enumerate().rev() {
+        let keep = match step {
+            StepDependencies::Inference(id) => keep_all || required.contains(id),
+
+            StepDependencies::Nogood(id, hints) => {
+                let keep = keep_all || !found_final_nogood || required.contains(id);
+                found_final_nogood = true;
+
+                if keep {
+                    match hints {
+                        Some(hints) => required.extend(hints.iter().copied()),
+                        None => keep_all = true,
+                    }
+                }
+
+                keep
+            }
+
+            // Whether a deletion is kept depends on the step it deletes, which precedes it.
+            StepDependencies::Delete(_) => continue,
+
+            StepDependencies::Conclusion => true,
+        };
+
+        if keep {
+            is_kept[index] = true;
+            match step {
+                StepDependencies::Inference(id) | StepDependencies::Nogood(id, _) => {
+                    let _ = kept_ids.insert(*id);
+                }
+                StepDependencies::Delete(_) | StepDependencies::Conclusion => {}
+            }
+        }
+    }
+
+    for (index, step) in steps.iter().enumerate() {
+        if let StepDependencies::Delete(id) = step {
+            is_kept[index] = kept_ids.contains(id);
+        }
+    }
+
+    is_kept
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn trim_to_string(source: &str) -> String {
+        let mut trimmed: Vec<u8> = Vec::new();
+        let _ = trim(Cursor::new(source), &mut trimmed).expect("valid proof");
+        String::from_utf8(trimmed).unwrap()
+    }
+
+    #[test]
+    fn hinted_nogoods_are_kept_transitively() {
+        let source = "i 1 1 0 2\nn 2 -1 0 1\nn 3 -2 0 2\ni 4 5 0 6\nn 5 -5 0 4\nn 6 0 3\nc UNSAT\n";
+
+        assert_eq!(
+            trim_to_string(source),
+            "i 1 1 0 2\nn 2 -1 0 1\nn 3 -2 0 2\nn 6 0 3\nc UNSAT\n"
+        );
+    }
+
+    #[test]
+    fn steps_before_a_kept_nogood_without_hints_are_kept() {
+        let source = "i 1 1 0 2\ni 2 3 0 4\nn 3 -1\nn 4 -3 0 2\nn 5 0 3\nc UNSAT\n";
+
+        assert_eq!(
+            trim_to_string(source),
+            "i 1 1 0 2\ni 2 3 0 4\nn 3 -1\nn 5 0 3\nc UNSAT\n"
+        );
+    }
+
+    #[test]
+    fn final_nogood_of_optimality_proof_is_kept() {
+        let source = "i 1 1 0 2\nn 2 -1 0 1\ni 3 3 0 4\nn 4 -3 0 3\nd 2\nc -3\n";
+
+        assert_eq!(trim_to_string(source), "i 3 3 0 4\nn 4 -3 0 3\nc -3\n");
+    }
+
+    #[test]
+    fn source_which_changes_between_reads_is_an_error() {
+        let source = ChangingSource {
+            contents: Cursor::new("i 1 1 0 2\nn 2 0 1\nc UNSAT\n"),
+            contents_after_rewind: Some("i 1 1 0 2\ni 2 3 0 4\nn 3 0 1\nc UNSAT\n"),
+        };
+
+        let result = trim(source, Vec::new());
+        assert!(matches!(
+            result,
+            Err(DrcpError::Io(error)) if error.kind() == ErrorKind::InvalidData
+        ));
+    }
+
+    /// A source which yields different contents after it is rewound.
+    struct ChangingSource {
+        contents: Cursor<&'static str>,
+        contents_after_rewind: Option<&'static str>,
+    }
+
+    impl std::io::Read for ChangingSource {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.contents.read(buf)
+        }
+    }
+
+    impl BufRead for ChangingSource {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            self.contents.fill_buf()
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.contents.consume(amt)
+        }
+    }
+
+    impl Seek for ChangingSource {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            if let Some(contents) = self.contents_after_rewind.take() {
+                self.contents = Cursor::new(contents);
+            }
+            self.contents.seek(pos)
+        }
+    }
+}
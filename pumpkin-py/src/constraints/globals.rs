@@ -16,7 +16,7 @@ macro_rules! python_constraint {
         #[pymethods]
         impl $name {
             #[new]
-            fn new($($field: $type),+) -> Self {
+            pub(crate) fn new($($field: $type),+) -> Self {
                 $name {
                     $($field),+
                 }
@@ -1,6 +1,7 @@
 use std::num::NonZero;
 use std::path::PathBuf;
 
+use pumpkin_solver::containers::HashMap;
 use pumpkin_solver::containers::KeyedVec;
 use pumpkin_solver::options::LearningOptions;
 use pumpkin_solver::options::SolverOptions;
@@ -12,8 +13,11 @@ use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 use pumpkin_solver::ConstraintOperationError;
 use pumpkin_solver::Solver;
+use pyo3::exceptions::PyOverflowError;
 use pyo3::prelude::*;
 
+use crate::constraints::globals::Element;
+use crate::constraints::globals::Equals;
 use crate::constraints::Constraint;
 use crate::result::SatisfactionResult;
 use crate::result::Solution;
@@ -29,6 +33,9 @@ pub struct Model {
     integer_variables: KeyedVec<IntVariable, ModelIntVar>,
     boolean_variables: KeyedVec<BoolVariable, ModelBoolVar>,
     constraints: Vec<ModelConstraint>,
+    /// The auxiliary variables which are introduced for subexpressions, such that identical
+    /// subexpressions share a single variable.
+    subexpressions: HashMap<Subexpression, IntVariable>,
 }
 
 #[pyclass(eq, eq_int)]
@@ -137,6 +144,105 @@ impl Model {
             .into()
     }
 
+    /// Get an integer expression which is equal to the sum of the given terms.
+    ///
+    /// An auxiliary variable is introduced for the linear combination; a linear combination which
+    /// has been created before (regardless of the order of the terms and the constant offset)
+    /// reuses the same auxiliary variable. In that case, `name` is ignored and the auxiliary
+    /// variable keeps the name with which it was created.
+    ///
+    /// Raises an `OverflowError` if the combined weight of a variable or the constant offset does
+    /// not fit in a 32-bit integer.
+    #[pyo3(signature = (terms, name=None))]
+    fn linear_combination(
+        &mut self,
+        terms: Vec<IntExpression>,
+        name: Option<&str>,
+    ) -> PyResult<IntExpression> {
+        let mut weights: HashMap<IntVariable, i64> = HashMap::default();
+        let mut constant = 0_i64;
+        for term in terms {
+            *weights.entry(term.variable).or_default() += term.scale as i64;
+            constant += term.offset as i64;
+        }
+
+        let mut weighted_variables = weights
+            .into_iter()
+            .filter(|&(_, weight)| weight != 0)
+            .map(|(variable, weight)| {
+                let weight = i32::try_from(weight).map_err(|_| {
+                    PyOverflowError::new_err(format!(
+                        "the weight {weight} of a variable does not fit in a 32-bit integer"
+                    ))
+                })?;
+                Ok((variable, weight))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        weighted_variables.sort();
+
+        let constant = i32::try_from(constant).map_err(|_| {
+            PyOverflowError::new_err(format!(
+                "the constant {constant} does not fit in a 32-bit integer"
+            ))
+        })?;
+
+        // A single variable with unit weight does not need an auxiliary variable
+        if let [(variable, 1)] = weighted_variables.as_slice() {
+            return Ok(IntExpression::from(*variable).offset(constant));
+        }
+
+        let terms = weighted_variables
+            .iter()
+            .map(|&(variable, weight)| IntExpression::from(variable).scaled(weight))
+            .collect::<Vec<_>>();
+        let (lower_bound, upper_bound) = terms.iter().fold((0, 0), |(lower, upper), &term| {
+            let (term_lower, term_upper) = self.bounds(term);
+            (lower + term_lower, upper + term_upper)
+        });
+
+        let variable = self.subexpression(
+            Subexpression::Linear(weighted_variables),
+            (lower_bound, upper_bound),
+            name,
+            |auxiliary| {
+                let mut terms = terms;
+                terms.push(IntExpression::from(auxiliary).scaled(-1));
+                Constraint::Equals(Equals::new(terms, 0))
+            },
+        );
+
+        Ok(IntExpression::from(variable).offset(constant))
+    }
+
+    /// Get an integer expression which is equal to `array[index]`.
+    ///
+    /// An auxiliary variable is introduced for the element access; accessing the same array with
+    /// the same index reuses the same auxiliary variable. In that case, `name` is ignored and the
+    /// auxiliary variable keeps the name with which it was created.
+    #[pyo3(signature = (index, array, name=None))]
+    fn element(
+        &mut self,
+        index: IntExpression,
+        array: Vec<IntExpression>,
+        name: Option<&str>,
+    ) -> IntExpression {
+        let (lower_bound, upper_bound) = array
+            .iter()
+            .map(|&element| self.bounds(element))
+            .reduce(|(lower, upper), (element_lower, element_upper)| {
+                (lower.min(element_lower), upper.max(element_upper))
+            })
+            .unwrap_or((0, 0));
+
+        self.subexpression(
+            Subexpression::Element(index, array.clone()),
+            (lower_bound, upper_bound),
+            name,
+            |auxiliary| Constraint::Element(Element::new(index, array, auxiliary.into())),
+        )
+        .into()
+    }
+
     /// Add the given constraint to the model.
     #[pyo3(signature = (constraint, tag=None))]
     fn add_constraint(&mut self, constraint: Constraint, tag: Option<NonZero<u32>>) {
@@ -197,15 +303,60 @@ impl Model {
                     variable_map,
                 })
             }
-            pumpkin_solver::results::SatisfactionResult::Unsatisfiable => {
+            pumpkin_solver::results::SatisfactionResult::Unsatisfiable(_) => {
                 SatisfactionResult::Unsatisfiable()
             }
-            pumpkin_solver::results::SatisfactionResult::Unknown => SatisfactionResult::Unknown(),
+            pumpkin_solver::results::SatisfactionResult::Unknown(_) => {
+                SatisfactionResult::Unknown()
+            }
         }
     }
 }
 
 impl Model {
+    /// Returns the auxiliary variable for the given subexpression; if the subexpression does not
+    /// have a variable yet, it is created with the given bounds and defined by the constraint
+    /// created by `define`.
+    fn subexpression(
+        &mut self,
+        subexpression: Subexpression,
+        (lower_bound, upper_bound): (i64, i64),
+        name: Option<&str>,
+        define: impl FnOnce(IntVariable) -> Constraint,
+    ) -> IntVariable {
+        if let Some(&variable) = self.subexpressions.get(&subexpression) {
+            return variable;
+        }
+
+        let variable = self.integer_variables.push(ModelIntVar {
+            lower_bound: lower_bound.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            upper_bound: upper_bound.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            name: name.map(|n| n.to_owned()),
+        });
+
+        self.constraints.push(ModelConstraint {
+            constraint: define(variable),
+            premise: None,
+            tag: None,
+        });
+        let _ = self.subexpressions.insert(subexpression, variable);
+
+        variable
+    }
+
+    /// Returns the bounds of the given expression based on the bounds of its variable.
+    fn bounds(&self, expression: IntExpression) -> (i64, i64) {
+        let ModelIntVar {
+            lower_bound,
+            upper_bound,
+            ..
+        } = self.integer_variables[expression.variable];
+
+        let first = expression.scale as i64 * lower_bound as i64 + expression.offset as i64;
+        let second = expression.scale as i64 * upper_bound as i64 + expression.offset as i64;
+        (first.min(second), first.max(second))
+    }
+
     fn create_variable_map(
         &self,
         solver: &mut Solver,
@@ -262,6 +413,15 @@ struct ModelConstraint {
     tag: Option<NonZero<u32>>,
 }
 
+/// A subexpression for which an auxiliary variable is introduced.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Subexpression {
+    /// A linear combination of distinct variables with non-zero weights, sorted by variable.
+    Linear(Vec<(IntVariable, i32)>),
+    /// An element access `array[index]`.
+    Element(IntExpression, Vec<IntExpression>),
+}
+
 struct ModelIntVar {
     lower_bound: i32,
     upper_bound: i32,
@@ -6,7 +6,7 @@ use pumpkin_solver::variables::Literal;
 use pumpkin_solver::variables::TransformableVariable;
 use pyo3::prelude::*;
 
-#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct IntVariable(usize);
 
 impl StorageKey for IntVariable {
@@ -20,7 +20,7 @@ impl StorageKey for IntVariable {
 }
 
 #[pyclass]
-#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct IntExpression {
     pub variable: IntVariable,
     pub offset: i32,
@@ -54,7 +54,7 @@ impl IntExpression {
 
 #[pymethods]
 impl IntExpression {
-    fn offset(&self, add_offset: i32) -> IntExpression {
+    pub fn offset(&self, add_offset: i32) -> IntExpression {
         let IntExpression {
             variable,
             offset,
@@ -68,7 +68,7 @@ impl IntExpression {
         }
     }
 
-    fn scaled(&self, scaling: i32) -> IntExpression {
+    pub fn scaled(&self, scaling: i32) -> IntExpression {
         let IntExpression {
             variable,
             offset,
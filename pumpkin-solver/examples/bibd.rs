@@ -154,10 +154,10 @@ fn main() {
 
             println!("{row_separator}");
         }
-        SatisfactionResult::Unsatisfiable => {
+        SatisfactionResult::Unsatisfiable(_) => {
             println!("UNSATISFIABLE")
         }
-        SatisfactionResult::Unknown => {
+        SatisfactionResult::Unknown(_) => {
             println!("UNKNOWN")
         }
     }
@@ -73,7 +73,7 @@ fn main() {
     let mut brancher = solver.default_brancher_over_all_propositional_variables();
     if matches!(
         solver.satisfy(&mut brancher, &mut Indefinite),
-        SatisfactionResult::Unsatisfiable,
+        SatisfactionResult::Unsatisfiable(_),
     ) {
         panic!("Infeasibility Detected")
     }
@@ -102,7 +102,7 @@ fn main() {
                     .join(" - ")
             );
         }
-        SatisfactionResult::Unsatisfiable => panic!("Infeasibility Detected"),
-        SatisfactionResult::Unknown => println!("Timeout."),
+        SatisfactionResult::Unsatisfiable(_) => panic!("Infeasibility Detected"),
+        SatisfactionResult::Unknown(_) => println!("Timeout."),
     }
 }
@@ -108,10 +108,10 @@ fn main() {
 
             println!("{row_separator}");
         }
-        SatisfactionResult::Unsatisfiable => {
+        SatisfactionResult::Unsatisfiable(_) => {
             println!("{n}-queens is unsatisfiable.");
         }
-        SatisfactionResult::Unknown => {
+        SatisfactionResult::Unknown(_) => {
             println!("Timeout.");
         }
     }
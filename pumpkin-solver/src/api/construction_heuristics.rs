@@ -0,0 +1,22 @@
+#[cfg(doc)]
+use crate::branching::branchers::hinted_brancher::HintedBrancher;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The options for constructing an initial solution using greedy dives (see
+/// [`Solver::construct_initial_solution`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstructionOptions {
+    /// The number of dives which are made before giving up; the first dive assigns every variable
+    /// to its smallest value, every subsequent dive (i.e. a random restart) assigns random values.
+    ///
+    /// A dive is abandoned as soon as propagation encounters a conflict, there is no backtracking
+    /// within a dive.
+    pub num_dives: usize,
+}
+
+impl Default for ConstructionOptions {
+    fn default() -> Self {
+        ConstructionOptions { num_dives: 10 }
+    }
+}
@@ -0,0 +1,261 @@
+use std::ops::Range;
+
+use crate::basic_types::HashMap;
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::propagation::PropagatorId;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::pumpkin_assert_simple;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A stable handle to an integer variable which was created through the [`Solver`].
+///
+/// Handles are assigned in the order in which the variables are created and are not affected by
+/// the internal representation of the variables (i.e. their [`DomainId`]s); they can thus be
+/// stored (e.g. when serialising a model or passing it over an FFI boundary) and later be resolved
+/// using [`Solver::variable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VariableId(u32);
+
+impl VariableId {
+    /// Creates the handle from its raw value; see [`VariableId::to_raw`].
+    pub fn from_raw(raw: u32) -> VariableId {
+        VariableId(raw)
+    }
+
+    /// Returns the raw value of the handle, which can be used to store it.
+    pub fn to_raw(self) -> u32 {
+        self.0
+    }
+}
+
+impl StorageKey for VariableId {
+    fn index(&self) -> usize {
+        self.0 as usize
+    }
+
+    fn create_from_index(index: usize) -> Self {
+        VariableId(index as u32)
+    }
+}
+
+/// A stable handle to a constraint which was posted to the [`Solver`]; it is returned when the
+/// constraint is posted using [`crate::constraints::ConstraintPoster::post_with_id`] (or one of
+/// the reified variants).
+///
+/// Handles are assigned in the order in which the constraints are posted and are not affected by
+/// the internal representation of the constraints (i.e. the propagators which are used for them).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConstraintId(u32);
+
+impl ConstraintId {
+    /// Creates the handle from its raw value; see [`ConstraintId::to_raw`].
+    pub fn from_raw(raw: u32) -> ConstraintId {
+        ConstraintId(raw)
+    }
+
+    /// Returns the raw value of the handle, which can be used to store it.
+    pub fn to_raw(self) -> u32 {
+        self.0
+    }
+}
+
+impl StorageKey for ConstraintId {
+    fn index(&self) -> usize {
+        self.0 as usize
+    }
+
+    fn create_from_index(index: usize) -> Self {
+        ConstraintId(index as u32)
+    }
+}
+
+/// Maps the public handles of the [`Solver`] to the internal representation of the variables and
+/// constraints.
+#[derive(Debug, Default)]
+pub(crate) struct Handles {
+    variables: KeyedVec<VariableId, DomainId>,
+    variable_ids: HashMap<DomainId, VariableId>,
+    /// The propagators and clauses which were added when a constraint was posted. The constraints
+    /// which were removed when their scope was closed (see [`Solver::pop`]) are [`None`].
+    constraints: KeyedVec<ConstraintId, Option<PostedConstraint>>,
+    /// The clauses which were added through [`Solver::add_clause`], in the order in which they
+    /// were added; a clause which is added outside of a constraint forms a constraint on its own.
+    clauses: Vec<Box<[Literal]>>,
+    /// The number of constraints which are currently being posted; these can be nested when a
+    /// constraint is decomposed into other constraints.
+    num_open_constraints: usize,
+    /// The number of constraints and clauses which were added before each of the open scopes was
+    /// opened.
+    scopes: Vec<(usize, usize)>,
+}
+
+/// The propagators and clauses which were added when a constraint was posted; a constraint which
+/// is decomposed into clauses has no propagators.
+#[derive(Clone, Debug)]
+struct PostedConstraint {
+    propagators: Range<u32>,
+    clauses: Range<u32>,
+}
+
+impl Handles {
+    pub(crate) fn new_variable(&mut self, domain_id: DomainId) -> VariableId {
+        let variable_id = self.variables.push(domain_id);
+        let _ = self.variable_ids.insert(domain_id, variable_id);
+        variable_id
+    }
+
+    pub(crate) fn variable(&self, variable_id: VariableId) -> Option<DomainId> {
+        (variable_id.index() < self.variables.len()).then(|| self.variables[variable_id])
+    }
+
+    pub(crate) fn variable_id(&self, domain_id: DomainId) -> Option<VariableId> {
+        self.variable_ids.get(&domain_id).copied()
+    }
+
+    /// Starts posting a constraint; the clauses which are added until the matching call to
+    /// [`Handles::close_constraint`] belong to it.
+    pub(crate) fn open_constraint(&mut self) {
+        self.num_open_constraints += 1;
+    }
+
+    pub(crate) fn close_constraint(&mut self) {
+        self.num_open_constraints -= 1;
+    }
+
+    /// Returns whether a constraint is currently being posted.
+    pub(crate) fn is_posting_constraint(&self) -> bool {
+        self.num_open_constraints > 0
+    }
+
+    /// Records a clause which was added while posting a constraint.
+    pub(crate) fn add_clause(&mut self, clause: &[Literal]) {
+        pumpkin_assert_simple!(self.is_posting_constraint());
+        self.clauses.push(clause.into());
+    }
+
+    pub(crate) fn num_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Creates the handle of a constraint for which the propagators with identifiers in
+    /// `propagators` and the clauses with indices in `clauses` were added.
+    pub(crate) fn new_constraint(
+        &mut self,
+        propagators: Range<usize>,
+        clauses: Range<usize>,
+    ) -> ConstraintId {
+        self.constraints.push(Some(PostedConstraint {
+            propagators: propagators.start as u32..propagators.end as u32,
+            clauses: clauses.start as u32..clauses.end as u32,
+        }))
+    }
+
+    /// Returns the handles of all posted constraints which have not been removed, in the order in
+    /// which they were posted.
+    pub(crate) fn constraint_ids(&self) -> impl Iterator<Item = ConstraintId> + '_ {
+        self.constraints
+            .entries()
+            .filter(|(_, constraint)| constraint.is_some())
+            .map(|(constraint_id, _)| constraint_id)
+    }
+
+    pub(crate) fn propagators(
+        &self,
+        constraint_id: ConstraintId,
+    ) -> Option<impl Iterator<Item = PropagatorId>> {
+        self.posted_constraint(constraint_id).map(|constraint| {
+            constraint
+                .propagators
+                .clone()
+                .map(|index| PropagatorId::create_from_index(index as usize))
+        })
+    }
+
+    /// Returns the clauses which were added when the constraint was posted.
+    pub(crate) fn clauses(
+        &self,
+        constraint_id: ConstraintId,
+    ) -> Option<impl Iterator<Item = &[Literal]> + '_> {
+        self.posted_constraint(constraint_id).map(|constraint| {
+            let clauses = constraint.clauses.start as usize..constraint.clauses.end as usize;
+            self.clauses[clauses].iter().map(|clause| &clause[..])
+        })
+    }
+
+    fn posted_constraint(&self, constraint_id: ConstraintId) -> Option<&PostedConstraint> {
+        if constraint_id.index() >= self.constraints.len() {
+            return None;
+        }
+
+        self.constraints[constraint_id].as_ref()
+    }
+
+    /// Opens a scope; the constraints which are posted until the scope is closed are removed
+    /// together with it (see [`Handles::pop_scope`]).
+    pub(crate) fn push_scope(&mut self) {
+        self.scopes
+            .push((self.constraints.len(), self.clauses.len()));
+    }
+
+    /// Removes the constraints which were posted within the innermost scope; their handles are
+    /// not reused.
+    pub(crate) fn pop_scope(&mut self) {
+        if let Some((num_constraints, num_clauses)) = self.scopes.pop() {
+            self.constraints
+                .iter_mut()
+                .skip(num_constraints)
+                .for_each(|constraint| *constraint = None);
+            self.clauses.truncate(num_clauses);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_handles_are_not_resolved() {
+        let mut handles = Handles::default();
+        let x = DomainId::new(3);
+
+        let variable_id = handles.new_variable(x);
+        assert_eq!(handles.variable(variable_id), Some(x));
+        assert_eq!(handles.variable_id(x), Some(variable_id));
+        assert_eq!(handles.variable(VariableId::from_raw(1)), None);
+        assert_eq!(handles.variable_id(DomainId::new(0)), None);
+
+        let constraint_id = handles.new_constraint(2..4, 0..0);
+        assert_eq!(
+            handles
+                .propagators(constraint_id)
+                .map(|propagators| propagators.collect::<Vec<_>>()),
+            Some(vec![
+                PropagatorId::create_from_index(2),
+                PropagatorId::create_from_index(3)
+            ])
+        );
+        assert!(handles.propagators(ConstraintId::from_raw(1)).is_none());
+    }
+
+    #[test]
+    fn constraints_of_closed_scopes_are_removed() {
+        let mut handles = Handles::default();
+        let outer = handles.new_constraint(0..1, 0..0);
+        handles.push_scope();
+        let inner = handles.new_constraint(1..1, 0..0);
+        handles.pop_scope();
+        let later = handles.new_constraint(1..2, 0..0);
+
+        assert!(handles.propagators(outer).is_some());
+        assert!(handles.propagators(inner).is_none());
+        assert_ne!(later, inner);
+        assert_eq!(
+            handles.constraint_ids().collect::<Vec<_>>(),
+            vec![outer, later]
+        );
+    }
+}
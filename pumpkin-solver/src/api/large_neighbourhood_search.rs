@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+
+use crate::basic_types::Random;
+use crate::basic_types::Solution;
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The options for large neighbourhood search (see [`Solver::minimise_with_lns`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LnsOptions {
+    /// The number of conflicts which may be spent on searching a single neighbourhood before
+    /// moving on to the next one.
+    ///
+    /// A neighbourhood in which no variables are fixed is searched without a budget, since it
+    /// corresponds to the full search space.
+    pub conflict_budget: u64,
+}
+
+impl Default for LnsOptions {
+    fn default() -> Self {
+        LnsOptions {
+            conflict_budget: 500,
+        }
+    }
+}
+
+/// The outcome of searching a neighbourhood, which is passed to
+/// [`NeighbourhoodSelector::notify_outcome`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeighbourhoodOutcome {
+    /// A solution with a better objective value was found in the neighbourhood.
+    Improved,
+    /// The neighbourhood was proven to contain no better solution.
+    Exhausted,
+    /// The conflict budget was spent before the neighbourhood was exhausted.
+    BudgetExceeded,
+}
+
+/// Determines the neighbourhoods which are explored by large neighbourhood search (see
+/// [`Solver::minimise_with_lns`]); a neighbourhood is defined by the variables which are fixed to
+/// their value in the incumbent solution, the remaining variables are free.
+pub trait NeighbourhoodSelector: Debug {
+    /// Returns the subset of `variables` which should be fixed to their value in `incumbent`.
+    fn select_variables_to_fix(
+        &mut self,
+        incumbent: &Solution,
+        variables: &[DomainId],
+        random: &mut dyn Random,
+    ) -> Vec<DomainId>;
+
+    /// Called after the neighbourhood which was last selected has been searched.
+    fn notify_outcome(&mut self, _outcome: NeighbourhoodOutcome) {}
+}
+
+/// A [`NeighbourhoodSelector`] which fixes a uniformly random subset of the variables.
+///
+/// The fraction of fixed variables adapts to the outcome of the search: if a neighbourhood is
+/// exhausted, then the next neighbourhood is larger (fewer variables are fixed), while a
+/// neighbourhood which exceeds its budget makes the next neighbourhood smaller. Eventually, the
+/// neighbourhoods of an incumbent which cannot be improved grow to the full search space, which
+/// allows the optimality of the incumbent to be proven.
+#[derive(Clone, Copy, Debug)]
+pub struct RandomNeighbourhood {
+    fraction_to_fix: f64,
+    step: f64,
+}
+
+impl RandomNeighbourhood {
+    /// Creates a selector which initially fixes `fraction_to_fix` (in `[0, 1]`) of the variables
+    /// and adapts this fraction by `step` after every neighbourhood which is not improving.
+    pub fn new(fraction_to_fix: f64, step: f64) -> Self {
+        RandomNeighbourhood {
+            fraction_to_fix: fraction_to_fix.clamp(0.0, 1.0),
+            step,
+        }
+    }
+}
+
+impl Default for RandomNeighbourhood {
+    fn default() -> Self {
+        RandomNeighbourhood::new(0.8, 0.05)
+    }
+}
+
+impl NeighbourhoodSelector for RandomNeighbourhood {
+    fn select_variables_to_fix(
+        &mut self,
+        _incumbent: &Solution,
+        variables: &[DomainId],
+        random: &mut dyn Random,
+    ) -> Vec<DomainId> {
+        let num_to_fix = (self.fraction_to_fix * variables.len() as f64).floor() as usize;
+
+        // A partial Fisher-Yates shuffle selects `num_to_fix` distinct variables
+        let mut candidates = variables.to_vec();
+        for index in 0..num_to_fix {
+            let selected = random.generate_usize_in_range(index..candidates.len());
+            candidates.swap(index, selected);
+        }
+        candidates.truncate(num_to_fix);
+
+        candidates
+    }
+
+    fn notify_outcome(&mut self, outcome: NeighbourhoodOutcome) {
+        match outcome {
+            NeighbourhoodOutcome::Improved => {}
+            NeighbourhoodOutcome::Exhausted => {
+                self.fraction_to_fix = (self.fraction_to_fix - self.step).max(0.0)
+            }
+            NeighbourhoodOutcome::BudgetExceeded => {
+                self.fraction_to_fix = (self.fraction_to_fix + self.step).min(1.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+
+    #[test]
+    fn random_neighbourhood_fixes_distinct_variables() {
+        let variables = (0..10).map(DomainId::new).collect::<Vec<_>>();
+        let mut neighbourhood = RandomNeighbourhood::new(0.5, 0.1);
+        let mut random = TestRandom {
+            usizes: vec![9, 1, 8, 3, 5],
+            ..Default::default()
+        };
+
+        let fixed =
+            neighbourhood.select_variables_to_fix(&Solution::default(), &variables, &mut random);
+        assert_eq!(
+            fixed,
+            [9, 1, 8, 3, 5].map(DomainId::new).to_vec(),
+            "the selected variables should be swapped to the front"
+        );
+    }
+
+    #[test]
+    fn random_neighbourhood_grows_when_exhausted() {
+        let variables = (0..10).map(DomainId::new).collect::<Vec<_>>();
+        let mut neighbourhood = RandomNeighbourhood::new(0.2, 0.1);
+
+        neighbourhood.notify_outcome(NeighbourhoodOutcome::Exhausted);
+        neighbourhood.notify_outcome(NeighbourhoodOutcome::Exhausted);
+        neighbourhood.notify_outcome(NeighbourhoodOutcome::Exhausted);
+
+        let fixed = neighbourhood.select_variables_to_fix(
+            &Solution::default(),
+            &variables,
+            &mut TestRandom::default(),
+        );
+        assert!(fixed.is_empty());
+    }
+}
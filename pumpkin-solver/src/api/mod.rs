@@ -1,14 +1,26 @@
+pub(crate) mod construction_heuristics;
+pub(crate) mod handles;
+pub(crate) mod large_neighbourhood_search;
+pub(crate) mod optimisation_target;
 mod outputs;
+pub mod portfolio;
 pub(crate) mod solver;
+pub(crate) mod solver_phase;
+pub(crate) mod weighted_objective;
 
 pub mod results {
     //! Contains the outputs of solving using the [`Solver`].
     //!
-    //! We differentiate between 3 different types of results:
+    //! We differentiate between 5 different types of results:
     //! - For a **satisfaction** problem ([`SatisfactionResult`])
     //! - For a **satisfaction** problem using **assumptions**
-    //!   ([`SatisfactionResultUnderAssumptions`])
-    //! - For an **optimisation** problem ([`OptimisationResult`])
+    //!   ([`SatisfactionResultUnderAssumptions`] or, for assumptions over predicates,
+    //!   [`SatisfactionResultWithCore`])
+    //! - For a **satisfaction** problem where every solution is passed to a **callback**
+    //!   ([`SatisfactionResultWithCallback`])
+    //! - For an **optimisation** problem ([`OptimisationResult`] or, for multiple objectives,
+    //!   [`LexicographicOptimisationResult`])
+    //! - For the **construction** of an initial solution ([`ConstructionResult`])
     //!
     //! On these results, different methods can be called which ensure that the solver is in the
     //! right state for these operations. For example,
@@ -17,12 +29,18 @@ pub mod results {
     pub use crate::api::outputs::solution_callback_arguments::SolutionCallbackArguments;
     pub use crate::api::outputs::solution_iterator;
     pub use crate::api::outputs::unsatisfiable;
+    pub use crate::api::outputs::ConstructionResult;
+    pub use crate::api::outputs::LexicographicOptimisationResult;
     pub use crate::api::outputs::OptimisationResult;
     pub use crate::api::outputs::ProblemSolution;
     pub use crate::api::outputs::SatisfactionResult;
     pub use crate::api::outputs::SatisfactionResultUnderAssumptions;
+    pub use crate::api::outputs::SatisfactionResultWithCallback;
+    pub use crate::api::outputs::SatisfactionResultWithCore;
     pub use crate::api::outputs::SolutionReference;
+    pub use crate::api::outputs::ViolatedConstraint;
     pub use crate::basic_types::Solution;
+    pub use crate::engine::SearchStatistics;
     #[cfg(doc)]
     use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
     #[cfg(doc)]
@@ -47,8 +65,12 @@ pub mod variables {
     //!   used when interacting with the [`Solver`]. A [`Literal`] is used when a
     //!   [`PropositionalVariable`] is given a polarity (i.e. it is the positive [`Literal`] or its
     //!   negated version). A [`Literal`] can be created using [`Solver::new_literal`].
+    pub use crate::api::handles::VariableId;
+    pub use crate::api::weighted_objective::WeightedObjective;
+    pub use crate::engine::propagation::DomainSnapshotAt;
     pub use crate::engine::variables::AffineView;
     pub use crate::engine::variables::DomainId;
+    pub use crate::engine::variables::HistoricalIntegerVariable;
     pub use crate::engine::variables::IntegerVariable;
     pub use crate::engine::variables::Literal;
     pub use crate::engine::variables::PropositionalVariable;
@@ -59,6 +81,12 @@ pub mod variables {
 
 pub mod containers {
     //! Contains containers which are used by the solver.
+    //!
+    //! The [`HashMap`] and [`HashSet`] use a deterministic hasher; using these rather than the
+    //! containers from [`std::collections`] ensures that two runs on the same input behave the
+    //! same.
+    pub use crate::basic_types::HashMap;
+    pub use crate::basic_types::HashSet;
     pub use crate::basic_types::KeyedVec;
     pub use crate::basic_types::StorageKey;
 }
@@ -70,14 +98,35 @@ pub mod options {
     //! - The restart strategy of the solver
     //! - The learned clause database management approach
     //! - The proof logging
+    //! - When the optimisation procedure is allowed to stop early
+    //! - The neighbourhoods which are explored by large neighbourhood search
+    //! - Whether the solutions which are found during optimisation are polished
+    //! - The phases in which the search is performed
+    //! - How initial solutions are constructed
+    pub use crate::api::construction_heuristics::ConstructionOptions;
+    pub use crate::api::large_neighbourhood_search::LnsOptions;
+    pub use crate::api::large_neighbourhood_search::NeighbourhoodOutcome;
+    pub use crate::api::large_neighbourhood_search::NeighbourhoodSelector;
+    pub use crate::api::large_neighbourhood_search::RandomNeighbourhood;
+    pub use crate::api::optimisation_target::OptimisationTarget;
+    pub use crate::api::solver_phase::PhaseStrategy;
+    pub use crate::api::solver_phase::SolverPhase;
     pub use crate::basic_types::sequence_generators::SequenceGeneratorType;
+    pub use crate::branching::ActivityBasedSearchOptions;
+    pub use crate::constraints::CardinalityEncoding;
+    pub use crate::engine::ImplicationGraphExport;
     pub use crate::engine::LearnedClauseSortingStrategy;
     pub use crate::engine::LearningOptions;
     pub use crate::engine::RestartOptions;
+    pub use crate::engine::RestartPolicy;
     pub use crate::engine::SatisfactionSolverOptions as SolverOptions;
+    pub use crate::engine::SequenceRestarts;
+    pub use crate::engine::SolutionPolishingOptions;
+    pub use crate::propagators::AllDifferentPropagationMethod;
     pub use crate::propagators::CumulativeExplanationType;
     pub use crate::propagators::CumulativeOptions;
     pub use crate::propagators::CumulativePropagationMethod;
+    pub use crate::propagators::DiffnOptions;
     #[cfg(doc)]
     use crate::Solver;
 }
@@ -135,10 +184,28 @@ pub mod predicates {
     pub use crate::engine::predicates::integer_predicate::IntegerPredicate;
     pub use crate::engine::predicates::predicate::Predicate;
     pub use crate::engine::predicates::predicate_constructor::PredicateConstructor;
+    pub use crate::variable_names::DisplayWith;
     #[cfg(doc)]
     use crate::variables::Literal;
 }
 
+pub mod replay {
+    //! Contains the structures which allow the search of the [`Solver`] to be recorded and
+    //! replayed.
+    //!
+    //! When recording is enabled through [`Solver::record_events`], every decision, domain event,
+    //! conflict and restart is stored in an [`EventLog`]. The decisions in such a log can be
+    //! replayed against a fresh [`Solver`] (with the same model) using the [`ReplayBrancher`];
+    //! comparing the log of the replay with the original log (see
+    //! [`EventLog::first_divergence`]) pins down any nondeterminism in the propagation.
+    pub use crate::engine::cp::IntDomainEvent;
+    pub use crate::engine::event_log::EventLog;
+    pub use crate::engine::event_log::LoggedEvent;
+    pub use crate::engine::event_log::ReplayBrancher;
+    #[cfg(doc)]
+    use crate::Solver;
+}
+
 pub mod encodings {
     //! Contains structures which encode pseudo-boolean constraints via the
     //! [`PseudoBooleanConstraintEncoder`].
@@ -0,0 +1,87 @@
+#[cfg(doc)]
+use crate::results::OptimisationResult;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Criteria which allow the optimisation procedure (see [`Solver::minimise_with_target`] and
+/// [`Solver::maximise_with_target`]) to stop once a good-enough solution has been found, rather
+/// than proving optimality. If a criterion is met, then the best solution is reported as
+/// [`OptimisationResult::TargetReached`].
+///
+/// By default, no criteria are set and the solver optimises until optimality is proven (or the
+/// termination condition triggers).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OptimisationTarget {
+    /// Stop as soon as a solution has been found with an objective value which is at least as
+    /// good as the provided value; i.e. an objective value `≤ objective_value` when minimising,
+    /// and `≥ objective_value` when maximising.
+    pub objective_value: Option<i64>,
+    /// Stop as soon as the gap between the best found objective value and the best known bound on
+    /// the objective, relative to the best found objective value, is at most the provided value;
+    /// e.g. `0.05` stops the search once the best solution is provably within 5% of the optimum.
+    pub relative_gap: Option<f64>,
+}
+
+impl OptimisationTarget {
+    /// Returns whether one of the criteria is met, where `best_objective_value` is the objective
+    /// value of the best solution and `objective_bound` is the best known bound on the objective.
+    ///
+    /// Both values are expected to be in the minimisation direction.
+    pub(crate) fn is_reached(
+        &self,
+        best_objective_value: i64,
+        objective_bound: i64,
+        is_maximising: bool,
+    ) -> bool {
+        let is_objective_value_reached = self.objective_value.is_some_and(|target| {
+            if is_maximising {
+                best_objective_value <= -target
+            } else {
+                best_objective_value <= target
+            }
+        });
+
+        let is_gap_reached = self.relative_gap.is_some_and(|relative_gap| {
+            let gap = best_objective_value.abs_diff(objective_bound) as f64;
+            gap <= relative_gap * best_objective_value.unsigned_abs().max(1) as f64
+        });
+
+        is_objective_value_reached || is_gap_reached
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objective_value_target_takes_direction_into_account() {
+        let target = OptimisationTarget {
+            objective_value: Some(10),
+            relative_gap: None,
+        };
+
+        assert!(target.is_reached(10, 0, false));
+        assert!(!target.is_reached(11, 0, false));
+
+        assert!(target.is_reached(-12, -20, true));
+        assert!(!target.is_reached(-9, -20, true));
+    }
+
+    #[test]
+    fn gap_is_relative_to_best_objective_value() {
+        let target = OptimisationTarget {
+            objective_value: None,
+            relative_gap: Some(0.1),
+        };
+
+        assert!(target.is_reached(100, 90, false));
+        assert!(!target.is_reached(100, 89, false));
+        assert!(target.is_reached(0, 0, false));
+    }
+
+    #[test]
+    fn no_criteria_are_never_reached() {
+        assert!(!OptimisationTarget::default().is_reached(0, 0, false));
+    }
+}
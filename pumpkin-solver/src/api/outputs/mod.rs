@@ -5,7 +5,15 @@ pub use crate::basic_types::SolutionReference;
 pub(crate) mod solution_callback_arguments;
 pub mod solution_iterator;
 pub mod unsatisfiable;
+use crate::api::handles::ConstraintId;
+#[cfg(doc)]
+use crate::branching::branchers::hinted_brancher::HintedBrancher;
 use crate::branching::Brancher;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::SearchStatistics;
+#[cfg(doc)]
+use crate::options::OptimisationTarget;
 #[cfg(doc)]
 use crate::termination::TerminationCondition;
 #[cfg(doc)]
@@ -17,11 +25,31 @@ use crate::Solver;
 pub enum SatisfactionResult {
     /// Indicates that a solution was found and provides the corresponding [`Solution`].
     Satisfiable(Solution),
-    /// Indicates that there is no solution to the satisfaction problem.
-    Unsatisfiable,
+    /// Indicates that there is no solution to the satisfaction problem. It provides the
+    /// [`SearchStatistics`] of the search which proved that no solution exists; since the final
+    /// conflict occurs at the root, the conflict itself does not carry further information.
+    Unsatisfiable(SearchStatistics),
     /// Indicates that it is not known whether a solution exists. This is likely due to a
-    /// [`TerminationCondition`] triggering.
-    Unknown,
+    /// [`TerminationCondition`] triggering. It provides the [`SearchStatistics`] of the search
+    /// which was performed before the solver stopped.
+    Unknown(SearchStatistics),
+}
+
+/// The result of a call to [`Solver::solve_with_callback`].
+#[derive(Clone, Copy, Debug)]
+pub enum SatisfactionResultWithCallback {
+    /// Indicates that the callback requested the search to stop.
+    Stopped,
+    /// Indicates that all solutions have been passed to the callback (and that there was at least
+    /// one solution).
+    Finished,
+    /// Indicates that there is no solution to the satisfaction problem. The [`SearchStatistics`]
+    /// of the search which proved this can be retrieved using [`Solver::search_statistics`].
+    Unsatisfiable,
+    /// Indicates that it is not known whether (more) solutions exist. This is likely due to the
+    /// time limit being exceeded. It provides the [`SearchStatistics`] of the search which was
+    /// performed before the solver stopped.
+    Unknown(SearchStatistics),
 }
 
 /// The result of a call to [`Solver::satisfy_under_assumptions`].
@@ -41,7 +69,49 @@ pub enum SatisfactionResultUnderAssumptions<'solver, 'brancher, B: Brancher> {
     Unknown,
 }
 
-/// The result of a call to [`Solver::maximise`] or [`Solver::minimise`].
+/// The result of a call to [`Solver::minimise_lexicographically`]; the objective values are given
+/// in the order of the objectives.
+#[derive(Debug)]
+pub enum LexicographicOptimisationResult {
+    /// Indicates that a solution has been found and proven to be lexicographically optimal. It
+    /// provides the optimal solution and its objective values.
+    Optimal {
+        solution: Solution,
+        objective_values: Vec<i32>,
+    },
+    /// Indicates that a solution was found and provides the best known solution (and its
+    /// objective values); the objectives before the one which was being optimised when the search
+    /// was stopped are optimal.
+    Satisfiable {
+        solution: Solution,
+        objective_values: Vec<i32>,
+    },
+    /// Indicates that there is no solution to the problem.
+    Unsatisfiable,
+    /// Indicates that it is not known whether a solution exists. This is likely due to a
+    /// [`TerminationCondition`] triggering.
+    Unknown,
+}
+
+/// The result of a call to [`Solver::solve_under_assumptions`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum SatisfactionResultWithCore {
+    /// Indicates that a solution was found and provides the corresponding [`Solution`].
+    Satisfiable(Solution),
+    /// Indicates that there is no solution to the satisfaction problem due to the provided
+    /// assumptions. It provides a (minimised) subset of the assumptions which cannot be satisfied
+    /// together.
+    UnsatisfiableCore(Vec<Predicate>),
+    /// Indicates that there is no solution to the satisfaction problem.
+    Unsatisfiable,
+    /// Indicates that it is not known whether a solution exists. This is likely due to a
+    /// [`TerminationCondition`] triggering.
+    Unknown,
+}
+
+/// The result of a call to [`Solver::maximise`] or [`Solver::minimise`]; the [`SearchStatistics`]
+/// of the search can be retrieved using [`Solver::search_statistics`].
 #[derive(Debug)]
 pub enum OptimisationResult {
     /// Indicates that an optimal solution has been found and proven to be optimal. It provides an
@@ -50,9 +120,55 @@ pub enum OptimisationResult {
     /// Indicates that a solution was found and provides an instance of [`Solution`] which contains
     /// best known solution by the solver.
     Satisfiable(Solution),
+    /// Indicates that the search was stopped because a criterion of the provided
+    /// [`OptimisationTarget`] was met; it provides the best solution found by the solver.
+    TargetReached(Solution),
     /// Indicates that there is no solution to the problem.
     Unsatisfiable,
     /// Indicates that it is not known whether a solution exists. This is likely due to a
     /// [`TerminationCondition`] triggering.
     Unknown,
 }
+
+/// A constraint which is violated by an assignment; see [`Solver::check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViolatedConstraint {
+    /// The handle of the violated constraint.
+    pub constraint_id: ConstraintId,
+    /// The description of the propagator of the constraint which detected the violation, in the
+    /// syntax of [`Solver::dump_pretty`], or the clause of the constraint which is violated.
+    pub description: String,
+    /// The part of the assignment which violates the constraint; this is empty if the propagator
+    /// does not explain the violation. For a violated clause, these are the negations of its
+    /// literals.
+    pub reason: Vec<Predicate>,
+}
+
+/// The result of a call to [`Solver::construct_initial_solution`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ConstructionResult {
+    /// Indicates that one of the dives reached a solution.
+    Solution(Solution),
+    /// Indicates that none of the dives reached a solution. It provides the values of the
+    /// variables which were assigned by the deepest dive, without a conflict, which can be used as
+    /// hints for the search (see [`HintedBrancher`]).
+    Hints(Vec<(DomainId, i32)>),
+    /// Indicates that there is no solution to the satisfaction problem.
+    Unsatisfiable,
+}
+
+impl ConstructionResult {
+    /// Returns the hints which can be used to warm-start the search (see [`HintedBrancher`]); for
+    /// a solution, these are its values for the provided `variables`.
+    pub fn into_hints(self, variables: &[DomainId]) -> Vec<(DomainId, i32)> {
+        match self {
+            ConstructionResult::Solution(solution) => variables
+                .iter()
+                .map(|&variable| (variable, solution.get_integer_value(variable)))
+                .collect(),
+            ConstructionResult::Hints(hints) => hints,
+            ConstructionResult::Unsatisfiable => vec![],
+        }
+    }
+}
@@ -41,10 +41,9 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
     /// [`Brancher::on_solution`] method from the [`Brancher`] used to run the initial solve.
     pub fn next_solution(&mut self) -> IteratedSolution {
         if let Some(blocking_clause) = self.next_blocking_clause.take() {
-            self.solver
-                .get_satisfaction_solver_mut()
-                .restore_state_at_root(self.brancher);
-            if self.solver.add_clause(blocking_clause).is_err() {
+            let satisfaction_solver = self.solver.get_satisfaction_solver_mut();
+            satisfaction_solver.restore_state_at_root(self.brancher);
+            if satisfaction_solver.add_clause(blocking_clause).is_err() {
                 return IteratedSolution::Finished;
             }
         }
@@ -54,14 +53,14 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
                 self.next_blocking_clause = Some(get_blocking_clause(&solution));
                 IteratedSolution::Solution(solution)
             }
-            Unsatisfiable => {
+            Unsatisfiable(_) => {
                 if self.has_solution {
                     IteratedSolution::Finished
                 } else {
                     IteratedSolution::Unsatisfiable
                 }
             }
-            Unknown => IteratedSolution::Unknown,
+            Unknown(_) => IteratedSolution::Unknown,
         }
     }
 }
@@ -0,0 +1,476 @@
+//! Contains the [`Portfolio`], which solves a model using several [`Solver`]s in parallel.
+//!
+//! The workers of a portfolio differ in their [`WorkerBrancher`], their random seed and their
+//! restart options, which causes them to explore different parts of the search space; the learned
+//! clauses which are likely to be useful to the other workers are shared between them.
+use std::num::NonZeroUsize;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+use crate::basic_types::InvalidArgument;
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::Solution;
+use crate::engine::nogood_exchange::NogoodExchange;
+use crate::engine::termination::time_budget::TimeBudget;
+use crate::engine::termination::TerminationCondition;
+use crate::engine::variables::DomainId;
+use crate::options::LearningOptions;
+use crate::options::RestartOptions;
+use crate::options::SolverOptions;
+use crate::predicate;
+use crate::results::OptimisationResult;
+use crate::results::SatisfactionResult;
+use crate::Solver;
+
+/// The brancher which is used by a worker of a [`Portfolio`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerBrancher {
+    /// See [`Solver::default_brancher_over_all_propositional_variables`].
+    Default,
+    /// See [`Solver::vsids_brancher_over_all_integer_variables`].
+    IntegerVsids,
+    /// See [`Solver::activity_based_brancher_over_all_integer_variables`].
+    ActivityBased,
+}
+
+/// The configuration of a single worker of a [`Portfolio`].
+#[derive(Clone, Copy, Debug)]
+pub struct PortfolioWorker {
+    /// The brancher which is used by the worker.
+    pub brancher: WorkerBrancher,
+    /// The seed of the random generator of the worker (see [`SolverOptions::random_generator`]).
+    pub seed: u64,
+    /// The restart options of the worker.
+    pub restart_options: RestartOptions,
+}
+
+impl PortfolioWorker {
+    /// Creates a worker with the provided brancher and seed, which uses the default restart
+    /// options.
+    pub fn new(brancher: WorkerBrancher, seed: u64) -> Self {
+        PortfolioWorker {
+            brancher,
+            seed,
+            restart_options: RestartOptions::default(),
+        }
+    }
+
+    fn create_solver(&self, nogood_exchange: NogoodExchange) -> Solver {
+        let mut solver = Solver::with_options(
+            LearningOptions::default(),
+            SolverOptions {
+                random_generator: SmallRng::seed_from_u64(self.seed),
+                restart_options: self.restart_options,
+                ..Default::default()
+            },
+        );
+        solver.set_nogood_exchange(nogood_exchange);
+        solver
+    }
+}
+
+/// Solves a model with several differently configured [`Solver`]s (the workers) in parallel,
+/// each on its own thread, and reports the result of the first worker which reaches a conclusion.
+///
+/// Since a [`Solver`] cannot be shared between threads, every worker creates its own [`Solver`]
+/// and builds the model using the provided closure; the closure should thus build the same model
+/// regardless of the worker which calls it. The workers share their learned clauses with a low
+/// LBD (see [`Portfolio::with_nogood_sharing`]), which are imported by the other workers when
+/// they restart. When optimising, the workers also share the bounds on the objective which are
+/// implied by the solutions that they find.
+///
+/// # Example
+/// ```rust
+/// # use std::num::NonZeroUsize;
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::portfolio::Portfolio;
+/// # use pumpkin_solver::results::OptimisationResult;
+/// # use pumpkin_solver::results::ProblemSolution;
+/// # use pumpkin_solver::variables::DomainId;
+/// let portfolio = Portfolio::with_default_workers(NonZeroUsize::new(3).unwrap());
+///
+/// let result = portfolio.minimise(
+///     |solver| {
+///         let variables = solver.new_bounded_integers(4, 0, 5);
+///         let objective = solver.new_bounded_integer(0, 20);
+///         let _ = solver
+///             .add_constraint(constraints::all_different(variables.clone()))
+///             .post();
+///         let _ = solver
+///             .add_constraint(constraints::maximum(variables, objective))
+///             .post();
+///         objective
+///     },
+///     None,
+/// );
+///
+/// let OptimisationResult::Optimal(solution) = result else {
+///     panic!("the optimum should be proven");
+/// };
+/// // Every worker builds the same model, so the objective is the fifth variable in every worker;
+/// // four distinct values require a maximum of at least 3
+/// assert_eq!(solution.get_integer_value(DomainId::new(4)), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Portfolio {
+    workers: Vec<PortfolioWorker>,
+    max_shared_lbd: u32,
+    max_shared_length: usize,
+}
+
+impl Portfolio {
+    /// Creates a portfolio with the provided workers; by default, the learned clauses with an LBD
+    /// of at most 2 and a length of at most 30 are shared.
+    ///
+    /// Returns [`InvalidArgument::EmptyArray`] if no workers are provided.
+    pub fn new(workers: Vec<PortfolioWorker>) -> Result<Self, InvalidArgument> {
+        if workers.is_empty() {
+            return Err(InvalidArgument::EmptyArray {
+                argument: "workers",
+            });
+        }
+
+        Ok(Portfolio {
+            workers,
+            max_shared_lbd: 2,
+            max_shared_length: 30,
+        })
+    }
+
+    /// Creates a portfolio with `num_workers` workers, which alternate between the available
+    /// [`WorkerBrancher`]s and use different seeds.
+    pub fn with_default_workers(num_workers: NonZeroUsize) -> Self {
+        let branchers = [
+            WorkerBrancher::Default,
+            WorkerBrancher::IntegerVsids,
+            WorkerBrancher::ActivityBased,
+        ];
+        Portfolio::new(
+            (0..num_workers.get())
+                .map(|index| PortfolioWorker::new(branchers[index % branchers.len()], index as u64))
+                .collect(),
+        )
+        .expect("there is at least one worker")
+    }
+
+    /// Shares the learned clauses with an LBD of at most `max_lbd` and a length of at most
+    /// `max_length` between the workers; a `max_lbd` of 0 disables the sharing of learned
+    /// clauses.
+    pub fn with_nogood_sharing(mut self, max_lbd: u32, max_length: usize) -> Self {
+        self.max_shared_lbd = max_lbd;
+        self.max_shared_length = max_length;
+        self
+    }
+
+    /// Solves the model which is built by `build_model` and returns the result of the first worker
+    /// which finds a solution or proves that none exists; [`SatisfactionResult::Unknown`] is
+    /// returned if none of the workers reach a conclusion within the `time_limit`.
+    pub fn solve(
+        &self,
+        build_model: impl Fn(&mut Solver) + Sync,
+        time_limit: Option<Duration>,
+    ) -> SatisfactionResult {
+        let stopped = AtomicBool::new(false);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for (worker, nogood_exchange) in self.workers.iter().zip(self.create_exchanges()) {
+                let sender = sender.clone();
+                let stopped = &stopped;
+                let build_model = &build_model;
+
+                let _ = scope.spawn(move || {
+                    let mut solver = worker.create_solver(nogood_exchange);
+                    build_model(&mut solver);
+
+                    let mut termination = WorkerTermination::new(stopped, time_limit);
+                    let result = match worker.brancher {
+                        WorkerBrancher::Default => {
+                            let mut brancher =
+                                solver.default_brancher_over_all_propositional_variables();
+                            solver.satisfy(&mut brancher, &mut termination)
+                        }
+                        WorkerBrancher::IntegerVsids => {
+                            let mut brancher = solver.vsids_brancher_over_all_integer_variables();
+                            solver.satisfy(&mut brancher, &mut termination)
+                        }
+                        WorkerBrancher::ActivityBased => {
+                            let mut brancher =
+                                solver.activity_based_brancher_over_all_integer_variables();
+                            solver.satisfy(&mut brancher, &mut termination)
+                        }
+                    };
+                    let _ = sender.send(result);
+                });
+            }
+            drop(sender);
+
+            let mut statistics = None;
+            for result in receiver {
+                match result {
+                    SatisfactionResult::Unknown(worker_statistics) => {
+                        statistics = statistics.or(Some(worker_statistics))
+                    }
+                    result => {
+                        stopped.store(true, Ordering::Relaxed);
+                        return result;
+                    }
+                }
+            }
+
+            SatisfactionResult::Unknown(statistics.unwrap_or_default())
+        })
+    }
+
+    /// Minimises the objective variable which is returned by `build_model`; the best solution
+    /// found by any of the workers is returned.
+    pub fn minimise(
+        &self,
+        build_model: impl Fn(&mut Solver) -> DomainId + Sync,
+        time_limit: Option<Duration>,
+    ) -> OptimisationResult {
+        self.optimise(build_model, time_limit, false)
+    }
+
+    /// Maximises the objective variable which is returned by `build_model`; the best solution
+    /// found by any of the workers is returned.
+    pub fn maximise(
+        &self,
+        build_model: impl Fn(&mut Solver) -> DomainId + Sync,
+        time_limit: Option<Duration>,
+    ) -> OptimisationResult {
+        self.optimise(build_model, time_limit, true)
+    }
+
+    fn optimise(
+        &self,
+        build_model: impl Fn(&mut Solver) -> DomainId + Sync,
+        time_limit: Option<Duration>,
+        is_maximising: bool,
+    ) -> OptimisationResult {
+        let stopped = AtomicBool::new(false);
+        let incumbent: Arc<Mutex<Option<(i32, Solution)>>> = Arc::new(Mutex::new(None));
+        let (sender, receiver) = mpsc::channel();
+
+        let is_proven = thread::scope(|scope| {
+            for (worker, nogood_exchange) in self.workers.iter().zip(self.create_exchanges()) {
+                let sender = sender.clone();
+                let stopped = &stopped;
+                let build_model = &build_model;
+                let incumbent = Arc::clone(&incumbent);
+
+                let _ = scope.spawn(move || {
+                    let mut solver = worker.create_solver(nogood_exchange.clone());
+                    let objective = build_model(&mut solver);
+
+                    // Every solution is offered as the incumbent, and the bound which it implies
+                    // is shared with the other workers; the incumbent is updated first so that a
+                    // worker which concludes based on the bound can rely on the incumbent.
+                    solver.with_solution_callback(move |arguments| {
+                        let value = arguments.solution.get_integer_value(objective);
+                        {
+                            let mut incumbent = incumbent
+                                .lock()
+                                .expect("a worker panicked while holding the lock");
+                            let is_improving = match incumbent.as_ref() {
+                                Some((best_value, _)) if is_maximising => value > *best_value,
+                                Some((best_value, _)) => value < *best_value,
+                                None => true,
+                            };
+                            if is_improving {
+                                *incumbent = Some((value, arguments.solution.clone()));
+                            }
+                        }
+
+                        let bound = if is_maximising {
+                            predicate![objective >= value + 1]
+                        } else {
+                            predicate![objective <= value - 1]
+                        };
+                        nogood_exchange.export(vec![bound
+                            .try_into()
+                            .expect("the bound is an integer predicate")]);
+                    });
+
+                    let mut termination = WorkerTermination::new(stopped, time_limit);
+                    let result = match worker.brancher {
+                        WorkerBrancher::Default => {
+                            let mut brancher =
+                                solver.default_brancher_over_all_propositional_variables();
+                            optimise_worker(
+                                &mut solver,
+                                &mut brancher,
+                                &mut termination,
+                                objective,
+                                is_maximising,
+                            )
+                        }
+                        WorkerBrancher::IntegerVsids => {
+                            let mut brancher = solver.vsids_brancher_over_all_integer_variables();
+                            optimise_worker(
+                                &mut solver,
+                                &mut brancher,
+                                &mut termination,
+                                objective,
+                                is_maximising,
+                            )
+                        }
+                        WorkerBrancher::ActivityBased => {
+                            let mut brancher =
+                                solver.activity_based_brancher_over_all_integer_variables();
+                            optimise_worker(
+                                &mut solver,
+                                &mut brancher,
+                                &mut termination,
+                                objective,
+                                is_maximising,
+                            )
+                        }
+                    };
+                    let _ = sender.send(result);
+                });
+            }
+            drop(sender);
+
+            for result in receiver {
+                // A worker which concludes does so relative to the bounds which it imported, it
+                // thus proves that the incumbent (if any) is optimal
+                if matches!(
+                    result,
+                    OptimisationResult::Optimal(_) | OptimisationResult::Unsatisfiable
+                ) {
+                    stopped.store(true, Ordering::Relaxed);
+                    return true;
+                }
+            }
+
+            false
+        });
+
+        let incumbent = incumbent
+            .lock()
+            .expect("a worker panicked while holding the lock")
+            .take();
+        match (incumbent, is_proven) {
+            (Some((_, solution)), true) => OptimisationResult::Optimal(solution),
+            (Some((_, solution)), false) => OptimisationResult::Satisfiable(solution),
+            (None, true) => OptimisationResult::Unsatisfiable,
+            (None, false) => OptimisationResult::Unknown,
+        }
+    }
+
+    fn create_exchanges(&self) -> Vec<NogoodExchange> {
+        NogoodExchange::create_for_workers(
+            self.workers.len(),
+            self.max_shared_lbd,
+            self.max_shared_length,
+        )
+    }
+}
+
+fn optimise_worker(
+    solver: &mut Solver,
+    brancher: &mut impl crate::branching::Brancher,
+    termination: &mut impl TerminationCondition,
+    objective: DomainId,
+    is_maximising: bool,
+) -> OptimisationResult {
+    if is_maximising {
+        solver.maximise(brancher, termination, objective)
+    } else {
+        solver.minimise(brancher, termination, objective)
+    }
+}
+
+/// Stops a worker when another worker has reached a conclusion or the time limit is exceeded.
+#[derive(Debug)]
+struct WorkerTermination<'a> {
+    stopped: &'a AtomicBool,
+    time_budget: Option<TimeBudget>,
+}
+
+impl<'a> WorkerTermination<'a> {
+    fn new(stopped: &'a AtomicBool, time_limit: Option<Duration>) -> Self {
+        WorkerTermination {
+            stopped,
+            time_budget: time_limit.map(TimeBudget::starting_now),
+        }
+    }
+}
+
+impl TerminationCondition for WorkerTermination<'_> {
+    fn should_stop(&mut self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+            || self
+                .time_budget
+                .as_mut()
+                .is_some_and(|time_budget| time_budget.should_stop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints;
+
+    fn pigeon_hole(solver: &mut Solver, num_pigeons: usize) {
+        let pigeons = solver.new_bounded_integers(num_pigeons, 0, num_pigeons as i32 - 2);
+        let _ = solver
+            .add_constraint(constraints::all_different(pigeons))
+            .post();
+    }
+
+    #[test]
+    fn portfolio_without_workers_is_rejected() {
+        let result = Portfolio::new(vec![]);
+
+        assert!(matches!(
+            result,
+            Err(InvalidArgument::EmptyArray {
+                argument: "workers"
+            })
+        ));
+    }
+
+    #[test]
+    fn all_workers_agree_that_the_pigeon_hole_problem_is_unsatisfiable() {
+        let portfolio = Portfolio::with_default_workers(NonZeroUsize::new(3).unwrap());
+
+        let result = portfolio.solve(|solver| pigeon_hole(solver, 5), None);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable(_)));
+    }
+
+    #[test]
+    fn the_optimum_is_proven_when_bounds_are_shared() {
+        let portfolio = Portfolio::with_default_workers(NonZeroUsize::new(2).unwrap())
+            .with_nogood_sharing(3, 30);
+
+        let result = portfolio.maximise(
+            |solver| {
+                let x = solver.new_bounded_integer(0, 10);
+                let y = solver.new_bounded_integer(0, 10);
+                let _ = solver
+                    .add_constraint(constraints::less_than_or_equals([x, y], 12))
+                    .post();
+                let _ = solver
+                    .add_constraint(constraints::binary_less_than(x, y))
+                    .post();
+                x
+            },
+            None,
+        );
+
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected the optimum to be proven");
+        };
+        assert_eq!(solution.get_integer_value(DomainId::new(0)), 5);
+    }
+}
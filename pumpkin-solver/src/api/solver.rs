@@ -1,39 +1,92 @@
 use std::num::NonZero;
+use std::ops::ControlFlow;
+use std::ops::RangeInclusive;
+use std::time::Duration;
 
+use log::info;
+
+use super::construction_heuristics::ConstructionOptions;
+use super::handles::ConstraintId;
+use super::handles::Handles;
+use super::handles::VariableId;
+use super::large_neighbourhood_search::LnsOptions;
+use super::large_neighbourhood_search::NeighbourhoodOutcome;
+use super::large_neighbourhood_search::NeighbourhoodSelector;
+use super::optimisation_target::OptimisationTarget;
+use super::results::ConstructionResult;
+use super::results::LexicographicOptimisationResult;
 use super::results::OptimisationResult;
 use super::results::SatisfactionResult;
 use super::results::SatisfactionResultUnderAssumptions;
+use super::results::SatisfactionResultWithCallback;
+use super::results::SatisfactionResultWithCore;
+use super::results::ViolatedConstraint;
+use super::solver_phase::PhaseStrategy;
+use super::solver_phase::SolverPhase;
+use super::weighted_objective::integral_weight;
+use super::weighted_objective::weights_gcd;
+use super::weighted_objective::WeightedObjective;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::HashSet;
+use crate::basic_types::ProblemSolution;
 use crate::basic_types::Solution;
+use crate::basic_types::SolverError;
+use crate::branching::branchers::dynamic_brancher::DynamicBrancher;
+use crate::branching::branchers::hinted_brancher::HintedBrancher;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
+use crate::branching::ActivityBased;
 use crate::branching::Brancher;
+use crate::branching::DecisionMiddleware;
+use crate::branching::InDomainMin;
+use crate::branching::InDomainRandom;
+use crate::branching::InputOrder;
+use crate::branching::LastConflict;
 use crate::branching::PhaseSaving;
 use crate::branching::SolutionGuidedValueSelector;
 use crate::branching::Vsids;
+use crate::constraints;
+use crate::constraints::validation::check_lengths;
 use crate::constraints::ConstraintPoster;
+use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
+use crate::engine::nogood_exchange::NogoodExchange;
 use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::propagation_context::HasAssignments;
 use crate::engine::propagation::Propagator;
+use crate::engine::symmetry::VariableSymmetry;
+use crate::engine::termination::combinator::Combinator;
+use crate::engine::termination::indefinite::Indefinite;
+use crate::engine::termination::time_budget::TimeBudget;
 use crate::engine::termination::TerminationCondition;
+use crate::engine::variables::AffineView;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
+use crate::engine::variables::TransformableVariable;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::options::LearningOptions;
+use crate::options::RestartPolicy;
+#[cfg(doc)]
+use crate::options::SolutionPolishingOptions;
 use crate::options::SolverOptions;
 use crate::predicate;
+use crate::propagators::util::energetic_makespan_lower_bound;
 use crate::pumpkin_assert_simple;
+use crate::replay::EventLog;
+use crate::results::solution_iterator::IteratedSolution;
 use crate::results::solution_iterator::SolutionIterator;
 use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
+use crate::results::SearchStatistics;
 use crate::results::SolutionCallbackArguments;
 use crate::statistics::statistic_logging::log_statistic;
 use crate::statistics::statistic_logging::log_statistic_postfix;
+use crate::statistics::ModelStatistics;
 use crate::variables::PropositionalVariable;
+use crate::InvalidArgument;
 
 /// The main interaction point which allows the creation of variables, the addition of constraints,
 /// and solving problems.
@@ -90,6 +143,9 @@ pub struct Solver {
     /// The function is called whenever an optimisation function finds a solution; see
     /// [`Solver::with_solution_callback`].
     solution_callback: Box<dyn Fn(SolutionCallbackArguments)>,
+    /// The stable handles of the variables and constraints; see [`VariableId`] and
+    /// [`ConstraintId`].
+    handles: Handles,
 }
 
 impl Default for Solver {
@@ -97,6 +153,7 @@ impl Default for Solver {
         Self {
             satisfaction_solver: Default::default(),
             solution_callback: create_empty_function(),
+            handles: Handles::default(),
         }
     }
 }
@@ -123,6 +180,7 @@ impl Solver {
                 solver_options,
             ),
             solution_callback: create_empty_function(),
+            handles: Handles::default(),
         }
     }
 
@@ -151,6 +209,286 @@ impl Solver {
         log_statistic_postfix();
     }
 
+    /// Returns a summary of the model in the solver (e.g. the number of variables per domain size
+    /// and the number of constraints per type); this can be retrieved before solving.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 9);
+    /// let y = solver.new_bounded_integer(0, 9);
+    /// let z = solver.new_bounded_integer(0, 4);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y]))
+    ///     .post();
+    ///
+    /// let statistics = solver.model_statistics();
+    /// assert_eq!(statistics.num_integer_variables, 3);
+    /// assert_eq!(statistics.integer_variables_by_domain_size[&10], 2);
+    /// assert_eq!(statistics.num_constraints(), 1);
+    /// ```
+    pub fn model_statistics(&self) -> ModelStatistics {
+        self.satisfaction_solver.get_model_statistics()
+    }
+
+    /// Returns a summary of the search performed by the solver so far, accumulated over all calls
+    /// to the solving methods; in contrast to the [`SearchStatistics`] which are part of
+    /// [`SatisfactionResult::Unsatisfiable`] and [`SatisfactionResult::Unknown`], this is available
+    /// regardless of the method which was used to solve (e.g. [`Solver::minimise`] or
+    /// [`Solver::solve_with_callback`]) and of its result.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// let z = solver.new_bounded_integer(0, 2);
+    /// let w = solver.new_bounded_integer(0, 2);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y, z, w]))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise(&mut brancher, &mut Indefinite, x);
+    /// assert!(matches!(result, OptimisationResult::Unsatisfiable));
+    ///
+    /// let statistics = solver.search_statistics();
+    /// assert!(statistics.num_conflicts > 0);
+    /// ```
+    pub fn search_statistics(&self) -> SearchStatistics {
+        self.satisfaction_solver.get_search_statistics()
+    }
+
+    /// Returns the model in the solver in a readable syntax which resembles MiniZinc; this can be
+    /// used to inspect the model which is posted (e.g. by a front-end which translates another
+    /// format), and can be retrieved before solving.
+    ///
+    /// Every integer variable is listed with its current domain, followed by the constraints in
+    /// the order in which they were posted and the clauses which were added. Variables are
+    /// displayed by their names (see [`Solver::new_named_bounded_integer`]) where available.
+    /// Constraints are displayed in an algebraic form where the propagator supports it (e.g.
+    /// `x + 2 * (y) <= 5`), and otherwise as the name of the propagator applied to the variables
+    /// which it watches.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_named_bounded_integer(0, 9, "x");
+    /// let y = solver.new_named_sparse_integer(vec![1, 3, 5], "y");
+    ///
+    /// solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 5))
+    ///     .post();
+    /// solver
+    ///     .add_constraint(constraints::all_different([x, y]))
+    ///     .post();
+    ///
+    /// // Note that the domain of `x` is narrowed by the first constraint
+    /// assert_eq!(
+    ///     solver.dump_pretty(),
+    ///     "var 0..4: x;\n\
+    ///      var {1, 3, 5}: y;\n\
+    ///      constraint x + y <= 5;\n\
+    ///      constraint AllDifferentRegin(x, y);\n"
+    /// );
+    /// ```
+    pub fn dump_pretty(&self) -> String {
+        self.satisfaction_solver.dump_pretty()
+    }
+
+    /// Start recording the decisions and domain events which occur during search; see
+    /// [`crate::replay`] for more information. Any previously recorded events are discarded.
+    pub fn record_events(&mut self) {
+        self.satisfaction_solver.record_events()
+    }
+
+    /// Returns the events recorded since [`Solver::record_events`] was called, and stops
+    /// recording. If events were not being recorded, [`None`] is returned.
+    pub fn take_event_log(&mut self) -> Option<EventLog> {
+        self.satisfaction_solver.take_event_log()
+    }
+
+    /// Registers a [`DecisionMiddleware`] which inspects every subsequent decision of the
+    /// [`Brancher`] before it is applied, and which can replace or veto it; middlewares are
+    /// applied in the order in which they were registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    /// # use pumpkin_solver::branching::value_selection::InDomainMin;
+    /// # use pumpkin_solver::branching::variable_selection::InputOrder;
+    /// # use pumpkin_solver::branching::SelectionContext;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::predicates::Predicate;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// // The decisions over `x` are replaced by the decision to fix `x` to its upper bound
+    /// solver.add_decision_middleware(
+    ///     move |decision: Predicate, context: &mut SelectionContext| {
+    ///         if decision.get_domain() == Some(x) {
+    ///             Some(predicate![x >= context.upper_bound(x)].into())
+    ///         } else {
+    ///             Some(decision)
+    ///         }
+    ///     },
+    /// );
+    ///
+    /// let mut brancher = IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainMin);
+    /// let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    /// let SatisfactionResult::Satisfiable(solution) = result else {
+    ///     panic!("the problem is satisfiable");
+    /// };
+    /// assert_eq!(solution.get_integer_value(x), 10);
+    /// ```
+    pub fn add_decision_middleware(&mut self, middleware: impl DecisionMiddleware + 'static) {
+        self.satisfaction_solver.add_decision_middleware(middleware)
+    }
+
+    /// Replaces the policy which determines when the solver restarts; by default, the policy is
+    /// configured by [`SolverOptions::restart_options`]. The [`Brancher`] is notified of every
+    /// restart (see [`Brancher::on_restart`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::options::SequenceGeneratorType;
+    /// # use pumpkin_solver::options::SequenceRestarts;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// solver.set_restart_policy(SequenceRestarts::new(SequenceGeneratorType::Luby, 32, None));
+    ///
+    /// let variables = solver.new_bounded_integers(5, 0, 4);
+    /// let _ = solver
+    ///     .add_constraint(constraints::all_different(variables.clone()))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    /// assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+    /// ```
+    pub fn set_restart_policy(&mut self, restart_policy: impl RestartPolicy + 'static) {
+        self.satisfaction_solver.set_restart_policy(restart_policy)
+    }
+
+    /// Connects the solver to the other workers of a portfolio; see [`NogoodExchange`].
+    pub(crate) fn set_nogood_exchange(&mut self, nogood_exchange: NogoodExchange) {
+        self.satisfaction_solver
+            .set_nogood_exchange(nogood_exchange)
+    }
+
+    /// Declares a symmetry of the model which maps `variables[i]` to `images[i]` (and every other
+    /// variable to itself); `images` should be a permutation of `variables`, otherwise a
+    /// [`ConstraintOperationError::InvalidArgument`] is returned.
+    ///
+    /// Whenever a clause is learned, its images under the declared symmetries are learned as
+    /// well, up to [`SolverOptions::max_symmetric_images`] per learned clause; on highly symmetric
+    /// problems, this avoids exploring the symmetric counterparts of parts of the search space
+    /// which have already been refuted.
+    ///
+    /// The symmetry should map every solution to a solution (with the same objective value when
+    /// optimising), which requires that the permuted variables have the same domains; otherwise,
+    /// solutions may be removed. Symmetries which permute values or which involve [`Literal`]s
+    /// which are not linked to an integer variable are not supported.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// // Five pigeons cannot be placed in four holes
+    /// let pigeons = (0..5)
+    ///     .map(|_| solver.new_bounded_integer(0, 3))
+    ///     .collect::<Vec<_>>();
+    /// for (i, &x) in pigeons.iter().enumerate() {
+    ///     for &y in &pigeons[i + 1..] {
+    ///         solver
+    ///             .add_constraint(constraints::binary_not_equals(x, y))
+    ///             .post()
+    ///             .expect("no conflict at the root");
+    ///     }
+    /// }
+    ///
+    /// // The pigeons are interchangeable: the swap of two pigeons and the rotation of all pigeons
+    /// // generate all permutations
+    /// let mut rotated = pigeons.clone();
+    /// rotated.rotate_left(1);
+    /// solver
+    ///     .add_symmetry([pigeons[0], pigeons[1]], [pigeons[1], pigeons[0]])
+    ///     .expect("the images are a permutation");
+    /// solver
+    ///     .add_symmetry(pigeons.clone(), rotated)
+    ///     .expect("the images are a permutation");
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    /// let SatisfactionResult::Unsatisfiable(statistics) = result else {
+    ///     panic!("there are more pigeons than holes");
+    /// };
+    /// assert!(statistics.num_conflicts > 0);
+    /// ```
+    pub fn add_symmetry(
+        &mut self,
+        variables: impl IntoIterator<Item = DomainId>,
+        images: impl IntoIterator<Item = DomainId>,
+    ) -> Result<(), ConstraintOperationError> {
+        let symmetry = VariableSymmetry::new(
+            variables.into_iter().collect(),
+            images.into_iter().collect(),
+        )?;
+        self.satisfaction_solver.add_symmetry(symmetry);
+
+        Ok(())
+    }
+
+    /// Returns the number of propagators which have been added to the solver.
+    pub(crate) fn num_propagators(&self) -> usize {
+        self.satisfaction_solver.num_propagators()
+    }
+
+    /// Posts a constraint using `post`, and creates the [`ConstraintId`] of the propagators and
+    /// clauses which it added.
+    pub(crate) fn post_constraint(
+        &mut self,
+        post: impl FnOnce(&mut Solver) -> Result<(), ConstraintOperationError>,
+    ) -> Result<ConstraintId, ConstraintOperationError> {
+        let first_propagator = self.num_propagators();
+        let first_clause = self.handles.num_clauses();
+
+        self.handles.open_constraint();
+        let result = post(self);
+        self.handles.close_constraint();
+        result?;
+
+        Ok(self.handles.new_constraint(
+            first_propagator..self.num_propagators(),
+            first_clause..self.handles.num_clauses(),
+        ))
+    }
+
+    pub(crate) fn get_satisfaction_solver(&self) -> &ConstraintSatisfactionSolver {
+        &self.satisfaction_solver
+    }
+
     pub(crate) fn get_satisfaction_solver_mut(&mut self) -> &mut ConstraintSatisfactionSolver {
         &mut self.satisfaction_solver
     }
@@ -205,6 +543,226 @@ impl Solver {
     pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
         self.satisfaction_solver.get_upper_bound(variable)
     }
+
+    /// Get the number of values in the domain of the given [`IntegerVariable`] at the root level
+    /// (after propagation); this is maintained by the solver and thus does not require iterating
+    /// over the domain.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_sparse_integer(vec![0, 3, 4, 10]);
+    ///
+    /// assert_eq!(solver.get_domain_size(&x), 4);
+    /// assert_eq!(solver.get_number_of_holes(x), 7);
+    /// ```
+    pub fn get_domain_size(&self, variable: &impl IntegerVariable) -> usize {
+        self.satisfaction_solver.get_domain_size(variable)
+    }
+
+    /// Get the number of values between the bounds of the given [`DomainId`] at the root level
+    /// (after propagation) which are not in its domain.
+    pub fn get_number_of_holes(&self, variable: DomainId) -> usize {
+        self.satisfaction_solver.get_number_of_holes(variable)
+    }
+
+    /// Get the domain of the given [`DomainId`] at the root level (after propagation) as a sorted
+    /// list of disjoint ranges, rather than as a description in terms of [`Predicate`]s.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_sparse_integer(vec![0, 1, 2, 5, 7, 8]);
+    ///
+    /// assert_eq!(solver.get_domain_ranges(x), vec![0..=2, 5..=5, 7..=8]);
+    /// ```
+    pub fn get_domain_ranges(&self, variable: DomainId) -> Vec<RangeInclusive<i32>> {
+        self.satisfaction_solver.get_domain_ranges(variable)
+    }
+
+    /// Get the conflict activity of the given [`DomainId`].
+    ///
+    /// The activity is increased every time the variable appears in a conflict, with recent
+    /// conflicts weighing more heavily than older ones. This is tracked by the solver itself,
+    /// regardless of the [`Brancher`] that is used, and can be used to prefer "hot" variables
+    /// (e.g. when selecting a neighbourhood for large neighbourhood search). Only the relative
+    /// order of activities is meaningful, the absolute values are not.
+    ///
+    /// Variables are numbered in the order in which they are created and these ids never change,
+    /// so [`DomainId::id`] can be used to store activities externally.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// // No conflicts have been encountered yet.
+    /// assert_eq!(solver.get_integer_variable_activity(x), 0.0);
+    /// ```
+    pub fn get_integer_variable_activity(&self, variable: DomainId) -> f64 {
+        self.satisfaction_solver
+            .get_integer_variable_activity(variable)
+    }
+
+    /// Get the conflict activity of the given [`PropositionalVariable`]; see
+    /// [`Solver::get_integer_variable_activity`] for more information.
+    pub fn get_propositional_variable_activity(&self, variable: PropositionalVariable) -> f64 {
+        self.satisfaction_solver
+            .get_propositional_variable_activity(variable)
+    }
+
+    /// Returns the stable handle of the given integer variable, or [`None`] if it was not created
+    /// through the [`Solver`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::variables::VariableId;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// // The raw value of the handle can be stored and later be resolved again
+    /// let raw = solver
+    ///     .variable_id(x)
+    ///     .expect("created by the solver")
+    ///     .to_raw();
+    /// assert_eq!(solver.variable(VariableId::from_raw(raw)), Some(x));
+    /// ```
+    pub fn variable_id(&self, variable: DomainId) -> Option<VariableId> {
+        self.handles.variable_id(variable)
+    }
+
+    /// Returns the integer variable with the given stable handle, or [`None`] if there is no such
+    /// variable.
+    pub fn variable(&self, variable_id: VariableId) -> Option<DomainId> {
+        self.handles.variable(variable_id)
+    }
+
+    /// Returns a description of every propagator which was added when the constraint with the
+    /// given handle was posted (in the syntax of [`Solver::dump_pretty`]), or [`None`] if there is
+    /// no such constraint. The description is empty if the constraint was decomposed into clauses.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_named_bounded_integer(0, 10, "x");
+    /// let y = solver.new_named_bounded_integer(0, 10, "y");
+    ///
+    /// let constraint_id = solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 5))
+    ///     .post_with_id()
+    ///     .expect("no conflict at the root");
+    /// assert_eq!(
+    ///     solver.describe_constraint(constraint_id),
+    ///     Some(vec!["x + y <= 5".to_owned()])
+    /// );
+    /// ```
+    pub fn describe_constraint(&self, constraint_id: ConstraintId) -> Option<Vec<String>> {
+        self.handles.propagators(constraint_id).map(|propagators| {
+            propagators
+                .map(|propagator_id| self.satisfaction_solver.describe_propagator(propagator_id))
+                .collect()
+        })
+    }
+
+    /// Creates an assignment in which the provided integer variables and literals are fixed to the
+    /// provided values, which can be checked using [`Solver::check`]; the other variables keep
+    /// their domain at the root. Returns [`None`] if one of the values is not in the domain of its
+    /// variable at the root.
+    pub fn assignment_from_values(
+        &self,
+        integer_values: &[(DomainId, i32)],
+        literal_values: &[(Literal, bool)],
+    ) -> Option<Solution> {
+        self.satisfaction_solver
+            .assignment_from_values(integer_values, literal_values)
+    }
+
+    /// Checks the provided assignment against all posted constraints, e.g. to validate a solution
+    /// which was found by an external heuristic (see [`Solver::assignment_from_values`]). It
+    /// returns the constraints which are violated by the assignment, in the order in which they
+    /// were posted.
+    ///
+    /// The assignment should assign every variable in the scope of the constraints. The check is
+    /// performed by the propagators of the constraints, and by unit propagation over the clauses
+    /// which were added for them (including the clauses which were added using
+    /// [`Solver::add_clause`]); the literals which are introduced by a decomposition therefore do
+    /// not have to be assigned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_named_bounded_integer(0, 10, "x");
+    /// let y = solver.new_named_bounded_integer(0, 10, "y");
+    ///
+    /// let constraint_id = solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 5))
+    ///     .post_with_id()
+    ///     .expect("no conflict at the root");
+    ///
+    /// let satisfying = solver
+    ///     .assignment_from_values(&[(x, 2), (y, 3)], &[])
+    ///     .expect("the values are in the domains");
+    /// assert!(solver.check(&satisfying).is_empty());
+    ///
+    /// let violating = solver
+    ///     .assignment_from_values(&[(x, 4), (y, 3)], &[])
+    ///     .expect("the values are in the domains");
+    /// let violated = solver.check(&violating);
+    /// assert_eq!(violated.len(), 1);
+    /// assert_eq!(violated[0].constraint_id, constraint_id);
+    /// assert_eq!(violated[0].description, "x + y <= 5");
+    /// ```
+    pub fn check(&self, assignment: &Solution) -> Vec<ViolatedConstraint> {
+        self.handles
+            .constraint_ids()
+            .filter_map(|constraint_id| {
+                self.handles
+                    .propagators(constraint_id)
+                    .expect("the constraint exists")
+                    .find_map(|propagator_id| {
+                        let reason = self.satisfaction_solver.check_propagator(
+                            propagator_id,
+                            assignment.assignments_integer(),
+                            assignment.assignments_propositional(),
+                        )?;
+                        Some(ViolatedConstraint {
+                            constraint_id,
+                            description: self
+                                .satisfaction_solver
+                                .describe_propagator(propagator_id),
+                            reason,
+                        })
+                    })
+                    .or_else(|| {
+                        let clauses = self
+                            .handles
+                            .clauses(constraint_id)
+                            .expect("the constraint exists")
+                            .collect::<Vec<_>>();
+                        let clause = ConstraintSatisfactionSolver::check_clauses(
+                            &clauses,
+                            assignment.assignments_propositional(),
+                        )?;
+                        Some(ViolatedConstraint {
+                            constraint_id,
+                            description: self.satisfaction_solver.display_with(clause).to_string(),
+                            reason: clause
+                                .iter()
+                                .map(|&literal| Predicate::Literal(!literal))
+                                .collect(),
+                        })
+                    })
+            })
+            .collect()
+    }
 }
 
 /// Functions to create and retrieve integer and propositional variables.
@@ -275,9 +833,76 @@ impl Solver {
     /// // We can create an integer variable with a domain in the range [0, 10]
     /// let integer_between_bounds = solver.new_bounded_integer(0, 10);
     /// ```
+    ///
+    /// # Panics
+    /// If `lower_bound > upper_bound`; see [`Solver::try_new_bounded_integer`] for a variant which
+    /// returns an error instead.
     pub fn new_bounded_integer(&mut self, lower_bound: i32, upper_bound: i32) -> DomainId {
-        self.satisfaction_solver
-            .create_new_integer_variable(lower_bound, upper_bound, None)
+        let domain_id =
+            self.satisfaction_solver
+                .create_new_integer_variable(lower_bound, upper_bound, None);
+        self.register_variable(domain_id)
+    }
+
+    /// Assigns a [`VariableId`] to the newly created `domain_id` and returns it.
+    fn register_variable(&mut self, domain_id: DomainId) -> DomainId {
+        let _ = self.handles.new_variable(domain_id);
+        domain_id
+    }
+
+    /// Create a new integer variable with the given bounds, or return
+    /// [`SolverError::EmptyDomain`] if `lower_bound > upper_bound`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::SolverError;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let result = solver.try_new_bounded_integer(10, 0);
+    /// assert!(matches!(result, Err(SolverError::EmptyDomain)));
+    /// ```
+    pub fn try_new_bounded_integer(
+        &mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Result<DomainId, SolverError> {
+        if lower_bound > upper_bound {
+            return Err(SolverError::EmptyDomain);
+        }
+
+        Ok(self.new_bounded_integer(lower_bound, upper_bound))
+    }
+
+    /// Create `num_variables` new integer variables with the given bounds; this is more efficient
+    /// than creating the variables one by one using [`Solver::new_bounded_integer`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let variables = solver.new_bounded_integers(100, 0, 10);
+    /// assert_eq!(variables.len(), 100);
+    /// assert!(variables
+    ///     .iter()
+    ///     .all(|variable| solver.upper_bound(variable) == 10));
+    /// ```
+    pub fn new_bounded_integers(
+        &mut self,
+        num_variables: usize,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Vec<DomainId> {
+        let domain_ids = self.satisfaction_solver.create_new_integer_variables(
+            num_variables,
+            lower_bound,
+            upper_bound,
+        );
+        for &domain_id in &domain_ids {
+            let _ = self.register_variable(domain_id);
+        }
+        domain_ids
     }
 
     /// Create a new named integer variable with the given bounds.
@@ -296,11 +921,12 @@ impl Solver {
         upper_bound: i32,
         name: impl Into<String>,
     ) -> DomainId {
-        self.satisfaction_solver.create_new_integer_variable(
+        let domain_id = self.satisfaction_solver.create_new_integer_variable(
             lower_bound,
             upper_bound,
             Some(name.into()),
-        )
+        );
+        self.register_variable(domain_id)
     }
 
     /// Create a new integer variable which has a domain of predefined values. We remove duplicates
@@ -314,11 +940,31 @@ impl Solver {
     /// // We can also create an integer variable with a non-continuous domain in the follow way
     /// let mut sparse_integer = solver.new_sparse_integer(vec![0, 3, 5]);
     /// ```
+    ///
+    /// # Panics
+    /// If `values` is empty; see [`Solver::try_new_sparse_integer`] for a variant which returns an
+    /// error instead.
     pub fn new_sparse_integer(&mut self, values: impl Into<Vec<i32>>) -> DomainId {
         let values: HashSet<i32> = values.into().into_iter().collect();
 
-        self.satisfaction_solver
-            .create_new_integer_variable_sparse(values.into_iter().collect(), None)
+        let domain_id = self
+            .satisfaction_solver
+            .create_new_integer_variable_sparse(values.into_iter().collect(), None);
+        self.register_variable(domain_id)
+    }
+
+    /// Create a new integer variable which has a domain of predefined values, or return
+    /// [`SolverError::EmptyDomain`] if `values` is empty.
+    pub fn try_new_sparse_integer(
+        &mut self,
+        values: impl Into<Vec<i32>>,
+    ) -> Result<DomainId, SolverError> {
+        let values = values.into();
+        if values.is_empty() {
+            return Err(SolverError::EmptyDomain);
+        }
+
+        Ok(self.new_sparse_integer(values))
     }
 
     /// Create a new named integer variable which has a domain of predefined values.
@@ -336,47 +982,522 @@ impl Solver {
         values: impl Into<Vec<i32>>,
         name: impl Into<String>,
     ) -> DomainId {
-        self.satisfaction_solver
-            .create_new_integer_variable_sparse(values.into(), Some(name.into()))
+        let domain_id = self
+            .satisfaction_solver
+            .create_new_integer_variable_sparse(values.into(), Some(name.into()));
+        self.register_variable(domain_id)
     }
-}
 
-/// Functions for solving with the constraints that have been added to the [`Solver`].
-impl Solver {
-    /// Solves the current model in the [`Solver`] until it finds a solution (or is indicated to
-    /// terminate by the provided [`TerminationCondition`]) and returns a [`SatisfactionResult`]
-    /// which can be used to obtain the found solution or find other solutions.
-    pub fn satisfy<B: Brancher, T: TerminationCondition>(
+    /// Create a single objective from a weighted sum of several objective expressions, which can
+    /// then be optimised using [`Solver::minimise`] or [`Solver::maximise`] on its
+    /// [`WeightedObjective::objective_variable`].
+    ///
+    /// The weights are divided by their greatest common divisor to keep the domain of the
+    /// objective variable small (see [`WeightedObjective::scale`]). The bounds of the (scaled)
+    /// weighted sum are computed using 128-bit arithmetic; if the scaled weights or the bounds do
+    /// not fit in an `i32`, then [`ConstraintOperationError::IntegerOverflow`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let cost = solver.new_bounded_integer(1, 10);
+    /// let lateness = solver.new_bounded_integer(2, 5);
+    ///
+    /// let objective = solver
+    ///     .new_weighted_objective([(4, cost.scaled(1)), (6, lateness.scaled(1))])
+    ///     .expect("the bounds fit in an i32");
+    /// assert_eq!(objective.scale(), 2);
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise(
+    ///     &mut brancher,
+    ///     &mut Indefinite,
+    ///     objective.objective_variable(),
+    /// );
+    ///
+    /// if let OptimisationResult::Optimal(solution) = result {
+    ///     assert_eq!(objective.value(&solution), 16);
+    ///     assert_eq!(objective.component_values(&solution), vec![1, 2]);
+    /// } else {
+    ///     panic!("Expected an optimal solution");
+    /// }
+    /// ```
+    pub fn new_weighted_objective<Weight: Into<i64>>(
         &mut self,
-        brancher: &mut B,
-        termination: &mut T,
-    ) -> SatisfactionResult {
-        match self.satisfaction_solver.solve(termination, brancher) {
-            CSPSolverExecutionFlag::Feasible => {
-                let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                self.process_solution(&solution, brancher);
-                SatisfactionResult::Satisfiable(solution)
-            }
-            CSPSolverExecutionFlag::Infeasible => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self.satisfaction_solver.conclude_proof_unsat();
+        components: impl IntoIterator<Item = (Weight, AffineView<DomainId>)>,
+    ) -> Result<WeightedObjective, ConstraintOperationError> {
+        let components = components
+            .into_iter()
+            .map(|(weight, expression)| (weight.into(), expression))
+            .collect();
 
-                SatisfactionResult::Unsatisfiable
-            }
-            CSPSolverExecutionFlag::Timeout => {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                SatisfactionResult::Unknown
-            }
-        }
+        self.create_weighted_objective(components, 1)
     }
 
-    pub fn get_solution_iterator<
-        'this,
-        'brancher,
-        'termination,
+    /// Create a single objective from a weighted sum of several objective expressions with
+    /// fractional weights (e.g. costs which are given as floating point numbers); see
+    /// [`Solver::new_weighted_objective`].
+    ///
+    /// The weights are rounded to `decimal_digits` decimals and multiplied by `10^decimal_digits`
+    /// (the [`WeightedObjective::denominator`]) to make them integral; the weighted sum is
+    /// accumulated exactly using 128-bit arithmetic, so the precision is only limited by the
+    /// requirement that the scaled weights and the bounds of the scaled weighted sum fit in an
+    /// `i32` (otherwise [`ConstraintOperationError::IntegerOverflow`] is returned). Weights which
+    /// are not finite are rejected with [`ConstraintOperationError::InvalidArgument`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let energy = solver.new_bounded_integer(3, 10);
+    /// let distance = solver.new_bounded_integer(2, 5);
+    ///
+    /// let objective = solver
+    ///     .new_scaled_objective([(0.25, energy.scaled(1)), (1.5, distance.scaled(1))], 2)
+    ///     .expect("the bounds fit in an i32");
+    /// assert_eq!(objective.denominator(), 100);
+    /// assert_eq!(objective.scale(), 25);
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise(
+    ///     &mut brancher,
+    ///     &mut Indefinite,
+    ///     objective.objective_variable(),
+    /// );
+    ///
+    /// if let OptimisationResult::Optimal(solution) = result {
+    ///     assert_eq!(objective.value(&solution), 375);
+    ///     assert_eq!(objective.approximate_value(&solution), 3.75);
+    /// } else {
+    ///     panic!("Expected an optimal solution");
+    /// }
+    /// ```
+    pub fn new_scaled_objective(
+        &mut self,
+        components: impl IntoIterator<Item = (f64, AffineView<DomainId>)>,
+        decimal_digits: u32,
+    ) -> Result<WeightedObjective, ConstraintOperationError> {
+        let denominator = 10_i64
+            .checked_pow(decimal_digits)
+            .ok_or(ConstraintOperationError::IntegerOverflow)?;
+
+        let components = components
+            .into_iter()
+            .enumerate()
+            .map(|(index, (weight, expression))| {
+                if !weight.is_finite() {
+                    return Err(InvalidArgument::NonFiniteElement {
+                        argument: "components",
+                        index,
+                    }
+                    .into());
+                }
+
+                integral_weight(weight, denominator)
+                    .map(|weight| (weight, expression))
+                    .ok_or(ConstraintOperationError::IntegerOverflow)
+            })
+            .collect::<Result<Vec<_>, ConstraintOperationError>>()?;
+
+        self.create_weighted_objective(components, denominator)
+    }
+
+    /// Creates the objective variable which is linked to the weighted sum of the `components`
+    /// (divided by their greatest common divisor).
+    fn create_weighted_objective(
+        &mut self,
+        components: Vec<(i64, AffineView<DomainId>)>,
+        denominator: i64,
+    ) -> Result<WeightedObjective, ConstraintOperationError> {
+        let scale = weights_gcd(components.iter().map(|&(weight, _)| weight));
+
+        let fits_in_i32 = |value: i128| i32::try_from(value).is_ok();
+
+        let mut lower_bound = 0_i128;
+        let mut upper_bound = 0_i128;
+        let mut terms = Vec::with_capacity(components.len() + 1);
+        for &(weight, expression) in &components {
+            let scaled_weight = weight as i128 / scale;
+            let bound_a = scaled_weight * self.lower_bound(&expression) as i128;
+            let bound_b = scaled_weight * self.upper_bound(&expression) as i128;
+
+            if !fits_in_i32(scaled_weight) || !fits_in_i32(bound_a) || !fits_in_i32(bound_b) {
+                return Err(ConstraintOperationError::IntegerOverflow);
+            }
+
+            lower_bound += bound_a.min(bound_b);
+            upper_bound += bound_a.max(bound_b);
+            terms.push(expression.scaled(scaled_weight as i32));
+        }
+
+        if !fits_in_i32(lower_bound) || !fits_in_i32(upper_bound) {
+            return Err(ConstraintOperationError::IntegerOverflow);
+        }
+
+        let objective_variable = self.new_bounded_integer(lower_bound as i32, upper_bound as i32);
+        terms.push(objective_variable.scaled(-1));
+        self.add_constraint(constraints::equals(terms, 0)).post()?;
+
+        Ok(WeightedObjective::new(
+            objective_variable,
+            scale,
+            denominator,
+            components,
+        ))
+    }
+}
+
+/// Functions for solving with the constraints that have been added to the [`Solver`].
+impl Solver {
+    /// Solves the current model in the [`Solver`] until it finds a solution (or is indicated to
+    /// terminate by the provided [`TerminationCondition`]) and returns a [`SatisfactionResult`]
+    /// which can be used to obtain the found solution or find other solutions.
+    pub fn satisfy<B: Brancher, T: TerminationCondition>(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut T,
+    ) -> SatisfactionResult {
+        match self.satisfaction_solver.solve(termination, brancher) {
+            CSPSolverExecutionFlag::Feasible => {
+                let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                self.process_solution(&solution, brancher);
+                SatisfactionResult::Satisfiable(solution)
+            }
+            CSPSolverExecutionFlag::Infeasible => {
+                // The model itself is only proven infeasible if the infeasibility does not depend
+                // on the variable bounds or the open scopes
+                let is_infeasible_at_root = !self
+                    .satisfaction_solver
+                    .state
+                    .is_infeasible_under_assumptions();
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                if is_infeasible_at_root {
+                    let _ = self.satisfaction_solver.conclude_proof_unsat();
+                }
+
+                SatisfactionResult::Unsatisfiable(self.satisfaction_solver.get_search_statistics())
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                SatisfactionResult::Unknown(self.satisfaction_solver.get_search_statistics())
+            }
+        }
+    }
+
+    /// Solves the current model in the [`Solver`] using the brancher created by
+    /// [`Solver::default_brancher_over_all_propositional_variables`]; this is a shorthand for
+    /// [`Solver::satisfy`] for when no control over the search is required.
+    ///
+    /// If a `time_limit` is provided then the search is stopped (resulting in
+    /// [`SatisfactionResult::Unknown`]) once it is exceeded; otherwise, the solver runs until it
+    /// either finds a solution or proves that none exists.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// solver
+    ///     .add_constraint(constraints::equals([x, y], 7))
+    ///     .post()
+    ///     .expect("the constraint does not make the model infeasible at the root");
+    ///
+    /// match solver.solve(Some(Duration::from_secs(10))) {
+    ///     SatisfactionResult::Satisfiable(solution) => {
+    ///         assert_eq!(
+    ///             solution.get_integer_value(x) + solution.get_integer_value(y),
+    ///             7
+    ///         );
+    ///     }
+    ///     _ => panic!("the model has a solution"),
+    /// }
+    /// ```
+    pub fn solve(&mut self, time_limit: Option<Duration>) -> SatisfactionResult {
+        let mut brancher = self.default_brancher_over_all_propositional_variables();
+
+        match time_limit {
+            Some(time_limit) => {
+                self.satisfy(&mut brancher, &mut TimeBudget::starting_now(time_limit))
+            }
+            None => self.satisfy(&mut brancher, &mut Indefinite),
+        }
+    }
+
+    /// Solves the current model in the [`Solver`] in the same manner as [`Solver::solve`], but
+    /// first tries to assign the variables to the values given by `hints` (in the provided order).
+    ///
+    /// The hints are soft decisions (similar to solution hints in MIP solvers), rather than
+    /// assumptions: once the search encounters a conflict, the hints are abandoned and the search
+    /// continues with the default brancher. A solution therefore does not necessarily agree with
+    /// the hints, and the result does not depend on whether the hints can be extended to a
+    /// solution. See [`HintedBrancher`] to combine hints with a custom [`Brancher`].
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// solver
+    ///     .add_constraint(constraints::equals([x, y], 7))
+    ///     .post()
+    ///     .expect("the constraint does not make the model infeasible at the root");
+    ///
+    /// match solver.solve_with_hints(&[(x, 3)], None) {
+    ///     SatisfactionResult::Satisfiable(solution) => {
+    ///         assert_eq!(solution.get_integer_value(x), 3);
+    ///         assert_eq!(solution.get_integer_value(y), 4);
+    ///     }
+    ///     _ => panic!("the model has a solution"),
+    /// }
+    /// ```
+    pub fn solve_with_hints(
+        &mut self,
+        hints: &[(DomainId, i32)],
+        time_limit: Option<Duration>,
+    ) -> SatisfactionResult {
+        let mut brancher = HintedBrancher::new(
+            hints,
+            self.default_brancher_over_all_propositional_variables(),
+        );
+
+        match time_limit {
+            Some(time_limit) => {
+                self.satisfy(&mut brancher, &mut TimeBudget::starting_now(time_limit))
+            }
+            None => self.satisfy(&mut brancher, &mut Indefinite),
+        }
+    }
+
+    /// Constructs an initial solution using greedy dives: a dive assigns the provided `variables`
+    /// one after another (in the provided order), propagating after every assignment, and is
+    /// abandoned as soon as a conflict is encountered. The first dive assigns the smallest values,
+    /// the subsequent dives (i.e. random restarts) assign random values; see
+    /// [`ConstructionOptions`]. The remaining variables are assigned by the default brancher.
+    ///
+    /// If none of the dives reach a solution, then the values of the deepest dive are returned as
+    /// hints; the result can be used to warm-start the search with a [`HintedBrancher`] through
+    /// [`ConstructionResult::into_hints`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::options::ConstructionOptions;
+    /// # use pumpkin_solver::results::ConstructionResult;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let variables = solver.new_bounded_integers(4, 0, 3);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(variables.clone()))
+    ///     .post()
+    ///     .expect("the constraint is satisfiable");
+    ///
+    /// let result = solver.construct_initial_solution(
+    ///     &mut Indefinite,
+    ///     &variables,
+    ///     ConstructionOptions::default(),
+    /// );
+    ///
+    /// // Greedily assigning the smallest values does not lead to a conflict
+    /// assert!(matches!(result, ConstructionResult::Solution(_)));
+    ///
+    /// let hints = result.into_hints(&variables);
+    /// assert_eq!(
+    ///     hints,
+    ///     vec![
+    ///         (variables[0], 0),
+    ///         (variables[1], 1),
+    ///         (variables[2], 2),
+    ///         (variables[3], 3)
+    ///     ]
+    /// );
+    /// assert!(matches!(
+    ///     solver.solve_with_hints(&hints, None),
+    ///     SatisfactionResult::Satisfiable(_)
+    /// ));
+    /// ```
+    pub fn construct_initial_solution(
+        &mut self,
+        termination: &mut impl TerminationCondition,
+        variables: &[DomainId],
+        options: ConstructionOptions,
+    ) -> ConstructionResult {
+        let mut hints = vec![];
+
+        for dive in 0..options.num_dives {
+            let greedy: Box<dyn Brancher> = if dive == 0 {
+                Box::new(IndependentVariableValueBrancher::new(
+                    InputOrder::new(variables),
+                    InDomainMin,
+                ))
+            } else {
+                Box::new(IndependentVariableValueBrancher::new(
+                    InputOrder::new(variables),
+                    InDomainRandom,
+                ))
+            };
+            let mut brancher = DynamicBrancher::new(vec![
+                greedy,
+                Box::new(self.default_brancher_over_all_propositional_variables()),
+            ]);
+
+            // The dive is abandoned at the first conflict
+            match self
+                .satisfaction_solver
+                .solve_under_assumptions_with_conflict_budget(&[], termination, &mut brancher, 1)
+            {
+                CSPSolverExecutionFlag::Feasible => {
+                    let solution: Solution =
+                        self.satisfaction_solver.get_solution_reference().into();
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver
+                        .restore_state_at_root(&mut brancher);
+                    return ConstructionResult::Solution(solution);
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver
+                        .restore_state_at_root(&mut brancher);
+                    return ConstructionResult::Unsatisfiable;
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // The solver backtracked after the conflict, so the remaining assignments are
+                    // consistent
+                    let dive_hints = variables
+                        .iter()
+                        .filter_map(|&variable| {
+                            self.satisfaction_solver
+                                .get_assigned_integer_value(&variable)
+                                .map(|value| (variable, value))
+                        })
+                        .collect::<Vec<_>>();
+                    if dive_hints.len() > hints.len() {
+                        hints = dive_hints;
+                    }
+                    self.satisfaction_solver
+                        .restore_state_at_root(&mut brancher);
+
+                    if termination.should_stop() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        ConstructionResult::Hints(hints)
+    }
+
+    /// Solves the current model in the [`Solver`] in the same manner as [`Solver::solve`], but
+    /// rather than stopping at the first solution, `on_solution` is called with every solution
+    /// which is found. The callback decides whether the search should look for another solution
+    /// ([`ControlFlow::Continue`]) or stop ([`ControlFlow::Break`]).
+    ///
+    /// Every solution is found at most once; this is achieved by adding a clause which blocks the
+    /// previous solution before searching for the next one (in the same way as
+    /// [`Solver::get_solution_iterator`]). Note that these clauses remain part of the model after
+    /// this method returns.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResultWithCallback;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y]))
+    ///     .post()
+    ///     .expect("the constraint does not make the model infeasible at the root");
+    ///
+    /// // Stop after the second solution
+    /// let mut solutions = Vec::new();
+    /// let result = solver.solve_with_callback(None, |solution| {
+    ///     solutions.push((solution.get_integer_value(x), solution.get_integer_value(y)));
+    ///     if solutions.len() == 2 {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    ///
+    /// assert!(matches!(result, SatisfactionResultWithCallback::Stopped));
+    /// assert_eq!(solutions.len(), 2);
+    /// assert_ne!(solutions[0], solutions[1]);
+    /// ```
+    pub fn solve_with_callback(
+        &mut self,
+        time_limit: Option<Duration>,
+        on_solution: impl FnMut(&Solution) -> ControlFlow<()>,
+    ) -> SatisfactionResultWithCallback {
+        let mut brancher = self.default_brancher_over_all_propositional_variables();
+
+        match time_limit {
+            Some(time_limit) => self.enumerate_with_callback(
+                &mut brancher,
+                &mut TimeBudget::starting_now(time_limit),
+                on_solution,
+            ),
+            None => self.enumerate_with_callback(&mut brancher, &mut Indefinite, on_solution),
+        }
+    }
+
+    fn enumerate_with_callback(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        mut on_solution: impl FnMut(&Solution) -> ControlFlow<()>,
+    ) -> SatisfactionResultWithCallback {
+        let mut solution_iterator = self.get_solution_iterator(brancher, termination);
+
+        loop {
+            match solution_iterator.next_solution() {
+                IteratedSolution::Solution(solution) => {
+                    if on_solution(&solution).is_break() {
+                        return SatisfactionResultWithCallback::Stopped;
+                    }
+                }
+                IteratedSolution::Finished => return SatisfactionResultWithCallback::Finished,
+                IteratedSolution::Unsatisfiable => {
+                    return SatisfactionResultWithCallback::Unsatisfiable
+                }
+                IteratedSolution::Unknown => break,
+            }
+        }
+
+        SatisfactionResultWithCallback::Unknown(self.satisfaction_solver.get_search_statistics())
+    }
+
+    pub fn get_solution_iterator<
+        'this,
+        'brancher,
+        'termination,
         B: Brancher,
         T: TerminationCondition,
     >(
@@ -435,39 +1556,822 @@ impl Solver {
             CSPSolverExecutionFlag::Timeout => {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                SatisfactionResultUnderAssumptions::Unknown
+                SatisfactionResultUnderAssumptions::Unknown
+            }
+        }
+    }
+
+    /// Solves the current model in the [`Solver`] under the assumption that the provided
+    /// [`Predicate`]s hold (or until it is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// If the model cannot be satisfied under the assumptions, then a
+    /// [`SatisfactionResultWithCore::UnsatisfiableCore`] is returned; it contains a subset of the
+    /// assumptions which cannot hold together. This core is minimised using semantic minimisation
+    /// (e.g. the assumption `[x >= 2]` is removed from the core `[x >= 2] /\ [x >= 5]`). In
+    /// contrast to [`Solver::satisfy_under_assumptions`], conflicting assumptions (e.g. `[x >= 5]`
+    /// and `[x <= 2]`) are reported as a core rather than resulting in a panic.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::results::SatisfactionResultWithCore;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// let z = solver.new_bounded_integer(0, 2);
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y]))
+    ///     .post()
+    ///     .expect("the constraint can be posted");
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let assumptions = [
+    ///     predicate![z >= 1],
+    ///     predicate![x == 1],
+    ///     predicate![y >= 1],
+    ///     predicate![y <= 1],
+    /// ];
+    /// let result = solver.solve_under_assumptions(&mut brancher, &mut Indefinite, &assumptions);
+    ///
+    /// let SatisfactionResultWithCore::UnsatisfiableCore(core) = result else {
+    ///     panic!("the assumptions cannot be satisfied");
+    /// };
+    /// // The assumption over `z` is not part of the core
+    /// assert_eq!(core.len(), 3);
+    /// assert!(!core.contains(&predicate![z >= 1]));
+    /// ```
+    pub fn solve_under_assumptions(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        assumptions: &[Predicate],
+    ) -> SatisfactionResultWithCore {
+        let literals = assumptions
+            .iter()
+            .map(|&predicate| self.get_literal(predicate))
+            .collect::<Vec<_>>();
+
+        match self
+            .satisfaction_solver
+            .solve_under_assumptions(&literals, termination, brancher)
+        {
+            CSPSolverExecutionFlag::Feasible => {
+                let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                brancher.on_solution(solution.as_reference());
+                SatisfactionResultWithCore::Satisfiable(solution)
+            }
+            CSPSolverExecutionFlag::Infeasible => {
+                if !self
+                    .satisfaction_solver
+                    .state
+                    .is_infeasible_under_assumptions()
+                {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    return SatisfactionResultWithCore::Unsatisfiable;
+                }
+
+                // The state is reset when the core is extracted
+                let core = match self
+                    .satisfaction_solver
+                    .extract_minimised_clausal_core(brancher)
+                {
+                    CoreExtractionResult::Core(core) => core,
+                    // Both the literal and its negation were assumed
+                    CoreExtractionResult::ConflictingAssumption(conflicting) => {
+                        vec![conflicting, !conflicting]
+                    }
+                };
+
+                SatisfactionResultWithCore::UnsatisfiableCore(
+                    assumptions
+                        .iter()
+                        .zip(literals)
+                        .filter(|(_, literal)| core.contains(literal))
+                        .map(|(&predicate, _)| predicate)
+                        .collect(),
+                )
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                SatisfactionResultWithCore::Unknown
+            }
+        }
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is minimised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
+    /// it exists.
+    pub fn minimise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        self.minimise_with_target(
+            brancher,
+            termination,
+            objective_variable,
+            OptimisationTarget::default(),
+        )
+    }
+
+    /// Minimises the provided `objective_variable` similar to [`Solver::minimise`], but stops as
+    /// soon as one of the criteria of the provided [`OptimisationTarget`] is met; in that case,
+    /// [`OptimisationResult::TargetReached`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::options::OptimisationTarget;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let objective = solver.new_bounded_integer(0, 20);
+    ///
+    /// solver.add_constraint(constraints::equals([x, y], 8)).post();
+    /// solver
+    ///     .add_constraint(constraints::equals(
+    ///         [x.scaled(1), y.scaled(1), objective.scaled(-1)],
+    ///         0,
+    ///     ))
+    ///     .post();
+    ///
+    /// let target = OptimisationTarget {
+    ///     objective_value: Some(12),
+    ///     ..Default::default()
+    /// };
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise_with_target(&mut brancher, &mut Indefinite, objective, target);
+    ///
+    /// // The objective is always 8, which satisfies the target
+    /// if let OptimisationResult::TargetReached(solution) = result {
+    ///     assert_eq!(solution.get_integer_value(objective), 8);
+    /// } else {
+    ///     panic!("Expected the target to be reached");
+    /// }
+    /// ```
+    pub fn minimise_with_target(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        target: OptimisationTarget,
+    ) -> OptimisationResult {
+        self.minimise_internal(brancher, termination, objective_variable, false, target)
+    }
+
+    /// Solves the model currently in the [`Solver`] to optimality where the provided
+    /// `objective_variable` is maximised (or is indicated to terminate by the provided
+    /// [`TerminationCondition`]).
+    ///
+    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
+    /// it exists.
+    pub fn maximise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+    ) -> OptimisationResult {
+        self.maximise_with_target(
+            brancher,
+            termination,
+            objective_variable,
+            OptimisationTarget::default(),
+        )
+    }
+
+    /// Maximises the provided `objective_variable` similar to [`Solver::maximise`], but stops as
+    /// soon as one of the criteria of the provided [`OptimisationTarget`] is met; in that case,
+    /// [`OptimisationResult::TargetReached`] is returned.
+    pub fn maximise_with_target(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        target: OptimisationTarget,
+    ) -> OptimisationResult {
+        self.minimise_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            true,
+            target,
+        )
+    }
+
+    /// Optimises the provided `objectives` lexicographically; i.e. the first objective is
+    /// minimised, then the second objective is minimised among the solutions for which the first
+    /// objective is optimal, and so on. An objective can be maximised by scaling it by `-1`.
+    ///
+    /// Every objective is optimised in a separate stage which starts from the incumbent of the
+    /// previous stage; the bound on the objective of the current stage is imposed as an assumption,
+    /// such that the solver can continue with the next stage once the bound is refuted. The
+    /// optimal value of every stage is then fixed for the remaining stages.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::LexicographicOptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// let z = solver.new_bounded_integer(0, 5);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::equals([x, y, z], 6))
+    ///     .post()
+    ///     .expect("the constraint is satisfiable");
+    ///
+    /// // Minimise `x` and then maximise `y`
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise_lexicographically(
+    ///     &mut brancher,
+    ///     &mut Indefinite,
+    ///     &[x.scaled(1), y.scaled(-1)],
+    /// );
+    ///
+    /// let LexicographicOptimisationResult::Optimal {
+    ///     solution,
+    ///     objective_values,
+    /// } = result
+    /// else {
+    ///     panic!("the optimum should be proven");
+    /// };
+    /// assert_eq!(objective_values, vec![0, -5]);
+    /// assert_eq!(solution.get_integer_value(z), 1);
+    /// ```
+    pub fn minimise_lexicographically<Var: IntegerVariable>(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objectives: &[Var],
+    ) -> LexicographicOptimisationResult {
+        let objective_values = |solution: &Solution| {
+            objectives
+                .iter()
+                .map(|objective| solution.get_integer_value(objective.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut incumbent: Solution = match self.satisfaction_solver.solve(termination, brancher) {
+            CSPSolverExecutionFlag::Feasible => {
+                self.satisfaction_solver.get_solution_reference().into()
+            }
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return LexicographicOptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return LexicographicOptimisationResult::Unknown;
+            }
+        };
+        self.satisfaction_solver.notify_new_incumbent();
+        self.process_solution(&incumbent, brancher);
+
+        for (stage, objective) in objectives.iter().enumerate() {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+            if stage > 0 {
+                // The previous objective is optimal, which the incumbent attains
+                let previous = &objectives[stage - 1];
+                let optimum = incumbent.get_integer_value(previous.clone());
+                self.satisfaction_solver
+                    .add_clause([self
+                        .satisfaction_solver
+                        .get_literal(previous.upper_bound_predicate(optimum))])
+                    .expect("the incumbent satisfies the bound");
+            }
+
+            loop {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+
+                let improving = self.satisfaction_solver.get_literal(
+                    objective
+                        .upper_bound_predicate(incumbent.get_integer_value(objective.clone()) - 1),
+                );
+                // The incumbent is optimal with respect to the objective of this stage if the
+                // improving bound is refuted
+                if self.satisfaction_solver.get_literal_value(improving) == Some(false) {
+                    break;
+                }
+                match self.satisfaction_solver.solve_under_assumptions(
+                    &[improving],
+                    termination,
+                    brancher,
+                ) {
+                    CSPSolverExecutionFlag::Feasible => {
+                        incumbent = self.satisfaction_solver.get_solution_reference().into();
+                        self.satisfaction_solver.notify_new_incumbent();
+                        self.process_solution(&incumbent, brancher);
+                    }
+                    CSPSolverExecutionFlag::Infeasible => break,
+                    CSPSolverExecutionFlag::Timeout => {
+                        // Reset the state whenever we return a result
+                        self.satisfaction_solver.restore_state_at_root(brancher);
+                        return LexicographicOptimisationResult::Satisfiable {
+                            objective_values: objective_values(&incumbent),
+                            solution: incumbent,
+                        };
+                    }
+                }
             }
         }
+
+        // Reset the state whenever we return a result
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        LexicographicOptimisationResult::Optimal {
+            objective_values: objective_values(&incumbent),
+            solution: incumbent,
+        }
     }
 
-    /// Solves the model currently in the [`Solver`] to optimality where the provided
-    /// `objective_variable` is minimised (or is indicated to terminate by the provided
-    /// [`TerminationCondition`]).
+    /// Minimises the provided `objective_variable` using large neighbourhood search (LNS) as an
+    /// alternative to [`Solver::minimise`]; LNS trades the proof of optimality for finding good
+    /// solutions quickly on large instances.
     ///
-    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
-    /// it exists.
-    pub fn minimise(
+    /// After an initial solution has been found, the solver repeatedly searches for a better
+    /// solution in a neighbourhood of the incumbent solution: the `neighbourhood` selects which of
+    /// the provided `variables` are fixed to their value in the incumbent, and the remaining
+    /// variables are searched with a conflict budget (see [`LnsOptions::conflict_budget`]). Every
+    /// neighbourhood is searched from the root, and the [`Brancher`] is notified of this as a
+    /// restart (see [`Brancher::on_restart`]).
+    ///
+    /// The search continues until the [`TerminationCondition`] triggers, in which case the best
+    /// solution is returned as [`OptimisationResult::Satisfiable`]; if a neighbourhood in which no
+    /// variables are fixed is exhausted, then the incumbent is returned as
+    /// [`OptimisationResult::Optimal`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::options::LnsOptions;
+    /// # use pumpkin_solver::options::RandomNeighbourhood;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let variables = solver.new_bounded_integers(4, 0, 5);
+    /// let objective = solver.new_bounded_integer(0, 20);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(variables.clone()))
+    ///     .post();
+    /// solver
+    ///     .add_constraint(constraints::equals(
+    ///         variables
+    ///             .iter()
+    ///             .map(|variable| variable.scaled(1))
+    ///             .chain([objective.scaled(-1)])
+    ///             .collect::<Vec<_>>(),
+    ///         0,
+    ///     ))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result = solver.minimise_with_lns(
+    ///     &mut brancher,
+    ///     &mut Indefinite,
+    ///     objective,
+    ///     &variables,
+    ///     &mut RandomNeighbourhood::default(),
+    ///     LnsOptions::default(),
+    /// );
+    ///
+    /// // The smallest sum of four distinct values is 0 + 1 + 2 + 3
+    /// let OptimisationResult::Optimal(solution) = result else {
+    ///     panic!("the optimum should be proven");
+    /// };
+    /// assert_eq!(solution.get_integer_value(objective), 6);
+    /// ```
+    pub fn minimise_with_lns(
         &mut self,
         brancher: &mut impl Brancher,
         termination: &mut impl TerminationCondition,
         objective_variable: impl IntegerVariable,
+        variables: &[DomainId],
+        neighbourhood: &mut impl NeighbourhoodSelector,
+        options: LnsOptions,
     ) -> OptimisationResult {
-        self.minimise_internal(brancher, termination, objective_variable, false)
+        self.lns_internal(
+            brancher,
+            termination,
+            objective_variable,
+            false,
+            variables,
+            neighbourhood,
+            options,
+        )
     }
 
-    /// Solves the model currently in the [`Solver`] to optimality where the provided
-    /// `objective_variable` is maximised (or is indicated to terminate by the provided
-    /// [`TerminationCondition`]).
-    ///
-    /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
-    /// it exists.
-    pub fn maximise(
+    /// Maximises the provided `objective_variable` using large neighbourhood search; see
+    /// [`Solver::minimise_with_lns`].
+    pub fn maximise_with_lns(
         &mut self,
         brancher: &mut impl Brancher,
         termination: &mut impl TerminationCondition,
         objective_variable: impl IntegerVariable,
+        variables: &[DomainId],
+        neighbourhood: &mut impl NeighbourhoodSelector,
+        options: LnsOptions,
+    ) -> OptimisationResult {
+        self.lns_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            true,
+            variables,
+            neighbourhood,
+            options,
+        )
+    }
+
+    /// Minimises the provided `objective_variable` by running the provided `phases` one after
+    /// another; e.g. a guided search with a short time budget, followed by complete search, and
+    /// finally large neighbourhood search.
+    ///
+    /// Every phase starts from the best solution of the previous phases, as only improving
+    /// solutions are searched for. The solve stops as soon as a phase proves optimality (or
+    /// unsatisfiability), or when the provided [`TerminationCondition`] triggers. If all phases
+    /// run out of their budget, then the best solution found by any of the phases is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
+    /// # use pumpkin_solver::options::LnsOptions;
+    /// # use pumpkin_solver::options::PhaseStrategy;
+    /// # use pumpkin_solver::options::RandomNeighbourhood;
+    /// # use pumpkin_solver::options::SolverPhase;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let variables = solver.new_bounded_integers(4, 0, 5);
+    /// let objective = solver.new_bounded_integer(0, 20);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(variables.clone()))
+    ///     .post();
+    /// solver
+    ///     .add_constraint(constraints::equals(
+    ///         variables
+    ///             .iter()
+    ///             .map(|variable| variable.scaled(1))
+    ///             .chain([objective.scaled(-1)])
+    ///             .collect::<Vec<_>>(),
+    ///         0,
+    ///     ))
+    ///     .post();
+    ///
+    /// let mut phases = vec![
+    ///     SolverPhase::new(
+    ///         "lns",
+    ///         DynamicBrancher::new(vec![Box::new(
+    ///             solver.default_brancher_over_all_propositional_variables(),
+    ///         )]),
+    ///         PhaseStrategy::Lns {
+    ///             variables: variables.clone(),
+    ///             neighbourhood: Box::new(RandomNeighbourhood::default()),
+    ///             options: LnsOptions::default(),
+    ///         },
+    ///     )
+    ///     .with_time_budget(Duration::from_millis(100)),
+    ///     SolverPhase::new(
+    ///         "complete",
+    ///         DynamicBrancher::new(vec![Box::new(
+    ///             solver.default_brancher_over_all_propositional_variables(),
+    ///         )]),
+    ///         PhaseStrategy::CompleteSearch,
+    ///     ),
+    /// ];
+    /// let result = solver.minimise_in_phases(&mut Indefinite, objective, &mut phases);
+    ///
+    /// // The smallest sum of four distinct values is 0 + 1 + 2 + 3
+    /// let OptimisationResult::Optimal(solution) = result else {
+    ///     panic!("the optimum should be proven");
+    /// };
+    /// assert_eq!(solution.get_integer_value(objective), 6);
+    /// ```
+    pub fn minimise_in_phases(
+        &mut self,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        phases: &mut [SolverPhase],
     ) -> OptimisationResult {
-        self.minimise_internal(brancher, termination, objective_variable.scaled(-1), true)
+        let mut best_solution = None;
+
+        for phase in phases.iter_mut() {
+            if termination.should_stop() {
+                break;
+            }
+            info!("Starting phase '{}'", phase.name);
+
+            let mut phase_termination = Combinator::new(
+                &mut *termination,
+                phase.time_budget.map(TimeBudget::starting_now),
+            );
+            let result = match &mut phase.strategy {
+                PhaseStrategy::CompleteSearch => self.minimise_internal(
+                    &mut phase.brancher,
+                    &mut phase_termination,
+                    objective_variable.clone(),
+                    false,
+                    OptimisationTarget::default(),
+                ),
+                PhaseStrategy::Lns {
+                    variables,
+                    neighbourhood,
+                    options,
+                } => self.lns_internal(
+                    &mut phase.brancher,
+                    &mut phase_termination,
+                    objective_variable.clone(),
+                    false,
+                    variables,
+                    neighbourhood.as_mut(),
+                    *options,
+                ),
+            };
+
+            match result {
+                OptimisationResult::Satisfiable(solution) => best_solution = Some(solution),
+                OptimisationResult::Unknown => {}
+                // The previous phases only leave non-improving solutions to be refuted
+                OptimisationResult::Unsatisfiable => {
+                    return match best_solution {
+                        Some(solution) => OptimisationResult::Optimal(solution),
+                        None => OptimisationResult::Unsatisfiable,
+                    }
+                }
+                OptimisationResult::Optimal(_) | OptimisationResult::TargetReached(_) => {
+                    return result
+                }
+            }
+        }
+
+        best_solution.map_or(OptimisationResult::Unknown, OptimisationResult::Satisfiable)
+    }
+
+    /// Tightens the lower-bound of `objective_variable` at the root by repeatedly testing whether
+    /// a solution exists with `objective_variable <= b`, where `b` is the current lower-bound;
+    /// every such test which is refuted within the budget leads to the lower-bound being
+    /// increased. Returns the resulting lower-bound, i.e. every smaller objective value has been
+    /// refuted.
+    ///
+    /// At most `conflict_budget` conflicts are spent in total; the procedure also stops as soon as
+    /// a test is not refuted (i.e. a solution is found or the budget is exhausted) or the
+    /// provided [`TerminationCondition`] triggers. No tests are performed while variable bounds
+    /// are set (see [`Solver::set_variable_bounds`]) or a scope is open (see [`Solver::push`]),
+    /// since a refutation which relies on them does not hold for the model itself.
+    ///
+    /// This is performed automatically by [`Solver::minimise`] and [`Solver::maximise`] before
+    /// the full search using the budget in [`SolverOptions`]. To bound a maximisation objective,
+    /// provide the objective variable scaled by `-1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// let z = solver.new_bounded_integer(0, 2);
+    /// let objective = solver.new_bounded_integer(0, 10);
+    ///
+    /// // The objective is the largest value, and all values are distinct
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y, z]))
+    ///     .post();
+    /// solver
+    ///     .add_constraint(constraints::maximum(vec![x, y, z], objective))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let lower_bound =
+    ///     solver.destructive_lower_bound(&mut brancher, &mut Indefinite, objective, 100);
+    ///
+    /// assert_eq!(lower_bound, 2);
+    /// assert_eq!(solver.lower_bound(&objective), 2);
+    /// ```
+    pub fn destructive_lower_bound(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        conflict_budget: u64,
+    ) -> i32 {
+        let mut remaining_budget = conflict_budget;
+
+        while remaining_budget > 0 && !self.satisfaction_solver.state.is_inconsistent() {
+            let bound = self.lower_bound(&objective_variable);
+            let probe = self.get_literal(predicate![objective_variable <= bound]);
+
+            if !self.refute_within_budget(brancher, termination, probe, &mut remaining_budget) {
+                break;
+            }
+        }
+
+        self.lower_bound(&objective_variable)
+    }
+
+    /// Tightens the bounds of the provided `variables` at the root by shaving; i.e. for every
+    /// variable, it is repeatedly tested whether a solution exists with `x <= lb(x)` (resp.
+    /// `x >= ub(x)`), and every such test which is refuted within the budget leads to the
+    /// lower-bound (resp. upper-bound) of `x` being tightened. Returns the number of bounds which
+    /// were tightened.
+    ///
+    /// At most `conflict_budget` conflicts are spent in total over all variables; shaving a bound
+    /// stops as soon as a test is not refuted. This can be decisive on hard (e.g. scheduling)
+    /// instances where the bounds of a few key variables determine the difficulty.
+    ///
+    /// Similar to [`Solver::destructive_lower_bound`], no bounds are shaved while variable bounds
+    /// are set (see [`Solver::set_variable_bounds`]) or a scope is open (see [`Solver::push`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 2);
+    /// let y = solver.new_bounded_integer(0, 2);
+    /// let z = solver.new_bounded_integer(0, 2);
+    /// let maximum = solver.new_bounded_integer(0, 10);
+    ///
+    /// solver
+    ///     .add_constraint(constraints::all_different(vec![x, y, z]))
+    ///     .post();
+    /// solver
+    ///     .add_constraint(constraints::maximum(vec![x, y, z], maximum))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let _ = solver.shave(&mut brancher, &mut Indefinite, &[maximum], 100);
+    ///
+    /// assert_eq!(solver.lower_bound(&maximum), 2);
+    /// assert_eq!(solver.upper_bound(&maximum), 2);
+    /// ```
+    pub fn shave<Var: IntegerVariable>(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        variables: &[Var],
+        conflict_budget: u64,
+    ) -> usize {
+        let mut remaining_budget = conflict_budget;
+        let mut num_tightened_bounds = 0;
+
+        for variable in variables {
+            for shave_lower_bound in [true, false] {
+                while remaining_budget > 0 && !self.satisfaction_solver.state.is_inconsistent() {
+                    let lower_bound = self.lower_bound(variable);
+                    let upper_bound = self.upper_bound(variable);
+                    if lower_bound == upper_bound {
+                        break;
+                    }
+
+                    let probe = if shave_lower_bound {
+                        self.get_literal(predicate![variable <= lower_bound])
+                    } else {
+                        self.get_literal(predicate![variable >= upper_bound])
+                    };
+                    if !self.refute_within_budget(
+                        brancher,
+                        termination,
+                        probe,
+                        &mut remaining_budget,
+                    ) {
+                        break;
+                    }
+
+                    num_tightened_bounds += 1;
+                }
+            }
+        }
+
+        num_tightened_bounds
+    }
+
+    /// Tests whether a solution exists in which `probe` holds, spending at most
+    /// `remaining_budget` conflicts; the spent conflicts are subtracted from the budget.
+    ///
+    /// If the test is refuted, then the negation of `probe` is added at the root and `true` is
+    /// returned. If the solver adds assumptions of its own (i.e. the variable bounds or the scope
+    /// literals), then no test is performed and `false` is returned, since the refutation could
+    /// rely on these assumptions.
+    fn refute_within_budget(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        probe: Literal,
+        remaining_budget: &mut u64,
+    ) -> bool {
+        if self.satisfaction_solver.has_internal_assumptions() {
+            return false;
+        }
+
+        let num_conflicts_before = self
+            .satisfaction_solver
+            .get_search_statistics()
+            .num_conflicts;
+        let result = self
+            .satisfaction_solver
+            .solve_under_assumptions_with_conflict_budget(
+                &[probe],
+                termination,
+                brancher,
+                *remaining_budget,
+            );
+        let num_conflicts = self
+            .satisfaction_solver
+            .get_search_statistics()
+            .num_conflicts
+            - num_conflicts_before;
+        // Every test consumes part of the budget, even when it is refuted without conflicts
+        *remaining_budget = remaining_budget.saturating_sub(num_conflicts.max(1));
+
+        let is_refuted = result == CSPSolverExecutionFlag::Infeasible
+            && self
+                .satisfaction_solver
+                .state
+                .is_infeasible_under_assumptions();
+        if result == CSPSolverExecutionFlag::Feasible {
+            let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
+            self.satisfaction_solver.restore_state_at_root(brancher);
+            brancher.on_solution(solution.as_reference());
+        } else {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+        }
+
+        is_refuted && self.satisfaction_solver.add_clause([!probe]).is_ok()
+    }
+
+    /// Searches for a solution in which the hinted variables (see [`Solver::add_hint`]) take their
+    /// suggested values, giving up after the conflict budget of the probe is exhausted. Returns
+    /// whether such a solution was found, in which case the solver still contains it; otherwise,
+    /// the solver is at the root afterwards.
+    fn probe_hints(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+    ) -> bool {
+        let conflict_budget = self.satisfaction_solver.get_hint_probe_conflict_budget();
+        if conflict_budget == 0 || self.satisfaction_solver.get_hints().is_empty() {
+            return false;
+        }
+
+        let assumptions = self
+            .satisfaction_solver
+            .get_hints()
+            .iter()
+            .map(|&(variable, value)| {
+                self.satisfaction_solver
+                    .get_literal(predicate![variable == value])
+            })
+            .collect::<Vec<_>>();
+        let flag = self
+            .satisfaction_solver
+            .solve_under_assumptions_with_conflict_budget(
+                &assumptions,
+                termination,
+                brancher,
+                conflict_budget,
+            );
+        if matches!(flag, CSPSolverExecutionFlag::Feasible) {
+            return true;
+        }
+
+        // The hints cannot be extended to a solution (within the budget)
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        false
     }
 
     /// The internal method which optimizes the objective function, this function takes an extra
@@ -483,19 +2387,36 @@ impl Solver {
         termination: &mut impl TerminationCondition,
         objective_variable: impl IntegerVariable,
         is_maximising: bool,
+        target: OptimisationTarget,
     ) -> OptimisationResult {
         // If we are maximising then when we simply scale the variable by -1, however, this will
         // lead to the printed objective value in the statistics to be multiplied by -1; this
         // objective_multiplier ensures that the objective is correctly logged.
         let objective_multiplier = if is_maximising { -1 } else { 1 };
 
-        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
+        let conflict_budget = self
+            .satisfaction_solver
+            .get_destructive_bounding_conflict_budget();
+        if conflict_budget > 0 {
+            let _ = self.destructive_lower_bound(
+                brancher,
+                termination,
+                objective_variable.clone(),
+                conflict_budget,
+            );
+        }
+
+        let initial_solve = if self.probe_hints(brancher, termination) {
+            CSPSolverExecutionFlag::Feasible
+        } else {
+            self.satisfaction_solver.solve(termination, brancher)
+        };
         match initial_solve {
             CSPSolverExecutionFlag::Feasible => {}
             CSPSolverExecutionFlag::Infeasible => {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self.satisfaction_solver.conclude_proof_unsat();
+                self.conclude_proof_unsat_without_internal_assumptions();
                 return OptimisationResult::Unsatisfiable;
             }
             CSPSolverExecutionFlag::Timeout => {
@@ -518,6 +2439,26 @@ impl Solver {
         loop {
             self.satisfaction_solver.restore_state_at_root(brancher);
 
+            self.polish_incumbent(
+                brancher,
+                termination,
+                &objective_variable,
+                objective_multiplier,
+                &mut best_objective_value,
+                &mut best_solution,
+            );
+
+            // The lower-bound of the objective at the root is a bound on the optimal value
+            if target.is_reached(
+                best_objective_value * objective_multiplier as i64,
+                self.satisfaction_solver
+                    .get_lower_bound(&objective_variable)
+                    .into(),
+                is_maximising,
+            ) {
+                return OptimisationResult::TargetReached(best_solution);
+            }
+
             let objective_bound_predicate = if is_maximising {
                 predicate![objective_variable <= best_objective_value as i32]
             } else {
@@ -537,9 +2478,7 @@ impl Solver {
             {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self
-                    .satisfaction_solver
-                    .conclude_proof_optimal(objective_bound_literal);
+                self.conclude_proof_optimal_without_internal_assumptions(objective_bound_literal);
 
                 return OptimisationResult::Optimal(best_solution);
             }
@@ -563,16 +2502,153 @@ impl Solver {
                     {
                         // Reset the state whenever we return a result
                         self.satisfaction_solver.restore_state_at_root(brancher);
-                        let _ = self
-                            .satisfaction_solver
-                            .conclude_proof_optimal(objective_bound_literal);
+                        self.conclude_proof_optimal_without_internal_assumptions(
+                            objective_bound_literal,
+                        );
+                        return OptimisationResult::Optimal(best_solution);
+                    }
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    return OptimisationResult::Satisfiable(best_solution);
+                }
+            }
+        }
+    }
+
+    /// The internal method which performs large neighbourhood search; similar to
+    /// [`Solver::minimise_internal`], `is_maximising` determines whether the logged objective
+    /// value should be scaled by `-1`.
+    #[allow(clippy::too_many_arguments)]
+    fn lns_internal(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        is_maximising: bool,
+        variables: &[DomainId],
+        neighbourhood: &mut (impl NeighbourhoodSelector + ?Sized),
+        options: LnsOptions,
+    ) -> OptimisationResult {
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
+        match initial_solve {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                self.conclude_proof_unsat_without_internal_assumptions();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        self.update_best_solution_and_process(
+            objective_multiplier,
+            &objective_variable,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+        );
+
+        loop {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+
+            let objective_bound_predicate = if is_maximising {
+                predicate![objective_variable <= best_objective_value as i32]
+            } else {
+                predicate![objective_variable >= best_objective_value as i32]
+            };
+            let objective_bound_literal = self
+                .satisfaction_solver
+                .get_literal(objective_bound_predicate);
+
+            // Only improving solutions are of interest, in every neighbourhood
+            if self
+                .strengthen(
+                    &objective_variable,
+                    best_objective_value * objective_multiplier as i64,
+                )
+                .is_err()
+            {
+                self.conclude_proof_optimal_without_internal_assumptions(objective_bound_literal);
+                return OptimisationResult::Optimal(best_solution);
+            }
+
+            if termination.should_stop() {
+                return OptimisationResult::Satisfiable(best_solution);
+            }
+
+            let fixed_variables = neighbourhood.select_variables_to_fix(
+                &best_solution,
+                variables,
+                self.satisfaction_solver.get_random_generator(),
+            );
+            let assumptions = fixed_variables
+                .iter()
+                .map(|&variable| {
+                    let value = best_solution.get_integer_value(variable);
+                    self.get_literal(predicate![variable == value])
+                })
+                .collect::<Vec<_>>();
+
+            // Every neighbourhood is searched from the root, which the brancher perceives as a
+            // restart
+            brancher.on_restart();
+            let result = if assumptions.is_empty() {
+                self.satisfaction_solver.solve(termination, brancher)
+            } else {
+                self.satisfaction_solver
+                    .solve_under_assumptions_with_conflict_budget(
+                        &assumptions,
+                        termination,
+                        brancher,
+                        options.conflict_budget,
+                    )
+            };
+
+            match result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.debug_bound_change(
+                        &objective_variable,
+                        best_objective_value * objective_multiplier as i64,
+                    );
+                    self.update_best_solution_and_process(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                    );
+                    neighbourhood.notify_outcome(NeighbourhoodOutcome::Improved);
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    if assumptions.is_empty()
+                        || !self
+                            .satisfaction_solver
+                            .state
+                            .is_infeasible_under_assumptions()
+                    {
+                        self.satisfaction_solver.restore_state_at_root(brancher);
+                        self.conclude_proof_optimal_without_internal_assumptions(
+                            objective_bound_literal,
+                        );
                         return OptimisationResult::Optimal(best_solution);
                     }
+                    neighbourhood.notify_outcome(NeighbourhoodOutcome::Exhausted);
                 }
                 CSPSolverExecutionFlag::Timeout => {
-                    // Reset the state whenever we return a result
-                    self.satisfaction_solver.restore_state_at_root(brancher);
-                    return OptimisationResult::Satisfiable(best_solution);
+                    if assumptions.is_empty() || termination.should_stop() {
+                        self.satisfaction_solver.restore_state_at_root(brancher);
+                        return OptimisationResult::Satisfiable(best_solution);
+                    }
+                    neighbourhood.notify_outcome(NeighbourhoodOutcome::BudgetExceeded);
                 }
             }
         }
@@ -586,7 +2662,7 @@ impl Solver {
     /// - Logging the statistics using [`Solver::log_statistics_with_objective`].
     /// - Calling the solution callback stored in [`Solver::solution_callback`].
     fn update_best_solution_and_process(
-        &self,
+        &mut self,
         objective_multiplier: i32,
         objective_variable: &impl IntegerVariable,
         best_objective_value: &mut i64,
@@ -599,6 +2675,7 @@ impl Solver {
                 .get_assigned_integer_value(objective_variable)
                 .expect("expected variable to be assigned")) as i64;
         *best_solution = self.satisfaction_solver.get_solution_reference().into();
+        self.satisfaction_solver.notify_new_incumbent();
 
         self.internal_process_solution(best_solution, brancher, Some(*best_objective_value))
     }
@@ -622,6 +2699,94 @@ impl Solver {
         ));
     }
 
+    /// Attempts to improve the incumbent by searching for better solutions in which most variables
+    /// keep their value (see [`SolutionPolishingOptions`]); every improving solution is processed
+    /// as in [`Solver::update_best_solution_and_process`]. The solver is at the root afterwards.
+    ///
+    /// The objective bound is only assumed rather than added to the solver, such that the caller
+    /// can strengthen the bound as usual; if the problem is proven infeasible regardless of the
+    /// assumptions, then the solver remains infeasible and the caller concludes optimality.
+    fn polish_incumbent(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: &impl IntegerVariable,
+        objective_multiplier: i32,
+        best_objective_value: &mut i64,
+        best_solution: &mut Solution,
+    ) {
+        let options = self.satisfaction_solver.get_solution_polishing_options();
+        if options.conflict_budget == 0 {
+            return;
+        }
+
+        loop {
+            self.satisfaction_solver.restore_state_at_root(brancher);
+            if self.satisfaction_solver.state.is_inconsistent() || termination.should_stop() {
+                return;
+            }
+
+            let mut assumptions = self
+                .satisfaction_solver
+                .get_polishing_assumptions(best_solution, options.window_size);
+            if assumptions.is_empty() {
+                // Polishing would amount to the global search
+                return;
+            }
+            let objective_bound = *best_objective_value * objective_multiplier as i64 - 1;
+            assumptions.insert(
+                0,
+                self.get_literal(objective_variable.upper_bound_predicate(objective_bound as i32)),
+            );
+
+            // The polishing search starts from the root, which the brancher perceives as a restart
+            brancher.on_restart();
+            let result = self
+                .satisfaction_solver
+                .solve_under_assumptions_with_conflict_budget(
+                    &assumptions,
+                    termination,
+                    brancher,
+                    options.conflict_budget,
+                );
+            if result != CSPSolverExecutionFlag::Feasible {
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return;
+            }
+
+            self.debug_bound_change(
+                objective_variable,
+                *best_objective_value * objective_multiplier as i64,
+            );
+            self.update_best_solution_and_process(
+                objective_multiplier,
+                objective_variable,
+                best_objective_value,
+                best_solution,
+                brancher,
+            );
+        }
+    }
+
+    /// Concludes the proof with the unsatisfiable claim, unless the infeasibility was derived
+    /// under the variable bounds or the open scopes (see
+    /// [`ConstraintSatisfactionSolver::num_internal_assumptions`]), in which case the claim does
+    /// not hold for the model itself.
+    fn conclude_proof_unsat_without_internal_assumptions(&mut self) {
+        if self.satisfaction_solver.num_internal_assumptions() == 0 {
+            let _ = self.satisfaction_solver.conclude_proof_unsat();
+        }
+    }
+
+    /// Concludes the proof with the optimality claim, unless the optimality was derived under
+    /// the variable bounds or the open scopes; see
+    /// [`Solver::conclude_proof_unsat_without_internal_assumptions`].
+    fn conclude_proof_optimal_without_internal_assumptions(&mut self, bound: Literal) {
+        if self.satisfaction_solver.num_internal_assumptions() == 0 {
+            let _ = self.satisfaction_solver.conclude_proof_optimal(bound);
+        }
+    }
+
     /// Given the current objective value `best_objective_value`, it adds a constraint specifying
     /// that the objective value should be at most `best_objective_value - 1`. Note that it is
     /// assumed that we are always minimising the variable.
@@ -690,13 +2855,289 @@ impl Solver {
     /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
     /// returned. Subsequent calls to this method will always return an error, and no
     /// modification of the solver will take place.
+    ///
+    /// A clause which is not added as part of a constraint is checked by [`Solver::check`] as a
+    /// constraint on its own.
     pub fn add_clause(
         &mut self,
         clause: impl IntoIterator<Item = Literal>,
     ) -> Result<(), ConstraintOperationError> {
+        if !self.handles.is_posting_constraint() {
+            return self
+                .post_constraint(|solver| solver.add_clause(clause))
+                .map(|_| ());
+        }
+
+        let clause = clause.into_iter().collect::<Vec<_>>();
+        self.handles.add_clause(&clause);
         self.satisfaction_solver.add_clause(clause)
     }
 
+    /// Tightens the lower-bound of `makespan` at the root based on the energy of the tasks of a
+    /// cumulative resource (see [`constraints::cumulative`]); returns the new lower-bound.
+    ///
+    /// For every earliest start time `t`, the tasks which cannot start before `t` require a
+    /// certain amount of energy (the sum of `duration * resource_requirement`), which is available
+    /// at a rate of at most `resource_capacity` per time unit; this leads to a lower-bound on the
+    /// latest end time of the tasks. This improves the initial bound on the objective when
+    /// minimising the makespan.
+    ///
+    /// Note that this bound is only valid if `makespan` is constrained to be at least the end time
+    /// of every task. The bound is not added as a constraint; instead, it is learned from the
+    /// current lower-bounds of the start times, and logged as such to the proof (if any).
+    ///
+    /// The length of `start_times`, `durations` and `resource_requirements` should be the same;
+    /// otherwise, [`ConstraintOperationError::InvalidArgument`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let start_times = solver.new_bounded_integers(3, 0, 10);
+    /// let makespan = solver.new_bounded_integer(0, 20);
+    ///
+    /// // Note that the end time of every task should be constrained to be at most the makespan
+    /// let lower_bound = solver
+    ///     .tighten_makespan_lower_bound(&start_times, &[3, 4, 5], &[1, 1, 1], 2, makespan)
+    ///     .expect("the bound does not exceed the upper-bound of the makespan");
+    ///
+    /// assert_eq!(lower_bound, 6);
+    /// assert_eq!(solver.lower_bound(&makespan), 6);
+    /// ```
+    pub fn tighten_makespan_lower_bound<Var: IntegerVariable>(
+        &mut self,
+        start_times: &[Var],
+        durations: &[i32],
+        resource_requirements: &[i32],
+        resource_capacity: i32,
+        makespan: impl IntegerVariable,
+    ) -> Result<i32, ConstraintOperationError> {
+        check_lengths(
+            ("start_times", start_times.len()),
+            &[
+                ("durations", durations.len()),
+                ("resource_requirements", resource_requirements.len()),
+            ],
+        )?;
+
+        let tasks = start_times
+            .iter()
+            .zip(durations)
+            .zip(resource_requirements)
+            .map(|((start_time, &duration), &resource_requirement)| {
+                (self.lower_bound(start_time), duration, resource_requirement)
+            });
+        let lower_bound = energetic_makespan_lower_bound(tasks, resource_capacity)
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+        if lower_bound > self.lower_bound(&makespan) {
+            // The bound is learned from the current lower-bounds of the start times (and it is
+            // logged as such to the proof), rather than added as a constraint of the model
+            let premises = start_times
+                .iter()
+                .map(|start_time| {
+                    let earliest_start_time = self.lower_bound(start_time);
+                    self.get_literal(predicate!(start_time >= earliest_start_time))
+                })
+                .collect();
+            let literal = self.get_literal(predicate!(makespan >= lower_bound));
+            self.satisfaction_solver
+                .learn_root_literal(premises, literal)?;
+        }
+
+        Ok(self.lower_bound(&makespan))
+    }
+
+    /// Restricts `variable` to the interval `[lower_bound, upper_bound]` in all subsequent calls to
+    /// the solving methods (e.g. [`Solver::satisfy`] or [`Solver::minimise`]), replacing the
+    /// bounds which were previously set for it using this method.
+    ///
+    /// In contrast to posting the bounds as a constraint, these bounds can be tightened and relaxed
+    /// between solves without rebuilding the model (e.g. when rescheduling with updated release
+    /// dates). They are imposed before the search starts and are retracted when the solver
+    /// returns; the propagators are only notified of the changes to the domain of `variable`, and
+    /// the clauses learned in earlier solves are kept. Note that this means that the bounds are not
+    /// reflected by [`Solver::lower_bound`] and [`Solver::upper_bound`] outside of solving.
+    ///
+    /// If the model is infeasible with these bounds, [`SatisfactionResult::Unsatisfiable`] is
+    /// returned, after which the bounds can be relaxed again. When solving under assumptions, the
+    /// extracted core is relative to these bounds: it only contains the provided assumptions, and
+    /// it is empty if the bounds alone are infeasible.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let start_a = solver.new_bounded_integer(0, 10);
+    /// let start_b = solver.new_bounded_integer(0, 10);
+    /// // Task `a` (with duration 4) is scheduled before task `b`
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals(
+    ///         vec![start_a.scaled(1), start_b.scaled(-1)],
+    ///         -4,
+    ///     ))
+    ///     .post();
+    ///
+    /// // The release date of `a` is moved to 8, which does not leave enough room for `b`
+    /// solver.set_variable_bounds(start_a, 8, 10);
+    /// assert!(matches!(
+    ///     solver.solve(None),
+    ///     SatisfactionResult::Unsatisfiable(_)
+    /// ));
+    ///
+    /// // The release date is moved to 5 instead
+    /// solver.set_variable_bounds(start_a, 5, 10);
+    /// let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert!(solution.get_integer_value(start_a) >= 5);
+    /// assert!(solution.get_integer_value(start_b) >= 9);
+    ///
+    /// // Without the release date, `a` can start at 0 again
+    /// solver.reset_variable_bounds(start_a);
+    /// solver.set_variable_bounds(start_b, 0, 4);
+    /// let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert_eq!(solution.get_integer_value(start_a), 0);
+    /// ```
+    pub fn set_variable_bounds(&mut self, variable: DomainId, lower_bound: i32, upper_bound: i32) {
+        self.satisfaction_solver
+            .set_variable_bounds(variable, lower_bound, upper_bound)
+    }
+
+    /// Removes the bounds of `variable` which were set using [`Solver::set_variable_bounds`]; in
+    /// subsequent calls to the solving methods, only the constraints of the model restrict its
+    /// domain.
+    pub fn reset_variable_bounds(&mut self, variable: DomainId) {
+        self.satisfaction_solver.reset_variable_bounds(variable)
+    }
+
+    /// Opens a new constraint scope; the constraints and clauses which are added until the
+    /// matching call to [`Solver::pop`] are removed from the model again once the scope is
+    /// closed. Scopes can be nested, in which case [`Solver::pop`] closes the innermost one.
+    ///
+    /// A constraint which is infeasible within a scope does not make the [`Solver`] infeasible;
+    /// instead, posting it returns [`ConstraintOperationError::InfeasibleScope`] (if the conflict
+    /// is detected at the root) and solving reports that the problem is unsatisfiable until the
+    /// scope is closed. The clauses which are learned while the scope is open remain valid after
+    /// it is closed, as those which depend on the constraints of the scope are removed along with
+    /// them.
+    ///
+    /// Variables and literals which are created within a scope (including those which are
+    /// introduced by the solver to condition the constraints on the scope) are not removed when it
+    /// is closed; they are only no longer constrained by the constraints of the scope.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals(vec![x, y], 8))
+    ///     .post();
+    ///
+    /// // Both `x` and `y` cannot be at least 5
+    /// solver.push();
+    /// let _ = solver
+    ///     .add_constraint(constraints::binary_equals(x, y))
+    ///     .post();
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals(vec![x.scaled(-1)], -5))
+    ///     .post();
+    /// assert!(matches!(
+    ///     solver.solve(None),
+    ///     SatisfactionResult::Unsatisfiable(_)
+    /// ));
+    ///
+    /// // Once the scope is closed, `x` can take any value again
+    /// assert!(solver.pop());
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals(vec![x.scaled(-1)], -7))
+    ///     .post();
+    /// let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert!(solution.get_integer_value(x) >= 7);
+    /// assert!(solution.get_integer_value(y) <= 1);
+    /// ```
+    pub fn push(&mut self) {
+        self.satisfaction_solver.push_scope();
+        self.handles.push_scope();
+    }
+
+    /// Closes the innermost scope which was opened using [`Solver::push`], removing the
+    /// constraints and clauses which were added within it, as well as the learned clauses which
+    /// depend on them. The handles of the removed constraints are no longer valid (e.g.
+    /// [`Solver::describe_constraint`] returns [`None`] for them). Returns `false` if no scope was
+    /// open.
+    pub fn pop(&mut self) -> bool {
+        let was_open = self.satisfaction_solver.pop_scope();
+        if was_open {
+            self.handles.pop_scope();
+        }
+        was_open
+    }
+
+    /// Suggests `value` for `variable`; a later hint for the same variable replaces this one.
+    ///
+    /// The hints are soft, i.e. they are never enforced: the branchers which are created by the
+    /// [`Solver`] (e.g. [`Solver::default_brancher_over_all_propositional_variables`]) prefer the
+    /// suggested values until the first solution is found. If enabled using
+    /// [`SolverOptions::hint_probe_conflict_budget`], the optimisation procedures (e.g.
+    /// [`Solver::minimise`]) first spend a small number of conflicts on finding a solution in which
+    /// all hinted variables take their suggested value, which then serves as the initial bound.
+    /// Note that the hints only affect the branchers which are created after they are added.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let _ = solver
+    ///     .add_constraint(constraints::less_than_or_equals(vec![x, y], 12))
+    ///     .post();
+    ///
+    /// solver.add_hint(x, 7);
+    /// solver.add_hint(y, 4);
+    /// let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert_eq!(solution.get_integer_value(x), 7);
+    /// assert_eq!(solution.get_integer_value(y), 4);
+    ///
+    /// // The hints are only preferred, the optimum is still found
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let OptimisationResult::Optimal(solution) = solver.minimise(&mut brancher, &mut Indefinite, x)
+    /// else {
+    ///     panic!("expected the optimum");
+    /// };
+    /// assert_eq!(solution.get_integer_value(x), 0);
+    /// ```
+    pub fn add_hint(&mut self, variable: DomainId, value: i32) {
+        self.satisfaction_solver.add_hint(variable, value)
+    }
+
+    /// Returns the activation literal of the innermost scope, if any; see [`Solver::push`].
+    pub(crate) fn get_scope_literal(&self) -> Option<Literal> {
+        self.satisfaction_solver.get_scope_literal()
+    }
+
     /// Adds a propagator with a tag, which is used to identify inferences made by this propagator
     /// in the proof log.
     pub(crate) fn add_tagged_propagator(
@@ -715,9 +3156,9 @@ impl Solver {
     /// because no call to [`Self::solve()`] has been made, or because
     /// [`Self::restore_state_at_root()`] was called.
     ///
-    /// If the solver is already in a conflicting state, i.e. a previous call to this method
-    /// already returned `false`, calling this again will not alter the solver in any way, and
-    /// `false` will be returned again.
+    /// If the solver is already infeasible, i.e. a previous constraint was found to be infeasible
+    /// at the root, calling this again will not alter the solver in any way, and
+    /// [`ConstraintOperationError::InfeasibleState`] is returned.
     pub(crate) fn add_propagator(
         &mut self,
         propagator: impl Propagator + 'static,
@@ -732,10 +3173,97 @@ impl Solver {
     /// [`VariableSelector`] and [`SolutionGuidedValueSelector`] (with [`PhaseSaving`] as its
     /// back-up selector) as its [`ValueSelector`]; it searches over all
     /// [`PropositionalVariable`]s defined in the provided `solver`.
+    ///
+    /// Until the first solution is found, the values which were suggested using
+    /// [`Solver::add_hint`] are preferred.
     pub fn default_brancher_over_all_propositional_variables(&self) -> DefaultBrancher {
         self.satisfaction_solver
             .default_brancher_over_all_propositional_variables()
     }
+
+    /// Creates an [`IndependentVariableValueBrancher`] which uses [`Vsids`] as [`VariableSelector`]
+    /// and [`SolutionGuidedValueSelector`] (with [`InDomainMin`] as its back-up selector) as its
+    /// [`ValueSelector`]; it searches over all integer variables defined in the provided `solver`.
+    ///
+    /// The activity of an integer variable is bumped whenever one of its predicates appears in the
+    /// learned nogood (or is resolved away during conflict analysis) and the activities are decayed
+    /// after every conflict. In contrast to
+    /// [`Solver::default_brancher_over_all_propositional_variables`], the decisions are thus
+    /// made over the domains of the variables, which tends to work better for problems which do
+    /// not provide a search strategy themselves.
+    ///
+    /// Note that variables which are created after this method is called are only considered once
+    /// they appear in a conflict.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 3);
+    /// let y = solver.new_bounded_integer(0, 3);
+    /// let _ = solver
+    ///     .add_constraint(constraints::not_equals(vec![x, y], 0))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.vsids_brancher_over_all_integer_variables();
+    /// let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    /// let SatisfactionResult::Satisfiable(solution) = result else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert_ne!(solution.get_integer_value(x), solution.get_integer_value(y));
+    /// ```
+    pub fn vsids_brancher_over_all_integer_variables(&self) -> IntegerVsidsBrancher {
+        self.satisfaction_solver
+            .vsids_brancher_over_all_integer_variables()
+    }
+
+    /// Creates an instance of the [`ActivityBasedBrancher`] over all of the integer variables
+    /// which have been created so far; it combines activity-based search (see [`ActivityBased`])
+    /// with last-conflict reasoning (see [`LastConflict`]) and is configured using
+    /// [`SolverOptions::activity_based_search`].
+    ///
+    /// Note that variables which are created after this method is called are not considered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::options::ActivityBasedSearchOptions;
+    /// # use pumpkin_solver::options::SolverOptions;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// let mut solver = Solver::with_options(
+    ///     Default::default(),
+    ///     SolverOptions {
+    ///         activity_based_search: ActivityBasedSearchOptions {
+    ///             last_conflict_size: 2,
+    ///             ..Default::default()
+    ///         },
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// let x = solver.new_bounded_integer(0, 3);
+    /// let y = solver.new_bounded_integer(0, 3);
+    /// let _ = solver
+    ///     .add_constraint(constraints::not_equals(vec![x, y], 0))
+    ///     .post();
+    ///
+    /// let mut brancher = solver.activity_based_brancher_over_all_integer_variables();
+    /// let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    /// let SatisfactionResult::Satisfiable(solution) = result else {
+    ///     panic!("expected a solution");
+    /// };
+    /// assert_ne!(solution.get_integer_value(x), solution.get_integer_value(y));
+    /// ```
+    pub fn activity_based_brancher_over_all_integer_variables(&self) -> ActivityBasedBrancher {
+        self.satisfaction_solver
+            .activity_based_brancher_over_all_integer_variables()
+    }
 }
 
 /// Proof logging methods
@@ -775,3 +3303,606 @@ pub type DefaultBrancher = IndependentVariableValueBrancher<
         PhaseSaving<PropositionalVariable, bool>,
     >,
 >;
+
+/// The type of [`Brancher`] which is created by
+/// [`Solver::vsids_brancher_over_all_integer_variables`].
+///
+/// It consists of the variable selector [`Vsids`] over [`DomainId`]s in combination with a
+/// [`SolutionGuidedValueSelector`] with as backup [`InDomainMin`].
+pub type IntegerVsidsBrancher = IndependentVariableValueBrancher<
+    DomainId,
+    Vsids<DomainId>,
+    SolutionGuidedValueSelector<DomainId, i32, InDomainMin>,
+>;
+
+/// The type of [`Brancher`] which is created by
+/// [`Solver::activity_based_brancher_over_all_integer_variables`].
+///
+/// It consists of the variable selector [`ActivityBased`] wrapped in [`LastConflict`] in
+/// combination with a [`SolutionGuidedValueSelector`] with as backup [`InDomainMin`].
+pub type ActivityBasedBrancher = IndependentVariableValueBrancher<
+    DomainId,
+    LastConflict<DomainId, ActivityBased<DomainId>>,
+    SolutionGuidedValueSelector<DomainId, i32, InDomainMin>,
+>;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::basic_types::TemporaryFile;
+    use crate::constraints::CardinalityEncoding;
+    use crate::options::RandomNeighbourhood;
+    use crate::options::SolutionPolishingOptions;
+    use crate::proof::Format;
+    use crate::proof::ProofLog;
+    use crate::results::ProblemSolution;
+
+    /// Creates `n` queens on an `n x n` board, such that no two queens attack each other; the
+    /// variable of a column is the row of the queen in that column.
+    fn n_queens(solver: &mut Solver, n: usize) -> Vec<DomainId> {
+        let queens = solver.new_bounded_integers(n, 0, n as i32 - 1);
+        let diagonals = |direction: i32| {
+            queens
+                .iter()
+                .enumerate()
+                .map(|(column, queen)| queen.offset(direction * column as i32))
+                .collect::<Vec<_>>()
+        };
+
+        let _ = solver
+            .add_constraint(constraints::all_different(queens.clone()))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::all_different(diagonals(1)))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::all_different(diagonals(-1)))
+            .post();
+
+        queens
+    }
+
+    /// Creates a jobshop instance with three jobs on three machines, of which the optimal makespan
+    /// is 11; it returns the start times of the operations and the makespan.
+    fn jobshop(solver: &mut Solver) -> (Vec<DomainId>, DomainId) {
+        let jobs: [&[(usize, i32)]; 3] = [
+            &[(0, 3), (1, 2), (2, 2)],
+            &[(0, 2), (2, 1), (1, 4)],
+            &[(1, 4), (2, 3)],
+        ];
+        let makespan = solver.new_bounded_integer(0, 30);
+
+        let mut all_start_times = vec![];
+        let mut machine_operations = vec![(vec![], vec![]); 3];
+        for job in jobs {
+            let start_times = solver.new_bounded_integers(job.len(), 0, 30);
+
+            for (index, &(machine, duration)) in job.iter().enumerate() {
+                let next = start_times.get(index + 1).copied().unwrap_or(makespan);
+                let _ = solver
+                    .add_constraint(constraints::less_than_or_equals(
+                        [start_times[index].scaled(1), next.scaled(-1)],
+                        -duration,
+                    ))
+                    .post();
+
+                machine_operations[machine].0.push(start_times[index]);
+                machine_operations[machine].1.push(duration);
+            }
+            all_start_times.extend(start_times);
+        }
+
+        for (start_times, durations) in machine_operations {
+            let _ = solver
+                .add_constraint(constraints::disjunctive(start_times, durations))
+                .post();
+        }
+
+        (all_start_times, makespan)
+    }
+
+    fn assert_optimum(
+        solver: &Solver,
+        result: OptimisationResult,
+        objective: DomainId,
+        expected: i32,
+    ) {
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("expected the optimum to be proven, but got {result:?}");
+        };
+        assert_eq!(solution.get_integer_value(objective), expected);
+        assert!(solver.check(&solution).is_empty());
+    }
+
+    #[test]
+    fn large_neighbourhood_search_proves_the_optimal_makespan() {
+        let mut solver = Solver::default();
+        let (start_times, makespan) = jobshop(&mut solver);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise_with_lns(
+            &mut brancher,
+            &mut Indefinite,
+            makespan,
+            &start_times,
+            &mut RandomNeighbourhood::default(),
+            LnsOptions {
+                conflict_budget: 10,
+            },
+        );
+
+        assert_optimum(&solver, result, makespan, 11);
+    }
+
+    #[test]
+    fn polishing_solutions_preserves_the_optimal_makespan() {
+        let mut solver = Solver::with_options(
+            Default::default(),
+            SolverOptions {
+                solution_polishing: SolutionPolishingOptions {
+                    conflict_budget: 20,
+                    window_size: 3,
+                },
+                ..Default::default()
+            },
+        );
+        let (_, makespan) = jobshop(&mut solver);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise(&mut brancher, &mut Indefinite, makespan);
+
+        assert_optimum(&solver, result, makespan, 11);
+    }
+
+    #[test]
+    fn lexicographic_optimisation_respects_the_order_of_the_objectives() {
+        for swap in [false, true] {
+            let mut solver = Solver::default();
+            let x = solver.new_bounded_integer(0, 4);
+            let y = solver.new_bounded_integer(0, 4);
+            // x + y >= 4
+            let _ = solver
+                .add_constraint(constraints::less_than_or_equals(
+                    [x.scaled(-1), y.scaled(-1)],
+                    -4,
+                ))
+                .post();
+            let objectives = if swap { [y, x] } else { [x, y] };
+
+            let mut brancher = solver.default_brancher_over_all_propositional_variables();
+            let result =
+                solver.minimise_lexicographically(&mut brancher, &mut Indefinite, &objectives);
+
+            let LexicographicOptimisationResult::Optimal {
+                solution,
+                objective_values,
+            } = result
+            else {
+                panic!("expected the optimum to be proven");
+            };
+            assert_eq!(objective_values, vec![0, 4]);
+            assert_eq!(solution.get_integer_value(objectives[0]), 0);
+            assert_eq!(solution.get_integer_value(objectives[1]), 4);
+        }
+    }
+
+    #[test]
+    fn solving_in_phases_proves_the_optimal_makespan() {
+        let mut solver = Solver::default();
+        let (start_times, makespan) = jobshop(&mut solver);
+
+        let mut phases = vec![
+            SolverPhase::new(
+                "lns",
+                DynamicBrancher::new(vec![Box::new(
+                    solver.default_brancher_over_all_propositional_variables(),
+                )]),
+                PhaseStrategy::Lns {
+                    variables: start_times,
+                    neighbourhood: Box::new(RandomNeighbourhood::default()),
+                    options: LnsOptions::default(),
+                },
+            )
+            .with_time_budget(Duration::from_millis(10)),
+            SolverPhase::new(
+                "complete",
+                DynamicBrancher::new(vec![Box::new(
+                    solver.default_brancher_over_all_propositional_variables(),
+                )]),
+                PhaseStrategy::CompleteSearch,
+            ),
+        ];
+        let result = solver.minimise_in_phases(&mut Indefinite, makespan, &mut phases);
+
+        assert_optimum(&solver, result, makespan, 11);
+    }
+
+    /// Creates four distinct variables of which the sum is minimised; the optimum (i.e. 6) is
+    /// found by the first dive of `input_order_brancher`, but proving it requires search (or
+    /// destructive lower-bounding).
+    fn distinct_values(solver: &mut Solver) -> (Vec<DomainId>, DomainId) {
+        let variables = solver.new_bounded_integers(4, 0, 5);
+        let objective = solver.new_bounded_integer(0, 20);
+        let _ = solver
+            .add_constraint(constraints::all_different(variables.clone()))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::equals(
+                variables
+                    .iter()
+                    .map(|variable| variable.scaled(1))
+                    .chain(std::iter::once(objective.scaled(-1)))
+                    .collect::<Vec<_>>(),
+                0,
+            ))
+            .post();
+
+        (variables, objective)
+    }
+
+    fn input_order_brancher(variables: &[DomainId]) -> DynamicBrancher {
+        DynamicBrancher::new(vec![Box::new(IndependentVariableValueBrancher::new(
+            InputOrder::new(variables),
+            InDomainMin,
+        ))])
+    }
+
+    /// A [`TerminationCondition`] which triggers once after every solution which is reported by
+    /// the solution callback of the solver.
+    struct StopAfterSolution(Rc<Cell<bool>>);
+
+    impl StopAfterSolution {
+        fn new(solver: &mut Solver) -> StopAfterSolution {
+            let found_solution = Rc::new(Cell::new(false));
+            let callback_flag = Rc::clone(&found_solution);
+            solver.with_solution_callback(move |_| callback_flag.set(true));
+            StopAfterSolution(found_solution)
+        }
+    }
+
+    impl TerminationCondition for StopAfterSolution {
+        fn should_stop(&mut self) -> bool {
+            self.0.replace(false)
+        }
+    }
+
+    #[test]
+    fn phase_which_times_out_hands_off_to_the_next_phase() {
+        let timed_out_phase = |variables: &[DomainId]| {
+            SolverPhase::new(
+                "timed out",
+                input_order_brancher(variables),
+                PhaseStrategy::CompleteSearch,
+            )
+            .with_time_budget(Duration::ZERO)
+        };
+
+        let mut solver = Solver::default();
+        let (variables, objective) = distinct_values(&mut solver);
+        let mut phases = vec![timed_out_phase(&variables)];
+        let result = solver.minimise_in_phases(&mut Indefinite, objective, &mut phases);
+        assert!(matches!(result, OptimisationResult::Unknown));
+
+        let mut solver = Solver::default();
+        let (variables, objective) = distinct_values(&mut solver);
+        let mut phases = vec![
+            timed_out_phase(&variables),
+            SolverPhase::new(
+                "complete",
+                input_order_brancher(&variables),
+                PhaseStrategy::CompleteSearch,
+            ),
+        ];
+        let result = solver.minimise_in_phases(&mut Indefinite, objective, &mut phases);
+        assert_optimum(&solver, result, objective, 6);
+    }
+
+    #[test]
+    fn unsatisfiable_phase_after_a_solution_proves_the_optimum() {
+        let complete_phase = |name: &str, variables: &[DomainId]| {
+            SolverPhase::new(
+                name,
+                input_order_brancher(variables),
+                PhaseStrategy::CompleteSearch,
+            )
+        };
+
+        let new_solver = || {
+            Solver::with_options(
+                Default::default(),
+                SolverOptions {
+                    destructive_bounding_conflict_budget: 0,
+                    ..Default::default()
+                },
+            )
+        };
+
+        // The first phase is stopped after finding the optimum, without proving it
+        let mut solver = new_solver();
+        let (variables, objective) = distinct_values(&mut solver);
+        let mut termination = StopAfterSolution::new(&mut solver);
+        let mut phases = vec![complete_phase("first", &variables)];
+        let result = solver.minimise_in_phases(&mut termination, objective, &mut phases);
+        let OptimisationResult::Satisfiable(solution) = result else {
+            panic!("expected the first phase to be stopped, but got {result:?}");
+        };
+        assert_eq!(solution.get_integer_value(objective), 6);
+
+        // The second phase then finds no better solution, which proves the optimum
+        let mut solver = new_solver();
+        let (variables, objective) = distinct_values(&mut solver);
+        let mut termination = StopAfterSolution::new(&mut solver);
+        let mut phases = vec![
+            complete_phase("first", &variables),
+            complete_phase("second", &variables),
+        ];
+        let result = solver.minimise_in_phases(&mut termination, objective, &mut phases);
+        assert_optimum(&solver, result, objective, 6);
+    }
+
+    #[test]
+    fn violated_constraints_are_reported() {
+        let mut solver = Solver::default();
+        let variables = solver.new_bounded_integers(3, 0, 3);
+        let all_different = solver
+            .add_constraint(constraints::all_different(variables.clone()))
+            .post_with_id()
+            .expect("no conflict at the root");
+        let sum = solver
+            .add_constraint(constraints::less_than_or_equals(variables.clone(), 4))
+            .post_with_id()
+            .expect("no conflict at the root");
+
+        let violated = |values: [i32; 3]| {
+            let values = variables.iter().copied().zip(values).collect::<Vec<_>>();
+            let assignment = solver
+                .assignment_from_values(&values, &[])
+                .expect("the values are in the domains");
+            solver
+                .check(&assignment)
+                .into_iter()
+                .map(|violated| violated.constraint_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(violated([0, 1, 2]).is_empty());
+        assert_eq!(violated([0, 0, 1]), vec![all_different]);
+        assert_eq!(violated([1, 2, 3]), vec![sum]);
+        assert_eq!(violated([3, 3, 3]), vec![all_different, sum]);
+    }
+
+    #[test]
+    fn violated_clauses_are_reported() {
+        let mut solver = Solver::default();
+        let x = solver.new_named_bounded_integer(0, 3, "x");
+        let literals = (0..3).map(|_| solver.new_literal()).collect::<Vec<_>>();
+        let at_most_one = solver
+            .add_constraint(constraints::at_most_k_with_options(
+                literals.clone(),
+                1,
+                CardinalityEncoding::SequentialCounter,
+            ))
+            .post_with_id()
+            .expect("no conflict at the root");
+        let x_is_large = solver.get_literal(predicate![x >= 2]);
+        solver
+            .add_clause([x_is_large, literals[0]])
+            .expect("no conflict at the root");
+
+        let violated = |x_value: i32, literal_values: [bool; 3]| {
+            let literal_values = literals
+                .iter()
+                .copied()
+                .zip(literal_values)
+                .collect::<Vec<_>>();
+            let assignment = solver
+                .assignment_from_values(&[(x, x_value)], &literal_values)
+                .expect("the values are in the domains");
+            solver.check(&assignment)
+        };
+
+        assert!(violated(2, [false, true, false]).is_empty());
+        assert!(violated(1, [true, false, false]).is_empty());
+
+        // The auxiliary literals of the sequential counter are not assigned
+        let violations = violated(3, [false, true, true]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].constraint_id, at_most_one);
+
+        // The clause which was added on its own is reported as a constraint on its own
+        let violations = violated(1, [false, true, false]);
+        assert_eq!(violations.len(), 1);
+        assert_ne!(violations[0].constraint_id, at_most_one);
+        assert_eq!(
+            violations[0].reason,
+            vec![
+                Predicate::Literal(!x_is_large),
+                Predicate::Literal(!literals[0])
+            ]
+        );
+    }
+
+    #[test]
+    fn greedy_construction_warm_starts_n_queens() {
+        for (n, num_dives) in [(8, ConstructionOptions::default().num_dives), (12, 1)] {
+            let mut solver = Solver::default();
+            let queens = n_queens(&mut solver, n);
+
+            let result = solver.construct_initial_solution(
+                &mut Indefinite,
+                &queens,
+                ConstructionOptions { num_dives },
+            );
+            let solution = match result {
+                ConstructionResult::Solution(solution) => solution,
+                ConstructionResult::Hints(hints) => match solver.solve_with_hints(&hints, None) {
+                    SatisfactionResult::Satisfiable(solution) => solution,
+                    _ => panic!("expected a solution to be found using the hints"),
+                },
+                ConstructionResult::Unsatisfiable => panic!("{n}-queens has a solution"),
+            };
+            assert!(solver.check(&solution).is_empty());
+        }
+    }
+
+    #[test]
+    fn hinted_solution_is_found_first() {
+        let hints = [0, 4, 7, 5, 2, 6, 1, 3];
+        let mut solver = Solver::default();
+        let queens = n_queens(&mut solver, hints.len());
+        for (&queen, &value) in queens.iter().zip(&hints) {
+            solver.add_hint(queen, value);
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let SatisfactionResult::Satisfiable(solution) =
+            solver.satisfy(&mut brancher, &mut Indefinite)
+        else {
+            panic!("expected a solution");
+        };
+        let values = queens
+            .iter()
+            .map(|&queen| solution.get_integer_value(queen))
+            .collect::<Vec<_>>();
+        assert_eq!(values, hints);
+    }
+
+    #[test]
+    fn shaving_under_variable_bounds_does_not_tighten_the_root_bounds() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        solver.set_variable_bounds(x, 5, 10);
+        let _ = solver.shave(&mut brancher, &mut Indefinite, &[x], 100);
+        assert_eq!(solver.lower_bound(&x), 0);
+
+        solver.reset_variable_bounds(x);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 2))
+            .post();
+        let SatisfactionResult::Satisfiable(solution) =
+            solver.satisfy(&mut brancher, &mut Indefinite)
+        else {
+            panic!("expected a solution");
+        };
+        assert!(solution.get_integer_value(x) <= 2);
+    }
+
+    #[test]
+    fn shaving_within_a_scope_does_not_report_tightened_bounds() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        solver.push();
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x.scaled(-1)], -5))
+            .post();
+        let num_tightened_bounds = solver.shave(&mut brancher, &mut Indefinite, &[x], 100);
+        assert_eq!(num_tightened_bounds, 0);
+        assert!(solver.pop());
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 2))
+            .post();
+        assert!(matches!(
+            solver.satisfy(&mut brancher, &mut Indefinite),
+            SatisfactionResult::Satisfiable(_)
+        ));
+    }
+
+    #[test]
+    fn hinted_solution_is_the_first_solution_when_probing_the_hints() {
+        let mut solver = Solver::with_options(
+            Default::default(),
+            SolverOptions {
+                hint_probe_conflict_budget: 1000,
+                ..Default::default()
+            },
+        );
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x, y], 12))
+            .post();
+        // The brancher is created before the hints are added, so it does not prefer them
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.add_hint(x, 7);
+        solver.add_hint(y, 4);
+
+        let first_value = Rc::new(Cell::new(None));
+        let callback_value = Rc::clone(&first_value);
+        solver.with_solution_callback(move |arguments| {
+            if callback_value.get().is_none() {
+                callback_value.set(Some(arguments.solution.get_integer_value(x)));
+            }
+        });
+
+        let result = solver.minimise(&mut brancher, &mut Indefinite, x);
+        assert_optimum(&solver, result, x, 0);
+        assert_eq!(first_value.get(), Some(7));
+    }
+
+    #[test]
+    fn infeasibility_under_variable_bounds_does_not_conclude_the_proof() {
+        let proof_file = TemporaryFile::new("bounded-optimisation", "drcp");
+        let mut solver = Solver::with_options(
+            Default::default(),
+            SolverOptions {
+                proof_log: ProofLog::cp(proof_file.path(), Format::Text, false, false)
+                    .expect("the proof can be created"),
+                ..Default::default()
+            },
+        );
+        let objective = solver.new_bounded_integer(5, 10);
+
+        solver.set_variable_bounds(objective, 0, 3);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        assert!(matches!(result, OptimisationResult::Unsatisfiable));
+
+        solver.reset_variable_bounds(objective);
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        assert_optimum(&solver, result, objective, 5);
+        drop(solver);
+
+        let proof = proof_file.read();
+        let conclusions = proof
+            .lines()
+            .filter(|line| line.starts_with("c "))
+            .collect::<Vec<_>>();
+        assert_eq!(conclusions.len(), 1);
+        assert_ne!(conclusions[0], "c UNSAT");
+    }
+
+    #[test]
+    fn tightened_makespan_is_justified_in_the_proof() {
+        let proof_file = TemporaryFile::new("makespan-lower-bound", "drcp");
+        let mut solver = Solver::with_options(
+            Default::default(),
+            SolverOptions {
+                proof_log: ProofLog::cp(proof_file.path(), Format::Text, true, false)
+                    .expect("the proof can be created"),
+                ..Default::default()
+            },
+        );
+        let start_times = solver.new_bounded_integers(3, 0, 10);
+        let makespan = solver.new_bounded_integer(0, 20);
+
+        let lower_bound = solver
+            .tighten_makespan_lower_bound(&start_times, &[3, 4, 5], &[1, 1, 1], 2, makespan)
+            .expect("the bound does not exceed the upper-bound of the makespan");
+        assert_eq!(lower_bound, 6);
+        drop(solver);
+
+        let proof = proof_file.read();
+        let steps = proof.lines().collect::<Vec<_>>();
+        assert_eq!(steps.len(), 2);
+        assert!(steps[0].starts_with("i 1 "));
+        assert!(steps[1].starts_with("n 2 "));
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use crate::api::large_neighbourhood_search::LnsOptions;
+use crate::api::large_neighbourhood_search::NeighbourhoodSelector;
+use crate::branching::branchers::dynamic_brancher::DynamicBrancher;
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The search which is performed during a [`SolverPhase`].
+#[derive(Debug)]
+pub enum PhaseStrategy {
+    /// Complete search (see [`Solver::minimise`]), which proves the optimality of the incumbent if
+    /// the budget of the phase allows it.
+    CompleteSearch,
+    /// Large neighbourhood search over the provided `variables` (see
+    /// [`Solver::minimise_with_lns`]).
+    Lns {
+        variables: Vec<DomainId>,
+        neighbourhood: Box<dyn NeighbourhoodSelector>,
+        options: LnsOptions,
+    },
+}
+
+/// A single phase of [`Solver::minimise_in_phases`]; every phase has its own brancher, search
+/// strategy and (optionally) time budget.
+#[derive(Debug)]
+pub struct SolverPhase {
+    /// The name of the phase, which is used for logging.
+    pub name: String,
+    /// The time which may be spent in this phase before moving on to the next phase; [`None`]
+    /// indicates that the phase is only stopped by the [`TerminationCondition`] of the solve.
+    ///
+    /// [`TerminationCondition`]: crate::termination::TerminationCondition
+    pub time_budget: Option<Duration>,
+    /// The brancher which is used during this phase.
+    pub brancher: DynamicBrancher,
+    /// The search which is performed during this phase.
+    pub strategy: PhaseStrategy,
+}
+
+impl SolverPhase {
+    /// Creates a phase without a time budget.
+    pub fn new(
+        name: impl Into<String>,
+        brancher: DynamicBrancher,
+        strategy: PhaseStrategy,
+    ) -> Self {
+        SolverPhase {
+            name: name.into(),
+            time_budget: None,
+            brancher,
+            strategy,
+        }
+    }
+
+    /// Limits the time which is spent in this phase to `time_budget`.
+    pub fn with_time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use crate::engine::variables::AffineView;
+use crate::engine::variables::DomainId;
+use crate::results::ProblemSolution;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A single objective which represents a weighted sum of several objective expressions; it can be
+/// created using [`Solver::new_weighted_objective`] or, for fractional weights,
+/// [`Solver::new_scaled_objective`].
+///
+/// To keep the domain of the objective variable small, the weights are divided by their greatest
+/// common divisor (the [`WeightedObjective::scale`]) before the sum is linked to the
+/// [`WeightedObjective::objective_variable`]; the weighted sum is thus equal to the value of the
+/// objective variable multiplied by the scale.
+///
+/// The weights of an objective with fractional weights are stored as integers which are multiplied
+/// by the [`WeightedObjective::denominator`]; the values which are returned are then multiplied by
+/// the denominator as well. The values are computed using 128-bit arithmetic, such that they do not
+/// overflow for any weights and any values of the components.
+#[derive(Clone, Debug)]
+pub struct WeightedObjective {
+    objective_variable: DomainId,
+    scale: i128,
+    denominator: i64,
+    components: Vec<(i64, AffineView<DomainId>)>,
+}
+
+impl WeightedObjective {
+    pub(crate) fn new(
+        objective_variable: DomainId,
+        scale: i128,
+        denominator: i64,
+        components: Vec<(i64, AffineView<DomainId>)>,
+    ) -> Self {
+        WeightedObjective {
+            objective_variable,
+            scale,
+            denominator,
+            components,
+        }
+    }
+
+    /// The variable which should be passed to [`Solver::minimise`] or [`Solver::maximise`].
+    pub fn objective_variable(&self) -> DomainId {
+        self.objective_variable
+    }
+
+    /// The factor by which the value of the objective variable has to be multiplied to obtain the
+    /// weighted sum of the components.
+    pub fn scale(&self) -> i128 {
+        self.scale
+    }
+
+    /// The number by which the weights were multiplied to make them integral; this is 1 for an
+    /// objective which was created using [`Solver::new_weighted_objective`].
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Returns the weighted sum of the components in the provided solution, multiplied by the
+    /// [`WeightedObjective::denominator`].
+    pub fn value(&self, solution: &impl ProblemSolution) -> i128 {
+        self.scale * solution.get_integer_value(self.objective_variable) as i128
+    }
+
+    /// Returns the weighted sum of the components in the provided solution; this is
+    /// [`WeightedObjective::value`] divided by the [`WeightedObjective::denominator`], which is
+    /// only exact if the quotient can be represented by an `f64`.
+    pub fn approximate_value(&self, solution: &impl ProblemSolution) -> f64 {
+        self.value(solution) as f64 / self.denominator as f64
+    }
+
+    /// Returns the (unweighted) value of every component in the provided solution, in the order
+    /// in which the components were provided.
+    pub fn component_values(&self, solution: &impl ProblemSolution) -> Vec<i32> {
+        self.components
+            .iter()
+            .map(|&(_, expression)| solution.get_integer_value(expression))
+            .collect()
+    }
+
+    /// Returns the weighted value of every component in the provided solution (multiplied by the
+    /// [`WeightedObjective::denominator`]), in the order in which the components were provided.
+    pub fn weighted_component_values(&self, solution: &impl ProblemSolution) -> Vec<i128> {
+        self.components
+            .iter()
+            .map(|&(weight, expression)| {
+                weight as i128 * solution.get_integer_value(expression) as i128
+            })
+            .collect()
+    }
+}
+
+/// Returns the greatest common divisor of the absolute values of the provided weights, or 1 if
+/// all weights are 0.
+pub(crate) fn weights_gcd(weights: impl IntoIterator<Item = i64>) -> i128 {
+    let gcd = weights.into_iter().fold(0_i128, |gcd, weight| {
+        let (mut a, mut b) = (gcd, (weight as i128).abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    });
+
+    gcd.max(1)
+}
+
+/// Returns the fractional `weight` rounded to `decimal_digits` decimals, as an integer which is
+/// multiplied by `denominator` (which is `10^decimal_digits`); returns [`None`] if it does not fit
+/// in an `i64`.
+pub(crate) fn integral_weight(weight: f64, denominator: i64) -> Option<i64> {
+    let scaled_weight = (weight * denominator as f64).round();
+    // Note that `i64::MAX as f64` is rounded up to 2^63, which does not fit
+    (scaled_weight >= i64::MIN as f64 && scaled_weight < i64::MAX as f64)
+        .then_some(scaled_weight as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_of_weights_ignores_signs() {
+        assert_eq!(weights_gcd([6, -4, 10]), 2);
+        assert_eq!(weights_gcd([3, 5]), 1);
+        assert_eq!(weights_gcd([0, 0]), 1);
+        assert_eq!(weights_gcd([i32::MIN as i64, i32::MIN as i64]), 1 << 31);
+        assert_eq!(weights_gcd([i64::MIN]), 1 << 63);
+    }
+
+    #[test]
+    fn fractional_weights_are_rounded_to_the_denominator() {
+        assert_eq!(integral_weight(0.125, 100), Some(13));
+        assert_eq!(integral_weight(-1.5, 10), Some(-15));
+        assert_eq!(integral_weight(1e10, 1_000_000_000), None);
+    }
+}
@@ -16,4 +16,60 @@ pub enum ConstraintOperationError {
     /// Error which indicate that adding a propagator led to infeasibility at the root.
     #[error("Adding the constraint failed because it is infeasible at the root")]
     InfeasiblePropagator,
+    /// Error which indicates that a constraint or clause which was added within a scope (see
+    /// [`Solver::push`]) is infeasible at the root together with the other constraints of the
+    /// open scopes; the [`Solver`] is infeasible until the innermost scope is closed.
+    #[error("Adding the constraint failed because it is infeasible within the open scopes")]
+    InfeasibleScope,
+    /// Error which indicates that the bounds of an expression which the constraint has to
+    /// introduce do not fit in an `i32`.
+    #[error("Adding the constraint failed because the bounds of an expression overflow an i32")]
+    IntegerOverflow,
+    /// Error which indicates that the arguments of the constraint are invalid (e.g. a task with a
+    /// negative duration); the [`Solver`] is left unchanged.
+    #[error("Adding the constraint failed because its arguments are invalid: {0}")]
+    InvalidArgument(#[from] InvalidArgument),
+}
+
+/// Describes why the arguments of a constraint were rejected when it was posted (see
+/// [`ConstraintOperationError::InvalidArgument`]).
+///
+/// The arguments are referred to by the names of the parameters of the function which created the
+/// constraint.
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InvalidArgument {
+    /// The array `argument` is empty, while the constraint is only defined for non-empty arrays.
+    #[error("`{argument}` should not be empty")]
+    EmptyArray { argument: &'static str },
+    /// The array `argument` should have the same length as the array `reference`.
+    #[error("`{argument}` has length {length}, while `{reference}` has length {expected_length}")]
+    InconsistentLengths {
+        argument: &'static str,
+        length: usize,
+        reference: &'static str,
+        expected_length: usize,
+    },
+    /// The element at `index` of the array `argument` is negative.
+    #[error("element {index} of `{argument}` is negative ({value})")]
+    NegativeElement {
+        argument: &'static str,
+        index: usize,
+        value: i32,
+    },
+    /// The value of `argument` is negative.
+    #[error("`{argument}` is negative ({value})")]
+    NegativeValue { argument: &'static str, value: i32 },
+    /// The element at `index` of the array `argument` is not a finite number.
+    #[error("element {index} of `{argument}` is not finite")]
+    NonFiniteElement {
+        argument: &'static str,
+        index: usize,
+    },
+    /// The array `argument` is not a permutation of the array `reference` (which should not
+    /// contain duplicates).
+    #[error("`{argument}` is not a permutation of `{reference}`")]
+    NotAPermutation {
+        argument: &'static str,
+        reference: &'static str,
+    },
 }
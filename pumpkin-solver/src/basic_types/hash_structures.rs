@@ -1,4 +1,15 @@
+//! Hash-based containers which should be used throughout the solver instead of the ones in
+//! [`std::collections`].
+//!
+//! The standard library containers use a randomly seeded hasher, which means that the iteration
+//! order of the containers differs from run to run. Any decision which depends on the iteration
+//! order (e.g. the order in which variables are created or propagators are enqueued) then leads to
+//! differences in search between runs with the same input. The containers in this module use the
+//! FNV hasher, which is unseeded (and thus deterministic) and faster for the small keys that are
+//! used in the solver.
 use fnv::FnvBuildHasher;
 
-pub(crate) type HashMap<K, V, Hasher = FnvBuildHasher> = std::collections::HashMap<K, V, Hasher>;
-pub(crate) type HashSet<K, Hasher = FnvBuildHasher> = std::collections::HashSet<K, Hasher>;
+/// A [`std::collections::HashMap`] which uses a deterministic hasher.
+pub type HashMap<K, V, Hasher = FnvBuildHasher> = std::collections::HashMap<K, V, Hasher>;
+/// A [`std::collections::HashSet`] which uses a deterministic hasher.
+pub type HashSet<K, Hasher = FnvBuildHasher> = std::collections::HashSet<K, Hasher>;
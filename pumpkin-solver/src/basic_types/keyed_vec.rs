@@ -58,6 +58,24 @@ impl<Key: StorageKey, Value> KeyedVec<Key, Value> {
         self.elements.iter()
     }
 
+    /// Iterate over the keys and the corresponding values in the vector.
+    pub fn entries(&self) -> impl Iterator<Item = (Key, &'_ Value)> {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| (Key::create_from_index(idx), value))
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.elements.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.elements.shrink_to_fit()
+    }
+
     pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &'_ mut Value> {
         self.elements.iter_mut()
     }
@@ -66,6 +84,11 @@ impl<Key: StorageKey, Value> KeyedVec<Key, Value> {
         self.elements.swap(a, b)
     }
 
+    /// Removes the values of all keys with an index of at least `len`.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.elements.truncate(len)
+    }
+
     pub(crate) fn into_entries(self) -> impl Iterator<Item = (Key, Value)> {
         self.elements
             .into_iter()
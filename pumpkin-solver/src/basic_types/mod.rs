@@ -14,16 +14,20 @@ mod propositional_conjunction;
 mod random;
 pub(crate) mod sequence_generators;
 mod solution;
+mod solver_error;
+#[cfg(test)]
+mod temporary_file;
 mod trail;
 mod weighted_literal;
 
 pub(crate) use clause_reference::ClauseReference;
 pub(crate) use conflict_info::*;
 pub use constraint_operation_error::ConstraintOperationError;
+pub use constraint_operation_error::InvalidArgument;
 pub(crate) use constraint_reference::ConstraintReference;
 pub(crate) use csp_solver_execution_flag::CSPSolverExecutionFlag;
 pub use function::Function;
-pub(crate) use hash_structures::*;
+pub use hash_structures::*;
 pub(crate) use key_value_heap::KeyValueHeap;
 pub use keyed_vec::*;
 pub(crate) use propagation_status_cp::Inconsistency;
@@ -34,5 +38,8 @@ pub use random::*;
 pub use solution::ProblemSolution;
 pub use solution::Solution;
 pub use solution::SolutionReference;
+pub use solver_error::SolverError;
+#[cfg(test)]
+pub(crate) use temporary_file::TemporaryFile;
 pub(crate) use trail::Trail;
 pub(crate) use weighted_literal::WeightedLiteral;
@@ -1,6 +1,8 @@
 use itertools::Itertools;
 
 use crate::engine::predicates::predicate::Predicate;
+use crate::variable_names::DisplayWith;
+use crate::Solver;
 
 /// A struct which represents a conjunction of [`Predicate`]s (e.g. it can represent `[x >= 5] /\ [y
 /// <= 10]`).
@@ -44,6 +46,16 @@ impl PropositionalConjunction {
             .collect();
         self
     }
+
+    /// Returns an adapter which displays the conjunction using the names of the variables in the
+    /// `solver`; a lower bound and an upper bound on the same variable are displayed together
+    /// (e.g. `[2 <= x <= 5]`).
+    pub fn display_with<'a>(
+        &'a self,
+        solver: &'a Solver,
+    ) -> DisplayWith<'a, PropositionalConjunction> {
+        solver.get_satisfaction_solver().display_with(self)
+    }
 }
 
 impl IntoIterator for PropositionalConjunction {
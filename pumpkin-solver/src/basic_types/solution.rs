@@ -1,4 +1,7 @@
+use std::ops::RangeInclusive;
+
 use crate::engine::propagation::propagation_context::HasAssignments;
+use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 use crate::engine::AssignmentsInteger;
@@ -56,6 +59,12 @@ pub trait ProblemSolution: HasAssignments {
 
         lower_bound
     }
+
+    /// Returns the domain of the provided [`DomainId`] as a sorted list of disjoint ranges; this
+    /// is a single range for every domain which is assigned.
+    fn get_domain_ranges(&self, domain_id: DomainId) -> Vec<RangeInclusive<i32>> {
+        self.assignments_integer().get_domain_ranges(domain_id)
+    }
 }
 
 /// A solution which keeps reference to its inner structures.
@@ -79,6 +88,11 @@ impl<'a> SolutionReference<'a> {
     pub fn get_propostional_variables(&self) -> impl Iterator<Item = PropositionalVariable> {
         self.assignments_propositional.get_propositional_variables()
     }
+
+    /// Returns an iterator over all the integer variables.
+    pub fn get_domains(&self) -> impl Iterator<Item = DomainId> {
+        self.assignments_integer.get_domains()
+    }
 }
 
 impl ProblemSolution for SolutionReference<'_> {}
@@ -0,0 +1,53 @@
+use thiserror::Error;
+
+use super::ConstraintOperationError;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The errors which can be returned by the fallible methods of the [`Solver`].
+///
+/// This allows an application which embeds the [`Solver`] to recover from invalid input (e.g. a
+/// variable with an empty domain) rather than crash; every more specific error (such as a
+/// [`ConstraintOperationError`]) can be converted into a [`SolverError`] using the `?` operator.
+///
+/// # Example
+/// ```
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::variables::DomainId;
+/// # use pumpkin_solver::SolverError;
+/// # use pumpkin_solver::Solver;
+/// fn build_model(
+///     solver: &mut Solver,
+///     bounds: &[(i32, i32)],
+/// ) -> Result<Vec<DomainId>, SolverError> {
+///     let variables = bounds
+///         .iter()
+///         .map(|&(lower_bound, upper_bound)| {
+///             solver.try_new_bounded_integer(lower_bound, upper_bound)
+///         })
+///         .collect::<Result<Vec<_>, _>>()?;
+///     solver
+///         .add_constraint(constraints::all_different(variables.clone()))
+///         .post()?;
+///     Ok(variables)
+/// }
+///
+/// let mut solver = Solver::default();
+/// assert!(build_model(&mut solver, &[(0, 1), (0, 1)]).is_ok());
+///
+/// // The second variable has an empty domain, which is reported rather than panicking
+/// let mut solver = Solver::default();
+/// assert!(matches!(
+///     build_model(&mut solver, &[(0, 1), (1, 0)]),
+///     Err(SolverError::EmptyDomain)
+/// ));
+/// ```
+#[derive(Error, Debug, Copy, Clone)]
+pub enum SolverError {
+    /// A constraint could not be added to the [`Solver`].
+    #[error(transparent)]
+    ConstraintOperation(#[from] ConstraintOperationError),
+    /// A variable was created with an empty domain; e.g. its lower-bound exceeds its upper-bound.
+    #[error("cannot create a variable with an empty domain")]
+    EmptyDomain,
+}
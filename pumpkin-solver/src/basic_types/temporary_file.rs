@@ -0,0 +1,47 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// A file in the temporary directory which is used by a test to inspect the output of the solver
+/// (e.g. a proof or an exported implication graph).
+///
+/// Every instance is given a unique path, even if tests using the same name run in parallel, and
+/// the file (together with the literal mapping which is written next to a proof) is removed when
+/// the instance is dropped.
+#[derive(Debug)]
+pub(crate) struct TemporaryFile {
+    path: PathBuf,
+}
+
+impl TemporaryFile {
+    /// Creates a new temporary file path of the form `pumpkin-<name>-<unique id>.<extension>`; the
+    /// file itself is not created.
+    pub(crate) fn new(name: &str, extension: &str) -> TemporaryFile {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "pumpkin-{name}-{}-{id}.{extension}",
+            std::process::id()
+        ));
+
+        TemporaryFile { path }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the contents of the file; panics if the file has not been written.
+    pub(crate) fn read(&self) -> String {
+        std::fs::read_to_string(&self.path).expect("the temporary file is written")
+    }
+}
+
+impl Drop for TemporaryFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+        let _ = std::fs::remove_file(self.path.with_extension("lits"));
+    }
+}
@@ -0,0 +1,378 @@
+//! Contains a harness for benchmarking the [`Solver`] over a directory of instances; this module
+//! is only available when the `bench` feature is enabled.
+//!
+//! Every instance in the directory is loaded into a fresh [`Solver`] through a user-provided
+//! loader, after which it is solved with the time limit specified in the [`BenchmarkOptions`].
+//! The resulting [`BenchmarkResults`] can be written as CSV or JSON, which allows the performance
+//! of different versions of the solver (e.g. before and after a change to a propagator) to be
+//! compared.
+//!
+//! # Example
+//! ```rust,no_run
+//! # use std::path::Path;
+//! # use std::time::Duration;
+//! # use pumpkin_solver::bench::run_benchmark;
+//! # use pumpkin_solver::bench::BenchmarkOptions;
+//! # use pumpkin_solver::Solver;
+//! let options = BenchmarkOptions {
+//!     time_limit: Duration::from_secs(10),
+//!     extension: Some("model".to_owned()),
+//! };
+//!
+//! let results = run_benchmark(Path::new("instances"), &options, |path| {
+//!     // Parse the instance at `path` and add its variables and constraints to a solver.
+//!     let solver = Solver::default();
+//!     Ok::<Solver, String>(solver)
+//! })
+//! .expect("could not read the instance directory");
+//!
+//! results
+//!     .write_csv(std::io::stdout())
+//!     .expect("could not write the results");
+//! ```
+use std::fmt::Display;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::results::SatisfactionResult;
+use crate::results::SearchStatistics;
+use crate::Solver;
+
+/// The options which determine how the instances of a benchmark are run.
+#[derive(Clone, Debug)]
+pub struct BenchmarkOptions {
+    /// The time limit for solving a single instance.
+    pub time_limit: Duration,
+    /// If provided, only the files with this extension (without the leading `.`) are considered
+    /// to be instances.
+    pub extension: Option<String>,
+}
+
+impl Default for BenchmarkOptions {
+    fn default() -> Self {
+        BenchmarkOptions {
+            time_limit: Duration::from_secs(60),
+            extension: None,
+        }
+    }
+}
+
+/// The outcome of running a single instance of a benchmark.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BenchmarkOutcome {
+    /// A solution was found.
+    Satisfiable,
+    /// The instance was proven to have no solution.
+    Unsatisfiable,
+    /// The time limit was exceeded before the instance was solved.
+    Unknown,
+    /// The instance could not be loaded; contains the error reported by the loader.
+    LoadError(String),
+}
+
+impl BenchmarkOutcome {
+    fn as_str(&self) -> &str {
+        match self {
+            BenchmarkOutcome::Satisfiable => "satisfiable",
+            BenchmarkOutcome::Unsatisfiable => "unsatisfiable",
+            BenchmarkOutcome::Unknown => "unknown",
+            BenchmarkOutcome::LoadError(_) => "error",
+        }
+    }
+}
+
+/// The result of running a single instance of a benchmark.
+#[derive(Clone, Debug)]
+pub struct BenchmarkEntry {
+    /// The path of the instance.
+    pub instance: PathBuf,
+    /// The outcome of solving the instance.
+    pub outcome: BenchmarkOutcome,
+    /// The wall-clock time spent on loading and solving the instance.
+    pub wall_time: Duration,
+    /// The statistics of the search; [`None`] if the instance could not be loaded.
+    pub statistics: Option<SearchStatistics>,
+}
+
+/// The results of [`run_benchmark`], in the order in which the instances were run (i.e. in
+/// lexicographical order of their paths).
+#[derive(Clone, Debug, Default)]
+pub struct BenchmarkResults {
+    pub entries: Vec<BenchmarkEntry>,
+}
+
+const CSV_HEADER: [&str; 8] = [
+    "instance",
+    "outcome",
+    "wall_time_ms",
+    "num_decisions",
+    "num_conflicts",
+    "num_restarts",
+    "num_propagations",
+    "time_spent_in_solver_ms",
+];
+
+impl BenchmarkResults {
+    /// Writes the results as CSV, with a header followed by one row per instance.
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER.join(","))?;
+
+        for entry in &self.entries {
+            let statistics = entry.statistics_columns();
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                escape_csv(&entry.instance.display().to_string()),
+                entry.outcome.as_str(),
+                entry.wall_time.as_millis(),
+                statistics.join(",")
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the results as a JSON array with one object per instance.
+    pub fn write_json(&self, mut writer: impl Write) -> io::Result<()> {
+        let objects = self
+            .entries
+            .iter()
+            .map(BenchmarkEntry::to_json)
+            .collect::<Vec<_>>();
+
+        writeln!(writer, "[{}]", objects.join(","))
+    }
+}
+
+impl BenchmarkEntry {
+    fn statistics_columns(&self) -> [String; 5] {
+        match &self.statistics {
+            Some(statistics) => [
+                statistics.num_decisions.to_string(),
+                statistics.num_conflicts.to_string(),
+                statistics.num_restarts.to_string(),
+                statistics.num_propagations.to_string(),
+                statistics.time_spent_in_solver.as_millis().to_string(),
+            ],
+            None => Default::default(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        let _ = write!(
+            json,
+            "\"instance\":{},\"outcome\":\"{}\",\"wall_time_ms\":{}",
+            escape_json(&self.instance.display().to_string()),
+            self.outcome.as_str(),
+            self.wall_time.as_millis()
+        );
+
+        if let BenchmarkOutcome::LoadError(error) = &self.outcome {
+            let _ = write!(json, ",\"error\":{}", escape_json(error));
+        }
+
+        let statistics = self.statistics_columns();
+        for (name, value) in CSV_HEADER[3..].iter().zip(statistics) {
+            let value = if value.is_empty() {
+                "null".to_owned()
+            } else {
+                value
+            };
+            let _ = write!(json, ",\"{name}\":{value}");
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+/// Runs the [`Solver`] on every instance in `directory` (non-recursively); every instance is
+/// loaded into a fresh [`Solver`] by `load_instance` and is then solved using the default
+/// brancher and the time limit in the `options`.
+///
+/// Returns an error if the directory could not be read; errors while loading an instance are
+/// reported as [`BenchmarkOutcome::LoadError`].
+pub fn run_benchmark<Error: Display>(
+    directory: &Path,
+    options: &BenchmarkOptions,
+    mut load_instance: impl FnMut(&Path) -> Result<Solver, Error>,
+) -> io::Result<BenchmarkResults> {
+    let mut instances = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    instances.retain(|path| {
+        let has_extension = match &options.extension {
+            Some(extension) => path
+                .extension()
+                .is_some_and(|path_extension| path_extension == extension.as_str()),
+            None => true,
+        };
+
+        path.is_file() && has_extension
+    });
+    instances.sort();
+
+    let entries = instances
+        .into_iter()
+        .map(|instance| run_instance(instance, options, &mut load_instance))
+        .collect();
+
+    Ok(BenchmarkResults { entries })
+}
+
+fn run_instance<Error: Display>(
+    instance: PathBuf,
+    options: &BenchmarkOptions,
+    load_instance: &mut impl FnMut(&Path) -> Result<Solver, Error>,
+) -> BenchmarkEntry {
+    let start = Instant::now();
+
+    let mut solver = match load_instance(&instance) {
+        Ok(solver) => solver,
+        Err(error) => {
+            return BenchmarkEntry {
+                instance,
+                outcome: BenchmarkOutcome::LoadError(error.to_string()),
+                wall_time: start.elapsed(),
+                statistics: None,
+            }
+        }
+    };
+
+    let outcome = match solver.solve(Some(options.time_limit)) {
+        SatisfactionResult::Satisfiable(_) => BenchmarkOutcome::Satisfiable,
+        SatisfactionResult::Unsatisfiable(_) => BenchmarkOutcome::Unsatisfiable,
+        SatisfactionResult::Unknown(_) => BenchmarkOutcome::Unknown,
+    };
+
+    BenchmarkEntry {
+        instance,
+        outcome,
+        wall_time: start.elapsed(),
+        statistics: Some(solver.search_statistics()),
+    }
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            character if character.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", character as u32);
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints;
+    use crate::variables::TransformableVariable;
+
+    fn create_instance_directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("pumpkin-bench-{}", std::process::id()));
+        fs::create_dir_all(&directory).expect("could create directory");
+
+        fs::write(directory.join("a.model"), "1").expect("could write instance");
+        fs::write(directory.join("b.model"), "0").expect("could write instance");
+        fs::write(directory.join("c.model"), "invalid").expect("could write instance");
+        fs::write(directory.join("ignored.txt"), "1").expect("could write instance");
+
+        directory
+    }
+
+    /// Loads an instance containing a single number; if it is 1 then the instance is
+    /// satisfiable, and otherwise it is unsatisfiable (three pigeons have to be placed in two
+    /// holes).
+    fn load_instance(path: &Path) -> Result<Solver, String> {
+        let contents = fs::read_to_string(path).map_err(|error| error.to_string())?;
+        let is_satisfiable = contents
+            .trim()
+            .parse::<u8>()
+            .map_err(|error| error.to_string())?
+            == 1;
+
+        let mut solver = Solver::default();
+        let num_holes = if is_satisfiable { 3 } else { 2 };
+        let pigeons = solver.new_bounded_integers(3, 1, num_holes);
+        for (index, &pigeon) in pigeons.iter().enumerate() {
+            for &other in &pigeons[index + 1..] {
+                let _ = solver
+                    .add_constraint(constraints::not_equals(
+                        [pigeon.scaled(1), other.scaled(-1)],
+                        0,
+                    ))
+                    .post();
+            }
+        }
+
+        Ok(solver)
+    }
+
+    #[test]
+    fn all_instances_with_the_extension_are_run() {
+        let directory = create_instance_directory();
+        let options = BenchmarkOptions {
+            extension: Some("model".to_owned()),
+            ..Default::default()
+        };
+
+        let results = run_benchmark(&directory, &options, load_instance).expect("directory exists");
+        fs::remove_dir_all(&directory).expect("could remove directory");
+
+        let outcomes = results
+            .entries
+            .iter()
+            .map(|entry| entry.outcome.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                BenchmarkOutcome::Satisfiable,
+                BenchmarkOutcome::Unsatisfiable,
+                BenchmarkOutcome::LoadError("invalid digit found in string".to_owned())
+            ]
+        );
+
+        let mut csv = Vec::new();
+        results.write_csv(&mut csv).expect("can write to vector");
+        let csv = String::from_utf8(csv).expect("valid utf-8");
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.starts_with(&CSV_HEADER.join(",")));
+
+        let mut json = Vec::new();
+        results.write_json(&mut json).expect("can write to vector");
+        let json = String::from_utf8(json).expect("valid utf-8");
+        assert!(json.contains("\"outcome\":\"unsatisfiable\""));
+        assert!(json.contains("\"error\":\"invalid digit found in string\",\"num_decisions\":null"));
+    }
+
+    #[test]
+    fn values_are_escaped() {
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("a\"b"), "\"a\"\"b\"");
+        assert_eq!(escape_json("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+}
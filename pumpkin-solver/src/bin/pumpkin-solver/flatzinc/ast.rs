@@ -1,5 +1,6 @@
 use log::warn;
 use pumpkin_solver::branching::AntiFirstFail;
+use pumpkin_solver::branching::DomWDeg;
 use pumpkin_solver::branching::DynamicValueSelector;
 use pumpkin_solver::branching::DynamicVariableSelector;
 use pumpkin_solver::branching::FirstFail;
@@ -49,7 +50,10 @@ impl VariableSelectionStrategy {
                 warn!("AntiFirstFail does not make sense for propositional variables, defaulting to input order...");
                 Box::new(InputOrder::new(propositional_variables))
             }
-            VariableSelectionStrategy::DomWDeg => todo!("DomWDeg is not yet implemented"),
+            VariableSelectionStrategy::DomWDeg => {
+                warn!("DomWDeg does not make sense for propositional variables, defaulting to input order...");
+                Box::new(InputOrder::new(propositional_variables))
+            }
             VariableSelectionStrategy::FirstFail => {
                 warn!("FirstFail does not make sense for propositional variables, defaulting to input order...");
                 Box::new(InputOrder::new(propositional_variables))
@@ -83,7 +87,7 @@ impl VariableSelectionStrategy {
     ) -> DynamicVariableSelector<DomainId> {
         DynamicVariableSelector::new(match self {
             VariableSelectionStrategy::AntiFirstFail => Box::new(AntiFirstFail::new(variables)),
-            VariableSelectionStrategy::DomWDeg => todo!("DomWDeg is not yet implemented"),
+            VariableSelectionStrategy::DomWDeg => Box::new(DomWDeg::new(variables)),
             VariableSelectionStrategy::FirstFail => Box::new(FirstFail::new(variables)),
             VariableSelectionStrategy::Impact => todo!("Impact is not yet implemented"),
             VariableSelectionStrategy::InputOrder => Box::new(InputOrder::new(variables)),
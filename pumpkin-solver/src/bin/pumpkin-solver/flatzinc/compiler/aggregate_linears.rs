@@ -0,0 +1,290 @@
+//! Aggregate variables which are defined by a small linear equality (e.g. `x + y = z`) into the
+//! linear constraint which uses them.
+//!
+//! MiniZinc introduces an auxiliary variable for (nearly) every sub-expression of a linear
+//! expression; a variable `z` which is defined by `int_lin_eq` and which occurs in exactly one
+//! other linear constraint is replaced by its definition in that constraint. This removes a
+//! propagator for every aggregated variable, and the remaining linear constraint propagates
+//! directly over the original variables.
+//!
+//! The substitution is only performed if it does not change the set of solutions; the variable
+//! should be introduced by the compiler (so it is not part of the output), it should not be the
+//! objective, and its domain should contain every value of its definition.
+//!
+//! The aggregated variable is not removed from the solver, it is merely left unconstrained; the
+//! substitution is thus not performed when all solutions are enumerated, since every value of the
+//! aggregated variable would then lead to a distinct solution.
+use itertools::Itertools;
+use pumpkin_solver::containers::HashMap;
+use pumpkin_solver::containers::HashSet;
+use pumpkin_solver::variables::DomainId;
+
+use super::context::CompilationContext;
+use super::context::Domain;
+use super::context::LinearDefinition;
+use crate::flatzinc::ast::FlatZincAst;
+use crate::flatzinc::ast::SingleVarDecl;
+use crate::flatzinc::ast::VarArrayDecl;
+use crate::flatzinc::FlatZincError;
+use crate::flatzinc::FlatZincOptions;
+
+/// The maximum number of terms (including the defined variable) of a definition which is
+/// aggregated.
+const MAX_DEFINITION_LENGTH: usize = 3;
+
+/// The linear constraints into which a definition can be substituted.
+const LINEAR_CONSTRAINTS: [&str; 6] = [
+    "int_lin_eq",
+    "int_lin_eq_reif",
+    "int_lin_le",
+    "int_lin_le_reif",
+    "int_lin_ne",
+    "int_lin_ne_reif",
+];
+
+pub(crate) fn run(
+    ast: &FlatZincAst,
+    context: &mut CompilationContext,
+    options: FlatZincOptions,
+) -> Result<(), FlatZincError> {
+    if !options.aggregate_linear_constraints || options.all_solutions {
+        return Ok(());
+    }
+
+    let introduced_variables = collect_introduced_variables(ast, context)?;
+
+    let mut occurrences: HashMap<DomainId, Vec<usize>> = HashMap::default();
+    for (index, constraint_item) in ast.constraint_decls.iter().enumerate() {
+        for expr in &constraint_item.exprs {
+            for variable in integer_variables_in(context, expr)? {
+                occurrences.entry(variable).or_default().push(index);
+            }
+        }
+    }
+
+    for (index, constraint_item) in ast.constraint_decls.iter().enumerate() {
+        let flatzinc::ConstraintItem { id, exprs, annos } = constraint_item;
+        if id != "int_lin_eq" || exprs.len() != 3 {
+            continue;
+        }
+
+        let Some(defined) = annos
+            .iter()
+            .find(|annotation| annotation.id == "defines_var")
+            .and_then(|annotation| match annotation.expressions.as_slice() {
+                [flatzinc::AnnExpr::Expr(flatzinc::Expr::VarParIdentifier(identifier))] => {
+                    Some(identifier)
+                }
+                _ => None,
+            })
+        else {
+            continue;
+        };
+        if !context.integer_equivalences.is_defined(defined) {
+            continue;
+        }
+        let defined = context.resolve_integer_variable_from_identifier(defined)?;
+
+        let Some(&(lower_bound, upper_bound)) = introduced_variables.get(&defined) else {
+            continue;
+        };
+
+        // The variable should occur only in its definition and in one other linear constraint
+        let is_used_once = match occurrences[&defined].as_slice() {
+            &[first, second] if first == index && second != index => {
+                LINEAR_CONSTRAINTS.contains(&ast.constraint_decls[second].id.as_str())
+            }
+            _ => false,
+        };
+        if !is_used_once {
+            continue;
+        }
+
+        let weights = context.resolve_array_integer_constants(&exprs[0])?;
+        let variables = context.resolve_integer_variable_array(&exprs[1])?;
+        let rhs = context.resolve_integer_constant_from_expr(&exprs[2])?;
+        if variables.len() > MAX_DEFINITION_LENGTH || weights.len() != variables.len() {
+            continue;
+        }
+
+        let Some(definition) = define(&weights, &variables, rhs, defined) else {
+            continue;
+        };
+
+        // Aggregating the variable removes its domain, which is only allowed if the domain does
+        // not restrict the values of the definition
+        let (minimum, maximum) = definition.terms.iter().fold(
+            (definition.constant as i64, definition.constant as i64),
+            |(minimum, maximum), &(weight, variable)| {
+                let lower = weight as i64 * context.solver.lower_bound(&variable) as i64;
+                let upper = weight as i64 * context.solver.upper_bound(&variable) as i64;
+                (minimum + lower.min(upper), maximum + lower.max(upper))
+            },
+        );
+        if minimum < lower_bound as i64 || maximum > upper_bound as i64 {
+            continue;
+        }
+
+        // A definition which (indirectly) refers to the defined variable cannot be substituted
+        let is_cyclic = match context.substitute_linear_definitions(definition.terms.clone()) {
+            Ok((terms, _)) => terms.iter().any(|&(_, variable)| variable == defined),
+            Err(_) => true,
+        };
+        if is_cyclic {
+            continue;
+        }
+
+        context.aggregate_linear_definition(defined, definition, index);
+    }
+
+    Ok(())
+}
+
+/// Returns the bounds of the variables which are introduced by the compiler and have a
+/// (non-constant) interval domain; these are the variables which can be aggregated.
+///
+/// Variables which are equivalent to a model variable, or which are part of an array or the
+/// objective, are excluded.
+fn collect_introduced_variables(
+    ast: &FlatZincAst,
+    context: &mut CompilationContext,
+) -> Result<HashMap<DomainId, (i32, i32)>, FlatZincError> {
+    let mut introduced = HashMap::default();
+    let mut protected: HashSet<DomainId> = HashSet::default();
+
+    for single_var_decl in &ast.single_variables {
+        let (id, annos) = match single_var_decl {
+            SingleVarDecl::IntInRange { id, annos, .. } => (id, annos),
+            SingleVarDecl::IntInSet { id, annos, .. } => (id, annos),
+            SingleVarDecl::Bool { .. } => continue,
+        };
+        let variable = context.resolve_integer_variable_from_identifier(id)?;
+
+        let is_introduced = annos
+            .iter()
+            .any(|annotation| annotation.id == "var_is_introduced");
+        if !is_introduced {
+            let _ = protected.insert(variable);
+            continue;
+        }
+
+        // Variables with a constant domain share their domain with the constant itself
+        match context.integer_equivalences.domain(id) {
+            Domain::IntervalDomain { lb, ub } if lb < ub => {
+                let _ = introduced.insert(variable, (lb, ub));
+            }
+            _ => {}
+        }
+    }
+
+    for array_decl in &ast.variable_arrays {
+        if let VarArrayDecl::Int { id, .. } = array_decl {
+            if let Some(array) = context.integer_variable_arrays.get(id.as_str()) {
+                protected.extend(array.iter().copied());
+            }
+        }
+    }
+
+    if let flatzinc::Goal::OptimizeBool(_, flatzinc::BoolExpr::VarParIdentifier(objective)) =
+        &ast.solve_item.goal
+    {
+        if context.integer_equivalences.is_defined(objective) {
+            let _ = protected.insert(context.resolve_integer_variable_from_identifier(objective)?);
+        }
+    }
+
+    introduced.retain(|variable, _| !protected.contains(variable));
+    Ok(introduced)
+}
+
+/// Returns the integer variables which occur in the provided constraint argument.
+fn integer_variables_in(
+    context: &mut CompilationContext,
+    expr: &flatzinc::Expr,
+) -> Result<Vec<DomainId>, FlatZincError> {
+    let identifiers = match expr {
+        flatzinc::Expr::VarParIdentifier(identifier) => {
+            if let Some(array) = context.integer_variable_arrays.get(identifier.as_str()) {
+                return Ok(array.to_vec());
+            }
+            vec![identifier]
+        }
+        flatzinc::Expr::ArrayOfInt(array) => array
+            .iter()
+            .filter_map(|element| match element {
+                flatzinc::IntExpr::VarParIdentifier(identifier) => Some(identifier),
+                flatzinc::IntExpr::Int(_) => None,
+            })
+            .collect(),
+        flatzinc::Expr::ArrayOfBool(array) => array
+            .iter()
+            .filter_map(|element| match element {
+                flatzinc::BoolExpr::VarParIdentifier(identifier) => Some(identifier),
+                flatzinc::BoolExpr::Bool(_) => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    identifiers
+        .into_iter()
+        .filter(|identifier| context.integer_equivalences.is_defined(identifier))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|identifier| context.resolve_integer_variable_from_identifier(identifier))
+        .collect()
+}
+
+/// Solves `sum(weights[i] * variables[i]) = rhs` for `defined`; this is only done if the weight
+/// of `defined` is 1 or -1, since the definition would otherwise not be integral.
+fn define(
+    weights: &[i32],
+    variables: &[DomainId],
+    rhs: i32,
+    defined: DomainId,
+) -> Option<LinearDefinition> {
+    let mut positions = variables
+        .iter()
+        .positions(|&variable| variable == defined)
+        .collect::<Vec<_>>();
+    let position = positions.pop().filter(|_| positions.is_empty())?;
+
+    let defined_weight = weights[position];
+    if defined_weight.abs() != 1 {
+        return None;
+    }
+
+    let terms = weights
+        .iter()
+        .zip(variables)
+        .enumerate()
+        .filter(|&(index, _)| index != position)
+        .map(|(_, (&weight, &variable))| Some((weight.checked_mul(-defined_weight)?, variable)))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(LinearDefinition {
+        terms,
+        constant: rhs.checked_mul(defined_weight)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_solver::Solver;
+
+    use super::*;
+
+    #[test]
+    fn defined_variable_is_isolated() {
+        let mut solver = Solver::default();
+        let [x, y, z] = [0; 3].map(|_| solver.new_bounded_integer(0, 5));
+
+        // x + 2y - z = 3, so z = x + 2y - 3
+        let definition = define(&[1, 2, -1], &[x, y, z], 3, z).expect("z has unit weight");
+        assert_eq!(definition.terms, vec![(1, x), (2, y)]);
+        assert_eq!(definition.constant, -3);
+
+        assert!(define(&[1, 2, -1], &[x, y, z], 3, y).is_none());
+        assert!(define(&[1, 1, -1], &[x, z, z], 0, z).is_none());
+    }
+}
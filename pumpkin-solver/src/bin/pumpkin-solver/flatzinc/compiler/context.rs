@@ -1,9 +1,9 @@
 use std::collections::BTreeSet;
-use std::collections::HashMap;
-use std::collections::HashSet;
 use std::rc::Rc;
 
 use log::warn;
+use pumpkin_solver::containers::HashMap;
+use pumpkin_solver::containers::HashSet;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 use pumpkin_solver::Solver;
@@ -50,11 +50,35 @@ pub(crate) struct CompilationContext<'a> {
     pub(crate) constant_domain_ids: HashMap<i32, DomainId>,
     /// A mapping from integer variable array identifiers to slices of domain ids.
     pub(crate) integer_variable_arrays: HashMap<Rc<str>, Rc<[DomainId]>>,
+    /// The pairs of variables whose disequality is enforced by a detected all-different
+    /// constraint.
+    covered_disequalities: HashSet<(DomainId, DomainId)>,
+    /// The definitions of the variables which are aggregated into the linear constraints in
+    /// which they occur.
+    linear_definitions: HashMap<DomainId, LinearDefinition>,
+    /// The indices of the constraints which define an aggregated variable; these constraints are
+    /// not posted.
+    aggregated_constraints: HashSet<usize>,
 
     /// All set parameters.
     pub(crate) set_constants: HashMap<Rc<str>, Set>,
 }
 
+fn ordered_pair(a: DomainId, b: DomainId) -> (DomainId, DomainId) {
+    if a.id <= b.id {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// The definition of a variable as the linear expression `sum(terms) + constant`.
+#[derive(Clone, Debug)]
+pub(crate) struct LinearDefinition {
+    pub(crate) terms: Vec<(i32, DomainId)>,
+    pub(crate) constant: i32,
+}
+
 /// A set parameter.
 #[derive(Clone, Debug)]
 pub(crate) enum Set {
@@ -89,11 +113,91 @@ impl CompilationContext<'_> {
             integer_equivalences: Default::default(),
             constant_domain_ids: Default::default(),
             integer_variable_arrays: Default::default(),
+            covered_disequalities: Default::default(),
+            linear_definitions: Default::default(),
+            aggregated_constraints: Default::default(),
 
             set_constants: Default::default(),
         }
     }
 
+    /// Records that the disequality between `a` and `b` is enforced by another constraint.
+    pub(crate) fn cover_disequality(&mut self, a: DomainId, b: DomainId) {
+        let _ = self.covered_disequalities.insert(ordered_pair(a, b));
+    }
+
+    /// Returns whether the disequality between `a` and `b` is enforced by another constraint.
+    pub(crate) fn is_disequality_covered(&self, a: DomainId, b: DomainId) -> bool {
+        self.covered_disequalities.contains(&ordered_pair(a, b))
+    }
+
+    /// Records that `variable` is defined by the constraint with index `constraint_index`, and
+    /// that the variable should be replaced by its definition in the linear constraints in which
+    /// it occurs.
+    pub(crate) fn aggregate_linear_definition(
+        &mut self,
+        variable: DomainId,
+        definition: LinearDefinition,
+        constraint_index: usize,
+    ) {
+        let _ = self.linear_definitions.insert(variable, definition);
+        let _ = self.aggregated_constraints.insert(constraint_index);
+    }
+
+    /// Returns whether the constraint with the given index defines an aggregated variable.
+    pub(crate) fn is_constraint_aggregated(&self, constraint_index: usize) -> bool {
+        self.aggregated_constraints.contains(&constraint_index)
+    }
+
+    /// Replaces every aggregated variable in the linear sum `terms` by its definition.
+    ///
+    /// Returns the resulting terms, in which every variable occurs at most once, together with
+    /// the constant which the substitution adds to the sum.
+    pub(crate) fn substitute_linear_definitions(
+        &self,
+        terms: impl IntoIterator<Item = (i32, DomainId)>,
+    ) -> Result<(Vec<(i32, DomainId)>, i32), FlatZincError> {
+        let mut to_substitute = terms
+            .into_iter()
+            .map(|(weight, variable)| (weight as i64, variable))
+            .collect::<Vec<_>>();
+        to_substitute.reverse();
+
+        let mut substituted: Vec<(i64, DomainId)> = vec![];
+        let mut positions: HashMap<DomainId, usize> = HashMap::default();
+        let mut constant = 0_i64;
+
+        while let Some((weight, variable)) = to_substitute.pop() {
+            if let Some(definition) = self.linear_definitions.get(&variable) {
+                constant += weight * definition.constant as i64;
+                to_substitute.extend(
+                    definition
+                        .terms
+                        .iter()
+                        .rev()
+                        .map(|&(inner_weight, inner)| (weight * inner_weight as i64, inner)),
+                );
+                continue;
+            }
+
+            match positions.get(&variable) {
+                Some(&position) => substituted[position].0 += weight,
+                None => {
+                    let _ = positions.insert(variable, substituted.len());
+                    substituted.push((weight, variable));
+                }
+            }
+        }
+
+        let terms = substituted
+            .into_iter()
+            .filter(|&(weight, _)| weight != 0)
+            .map(|(weight, variable)| Ok((i32::try_from(weight)?, variable)))
+            .collect::<Result<Vec<_>, FlatZincError>>()?;
+
+        Ok((terms, i32::try_from(constant)?))
+    }
+
     pub(crate) fn is_identifier_parameter(&mut self, identifier: &str) -> bool {
         self.integer_parameters.contains_key(identifier)
     }
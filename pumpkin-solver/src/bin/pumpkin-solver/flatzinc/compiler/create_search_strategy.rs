@@ -91,8 +91,12 @@ fn create_from_search_strategy(
                 "when no search is specified, we must add a default search"
             );
 
-            // The default search will be added below, so we give an empty brancher here.
-            DynamicBrancher::new(vec![])
+            // Without any guidance from the model, we branch on the integer variables which
+            // appear most often in recent conflicts; the default search over the propositional
+            // variables is added below.
+            DynamicBrancher::new(vec![Box::new(
+                context.solver.vsids_brancher_over_all_integer_variables(),
+            )])
         }
     };
 
@@ -0,0 +1,157 @@
+//! Detect cliques of pairwise disequalities (`int_ne`) and replace them by a single all-different
+//! constraint; models which state that variables are distinct using disequalities thus still
+//! benefit from the (much stronger) propagation of the all-different constraint.
+//!
+//! The disequalities which are covered by an all-different constraint are recorded in the
+//! [`CompilationContext`], such that they are not posted again when compiling the constraints.
+use std::cmp::Reverse;
+
+use pumpkin_solver::constraints;
+use pumpkin_solver::constraints::Constraint;
+use pumpkin_solver::containers::HashMap;
+use pumpkin_solver::containers::HashSet;
+use pumpkin_solver::variables::DomainId;
+
+use super::context::CompilationContext;
+use crate::flatzinc::ast::FlatZincAst;
+use crate::flatzinc::FlatZincError;
+use crate::flatzinc::FlatZincOptions;
+
+pub(crate) fn run(
+    ast: &FlatZincAst,
+    context: &mut CompilationContext,
+    options: FlatZincOptions,
+) -> Result<(), FlatZincError> {
+    if !options.detect_all_different {
+        return Ok(());
+    }
+
+    let mut neighbours: HashMap<DomainId, HashSet<DomainId>> = HashMap::default();
+    for constraint_item in &ast.constraint_decls {
+        let flatzinc::ConstraintItem {
+            id,
+            exprs,
+            annos: _,
+        } = constraint_item;
+        if id != "int_ne" || exprs.len() != 2 {
+            continue;
+        }
+
+        let a = context.resolve_integer_variable(&exprs[0])?;
+        let b = context.resolve_integer_variable(&exprs[1])?;
+        if a == b {
+            continue;
+        }
+
+        let _ = neighbours.entry(a).or_default().insert(b);
+        let _ = neighbours.entry(b).or_default().insert(a);
+    }
+
+    for clique in find_cliques(neighbours) {
+        for (index, &a) in clique.iter().enumerate() {
+            for &b in &clique[index + 1..] {
+                context.cover_disequality(a, b);
+            }
+        }
+
+        if constraints::all_different(clique)
+            .post(context.solver, None)
+            .is_err()
+        {
+            // The problem is infeasible, this is detected again when posting the remaining
+            // constraints
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Greedily partitions the edges of the disequality graph into cliques; only cliques which
+/// contain at least three variables are returned, since a clique of two variables is just a
+/// disequality.
+///
+/// Every clique is grown from the variable with the most uncovered disequalities, adding the
+/// neighbours which are distinct from all variables in the clique so far. The edges of a clique
+/// are removed from the graph once it is found, so the cliques do not share disequalities.
+fn find_cliques(mut neighbours: HashMap<DomainId, HashSet<DomainId>>) -> Vec<Vec<DomainId>> {
+    let mut cliques = vec![];
+    let mut seeds = neighbours.keys().copied().collect::<Vec<_>>();
+
+    loop {
+        seeds.retain(|seed| neighbours[seed].len() >= 2);
+        let Some(&seed) = seeds
+            .iter()
+            .max_by_key(|seed| (neighbours[seed].len(), Reverse(seed.id)))
+        else {
+            break;
+        };
+
+        let mut candidates = neighbours[&seed].iter().copied().collect::<Vec<_>>();
+        candidates.sort_by_key(|candidate| (Reverse(neighbours[candidate].len()), candidate.id));
+
+        let mut clique = vec![seed];
+        for candidate in candidates {
+            if clique
+                .iter()
+                .all(|member| neighbours[member].contains(&candidate))
+            {
+                clique.push(candidate);
+            }
+        }
+
+        if clique.len() < 3 {
+            seeds.retain(|&other| other != seed);
+            continue;
+        }
+
+        for &a in &clique {
+            let adjacent = neighbours.get_mut(&a).expect("every member has neighbours");
+            for b in &clique {
+                let _ = adjacent.remove(b);
+            }
+        }
+        cliques.push(clique);
+    }
+
+    cliques
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_solver::Solver;
+
+    use super::*;
+
+    fn graph(edges: &[(DomainId, DomainId)]) -> HashMap<DomainId, HashSet<DomainId>> {
+        let mut neighbours: HashMap<DomainId, HashSet<DomainId>> = HashMap::default();
+        for &(a, b) in edges {
+            let _ = neighbours.entry(a).or_default().insert(b);
+            let _ = neighbours.entry(b).or_default().insert(a);
+        }
+        neighbours
+    }
+
+    #[test]
+    fn complete_graph_is_a_single_clique() {
+        let mut solver = Solver::default();
+        let [a, b, c, d] = [0; 4].map(|_| solver.new_bounded_integer(0, 5));
+
+        let cliques = find_cliques(graph(&[(a, b), (a, c), (a, d), (b, c), (b, d), (c, d)]));
+
+        assert_eq!(cliques.len(), 1);
+        let mut clique = cliques[0].clone();
+        clique.sort_by_key(|variable| variable.id);
+        assert_eq!(clique, vec![a, b, c, d]);
+    }
+
+    #[test]
+    fn chains_of_disequalities_are_not_cliques() {
+        let mut solver = Solver::default();
+        let [a, b, c, d] = [0; 4].map(|_| solver.new_bounded_integer(0, 5));
+
+        let cliques = find_cliques(graph(&[(a, b), (b, c), (c, d), (d, a)]));
+
+        assert!(cliques.is_empty());
+    }
+}
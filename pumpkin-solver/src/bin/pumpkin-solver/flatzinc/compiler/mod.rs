@@ -1,9 +1,11 @@
+mod aggregate_linears;
 mod collect_domains;
 mod context;
 mod create_objective;
 mod create_search_strategy;
 mod define_constants;
 mod define_variable_arrays;
+mod detect_all_different;
 mod handle_set_in;
 mod merge_equivalences;
 mod post_constraints;
@@ -30,6 +32,8 @@ pub(crate) fn compile(
     handle_set_in::run(&ast, &mut context)?;
     collect_domains::run(&ast, &mut context)?;
     define_variable_arrays::run(&ast, &mut context)?;
+    detect_all_different::run(&ast, &mut context, options)?;
+    aggregate_linears::run(&ast, &mut context, options)?;
     post_constraints::run(&ast, &mut context, options)?;
     let objective_function = create_objective::run(&ast, &mut context)?;
     let search = create_search_strategy::run(&ast, &mut context)?;
@@ -10,6 +10,7 @@ use pumpkin_solver::variables::AffineView;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 use pumpkin_solver::variables::TransformableVariable;
+use pumpkin_solver::ConstraintOperationError;
 
 use super::context::CompilationContext;
 use crate::flatzinc::ast::FlatZincAst;
@@ -22,7 +23,12 @@ pub(crate) fn run(
     context: &mut CompilationContext,
     options: FlatZincOptions,
 ) -> Result<(), FlatZincError> {
-    for constraint_item in &ast.constraint_decls {
+    for (index, constraint_item) in ast.constraint_decls.iter().enumerate() {
+        // The constraint defines a variable which is aggregated into another linear constraint
+        if context.is_constraint_aggregated(index) {
+            continue;
+        }
+
         let flatzinc::ConstraintItem { id, exprs, annos } = constraint_item;
 
         let is_satisfiable: bool = match id.as_str() {
@@ -81,13 +87,7 @@ pub(crate) fn run(
                 "int_lin_eq_reif",
                 constraints::equals,
             )?,
-            "int_ne" => compile_binary_int_predicate(
-                context,
-                exprs,
-                annos,
-                "int_ne",
-                constraints::binary_not_equals,
-            )?,
+            "int_ne" => compile_int_ne(context, exprs)?,
             "int_ne_reif" => compile_reified_binary_int_predicate(
                 context,
                 exprs,
@@ -142,6 +142,13 @@ pub(crate) fn run(
                 compile_ternary_int_predicate(context, exprs, annos, "int_plus", constraints::plus)?
             }
 
+            "int_times" if has_domain_annotation(annos) => compile_ternary_int_predicate(
+                context,
+                exprs,
+                annos,
+                "int_times",
+                constraints::times_domain_consistent,
+            )?,
             "int_times" => compile_ternary_int_predicate(
                 context,
                 exprs,
@@ -179,13 +186,9 @@ pub(crate) fn run(
 
             "bool2int" => compile_bool2int(context, exprs)?,
 
-            "bool_lin_eq" => {
-                compile_bool_lin_eq_predicate(context, exprs)?
-            }
+            "bool_lin_eq" => compile_bool_lin_eq_predicate(context, exprs)?,
 
-            "bool_lin_le" => {
-                compile_bool_lin_le_predicate(context, exprs)?
-            }
+            "bool_lin_le" => compile_bool_lin_le_predicate(context, exprs)?,
 
             "bool_and" => compile_bool_and(context, exprs)?,
             "bool_clause" => compile_bool_clause(context, exprs)?,
@@ -199,8 +202,17 @@ pub(crate) fn run(
                 true
             }
 
+            "pumpkin_among" => compile_among(context, exprs)?,
+            "pumpkin_count_eq" => compile_count_eq(context, exprs)?,
+            "pumpkin_lex_less" => compile_lex_less(context, exprs, "pumpkin_lex_less", true)?,
+            "pumpkin_lex_lesseq" => compile_lex_less(context, exprs, "pumpkin_lex_lesseq", false)?,
+            "pumpkin_sort" => compile_sort(context, exprs)?,
+            "pumpkin_value_precede" => compile_value_precede(context, exprs)?,
+            "pumpkin_value_precede_chain" => compile_value_precede_chain(context, exprs)?,
+
             "pumpkin_cumulative" => compile_cumulative(context, exprs, &options)?,
-            "pumpkin_cumulative_var" => todo!("The `cumulative` constraint with variable duration/resource consumption/bound is not implemented yet!"),
+            "pumpkin_diffn" => compile_diffn(context, exprs, &options)?,
+            "pumpkin_cumulative_var" => compile_cumulative_var(context, exprs, &options)?,
             unknown => todo!("unsupported constraint {unknown}"),
         };
 
@@ -224,6 +236,28 @@ macro_rules! check_parameters {
     };
 }
 
+/// Returns whether the constraint was posted without the solver becoming infeasible; if the
+/// constraint was rejected because its arithmetic may overflow or because its arguments are
+/// invalid, then an error is returned, since the model cannot be solved without it.
+fn is_posted(
+    result: Result<(), ConstraintOperationError>,
+    constraint_id: &str,
+) -> Result<bool, FlatZincError> {
+    match result {
+        Ok(()) => Ok(true),
+        Err(ConstraintOperationError::IntegerOverflow) => {
+            Err(FlatZincError::IntegerOverflow(constraint_id.into()))
+        }
+        Err(ConstraintOperationError::InvalidArgument(error)) => {
+            Err(FlatZincError::InvalidArgument {
+                constraint_id: constraint_id.into(),
+                error,
+            })
+        }
+        Err(_) => Ok(false),
+    }
+}
+
 fn compile_cumulative(
     context: &mut CompilationContext<'_>,
     exprs: &[flatzinc::Expr],
@@ -244,7 +278,53 @@ fn compile_cumulative(
         options.cumulative_options,
     )
     .post(context.solver, None);
-    Ok(post_result.is_ok())
+    is_posted(post_result, "pumpkin_cumulative")
+}
+
+fn compile_cumulative_var(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+    options: &FlatZincOptions,
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 4, "pumpkin_cumulative_var");
+
+    let start_times = context.resolve_integer_variable_array(&exprs[0])?;
+    let durations = context.resolve_integer_variable_array(&exprs[1])?;
+    let resource_requirements = context.resolve_integer_variable_array(&exprs[2])?;
+    let resource_capacity = context.resolve_integer_constant_from_expr(&exprs[3])?;
+
+    let post_result = constraints::cumulative_with_variable_tasks(
+        start_times.iter().copied(),
+        durations.iter().copied(),
+        resource_requirements.iter().copied(),
+        resource_capacity,
+        options.cumulative_options,
+    )
+    .post(context.solver, None);
+    is_posted(post_result, "pumpkin_cumulative_var")
+}
+
+fn compile_diffn(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+    options: &FlatZincOptions,
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 4, "pumpkin_diffn");
+
+    let x = context.resolve_integer_variable_array(&exprs[0])?;
+    let y = context.resolve_integer_variable_array(&exprs[1])?;
+    let widths = context.resolve_array_integer_constants(&exprs[2])?;
+    let heights = context.resolve_array_integer_constants(&exprs[3])?;
+
+    let post_result = constraints::diffn_with_options(
+        x.iter().copied(),
+        y.iter().copied(),
+        widths.iter().copied(),
+        heights.iter().copied(),
+        options.diffn_options,
+    )
+    .post(context.solver, None);
+    is_posted(post_result, "pumpkin_diffn")
 }
 
 fn compile_array_int_maximum(
@@ -256,9 +336,10 @@ fn compile_array_int_maximum(
     let rhs = context.resolve_integer_variable(&exprs[0])?;
     let array = context.resolve_integer_variable_array(&exprs[1])?;
 
-    Ok(constraints::maximum(array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
-        .is_ok())
+    is_posted(
+        constraints::maximum(array.as_ref().to_owned(), rhs).post(context.solver, None),
+        "array_int_maximum",
+    )
 }
 
 fn compile_array_int_minimum(
@@ -270,9 +351,10 @@ fn compile_array_int_minimum(
     let rhs = context.resolve_integer_variable(&exprs[0])?;
     let array = context.resolve_integer_variable_array(&exprs[1])?;
 
-    Ok(constraints::minimum(array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
-        .is_ok())
+    is_posted(
+        constraints::minimum(array.as_ref().to_owned(), rhs).post(context.solver, None),
+        "array_int_minimum",
+    )
 }
 
 fn compile_set_in_reif(
@@ -329,21 +411,118 @@ fn compile_set_in_reif(
             forward && backward
         }
 
-        Set::Sparse { values } => {
-            let clause = values
-                .iter()
-                .map(|&value| context.solver.get_literal(predicate![variable == value]))
-                .collect::<Vec<_>>();
-
-            constraints::clause(clause)
-                .reify(context.solver, reif, None)
-                .is_ok()
-        }
+        Set::Sparse { values } => is_posted(
+            constraints::member(variable, values.to_vec()).reify(context.solver, reif, None),
+            "set_in_reif",
+        )?,
     };
 
     Ok(success)
 }
 
+fn compile_among(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 3, "pumpkin_among");
+
+    let count = context.resolve_integer_variable(&exprs[0])?;
+    let variables = context.resolve_integer_variable_array(&exprs[1])?.to_vec();
+    let values = match context.resolve_set_constant(&exprs[2])? {
+        Set::Interval {
+            lower_bound,
+            upper_bound,
+        } => (lower_bound..=upper_bound).collect::<Vec<_>>(),
+        Set::Sparse { values } => values.to_vec(),
+    };
+
+    is_posted(
+        constraints::among(count, variables, values).post(context.solver, None),
+        "pumpkin_among",
+    )
+}
+
+fn compile_count_eq(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 3, "pumpkin_count_eq");
+
+    let variables = context.resolve_integer_variable_array(&exprs[0])?.to_vec();
+    let value = context.resolve_integer_constant_from_expr(&exprs[1])?;
+    let count = context.resolve_integer_variable(&exprs[2])?;
+
+    is_posted(
+        constraints::count(value, variables, count).post(context.solver, None),
+        "pumpkin_count_eq",
+    )
+}
+
+fn compile_lex_less(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+    predicate_name: &str,
+    strict: bool,
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, predicate_name);
+
+    let x = context.resolve_integer_variable_array(&exprs[0])?.to_vec();
+    let y = context.resolve_integer_variable_array(&exprs[1])?.to_vec();
+
+    let result = if strict {
+        constraints::lex_less(x, y).post(context.solver, None)
+    } else {
+        constraints::lex_lesseq(x, y).post(context.solver, None)
+    };
+
+    is_posted(result, predicate_name)
+}
+
+fn compile_sort(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "pumpkin_sort");
+
+    let x = context.resolve_integer_variable_array(&exprs[0])?.to_vec();
+    let y = context.resolve_integer_variable_array(&exprs[1])?.to_vec();
+
+    let post_result = constraints::sorted(x, y).post(context.solver, None);
+    is_posted(post_result, "pumpkin_sort")
+}
+
+fn compile_value_precede(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 3, "pumpkin_value_precede");
+
+    let s = context.resolve_integer_constant_from_expr(&exprs[0])?;
+    let t = context.resolve_integer_constant_from_expr(&exprs[1])?;
+    let variables = context.resolve_integer_variable_array(&exprs[2])?.to_vec();
+
+    is_posted(
+        constraints::value_precede(s, t, variables).post(context.solver, None),
+        "pumpkin_value_precede",
+    )
+}
+
+fn compile_value_precede_chain(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "pumpkin_value_precede_chain");
+
+    let values = context.resolve_array_integer_constants(&exprs[0])?;
+    let variables = context.resolve_integer_variable_array(&exprs[1])?.to_vec();
+
+    is_posted(
+        constraints::value_precede_chain(values.iter().copied(), variables)
+            .post(context.solver, None),
+        "pumpkin_value_precede_chain",
+    )
+}
+
 fn compile_array_var_int_element(
     context: &mut CompilationContext<'_>,
     exprs: &[flatzinc::Expr],
@@ -354,9 +533,10 @@ fn compile_array_var_int_element(
     let array = context.resolve_integer_variable_array(&exprs[1])?;
     let rhs = context.resolve_integer_variable(&exprs[2])?;
 
-    Ok(constraints::element(index, array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
-        .is_ok())
+    is_posted(
+        constraints::element(index, array.as_ref().to_owned(), rhs).post(context.solver, None),
+        "array_var_int_element",
+    )
 }
 
 fn compile_bool_not(
@@ -479,9 +659,10 @@ fn compile_bool_or(
     let clause = context.resolve_bool_variable_array(&exprs[0])?;
     let r = context.resolve_bool_variable(&exprs[1])?;
 
-    Ok(constraints::clause(clause.as_ref())
-        .reify(context.solver, r, None)
-        .is_ok())
+    is_posted(
+        constraints::clause(clause.as_ref()).reify(context.solver, r, None),
+        "bool_or",
+    )
 }
 
 fn compile_bool_xor(
@@ -560,9 +741,15 @@ fn compile_array_bool_and(
     let conjunction = context.resolve_bool_variable_array(&exprs[0])?;
     let r = context.resolve_bool_variable(&exprs[1])?;
 
-    Ok(constraints::conjunction(conjunction.as_ref())
-        .reify(context.solver, r, None)
-        .is_ok())
+    is_posted(
+        constraints::conjunction(conjunction.as_ref()).reify(context.solver, r, None),
+        "array_bool_and",
+    )
+}
+
+/// Whether the constraint is annotated with `domain`, which requests domain consistent propagation.
+fn has_domain_annotation(annos: &[flatzinc::Annotation]) -> bool {
+    annos.iter().any(|annotation| annotation.id == "domain")
 }
 
 fn compile_ternary_int_predicate<C: Constraint>(
@@ -579,7 +766,7 @@ fn compile_ternary_int_predicate<C: Constraint>(
     let c = context.resolve_integer_variable(&exprs[2])?;
 
     let constraint = create_constraint(a, b, c);
-    Ok(constraint.post(context.solver, None).is_ok())
+    is_posted(constraint.post(context.solver, None), predicate_name)
 }
 
 fn compile_binary_int_predicate<C: Constraint>(
@@ -595,7 +782,27 @@ fn compile_binary_int_predicate<C: Constraint>(
     let b = context.resolve_integer_variable(&exprs[1])?;
 
     let constraint = create_constraint(a, b);
-    Ok(constraint.post(context.solver, None).is_ok())
+    is_posted(constraint.post(context.solver, None), predicate_name)
+}
+
+fn compile_int_ne(
+    context: &mut CompilationContext,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "int_ne");
+
+    let a = context.resolve_integer_variable(&exprs[0])?;
+    let b = context.resolve_integer_variable(&exprs[1])?;
+
+    // The disequality is already enforced by a detected all-different constraint
+    if context.is_disequality_covered(a, b) {
+        return Ok(true);
+    }
+
+    is_posted(
+        constraints::binary_not_equals(a, b).post(context.solver, None),
+        "int_ne",
+    )
 }
 
 fn compile_reified_binary_int_predicate<C: NegatableConstraint>(
@@ -612,14 +819,30 @@ fn compile_reified_binary_int_predicate<C: NegatableConstraint>(
     let reif = context.resolve_bool_variable(&exprs[2])?;
 
     let constraint = create_constraint(a, b);
-    Ok(constraint.reify(context.solver, reif, None).is_ok())
+    is_posted(constraint.reify(context.solver, reif, None), predicate_name)
 }
 
-fn weighted_vars(weights: Rc<[i32]>, vars: Rc<[DomainId]>) -> Box<[AffineView<DomainId>]> {
-    vars.iter()
-        .zip(weights.iter())
-        .map(|(x_i, &w_i)| x_i.scaled(w_i))
-        .collect::<Box<[_]>>()
+/// The terms and right-hand side of a linear constraint.
+type LinearTerms = (Box<[AffineView<DomainId>]>, i32);
+
+/// Creates the terms of the linear constraint `sum(weights[i] * vars[i]) <op> rhs`, in which the
+/// aggregated variables are replaced by their definitions; returns the terms together with the
+/// (adjusted) right-hand side.
+fn weighted_vars(
+    context: &CompilationContext,
+    weights: Rc<[i32]>,
+    vars: Rc<[DomainId]>,
+    rhs: i32,
+) -> Result<LinearTerms, FlatZincError> {
+    let (terms, constant) =
+        context.substitute_linear_definitions(weights.iter().copied().zip(vars.iter().copied()))?;
+    let rhs = i32::try_from(rhs as i64 - constant as i64)?;
+
+    let terms = terms
+        .into_iter()
+        .map(|(w_i, x_i)| x_i.scaled(w_i))
+        .collect::<Box<[_]>>();
+    Ok((terms, rhs))
 }
 
 fn compile_int_lin_predicate<C: Constraint>(
@@ -635,10 +858,10 @@ fn compile_int_lin_predicate<C: Constraint>(
     let vars = context.resolve_integer_variable_array(&exprs[1])?;
     let rhs = context.resolve_integer_constant_from_expr(&exprs[2])?;
 
-    let terms = weighted_vars(weights, vars);
+    let (terms, rhs) = weighted_vars(context, weights, vars, rhs)?;
 
     let constraint = create_constraint(terms, rhs);
-    Ok(constraint.post(context.solver, None).is_ok())
+    is_posted(constraint.post(context.solver, None), predicate_name)
 }
 
 fn compile_reified_int_lin_predicate<C: NegatableConstraint>(
@@ -655,10 +878,10 @@ fn compile_reified_int_lin_predicate<C: NegatableConstraint>(
     let rhs = context.resolve_integer_constant_from_expr(&exprs[2])?;
     let reif = context.resolve_bool_variable(&exprs[3])?;
 
-    let terms = weighted_vars(weights, vars);
+    let (terms, rhs) = weighted_vars(context, weights, vars, rhs)?;
 
     let constraint = create_constraint(terms, rhs);
-    Ok(constraint.reify(context.solver, reif, None).is_ok())
+    is_posted(constraint.reify(context.solver, reif, None), predicate_name)
 }
 
 fn compile_bool_lin_eq_predicate(
@@ -671,10 +894,10 @@ fn compile_bool_lin_eq_predicate(
     let bools = context.resolve_bool_variable_array(&exprs[1])?;
     let rhs = context.resolve_integer_variable(&exprs[2])?;
 
-    Ok(
+    is_posted(
         constraints::boolean_equals(weights.as_ref().to_owned(), bools.as_ref().to_owned(), rhs)
-            .post(context.solver, None)
-            .is_ok(),
+            .post(context.solver, None),
+        "bool_lin_eq",
     )
 }
 
@@ -688,13 +911,15 @@ fn compile_bool_lin_le_predicate(
     let bools = context.resolve_bool_variable_array(&exprs[1])?;
     let rhs = context.resolve_integer_constant_from_expr(&exprs[2])?;
 
-    Ok(constraints::boolean_less_than_or_equals(
-        weights.as_ref().to_owned(),
-        bools.as_ref().to_owned(),
-        rhs,
+    is_posted(
+        constraints::boolean_less_than_or_equals(
+            weights.as_ref().to_owned(),
+            bools.as_ref().to_owned(),
+            rhs,
+        )
+        .post(context.solver, None),
+        "bool_lin_le",
     )
-    .post(context.solver, None)
-    .is_ok())
 }
 
 fn compile_all_different(
@@ -705,7 +930,8 @@ fn compile_all_different(
     check_parameters!(exprs, 1, "fzn_all_different");
 
     let variables = context.resolve_integer_variable_array(&exprs[0])?.to_vec();
-    Ok(constraints::all_different(variables)
-        .post(context.solver, None)
-        .is_ok())
+    is_posted(
+        constraints::all_different(variables).post(context.solver, None),
+        "fzn_all_different",
+    )
 }
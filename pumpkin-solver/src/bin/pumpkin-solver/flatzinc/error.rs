@@ -1,5 +1,6 @@
 use std::num::TryFromIntError;
 
+use pumpkin_solver::InvalidArgument;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -34,4 +35,13 @@ pub(crate) enum FlatZincError {
 
     #[error("missing solve item")]
     MissingSolveItem,
+
+    #[error("the arithmetic of constraint {0} may overflow")]
+    IntegerOverflow(Box<str>),
+
+    #[error("the arguments of constraint {constraint_id} are invalid: {error}")]
+    InvalidArgument {
+        constraint_id: Box<str>,
+        error: InvalidArgument,
+    },
 }
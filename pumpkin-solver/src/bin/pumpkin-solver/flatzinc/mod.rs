@@ -15,7 +15,10 @@ use pumpkin_solver::branching::branchers::alternating_brancher::AlternatingStrat
 use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
 #[cfg(doc)]
 use pumpkin_solver::constraints::cumulative;
+#[cfg(doc)]
+use pumpkin_solver::constraints::diffn;
 use pumpkin_solver::options::CumulativeOptions;
+use pumpkin_solver::options::DiffnOptions;
 use pumpkin_solver::predicate;
 use pumpkin_solver::predicates::Predicate;
 use pumpkin_solver::results::solution_iterator::IteratedSolution;
@@ -45,8 +48,18 @@ pub(crate) struct FlatZincOptions {
     /// the solver to print intermediate solutions.
     pub(crate) all_solutions: bool,
 
+    /// If `true`, cliques of pairwise disequalities are replaced by an all-different constraint.
+    pub(crate) detect_all_different: bool,
+
+    /// If `true`, variables defined by a small linear equality are substituted into the linear
+    /// constraint which uses them.
+    pub(crate) aggregate_linear_constraints: bool,
+
     /// Options used for the cumulative constraint (see [`cumulative`]).
     pub(crate) cumulative_options: CumulativeOptions,
+
+    /// Options used for the diffn constraint (see [`diffn`]).
+    pub(crate) diffn_options: DiffnOptions,
 }
 
 #[cfg(test)]
@@ -56,7 +69,10 @@ impl Default for FlatZincOptions {
         Self {
             free_search: false,
             all_solutions: false,
+            detect_all_different: true,
+            aggregate_linear_constraints: true,
             cumulative_options: CumulativeOptions::default(),
+            diffn_options: DiffnOptions::default(),
         }
     }
 }
@@ -130,7 +146,8 @@ pub(crate) fn solve(
                 println!("==========");
                 Some(optimal_objective_value)
             }
-            OptimisationResult::Satisfiable(solution) => {
+            OptimisationResult::Satisfiable(solution)
+            | OptimisationResult::TargetReached(solution) => {
                 let best_found_objective_value =
                     solution.get_integer_value(*objective_function.get_domain());
                 Some(best_found_objective_value)
@@ -171,14 +188,14 @@ pub(crate) fn solve(
         } else {
             match solver.satisfy(&mut brancher, &mut termination) {
                 SatisfactionResult::Satisfiable(_) => {}
-                SatisfactionResult::Unsatisfiable => {
+                SatisfactionResult::Unsatisfiable(_) => {
                     if solver.conclude_proof_unsat().is_err() {
                         warn!("Failed to log solver conclusion");
                     };
 
                     println!("{MSG_UNSATISFIABLE}");
                 }
-                SatisfactionResult::Unknown => {
+                SatisfactionResult::Unknown(_) => {
                     println!("{MSG_UNKNOWN}");
                 }
             }
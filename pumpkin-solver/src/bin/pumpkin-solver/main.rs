@@ -41,7 +41,9 @@ use result::PumpkinError;
 use result::PumpkinResult;
 
 use crate::flatzinc::FlatZincOptions;
+use crate::maxsat::optimisation::linear_search::OptimisationStrategy;
 use crate::maxsat::wcnf_problem;
+use crate::maxsat::MaxSatAlgorithm;
 
 #[derive(Debug, Parser)]
 #[command(
@@ -80,6 +82,36 @@ struct Args {
     #[arg(long, default_value_t = ProofType::Scaffold)]
     proof_type: ProofType,
 
+    /// Remove the steps from the proof which are not needed to derive its conclusion, once the
+    /// proof is concluded.
+    ///
+    /// Trimming follows the propagation hints of the nogoods, so it only has an effect with the
+    /// `with-hints` proof type.
+    #[arg(long)]
+    trim_proof: bool,
+
+    /// The output path for the implication graph of the conflict given by
+    /// `implication_graph_conflict`, which is written in the DOT format.
+    ///
+    /// Note that the graph is not written if the conflict is not analysed (see
+    /// `learning_max_decision_level`).
+    #[arg(long, verbatim_doc_comment)]
+    implication_graph_path: Option<PathBuf>,
+
+    /// The number of the conflict (counting from 1) of which the implication graph is written.
+    ///
+    /// If the `implication_graph_path` option is not provided, this is ignored.
+    #[arg(long, default_value_t = 1)]
+    implication_graph_conflict: u64,
+
+    /// Compute the nogoods which the last UIP and all-decision learning schemes derive from every
+    /// analysed conflict and log how they compare to the learned (first UIP) nogoods as part of
+    /// the statistics; this does not affect the search.
+    ///
+    /// This is ignored when a proof is logged.
+    #[arg(long)]
+    compare_learning_schemes: bool,
+
     /// The number of high lbd learned clauses that are kept in the database.
     /// Learned clauses are kept based on the tiered system introduced in "Improving
     /// SAT Solvers by Exploiting Empirical Characteristics of CDCL - Chanseok Oh (2016)".
@@ -114,6 +146,17 @@ struct Args {
     )]
     learning_sorting_strategy: LearnedClauseSortingStrategy,
 
+    /// The maximum decision level at which clauses are learned from conflicts; if a conflict
+    /// occurs at a deeper decision level, then the solver backtracks chronologically instead. If
+    /// the value is 0, then no clauses are learned at all (i.e. the solver performs classical
+    /// depth-first search). By default, clauses are learned at every decision level.
+    ///
+    /// This option cannot be combined with proof logging.
+    ///
+    /// Possible values: usize
+    #[arg(long = "learning-max-decision-level", verbatim_doc_comment)]
+    learning_max_decision_level: Option<usize>,
+
     /// Decides whether learned clauses are minimised as a post-processing step after computing the
     /// 1-UIP Minimisation is done; according to the idea proposed in "Generalized Conflict-Clause
     /// Strengthening for Satisfiability Solvers - Allen van Gelder (2011)".
@@ -244,6 +287,50 @@ struct Args {
     )]
     random_seed: u64,
 
+    /// The number of conflicts which may be spent on destructive lower-bounding of the objective
+    /// before the search of an optimisation problem; i.e. refuting `objective <= b` for
+    /// increasing values of `b`. A value of 0 disables destructive lower-bounding.
+    ///
+    /// Possible values: u64
+    #[arg(
+        long = "destructive-bounding-conflict-budget",
+        default_value_t = 1000,
+        verbatim_doc_comment
+    )]
+    destructive_bounding_conflict_budget: u64,
+
+    /// The number of conflicts which may be spent on polishing every solution of an optimisation
+    /// problem; i.e. searching for a better solution in which only the most active variables can
+    /// change. A value of 0 disables polishing.
+    ///
+    /// Possible values: u64
+    #[arg(
+        long = "polishing-conflict-budget",
+        default_value_t = 0,
+        verbatim_doc_comment
+    )]
+    polishing_conflict_budget: u64,
+
+    /// The number of variables which can change their value when polishing a solution.
+    ///
+    /// Possible values: usize
+    #[arg(
+        long = "polishing-window-size",
+        default_value_t = 8,
+        verbatim_doc_comment
+    )]
+    polishing_window_size: usize,
+
+    /// Determines whether propagators are checked for integer overflow when they are posted; a
+    /// constraint whose arithmetic may overflow is then rejected with an error rather than
+    /// silently wrapping around during search.
+    ///
+    /// By default, this is enabled in debug builds and disabled in release builds.
+    ///
+    /// Possible values: bool
+    #[arg(long = "checked-arithmetic", verbatim_doc_comment)]
+    checked_arithmetic: Option<bool>,
+
     /// Enables log message output from the solver.
     ///
     /// For printing statistics see the option "--log-statistics", and for printing all solutions
@@ -281,6 +368,21 @@ struct Args {
     #[arg(short = 'a', long = "all-solutions", verbatim_doc_comment)]
     all_solutions: bool,
 
+    /// Disables the detection of cliques of pairwise disequalities (`int_ne`) when solving a
+    /// MiniZinc model; by default, such cliques are replaced by an all-different constraint.
+    ///
+    /// Possible values: bool
+    #[arg(long = "no-all-different-detection", verbatim_doc_comment)]
+    no_all_different_detection: bool,
+
+    /// Disables the aggregation of linear constraints when solving a MiniZinc model; by default,
+    /// a variable which is defined by a small linear equality and which is used in a single
+    /// other linear constraint is replaced by its definition.
+    ///
+    /// Possible values: bool
+    #[arg(long = "no-linear-aggregation", verbatim_doc_comment)]
+    no_linear_aggregation: bool,
+
     /// If `--verbose` is enabled then this option removes the timestamp information from the log
     /// messages. Note that this option will only take affect in the case of a (W)CNF instance.
     ///
@@ -309,6 +411,31 @@ struct Args {
     )]
     upper_bound_encoding: PseudoBooleanEncoding,
 
+    /// The algorithm which is used to solve a MaxSAT optimisation problem.
+    ///
+    /// The "linear-search" value specifies that the solver repeatedly looks for a better solution
+    /// using the upper bound encoding, and the "core-guided" value specifies that the solver uses
+    /// the OLL algorithm (see "Core-guided MaxSAT with soft cardinality constraints - Morgado et
+    /// al. (2014)").
+    #[arg(
+        long = "maxsat-algorithm",
+        default_value_t = MaxSatAlgorithm::LinearSearch, verbatim_doc_comment
+    )]
+    maxsat_algorithm: MaxSatAlgorithm,
+
+    /// The direction in which the "linear-search" MaxSAT algorithm searches for the optimal
+    /// objective value.
+    ///
+    /// The "upper-bounding" value specifies that the solver repeatedly looks for a better solution
+    /// than the best solution found so far, and the "lower-bounding" value specifies that the
+    /// solver performs a binary search on the objective value, where every refuted bound improves
+    /// the lower-bound.
+    #[arg(
+        long = "optimisation-strategy",
+        default_value_t = OptimisationStrategy::UpperBounding, verbatim_doc_comment
+    )]
+    optimisation_strategy: OptimisationStrategy,
+
     /// Determines that the cumulative propagator(s) are allowed to create holes in the domain.
     ///
     /// Possible values: bool
@@ -320,6 +447,22 @@ struct Args {
     /// Possible values: bool
     #[arg(long = "no-restarts", verbatim_doc_comment)]
     no_restarts: bool,
+
+    /// Determines that the solver reuses the part of the trail upon a restart which consists of
+    /// the decisions that the brancher would make again, rather than backtracking to the root.
+    ///
+    /// Possible values: bool
+    #[arg(long = "restart-reuse-trail", verbatim_doc_comment)]
+    restart_reuse_trail: bool,
+
+    /// Determines that the restart sequence starts over whenever a new incumbent is found during
+    /// optimisation, such that the solver restarts frequently while searching around the new
+    /// incumbent.
+    ///
+    /// Possible values: bool
+    #[arg(long = "restart-on-new-incumbent", verbatim_doc_comment)]
+    restart_on_new_incumbent: bool,
+
     /// Determines the type of explanation used by the cumulative propagator(s) to explain
     /// propagations/conflicts.
     #[arg(long = "cumulative-explanation-type", default_value_t = CumulativeExplanationType::default())]
@@ -345,6 +488,46 @@ struct Args {
     /// Possible values: bool
     #[arg(long = "cumulative-incremental-backtracking")]
     cumulative_incremental_backtracking: bool,
+
+    /// Determines whether the cumulative constraint is additionally propagated using energetic
+    /// reasoning, which is stronger but more expensive than time-tabling.
+    ///
+    /// Possible values: bool
+    #[arg(long = "cumulative-energetic-reasoning")]
+    cumulative_energetic_reasoning: bool,
+
+    /// Determines whether the cumulative constraint is additionally propagated using
+    /// edge-finding, which (like energetic reasoning) also reasons over the parts of the tasks
+    /// which are not mandatory.
+    ///
+    /// Possible values: bool
+    #[arg(long = "cumulative-edge-finding")]
+    cumulative_edge_finding: bool,
+
+    /// Determines whether the cumulative constraint is additionally propagated using time-table
+    /// edge-finding, which combines the time-table with the energy of the tasks which have to be
+    /// processed within a time window.
+    ///
+    /// Possible values: bool
+    #[arg(long = "cumulative-time-table-edge-finding")]
+    cumulative_time_table_edge_finding: bool,
+
+    /// Determines whether the cumulative constraint is propagated using a specialised propagator
+    /// when the tasks allow it; a cumulative constraint of which no two tasks can execute at the
+    /// same time is then propagated as a disjunctive constraint, and a cumulative constraint of
+    /// which all tasks have unit duration is propagated by bounding the resource usage at the
+    /// time points at which the tasks can overload the resource.
+    ///
+    /// Possible values: bool
+    #[arg(long = "cumulative-specialisation")]
+    cumulative_specialisation: bool,
+
+    /// Determines whether the diffn constraint is additionally propagated using a cumulative
+    /// constraint over the projection of the rectangles onto either axis.
+    ///
+    /// Possible values: bool
+    #[arg(long = "diffn-cumulative-relaxations")]
+    diffn_cumulative_relaxations: bool,
 }
 
 fn configure_logging(
@@ -479,10 +662,17 @@ fn run() -> PumpkinResult<()> {
         num_high_lbd_learned_clauses_max: args.learning_max_num_clauses,
         high_lbd_learned_clause_sorting_strategy: args.learning_sorting_strategy,
         lbd_threshold: args.learning_lbd_threshold,
+        max_learning_decision_level: args.learning_max_decision_level,
         ..Default::default()
     };
 
     let proof_log = if let Some(path_buf) = args.proof_path {
+        if args.learning_max_decision_level.is_some() {
+            return Err(PumpkinError::ProofGenerationNotSupported(
+                "solving with limited clause learning".to_owned(),
+            ));
+        }
+
         match file_format {
             FileFormat::CnfDimacsPLine => ProofLog::dimacs(&path_buf)?,
             FileFormat::WcnfDimacsPLine => {
@@ -492,7 +682,12 @@ fn run() -> PumpkinResult<()> {
                 let log_inferences =
                     matches!(args.proof_type, ProofType::Full | ProofType::WithHints);
                 let log_hints = matches!(args.proof_type, ProofType::WithHints);
-                ProofLog::cp(&path_buf, Format::Text, log_inferences, log_hints)?
+                let proof_log = ProofLog::cp(&path_buf, Format::Text, log_inferences, log_hints)?;
+                if args.trim_proof {
+                    proof_log.with_trimming()
+                } else {
+                    proof_log
+                }
             }
         }
     } else {
@@ -510,10 +705,33 @@ fn run() -> PumpkinResult<()> {
             num_assigned_window: args.restart_num_assigned_window,
             geometric_coef: args.restart_geometric_coef,
             no_restarts: args.no_restarts,
+            reuse_trail: args.restart_reuse_trail,
+            restart_on_new_incumbent: args.restart_on_new_incumbent,
         },
         proof_log,
         learning_clause_minimisation: !args.no_learning_clause_minimisation,
         random_generator: SmallRng::seed_from_u64(args.random_seed),
+        destructive_bounding_conflict_budget: args.destructive_bounding_conflict_budget,
+        checked_arithmetic: args.checked_arithmetic.unwrap_or(cfg!(debug_assertions)),
+        // Symmetries cannot be declared in the supported file formats
+        max_symmetric_images: 0,
+        // The branchers which are used for the supported file formats do not use the lookahead
+        lookahead_budget: 0,
+        implication_graph_export: args
+            .implication_graph_path
+            .map(|path| ImplicationGraphExport {
+                conflict: args.implication_graph_conflict,
+                path,
+            }),
+        // The activity-based brancher is not used for the supported file formats
+        activity_based_search: ActivityBasedSearchOptions::default(),
+        compare_learning_schemes: args.compare_learning_schemes,
+        solution_polishing: SolutionPolishingOptions {
+            conflict_budget: args.polishing_conflict_budget,
+            window_size: args.polishing_window_size,
+        },
+        // Hints cannot be declared in the supported file formats
+        hint_probe_conflict_budget: 0,
     };
 
     let time_limit = args.time_limit.map(Duration::from_millis);
@@ -532,6 +750,8 @@ fn run() -> PumpkinResult<()> {
             time_limit,
             instance_path,
             args.upper_bound_encoding,
+            args.maxsat_algorithm,
+            args.optimisation_strategy,
         )?,
         FileFormat::FlatZinc => flatzinc::solve(
             Solver::with_options(learning_options, solver_options),
@@ -540,13 +760,22 @@ fn run() -> PumpkinResult<()> {
             FlatZincOptions {
                 free_search: args.free_search,
                 all_solutions: args.all_solutions,
+                detect_all_different: !args.no_all_different_detection,
+                aggregate_linear_constraints: !args.no_linear_aggregation,
                 cumulative_options: CumulativeOptions::new(
                     args.cumulative_allow_holes,
                     args.cumulative_explanation_type,
                     args.cumulative_generate_sequence,
                     args.cumulative_propagation_method,
                     args.cumulative_incremental_backtracking,
+                    args.cumulative_energetic_reasoning,
+                    args.cumulative_edge_finding,
+                    args.cumulative_time_table_edge_finding,
+                    args.cumulative_specialisation,
                 ),
+                diffn_options: DiffnOptions {
+                    cumulative_relaxations: args.diffn_cumulative_relaxations,
+                },
             },
         )?,
     }
@@ -579,7 +808,7 @@ fn cnf_problem(
                 stringify_solution(&solution, num_propositional_variables, true)
             );
         }
-        SatisfactionResult::Unsatisfiable => {
+        SatisfactionResult::Unsatisfiable(_) => {
             solver.log_statistics();
             if solver.conclude_proof_unsat().is_err() {
                 warn!("Failed to log solver conclusion");
@@ -587,7 +816,7 @@ fn cnf_problem(
 
             println!("s UNSATISFIABLE");
         }
-        SatisfactionResult::Unknown => {
+        SatisfactionResult::Unknown(_) => {
             solver.log_statistics();
             println!("s UNKNOWN");
         }
@@ -1,10 +1,15 @@
+use std::fmt::Display;
 use std::fs::File;
 use std::path::Path;
 use std::time::Duration;
 pub(crate) mod optimisation;
 
+use clap::ValueEnum;
+use optimisation::core_guided::CoreGuidedSearch;
 use optimisation::linear_search::LinearSearch;
+use optimisation::linear_search::OptimisationStrategy;
 use optimisation::optimisation_result::MaxSatOptimisationResult;
+use optimisation::optimisation_solver::OptimisationProcedure;
 use optimisation::optimisation_solver::OptimisationSolver;
 use pumpkin_solver::encodings::PseudoBooleanEncoding;
 use pumpkin_solver::options::LearningOptions;
@@ -18,12 +23,33 @@ use crate::parsers::dimacs::WcnfInstance;
 use crate::result::PumpkinError;
 use crate::stringify_solution;
 
+/// The algorithm which is used to solve a MaxSAT problem.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum MaxSatAlgorithm {
+    /// Repeatedly constrains the objective to be better than the best solution found so far.
+    LinearSearch,
+    /// Core-guided search using the OLL algorithm, which increases a lower-bound based on
+    /// unsatisfiable cores.
+    CoreGuided,
+}
+
+impl Display for MaxSatAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxSatAlgorithm::LinearSearch => write!(f, "linear-search"),
+            MaxSatAlgorithm::CoreGuided => write!(f, "core-guided"),
+        }
+    }
+}
+
 pub(crate) fn wcnf_problem(
     learning_options: LearningOptions,
     solver_options: SolverOptions,
     time_limit: Option<Duration>,
     instance_path: impl AsRef<Path>,
     upper_bound_encoding: PseudoBooleanEncoding,
+    algorithm: MaxSatAlgorithm,
+    strategy: OptimisationStrategy,
 ) -> Result<(), PumpkinError> {
     let instance_file = File::open(instance_path)?;
     let WcnfInstance {
@@ -37,11 +63,13 @@ pub(crate) fn wcnf_problem(
 
     let brancher = solver.default_brancher_over_all_propositional_variables();
 
-    let mut solver = OptimisationSolver::new(
-        solver,
-        objective_function,
-        LinearSearch::new(upper_bound_encoding),
-    );
+    let procedure = match algorithm {
+        MaxSatAlgorithm::LinearSearch => {
+            OptimisationProcedure::LinearSearch(LinearSearch::new(upper_bound_encoding, strategy))
+        }
+        MaxSatAlgorithm::CoreGuided => OptimisationProcedure::CoreGuided(CoreGuidedSearch),
+    };
+    let mut solver = OptimisationSolver::new(solver, objective_function, procedure);
 
     let mut termination = time_limit.map(TimeBudget::starting_now);
 
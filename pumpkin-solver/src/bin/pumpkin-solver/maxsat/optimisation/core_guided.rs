@@ -0,0 +1,305 @@
+use log::info;
+use pumpkin_solver::branching::Brancher;
+use pumpkin_solver::containers::HashSet;
+use pumpkin_solver::encodings::Function;
+use pumpkin_solver::results::SatisfactionResultUnderAssumptions;
+use pumpkin_solver::results::Solution;
+use pumpkin_solver::termination::TerminationCondition;
+use pumpkin_solver::variables::Literal;
+use pumpkin_solver::Solver;
+
+use super::optimisation_result::MaxSatOptimisationResult;
+use super::stopwatch::Stopwatch;
+
+/// A soft literal of the (reformulated) objective; the solver is penalised by `weight` if the
+/// literal is true.
+#[derive(Debug, Clone, Copy)]
+struct SoftLiteral {
+    literal: Literal,
+    weight: u64,
+    /// If the literal is an output of a totalizer (i.e. it represents `sum >= k`), then this is
+    /// the index of the totalizer and `k`; the output for `sum >= k + 1` becomes soft once
+    /// this literal is relaxed.
+    totalizer_output: Option<(usize, usize)>,
+}
+
+/// Core-guided optimisation using the OLL algorithm \[1\].
+///
+/// The objective is minimised by solving under the assumptions that none of the soft literals are
+/// true. Every unsatisfiable core (i.e. a set of soft literals of which at least one is true)
+/// increases the lower-bound by the minimum weight in the core; the core is then relaxed by
+/// introducing a totalizer over its literals, of which the outputs (`sum >= 2`, `sum >= 3`, ...)
+/// are added as new soft literals. The first solution which is found under the assumptions is
+/// optimal.
+///
+/// # Bibliography
+/// \[1\] A. Morgado, C. Dodaro, and J. Marques-Silva, ‘Core-guided MaxSAT with soft cardinality
+/// constraints’, in International Conference on Principles and Practice of Constraint
+/// Programming, 2014, pp. 564–573.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct CoreGuidedSearch;
+
+impl CoreGuidedSearch {
+    pub(crate) fn solve(
+        &self,
+        solver: &mut Solver,
+        process_time: Stopwatch,
+        objective_function: &Function,
+        termination: &mut impl TerminationCondition,
+        mut brancher: impl Brancher,
+        initial_solution: Solution,
+    ) -> MaxSatOptimisationResult {
+        let best_objective_value = objective_function.evaluate_assignment(&initial_solution);
+        solver.log_statistics_with_objective(best_objective_value as i64);
+        println!("o {}", best_objective_value);
+
+        // An integer `x` contributes `lb(x) + [x >= lb(x) + 1] + ... + [x >= ub(x)]`
+        let mut lower_bound = objective_function.get_constant_term()
+            + objective_function
+                .get_weighted_integers()
+                .map(|(domain_id, weight)| weight * solver.lower_bound(domain_id) as u64)
+                .sum::<u64>();
+        let mut soft_literals = objective_function
+            .get_function_as_weighted_literals_vector(solver)
+            .into_iter()
+            .filter(|weighted_literal| weighted_literal.weight > 0)
+            .map(|weighted_literal| SoftLiteral {
+                literal: weighted_literal.literal,
+                weight: weighted_literal.weight,
+                totalizer_output: None,
+            })
+            .collect::<Vec<_>>();
+        let mut totalizers: Vec<Vec<Literal>> = vec![];
+
+        loop {
+            if lower_bound == best_objective_value {
+                solver.log_statistics_with_objective(best_objective_value as i64);
+                return MaxSatOptimisationResult::Optimal {
+                    solution: initial_solution,
+                };
+            }
+
+            let assumptions = soft_literals
+                .iter()
+                .map(|soft_literal| !soft_literal.literal)
+                .collect::<Vec<_>>();
+
+            // The result borrows the solver, so the core (or the solution) is extracted first
+            let core =
+                match solver.satisfy_under_assumptions(&mut brancher, termination, &assumptions) {
+                    SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                        Err(Some(solution))
+                    }
+                    SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
+                        mut unsatisfiable,
+                    ) => Ok(unsatisfiable.extract_core()),
+                    // The relaxation of the cores does not remove any solutions, so this can only
+                    // occur if the search was interrupted
+                    SatisfactionResultUnderAssumptions::Unsatisfiable
+                    | SatisfactionResultUnderAssumptions::Unknown => Err(None),
+                };
+            let core = match core {
+                Ok(core) => core,
+                Err(Some(solution)) => {
+                    let objective_value = objective_function.evaluate_assignment(&solution);
+                    solver.log_statistics_with_objective(objective_value as i64);
+                    println!("o {}", objective_value);
+                    return MaxSatOptimisationResult::Optimal { solution };
+                }
+                Err(None) => {
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    return MaxSatOptimisationResult::Satisfiable {
+                        best_solution: initial_solution,
+                    };
+                }
+            };
+
+            let in_core = core
+                .iter()
+                .map(|&assumption| !assumption)
+                .collect::<HashSet<_>>();
+            let core_indices = soft_literals
+                .iter()
+                .enumerate()
+                .filter(|(_, soft_literal)| in_core.contains(&soft_literal.literal))
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            let min_weight = core_indices
+                .iter()
+                .map(|&index| soft_literals[index].weight)
+                .min()
+                .expect("a core is never empty");
+            lower_bound += min_weight;
+            info!(
+                "Found a core of size {} after {} seconds, the lower-bound is now {}",
+                core_indices.len(),
+                process_time.elapsed().as_secs(),
+                lower_bound,
+            );
+
+            let mut new_soft_literals = vec![];
+            for &index in &core_indices {
+                soft_literals[index].weight -= min_weight;
+
+                // Once `sum >= k` is relaxed, the next output of the totalizer is penalised
+                if let Some((totalizer, k)) = soft_literals[index].totalizer_output {
+                    if let Some(&output) = totalizers[totalizer].get(k) {
+                        new_soft_literals.push(SoftLiteral {
+                            literal: output,
+                            weight: min_weight,
+                            totalizer_output: Some((totalizer, k + 1)),
+                        });
+                    }
+                }
+            }
+
+            // The core implies that `sum >= 1`, so the first soft output is `sum >= 2`
+            let core_literals = core_indices
+                .iter()
+                .map(|&index| soft_literals[index].literal)
+                .collect::<Vec<_>>();
+            let outputs = encode_totalizer(solver, &core_literals);
+            if let Some(&output) = outputs.get(1) {
+                new_soft_literals.push(SoftLiteral {
+                    literal: output,
+                    weight: min_weight,
+                    totalizer_output: Some((totalizers.len(), 2)),
+                });
+            }
+            totalizers.push(outputs);
+
+            soft_literals.retain(|soft_literal| soft_literal.weight > 0);
+            for new_soft_literal in new_soft_literals {
+                // An output which is already soft is penalised by the sum of the weights
+                match soft_literals
+                    .iter_mut()
+                    .find(|soft_literal| soft_literal.literal == new_soft_literal.literal)
+                {
+                    Some(soft_literal) => soft_literal.weight += new_soft_literal.weight,
+                    None => soft_literals.push(new_soft_literal),
+                }
+            }
+        }
+    }
+}
+
+/// Creates the outputs `o_1, ..., o_n` of a totalizer over the provided `literals`, such that `o_k`
+/// is true if at least `k` of the `literals` are true; the converse is not enforced, as the
+/// outputs are only used as soft literals which the solver prefers to be false.
+fn encode_totalizer(solver: &mut Solver, literals: &[Literal]) -> Vec<Literal> {
+    if literals.len() <= 1 {
+        return literals.to_vec();
+    }
+
+    let (left, right) = literals.split_at(literals.len() / 2);
+    let left = encode_totalizer(solver, left);
+    let right = encode_totalizer(solver, right);
+
+    let outputs = solver
+        .new_literals()
+        .take(literals.len())
+        .collect::<Vec<_>>();
+    for (i, &left_literal) in left.iter().enumerate() {
+        let _ = solver.add_clause([!left_literal, outputs[i]]);
+        for (j, &right_literal) in right.iter().enumerate() {
+            let _ = solver.add_clause([!left_literal, !right_literal, outputs[i + j + 1]]);
+        }
+    }
+    for (j, &right_literal) in right.iter().enumerate() {
+        let _ = solver.add_clause([!right_literal, outputs[j]]);
+    }
+
+    outputs
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_solver::results::ProblemSolution;
+    use pumpkin_solver::results::SatisfactionResult;
+    use pumpkin_solver::termination::Indefinite;
+
+    use super::*;
+
+    #[test]
+    fn totalizer_outputs_are_implied_by_the_number_of_true_inputs() {
+        let mut solver = Solver::default();
+        let inputs = solver.new_literals().take(3).collect::<Vec<_>>();
+        let outputs = encode_totalizer(&mut solver, &inputs);
+        assert_eq!(outputs.len(), inputs.len());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        for assignment in 0..1_usize << inputs.len() {
+            let num_true_inputs = assignment.count_ones() as usize;
+            let input_assumptions = inputs
+                .iter()
+                .enumerate()
+                .map(|(index, &input)| {
+                    if assignment & (1 << index) != 0 {
+                        input
+                    } else {
+                        !input
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for (index, &output) in outputs.iter().enumerate() {
+                let mut assumptions = input_assumptions.clone();
+                assumptions.push(!output);
+
+                // The output `o_k` is forced to be true if at least `k` inputs are true
+                let is_forced = index < num_true_inputs;
+                let result =
+                    solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &assumptions);
+                assert_eq!(
+                    matches!(
+                        result,
+                        SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+                    ),
+                    is_forced,
+                    "output {} with {num_true_inputs} true inputs",
+                    index + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn optimum_of_small_instance_is_found() {
+        let mut solver = Solver::default();
+        let literals = solver.new_literals().take(3).collect::<Vec<_>>();
+
+        // At least two of the literals are true
+        let _ = solver.add_clause([literals[0], literals[1]]);
+        let _ = solver.add_clause([literals[1], literals[2]]);
+        let _ = solver.add_clause([literals[0], literals[2]]);
+
+        let mut objective_function = Function::default();
+        objective_function.add_weighted_literal(literals[0], 2);
+        objective_function.add_weighted_literal(literals[1], 3);
+        objective_function.add_weighted_literal(literals[2], 4);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let SatisfactionResult::Satisfiable(initial_solution) =
+            solver.satisfy(&mut brancher, &mut Indefinite)
+        else {
+            panic!("the instance is satisfiable");
+        };
+
+        let result = CoreGuidedSearch.solve(
+            &mut solver,
+            Stopwatch::starting_now(),
+            &objective_function,
+            &mut Indefinite,
+            brancher,
+            initial_solution,
+        );
+
+        let MaxSatOptimisationResult::Optimal { solution } = result else {
+            panic!("the optimum is proven");
+        };
+        assert_eq!(objective_function.evaluate_assignment(&solution), 5);
+        assert!(solution.get_literal_value(literals[0]));
+        assert!(solution.get_literal_value(literals[1]));
+        assert!(!solution.get_literal_value(literals[2]));
+    }
+}
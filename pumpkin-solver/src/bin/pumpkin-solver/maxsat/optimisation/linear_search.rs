@@ -1,10 +1,16 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
 use log::info;
 use pumpkin_solver::asserts::pumpkin_assert_moderate;
 use pumpkin_solver::branching::Brancher;
+use pumpkin_solver::constraints;
 use pumpkin_solver::encodings::Function;
 use pumpkin_solver::encodings::PseudoBooleanConstraintEncoder;
 use pumpkin_solver::encodings::PseudoBooleanEncoding;
+use pumpkin_solver::predicate;
 use pumpkin_solver::results::SatisfactionResult;
+use pumpkin_solver::results::SatisfactionResultUnderAssumptions;
 use pumpkin_solver::results::Solution;
 use pumpkin_solver::termination::TerminationCondition;
 use pumpkin_solver::Solver;
@@ -12,19 +18,75 @@ use pumpkin_solver::Solver;
 use super::optimisation_result::MaxSatOptimisationResult;
 use super::stopwatch::Stopwatch;
 
+/// The direction in which [`LinearSearch`] searches for the optimal objective value.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub(crate) enum OptimisationStrategy {
+    /// Repeatedly constrains the objective to be better than the best solution found so far, such
+    /// that every iteration improves the upper-bound until no better solution exists.
+    #[default]
+    UpperBounding,
+    /// Performs a binary search on the objective value between the lower-bound and the best
+    /// solution found so far; every refuted bound improves the lower-bound, while every solution
+    /// improves the upper-bound.
+    LowerBounding,
+}
+
+impl Display for OptimisationStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimisationStrategy::UpperBounding => write!(f, "upper-bounding"),
+            OptimisationStrategy::LowerBounding => write!(f, "lower-bounding"),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct LinearSearch {
     upper_bound_encoding: PseudoBooleanEncoding,
+    strategy: OptimisationStrategy,
 }
 
 impl LinearSearch {
-    pub(crate) fn new(upper_bound_encoding: PseudoBooleanEncoding) -> LinearSearch {
+    pub(crate) fn new(
+        upper_bound_encoding: PseudoBooleanEncoding,
+        strategy: OptimisationStrategy,
+    ) -> LinearSearch {
         LinearSearch {
             upper_bound_encoding,
+            strategy,
         }
     }
 
     pub(crate) fn solve(
+        &self,
+        solver: &mut Solver,
+        process_time: Stopwatch,
+        objective_function: &Function,
+        termination: &mut impl TerminationCondition,
+        brancher: impl Brancher,
+        initial_solution: Solution,
+    ) -> MaxSatOptimisationResult {
+        match self.strategy {
+            OptimisationStrategy::UpperBounding => self.solve_upper_bounding(
+                solver,
+                process_time,
+                objective_function,
+                termination,
+                brancher,
+                initial_solution,
+            ),
+            OptimisationStrategy::LowerBounding => self.solve_lower_bounding(
+                solver,
+                process_time,
+                objective_function,
+                termination,
+                brancher,
+                initial_solution,
+            ),
+        }
+    }
+
+    fn solve_upper_bounding(
         &self,
         solver: &mut Solver,
         process_time: Stopwatch,
@@ -96,14 +158,147 @@ impl LinearSearch {
                         process_time.elapsed().as_millis(),
                     );
                 }
-                SatisfactionResult::Unsatisfiable => {
+                SatisfactionResult::Unsatisfiable(_) => {
                     solver.log_statistics_with_objective(best_objective_value as i64);
 
                     return MaxSatOptimisationResult::Optimal {
                         solution: best_solution,
                     };
                 }
-                SatisfactionResult::Unknown => {
+                SatisfactionResult::Unknown(_) => {
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    return MaxSatOptimisationResult::Satisfiable { best_solution };
+                }
+            }
+        }
+    }
+
+    /// Binary search on the objective value; the objective is linked to an integer variable, of
+    /// which every tested bound is imposed as an assumption.
+    fn solve_lower_bounding(
+        &self,
+        solver: &mut Solver,
+        process_time: Stopwatch,
+        objective_function: &Function,
+        termination: &mut impl TerminationCondition,
+        mut brancher: impl Brancher,
+        initial_solution: Solution,
+    ) -> MaxSatOptimisationResult {
+        let weighted_literals = objective_function.get_function_as_weighted_literals_vector(solver);
+        let total_weight = weighted_literals
+            .iter()
+            .map(|weighted_literal| weighted_literal.weight)
+            .sum::<u64>();
+        if total_weight > i32::MAX as u64 {
+            info!("The objective is too large for lower-bounding, using upper-bounding instead");
+            return self.solve_upper_bounding(
+                solver,
+                process_time,
+                objective_function,
+                termination,
+                brancher,
+                initial_solution,
+            );
+        }
+
+        let mut best_solution: Solution = initial_solution;
+        let mut best_objective_value = objective_function.evaluate_assignment(&best_solution);
+
+        solver.log_statistics_with_objective(best_objective_value as i64);
+        println!("o {}", best_objective_value);
+
+        // The part of the objective which is not captured by the weighted literals
+        let offset = objective_function.get_constant_term()
+            + objective_function
+                .get_weighted_integers()
+                .map(|(domain_id, weight)| weight * solver.lower_bound(domain_id) as u64)
+                .sum::<u64>();
+        let objective = solver.new_bounded_integer(0, total_weight as i32);
+        if solver
+            .add_constraint(constraints::boolean_equals(
+                weighted_literals
+                    .iter()
+                    .map(|weighted_literal| weighted_literal.weight as i32)
+                    .collect::<Vec<_>>(),
+                weighted_literals
+                    .iter()
+                    .map(|weighted_literal| weighted_literal.literal)
+                    .collect::<Vec<_>>(),
+                objective,
+            ))
+            .post()
+            .is_err()
+        {
+            solver.log_statistics_with_objective(best_objective_value as i64);
+            return MaxSatOptimisationResult::Optimal {
+                solution: best_solution,
+            };
+        }
+
+        let mut lower_bound = solver.lower_bound(&objective) as u64;
+        loop {
+            let upper_bound = best_objective_value - offset;
+            if lower_bound >= upper_bound {
+                solver.log_statistics_with_objective(best_objective_value as i64);
+                return MaxSatOptimisationResult::Optimal {
+                    solution: best_solution,
+                };
+            }
+
+            let middle = lower_bound + (upper_bound - lower_bound) / 2;
+            let assumption = solver.get_literal(predicate![objective <= middle as i32]);
+
+            // The result borrows the solver, so the solution is extracted first
+            let result =
+                match solver.satisfy_under_assumptions(&mut brancher, termination, &[assumption]) {
+                    SatisfactionResultUnderAssumptions::Satisfiable(solution) => Ok(Some(solution)),
+                    SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(_)
+                    | SatisfactionResultUnderAssumptions::Unsatisfiable => Ok(None),
+                    SatisfactionResultUnderAssumptions::Unknown => Err(()),
+                };
+
+            match result {
+                Ok(Some(solution)) => {
+                    let new_objective_value = objective_function.evaluate_assignment(&solution);
+
+                    pumpkin_assert_moderate!(
+                        new_objective_value < best_objective_value,
+                        "A solution under the assumed bound must be strictly better."
+                    );
+
+                    best_objective_value = new_objective_value;
+                    best_solution = solution;
+
+                    solver.log_statistics_with_objective(best_objective_value as i64);
+                    println!("o {}", best_objective_value);
+                    info!(
+                        "Current objective is {} after {} seconds ({} ms)",
+                        best_objective_value,
+                        process_time.elapsed().as_secs(),
+                        process_time.elapsed().as_millis(),
+                    );
+                }
+                Ok(None) => {
+                    // No solution exists with an objective value of at most `middle`
+                    lower_bound = middle + 1;
+                    info!(
+                        "The lower-bound is now {} after {} seconds",
+                        lower_bound + offset,
+                        process_time.elapsed().as_secs(),
+                    );
+                    if solver
+                        .add_clause([
+                            solver.get_literal(predicate![objective >= lower_bound as i32])
+                        ])
+                        .is_err()
+                    {
+                        solver.log_statistics_with_objective(best_objective_value as i64);
+                        return MaxSatOptimisationResult::Optimal {
+                            solution: best_solution,
+                        };
+                    }
+                }
+                Err(()) => {
                     solver.log_statistics_with_objective(best_objective_value as i64);
                     return MaxSatOptimisationResult::Satisfiable { best_solution };
                 }
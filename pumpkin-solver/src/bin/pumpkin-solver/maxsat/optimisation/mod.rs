@@ -1,3 +1,4 @@
+pub(crate) mod core_guided;
 pub(crate) mod linear_search;
 pub(crate) mod optimisation_result;
 pub(crate) mod optimisation_solver;
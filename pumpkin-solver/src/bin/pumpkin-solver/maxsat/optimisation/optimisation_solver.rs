@@ -5,29 +5,40 @@ use pumpkin_solver::results::SatisfactionResult;
 use pumpkin_solver::termination::TerminationCondition;
 use pumpkin_solver::Solver;
 
+use super::core_guided::CoreGuidedSearch;
 use super::linear_search::LinearSearch;
 use super::optimisation_result::MaxSatOptimisationResult;
 use super::stopwatch::Stopwatch;
 
+/// The procedure which is used by the [`OptimisationSolver`] to improve upon the initial solution.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum OptimisationProcedure {
+    /// Repeatedly looks for a better solution than the best solution found so far.
+    LinearSearch(LinearSearch),
+    /// Improves the lower-bound using unsatisfiable cores until it meets the objective value of a
+    /// solution.
+    CoreGuided(CoreGuidedSearch),
+}
+
 /// Attempt to find optimal solutions to a constraint satisfaction problem with respect to an
 /// objective function.
 #[derive(Debug)]
 pub(crate) struct OptimisationSolver {
     solver: Solver,
     objective_function: Function,
-    linear_search: LinearSearch,
+    procedure: OptimisationProcedure,
 }
 
 impl OptimisationSolver {
     pub(crate) fn new(
         csp_solver: Solver,
         objective_function: Function,
-        linear_search: LinearSearch,
+        procedure: OptimisationProcedure,
     ) -> OptimisationSolver {
         OptimisationSolver {
             solver: csp_solver,
             objective_function,
-            linear_search,
+            procedure,
         }
     }
 }
@@ -50,20 +61,31 @@ impl OptimisationSolver {
                     process_time.elapsed().as_secs(),
                 );
 
-                self.linear_search.solve(
-                    &mut self.solver,
-                    process_time,
-                    &self.objective_function,
-                    termination,
-                    brancher,
-                    initial_solution,
-                )
+                match self.procedure {
+                    OptimisationProcedure::LinearSearch(linear_search) => linear_search.solve(
+                        &mut self.solver,
+                        process_time,
+                        &self.objective_function,
+                        termination,
+                        brancher,
+                        initial_solution,
+                    ),
+                    OptimisationProcedure::CoreGuided(core_guided_search) => core_guided_search
+                        .solve(
+                            &mut self.solver,
+                            process_time,
+                            &self.objective_function,
+                            termination,
+                            brancher,
+                            initial_solution,
+                        ),
+                }
             }
-            SatisfactionResult::Unsatisfiable => {
+            SatisfactionResult::Unsatisfiable(_) => {
                 self.solver.log_statistics();
                 MaxSatOptimisationResult::Infeasible
             }
-            SatisfactionResult::Unknown => {
+            SatisfactionResult::Unknown(_) => {
                 self.solver.log_statistics();
                 MaxSatOptimisationResult::Unknown
             }
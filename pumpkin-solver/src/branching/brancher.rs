@@ -6,6 +6,8 @@ use crate::branching;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
 #[cfg(doc)]
+use crate::branching::variable_selection::DomWDeg;
+#[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::Vsids;
@@ -14,6 +16,8 @@ use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
+use crate::engine::RestartOptions;
+#[cfg(doc)]
 use crate::results::solution_iterator::SolutionIterator;
 
 /// A trait for definining a branching strategy (oftentimes utilising a [`VariableSelector`] and a
@@ -35,6 +39,10 @@ pub trait Brancher {
     /// Note that this method **cannot** perform the assignment of the decision, it should return a
     /// [`Predicate`]; the [`SelectionContext`] is only mutable
     /// to account for the usage of random generators (e.g. see [`Random`]).
+    ///
+    /// A [`Predicate`] which is already satisfied or falsified is skipped and the [`Brancher`] is
+    /// asked for another decision; the solver panics if the same assigned [`Predicate`] is
+    /// returned twice in a row, since the search would otherwise not make progress.
     fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate>;
 
     /// A function which is called after a conflict has been found and processed but (currently)
@@ -62,6 +70,14 @@ pub trait Brancher {
     /// analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 
+    /// A function which is called when a propagator reports a conflict or empties a domain
+    /// (before the conflict is analysed); it provides the integer variables which the propagator
+    /// watches (i.e. the scope of the constraint). Conflicts which are found by clauses do not lead
+    /// to a call to this method.
+    ///
+    /// This can be used to weigh constraints based on how often they fail (see [`DomWDeg`]).
+    fn on_propagator_conflict(&mut self, _scope: &[DomainId]) {}
+
     /// This method is called when a solution is found; this will either be called when a new
     /// incumbent solution is found (i.e. a solution with a better objective value than previously
     /// known) or when a new solution is found when iterating over solutions using
@@ -84,4 +100,21 @@ pub trait Brancher {
     fn is_restart_pointless(&mut self) -> bool {
         true
     }
+
+    /// Returns the number of `decisions` (starting from the first decision) which the
+    /// [`Brancher`] would make again if the solver were to restart; this method is only called
+    /// when the solver reuses the trail upon a restart (see [`RestartOptions::reuse_trail`]). The
+    /// solver then only backtracks to the level of the last of these decisions.
+    ///
+    /// The `decisions` are the [`Predicate`]s which have been returned by
+    /// [`Brancher::next_decision`] and which are currently on the trail; the [`SelectionContext`]
+    /// reflects the current state of the solver. By default no decisions are reused, which
+    /// corresponds to a regular restart.
+    fn num_reusable_decisions(
+        &mut self,
+        _context: &mut SelectionContext,
+        _decisions: &[Predicate],
+    ) -> usize {
+        0
+    }
 }
@@ -103,6 +103,10 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
             .on_appearance_in_conflict_integer(variable)
     }
 
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.other_brancher.on_propagator_conflict(scope)
+    }
+
     fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
         self.other_brancher
             .on_appearance_in_conflict_literal(literal);
@@ -184,6 +188,25 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
             }
         }
     }
+
+    fn num_reusable_decisions(
+        &mut self,
+        context: &mut SelectionContext,
+        decisions: &[Predicate],
+    ) -> usize {
+        if self.strategy == AlternatingStrategy::EveryRestart {
+            // The brancher is switched upon the restart, so the decisions are not repeated
+            return 0;
+        }
+
+        if self.is_using_default_brancher {
+            self.default_brancher
+                .num_reusable_decisions(context, decisions)
+        } else {
+            self.other_brancher
+                .num_reusable_decisions(context, decisions)
+        }
+    }
 }
 
 #[cfg(test)]
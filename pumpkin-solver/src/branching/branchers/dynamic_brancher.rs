@@ -101,6 +101,12 @@ impl Brancher for DynamicBrancher {
             .for_each(|brancher| brancher.on_appearance_in_conflict_integer(variable));
     }
 
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_propagator_conflict(scope));
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.brancher_index = 0;
         self.branchers
@@ -116,4 +122,16 @@ impl Brancher for DynamicBrancher {
             .iter_mut()
             .all(|brancher| brancher.is_restart_pointless())
     }
+
+    fn num_reusable_decisions(
+        &mut self,
+        context: &mut SelectionContext,
+        decisions: &[Predicate],
+    ) -> usize {
+        // The first decisions on the trail are made by the first brancher, so we only consider the
+        // decisions which it would make again
+        self.branchers.first_mut().map_or(0, |brancher| {
+            brancher.num_reusable_decisions(context, decisions)
+        })
+    }
 }
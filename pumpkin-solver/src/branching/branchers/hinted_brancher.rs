@@ -0,0 +1,135 @@
+//! A [`Brancher`] which first follows a list of solution hints before deferring to another
+//! [`Brancher`].
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::predicate;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A [`Brancher`] which tries to assign the variables to the values of the provided hints (in the
+/// provided order) before deferring to the other [`Brancher`].
+///
+/// The hints are soft decisions, similar to solution hints in MIP solvers: unlike assumptions (see
+/// [`Solver::satisfy_under_assumptions`]), the hints are abandoned for the rest of the search as
+/// soon as a conflict is encountered. Hints of which the value is not in the domain of the
+/// variable when they are considered are skipped.
+#[derive(Debug)]
+pub struct HintedBrancher<OtherBrancher> {
+    /// The `(variable, value)` pairs which are tried first.
+    hints: Vec<(DomainId, i32)>,
+    /// Whether a conflict has been encountered, after which the hints are no longer used.
+    is_abandoned: bool,
+    /// The [`Brancher`] which is used once the hints are exhausted or abandoned.
+    other_brancher: OtherBrancher,
+}
+
+impl<OtherBrancher: Brancher> HintedBrancher<OtherBrancher> {
+    pub fn new(hints: impl Into<Vec<(DomainId, i32)>>, other_brancher: OtherBrancher) -> Self {
+        Self {
+            hints: hints.into(),
+            is_abandoned: false,
+            other_brancher,
+        }
+    }
+}
+
+impl<OtherBrancher: Brancher> Brancher for HintedBrancher<OtherBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        if !self.is_abandoned {
+            let hint = self.hints.iter().find(|&&(variable, value)| {
+                !context.is_integer_fixed(variable) && context.contains(variable, value)
+            });
+
+            if let Some(&(variable, value)) = hint {
+                return Some(predicate!(variable == value));
+            }
+        }
+
+        self.other_brancher.next_decision(context)
+    }
+
+    fn on_conflict(&mut self) {
+        self.is_abandoned = true;
+        self.other_brancher.on_conflict()
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.other_brancher.on_unassign_literal(literal)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.other_brancher.on_unassign_integer(variable, value)
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.other_brancher
+            .on_appearance_in_conflict_literal(literal)
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.other_brancher
+            .on_appearance_in_conflict_integer(variable)
+    }
+
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.other_brancher.on_propagator_conflict(scope)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.other_brancher.on_solution(solution)
+    }
+
+    fn on_restart(&mut self) {
+        self.other_brancher.on_restart()
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.other_brancher.is_restart_pointless()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+
+    /// A [`Brancher`] which never makes a decision.
+    struct NoDecisions;
+
+    impl Brancher for NoDecisions {
+        fn next_decision(&mut self, _context: &mut SelectionContext) -> Option<Predicate> {
+            None
+        }
+    }
+
+    #[test]
+    fn hints_are_followed_until_a_conflict() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (3, 3)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        // The hint for the fixed variable is skipped, as is the hint outside of the domain
+        let mut brancher = HintedBrancher::new(
+            [(domain_ids[1], 3), (domain_ids[0], 11), (domain_ids[0], 5)],
+            NoDecisions,
+        );
+        assert_eq!(
+            brancher.next_decision(&mut context),
+            Some(predicate!(domain_ids[0] == 5))
+        );
+
+        brancher.on_conflict();
+        assert_eq!(brancher.next_decision(&mut context), None);
+    }
+}
@@ -89,6 +89,10 @@ where
             .on_appearance_in_conflict_integer(variable)
     }
 
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.variable_selector.on_propagator_conflict(scope)
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.value_selector.on_solution(solution);
     }
@@ -96,4 +100,13 @@ where
     fn is_restart_pointless(&mut self) -> bool {
         self.variable_selector.is_restart_pointless() && self.value_selector.is_restart_pointless()
     }
+
+    fn num_reusable_decisions(
+        &mut self,
+        context: &mut SelectionContext,
+        decisions: &[Predicate],
+    ) -> usize {
+        self.variable_selector
+            .num_reusable_decisions(context, decisions)
+    }
 }
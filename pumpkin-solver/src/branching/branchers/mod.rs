@@ -2,6 +2,7 @@
 
 pub mod alternating_brancher;
 pub mod dynamic_brancher;
+pub mod hinted_brancher;
 pub mod independent_variable_value_brancher;
 #[cfg(doc)]
 use super::Brancher;
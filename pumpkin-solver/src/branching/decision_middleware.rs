@@ -0,0 +1,92 @@
+use std::fmt::Debug;
+
+#[cfg(doc)]
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::DebugDyn;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A component which inspects every decision made by the [`Brancher`] before it is applied, and
+/// which can replace the decision by another one or veto it (see
+/// [`Solver::add_decision_middleware`]).
+///
+/// This allows changing the search without changing the [`Brancher`] itself; for example, a
+/// middleware could round the value of a decision to the canonical value of its symmetry class,
+/// thereby breaking the symmetry dynamically during search.
+///
+/// Note that a middleware is applied to the decisions of every [`Brancher`] which is passed to the
+/// solver; it is implemented for closures with the same signature as
+/// [`DecisionMiddleware::on_decision`].
+pub trait DecisionMiddleware {
+    /// Called with the `decision` which is about to be applied; returns the [`Predicate`] which
+    /// should be applied instead (which is `decision` itself if it should not be changed), or
+    /// [`None`] if the decision is vetoed.
+    ///
+    /// If a decision is vetoed, then its negation is applied as the decision instead; this ensures
+    /// that the [`Brancher`] does not propose the vetoed decision again, while the part of the
+    /// search space in which it holds is not explored. Similar to the decisions of the
+    /// [`Brancher`], a replacement which is already satisfied or falsified is skipped.
+    fn on_decision(
+        &mut self,
+        decision: Predicate,
+        context: &mut SelectionContext,
+    ) -> Option<Predicate>;
+}
+
+impl<F> DecisionMiddleware for F
+where
+    F: FnMut(Predicate, &mut SelectionContext) -> Option<Predicate>,
+{
+    fn on_decision(
+        &mut self,
+        decision: Predicate,
+        context: &mut SelectionContext,
+    ) -> Option<Predicate> {
+        self(decision, context)
+    }
+}
+
+/// The [`DecisionMiddleware`]s registered with the solver, which are applied in the order in which
+/// they were added.
+#[derive(Default)]
+pub(crate) struct DecisionMiddlewares {
+    middlewares: Vec<Box<dyn DecisionMiddleware>>,
+}
+
+impl DecisionMiddlewares {
+    pub(crate) fn add(&mut self, middleware: Box<dyn DecisionMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    /// Passes the `decision` through every middleware; returns [`None`] as soon as one of them
+    /// vetoes the decision.
+    pub(crate) fn on_decision(
+        &mut self,
+        decision: Predicate,
+        context: &mut SelectionContext,
+    ) -> Option<Predicate> {
+        self.middlewares
+            .iter_mut()
+            .try_fold(decision, |decision, middleware| {
+                middleware.on_decision(decision, context)
+            })
+    }
+}
+
+impl Debug for DecisionMiddlewares {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let middlewares: Vec<_> = self
+            .middlewares
+            .iter()
+            .map(|_| DebugDyn::from("DecisionMiddleware"))
+            .collect();
+
+        write!(f, "{middlewares:?}")
+    }
+}
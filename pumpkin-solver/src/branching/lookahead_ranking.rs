@@ -0,0 +1,47 @@
+#[cfg(doc)]
+use crate::branching::Brancher;
+#[cfg(doc)]
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+
+/// The ranking of candidate decisions which is computed by a lookahead at the root (see
+/// [`SatisfactionSolverOptions::lookahead_budget`]); it is available to the [`Brancher`] through
+/// [`SelectionContext::lookahead_ranking`].
+///
+/// For every probed variable `x` with bounds `[l, u]`, both `[x <= m]` and `[x >= m + 1]` are
+/// propagated at the root, where `m` is the midpoint of the domain; the impact of a branch is the
+/// number of domain changes it causes. The score of the variable is the product of (one plus) the
+/// impacts of both branches, which favours variables of which both branches have a large impact.
+/// A branch which leads to a conflict has an infinite impact.
+///
+/// The ranking contains, in order of decreasing score, the branch with the smallest impact of every
+/// probed variable, as this branch is the most likely to lead to a solution.
+#[derive(Debug, Clone, Default)]
+pub struct LookaheadRanking {
+    candidates: Vec<(Predicate, f64)>,
+}
+
+impl LookaheadRanking {
+    /// Creates the ranking from the pairs of a predicate and its score, in any order.
+    pub(crate) fn new(mut candidates: Vec<(Predicate, f64)>) -> LookaheadRanking {
+        candidates.sort_by(|(_, score), (_, other_score)| other_score.total_cmp(score));
+        LookaheadRanking { candidates }
+    }
+
+    /// Returns the ranked predicates with their scores, in order of decreasing score.
+    pub fn iter(&self) -> impl Iterator<Item = (Predicate, f64)> + '_ {
+        self.candidates.iter().copied()
+    }
+
+    /// Returns the number of ranked predicates.
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Returns whether no predicates were ranked.
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+}
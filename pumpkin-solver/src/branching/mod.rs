@@ -76,12 +76,17 @@
 
 mod brancher;
 pub mod branchers;
+mod decision_middleware;
+mod lookahead_ranking;
 mod selection_context;
 pub mod tie_breaking;
 pub mod value_selection;
 pub mod variable_selection;
 
 pub use brancher::Brancher;
+pub use decision_middleware::DecisionMiddleware;
+pub(crate) use decision_middleware::DecisionMiddlewares;
+pub use lookahead_ranking::LookaheadRanking;
 pub use selection_context::SelectionContext;
 pub use tie_breaking::*;
 pub use value_selection::*;
@@ -3,6 +3,8 @@ use std::fmt::Debug;
 use crate::basic_types::Random;
 #[cfg(doc)]
 use crate::branching::Brancher;
+use crate::branching::LookaheadRanking;
+use crate::engine::propagation::DomainSnapshotAt;
 #[cfg(doc)]
 use crate::engine::propagation::PropagationContext;
 use crate::engine::variables::DomainGeneratorIterator;
@@ -13,6 +15,8 @@ use crate::engine::variables::PropositionalVariable;
 use crate::engine::variables::PropositionalVariableGeneratorIterator;
 use crate::engine::AssignmentsInteger;
 use crate::engine::AssignmentsPropositional;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
 
 /// The context provided to the [`Brancher`],
 /// it allows the retrieval of domain values of variables and access to methods from a [`Random`]
@@ -22,6 +26,7 @@ pub struct SelectionContext<'a> {
     assignments_integer: &'a AssignmentsInteger,
     assignments_propositional: &'a AssignmentsPropositional,
     random_generator: &'a mut dyn Random,
+    lookahead_ranking: Option<&'a LookaheadRanking>,
 }
 
 impl<'a> SelectionContext<'a> {
@@ -34,9 +39,26 @@ impl<'a> SelectionContext<'a> {
             assignments_integer,
             assignments_propositional,
             random_generator: rng,
+            lookahead_ranking: None,
         }
     }
 
+    /// Provides the ranking which was computed by the lookahead at the root to the [`Brancher`].
+    pub(crate) fn with_lookahead_ranking(
+        mut self,
+        lookahead_ranking: Option<&'a LookaheadRanking>,
+    ) -> Self {
+        self.lookahead_ranking = lookahead_ranking;
+        self
+    }
+
+    /// Returns the ranking of candidate decisions which was computed by the lookahead at the root
+    /// of the current call to solve, or [`None`] if no lookahead was performed (see
+    /// [`SatisfactionSolverOptions::lookahead_budget`]).
+    pub fn lookahead_ranking(&self) -> Option<&LookaheadRanking> {
+        self.lookahead_ranking
+    }
+
     /// Returns a random generator which can be used to generate random values (see [`Random`] for
     /// more information).
     pub fn random(&mut self) -> &mut dyn Random {
@@ -50,6 +72,14 @@ impl<'a> SelectionContext<'a> {
         var.upper_bound(self.assignments_integer) - var.lower_bound(self.assignments_integer)
     }
 
+    /// Returns the number of values which are in the domain of the provided [`IntegerVariable`];
+    /// unlike [`SelectionContext::get_size_of_domain`], this takes holes in the domain into
+    /// account. The size of a domain is maintained by the solver, this is thus a constant time
+    /// operation.
+    pub fn get_number_of_values_in_domain<Var: IntegerVariable>(&self, var: Var) -> usize {
+        var.size(self.assignments_integer)
+    }
+
     /// Returns the lower bound of the provided [`IntegerVariable`]
     pub fn lower_bound<Var: IntegerVariable>(&self, var: Var) -> i32 {
         var.lower_bound(self.assignments_integer)
@@ -65,6 +95,19 @@ impl<'a> SelectionContext<'a> {
         var.contains(self.assignments_integer, value)
     }
 
+    /// Returns the current position on the trail; this can be used to create a
+    /// [`DomainSnapshotAt`] of the current domains at a later point in the search using
+    /// [`SelectionContext::domain_snapshot_at`].
+    pub fn trail_position(&self) -> usize {
+        self.assignments_integer.num_trail_entries()
+    }
+
+    /// Returns a view of the domains as they were when the trail contained `trail_position`
+    /// entries; the search should not have backtracked past this point.
+    pub fn domain_snapshot_at(&self, trail_position: usize) -> DomainSnapshotAt<'_> {
+        DomainSnapshotAt::new(self.assignments_integer, trail_position)
+    }
+
     /// Determines whether the provided [`IntegerVariable`] has a unit domain (i.e. a domain of size
     /// 1)
     pub fn is_integer_fixed<Var: IntegerVariable>(&self, var: Var) -> bool {
@@ -15,11 +15,19 @@ impl<Var: IntegerVariable + Copy> ValueSelector<Var> for InDomainMedian {
         context: &mut SelectionContext,
         decision_variable: Var,
     ) -> Predicate {
-        let values_in_domain = (context.lower_bound(decision_variable)
-            ..=context.upper_bound(decision_variable))
+        let lower_bound = context.lower_bound(decision_variable);
+        let num_values = context.get_number_of_values_in_domain(decision_variable);
+
+        // Without holes, the median can be computed from the bounds
+        if context.get_size_of_domain(decision_variable) as usize + 1 == num_values {
+            return predicate!(decision_variable == lower_bound + (num_values / 2) as i32);
+        }
+
+        let median = (lower_bound..=context.upper_bound(decision_variable))
             .filter(|bound| context.contains(decision_variable, *bound))
-            .collect::<Vec<_>>();
-        predicate!(decision_variable == values_in_domain[values_in_domain.len() / 2])
+            .nth(num_values / 2)
+            .expect("the domain contains the median");
+        predicate!(decision_variable == median)
     }
 }
 
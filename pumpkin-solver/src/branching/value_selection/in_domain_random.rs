@@ -16,14 +16,20 @@ impl ValueSelector<DomainId> for InDomainRandom {
         context: &mut SelectionContext,
         decision_variable: DomainId,
     ) -> Predicate {
-        let values_in_domain = (context.lower_bound(decision_variable)
-            ..=context.upper_bound(decision_variable))
+        let lower_bound = context.lower_bound(decision_variable);
+        let num_values = context.get_number_of_values_in_domain(decision_variable);
+        let random_index = context.random().generate_usize_in_range(0..num_values);
+
+        // Without holes, the value can be computed from the bounds
+        if context.get_size_of_domain(decision_variable) as usize + 1 == num_values {
+            return predicate!(decision_variable == lower_bound + random_index as i32);
+        }
+
+        let value = (lower_bound..=context.upper_bound(decision_variable))
             .filter(|bound| context.contains(decision_variable, *bound))
-            .collect::<Vec<_>>();
-        let random_index = context
-            .random()
-            .generate_usize_in_range(0..values_in_domain.len());
-        predicate!(decision_variable == values_in_domain[random_index])
+            .nth(random_index)
+            .expect("the domain contains the selected value");
+        predicate!(decision_variable == value)
     }
 
     fn is_restart_pointless(&mut self) -> bool {
@@ -74,4 +80,29 @@ mod tests {
 
         assert_eq!(selected_predicate, predicate!(domain_ids[0] == 3))
     }
+
+    #[test]
+    fn holes_are_skipped() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
+        let domain_ids = assignments_integer.get_domains().collect::<Vec<_>>();
+        let _ = assignments_integer.remove_value_from_domain(domain_ids[0], 1, None);
+        let _ = assignments_integer.remove_value_from_domain(domain_ids[0], 2, None);
+
+        let mut test_random = TestRandom {
+            usizes: vec![3],
+            bools: vec![],
+        };
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_random,
+        );
+
+        let mut selector = InDomainRandom;
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] == 5))
+    }
 }
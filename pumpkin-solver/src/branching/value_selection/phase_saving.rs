@@ -2,6 +2,8 @@ use log::warn;
 
 use super::ValueSelector;
 use crate::basic_types::KeyedVec;
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::SolutionReference;
 use crate::basic_types::StorageKey;
 use crate::branching::SelectionContext;
 use crate::engine::predicates::predicate::Predicate;
@@ -146,6 +148,21 @@ impl ValueSelector<PropositionalVariable> for PhaseSaving<PropositionalVariable,
         self.update(lit.get_propositional_variable(), lit.is_positive())
     }
 
+    /// Resets the (non-frozen) saved values to the values in the new solution, such that the
+    /// search continues around the incumbent.
+    fn on_solution(&mut self, solution: SolutionReference) {
+        for propositional_variable in solution.get_propostional_variables() {
+            self.saved_values.accomodate(
+                propositional_variable,
+                StoredValue::Regular(self.default_value),
+            );
+            self.update(
+                propositional_variable,
+                solution.get_propositional_variable_value(propositional_variable),
+            )
+        }
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         false
     }
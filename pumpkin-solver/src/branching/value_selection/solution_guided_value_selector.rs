@@ -7,8 +7,10 @@ use crate::basic_types::SolutionReference;
 use crate::basic_types::StorageKey;
 use crate::branching::SelectionContext;
 use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
+use crate::predicate;
 use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
 
@@ -16,7 +18,8 @@ use crate::pumpkin_assert_simple;
 ///
 /// It does this by saving the values found in the solution and assigning to those values
 /// whenever possible, if it is not possible then it will fall back on the provided
-/// `backup_selector`.
+/// `backup_selector`. For integer variables, this is also the case when the saved value has been
+/// removed from the domain (e.g. by the bound on the objective during optimisation).
 ///
 /// # Bibliography
 /// \[1\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
@@ -31,13 +34,18 @@ pub struct SolutionGuidedValueSelector<Var, Value, BackUpSelector> {
     backup_selector: BackUpSelector,
 }
 
-impl<BackupSelector> SolutionGuidedValueSelector<PropositionalVariable, bool, BackupSelector>
+impl<Var, Value, BackupSelector> SolutionGuidedValueSelector<Var, Value, BackupSelector>
 where
-    BackupSelector: ValueSelector<PropositionalVariable>,
+    Var: StorageKey + Copy + PartialEq,
+    Value: Copy,
+    BackupSelector: ValueSelector<Var>,
 {
+    /// Creates a new [`SolutionGuidedValueSelector`] over `variables`; until a solution is found,
+    /// the values in `variables_with_initial_value` are selected and the `backup_selector` is used
+    /// for the other variables.
     pub fn new(
-        variables: &[PropositionalVariable],
-        variables_with_initial_value: Vec<(PropositionalVariable, bool)>,
+        variables: &[Var],
+        variables_with_initial_value: Vec<(Var, Value)>,
         backup_selector: BackupSelector,
     ) -> Self {
         pumpkin_assert_simple!(
@@ -72,14 +80,7 @@ where
         }
         solution_guided
     }
-}
 
-impl<Var, Value, BackupSelector> SolutionGuidedValueSelector<Var, Value, BackupSelector>
-where
-    Var: StorageKey,
-    Value: Copy,
-    BackupSelector: ValueSelector<Var>,
-{
     /// Update the value of the current variable
     fn update(&mut self, var: Var, new_value: Value) {
         self.saved_values[var] = Some(new_value);
@@ -126,15 +127,58 @@ where
     }
 }
 
+impl<BackupSelector> ValueSelector<DomainId>
+    for SolutionGuidedValueSelector<DomainId, i32, BackupSelector>
+where
+    BackupSelector: ValueSelector<DomainId>,
+{
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: DomainId,
+    ) -> Predicate {
+        self.saved_values.accomodate(decision_variable, None);
+        match self.saved_values[decision_variable] {
+            // The value in the solution could have been removed by (for example) the bound on the
+            // objective, in which case we fall back on the backup selector
+            Some(value) if context.contains(decision_variable, value) => {
+                predicate!(decision_variable == value)
+            }
+            _ => self
+                .backup_selector
+                .select_value(context, decision_variable),
+        }
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.backup_selector.on_unassign_integer(variable, value)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        for domain in solution.get_domains() {
+            self.saved_values.accomodate(domain, None);
+            self.update(domain, solution.get_integer_value(domain))
+        }
+        self.backup_selector.on_solution(solution)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.backup_selector.is_restart_pointless()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SolutionGuidedValueSelector;
     use crate::basic_types::tests::TestRandom;
     use crate::basic_types::StorageKey;
+    use crate::branching::value_selection::InDomainMin;
     use crate::branching::value_selection::PhaseSaving;
     use crate::branching::value_selection::ValueSelector;
     use crate::branching::SelectionContext;
     use crate::engine::predicates::predicate::Predicate;
+    use crate::engine::variables::DomainId;
+    use crate::predicate;
     use crate::results::SolutionReference;
     use crate::variables::Literal;
     use crate::variables::PropositionalVariable;
@@ -225,6 +269,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn value_in_solution_is_returned_int() {
+        let (solution_integer, solution_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(3, 3)]));
+        let domain = DomainId::new(0);
+
+        let mut solution_guided = SolutionGuidedValueSelector::new(&[domain], vec![], InDomainMin);
+        solution_guided.on_solution(SolutionReference::new(
+            &solution_propositional,
+            &solution_integer,
+        ));
+
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let selected = solution_guided.select_value(&mut context, domain);
+        assert_eq!(selected, predicate!(domain == 3));
+    }
+
+    #[test]
+    fn backup_is_used_when_saved_value_is_removed_int() {
+        let domain = DomainId::new(0);
+        let mut solution_guided =
+            SolutionGuidedValueSelector::new(&[domain], vec![(domain, 3)], InDomainMin);
+
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(1, 0, Some(vec![(4, 10)]));
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        let selected = solution_guided.select_value(&mut context, domain);
+        assert_eq!(selected, predicate!(domain <= 4));
+    }
+
     #[test]
     fn does_not_panic_with_unknown_selected_variable() {
         let variable = PropositionalVariable::create_from_index(1);
@@ -0,0 +1,323 @@
+use log::warn;
+
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+#[cfg(doc)]
+use crate::Solver;
+
+const DEFAULT_ABS_DECAY_FACTOR: f64 = 0.999;
+const DEFAULT_LAST_CONFLICT_SIZE: usize = 1;
+
+/// The options of the activity-based search which is created by
+/// [`Solver::activity_based_brancher_over_all_integer_variables`] (see
+/// [`SatisfactionSolverOptions::activity_based_search`]).
+#[derive(Clone, Copy, Debug)]
+pub struct ActivityBasedSearchOptions {
+    /// The factor by which the activity of an unfixed variable is multiplied when its domain is
+    /// not reduced by a decision (see [`ActivityBased`]).
+    pub decay_factor: f64,
+    /// The maximum number of conflict variables which are prioritised by last-conflict reasoning
+    /// (see [`LastConflict`]); a value of 0 disables last-conflict reasoning.
+    pub last_conflict_size: usize,
+}
+
+impl Default for ActivityBasedSearchOptions {
+    fn default() -> Self {
+        Self {
+            decay_factor: DEFAULT_ABS_DECAY_FACTOR,
+            last_conflict_size: DEFAULT_LAST_CONFLICT_SIZE,
+        }
+    }
+}
+
+/// A [`VariableSelector`] which implements activity-based search (ABS) \[1\].
+///
+/// The activity of a variable measures how often its domain is reduced by the propagation which
+/// follows a decision: after every decision, the activity of every variable whose domain was
+/// reduced is increased by one, while the activity of the other unfixed variables is multiplied
+/// by the decay factor. The selector then picks the variable with the largest ratio between its
+/// activity and the size of its domain (i.e. the number of values in its domain, taking holes
+/// into account).
+///
+/// The reductions are determined by comparing the sizes of the domains to those at the previous
+/// selection, so removing a value from the middle of a domain also counts as a reduction; if the
+/// solver backtracked in the meantime (e.g. due to a conflict), then the activities are left
+/// untouched.
+///
+/// # Bibliography
+/// \[1\] L. Michel and P. Van Hentenryck, ‘Activity-based search for black-box constraint
+/// programming solvers’, in CPAIOR, 2012, pp. 228–243.
+#[derive(Debug)]
+pub struct ActivityBased<Var> {
+    variables: Vec<Var>,
+    activities: Vec<f64>,
+    /// The sizes of the domains of the variables at the previous selection.
+    domain_sizes: Vec<usize>,
+    /// Whether [`ActivityBased::domain_sizes`] can be compared to the current domains, i.e.
+    /// whether the solver has not backtracked since the previous selection.
+    can_compare_domain_sizes: bool,
+    decay_factor: f64,
+    tie_breaker: InOrderTieBreaker<Var, f64>,
+}
+
+impl<Var: Copy> ActivityBased<Var> {
+    pub fn new(variables: &[Var], decay_factor: f64) -> Self {
+        if variables.is_empty() {
+            warn!("The ActivityBased variable selector was not provided with any variables");
+        }
+        Self {
+            variables: variables.to_vec(),
+            activities: vec![0.0; variables.len()],
+            domain_sizes: vec![0; variables.len()],
+            can_compare_domain_sizes: false,
+            decay_factor,
+            tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
+        }
+    }
+}
+
+impl VariableSelector<DomainId> for ActivityBased<DomainId> {
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+        for (index, &variable) in self.variables.iter().enumerate() {
+            let domain_size = context.get_number_of_values_in_domain(variable);
+
+            if self.can_compare_domain_sizes {
+                if domain_size < self.domain_sizes[index] {
+                    self.activities[index] += 1.0;
+                } else if !context.is_integer_fixed(variable) {
+                    self.activities[index] *= self.decay_factor;
+                }
+            }
+            self.domain_sizes[index] = domain_size;
+
+            if !context.is_integer_fixed(variable) {
+                self.tie_breaker
+                    .consider(variable, self.activities[index] / domain_size as f64);
+            }
+        }
+        self.can_compare_domain_sizes = true;
+
+        self.tie_breaker.select()
+    }
+
+    fn on_conflict(&mut self) {
+        self.can_compare_domain_sizes = false;
+    }
+
+    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {
+        self.can_compare_domain_sizes = false;
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+/// A [`VariableSelector`] which adds last-conflict reasoning \[1\] to another [`VariableSelector`].
+///
+/// When a conflict is found, the most recently selected variable is recorded as a conflict
+/// variable; as long as one of the (at most `size`) most recent conflict variables is unfixed, it
+/// is selected before consulting the underlying selector. Once all of the conflict variables are
+/// fixed without a new conflict, they are forgotten.
+///
+/// # Bibliography
+/// \[1\] C. Lecoutre, L. Saïs, S. Tabary, and V. Vidal, ‘Reasoning from last conflict(s) in
+/// constraint programming’, Artificial Intelligence, vol. 173, no. 18, pp. 1592–1614, 2009.
+#[derive(Debug)]
+pub struct LastConflict<Var, Selector> {
+    selector: Selector,
+    /// The variables which were selected right before the most recent conflicts, with the most
+    /// recent one first.
+    conflict_variables: Vec<Var>,
+    size: usize,
+    last_selected: Option<Var>,
+}
+
+impl<Var, Selector> LastConflict<Var, Selector> {
+    pub fn new(selector: Selector, size: usize) -> Self {
+        Self {
+            selector,
+            conflict_variables: Vec::with_capacity(size),
+            size,
+            last_selected: None,
+        }
+    }
+}
+
+impl<Selector: VariableSelector<DomainId>> VariableSelector<DomainId>
+    for LastConflict<DomainId, Selector>
+{
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+        let conflict_variable = self
+            .conflict_variables
+            .iter()
+            .copied()
+            .find(|&variable| !context.is_integer_fixed(variable));
+        if conflict_variable.is_none() {
+            self.conflict_variables.clear();
+        }
+
+        let selected = conflict_variable.or_else(|| self.selector.select_variable(context));
+        self.last_selected = selected;
+        selected
+    }
+
+    fn on_conflict(&mut self) {
+        if let Some(variable) = self.last_selected.take() {
+            if self.size > 0 && !self.conflict_variables.contains(&variable) {
+                self.conflict_variables.truncate(self.size - 1);
+                self.conflict_variables.insert(0, variable);
+            }
+        }
+        self.selector.on_conflict()
+    }
+
+    fn on_unassign_literal(&mut self, literal: Literal) {
+        self.selector.on_unassign_literal(literal)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+        self.selector.on_unassign_integer(variable, value)
+    }
+
+    fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+        self.selector.on_appearance_in_conflict_literal(literal)
+    }
+
+    fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+        self.selector.on_appearance_in_conflict_integer(variable)
+    }
+
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.selector.on_propagator_conflict(scope)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.conflict_variables.is_empty() && self.selector.is_restart_pointless()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::ActivityBased;
+    use crate::branching::InputOrder;
+    use crate::branching::LastConflict;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn variables_whose_domains_are_reduced_are_preferred() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = ActivityBased::new(&integer_variables, 0.5);
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+            // Without any activity, the first variable is selected
+            let selected = strategy.select_variable(&context);
+            assert_eq!(selected, Some(integer_variables[0]));
+        }
+
+        // The decision reduces the domain of the second variable
+        let _ = assignments_integer.tighten_upper_bound(integer_variables[1], 8, None);
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let selected = strategy.select_variable(&context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn activities_are_not_updated_after_a_conflict() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = ActivityBased::new(&integer_variables, 0.5);
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+            let _ = strategy.select_variable(&context);
+        }
+
+        strategy.on_conflict();
+        let _ = assignments_integer.tighten_upper_bound(integer_variables[1], 8, None);
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let selected = strategy.select_variable(&context);
+        assert_eq!(selected, Some(integer_variables[0]));
+    }
+
+    #[test]
+    fn last_conflict_variable_is_selected_first() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (0, 10), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = LastConflict::new(InputOrder::new(&integer_variables), 1);
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+            assert_eq!(
+                strategy.select_variable(&context),
+                Some(integer_variables[0])
+            );
+        }
+
+        // The first variable is fixed and then the conflict is found after selecting the third
+        let _ = assignments_integer.make_assignment(integer_variables[0], 0, None);
+        let _ = assignments_integer.make_assignment(integer_variables[1], 0, None);
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+            assert_eq!(
+                strategy.select_variable(&context),
+                Some(integer_variables[2])
+            );
+        }
+        strategy.on_conflict();
+
+        // After backtracking, the conflict variable is selected before the input order
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (0, 10), (0, 10)]));
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        assert_eq!(
+            strategy.select_variable(&context),
+            Some(integer_variables[2])
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use log::warn;
+
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::branching::Direction;
+use crate::branching::InOrderTieBreaker;
+use crate::branching::SelectionContext;
+use crate::branching::TieBreaker;
+use crate::branching::VariableSelector;
+use crate::engine::variables::DomainId;
+use crate::pumpkin_assert_eq_simple;
+
+/// The weight with which every constraint starts.
+const INITIAL_WEIGHT: u64 = 1;
+
+/// A [`VariableSelector`] which selects the variable with the smallest ratio between the number
+/// of values in its domain and its weighted degree (dom/wdeg \[1\]).
+///
+/// Every constraint is given a weight, which is increased by one every time its propagator reports
+/// a conflict (see [`VariableSelector::on_propagator_conflict`]); the weighted degree of a variable
+/// is the sum of the weights of the constraints which it is attached to. Intuitively, variables
+/// which are involved in constraints which are often violated are thus preferred. Note that the
+/// variables are only notified of conflicts of their own constraints, which means that every
+/// variable starts out with a weighted degree of 1 (i.e. the selector initially behaves like
+/// [`FirstFail`]).
+///
+/// Uses a [`TieBreaker`] to break ties, the default is the [`InOrderTieBreaker`] but it is
+/// possible to construct the variable selector with a custom [`TieBreaker`] by using
+/// the method [`DomWDeg::with_tie_breaker`].
+///
+/// # Bibliography
+/// \[1\] F. Boussemart, F. Hemery, C. Lecoutre, and L. Sais, ‘Boosting systematic search by
+/// weighting constraints’, in ECAI, 2004, pp. 146–150.
+///
+/// [`FirstFail`]: crate::branching::variable_selection::FirstFail
+pub struct DomWDeg<Var, TieBreaking> {
+    variables: Vec<Var>,
+    tie_breaker: TieBreaking,
+    /// The sum of the weights of the constraints which a variable is attached to.
+    weighted_degrees: KeyedVec<DomainId, u64>,
+}
+
+impl<Var, TieBreaking> std::fmt::Debug for DomWDeg<Var, TieBreaking> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DomWDeg").finish()
+    }
+}
+
+impl<Var: Clone> DomWDeg<Var, InOrderTieBreaker<Var, f64>> {
+    pub fn new(variables: &[Var]) -> Self {
+        if variables.is_empty() {
+            warn!("The DomWDeg variable selector was not provided with any variables");
+        }
+        Self {
+            variables: variables.to_vec(),
+            tie_breaker: InOrderTieBreaker::new(Direction::Minimum),
+            weighted_degrees: KeyedVec::default(),
+        }
+    }
+}
+
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, f64>> DomWDeg<Var, TieBreaking> {
+    pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
+        pumpkin_assert_eq_simple!(
+            tie_breaker.get_direction(),
+            Direction::Minimum,
+            "The provided tie-breaker to DomWDeg attempts to find the Maximum value
+             instead of the Minimum value, please ensure that you have passed the correct tie-breaker");
+        if variables.is_empty() {
+            warn!("The DomWDeg variable selector was not provided with any variables");
+        }
+
+        Self {
+            variables: variables.to_vec(),
+            tie_breaker,
+            weighted_degrees: KeyedVec::default(),
+        }
+    }
+
+    /// Returns the weighted degree of the provided variable.
+    fn weighted_degree(&self, variable: DomainId) -> u64 {
+        if variable.index() < self.weighted_degrees.len() {
+            self.weighted_degrees[variable]
+        } else {
+            INITIAL_WEIGHT
+        }
+    }
+}
+
+impl<TieBreaking> VariableSelector<DomainId> for DomWDeg<DomainId, TieBreaking>
+where
+    TieBreaking: TieBreaker<DomainId, f64>,
+{
+    fn select_variable(&mut self, context: &SelectionContext) -> Option<DomainId> {
+        for &variable in &self.variables {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            let ratio = context.get_number_of_values_in_domain(variable) as f64
+                / self.weighted_degree(variable) as f64;
+            self.tie_breaker.consider(variable, ratio);
+        }
+        self.tie_breaker.select()
+    }
+
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        for &variable in scope {
+            self.weighted_degrees.accomodate(variable, INITIAL_WEIGHT);
+            self.weighted_degrees[variable] += 1;
+        }
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::DomWDeg;
+    use crate::branching::SelectionContext;
+    use crate::branching::VariableSelector;
+
+    #[test]
+    fn without_conflicts_the_smallest_domain_is_selected() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (5, 7)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        let selected = strategy.select_variable(&context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn variables_in_failing_constraints_are_preferred() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 10), (5, 7), (0, 3)]));
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = DomWDeg::new(&integer_variables);
+
+        // Both the first and the third variable now have a weighted degree of 5
+        for _ in 0..4 {
+            strategy.on_propagator_conflict(&[integer_variables[0], integer_variables[2]]);
+        }
+
+        {
+            let context = SelectionContext::new(
+                &assignments_integer,
+                &assignments_propositional,
+                &mut test_rng,
+            );
+
+            // The third variable has the smallest ratio (4 / 5)
+            let selected = strategy.select_variable(&context);
+            assert_eq!(selected, Some(integer_variables[2]));
+        }
+
+        // The first variable (11 / 5) is preferred over the second variable (3 / 1)
+        let _ = assignments_integer.make_assignment(integer_variables[2], 0, None);
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let selected = strategy.select_variable(&context);
+        assert_eq!(selected, Some(integer_variables[0]));
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 0), (5, 7)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        strategy.on_propagator_conflict(&[integer_variables[0]]);
+
+        let selected = strategy.select_variable(&context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+}
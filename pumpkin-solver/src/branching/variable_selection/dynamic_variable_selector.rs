@@ -34,6 +34,10 @@ impl<Var> VariableSelector<Var> for DynamicVariableSelector<Var> {
         self.selector.on_appearance_in_conflict_integer(variable)
     }
 
+    fn on_propagator_conflict(&mut self, scope: &[DomainId]) {
+        self.selector.on_propagator_conflict(scope)
+    }
+
     fn on_conflict(&mut self) {
         self.selector.on_conflict()
     }
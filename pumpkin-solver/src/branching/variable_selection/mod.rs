@@ -6,7 +6,9 @@
 //! [`Vsids`]. Any [`VariableSelector`] should only select variables which have a domain of size 2
 //! or larger.
 
+mod activity_based;
 mod anti_first_fail;
+mod dom_w_deg;
 mod dynamic_variable_selector;
 mod first_fail;
 mod input_order;
@@ -18,7 +20,9 @@ mod smallest;
 mod variable_selector;
 mod vsids;
 
+pub use activity_based::*;
 pub use anti_first_fail::*;
+pub use dom_w_deg::*;
 pub use dynamic_variable_selector::*;
 pub use first_fail::*;
 pub use input_order::*;
@@ -1,8 +1,11 @@
 #[cfg(doc)]
 use crate::branching::variable_selection::Smallest;
+#[cfg(doc)]
+use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 #[cfg(doc)]
 use crate::branching::Vsids;
+use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
@@ -41,6 +44,10 @@ pub trait VariableSelector<Var> {
     /// A function which is called when a variable appears in a conflict during conflict analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 
+    /// A function which is called when a propagator reports a conflict, with the integer variables
+    /// which the propagator watches; see [`Brancher::on_propagator_conflict`].
+    fn on_propagator_conflict(&mut self, _scope: &[DomainId]) {}
+
     /// This method returns whether a restart is *currently* pointless for the [`VariableSelector`].
     ///
     /// For example, if a [`VariableSelector`] is using a static strategy (e.g. [`Smallest`]) then a
@@ -52,4 +59,15 @@ pub trait VariableSelector<Var> {
     fn is_restart_pointless(&mut self) -> bool {
         true
     }
+
+    /// Returns the number of `decisions` (starting from the first decision) of which the variable
+    /// would be selected again if the solver were to restart (see
+    /// [`Brancher::num_reusable_decisions`]). By default no decisions are reused.
+    fn num_reusable_decisions(
+        &mut self,
+        _context: &SelectionContext,
+        _decisions: &[Predicate],
+    ) -> usize {
+        0
+    }
 }
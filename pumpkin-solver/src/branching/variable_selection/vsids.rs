@@ -4,6 +4,7 @@ use super::VariableSelector;
 use crate::basic_types::KeyValueHeap;
 use crate::basic_types::StorageKey;
 use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
@@ -26,6 +27,9 @@ use crate::pumpkin_assert_eq_simple;
 /// \[1\] M. W. Moskewicz, C. F. Madigan, Y. Zhao, L. Zhang, and S. Malik, ‘Chaff: Engineering an
 /// efficient SAT solver’, in Proceedings of the 38th annual Design Automation Conference, 2001, pp.
 /// 530–535.
+///
+/// \[2\] P. van der Tak, A. Ramos, and M. Heule, ‘Reusing the assignment trail in CDCL solvers’,
+/// Journal on Satisfiability, Boolean Modeling and Computation, vol. 7, no. 4, pp. 133–138, 2011.
 #[derive(Debug)]
 pub struct Vsids<Var: StorageKey> {
     heap: KeyValueHeap<Var, f64>,
@@ -158,6 +162,46 @@ impl<Var: StorageKey + Clone + Copy> Vsids<Var> {
     fn decay_activities(&mut self) {
         self.increment *= 1.0 / self.decay_factor;
     }
+
+    /// Returns the highest activity of the variables which are not fixed (i.e. the activity of
+    /// the variable which would be selected next), or [`None`] if all variables are fixed.
+    ///
+    /// In contrast to [`VariableSelector::select_variable`], the fixed variables are not removed
+    /// from the heap, which means that querying this does not affect later selections.
+    fn highest_unfixed_activity(&self, is_fixed: impl Fn(Var) -> bool) -> Option<f64> {
+        if let Some((&candidate, &activity)) = self.heap.peek_max() {
+            if !is_fixed(candidate) {
+                return Some(activity);
+            }
+        }
+
+        (0..self.heap.len())
+            .map(Var::create_from_index)
+            .filter(|&variable| self.heap.is_key_present(variable) && !is_fixed(variable))
+            .map(|variable| *self.heap.get_value(variable))
+            .max_by(f64::total_cmp)
+    }
+
+    /// Returns the number of `decision_variables` (starting from the first) which have a higher
+    /// activity than `threshold` (the activity of the variable which would be selected next, see
+    /// [`Vsids::highest_unfixed_activity`]); after a restart, these variables would be selected
+    /// before the next variable \[2\]. A decision which is not over one of the variables of the
+    /// selector ends the sequence.
+    fn num_decisions_with_higher_activity(
+        &self,
+        threshold: Option<f64>,
+        decision_variables: impl Iterator<Item = Option<Var>>,
+    ) -> usize {
+        decision_variables
+            .take_while(|decision_variable| {
+                decision_variable.is_some_and(|variable| {
+                    variable.index() < self.heap.len()
+                        && threshold
+                            .is_none_or(|threshold| *self.heap.get_value(variable) > threshold)
+                })
+            })
+            .count()
+    }
 }
 
 impl VariableSelector<DomainId> for Vsids<DomainId> {
@@ -192,6 +236,19 @@ impl VariableSelector<DomainId> for Vsids<DomainId> {
     fn is_restart_pointless(&mut self) -> bool {
         false
     }
+
+    fn num_reusable_decisions(
+        &mut self,
+        context: &SelectionContext,
+        decisions: &[Predicate],
+    ) -> usize {
+        let threshold =
+            self.highest_unfixed_activity(|variable| context.is_integer_fixed(variable));
+        self.num_decisions_with_higher_activity(
+            threshold,
+            decisions.iter().map(|decision| decision.get_domain()),
+        )
+    }
 }
 
 impl VariableSelector<PropositionalVariable> for Vsids<PropositionalVariable> {
@@ -224,6 +281,22 @@ impl VariableSelector<PropositionalVariable> for Vsids<PropositionalVariable> {
     fn is_restart_pointless(&mut self) -> bool {
         false
     }
+
+    fn num_reusable_decisions(
+        &mut self,
+        context: &SelectionContext,
+        decisions: &[Predicate],
+    ) -> usize {
+        let threshold = self
+            .highest_unfixed_activity(|variable| context.is_propositional_variable_fixed(variable));
+        self.num_decisions_with_higher_activity(
+            threshold,
+            decisions.iter().map(|decision| match decision {
+                Predicate::Literal(literal) => Some(literal.get_propositional_variable()),
+                _ => None,
+            }),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -234,6 +307,7 @@ mod tests {
     use crate::branching::variable_selection::VariableSelector;
     use crate::branching::SelectionContext;
     use crate::engine::variables::PropositionalVariable;
+    use crate::predicate;
     use crate::variables::Literal;
 
     #[test]
@@ -257,6 +331,54 @@ mod tests {
         assert_eq!(chosen.unwrap(), domains[1]);
     }
 
+    #[test]
+    fn decisions_with_higher_activity_than_next_variable_are_reusable() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(3, 0, Some(vec![(0, 0), (0, 0), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domains = context.get_domains().collect::<Vec<_>>();
+
+        let mut vsids = Vsids::new(&domains);
+        for _ in 0..3 {
+            vsids.bump_activity(domains[0]);
+        }
+        vsids.bump_activity(domains[1]);
+        for _ in 0..2 {
+            vsids.bump_activity(domains[2]);
+        }
+
+        // The next variable is the only unfixed variable, which has a higher activity than the
+        // second decision
+        let decisions = [predicate![domains[0] == 0], predicate![domains[1] == 0]];
+        assert_eq!(vsids.num_reusable_decisions(&context, &decisions), 1);
+    }
+
+    #[test]
+    fn counting_reusable_decisions_does_not_remove_fixed_variables() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 0), (0, 10)]));
+        let mut test_rng = TestRandom::default();
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let domains = context.get_domains().collect::<Vec<_>>();
+
+        let mut vsids = Vsids::new(&domains);
+        vsids.bump_activity(domains[0]);
+
+        let decisions = [predicate![domains[0] == 0]];
+        assert_eq!(vsids.num_reusable_decisions(&context, &decisions), 1);
+        assert!(vsids.heap.is_key_present(domains[0]));
+        assert_eq!(vsids.num_reusable_decisions(&context, &decisions), 1);
+    }
+
     #[test]
     fn vsids_no_variables_will_return_none() {
         let mut vsids: Vsids<PropositionalVariable> = Vsids::new(&Vec::new());
@@ -1,22 +1,75 @@
-use super::binary_not_equals;
+use std::num::NonZero;
+
 use super::Constraint;
+use crate::options::AllDifferentPropagationMethod;
+use crate::propagators::AllDifferentBoundsPropagator;
+use crate::propagators::AllDifferentReginPropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
 
 /// Creates the [`Constraint`] that enforces that all the given `variables` are distinct.
+///
+/// The constraint is propagated to domain consistency (see
+/// [`AllDifferentPropagationMethod::DomainConsistency`]); a cheaper propagation method can be
+/// selected using [`all_different_with_options`].
 pub fn all_different<Var: IntegerVariable + 'static>(
     variables: impl Into<Box<[Var]>>,
 ) -> impl Constraint {
-    let variables: Box<[Var]> = variables.into();
-    let mut constraints = Vec::new();
+    all_different_with_options(variables, AllDifferentPropagationMethod::default())
+}
 
-    for i in 0..variables.len() {
-        for j in i + 1..variables.len() {
-            constraints.push(binary_not_equals(
-                variables[i].clone(),
-                variables[j].clone(),
-            ));
+/// Creates the [`Constraint`] that enforces that all the given `variables` are distinct, which is
+/// propagated using the provided [`AllDifferentPropagationMethod`].
+///
+/// See the documentation of [`all_different`] for more information about the constraint.
+pub fn all_different_with_options<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    propagation_method: AllDifferentPropagationMethod,
+) -> impl Constraint {
+    AllDifferentConstraint {
+        variables: variables.into(),
+        propagation_method,
+    }
+}
+
+struct AllDifferentConstraint<Var> {
+    variables: Box<[Var]>,
+    propagation_method: AllDifferentPropagationMethod,
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for AllDifferentConstraint<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        match self.propagation_method {
+            AllDifferentPropagationMethod::DomainConsistency => {
+                AllDifferentReginPropagator::new(self.variables).post(solver, tag)
+            }
+            AllDifferentPropagationMethod::BoundsConsistency => {
+                AllDifferentBoundsPropagator::new(self.variables).post(solver, tag)
+            }
         }
     }
 
-    constraints
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        match self.propagation_method {
+            AllDifferentPropagationMethod::DomainConsistency => AllDifferentReginPropagator::new(
+                self.variables,
+            )
+            .implied_by(solver, reification_literal, tag),
+            AllDifferentPropagationMethod::BoundsConsistency => AllDifferentBoundsPropagator::new(
+                self.variables,
+            )
+            .implied_by(solver, reification_literal, tag),
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use super::Constraint;
+use crate::propagators::AmongPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [among](https://sofdem.github.io/gccat/gccat/Camong.html) [`Constraint`] which
+/// states that `count` is equal to the number of `variables` which take a value in `values`.
+///
+/// The propagator updates the bounds of `count` based on the variables which certainly (do not)
+/// take a value in `values`, and removes values from the variables once the bounds of `count`
+/// determine whether the remaining variables take a value in `values`.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(1, 2);
+/// let y = solver.new_bounded_integer(1, 5);
+/// let count = solver.new_bounded_integer(0, 1);
+///
+/// solver
+///     .add_constraint(constraints::among(count, [x, y], [1, 2, 3]))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The value of `x` is in the set, so `y` has to take a value outside of the set
+/// assert_eq!(solver.lower_bound(&count), 1);
+/// assert_eq!(solver.lower_bound(&y), 4);
+/// ```
+pub fn among<Var: IntegerVariable + 'static>(
+    count: impl IntegerVariable + 'static,
+    variables: impl Into<Box<[Var]>>,
+    values: impl IntoIterator<Item = i32>,
+) -> impl Constraint {
+    AmongPropagator::new(variables.into(), values, count)
+}
+
+/// Creates the [count](https://sofdem.github.io/gccat/gccat/Ccount.html) [`Constraint`] which
+/// states that `count` is equal to the number of `variables` which are equal to `value`.
+///
+/// This is the [`among`] constraint with a single value; see its documentation for more
+/// information.
+pub fn count<Var: IntegerVariable + 'static>(
+    value: i32,
+    variables: impl Into<Box<[Var]>>,
+    count: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    among(count, variables, [value])
+}
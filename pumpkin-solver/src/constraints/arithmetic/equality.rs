@@ -3,6 +3,8 @@ use std::num::NonZero;
 use super::less_than_or_equals;
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
+use crate::propagators::binary_equals::BinaryEqualsPropagator;
+use crate::propagators::binary_not_equals::BinaryNotEqualsPropagator;
 use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
@@ -24,12 +26,15 @@ pub fn equals<Var: IntegerVariable + Clone + 'static>(
 
 /// Creates the [`NegatableConstraint`] `lhs = rhs`.
 ///
+/// The constraint is propagated to domain consistency; i.e. the bounds of both sides are made equal
+/// and a value which is removed from one side is also removed from the other side.
+///
 /// Its negation is [`binary_not_equals`].
 pub fn binary_equals<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
 ) -> impl NegatableConstraint {
-    equals([lhs.scaled(1), rhs.scaled(-1)], 0)
+    BinaryEqualConstraint { lhs, rhs }
 }
 
 /// Create the [`NegatableConstraint`] `\sum terms_i != rhs`.
@@ -44,12 +49,15 @@ pub fn not_equals<Var: IntegerVariable + Clone + 'static>(
 
 /// Creates the [`NegatableConstraint`] `lhs != rhs`.
 ///
+/// The constraint is propagated by removing the value of either side from the domain of the other
+/// side once it is fixed.
+///
 /// Its negation is [`binary_equals`].
 pub fn binary_not_equals<Var: IntegerVariable + 'static>(
     lhs: Var,
     rhs: Var,
 ) -> impl NegatableConstraint {
-    not_equals([lhs.scaled(1), rhs.scaled(-1)], 0)
+    BinaryNotEqualConstraint { lhs, rhs }
 }
 
 struct EqualConstraint<Var> {
@@ -159,3 +167,93 @@ where
         }
     }
 }
+
+struct BinaryEqualConstraint<Var> {
+    lhs: Var,
+    rhs: Var,
+}
+
+impl<Var> Constraint for BinaryEqualConstraint<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        BinaryEqualsPropagator::new(self.lhs, self.rhs, 0).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        BinaryEqualsPropagator::new(self.lhs, self.rhs, 0).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
+
+impl<Var> NegatableConstraint for BinaryEqualConstraint<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    type NegatedConstraint = BinaryNotEqualConstraint<Var>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        BinaryNotEqualConstraint {
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+        }
+    }
+}
+
+struct BinaryNotEqualConstraint<Var> {
+    lhs: Var,
+    rhs: Var,
+}
+
+impl<Var> Constraint for BinaryNotEqualConstraint<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        BinaryNotEqualsPropagator::new(self.lhs, self.rhs, 0).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        BinaryNotEqualsPropagator::new(self.lhs, self.rhs, 0).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
+
+impl<Var> NegatableConstraint for BinaryNotEqualConstraint<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    type NegatedConstraint = BinaryEqualConstraint<Var>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        BinaryEqualConstraint {
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+        }
+    }
+}
@@ -3,13 +3,18 @@ use std::num::NonZero;
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
 use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::propagators::reified_linear_less_or_equal::ReifiedLinearLessOrEqualPropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
 
 /// Create the [`NegatableConstraint`] `\sum terms_i <= rhs`.
 ///
-/// Its negation is `\sum terms_i > rhs`
+/// Its negation is `\sum terms_i > rhs`. The half-reified and fully-reified versions of the
+/// constraint (see [`Constraint::implied_by`] and [`NegatableConstraint::reify`]) are propagated by
+/// a single propagator, which also propagates the reification literal based on the bounds of the
+/// terms.
 pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     terms: impl Into<Box<[Var]>>,
     rhs: i32,
@@ -57,14 +62,11 @@ impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
     fn implied_by(
         self,
         solver: &mut Solver,
-        reification_literal: crate::variables::Literal,
+        reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        LinearLessOrEqualPropagator::new(self.terms, self.rhs).implied_by(
-            solver,
-            reification_literal,
-            tag,
-        )
+        ReifiedLinearLessOrEqualPropagator::new(self.terms, self.rhs, reification_literal, false)
+            .post(solver, tag)
     }
 }
 
@@ -77,4 +79,14 @@ impl<Var: IntegerVariable + 'static> NegatableConstraint for Inequality<Var> {
             rhs: -self.rhs - 1,
         }
     }
+
+    fn reify(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        ReifiedLinearLessOrEqualPropagator::new(self.terms, self.rhs, reification_literal, true)
+            .post(solver, tag)
+    }
 }
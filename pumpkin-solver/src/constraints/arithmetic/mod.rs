@@ -4,12 +4,20 @@ mod inequality;
 pub use equality::*;
 pub use inequality::*;
 
+use super::validation::check_non_empty;
+use super::validation::ValidatedConstraint;
 use super::Constraint;
 use crate::propagators::absolute_value::AbsoluteValuePropagator;
+use crate::propagators::binary_maximum::BinaryMaximumPropagator;
+use crate::propagators::bounds_consistent_multiplication::BoundsConsistentMultiplicationPropagator;
 use crate::propagators::division::DivisionPropagator;
 use crate::propagators::integer_multiplication::IntegerMultiplicationPropagator;
+use crate::propagators::integer_square::IntegerSquarePropagator;
 use crate::propagators::maximum::MaximumPropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+#[cfg(doc)]
+use crate::InvalidArgument;
 
 /// Creates the [`Constraint`] `a + b = c`.
 pub fn plus<Var: IntegerVariable + 'static>(a: Var, b: Var, c: Var) -> impl Constraint {
@@ -25,6 +33,105 @@ pub fn times(
     IntegerMultiplicationPropagator::new(a, b, c)
 }
 
+/// Creates the [`Constraint`] `a * b = c` which is propagated to domain consistency, i.e. every
+/// value which is not part of a solution of `a * b = c` is removed from the domains.
+///
+/// The supports of the values are enumerated, which is only done if the domains of the variables
+/// are small (e.g. for cryptarithmetic puzzles); otherwise, the constraint is propagated in the
+/// same way as [`times`].
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let a = solver.new_bounded_integer(2, 3);
+/// let b = solver.new_bounded_integer(2, 3);
+/// let c = solver.new_bounded_integer(5, 9);
+///
+/// solver
+///     .add_constraint(constraints::times_domain_consistent(a, b, c))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The value 5 cannot be written as the product of `a` and `b`
+/// assert_eq!(solver.lower_bound(&c), 6);
+/// ```
+pub fn times_domain_consistent(
+    a: impl IntegerVariable + 'static,
+    b: impl IntegerVariable + 'static,
+    c: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    IntegerMultiplicationPropagator::new_domain_consistent(a, b, c)
+}
+
+/// Creates the [`Constraint`] `a * b = c` which is propagated to bounds consistency, regardless of
+/// the signs of the variables.
+///
+/// Whereas [`times`] only tightens the bounds when the variables are non-negative, this
+/// constraint splits `a` and `b` on their signs to tighten the bounds of all three variables.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let a = solver.new_bounded_integer(-10, 10);
+/// let b = solver.new_bounded_integer(-3, -2);
+/// let c = solver.new_bounded_integer(4, 9);
+///
+/// solver
+///     .add_constraint(constraints::times_bounds_consistent(a, b, c))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The product is positive while `b` is negative, so `a` is in `[-4, -2]`
+/// assert_eq!(solver.lower_bound(&a), -4);
+/// assert_eq!(solver.upper_bound(&a), -2);
+/// ```
+pub fn times_bounds_consistent(
+    a: impl IntegerVariable + 'static,
+    b: impl IntegerVariable + 'static,
+    c: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    BoundsConsistentMultiplicationPropagator::new(a, b, c)
+}
+
+/// Creates the [`Constraint`] `x * x = y`.
+///
+/// Next to propagating the bounds of both variables (where `x` is split on its sign), holes are
+/// filtered: the bounds of `y` are tightened to squares, the squares of the values which are not in
+/// the domain of `x` are removed from `y`, and the values of `x` of which the square is not in the
+/// domain of `y` are removed; e.g. a lower-bound of 1 on `y` removes the value 0 from `x`.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(-5, 5);
+/// let y = solver.new_bounded_integer(2, 10);
+///
+/// solver
+///     .add_constraint(constraints::square(x, y))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // `y` is either 4 or 9, hence `x` is in `{-3, -2, 2, 3}`
+/// assert_eq!(solver.lower_bound(&y), 4);
+/// assert_eq!(solver.upper_bound(&y), 9);
+/// assert_eq!(solver.get_domain_ranges(x), vec![-3..=-2, 2..=3]);
+/// ```
+pub fn square(
+    x: impl IntegerVariable + 'static,
+    y: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    IntegerSquarePropagator::new(x, y)
+}
+
 /// Creates the [`Constraint`] `numerator / denominator = rhs`.
 ///
 /// Note that this [`Constraint`] models truncating division (i.e. rounding towards 0).
@@ -48,14 +155,23 @@ pub fn absolute(
 }
 
 /// Creates the [`Constraint`] `max(array) = m`.
+///
+/// The `array` should not be empty; otherwise, posting the constraint fails with
+/// [`InvalidArgument::EmptyArray`].
 pub fn maximum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    MaximumPropagator::new(array.into_iter().collect(), rhs)
+    let array: Box<[Var]> = array.into_iter().collect();
+    ValidatedConstraint::new(check_non_empty("array", array.len()), || {
+        MaximumPropagator::new(array, rhs)
+    })
 }
 
 /// Creates the [`Constraint`] `min(array) = m`.
+///
+/// The `array` should not be empty; otherwise, posting the constraint fails with
+/// [`InvalidArgument::EmptyArray`].
 pub fn minimum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
     rhs: impl IntegerVariable + 'static,
@@ -63,3 +179,59 @@ pub fn minimum<Var: IntegerVariable + 'static>(
     let array = array.into_iter().map(|var| var.scaled(-1));
     maximum(array, rhs.scaled(-1))
 }
+
+/// Creates the [`Constraint`] `max(lhs, rhs) = maximum`, which optionally also enforces
+/// `comparison <-> (lhs <= rhs)`.
+///
+/// Compared to posting [`maximum`] and a reified [`binary_less_than_or_equals`] separately, the
+/// comparison literal directly determines which of the two variables is equal to the maximum, and
+/// it is assigned as soon as the bounds of the variables (including those derived from the maximum)
+/// determine their order. Note that this constraint only propagates the bounds of the variables.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(0, 10);
+/// let y = solver.new_bounded_integer(2, 4);
+/// let z = solver.new_bounded_integer(6, 8);
+/// let b = solver.new_literal();
+///
+/// solver
+///     .add_constraint(constraints::binary_maximum(x, y, z, Some(b)))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The maximum can only be reached by `x`, which is therefore larger than `y`
+/// assert_eq!(solver.lower_bound(&x), 6);
+/// assert_eq!(solver.get_literal_value(b), Some(false));
+/// ```
+pub fn binary_maximum(
+    lhs: impl IntegerVariable + 'static,
+    rhs: impl IntegerVariable + 'static,
+    maximum: impl IntegerVariable + 'static,
+    comparison: Option<Literal>,
+) -> impl Constraint {
+    BinaryMaximumPropagator::new(lhs, rhs, maximum, comparison)
+}
+
+/// Creates the [`Constraint`] `min(lhs, rhs) = minimum`, which optionally also enforces
+/// `comparison <-> (lhs <= rhs)`.
+///
+/// See [`binary_maximum`] for more information.
+pub fn binary_minimum(
+    lhs: impl IntegerVariable + 'static,
+    rhs: impl IntegerVariable + 'static,
+    minimum: impl IntegerVariable + 'static,
+    comparison: Option<Literal>,
+) -> impl Constraint {
+    // `lhs <= rhs` holds if and only if `-rhs <= -lhs`, so the negated variables are swapped
+    BinaryMaximumPropagator::new(
+        rhs.scaled(-1),
+        lhs.scaled(-1),
+        minimum.scaled(-1),
+        comparison,
+    )
+}
@@ -1,9 +1,9 @@
 use std::num::NonZero;
 
 use super::equals;
-use super::less_than_or_equals;
 use super::Constraint;
 use crate::predicate;
+use crate::propagators::pseudo_boolean_less_or_equal::PseudoBooleanLessOrEqualPropagator;
 use crate::variables::AffineView;
 use crate::variables::DomainId;
 use crate::variables::Literal;
@@ -12,6 +12,35 @@ use crate::ConstraintOperationError;
 use crate::Solver;
 
 /// Creates the [`Constraint`] `\sum weights_i * bools_i <= rhs`.
+///
+/// The constraint is propagated directly over the literals (rather than through a linear sum over
+/// 0-1 integer variables); the weights can be negative.
+///
+/// # Example
+/// ```
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let a = solver.new_literal();
+/// let b = solver.new_literal();
+/// let c = solver.new_literal();
+///
+/// solver
+///     .add_constraint(constraints::boolean_less_than_or_equals(
+///         [2, 3, 4],
+///         [a, b, c],
+///         5,
+///     ))
+///     .post()
+///     .expect("the constraint is satisfiable");
+///
+/// // Setting `c` to true leaves no room for `b`
+/// solver
+///     .add_clause([c])
+///     .expect("the constraint is satisfiable");
+/// assert_eq!(solver.get_literal_value(b), Some(false));
+/// ```
 pub fn boolean_less_than_or_equals(
     weights: impl Into<Box<[i32]>>,
     bools: impl Into<Box<[Literal]>>,
@@ -49,9 +78,8 @@ impl Constraint for BooleanLessThanOrEqual {
         solver: &mut Solver,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        let domains = self.create_domains(solver);
-
-        less_than_or_equals(domains, self.rhs).post(solver, tag)
+        PseudoBooleanLessOrEqualPropagator::new(&self.weights, &self.bools, self.rhs)
+            .post(solver, tag)
     }
 
     fn implied_by(
@@ -60,34 +88,11 @@ impl Constraint for BooleanLessThanOrEqual {
         reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        let domains = self.create_domains(solver);
-
-        less_than_or_equals(domains, self.rhs).implied_by(solver, reification_literal, tag)
-    }
-}
-
-impl BooleanLessThanOrEqual {
-    fn create_domains(&self, solver: &mut Solver) -> Vec<AffineView<DomainId>> {
-        let domains = self
-            .bools
-            .iter()
-            .enumerate()
-            .map(|(index, bool)| {
-                let corresponding_domain_id = solver.new_bounded_integer(0, 1);
-                // bool -> [domain = 1]
-                let _ = solver.add_clause([
-                    !*bool,
-                    solver.get_literal(predicate![corresponding_domain_id >= 1]),
-                ]);
-                // !bool -> [domain = 0]
-                let _ = solver.add_clause([
-                    *bool,
-                    solver.get_literal(predicate![corresponding_domain_id <= 0]),
-                ]);
-                corresponding_domain_id.scaled(self.weights[index])
-            })
-            .collect::<Vec<_>>();
-        domains
+        PseudoBooleanLessOrEqualPropagator::new(&self.weights, &self.bools, self.rhs).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
     }
 }
 
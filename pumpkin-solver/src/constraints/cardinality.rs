@@ -0,0 +1,399 @@
+use std::fmt::Display;
+use std::num::NonZero;
+
+use super::Constraint;
+#[cfg(doc)]
+use crate::encodings::PseudoBooleanConstraintEncoder;
+use crate::propagators::pseudo_boolean_less_or_equal::PseudoBooleanLessOrEqualPropagator;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// The way in which the at-most-k constraint (see [`at_most_k`]) is enforced.
+///
+/// Both encodings only introduce the auxiliary literals which count up to `k + 1`, and unit
+/// propagation on either of them achieves domain consistency; they differ in their size. The
+/// sequential counter introduces `O(n * k)` literals and clauses, whereas the totalizer
+/// introduces `O(n * log(n))` literals and `O(n * k)` clauses (for `n` literals).
+///
+/// Note that these encodings are meant for constraints of the model, which can be reified; for
+/// encoding the (incrementally strengthened) upper bound on an objective, see the
+/// [`PseudoBooleanConstraintEncoder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CardinalityEncoding {
+    /// Propagates the constraint with a dedicated propagator which counts the true literals; this
+    /// does not introduce any auxiliary literals.
+    #[default]
+    Propagator,
+    /// Encodes the constraint into clauses using the sequential counter by Sinz \[1\].
+    ///
+    /// # Bibliography
+    /// \[1\] C. Sinz, ‘Towards an optimal CNF encoding of Boolean cardinality constraints’, in
+    /// International Conference on Principles and Practice of Constraint Programming, 2005, pp.
+    /// 827–831.
+    SequentialCounter,
+    /// Encodes the constraint into clauses using the totalizer by Bailleux and Boufkhad \[1\].
+    ///
+    /// # Bibliography
+    /// \[1\] O. Bailleux and Y. Boufkhad, ‘Efficient CNF encoding of Boolean cardinality
+    /// constraints’, in International Conference on Principles and Practice of Constraint
+    /// Programming, 2003, pp. 108–122.
+    Totalizer,
+}
+
+impl Display for CardinalityEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardinalityEncoding::Propagator => write!(f, "propagator"),
+            CardinalityEncoding::SequentialCounter => write!(f, "sequential-counter"),
+            CardinalityEncoding::Totalizer => write!(f, "totalizer"),
+        }
+    }
+}
+
+/// Creates the [`Constraint`] which states that at most `k` of the `literals` are true.
+///
+/// The constraint is propagated by a dedicated propagator (see
+/// [`CardinalityEncoding::Propagator`]); it can instead be encoded into clauses using
+/// [`at_most_k_with_options`]. The clauses of an encoding are only generated when the constraint
+/// is posted, and the encoding does not have to be chosen for all constraints at once.
+///
+/// # Example
+/// ```
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let a = solver.new_literal();
+/// let b = solver.new_literal();
+/// let c = solver.new_literal();
+///
+/// solver
+///     .add_constraint(constraints::at_most_k([a, b, c], 1))
+///     .post()
+///     .expect("the constraint is satisfiable");
+///
+/// // Once `a` is true, the other literals have to be false
+/// solver
+///     .add_clause([a])
+///     .expect("the constraint is satisfiable");
+/// assert_eq!(solver.get_literal_value(b), Some(false));
+/// assert_eq!(solver.get_literal_value(c), Some(false));
+/// ```
+pub fn at_most_k(literals: impl Into<Box<[Literal]>>, k: u32) -> impl Constraint {
+    at_most_k_with_options(literals, k, CardinalityEncoding::default())
+}
+
+/// Creates the [`Constraint`] which states that at most `k` of the `literals` are true, which is
+/// enforced using the provided [`CardinalityEncoding`].
+///
+/// Inferences of tagged constraints should be traced to the constraint, which is only possible if
+/// they are made by a propagator; a tagged constraint is therefore always propagated by the
+/// dedicated propagator, regardless of the provided encoding.
+///
+/// See the documentation of [`at_most_k`] for more information about the constraint.
+pub fn at_most_k_with_options(
+    literals: impl Into<Box<[Literal]>>,
+    k: u32,
+    encoding: CardinalityEncoding,
+) -> impl Constraint {
+    AtMostK {
+        literals: literals.into(),
+        k,
+        encoding,
+    }
+}
+
+struct AtMostK {
+    literals: Box<[Literal]>,
+    k: u32,
+    encoding: CardinalityEncoding,
+}
+
+impl AtMostK {
+    /// Returns whether the constraint is encoded into clauses rather than propagated by the
+    /// dedicated propagator; tagged constraints are always propagated.
+    fn is_encoded(&self, tag: Option<NonZero<u32>>) -> bool {
+        tag.is_none() && self.encoding != CardinalityEncoding::Propagator
+    }
+
+    fn into_propagator(self) -> PseudoBooleanLessOrEqualPropagator {
+        PseudoBooleanLessOrEqualPropagator::new(
+            &vec![1; self.literals.len()],
+            &self.literals,
+            self.k as i32,
+        )
+    }
+
+    /// Posts the encoding into clauses; the clauses which forbid more than `k` literals from
+    /// being true are extended with `!reification_literal`. The other clauses only define the
+    /// auxiliary literals, and can always be satisfied.
+    fn encode(
+        self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.literals.len() <= self.k as usize {
+            return Ok(());
+        }
+
+        let mut encoder = Encoder {
+            solver,
+            reification_literal,
+        };
+        match self.encoding {
+            CardinalityEncoding::SequentialCounter => {
+                encoder.sequential_counter(&self.literals, self.k as usize)
+            }
+            CardinalityEncoding::Totalizer => encoder.totalizer(&self.literals, self.k as usize),
+            CardinalityEncoding::Propagator => unreachable!("the propagator is not an encoding"),
+        }
+    }
+}
+
+impl Constraint for AtMostK {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.is_encoded(tag) {
+            self.encode(solver, None)
+        } else {
+            self.into_propagator().post(solver, tag)
+        }
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.is_encoded(tag) {
+            self.encode(solver, Some(reification_literal))
+        } else {
+            self.into_propagator()
+                .implied_by(solver, reification_literal, tag)
+        }
+    }
+}
+
+/// Adds the clauses of an encoding to the solver.
+struct Encoder<'a> {
+    solver: &'a mut Solver,
+    reification_literal: Option<Literal>,
+}
+
+impl Encoder<'_> {
+    /// Adds the clause which forbids that all `literals` are true.
+    fn forbid(&mut self, literals: &[Literal]) -> Result<(), ConstraintOperationError> {
+        self.solver.add_clause(
+            literals
+                .iter()
+                .map(|&literal| !literal)
+                .chain(self.reification_literal.map(|literal| !literal)),
+        )
+    }
+
+    /// Adds the clause which states that `literals` together imply `implied`.
+    fn imply(
+        &mut self,
+        literals: &[Literal],
+        implied: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        self.solver.add_clause(
+            literals
+                .iter()
+                .map(|&literal| !literal)
+                .chain(std::iter::once(implied)),
+        )
+    }
+
+    /// Encodes the constraint using a sequential counter; after processing a prefix of the
+    /// literals, `counter[j]` is implied if at least `j + 1` literals of the prefix are true. Only
+    /// the counts which can be reached by the prefix get a literal.
+    fn sequential_counter(
+        &mut self,
+        literals: &[Literal],
+        k: usize,
+    ) -> Result<(), ConstraintOperationError> {
+        if k == 0 {
+            return literals
+                .iter()
+                .try_for_each(|&literal| self.forbid(&[literal]));
+        }
+
+        let mut counter: Vec<Literal> = vec![];
+        for (index, &literal) in literals.iter().enumerate() {
+            if counter.len() == k {
+                self.forbid(&[literal, counter[k - 1]])?;
+            }
+
+            if index == literals.len() - 1 {
+                break;
+            }
+
+            let next_counter = (0..(counter.len() + 1).min(k))
+                .map(|_| self.solver.new_literal())
+                .collect::<Vec<_>>();
+            for (j, &count) in next_counter.iter().enumerate() {
+                if let Some(&previous) = counter.get(j) {
+                    self.imply(&[previous], count)?;
+                }
+                if j == 0 {
+                    self.imply(&[literal], count)?;
+                } else {
+                    self.imply(&[literal, counter[j - 1]], count)?;
+                }
+            }
+            counter = next_counter;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the constraint using a totalizer; the literals are recursively split into two
+    /// halves, and the counts of both halves are added in a new unary counter.
+    fn totalizer(
+        &mut self,
+        literals: &[Literal],
+        k: usize,
+    ) -> Result<(), ConstraintOperationError> {
+        let counter = self.totalizer_node(literals, k + 1)?;
+        if counter.len() > k {
+            self.forbid(&[counter[k]])?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the unary counter of `literals`, of which the literal at index `j` is implied if
+    /// at least `j + 1` of the literals are true; only the first `limit` counts are represented.
+    fn totalizer_node(
+        &mut self,
+        literals: &[Literal],
+        limit: usize,
+    ) -> Result<Vec<Literal>, ConstraintOperationError> {
+        if literals.len() == 1 {
+            return Ok(literals.to_vec());
+        }
+
+        let (left, right) = literals.split_at(literals.len() / 2);
+        let left = self.totalizer_node(left, limit)?;
+        let right = self.totalizer_node(right, limit)?;
+
+        let counter = (0..(left.len() + right.len()).min(limit))
+            .map(|_| self.solver.new_literal())
+            .collect::<Vec<_>>();
+        // A count of `i` in the left half and `j` in the right half implies a count of `i + j`
+        for i in 0..=left.len() {
+            for j in 0..=right.len() {
+                if i + j == 0 || i + j > counter.len() {
+                    continue;
+                }
+
+                let antecedents = left[..i]
+                    .last()
+                    .into_iter()
+                    .chain(right[..j].last())
+                    .copied()
+                    .collect::<Vec<_>>();
+                self.imply(&antecedents, counter[i + j - 1])?;
+            }
+        }
+
+        Ok(counter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the assignments to `num_literals` literals which are not rejected at the root.
+    fn count_solutions(num_literals: usize, k: u32, encoding: CardinalityEncoding) -> usize {
+        (0..1_u32 << num_literals)
+            .filter(|assignment| {
+                let mut solver = Solver::default();
+                let literals = (0..num_literals)
+                    .map(|_| solver.new_literal())
+                    .collect::<Vec<_>>();
+                solver
+                    .add_constraint(at_most_k_with_options(literals.clone(), k, encoding))
+                    .post()
+                    .expect("the constraint is satisfiable");
+
+                literals.iter().enumerate().all(|(index, &literal)| {
+                    let is_true = assignment & (1 << index) != 0;
+                    solver
+                        .add_clause([if is_true { literal } else { !literal }])
+                        .is_ok()
+                })
+            })
+            .count()
+    }
+
+    #[test]
+    fn encodings_remove_assignments_with_more_than_k_true_literals() {
+        for encoding in [
+            CardinalityEncoding::Propagator,
+            CardinalityEncoding::SequentialCounter,
+            CardinalityEncoding::Totalizer,
+        ] {
+            // There are 1 + 5 + 10 assignments of 5 literals with at most 2 true literals
+            assert_eq!(16, count_solutions(5, 2, encoding), "{encoding}");
+            assert_eq!(1, count_solutions(4, 0, encoding), "{encoding}");
+        }
+    }
+
+    #[test]
+    fn reified_encoding_only_applies_when_the_literal_is_true() {
+        for encoding in [
+            CardinalityEncoding::SequentialCounter,
+            CardinalityEncoding::Totalizer,
+        ] {
+            let mut solver = Solver::default();
+            let reification_literal = solver.new_literal();
+            let literals = (0..3).map(|_| solver.new_literal()).collect::<Vec<_>>();
+            solver
+                .add_constraint(at_most_k_with_options(literals.clone(), 1, encoding))
+                .implied_by(reification_literal)
+                .expect("the constraint is satisfiable");
+
+            solver
+                .add_clause([literals[0]])
+                .expect("the constraint is satisfiable");
+            solver
+                .add_clause([literals[1]])
+                .expect("the constraint is satisfiable");
+            assert_eq!(
+                solver.get_literal_value(reification_literal),
+                Some(false),
+                "{encoding}"
+            );
+        }
+    }
+
+    #[test]
+    fn tagged_encoding_is_propagated_by_the_propagator() {
+        let mut solver = Solver::default();
+        let literals = (0..3).map(|_| solver.new_literal()).collect::<Vec<_>>();
+        let num_propagators = solver.num_propagators();
+        solver
+            .add_constraint(at_most_k_with_options(
+                literals.clone(),
+                1,
+                CardinalityEncoding::Totalizer,
+            ))
+            .with_tag(NonZero::new(1).unwrap())
+            .post()
+            .expect("the constraint is satisfiable");
+
+        assert_eq!(num_propagators + 1, solver.num_propagators());
+        solver
+            .add_clause([literals[0]])
+            .expect("the constraint is satisfiable");
+        assert_eq!(solver.get_literal_value(literals[1]), Some(false));
+    }
+}
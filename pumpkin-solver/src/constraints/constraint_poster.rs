@@ -4,6 +4,7 @@ use log::warn;
 
 use super::Constraint;
 use super::NegatableConstraint;
+use crate::api::handles::ConstraintId;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
@@ -39,9 +40,35 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
     /// Add the [`Constraint`] to the [`Solver`].
     ///
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
-    /// to a root-level conflict.
-    pub fn post(mut self) -> Result<(), ConstraintOperationError> {
-        self.constraint.take().unwrap().post(self.solver, self.tag)
+    /// to a root-level conflict. The [`Solver`] then remembers that it is infeasible; adding
+    /// further constraints fails with [`ConstraintOperationError::InfeasibleState`], and solving
+    /// immediately reports that the problem is unsatisfiable.
+    ///
+    /// If a scope is open (see [`Solver::push`]), then the constraint is only enforced while the
+    /// scope remains open. A root-level conflict then only makes the open scopes infeasible, which
+    /// is reported as [`ConstraintOperationError::InfeasibleScope`]; the [`Solver`] is feasible
+    /// again once the innermost scope is closed.
+    pub fn post(self) -> Result<(), ConstraintOperationError> {
+        self.post_with_id().map(|_| ())
+    }
+
+    /// Add the [`Constraint`] to the [`Solver`] similar to [`ConstraintPoster::post`], and return
+    /// the stable handle of the posted constraint.
+    pub fn post_with_id(mut self) -> Result<ConstraintId, ConstraintOperationError> {
+        if let Some(scope_literal) = self.solver.get_scope_literal() {
+            let constraint_id = self.post_implied_by(scope_literal)?;
+            // The constraint is conditioned on the activation literal, which is falsified if the
+            // constraint is infeasible at the root
+            if self.solver.get_literal_value(scope_literal) == Some(false) {
+                return Err(ConstraintOperationError::InfeasibleScope);
+            }
+            return Ok(constraint_id);
+        }
+
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+        self.solver
+            .post_constraint(|solver| constraint.post(solver, tag))
     }
 
     /// Add the half-reified version of the [`Constraint`] to the [`Solver`]; i.e. post the
@@ -49,14 +76,37 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
     ///
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
-    pub fn implied_by(
+    pub fn implied_by(self, reification_literal: Literal) -> Result<(), ConstraintOperationError> {
+        self.implied_by_with_id(reification_literal).map(|_| ())
+    }
+
+    /// Add the half-reified version of the [`Constraint`] to the [`Solver`] similar to
+    /// [`ConstraintPoster::implied_by`], and return the stable handle of the posted constraint.
+    pub fn implied_by_with_id(
         mut self,
         reification_literal: Literal,
-    ) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .implied_by(self.solver, reification_literal, self.tag)
+    ) -> Result<ConstraintId, ConstraintOperationError> {
+        self.post_implied_by(reification_literal)
+    }
+
+    fn post_implied_by(
+        &mut self,
+        reification_literal: Literal,
+    ) -> Result<ConstraintId, ConstraintOperationError> {
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+        self.solver.post_constraint(|solver| {
+            // Within a scope, the constraint is implied by `r /\ a` for the activation literal `a`
+            let reification_literal = match solver.get_scope_literal() {
+                Some(scope_literal) if scope_literal != reification_literal => {
+                    let literal = solver.new_literal();
+                    solver.add_clause([!scope_literal, !reification_literal, literal])?;
+                    literal
+                }
+                _ => reification_literal,
+            };
+            constraint.implied_by(solver, reification_literal, tag)
+        })
     }
 }
 
@@ -66,11 +116,32 @@ impl<ConstraintImpl: NegatableConstraint> ConstraintPoster<'_, ConstraintImpl> {
     ///
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
-    pub fn reify(mut self, reification_literal: Literal) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .reify(self.solver, reification_literal, self.tag)
+    pub fn reify(self, reification_literal: Literal) -> Result<(), ConstraintOperationError> {
+        self.reify_with_id(reification_literal).map(|_| ())
+    }
+
+    /// Add the reified version of the [`Constraint`] to the [`Solver`] similar to
+    /// [`ConstraintPoster::reify`], and return the stable handle of the posted constraint.
+    pub fn reify_with_id(
+        mut self,
+        reification_literal: Literal,
+    ) -> Result<ConstraintId, ConstraintOperationError> {
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+        self.solver.post_constraint(|solver| {
+            // Within a scope, `r <-> constraint` is only enforced while the activation literal `a`
+            // holds; the constraint is reified by a fresh literal which is equal to `r` under `a`
+            let reification_literal = match solver.get_scope_literal() {
+                Some(scope_literal) => {
+                    let literal = solver.new_literal();
+                    solver.add_clause([!scope_literal, !literal, reification_literal])?;
+                    solver.add_clause([!scope_literal, literal, !reification_literal])?;
+                    literal
+                }
+                None => reification_literal,
+            };
+            constraint.reify(solver, reification_literal, tag)
+        })
     }
 }
 
@@ -81,3 +152,106 @@ impl<ConstraintImpl> Drop for ConstraintPoster<'_, ConstraintImpl> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints;
+    use crate::results::ProblemSolution;
+    use crate::results::SatisfactionResult;
+    use crate::variables::TransformableVariable;
+
+    #[test]
+    fn propagators_of_closed_scopes_are_removed() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let num_propagators = solver.num_propagators();
+
+        solver.push();
+        let constraint_id = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x, y], 3))
+            .post_with_id()
+            .expect("the constraint is feasible");
+        assert!(solver.num_propagators() > num_propagators);
+        let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+            panic!("expected a solution");
+        };
+        assert!(solution.get_integer_value(x) + solution.get_integer_value(y) <= 3);
+
+        assert!(solver.pop());
+        assert_eq!(solver.num_propagators(), num_propagators);
+        assert_eq!(solver.describe_constraint(constraint_id), None);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                vec![x.scaled(-1), y.scaled(-1)],
+                -20,
+            ))
+            .post();
+        let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+            panic!("expected a solution");
+        };
+        assert_eq!(solution.get_integer_value(x), 10);
+    }
+
+    #[test]
+    fn constraints_which_are_infeasible_within_a_scope_are_reported() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        solver.push();
+        let result = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x, y], -1))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasibleScope)
+        ));
+        assert!(matches!(
+            solver.solve(None),
+            SatisfactionResult::Unsatisfiable(_)
+        ));
+
+        assert!(solver.pop());
+        assert!(matches!(
+            solver.solve(None),
+            SatisfactionResult::Satisfiable(_)
+        ));
+    }
+
+    #[test]
+    fn reified_constraints_of_closed_scopes_are_removed() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let r = solver.new_literal();
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x.scaled(-1)], -5))
+            .post();
+
+        // Since `x >= 5`, the reification literal is false within the scope
+        solver.push();
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(vec![x], 3))
+            .reify(r);
+        let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+            panic!("expected a solution");
+        };
+        assert!(!solution.get_literal_value(r));
+
+        assert!(solver.add_clause([r]).is_ok());
+        assert!(matches!(
+            solver.solve(None),
+            SatisfactionResult::Unsatisfiable(_)
+        ));
+
+        // Closing the scope after an unsatisfiable solve removes the reification
+        assert!(solver.pop());
+        assert!(solver.add_clause([r]).is_ok());
+        let SatisfactionResult::Satisfiable(solution) = solver.solve(None) else {
+            panic!("expected a solution");
+        };
+        assert!(solution.get_literal_value(r));
+        assert!(solution.get_integer_value(x) >= 5);
+    }
+}
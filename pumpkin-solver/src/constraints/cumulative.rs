@@ -1,15 +1,24 @@
 use std::fmt::Debug;
 use std::num::NonZero;
 
+use super::boolean_less_than_or_equals;
+use super::disjunctive_with_optional_tasks;
+use super::validation::check_lengths;
+use super::validation::check_non_negative;
+use super::validation::check_non_negative_elements;
+use super::validation::ValidatedConstraint;
 use super::Constraint;
 use crate::options::CumulativePropagationMethod;
+use crate::predicate;
 use crate::propagators::ArgTask;
 use crate::propagators::CumulativeOptions;
+use crate::propagators::EdgeFindingPropagator;
+use crate::propagators::EnergeticReasoningPropagator;
+use crate::propagators::TimeTableEdgeFindingPropagator;
 use crate::propagators::TimeTableOverIntervalIncrementalPropagator;
 use crate::propagators::TimeTableOverIntervalPropagator;
 use crate::propagators::TimeTablePerPointIncrementalPropagator;
 use crate::propagators::TimeTablePerPointPropagator;
-use crate::pumpkin_assert_simple;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
@@ -24,8 +33,21 @@ use crate::Solver;
 /// reasoning, see \[1], note that it does **not** implement the specific algorithm in the paper
 /// but that the reasoning used is the same).
 ///
-/// The length of `start_times`, `durations` and `resource_requirements` should be the same; if
-/// this is not the case then this method will panic.
+/// The length of `start_times`, `durations` and `resource_requirements` should be the same, and
+/// the durations, resource requirements and resource capacity should be non-negative; otherwise,
+/// posting the constraint fails with [`ConstraintOperationError::InvalidArgument`].
+///
+/// If [`CumulativeOptions::specialisation`] is set and the tasks allow it, the constraint is
+/// propagated by a specialised propagator rather than by time-tabling:
+/// - If no two tasks can execute at the same time (e.g. if all tasks have a resource requirement of
+///   1 and the capacity is 1), then the constraint is propagated as a [`disjunctive`] constraint.
+/// - If all tasks have a duration of 1, then the tasks which start at a time point cannot exceed
+///   the capacity; this is propagated per time point (similar to a global cardinality constraint)
+///   which removes the time points at which a task does not fit from its domain. Only the time
+///   points at which the tasks which can start there exceed the capacity are considered.
+///
+/// Tasks with a duration or resource requirement of 0 do not use the resource and are ignored
+/// when detecting (and propagating) these cases.
 ///
 /// It is possible to specify certain options for the cumulative (such as whether to allow holes in
 /// the domain or the type of explanation) using [`cumulative_with_options`].
@@ -164,35 +186,247 @@ where
     ResourceRequirements::IntoIter: ExactSizeIterator,
 {
     let start_times = start_times.into_iter();
-    let durations = durations.into_iter();
-    let resource_requirements = resource_requirements.into_iter();
-
-    pumpkin_assert_simple!(
-        start_times.len() == durations.len() && durations.len() == resource_requirements.len(),
-        "The number of start variables, durations and resource requirements should be the same!"
-    );
-
-    CumulativeConstraint::new(
-        &start_times
-            .zip(durations)
-            .zip(resource_requirements)
-            .map(|((start_time, duration), resource_requirement)| ArgTask {
-                start_time,
-                processing_time: duration,
-                resource_usage: resource_requirement,
-            })
-            .collect::<Vec<_>>(),
+    let presence_literals = vec![None; start_times.len()];
+
+    cumulative_with_optional_tasks(
+        start_times,
+        durations,
+        resource_requirements,
+        presence_literals,
         resource_capacity,
         options,
     )
 }
 
+/// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
+/// over tasks which can be optional (e.g. for flexible job-shop problems).
+///
+/// A task with a presence literal only uses the resource if its presence literal is true; if the
+/// presence literal is [`None`] then the task is always present. If an optional task cannot be
+/// scheduled anymore then its presence literal is set to false. The bounds of an optional task are
+/// only propagated once it is known to be present.
+///
+/// Optional tasks are only supported by the non-incremental time-table propagators, so if there
+/// are optional tasks then the non-incremental variant of the
+/// [`CumulativeOptions::propagation_method`] is used; the energetic reasoning and edge-finding
+/// propagators (if enabled) only consider the tasks which are always present.
+///
+/// The length of `start_times`, `durations`, `resource_requirements` and `presence_literals`
+/// should be the same, and the durations, resource requirements and resource capacity should be
+/// non-negative; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// # Example
+/// ```rust
+/// // Two optional tasks which cannot both be scheduled in the horizon on a resource with capacity 1
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::options::CumulativeOptions;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let start_0 = solver.new_bounded_integer(0, 2);
+/// let start_1 = solver.new_bounded_integer(0, 2);
+/// let present_0 = solver.new_literal();
+/// let present_1 = solver.new_literal();
+///
+/// solver
+///     .add_constraint(constraints::cumulative_with_optional_tasks(
+///         [start_0, start_1],
+///         [3, 3],
+///         [1, 1],
+///         [Some(present_0), Some(present_1)],
+///         1,
+///         CumulativeOptions::default(),
+///     ))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // Once the first task is present (and thus executes at time 2), the second task no longer fits
+/// solver
+///     .add_clause([present_0])
+///     .expect("no conflict at the root");
+/// assert_eq!(solver.get_literal_value(present_1), Some(false));
+/// ```
+pub fn cumulative_with_optional_tasks<StartTimes, Durations, ResourceRequirements, Presences>(
+    start_times: StartTimes,
+    durations: Durations,
+    resource_requirements: ResourceRequirements,
+    presence_literals: Presences,
+    resource_capacity: i32,
+    options: CumulativeOptions,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i32>,
+    Durations::IntoIter: ExactSizeIterator,
+    ResourceRequirements: IntoIterator<Item = i32>,
+    ResourceRequirements::IntoIter: ExactSizeIterator,
+    Presences: IntoIterator<Item = Option<Literal>>,
+    Presences::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter().collect::<Vec<_>>();
+    let durations = durations.into_iter().collect::<Vec<_>>();
+    let resource_requirements = resource_requirements.into_iter().collect::<Vec<_>>();
+    let presence_literals = presence_literals.into_iter().collect::<Vec<_>>();
+
+    let validation = check_lengths(
+        ("start_times", start_times.len()),
+        &[
+            ("durations", durations.len()),
+            ("resource_requirements", resource_requirements.len()),
+            ("presence_literals", presence_literals.len()),
+        ],
+    )
+    .and_then(|_| check_non_negative_elements("durations", &durations))
+    .and_then(|_| check_non_negative_elements("resource_requirements", &resource_requirements))
+    .and_then(|_| check_non_negative("resource_capacity", resource_capacity));
+
+    ValidatedConstraint::new(validation, || {
+        CumulativeConstraint::new(
+            &start_times
+                .into_iter()
+                .zip(durations)
+                .zip(resource_requirements)
+                .zip(presence_literals)
+                .map(
+                    |(((start_time, duration), resource_requirement), presence)| ArgTask {
+                        start_time,
+                        processing_time: duration,
+                        resource_usage: resource_requirement,
+                        presence,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                )
+                .collect::<Vec<_>>(),
+            resource_capacity,
+            options,
+        )
+    })
+}
+
+/// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
+/// over tasks of which the duration and the resource requirement are variables (e.g. for tasks
+/// which can trade off their duration and resource usage).
+///
+/// The time-table is constructed using the lower-bounds of the durations and resource
+/// requirements, and the explanations contain the lower-bounds which are used; the durations and
+/// resource requirements themselves are not propagated. The durations and resource requirements are
+/// constrained to be non-negative.
+///
+/// Variable tasks are only supported by the non-incremental time-table propagators, so the
+/// non-incremental variant of the [`CumulativeOptions::propagation_method`] is used; the energetic
+/// reasoning and edge-finding propagators (if enabled) use the lower-bounds of the durations and
+/// resource requirements at the root.
+///
+/// The length of `start_times`, `durations` and `resource_requirements` should be the same and the
+/// resource capacity should be non-negative; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// # Example
+/// ```rust
+/// // Two tasks which overlap at time 2 on a resource with capacity 3; once the resource usage of
+/// // the second task is at least 2, the first task has to start later
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::options::CumulativeOptions;
+/// # use pumpkin_solver::predicate;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let start_0 = solver.new_bounded_integer(1, 5);
+/// let start_1 = solver.new_bounded_integer(2, 2);
+/// let duration_0 = solver.new_bounded_integer(2, 2);
+/// let duration_1 = solver.new_bounded_integer(1, 3);
+/// let usage_0 = solver.new_bounded_integer(2, 2);
+/// let usage_1 = solver.new_bounded_integer(1, 3);
+///
+/// solver
+///     .add_constraint(constraints::cumulative_with_variable_tasks(
+///         [start_0, start_1],
+///         [duration_0, duration_1],
+///         [usage_0, usage_1],
+///         3,
+///         CumulativeOptions::default(),
+///     ))
+///     .post()
+///     .expect("no conflict at the root");
+/// assert_eq!(solver.lower_bound(&start_0), 1);
+///
+/// let usage_at_least_two = solver.get_literal(predicate![usage_1 >= 2]);
+/// solver
+///     .add_clause([usage_at_least_two])
+///     .expect("no conflict at the root");
+/// assert_eq!(solver.lower_bound(&start_0), 3);
+/// ```
+pub fn cumulative_with_variable_tasks<StartTimes, Durations, ResourceRequirements>(
+    start_times: StartTimes,
+    durations: Durations,
+    resource_requirements: ResourceRequirements,
+    resource_capacity: i32,
+    options: CumulativeOptions,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = StartTimes::Item>,
+    Durations::IntoIter: ExactSizeIterator,
+    ResourceRequirements: IntoIterator<Item = StartTimes::Item>,
+    ResourceRequirements::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter().collect::<Vec<_>>();
+    let durations = durations.into_iter().collect::<Vec<_>>();
+    let resource_requirements = resource_requirements.into_iter().collect::<Vec<_>>();
+
+    let validation = check_lengths(
+        ("start_times", start_times.len()),
+        &[
+            ("durations", durations.len()),
+            ("resource_requirements", resource_requirements.len()),
+        ],
+    )
+    .and_then(|_| check_non_negative("resource_capacity", resource_capacity));
+
+    ValidatedConstraint::new(validation, || {
+        CumulativeConstraint::new(
+            &start_times
+                .into_iter()
+                .zip(durations)
+                .zip(resource_requirements)
+                .map(|((start_time, duration), resource_requirement)| ArgTask {
+                    start_time,
+                    // The lower-bounds are set once the constraint is posted
+                    processing_time: 0,
+                    resource_usage: 0,
+                    presence: None,
+                    processing_time_variable: Some(duration),
+                    resource_usage_variable: Some(resource_requirement),
+                })
+                .collect::<Vec<_>>(),
+            resource_capacity,
+            options,
+        )
+    })
+}
+
 struct CumulativeConstraint<Var> {
     tasks: Vec<ArgTask<Var>>,
     resource_capacity: i32,
     options: CumulativeOptions,
 }
 
+/// The specialised propagation which is used for a [`CumulativeConstraint`] of which the tasks
+/// allow it (see [`cumulative`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Specialisation {
+    /// No two tasks can execute at the same time, so the tasks are processed on a unary resource.
+    Disjunctive,
+    /// All tasks have a duration of 1, so the resource usage can be bounded per time point.
+    UnitDuration,
+}
+
 impl<Var: IntegerVariable + 'static> CumulativeConstraint<Var> {
     fn new(tasks: &[ArgTask<Var>], resource_capacity: i32, options: CumulativeOptions) -> Self {
         Self {
@@ -201,15 +435,244 @@ impl<Var: IntegerVariable + 'static> CumulativeConstraint<Var> {
             options,
         }
     }
+
+    /// The time-table propagation method which is used; the incremental time-table propagators do
+    /// not support optional or variable tasks, so their non-incremental variant is used if there
+    /// are any.
+    fn propagation_method(&self) -> CumulativePropagationMethod {
+        if self.tasks.iter().all(|task| {
+            task.presence.is_none()
+                && task.processing_time_variable.is_none()
+                && task.resource_usage_variable.is_none()
+        }) {
+            return self.options.propagation_method;
+        }
+
+        match self.options.propagation_method {
+            CumulativePropagationMethod::TimeTablePerPoint
+            | CumulativePropagationMethod::TimeTablePerPointIncremental
+            | CumulativePropagationMethod::TimeTablePerPointIncrementalSynchronised => {
+                CumulativePropagationMethod::TimeTablePerPoint
+            }
+            CumulativePropagationMethod::TimeTableOverInterval
+            | CumulativePropagationMethod::TimeTableOverIntervalIncremental
+            | CumulativePropagationMethod::TimeTableOverIntervalIncrementalSynchronised => {
+                CumulativePropagationMethod::TimeTableOverInterval
+            }
+        }
+    }
+
+    /// The tasks which use the resource if they are present (i.e. their duration and resource
+    /// requirement are positive).
+    fn resource_using_tasks(&self) -> impl Iterator<Item = &ArgTask<Var>> + '_ {
+        self.tasks
+            .iter()
+            .filter(|task| task.processing_time > 0 && task.resource_usage > 0)
+    }
+
+    /// Returns the [`Specialisation`] which is used to propagate the constraint, if any; variable
+    /// tasks are always propagated using time-tabling.
+    fn specialisation(&self) -> Option<Specialisation> {
+        if !self.options.specialisation
+            || self.tasks.iter().any(|task| {
+                task.processing_time_variable.is_some() || task.resource_usage_variable.is_some()
+            })
+            || self.resource_using_tasks().next().is_none()
+        {
+            return None;
+        }
+
+        if self
+            .resource_using_tasks()
+            .all(|task| task.processing_time == 1 && task.presence.is_none())
+        {
+            Some(Specialisation::UnitDuration)
+        } else if self.resource_using_tasks().all(|task| {
+            task.resource_usage <= self.resource_capacity
+                && 2 * i64::from(task.resource_usage) > i64::from(self.resource_capacity)
+        }) {
+            Some(Specialisation::Disjunctive)
+        } else {
+            None
+        }
+    }
+
+    /// Posts the provided [`Specialisation`] of the constraint; if a `reification_literal` is
+    /// provided, then the constraint is only enforced if it holds.
+    fn post_specialisation(
+        self,
+        solver: &mut Solver,
+        specialisation: Specialisation,
+        reification_literal: Option<Literal>,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        let tasks = self.resource_using_tasks().cloned().collect::<Vec<_>>();
+
+        match specialisation {
+            Specialisation::Disjunctive => {
+                let constraint = disjunctive_with_optional_tasks(
+                    tasks
+                        .iter()
+                        .map(|task| task.start_time.clone())
+                        .collect::<Vec<_>>(),
+                    tasks
+                        .iter()
+                        .map(|task| task.processing_time)
+                        .collect::<Vec<_>>(),
+                    tasks.iter().map(|task| task.presence).collect::<Vec<_>>(),
+                );
+                match reification_literal {
+                    Some(literal) => constraint.implied_by(solver, literal, tag),
+                    None => constraint.post(solver, tag),
+                }
+            }
+            Specialisation::UnitDuration => {
+                for time in self.overloaded_time_points(solver, &tasks) {
+                    let (resource_usages, starts_at_time): (Vec<i32>, Vec<Literal>) = tasks
+                        .iter()
+                        .filter(|task| {
+                            solver.lower_bound(&task.start_time) <= time
+                                && time <= solver.upper_bound(&task.start_time)
+                        })
+                        .map(|task| {
+                            let start_time = &task.start_time;
+                            (
+                                task.resource_usage,
+                                solver.get_literal(predicate![start_time == time]),
+                            )
+                        })
+                        .filter(|&(_, literal)| solver.get_literal_value(literal) != Some(false))
+                        .unzip();
+                    if resource_usages
+                        .iter()
+                        .map(|&usage| i64::from(usage))
+                        .sum::<i64>()
+                        <= i64::from(self.resource_capacity)
+                    {
+                        continue;
+                    }
+
+                    let constraint = boolean_less_than_or_equals(
+                        resource_usages,
+                        starts_at_time,
+                        self.resource_capacity,
+                    );
+                    match reification_literal {
+                        Some(literal) => constraint.implied_by(solver, literal, tag),
+                        None => constraint.post(solver, tag),
+                    }?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the time points at which the resource usage of the (unit duration) `tasks` which can
+    /// start there exceeds the capacity; at the other time points, the tasks always fit.
+    ///
+    /// The time points are found by sweeping over the bounds of the start times, so the time
+    /// points which are not within the bounds of any two tasks are never visited.
+    fn overloaded_time_points(&self, solver: &Solver, tasks: &[ArgTask<Var>]) -> Vec<i32> {
+        let mut events = tasks
+            .iter()
+            .flat_map(|task| {
+                let resource_usage = i64::from(task.resource_usage);
+                [
+                    (
+                        i64::from(solver.lower_bound(&task.start_time)),
+                        resource_usage,
+                    ),
+                    (
+                        i64::from(solver.upper_bound(&task.start_time)) + 1,
+                        -resource_usage,
+                    ),
+                ]
+            })
+            .collect::<Vec<_>>();
+        events.sort_unstable();
+
+        let mut time_points = vec![];
+        let mut resource_usage = 0;
+        for (index, &(time, change)) in events.iter().enumerate() {
+            resource_usage += change;
+
+            let is_last_event_at_time = events
+                .get(index + 1)
+                .is_none_or(|&(next_time, _)| next_time != time);
+            if is_last_event_at_time && resource_usage > i64::from(self.resource_capacity) {
+                // The usage only changes at the next event, which exists since every task which
+                // starts being considered also stops being considered
+                let next_time = events[index + 1].0;
+                time_points.extend(
+                    (time..next_time).map(|time| i32::try_from(time).expect("within the bounds")),
+                );
+            }
+        }
+
+        time_points
+    }
+
+    /// The tasks which are always present; these are the only tasks which are considered by the
+    /// energetic reasoning and edge-finding propagators, which use the lower-bounds at the root of
+    /// variable durations and resource usages.
+    fn mandatory_tasks(&self) -> Vec<ArgTask<Var>> {
+        self.tasks
+            .iter()
+            .filter(|task| task.presence.is_none())
+            .map(|task| ArgTask {
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                ..task.clone()
+            })
+            .collect()
+    }
+
+    /// Constrains the variable durations and resource usages to be non-negative (if
+    /// `reification_literal` holds) and sets the constant durations and resource usages of the
+    /// variable tasks to the lower-bounds of their variables at the root.
+    fn initialise_variable_parameters(
+        &mut self,
+        solver: &mut Solver,
+        reification_literal: Option<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        for task in self.tasks.iter_mut() {
+            for (variable, value) in [
+                (&task.processing_time_variable, &mut task.processing_time),
+                (&task.resource_usage_variable, &mut task.resource_usage),
+            ] {
+                let Some(variable) = variable else {
+                    continue;
+                };
+
+                let is_non_negative = solver.get_literal(predicate![variable >= 0]);
+                solver.add_clause(
+                    reification_literal
+                        .map(|literal| !literal)
+                        .into_iter()
+                        .chain([is_non_negative]),
+                )?;
+                *value = solver.lower_bound(variable).max(0);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint<Var> {
     fn post(
-        self,
+        mut self,
         solver: &mut Solver,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        match self.options.propagation_method {
+        if let Some(specialisation) = self.specialisation() {
+            return self.post_specialisation(solver, specialisation, None, tag);
+        }
+
+        self.initialise_variable_parameters(solver, None)?;
+
+        match self.propagation_method() {
             CumulativePropagationMethod::TimeTablePerPoint => TimeTablePerPointPropagator::new(
                 &self.tasks,
                 self.resource_capacity,
@@ -257,16 +720,56 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
                 )
                 .post(solver, tag)
             }
+        }?;
+
+        if self.options.energetic_reasoning {
+            EnergeticReasoningPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .post(solver, tag)?;
+        }
+
+        if self.options.edge_finding {
+            EdgeFindingPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .post(solver, tag)?;
+        }
+
+        if self.options.time_table_edge_finding {
+            TimeTableEdgeFindingPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .post(solver, tag)?;
         }
+
+        Ok(())
     }
 
     fn implied_by(
-        self,
+        mut self,
         solver: &mut Solver,
         reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        match self.options.propagation_method {
+        if let Some(specialisation) = self.specialisation() {
+            return self.post_specialisation(
+                solver,
+                specialisation,
+                Some(reification_literal),
+                tag,
+            );
+        }
+
+        self.initialise_variable_parameters(solver, Some(reification_literal))?;
+
+        match self.propagation_method() {
             CumulativePropagationMethod::TimeTablePerPoint => TimeTablePerPointPropagator::new(
                 &self.tasks,
                 self.resource_capacity,
@@ -313,6 +816,35 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
                 )
                 .implied_by(solver, reification_literal, tag)
             }
+        }?;
+
+        if self.options.energetic_reasoning {
+            EnergeticReasoningPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .implied_by(solver, reification_literal, tag)?;
         }
+
+        if self.options.edge_finding {
+            EdgeFindingPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .implied_by(solver, reification_literal, tag)?;
+        }
+
+        if self.options.time_table_edge_finding {
+            TimeTableEdgeFindingPropagator::new(
+                &self.mandatory_tasks(),
+                self.resource_capacity,
+                self.options.propagator_options,
+            )
+            .implied_by(solver, reification_literal, tag)?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,194 @@
+use std::fmt::Debug;
+use std::num::NonZero;
+
+use super::cumulative;
+use super::validation::check_lengths;
+use super::validation::check_non_negative_elements;
+use super::validation::ValidatedConstraint;
+use super::Constraint;
+use crate::options::DiffnOptions;
+use crate::propagators::ArgRectangle;
+use crate::propagators::DiffnPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [Diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html) [`Constraint`] in two
+/// dimensions, which states that no two rectangles overlap (e.g. for rectangle packing).
+///
+/// Rectangle `i` has its origin (i.e. its bottom-left corner) at `(x[i], y[i])`, and has a width
+/// of `widths[i]` and a height of `heights[i]`. Two rectangles do not overlap if one of them is
+/// placed completely to the left of or below the other one; note that this also applies to
+/// rectangles with a width or height of zero.
+///
+/// The constraint is propagated by reasoning over every pair of rectangles; the projections of the
+/// rectangles onto both axes can additionally be propagated as cumulative constraints using
+/// [`diffn_with_options`].
+///
+/// The length of `x`, `y`, `widths` and `heights` should be the same and the widths and heights
+/// should be non-negative; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// // Two 2x2 squares in a 3x2 area
+/// let x_0 = solver.new_bounded_integer(0, 1);
+/// let y_0 = solver.new_bounded_integer(0, 0);
+/// let x_1 = solver.new_bounded_integer(0, 3);
+/// let y_1 = solver.new_bounded_integer(0, 0);
+///
+/// solver
+///     .add_constraint(constraints::diffn([x_0, x_1], [y_0, y_1], [2, 2], [2, 2]))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The second square cannot be placed left of the first one, so it is placed to its right
+/// assert_eq!(solver.lower_bound(&x_1), 2);
+/// ```
+pub fn diffn<Var: IntegerVariable + Debug + 'static>(
+    x: impl IntoIterator<Item = Var>,
+    y: impl IntoIterator<Item = Var>,
+    widths: impl IntoIterator<Item = i32>,
+    heights: impl IntoIterator<Item = i32>,
+) -> impl Constraint {
+    diffn_with_options(x, y, widths, heights, DiffnOptions::default())
+}
+
+/// Creates the [Diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html) [`Constraint`] with the
+/// provided [`DiffnOptions`].
+///
+/// If [`DiffnOptions::cumulative_relaxations`] is enabled, then the rectangles are also posted as
+/// a cumulative constraint along either axis: along the x-axis, rectangle `i` is a task which
+/// starts at `x[i]` with duration `widths[i]` and resource requirement `heights[i]`, and the
+/// capacity is the height of the area in which the rectangles are placed (based on the bounds of
+/// `y` when the constraint is posted).
+///
+/// See the documentation of [`diffn`] for more information about the constraint.
+pub fn diffn_with_options<Var: IntegerVariable + Debug + 'static>(
+    x: impl IntoIterator<Item = Var>,
+    y: impl IntoIterator<Item = Var>,
+    widths: impl IntoIterator<Item = i32>,
+    heights: impl IntoIterator<Item = i32>,
+    options: DiffnOptions,
+) -> impl Constraint {
+    let x = x.into_iter().collect::<Vec<_>>();
+    let y = y.into_iter().collect::<Vec<_>>();
+    let widths = widths.into_iter().collect::<Vec<_>>();
+    let heights = heights.into_iter().collect::<Vec<_>>();
+
+    let validation = check_lengths(
+        ("x", x.len()),
+        &[
+            ("y", y.len()),
+            ("widths", widths.len()),
+            ("heights", heights.len()),
+        ],
+    )
+    .and_then(|_| check_non_negative_elements("widths", &widths))
+    .and_then(|_| check_non_negative_elements("heights", &heights));
+
+    ValidatedConstraint::new(validation, || DiffnConstraint {
+        rectangles: x
+            .into_iter()
+            .zip(y)
+            .zip(widths)
+            .zip(heights)
+            .map(|(((x, y), width), height)| ArgRectangle {
+                x,
+                y,
+                width,
+                height,
+            })
+            .collect(),
+        options,
+    })
+}
+
+struct DiffnConstraint<Var> {
+    rectangles: Vec<ArgRectangle<Var>>,
+    options: DiffnOptions,
+}
+
+impl<Var: IntegerVariable + Debug + 'static> DiffnConstraint<Var> {
+    /// Returns the cumulative constraints over the projections of the rectangles onto the x-axis
+    /// and onto the y-axis, of which the capacity is the size of the area in which the rectangles
+    /// are placed along the other axis.
+    fn cumulative_relaxations(&self, solver: &Solver) -> [impl Constraint; 2] {
+        let x = self.rectangles.iter().map(|rectangle| rectangle.x.clone());
+        let y = self.rectangles.iter().map(|rectangle| rectangle.y.clone());
+        let widths = self.rectangles.iter().map(|rectangle| rectangle.width);
+        let heights = self.rectangles.iter().map(|rectangle| rectangle.height);
+
+        let total_height = span(solver, y.clone().zip(heights.clone()));
+        let total_width = span(solver, x.clone().zip(widths.clone()));
+
+        [
+            cumulative(
+                x.collect::<Vec<_>>(),
+                widths.clone().collect::<Vec<_>>(),
+                heights.clone().collect::<Vec<_>>(),
+                total_height,
+            ),
+            cumulative(
+                y.collect::<Vec<_>>(),
+                heights.collect::<Vec<_>>(),
+                widths.collect::<Vec<_>>(),
+                total_width,
+            ),
+        ]
+    }
+}
+
+/// Returns the size of the smallest interval which contains every interval `[origin, origin +
+/// size)` for all values in the domain of `origin`.
+fn span<Var: IntegerVariable>(solver: &Solver, intervals: impl Iterator<Item = (Var, i32)>) -> i32 {
+    let (start, end) = intervals.fold((i64::MAX, i64::MIN), |(start, end), (origin, size)| {
+        (
+            start.min(solver.lower_bound(&origin) as i64),
+            end.max(solver.upper_bound(&origin) as i64 + size as i64),
+        )
+    });
+    end.saturating_sub(start).clamp(0, i32::MAX as i64) as i32
+}
+
+impl<Var: IntegerVariable + Debug + 'static> Constraint for DiffnConstraint<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        let relaxations = self
+            .options
+            .cumulative_relaxations
+            .then(|| self.cumulative_relaxations(solver));
+
+        DiffnPropagator::new(&self.rectangles).post(solver, tag)?;
+        relaxations
+            .into_iter()
+            .flatten()
+            .try_for_each(|relaxation| relaxation.post(solver, tag))
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        let relaxations = self
+            .options
+            .cumulative_relaxations
+            .then(|| self.cumulative_relaxations(solver));
+
+        DiffnPropagator::new(&self.rectangles).implied_by(solver, reification_literal, tag)?;
+        relaxations
+            .into_iter()
+            .flatten()
+            .try_for_each(|relaxation| relaxation.implied_by(solver, reification_literal, tag))
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Debug;
+
+use super::validation::check_lengths;
+use super::validation::check_non_negative_elements;
+use super::validation::ValidatedConstraint;
+use super::Constraint;
+use crate::propagators::ArgDisjunctiveTask;
+use crate::propagators::DisjunctivePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+#[cfg(doc)]
+use crate::ConstraintOperationError;
+
+/// Creates the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`], which states that no two of the tasks overlap in time (i.e. the tasks are
+/// processed on a unary resource).
+///
+/// The constraint is propagated using overload checking and detectable precedences; this is
+/// stronger than decomposing it into pairwise (reified) precedences.
+///
+/// The length of `start_times` and `durations` should be the same and the durations should be
+/// non-negative; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let start_0 = solver.new_bounded_integer(0, 1);
+/// let start_1 = solver.new_bounded_integer(0, 10);
+///
+/// solver
+///     .add_constraint(constraints::disjunctive([start_0, start_1], [3, 2]))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The first task cannot be processed after the second task, so the second task starts after
+/// // the first task completes
+/// assert_eq!(solver.lower_bound(&start_1), 3);
+/// ```
+pub fn disjunctive<StartTimes, Durations>(
+    start_times: StartTimes,
+    durations: Durations,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i32>,
+    Durations::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter();
+    let presence_literals = vec![None; start_times.len()];
+
+    disjunctive_with_optional_tasks(start_times, durations, presence_literals)
+}
+
+/// Creates the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`] over tasks which can be optional (e.g. for flexible job-shop problems, where
+/// every operation is processed by one of several machines).
+///
+/// A task with a presence literal is only processed on the resource if its presence literal is
+/// true; if the presence literal is [`None`] then the task is always present. If an optional task
+/// cannot be processed together with the present tasks then its presence literal is set to false.
+/// The bounds of an optional task are only propagated once it is known to be present.
+///
+/// The length of `start_times`, `durations` and `presence_literals` should be the same and the
+/// durations should be non-negative; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// See the documentation of [`disjunctive`] for more information about the constraint.
+pub fn disjunctive_with_optional_tasks<StartTimes, Durations, Presences>(
+    start_times: StartTimes,
+    durations: Durations,
+    presence_literals: Presences,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i32>,
+    Durations::IntoIter: ExactSizeIterator,
+    Presences: IntoIterator<Item = Option<Literal>>,
+    Presences::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter().collect::<Vec<_>>();
+    let durations = durations.into_iter().collect::<Vec<_>>();
+    let presence_literals = presence_literals.into_iter().collect::<Vec<_>>();
+
+    let validation = check_lengths(
+        ("start_times", start_times.len()),
+        &[
+            ("durations", durations.len()),
+            ("presence_literals", presence_literals.len()),
+        ],
+    )
+    .and_then(|_| check_non_negative_elements("durations", &durations));
+
+    ValidatedConstraint::new(validation, || {
+        DisjunctivePropagator::new(
+            &start_times
+                .into_iter()
+                .zip(durations)
+                .zip(presence_literals)
+                .map(
+                    |((start_time, processing_time), presence)| ArgDisjunctiveTask {
+                        start_time,
+                        processing_time,
+                        presence,
+                    },
+                )
+                .collect::<Vec<_>>(),
+        )
+    })
+}
@@ -1,12 +1,26 @@
+use super::validation::check_non_empty;
+use super::validation::ValidatedConstraint;
 use super::Constraint;
 use crate::propagators::element::ElementPropagator;
 use crate::variables::IntegerVariable;
+#[cfg(doc)]
+use crate::InvalidArgument;
 
 /// Creates the [element](https://sofdem.github.io/gccat/gccat/Celement.html) [`Constraint`] which states that `array[index] = rhs`.
+///
+/// The elements of `array` are integer variables (constants can be given as fixed variables), and
+/// `index` is 0-based. The propagator removes the values of `index` and `rhs` which have no
+/// support, and once `index` is fixed it makes `array[index]` and `rhs` equal.
+///
+/// The `array` should not be empty; otherwise, posting the constraint fails with
+/// [`InvalidArgument::EmptyArray`].
 pub fn element<ElementVar: IntegerVariable + 'static>(
     index: impl IntegerVariable + 'static,
     array: impl IntoIterator<Item = ElementVar>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    ElementPropagator::new(array.into_iter().collect(), index, rhs)
+    let array: Box<[ElementVar]> = array.into_iter().collect();
+    ValidatedConstraint::new(check_non_empty("array", array.len()), || {
+        ElementPropagator::new(array, index, rhs)
+    })
 }
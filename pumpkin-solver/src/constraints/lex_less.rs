@@ -0,0 +1,53 @@
+use super::Constraint;
+use crate::propagators::LexLessPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [lex_less](https://sofdem.github.io/gccat/gccat/Clex_less.html) [`Constraint`]
+/// which states that the vector `x` is lexicographically strictly smaller than the vector `y`.
+///
+/// The vectors do not need to be of the same length; if `x` is a proper prefix of `y`, then it is
+/// considered to be smaller than `y`. This constraint is commonly used for breaking symmetries
+/// between interchangeable rows of variables.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = [
+///     solver.new_bounded_integer(1, 1),
+///     solver.new_bounded_integer(2, 5),
+/// ];
+/// let y = [
+///     solver.new_bounded_integer(0, 1),
+///     solver.new_bounded_integer(0, 3),
+/// ];
+///
+/// solver
+///     .add_constraint(constraints::lex_less(x, y))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The first positions have to be equal, so the second position of `x` should be smaller
+/// assert_eq!(solver.lower_bound(&y[0]), 1);
+/// assert_eq!(solver.upper_bound(&x[1]), 2);
+/// assert_eq!(solver.lower_bound(&y[1]), 3);
+/// ```
+pub fn lex_less<Var: IntegerVariable + 'static>(
+    x: impl Into<Box<[Var]>>,
+    y: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    LexLessPropagator::new(x.into(), y.into(), true)
+}
+
+/// Creates the [lex_lesseq](https://sofdem.github.io/gccat/gccat/Clex_lesseq.html) [`Constraint`]
+/// which states that the vector `x` is lexicographically smaller than or equal to the vector `y`.
+///
+/// See [`lex_less`] for more information.
+pub fn lex_lesseq<Var: IntegerVariable + 'static>(
+    x: impl Into<Box<[Var]>>,
+    y: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    LexLessPropagator::new(x.into(), y.into(), false)
+}
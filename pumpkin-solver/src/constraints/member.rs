@@ -0,0 +1,118 @@
+use std::num::NonZero;
+use std::rc::Rc;
+
+use super::Constraint;
+use super::NegatableConstraint;
+use crate::propagators::MemberPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [`NegatableConstraint`] `variable \in values`, where `values` is a constant set
+/// (duplicate values are ignored).
+///
+/// The constraint is propagated to domain consistency; when it is (half-)reified, the reification
+/// literal is falsified once none of the values of the set are in the domain.
+///
+/// Its negation is [`not_member`].
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::constraints::NegatableConstraint;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(0, 10);
+/// let y = solver.new_bounded_integer(0, 10);
+/// let is_even = solver.new_literal();
+///
+/// solver
+///     .add_constraint(constraints::member(x, [2, 4, 7]))
+///     .post()
+///     .expect("no conflict at the root");
+/// solver
+///     .add_constraint(constraints::member(y, [0, 2, 4, 6, 8, 10]))
+///     .reify(is_even)
+///     .expect("no conflict at the root");
+///
+/// assert_eq!(solver.lower_bound(&x), 2);
+/// assert_eq!(solver.upper_bound(&x), 7);
+/// assert_eq!(solver.get_literal_value(is_even), None);
+/// ```
+pub fn member<Var: IntegerVariable + 'static>(
+    variable: Var,
+    values: impl Into<Vec<i32>>,
+) -> impl NegatableConstraint {
+    MemberConstraint::new(variable, values.into(), true)
+}
+
+/// Creates the [`NegatableConstraint`] `variable \notin values`, where `values` is a constant set
+/// (duplicate values are ignored).
+///
+/// Like [`member`], the constraint is propagated to domain consistency.
+///
+/// Its negation is [`member`].
+pub fn not_member<Var: IntegerVariable + 'static>(
+    variable: Var,
+    values: impl Into<Vec<i32>>,
+) -> impl NegatableConstraint {
+    MemberConstraint::new(variable, values.into(), false)
+}
+
+struct MemberConstraint<Var> {
+    variable: Var,
+    /// The values of the set, sorted in increasing order and without duplicates.
+    values: Rc<[i32]>,
+    is_member: bool,
+}
+
+impl<Var: IntegerVariable + 'static> MemberConstraint<Var> {
+    fn new(variable: Var, mut values: Vec<i32>, is_member: bool) -> Self {
+        values.sort_unstable();
+        values.dedup();
+
+        MemberConstraint {
+            variable,
+            values: values.into(),
+            is_member,
+        }
+    }
+
+    fn into_propagator(self) -> MemberPropagator<Var> {
+        MemberPropagator::new(self.variable, self.values, self.is_member)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for MemberConstraint<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.into_propagator().post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.into_propagator()
+            .implied_by(solver, reification_literal, tag)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> NegatableConstraint for MemberConstraint<Var> {
+    type NegatedConstraint = MemberConstraint<Var>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        MemberConstraint {
+            variable: self.variable.clone(),
+            values: Rc::clone(&self.values),
+            is_member: !self.is_member,
+        }
+    }
+}
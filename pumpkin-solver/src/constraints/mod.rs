@@ -23,23 +23,43 @@
 //! propagator API is stabilized, it will become part of the public API.
 
 mod all_different;
+mod among;
 mod arithmetic;
 mod boolean;
+mod cardinality;
 mod clause;
 mod constraint_poster;
 mod cumulative;
+mod diffn;
+mod disjunctive;
 mod element;
+mod lex_less;
+mod member;
+mod precedence;
+mod sorted;
+pub(crate) mod validation;
+mod value_precede;
 
 use std::num::NonZero;
 
 pub use all_different::*;
+pub use among::*;
 pub use arithmetic::*;
 pub use boolean::*;
+pub use cardinality::*;
 pub use clause::*;
 pub use constraint_poster::*;
 pub use cumulative::*;
+pub use diffn::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use lex_less::*;
+pub use member::*;
+pub use precedence::*;
+pub use sorted::*;
+pub use value_precede::*;
 
+pub use crate::api::handles::ConstraintId;
 use crate::engine::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
 use crate::variables::Literal;
@@ -0,0 +1,57 @@
+use super::validation::check_lengths;
+use super::validation::ValidatedConstraint;
+use super::Constraint;
+use crate::propagators::SortedPropagator;
+use crate::variables::IntegerVariable;
+#[cfg(doc)]
+use crate::ConstraintOperationError;
+
+/// Creates the [sort](https://sofdem.github.io/gccat/gccat/Csort.html) [`Constraint`] which states
+/// that `y` is the permutation of `x` which is sorted in non-decreasing order; this is commonly
+/// used in balancing models, e.g. to constrain the largest or the median of a set of loads.
+///
+/// The bounds of `y` are narrowed to the sorted bounds of `x`, while the bounds of `x` are narrowed
+/// by counting how many of the variables of `x` have to be placed before (or after) a position in
+/// `y`.
+///
+/// The length of `x` and `y` should be the same; otherwise, posting the constraint fails with
+/// [`ConstraintOperationError::InvalidArgument`].
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = [
+///     solver.new_bounded_integer(5, 9),
+///     solver.new_bounded_integer(1, 3),
+///     solver.new_bounded_integer(2, 8),
+/// ];
+/// let y = [
+///     solver.new_bounded_integer(0, 10),
+///     solver.new_bounded_integer(0, 10),
+///     solver.new_bounded_integer(0, 10),
+/// ];
+///
+/// solver
+///     .add_constraint(constraints::sorted(x, y))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The smallest value is at most the smallest upper bound in `x`
+/// assert_eq!(solver.upper_bound(&y[0]), 3);
+/// // The largest value is at least the largest lower bound in `x`
+/// assert_eq!(solver.lower_bound(&y[2]), 5);
+/// ```
+pub fn sorted<Var: IntegerVariable + 'static>(
+    x: impl Into<Box<[Var]>>,
+    y: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    let x = x.into();
+    let y = y.into();
+
+    ValidatedConstraint::new(check_lengths(("x", x.len()), &[("y", y.len())]), || {
+        SortedPropagator::new(x, y)
+    })
+}
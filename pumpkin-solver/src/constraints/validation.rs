@@ -0,0 +1,153 @@
+//! Validation of the arguments of constraints.
+//!
+//! Constraints with arguments which can be invalid (e.g. a task with a negative duration) check
+//! them when they are created, and are represented by a [`ValidatedConstraint`]. An invalid
+//! constraint is rejected with [`ConstraintOperationError::InvalidArgument`] when it is posted,
+//! rather than panicking when its propagator is constructed or run.
+
+use std::num::NonZero;
+
+use super::Constraint;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::InvalidArgument;
+use crate::Solver;
+
+/// A [`Constraint`] of which the arguments have been validated; posting it fails with the
+/// [`InvalidArgument`] (without changing the [`Solver`]) if the validation failed.
+pub(crate) struct ValidatedConstraint<C>(Result<C, InvalidArgument>);
+
+impl<C> ValidatedConstraint<C> {
+    /// Creates the constraint using `create` if `validation` succeeded.
+    pub(crate) fn new(
+        validation: Result<(), InvalidArgument>,
+        create: impl FnOnce() -> C,
+    ) -> ValidatedConstraint<C> {
+        ValidatedConstraint(validation.map(|_| create()))
+    }
+}
+
+impl<C: Constraint> Constraint for ValidatedConstraint<C> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.0?.post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.0?.implied_by(solver, reification_literal, tag)
+    }
+}
+
+/// Checks that the array `argument` is not empty.
+pub(crate) fn check_non_empty(
+    argument: &'static str,
+    length: usize,
+) -> Result<(), InvalidArgument> {
+    if length == 0 {
+        return Err(InvalidArgument::EmptyArray { argument });
+    }
+
+    Ok(())
+}
+
+/// Checks that the arrays in `others` have the same length as the array `reference`; the arrays
+/// are given as pairs of their name and their length.
+pub(crate) fn check_lengths(
+    (reference, expected_length): (&'static str, usize),
+    others: &[(&'static str, usize)],
+) -> Result<(), InvalidArgument> {
+    match others
+        .iter()
+        .find(|&&(_, length)| length != expected_length)
+    {
+        Some(&(argument, length)) => Err(InvalidArgument::InconsistentLengths {
+            argument,
+            length,
+            reference,
+            expected_length,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that none of the elements of the array `argument` are negative.
+pub(crate) fn check_non_negative_elements(
+    argument: &'static str,
+    values: &[i32],
+) -> Result<(), InvalidArgument> {
+    match values.iter().position(|&value| value < 0) {
+        Some(index) => Err(InvalidArgument::NegativeElement {
+            argument,
+            index,
+            value: values[index],
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the value of `argument` is not negative.
+pub(crate) fn check_non_negative(
+    argument: &'static str,
+    value: i32,
+) -> Result<(), InvalidArgument> {
+    if value < 0 {
+        return Err(InvalidArgument::NegativeValue { argument, value });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints;
+
+    #[test]
+    fn inconsistent_lengths_are_reported() {
+        let result = check_lengths(("start_times", 3), &[("durations", 3), ("presences", 2)]);
+
+        assert_eq!(
+            result,
+            Err(InvalidArgument::InconsistentLengths {
+                argument: "presences",
+                length: 2,
+                reference: "start_times",
+                expected_length: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_constraint_does_not_change_the_solver() {
+        let mut solver = Solver::default();
+        let start_times = solver.new_bounded_integers(2, 0, 5);
+
+        let result = solver
+            .add_constraint(constraints::disjunctive(start_times.clone(), [2, -1]))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InvalidArgument(
+                InvalidArgument::NegativeElement {
+                    argument: "durations",
+                    index: 1,
+                    value: -1,
+                }
+            ))
+        ));
+
+        // The solver is not infeasible, so constraints can still be added
+        solver
+            .add_constraint(constraints::disjunctive(start_times, [2, 1]))
+            .post()
+            .expect("the constraint is valid and feasible");
+    }
+}
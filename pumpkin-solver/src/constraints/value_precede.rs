@@ -0,0 +1,56 @@
+use super::Constraint;
+use crate::propagators::ValuePrecedePropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [value precedence](https://sofdem.github.io/gccat/gccat/Cint_value_precede.html)
+/// [`Constraint`] which states that if a variable in `variables` takes the value `t`, then an
+/// earlier variable in `variables` takes the value `s`.
+///
+/// This constraint is commonly used to break value symmetries, i.e. when the values `s` and `t` are
+/// interchangeable in any solution.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = [
+///     solver.new_bounded_integer(1, 2),
+///     solver.new_bounded_integer(1, 2),
+/// ];
+///
+/// solver
+///     .add_constraint(constraints::value_precede(1, 2, x))
+///     .post()
+///     .expect("no conflict at the root");
+///
+/// // The value 2 cannot be taken by the first variable
+/// assert_eq!(solver.upper_bound(&x[0]), 1);
+/// ```
+pub fn value_precede<Var: IntegerVariable + 'static>(
+    s: i32,
+    t: i32,
+    variables: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    ValuePrecedePropagator::new(s, t, variables.into())
+}
+
+/// Creates the [`Constraint`] which states that each value in `values` precedes the next value in
+/// `values` (see [`value_precede`]); i.e. the first occurrence of `values[i]` in `variables` is
+/// before the first occurrence of `values[i + 1]`, and the latter only occurs if the former does.
+///
+/// The constraint is decomposed into a [`value_precede`] constraint for every pair of consecutive
+/// values.
+pub fn value_precede_chain<Var: IntegerVariable + 'static>(
+    values: impl IntoIterator<Item = i32>,
+    variables: impl Into<Box<[Var]>>,
+) -> impl Constraint {
+    let values = values.into_iter().collect::<Vec<_>>();
+    let variables = variables.into();
+
+    values
+        .windows(2)
+        .map(|pair| ValuePrecedePropagator::new(pair[0], pair[1], variables.clone()))
+        .collect::<Vec<_>>()
+}
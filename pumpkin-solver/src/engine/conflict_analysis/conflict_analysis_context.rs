@@ -1,6 +1,7 @@
 use drcp_format::steps::StepId;
 
 use super::AnalysisStep;
+use super::VariableActivities;
 use crate::basic_types::ClauseReference;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::StoredConflictInfo;
@@ -45,6 +46,7 @@ pub(crate) struct ConflictAnalysisContext<'a> {
     pub(crate) reason_store: &'a mut ReasonStore,
     pub(crate) counters: &'a mut SolverStatistics,
     pub(crate) learned_clause_manager: &'a mut LearnedClauseManager,
+    pub(crate) variable_activities: &'a mut VariableActivities,
 }
 
 impl ConflictAnalysisContext<'_> {
@@ -152,7 +154,7 @@ impl ConflictAnalysisContext<'_> {
                 // create the explanation clause
                 //  allocate a fresh vector each time might be a performance bottleneck
                 //  todo better ways
-                let explanation_literals: Vec<Literal> = conjunction
+                let mut explanation_literals: Vec<Literal> = conjunction
                     .iter()
                     .map(|&predicate| match predicate {
                         Predicate::IntegerPredicate(integer_predicate) => {
@@ -182,6 +184,13 @@ impl ConflictAnalysisContext<'_> {
                     propagated: self.assignments_propositional.false_literal,
                 });
 
+                // A conflict which is (almost) independent of the assignment leads to a clause
+                // which is too short to be allocated; adding literals which are false at the root
+                // does not change the meaning of the clause
+                while explanation_literals.len() < 2 {
+                    explanation_literals.push(self.assignments_propositional.false_literal);
+                }
+
                 self.explanation_clause_manager
                     .add_explanation_clause_unchecked(explanation_literals, self.clause_allocator)
             }
@@ -207,7 +216,7 @@ impl ConflictAnalysisContext<'_> {
         //  allocate a fresh vector each time might be a performance bottleneck
         //  todo better ways
         // important to keep propagated literal at the zero-th position
-        let explanation_literals: Vec<Literal> = std::iter::once(propagated_literal)
+        let mut explanation_literals: Vec<Literal> = std::iter::once(propagated_literal)
             .chain(reason.iter().map(|&predicate| {
                 match predicate {
                     Predicate::IntegerPredicate(integer_predicate) => {
@@ -236,6 +245,12 @@ impl ConflictAnalysisContext<'_> {
             propagated: propagated_literal,
         });
 
+        // A propagation with an empty reason leads to a unit clause, which cannot be allocated;
+        // adding a literal which is false at the root does not change the meaning of the clause
+        if explanation_literals.len() == 1 {
+            explanation_literals.push(self.assignments_propositional.false_literal);
+        }
+
         self.explanation_clause_manager
             .add_explanation_clause_unchecked(explanation_literals, self.clause_allocator)
     }
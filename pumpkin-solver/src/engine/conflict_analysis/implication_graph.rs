@@ -0,0 +1,178 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::basic_types::HashMap;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+
+/// Describes which implication graph should be exported (see
+/// [`SatisfactionSolverOptions::implication_graph_export`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImplicationGraphExport {
+    /// The number of the conflict whose implication graph is exported, counting from 1.
+    pub conflict: u64,
+    /// The file to which the implication graph is written in the DOT format.
+    pub path: PathBuf,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ImplicationGraphNode {
+    literal: Literal,
+    decision_level: usize,
+    is_decision: bool,
+}
+
+/// The implication graph of a conflict, as it is traversed by conflict analysis.
+///
+/// The nodes are the literals which are true in the current assignment, together with the
+/// decision level at which they were assigned; an edge from `a` to `b` indicates that `a` is part
+/// of the reason for `b`. The edges towards the conflict node form the reason for the conflict.
+///
+/// Only the literals at the current decision level are explained, the literals from previous
+/// decision levels (which end up in the learned clause) are thus the sources of the graph together
+/// with the decision of the current decision level. Literals which are assigned at the root are
+/// left out entirely.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ImplicationGraph {
+    nodes: Vec<ImplicationGraphNode>,
+    node_indices: HashMap<Literal, usize>,
+    /// The edges between the indices of the nodes; the target is `None` for the conflict node.
+    edges: Vec<(usize, Option<usize>)>,
+}
+
+impl ImplicationGraph {
+    /// Adds the node of the provided (true) literal if it is not yet part of the graph.
+    pub(crate) fn add_node(
+        &mut self,
+        literal: Literal,
+        decision_level: usize,
+        is_decision: bool,
+    ) -> usize {
+        *self.node_indices.entry(literal).or_insert_with(|| {
+            self.nodes.push(ImplicationGraphNode {
+                literal,
+                decision_level,
+                is_decision,
+            });
+            self.nodes.len() - 1
+        })
+    }
+
+    /// Adds an edge from `reason` to `implied`, where `None` is the conflict; the nodes should
+    /// have been added using [`ImplicationGraph::add_node`].
+    pub(crate) fn add_edge(&mut self, reason: Literal, implied: Option<Literal>) {
+        let edge = (
+            self.node_indices[&reason],
+            implied.map(|literal| self.node_indices[&literal]),
+        );
+        self.edges.push(edge);
+    }
+
+    /// Writes the graph in the DOT format, where the nodes are labelled using `label`; the nodes
+    /// which are assigned at the same decision level are grouped in a cluster and decisions are
+    /// drawn as boxes.
+    pub(crate) fn write_dot(
+        &self,
+        writer: &mut impl Write,
+        label: impl Fn(Literal) -> String,
+    ) -> std::io::Result<()> {
+        writeln!(writer, "digraph implication_graph {{")?;
+        writeln!(writer, "    conflict [label=\"conflict\", shape=octagon];")?;
+
+        let mut decision_levels = self
+            .nodes
+            .iter()
+            .map(|node| node.decision_level)
+            .collect::<Vec<_>>();
+        decision_levels.sort_unstable();
+        decision_levels.dedup();
+
+        for decision_level in decision_levels {
+            writeln!(writer, "    subgraph cluster_{decision_level} {{")?;
+            writeln!(writer, "        label=\"level {decision_level}\";")?;
+            for (index, node) in self.nodes.iter().enumerate() {
+                if node.decision_level != decision_level {
+                    continue;
+                }
+                let shape = if node.is_decision { "box" } else { "ellipse" };
+                writeln!(
+                    writer,
+                    "        n{index} [label=\"{}\", shape={shape}];",
+                    escape(&label(node.literal))
+                )?;
+            }
+            writeln!(writer, "    }}")?;
+        }
+
+        for &(reason, implied) in &self.edges {
+            match implied {
+                Some(implied) => writeln!(writer, "    n{reason} -> n{implied};")?,
+                None => writeln!(writer, "    n{reason} -> conflict;")?,
+            }
+        }
+
+        writeln!(writer, "}}")
+    }
+}
+
+/// Escapes the characters which have a special meaning in a quoted DOT string.
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::variables::PropositionalVariable;
+
+    #[test]
+    fn graph_is_written_with_clusters_per_decision_level() {
+        let a = Literal::new(PropositionalVariable::new(1), true);
+        let b = Literal::new(PropositionalVariable::new(2), true);
+        let c = Literal::new(PropositionalVariable::new(3), false);
+
+        let mut graph = ImplicationGraph::default();
+        let _ = graph.add_node(a, 1, true);
+        let _ = graph.add_node(b, 2, true);
+        let _ = graph.add_node(c, 2, false);
+        graph.add_edge(a, Some(c));
+        graph.add_edge(b, Some(c));
+        graph.add_edge(a, None);
+        graph.add_edge(c, None);
+
+        let mut output = Vec::new();
+        graph
+            .write_dot(&mut output, |literal| format!("\"{literal}\""))
+            .expect("writing to a vector does not fail");
+
+        let expected = r#"digraph implication_graph {
+    conflict [label="conflict", shape=octagon];
+    subgraph cluster_1 {
+        label="level 1";
+        n0 [label="\"p1\"", shape=box];
+    }
+    subgraph cluster_2 {
+        label="level 2";
+        n1 [label="\"p2\"", shape=box];
+        n2 [label="\"~p3\"", shape=ellipse];
+    }
+    n0 -> n2;
+    n1 -> n2;
+    n0 -> conflict;
+    n2 -> conflict;
+}
+"#;
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn nodes_are_added_once() {
+        let a = Literal::new(PropositionalVariable::new(1), true);
+
+        let mut graph = ImplicationGraph::default();
+        assert_eq!(graph.add_node(a, 1, true), 0);
+        assert_eq!(graph.add_node(a, 1, true), 0);
+        assert_eq!(graph.nodes.len(), 1);
+    }
+}
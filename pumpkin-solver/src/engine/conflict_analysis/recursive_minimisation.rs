@@ -2,7 +2,6 @@ use super::ConflictAnalysisContext;
 use super::ConflictAnalysisResult;
 use crate::basic_types::moving_averages::MovingAverage;
 use crate::basic_types::HashMap;
-use crate::basic_types::HashSet;
 use crate::engine::clause_allocators::ClauseAllocatorInterface;
 use crate::engine::clause_allocators::ClauseInterface;
 use crate::engine::AssignmentsPropositional;
@@ -28,8 +27,13 @@ pub(crate) struct RecursiveMinimiser {
     label_assignments: HashMap<Literal, Option<Label>>,
     /// The current depth of the minimization
     current_depth: usize,
-    /// The decision levels from which [`Literal`]s can be removed during minimisation
-    allowed_decision_levels: HashSet<usize>,
+    /// Indicates for every decision level whether [`Literal`]s from that level can be removed
+    /// during minimisation; this buffer is kept between conflicts to avoid allocating for every
+    /// conflict.
+    allowed_decision_levels: Vec<bool>,
+    /// The decision levels which are marked in [`RecursiveMinimiser::allowed_decision_levels`],
+    /// which are reset after minimisation.
+    marked_decision_levels: Vec<usize>,
 }
 
 /// The maximum number of recursive calls which can be made
@@ -239,11 +243,22 @@ impl RecursiveMinimiser {
     }
 
     fn is_decision_level_allowed(&self, decision_level: usize) -> bool {
-        self.allowed_decision_levels.contains(&decision_level)
+        self.allowed_decision_levels
+            .get(decision_level)
+            .copied()
+            .unwrap_or(false)
     }
 
     fn mark_decision_level_as_allowed(&mut self, decision_level: usize) {
-        let _ = self.allowed_decision_levels.insert(decision_level);
+        if decision_level >= self.allowed_decision_levels.len() {
+            self.allowed_decision_levels
+                .resize(decision_level + 1, false);
+        }
+
+        if !self.allowed_decision_levels[decision_level] {
+            self.allowed_decision_levels[decision_level] = true;
+            self.marked_decision_levels.push(decision_level);
+        }
     }
 
     fn is_literal_assigned_seen(&self, literal: Literal) -> bool {
@@ -283,7 +298,9 @@ impl RecursiveMinimiser {
     fn clean_up(&mut self) {
         pumpkin_assert_simple!(self.current_depth == 0);
 
-        self.allowed_decision_levels.clear();
+        for decision_level in self.marked_decision_levels.drain(..) {
+            self.allowed_decision_levels[decision_level] = false;
+        }
         self.label_assignments.clear();
     }
 
@@ -292,3 +309,31 @@ impl RecursiveMinimiser {
         self.current_depth == MAX_DEPTH
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RecursiveMinimiser;
+
+    #[test]
+    fn allowed_decision_levels_are_reset_but_retained() {
+        let mut minimiser = RecursiveMinimiser::default();
+
+        minimiser.mark_decision_level_as_allowed(3);
+        minimiser.mark_decision_level_as_allowed(1);
+        minimiser.mark_decision_level_as_allowed(3);
+
+        assert!(minimiser.is_decision_level_allowed(1));
+        assert!(minimiser.is_decision_level_allowed(3));
+        assert!(!minimiser.is_decision_level_allowed(2));
+        assert!(!minimiser.is_decision_level_allowed(10));
+        assert_eq!(minimiser.marked_decision_levels, vec![3, 1]);
+
+        minimiser.clean_up();
+
+        assert!(!minimiser.is_decision_level_allowed(1));
+        assert!(!minimiser.is_decision_level_allowed(3));
+        assert!(minimiser.marked_decision_levels.is_empty());
+        // The buffer is kept for the next conflict
+        assert_eq!(minimiser.allowed_decision_levels.len(), 4);
+    }
+}
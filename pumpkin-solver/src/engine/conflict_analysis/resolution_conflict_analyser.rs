@@ -1,4 +1,5 @@
 use super::ConflictAnalysisContext;
+use super::ImplicationGraph;
 use super::RecursiveMinimiser;
 use super::SemanticMinimiser;
 use crate::basic_types::moving_averages::MovingAverage;
@@ -9,8 +10,11 @@ use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
 use crate::engine::propagation::PropagatorId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
+use crate::engine::AssignmentsInteger;
+use crate::engine::AssignmentsPropositional;
 #[cfg(doc)]
 use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::VariableLiteralMappings;
 use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_eq_simple;
 use crate::pumpkin_assert_moderate;
@@ -37,9 +41,38 @@ pub(crate) struct ResolutionConflictAnalyser {
     recursive_minimiser: RecursiveMinimiser,
     /// A clause minimiser which uses a semantic minimisation approach (see [`SemanticMinimiser`]).
     semantic_minimiser: SemanticMinimiser,
+    /// The implication graph which is recorded during the next call to
+    /// [`ResolutionConflictAnalyser::compute_1uip`] (see
+    /// [`ResolutionConflictAnalyser::record_implication_graph`]).
+    implication_graph: Option<ImplicationGraph>,
+    /// The buffer of the nogoods which are derived by the alternative learning schemes (see
+    /// [`ResolutionConflictAnalyser::compare_learning_schemes`]).
+    alternative_nogood: Vec<Literal>,
+}
+
+/// A learning scheme which is compared to the first UIP scheme (see
+/// [`ResolutionConflictAnalyser::compare_learning_schemes`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LearningScheme {
+    /// Resolves all propagated literals at the conflict level, leaving only the decision.
+    LastUip,
+    /// Resolves all propagated literals, leaving only decisions.
+    AllDecision,
 }
 
 impl ResolutionConflictAnalyser {
+    /// Records the [`ImplicationGraph`] of the conflict which is analysed by the next call to
+    /// [`ResolutionConflictAnalyser::compute_1uip`]; it can be retrieved afterwards using
+    /// [`ResolutionConflictAnalyser::take_implication_graph`].
+    pub(crate) fn record_implication_graph(&mut self) {
+        self.implication_graph = Some(ImplicationGraph::default());
+    }
+
+    /// Returns the recorded [`ImplicationGraph`], if any, and stops recording.
+    pub(crate) fn take_implication_graph(&mut self) -> Option<ImplicationGraph> {
+        self.implication_graph.take()
+    }
+
     /// Compute the 1-UIP clause based on the current conflict. According to \[1\] a unit
     /// implication point (UIP), "represents an alternative decision assignment at the current
     /// decision level that results in the same conflict" (i.e. no matter what the variable at the
@@ -61,7 +94,7 @@ impl ResolutionConflictAnalyser {
     pub(crate) fn compute_1uip(
         &mut self,
         context: &mut ConflictAnalysisContext,
-    ) -> ConflictAnalysisResult {
+    ) -> &ConflictAnalysisResult {
         self.seen.resize(
             context
                 .assignments_propositional
@@ -101,6 +134,15 @@ impl ResolutionConflictAnalyser {
                     .add_term(context.clause_allocator[conflict].len() as u64);
                 conflict
             };
+            if let Some(implication_graph) = self.implication_graph.as_mut() {
+                Self::add_reason_to_implication_graph(
+                    implication_graph,
+                    context,
+                    clause_reference,
+                    next_literal,
+                );
+            }
+
             context
                 .learned_clause_manager
                 .update_clause_lbd_and_bump_activity(
@@ -131,6 +173,9 @@ impl ResolutionConflictAnalyser {
                     context
                         .brancher
                         .on_appearance_in_conflict_literal(reason_literal);
+                    context
+                        .variable_activities
+                        .bump_propositional(reason_literal.get_propositional_variable());
                     if let Some(reason_domain) = context
                         .variable_literal_mappings
                         .get_domain_literal(reason_literal)
@@ -138,6 +183,7 @@ impl ResolutionConflictAnalyser {
                         context
                             .brancher
                             .on_appearance_in_conflict_integer(reason_domain);
+                        context.variable_activities.bump_integer(reason_domain);
                     }
 
                     let literal_decision_level = context
@@ -188,7 +234,7 @@ impl ResolutionConflictAnalyser {
 
                     self.analysis_result.learned_literals.clear();
 
-                    return self.analysis_result.clone();
+                    return &self.analysis_result;
                 }
 
                 next_trail_index -= 1;
@@ -242,13 +288,196 @@ impl ResolutionConflictAnalyser {
                 .minimise(context, &mut self.analysis_result);
         }
 
+        if context.internal_parameters.compare_learning_schemes
+            && !context.internal_parameters.proof_log.is_active()
+        {
+            self.compare_learning_schemes(context);
+        }
+
         context
             .explanation_clause_manager
             .clean_up_explanation_clauses(context.clause_allocator);
 
         pumpkin_assert_moderate!(self.debug_check_conflict_analysis_result(false, context));
         // the return value is stored in the input 'analysis_result'
-        self.analysis_result.clone()
+        &self.analysis_result
+    }
+
+    /// Records the statistics of the learned (first UIP) nogood and of the nogoods which the
+    /// last UIP and all-decision schemes derive from the same conflict (see
+    /// [`LearningSchemeStatistics`]); the alternative nogoods are not learned and they are
+    /// derived without notifying the brancher or bumping the activities of clauses and variables.
+    ///
+    /// Note that only the learned nogood is minimised.
+    ///
+    /// [`LearningSchemeStatistics`]: crate::engine::solver_statistics::LearningSchemeStatistics
+    fn compare_learning_schemes(&mut self, context: &mut ConflictAnalysisContext) {
+        let (lbd, backjump_level) =
+            Self::lbd_and_backjump_level(&self.analysis_result.learned_literals, context);
+        context.counters.learning_scheme_statistics.first_uip.add(
+            self.analysis_result.learned_literals.len(),
+            lbd,
+            backjump_level,
+        );
+
+        for scheme in [LearningScheme::LastUip, LearningScheme::AllDecision] {
+            self.compute_alternative_nogood(scheme, context);
+
+            let (lbd, backjump_level) =
+                Self::lbd_and_backjump_level(&self.alternative_nogood, context);
+            let statistics = &mut context.counters.learning_scheme_statistics;
+            let nogood_statistics = match scheme {
+                LearningScheme::LastUip => &mut statistics.last_uip,
+                LearningScheme::AllDecision => &mut statistics.all_decision,
+            };
+            nogood_statistics.add(self.alternative_nogood.len(), lbd, backjump_level);
+        }
+    }
+
+    /// Derives the nogood of the current conflict according to the provided scheme and stores it
+    /// in [`ResolutionConflictAnalyser::alternative_nogood`].
+    fn compute_alternative_nogood(
+        &mut self,
+        scheme: LearningScheme,
+        context: &mut ConflictAnalysisContext,
+    ) {
+        let conflict_level = context.get_decision_level();
+        self.alternative_nogood.clear();
+
+        let mut num_literals_to_resolve = 0;
+        let mut next_trail_index = context.assignments_propositional.num_trail_entries();
+        let mut next_literal: Option<Literal> = None;
+
+        loop {
+            let clause_reference = if let Some(propagated_literal) = next_literal {
+                context.get_propagation_clause_reference(propagated_literal, &mut |_| {})
+            } else {
+                context.get_conflict_reason_clause_reference(&mut |_| {})
+            };
+
+            let start_index = next_literal.is_some() as usize;
+            for &reason_literal in
+                &context.clause_allocator[clause_reference].get_literal_slice()[start_index..]
+            {
+                let variable = reason_literal.get_propositional_variable();
+                if self.seen[variable]
+                    || context
+                        .assignments_propositional
+                        .is_literal_root_assignment(reason_literal)
+                {
+                    continue;
+                }
+                self.seen[variable] = true;
+
+                if Self::is_resolved(scheme, !reason_literal, conflict_level, context) {
+                    num_literals_to_resolve += 1;
+                } else {
+                    self.alternative_nogood.push(reason_literal);
+                }
+            }
+
+            if num_literals_to_resolve == 0 {
+                break;
+            }
+
+            // The next literal to resolve is the most recently assigned one
+            let literal = loop {
+                next_trail_index -= 1;
+                let literal = context
+                    .assignments_propositional
+                    .get_trail_entry(next_trail_index);
+                if self.seen[literal.get_propositional_variable()]
+                    && Self::is_resolved(scheme, literal, conflict_level, context)
+                {
+                    break literal;
+                }
+            };
+            self.seen[literal.get_propositional_variable()] = false;
+            num_literals_to_resolve -= 1;
+            next_literal = Some(literal);
+        }
+
+        for literal in &self.alternative_nogood {
+            self.seen[literal.get_propositional_variable()] = false;
+        }
+    }
+
+    /// Returns whether the provided (true) literal is resolved away according to the scheme.
+    fn is_resolved(
+        scheme: LearningScheme,
+        literal: Literal,
+        conflict_level: usize,
+        context: &ConflictAnalysisContext,
+    ) -> bool {
+        let assignments = context.assignments_propositional;
+        assignments.is_literal_propagated(literal)
+            && match scheme {
+                LearningScheme::LastUip => {
+                    assignments.get_literal_assignment_level(literal) == conflict_level
+                }
+                LearningScheme::AllDecision => true,
+            }
+    }
+
+    /// Returns the LBD of the nogood and the decision level to which it would backjump (i.e. the
+    /// highest decision level below the conflict level).
+    fn lbd_and_backjump_level(
+        nogood: &[Literal],
+        context: &ConflictAnalysisContext,
+    ) -> (u32, usize) {
+        let lbd = context
+            .learned_clause_manager
+            .compute_lbd_for_literals(nogood, context.assignments_propositional);
+        let conflict_level = context.get_decision_level();
+        let backjump_level = nogood
+            .iter()
+            .map(|&literal| {
+                context
+                    .assignments_propositional
+                    .get_literal_assignment_level(literal)
+            })
+            .filter(|&level| level < conflict_level)
+            .max()
+            .unwrap_or(0);
+
+        (lbd, backjump_level)
+    }
+
+    /// Adds the reason for `implied` (or for the conflict if it is [`None`]), which is given by the
+    /// clause, to the implication graph; literals which are assigned at the root are left out.
+    fn add_reason_to_implication_graph(
+        implication_graph: &mut ImplicationGraph,
+        context: &ConflictAnalysisContext,
+        clause_reference: ClauseReference,
+        implied: Option<Literal>,
+    ) {
+        let assignments = context.assignments_propositional;
+        let mut add_node = |literal: Literal| {
+            let _ = implication_graph.add_node(
+                literal,
+                assignments.get_literal_assignment_level(literal),
+                assignments.is_literal_decision(literal),
+            );
+        };
+
+        if let Some(implied) = implied {
+            add_node(implied);
+        }
+
+        // The literals of the clause (except for the implied literal at index 0) are false, so
+        // their negations form the reason
+        let start_index = implied.is_some() as usize;
+        let reason = context.clause_allocator[clause_reference].get_literal_slice()[start_index..]
+            .iter()
+            .map(|&literal| !literal)
+            .filter(|&literal| !assignments.is_literal_root_assignment(literal))
+            .collect::<Vec<_>>();
+        for &literal in &reason {
+            add_node(literal);
+        }
+        for literal in reason {
+            implication_graph.add_edge(literal, implied);
+        }
     }
 
     // computes the learned clause containing only decision literals and stores it in
@@ -458,6 +687,23 @@ impl ResolutionConflictAnalyser {
         self.compute_all_decision_learning_helper(next_literal, true, context, on_analysis_step);
     }
 
+    /// Minimises the provided core using the [`SemanticMinimiser`] (see
+    /// [`SemanticMinimiser::minimise_core`]).
+    pub(crate) fn minimise_core(
+        &mut self,
+        core: &[Literal],
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+        variable_literal_mappings: &VariableLiteralMappings,
+    ) -> Vec<Literal> {
+        self.semantic_minimiser.minimise_core(
+            core,
+            assignments_integer,
+            assignments_propositional,
+            variable_literal_mappings,
+        )
+    }
+
     pub(crate) fn compute_clausal_core(
         &mut self,
         context: &mut ConflictAnalysisContext,
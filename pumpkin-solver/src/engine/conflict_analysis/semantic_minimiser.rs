@@ -36,8 +36,15 @@ pub(crate) struct SemanticMinimiser {
     domains: KeyedVec<DomainId, SimpleIntegerDomain>,
     /// The [`DomainId`]s which are present in the current clause.
     present_ids: SparseSet<DomainId>,
+    /// Stores the nogood which is created from the clause which is being minimised.
+    nogood: Vec<Literal>,
     /// Stores the final nogood which is created after minimisation.
     final_nogood: Vec<Literal>,
+    /// Stores the minimised clause (i.e. the negation of [`SemanticMinimiser::final_nogood`]).
+    ///
+    /// Note that this buffer (similar to the other buffers) is reused across conflicts to avoid
+    /// allocating for every conflict.
+    minimised_clause: Vec<Literal>,
 }
 
 impl Default for SemanticMinimiser {
@@ -47,7 +54,9 @@ impl Default for SemanticMinimiser {
             original_domains: Default::default(),
             domains: Default::default(),
             present_ids: SparseSet::new(vec![], mapping),
+            nogood: Vec::default(),
             final_nogood: Vec::default(),
+            minimised_clause: Vec::default(),
         }
     }
 }
@@ -63,14 +72,19 @@ impl SemanticMinimiser {
         let number_of_literals_before_semantic_minimisation =
             analysis_result.learned_literals.len();
 
-        let mut minimised_clause = self.minimise_clause(
+        let minimised_clause = self.minimise_clause(
             analysis_result.learned_literals.iter().copied(),
             context.assignments_integer,
             context.assignments_propositional,
             context.variable_literal_mappings,
         );
+        analysis_result.learned_literals.clear();
+        analysis_result
+            .learned_literals
+            .extend_from_slice(minimised_clause);
+        let minimised_clause = &mut analysis_result.learned_literals;
 
-        recompute_invariant_learned_clause(&mut minimised_clause, context);
+        recompute_invariant_learned_clause(minimised_clause, context);
 
         if minimised_clause.len() == 1 {
             // If the learned clause is unit then we jump back to the root and propagate it there
@@ -86,39 +100,93 @@ impl SemanticMinimiser {
                 .get_literal_assignment_level(minimised_clause[1]);
             analysis_result.backjump_level = new_backjump_level;
         }
-        analysis_result.learned_literals = minimised_clause;
-
-        context
-            .counters
-            .learned_clause_statistics
+        let statistics = &mut context.counters.learned_clause_statistics;
+        let number_of_literals_after_semantic_minimisation = analysis_result.learned_literals.len();
+        statistics
             .average_number_of_removed_literals_semantic
             .add_term(
                 (number_of_literals_before_semantic_minimisation
-                    - analysis_result.learned_literals.len()) as u64,
+                    - number_of_literals_after_semantic_minimisation) as u64,
             );
+        statistics
+            .average_length_before_semantic_minimisation
+            .add_term(number_of_literals_before_semantic_minimisation as u64);
+        statistics.max_length_before_semantic_minimisation = statistics
+            .max_length_before_semantic_minimisation
+            .max(number_of_literals_before_semantic_minimisation as u64);
+        statistics
+            .average_length_after_semantic_minimisation
+            .add_term(number_of_literals_after_semantic_minimisation as u64);
+        statistics.max_length_after_semantic_minimisation = statistics
+            .max_length_after_semantic_minimisation
+            .max(number_of_literals_after_semantic_minimisation as u64);
     }
 
-    fn minimise_clause(
+    /// Minimises a core (i.e. a conjunction of assumptions which cannot be satisfied) such that
+    /// the result is a subset of the provided `core`.
+    ///
+    /// The core is minimised as a nogood; an assumption is removed if it is implied by the other
+    /// assumptions over the same [`DomainId`] (e.g. `[x >= 2]` is removed from the core `[x >= 2]
+    /// /\ [x >= 5]`). If the minimised nogood contains a literal which is not an assumption (e.g.
+    /// `[x == 5]` for the core `[x >= 5] /\ [x <= 5]`), then all of the assumptions over that
+    /// [`DomainId`] are kept.
+    pub(crate) fn minimise_core(
         &mut self,
-        learned_clause: impl Iterator<Item = Literal>,
+        core: &[Literal],
         assignments_integer: &AssignmentsInteger,
         assignments_propositional: &AssignmentsPropositional,
         variable_literal_mappings: &VariableLiteralMappings,
     ) -> Vec<Literal> {
-        // We get a clause and we turn it into a nogood by negating
-        let nogood = learned_clause.map(|literal| !literal).collect();
+        let minimised_nogood = self
+            .minimise_clause(
+                core.iter().map(|&literal| !literal),
+                assignments_integer,
+                assignments_propositional,
+                variable_literal_mappings,
+            )
+            .iter()
+            .map(|&literal| !literal)
+            .collect::<HashSet<_>>();
+
+        let get_domain = |literal: Literal| {
+            variable_literal_mappings
+                .get_predicates(literal)
+                .next()
+                .map(|predicate| predicate.get_domain())
+        };
+        let described_domains = minimised_nogood
+            .iter()
+            .filter(|literal| !core.contains(literal))
+            .filter_map(|&literal| get_domain(literal))
+            .collect::<HashSet<_>>();
+
+        core.iter()
+            .copied()
+            .filter(|&literal| {
+                minimised_nogood.contains(&literal)
+                    || get_domain(literal)
+                        .is_some_and(|domain_id| described_domains.contains(&domain_id))
+            })
+            .collect()
+    }
 
+    fn minimise_clause(
+        &mut self,
+        learned_clause: impl Iterator<Item = Literal>,
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+        variable_literal_mappings: &VariableLiteralMappings,
+    ) -> &[Literal] {
         // Then we ensure that any newly defined variables are added to our structures
         self.accommodate(assignments_integer);
         // We clean up from the previous invocation
         self.clean_up();
 
+        // We get a clause and we turn it into a nogood by negating
+        self.nogood.extend(learned_clause.map(|literal| !literal));
+
         // Now we apply all of the predicates to our pseudo-domain
-        self.apply_predicates(
-            &nogood,
-            variable_literal_mappings,
-            assignments_propositional,
-        );
+        self.apply_predicates(variable_literal_mappings, assignments_propositional);
 
         // Then we go over every domain present in the nogood
         for domain_id in self.present_ids.iter() {
@@ -140,25 +208,24 @@ impl SemanticMinimiser {
         }
 
         // We turn the final nogood into a clause by negating it
-        self.final_nogood
-            .iter()
-            .map(|literal| !(*literal))
-            .collect::<Vec<_>>()
+        self.minimised_clause
+            .extend(self.final_nogood.iter().map(|literal| !(*literal)));
+        &self.minimised_clause
     }
 
-    /// Applies the [`IntegerPredicate`]s which are given in the `nogood` to [`Self::domains`]. If
-    /// the [`Literal`] in the nogood has no corresponding [`IntegerPredicate`] then it is directly
-    /// added to the nogood as we have no semantic information concerning it.
+    /// Applies the [`IntegerPredicate`]s which are given in [`Self::nogood`] to
+    /// [`Self::domains`]. If the [`Literal`] in the nogood has no corresponding
+    /// [`IntegerPredicate`] then it is directly added to the final nogood as we have no semantic
+    /// information concerning it.
     fn apply_predicates(
         &mut self,
-        nogood: &Vec<Literal>,
         variable_literal_mapping: &VariableLiteralMappings,
         assignments_propositional: &AssignmentsPropositional,
     ) {
         // Apply the predicates to the domains in a straight-forward way.
         // Note that we take into account the effect of holes on the upper/lower bound after this
         // loop.
-        for literal in nogood {
+        for literal in &self.nogood {
             let predicate = variable_literal_mapping.get_predicates(*literal).next();
             if let Some(predicate) = predicate {
                 // If there is a corresponding predicate then we add it to the domain of that domain
@@ -246,16 +313,17 @@ impl SemanticMinimiser {
     /// following:
     /// - We reset the domains of the present ids
     /// - We remove all of the present ids
-    /// - We clear the final nogood
+    /// - We clear the nogoods and the minimised clause
     fn clean_up(&mut self) {
         // Remove the domain ids from the present domain ids.
-        let vals: Vec<DomainId> = self.present_ids.iter().copied().collect();
-
-        for domain_id in vals {
+        while !self.present_ids.is_empty() {
+            let domain_id = *self.present_ids.get(0);
             self.domains[domain_id] = self.original_domains[domain_id].clone();
             self.present_ids.remove(&domain_id)
         }
+        self.nogood.clear();
         self.final_nogood.clear();
+        self.minimised_clause.clear();
     }
 }
 
@@ -559,7 +627,7 @@ mod tests {
         (assignments_integer, assignments_propositional, mediator)
     }
 
-    fn assert_elements_equal(first: Vec<Literal>, second: Vec<Literal>) {
+    fn assert_elements_equal(first: &[Literal], second: Vec<Literal>) {
         assert_eq!(first.len(), second.len());
         assert!(first.iter().all(|literal| second.contains(literal)));
         assert!(second.iter().all(|literal| first.contains(literal)));
@@ -903,4 +971,48 @@ mod tests {
             ),
         )
     }
+
+    #[test]
+    fn minimised_core_is_subset_of_core() {
+        let mut p = SemanticMinimiser::default();
+        let (assignments_integer, assignments_propositional, variable_literal_mappings) =
+            create_for_testing(2, 0, None);
+        let domain_0 = assignments_integer.get_domains().next().unwrap();
+        let domain_1 = assignments_integer.get_domains().nth(1).unwrap();
+        let to_literals = |predicates: Vec<Predicate>| {
+            predicates
+                .into_iter()
+                .map(|predicate| {
+                    variable_literal_mappings.get_literal(
+                        predicate.try_into().unwrap(),
+                        &assignments_propositional,
+                        &assignments_integer,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let core = to_literals(vec![
+            predicate![domain_0 >= 5],
+            predicate![domain_0 <= 5],
+            predicate![domain_1 >= 1],
+            predicate![domain_1 >= 3],
+        ]);
+
+        let minimised_core = p.minimise_core(
+            &core,
+            &assignments_integer,
+            &assignments_propositional,
+            &variable_literal_mappings,
+        );
+
+        // The assignment `[domain_0 == 5]` is not part of the core, so both of its bounds are kept
+        assert_elements_equal(
+            &minimised_core,
+            to_literals(vec![
+                predicate![domain_0 >= 5],
+                predicate![domain_0 <= 5],
+                predicate![domain_1 >= 3],
+            ]),
+        );
+    }
 }
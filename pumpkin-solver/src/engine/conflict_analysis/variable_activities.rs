@@ -0,0 +1,156 @@
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::PropositionalVariable;
+
+const DEFAULT_ACTIVITY_INCREMENT: f64 = 1.0;
+const DEFAULT_ACTIVITY_MAX_THRESHOLD: f64 = 1e100;
+const DEFAULT_ACTIVITY_DECAY_FACTOR: f64 = 0.95;
+
+/// Keeps track of how often variables appear in (recent) conflicts, independently of the
+/// [`Brancher`] which is used during search.
+///
+/// The scores follow the same bump-and-decay scheme as [`Vsids`]: every time a variable is
+/// encountered during conflict analysis its activity is increased by the current increment, and
+/// after every conflict the increment is increased by `1 / decay_factor`. This means that the
+/// scores of different variables can be compared with one another, but the absolute values carry no
+/// meaning.
+///
+/// The activities are indexed by the (stable) ids of the variables; see
+/// [`DomainId::id`] and [`PropositionalVariable::get_index`].
+///
+/// [`Brancher`]: crate::branching::Brancher
+/// [`Vsids`]: crate::branching::variable_selection::Vsids
+#[derive(Debug)]
+pub(crate) struct VariableActivities {
+    integer: KeyedVec<DomainId, f64>,
+    propositional: KeyedVec<PropositionalVariable, f64>,
+    /// How much the activity of a variable is increased when it appears in a conflict.
+    increment: f64,
+    /// If an activity reaches this value then all of the activities (and the increment) are
+    /// divided by this value.
+    max_threshold: f64,
+    /// After every conflict, the increment is multiplied by `1 / decay_factor`.
+    decay_factor: f64,
+}
+
+impl Default for VariableActivities {
+    fn default() -> Self {
+        VariableActivities {
+            integer: KeyedVec::default(),
+            propositional: KeyedVec::default(),
+            increment: DEFAULT_ACTIVITY_INCREMENT,
+            max_threshold: DEFAULT_ACTIVITY_MAX_THRESHOLD,
+            decay_factor: DEFAULT_ACTIVITY_DECAY_FACTOR,
+        }
+    }
+}
+
+impl VariableActivities {
+    /// Returns the activity of the provided integer variable; variables which have never appeared
+    /// in a conflict have an activity of `0.0`.
+    pub(crate) fn integer_activity(&self, domain: DomainId) -> f64 {
+        if domain.index() < self.integer.len() {
+            self.integer[domain]
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the activity of the provided propositional variable; variables which have never
+    /// appeared in a conflict have an activity of `0.0`.
+    pub(crate) fn propositional_activity(&self, variable: PropositionalVariable) -> f64 {
+        if variable.index() < self.propositional.len() {
+            self.propositional[variable]
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn bump_integer(&mut self, domain: DomainId) {
+        self.integer.accomodate(domain, 0.0);
+        self.integer[domain] += self.increment;
+
+        if self.integer[domain] >= self.max_threshold {
+            self.rescale();
+        }
+    }
+
+    pub(crate) fn bump_propositional(&mut self, variable: PropositionalVariable) {
+        self.propositional.accomodate(variable, 0.0);
+        self.propositional[variable] += self.increment;
+
+        if self.propositional[variable] >= self.max_threshold {
+            self.rescale();
+        }
+    }
+
+    /// Should be called after every conflict; this makes bumps in future conflicts weigh more
+    /// heavily than bumps in past conflicts.
+    pub(crate) fn decay(&mut self) {
+        self.increment *= 1.0 / self.decay_factor;
+
+        if self.increment >= self.max_threshold {
+            self.rescale();
+        }
+    }
+
+    fn rescale(&mut self) {
+        self.integer
+            .iter_mut()
+            .for_each(|activity| *activity /= self.max_threshold);
+        self.propositional
+            .iter_mut()
+            .for_each(|activity| *activity /= self.max_threshold);
+        self.increment /= self.max_threshold;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unbumped_variables_have_zero_activity() {
+        let activities = VariableActivities::default();
+
+        assert_eq!(activities.integer_activity(DomainId::new(3)), 0.0);
+        assert_eq!(
+            activities.propositional_activity(PropositionalVariable::new(5)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn recent_bumps_weigh_more_than_older_bumps() {
+        let mut activities = VariableActivities::default();
+
+        activities.bump_integer(DomainId::new(0));
+        activities.decay();
+        activities.bump_integer(DomainId::new(1));
+
+        assert!(
+            activities.integer_activity(DomainId::new(1))
+                > activities.integer_activity(DomainId::new(0))
+        );
+    }
+
+    #[test]
+    fn rescaling_preserves_order() {
+        let mut activities = VariableActivities {
+            max_threshold: 10.0,
+            ..Default::default()
+        };
+
+        activities.bump_propositional(PropositionalVariable::new(0));
+        for _ in 0..13 {
+            activities.bump_propositional(PropositionalVariable::new(1));
+        }
+
+        assert!(
+            activities.propositional_activity(PropositionalVariable::new(1))
+                > activities.propositional_activity(PropositionalVariable::new(0))
+        );
+        assert!(activities.propositional_activity(PropositionalVariable::new(1)) < 10.0);
+    }
+}
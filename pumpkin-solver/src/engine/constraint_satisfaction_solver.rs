@@ -4,11 +4,18 @@
 use std::cmp::min;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::fmt::Write;
+use std::fs::File;
+use std::io::BufWriter;
 use std::marker::PhantomData;
 use std::num::NonZero;
+use std::ops::RangeInclusive;
 use std::time::Instant;
 
 use drcp_format::steps::StepId;
+use itertools::Itertools;
+use log::trace;
+use log::warn;
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 
@@ -16,8 +23,16 @@ use super::clause_allocators::ClauseAllocatorInterface;
 use super::clause_allocators::ClauseInterface;
 use super::conflict_analysis::AnalysisStep;
 use super::conflict_analysis::ConflictAnalysisResult;
+use super::conflict_analysis::ImplicationGraphExport;
 use super::conflict_analysis::ResolutionConflictAnalyser;
+use super::conflict_analysis::VariableActivities;
+use super::event_log::EventLog;
+use super::event_log::LoggedEvent;
+#[cfg(doc)]
+use super::event_log::ReplayBrancher;
+use super::model_statistics::ModelStatistics;
 use super::propagation::store::PropagatorStore;
+use super::solver_statistics::SearchStatistics;
 use super::solver_statistics::SolverStatistics;
 use super::termination::TerminationCondition;
 use super::variables::IntegerVariable;
@@ -28,14 +43,25 @@ use crate::basic_types::ConflictInfo;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::KeyedVec;
+use crate::basic_types::ProblemSolution;
 use crate::basic_types::PropagationStatusOneStepCP;
 use crate::basic_types::Random;
+use crate::basic_types::Solution;
 use crate::basic_types::SolutionReference;
+use crate::basic_types::StorageKey;
 use crate::basic_types::StoredConflictInfo;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use crate::branching::ActivityBased;
+use crate::branching::ActivityBasedSearchOptions;
 use crate::branching::Brancher;
+use crate::branching::DecisionMiddleware;
+use crate::branching::DecisionMiddlewares;
+use crate::branching::InDomainMin;
+use crate::branching::LastConflict;
+use crate::branching::LookaheadRanking;
 use crate::branching::PhaseSaving;
 use crate::branching::SelectionContext;
 use crate::branching::SolutionGuidedValueSelector;
@@ -45,14 +71,18 @@ use crate::engine::conflict_analysis::ConflictAnalysisContext;
 use crate::engine::cp::PropagatorQueue;
 use crate::engine::cp::WatchListCP;
 use crate::engine::cp::WatchListPropositional;
+use crate::engine::nogood_exchange::NogoodExchange;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::proof::ProofLog;
 use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::PendingConstraints;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::reason::ReasonStore;
+use crate::engine::symmetry::VariableSymmetry;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
@@ -66,8 +96,10 @@ use crate::engine::IntDomainEvent;
 use crate::engine::LearnedClauseManager;
 use crate::engine::LearningOptions;
 use crate::engine::RestartOptions;
+use crate::engine::RestartPolicy;
 use crate::engine::RestartStrategy;
 use crate::engine::VariableLiteralMappings;
+use crate::predicate;
 use crate::propagators::clausal::BasicClausalPropagator;
 use crate::propagators::clausal::ClausalPropagator;
 use crate::pumpkin_assert_advanced;
@@ -77,8 +109,11 @@ use crate::pumpkin_assert_simple;
 use crate::statistics::statistic_logger::StatisticLogger;
 use crate::statistics::statistic_logging::should_log_statistics;
 use crate::statistics::Statistic;
+use crate::variable_names::DisplayWith;
 use crate::variable_names::VariableNames;
+use crate::ActivityBasedBrancher;
 use crate::DefaultBrancher;
+use crate::IntegerVsidsBrancher;
 #[cfg(doc)]
 use crate::Solver;
 
@@ -130,6 +165,9 @@ pub struct ConstraintSatisfactionSolver {
     /// The list of propagators. Propagators live here and are queried when events (domain changes)
     /// happen. The list is only traversed during synchronisation for now.
     cp_propagators: PropagatorStore,
+    /// The constraints which propagators requested to be posted during search; these are added
+    /// to [`ConstraintSatisfactionSolver::cp_propagators`] when the solver is at the root.
+    pending_constraints: PendingConstraints,
     /// Tracks information about all allocated clauses. All clause allocaton goes exclusively
     /// through the clause allocator. There are two notable exceptions:
     /// - Unit clauses are stored directly on the trail.
@@ -140,12 +178,33 @@ pub struct ConstraintSatisfactionSolver {
     learned_clause_manager: LearnedClauseManager,
     /// Tracks information about the restarts. Occassionally the solver will undo all its decisions
     /// and start the search from the root note. Note that learned clauses and other state
-    /// information is kept after a restart.
-    restart_strategy: RestartStrategy,
+    /// information is kept after a restart. By default, this is a [`RestartStrategy`] configured
+    /// by [`SatisfactionSolverOptions::restart_options`]; see
+    /// [`ConstraintSatisfactionSolver::set_restart_policy`].
+    restart_strategy: Box<dyn RestartPolicy>,
     /// Holds the assumptions when the solver is queried to solve under assumptions.
     assumptions: Vec<Literal>,
+    /// The bounds which are imposed on integer variables in every call to solve; see
+    /// [`ConstraintSatisfactionSolver::set_variable_bounds`]. They are added as assumptions such
+    /// that they can be relaxed again afterwards.
+    variable_bounds: HashMap<DomainId, (i32, i32)>,
+    /// The scopes which are currently open; see [`ConstraintSatisfactionSolver::push_scope`]. The
+    /// clauses and propagators which are added within a scope are conditioned on its activation
+    /// literal, which is assumed in every call to solve.
+    scopes: Vec<Scope>,
+    /// The number of leading [`ConstraintSatisfactionSolver::assumptions`] which were added by the
+    /// solver itself (i.e. the variable bounds and the scope literals); these are never part of an
+    /// extracted core.
+    num_internal_assumptions: usize,
+    /// The suggested values of integer variables; see [`ConstraintSatisfactionSolver::add_hint`].
+    hints: Vec<(DomainId, i32)>,
+    /// The decisions made by the brancher, one for each decision level after the assumption
+    /// levels; these are used to determine which part of the trail can be reused upon a restart.
+    decisions: Vec<Predicate>,
     /// Performs conflict analysis, core extraction, and minimisation.
     conflict_analyser: ResolutionConflictAnalyser,
+    /// Tracks how often variables appear in recent conflicts, independently of the brancher.
+    variable_activities: VariableActivities,
     /// Tracks information related to the assignments of integer variables.
     pub(crate) assignments_integer: AssignmentsInteger,
     /// Contains information on which propagator to notify upon
@@ -202,6 +261,33 @@ pub struct ConstraintSatisfactionSolver {
     /// A map from clause references to nogood step ids in the proof.
     nogood_step_ids: KeyedVec<ClauseReference, Option<StepId>>,
     unit_nogood_step_ids: HashMap<Literal, StepId>,
+    /// If events are being recorded, this contains the log of the decisions and domain events
+    /// which occurred during search; see [`ConstraintSatisfactionSolver::record_events`].
+    event_log: Option<EventLog>,
+    /// The number of clauses which have been added through
+    /// [`ConstraintSatisfactionSolver::add_clause`].
+    num_clauses: usize,
+    /// If set, the current call to solve stops (as if it timed out) once the number of conflicts
+    /// reaches this value; see
+    /// [`ConstraintSatisfactionSolver::solve_under_assumptions_with_conflict_budget`].
+    conflict_limit: Option<u64>,
+    /// The components which inspect (and possibly replace) the decisions of the brancher; see
+    /// [`ConstraintSatisfactionSolver::add_decision_middleware`].
+    decision_middlewares: DecisionMiddlewares,
+    /// The symmetries of the model which are used to learn the symmetric images of learned
+    /// clauses; see [`ConstraintSatisfactionSolver::add_symmetry`].
+    symmetries: Vec<VariableSymmetry>,
+    /// The symmetric images of learned clauses which are added to the solver at the next restart.
+    pending_symmetric_images: Vec<Vec<Literal>>,
+    /// Shares learned clauses with the other workers of a portfolio; see
+    /// [`ConstraintSatisfactionSolver::set_nogood_exchange`].
+    nogood_exchange: Option<NogoodExchange>,
+    /// The clauses which were imported from the other workers of a portfolio and which are added
+    /// to the solver at the next restart.
+    pending_imported_clauses: Vec<Vec<Literal>>,
+    /// The ranking of candidate decisions which is computed by the lookahead at the root of the
+    /// current call to solve; see [`SatisfactionSolverOptions::lookahead_budget`].
+    lookahead_ranking: Option<LookaheadRanking>,
 }
 
 impl Default for ConstraintSatisfactionSolver {
@@ -244,6 +330,95 @@ pub struct SatisfactionSolverOptions {
     /// A random generator which is used by the [`Solver`], passing it as an
     /// argument allows seeding of the randomization.
     pub random_generator: SmallRng,
+
+    /// The number of conflicts which the optimiser may spend on destructive lower-bounding (see
+    /// [`Solver::destructive_lower_bound`]) before the full search; a value of 0 disables it.
+    ///
+    /// Note that destructive lower-bounding is never performed when a proof is logged.
+    pub destructive_bounding_conflict_budget: u64,
+
+    /// Whether propagators are checked for integer overflow when they are added; a propagator
+    /// whose arithmetic may overflow an `i32` is then rejected with
+    /// [`ConstraintOperationError::IntegerOverflow`] rather than silently wrapping around during
+    /// search (see [`Propagator::may_overflow`]).
+    ///
+    /// By default, this is enabled in debug builds.
+    pub checked_arithmetic: bool,
+
+    /// The maximum number of symmetric images which are learned for every learned clause, one for
+    /// each symmetry which is declared using [`Solver::add_symmetry`]; a value of 0 disables
+    /// symmetric explanation learning.
+    ///
+    /// Note that the images are added at the next restart and that they are not learned when a
+    /// proof is logged.
+    pub max_symmetric_images: usize,
+
+    /// The maximum number of integer variables which are probed by the lookahead at the root of
+    /// every call to solve; the resulting [`LookaheadRanking`] of candidate decisions is provided
+    /// to the brancher through [`SelectionContext::lookahead_ranking`]. A value of 0 disables the
+    /// lookahead.
+    ///
+    /// The variables are probed in the order in which they were created, skipping the variables
+    /// which are fixed at the root.
+    pub lookahead_budget: usize,
+
+    /// If provided, the implication graph of the given conflict is written to a file in the DOT
+    /// format (e.g. to inspect why a weak nogood was learned).
+    ///
+    /// Note that the graph is only written if the conflict is analysed using 1UIP learning (see
+    /// [`LearningOptions::max_learning_decision_level`]).
+    pub implication_graph_export: Option<ImplicationGraphExport>,
+
+    /// The options of the brancher which is created by
+    /// [`Solver::activity_based_brancher_over_all_integer_variables`].
+    pub activity_based_search: ActivityBasedSearchOptions,
+
+    /// Whether the nogoods which alternative learning schemes (last UIP and all-decision) derive
+    /// from every analysed conflict are computed and compared to the learned (first UIP) nogood;
+    /// the comparison is logged as part of the statistics and does not affect the search.
+    ///
+    /// Note that the comparison is not made when a proof is logged.
+    pub compare_learning_schemes: bool,
+
+    /// The options for polishing every solution which is found during optimisation.
+    pub solution_polishing: SolutionPolishingOptions,
+
+    /// The number of conflicts which the optimiser may spend on finding an initial solution in
+    /// which the hinted variables take their suggested values (see [`Solver::add_hint`]); a value
+    /// of 0 disables the probe.
+    ///
+    /// By default, the probe is disabled. Note that the probe is never performed when a proof is
+    /// logged.
+    pub hint_probe_conflict_budget: u64,
+}
+
+/// The options for polishing the solutions which are found by the optimiser (see
+/// [`Solver::minimise`]); polishing is disabled by default.
+///
+/// After every improving solution, the optimiser searches for a better solution in which only a
+/// small window of variables can change and all other variables are fixed to their value in the
+/// solution; the window consists of the variables with the highest conflict activity (see
+/// [`Solver::get_integer_variable_activity`]), which are likely to be critical to the objective.
+/// Polishing is repeated as long as it finds better solutions, after which the global search
+/// continues.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SolutionPolishingOptions {
+    /// The number of conflicts which may be spent on a single polishing step; a value of 0
+    /// disables polishing.
+    ///
+    /// Note that polishing is never performed when a proof is logged.
+    pub conflict_budget: u64,
+    /// The number of variables which are free during polishing.
+    pub window_size: usize,
+}
+
+impl Default for SolutionPolishingOptions {
+    fn default() -> Self {
+        SolutionPolishingOptions {
+            conflict_budget: 0,
+            window_size: 8,
+        }
+    }
 }
 
 impl Default for SatisfactionSolverOptions {
@@ -253,6 +428,15 @@ impl Default for SatisfactionSolverOptions {
             proof_log: ProofLog::default(),
             learning_clause_minimisation: true,
             random_generator: SmallRng::seed_from_u64(42),
+            destructive_bounding_conflict_budget: 1000,
+            checked_arithmetic: cfg!(debug_assertions),
+            max_symmetric_images: 16,
+            lookahead_budget: 0,
+            implication_graph_export: None,
+            activity_based_search: ActivityBasedSearchOptions::default(),
+            compare_learning_schemes: false,
+            solution_polishing: SolutionPolishingOptions::default(),
+            hint_probe_conflict_budget: 0,
         }
     }
 }
@@ -304,6 +488,10 @@ impl ConstraintSatisfactionSolver {
             }
 
             for (event, domain) in self.event_drain.drain(..) {
+                if let Some(event_log) = self.event_log.as_mut() {
+                    event_log.push(LoggedEvent::DomainEvent { domain, event });
+                }
+
                 for propagator_var in self.watch_list_cp.get_affected_propagators(event, domain) {
                     let propagator = &mut self.cp_propagators[propagator_var.propagator];
                     let context = PropagationContext::new(
@@ -320,8 +508,15 @@ impl ConstraintSatisfactionSolver {
                     }
                 }
             }
-            self.last_notified_cp_trail_index = self.assignments_integer.num_trail_entries();
+        } else {
+            // The events are discarded, as otherwise propagators which are added later would be
+            // notified of changes which happened before they were initialised
+            self.assignments_integer
+                .drain_domain_events()
+                .for_each(drop);
         }
+        self.last_notified_cp_trail_index = self.assignments_integer.num_trail_entries();
+
         // If there are no literals being watched then there is no reason to perform these
         // operations
         if self.watch_list_propositional.is_watching_anything() {
@@ -352,8 +547,8 @@ impl ConstraintSatisfactionSolver {
                     }
                 }
             }
-            self.propositional_trail_index = self.assignments_propositional.num_trail_entries();
         }
+        self.propositional_trail_index = self.assignments_propositional.num_trail_entries();
 
         true
     }
@@ -387,6 +582,13 @@ impl ConstraintSatisfactionSolver {
         self.state.declare_ready()
     }
 
+    /// Returns the number of assumptions which were added by the solver itself (i.e. the variable
+    /// bounds and the scope literals) in the last call to solve; a conclusion which is reached
+    /// under these assumptions does not hold for the model itself.
+    pub(crate) fn num_internal_assumptions(&self) -> usize {
+        self.num_internal_assumptions
+    }
+
     /// Conclude the proof with the unsatisfiable claim.
     ///
     /// This method will finish the proof. Any new operation will not be logged to the proof.
@@ -409,11 +611,11 @@ impl ConstraintSatisfactionSolver {
             "Proof attempted to be completed while not in conflicting state"
         );
 
-        let result = self.compute_learned_clause(&mut DummyBrancher);
+        self.compute_learned_clause(&mut DummyBrancher);
         let _ = self
             .internal_parameters
             .proof_log
-            .log_learned_clause(result.learned_literals);
+            .log_learned_clause(self.analysis_result.learned_literals.iter().copied());
     }
 
     // fn debug_check_consistency(&self, cp_data_structures: &CPEngineDataStructures) -> bool {
@@ -435,20 +637,33 @@ impl ConstraintSatisfactionSolver {
 // methods that offer basic functionality
 impl ConstraintSatisfactionSolver {
     pub fn new(
-        learning_options: LearningOptions,
+        mut learning_options: LearningOptions,
         solver_options: SatisfactionSolverOptions,
     ) -> ConstraintSatisfactionSolver {
+        // The clauses which are learned instead of the limited clauses cannot be checked by a
+        // proof checker
+        if solver_options.proof_log.is_active()
+            && learning_options.max_learning_decision_level.is_some()
+        {
+            warn!("Clause learning cannot be limited when logging a proof, the limit is ignored.");
+            learning_options.max_learning_decision_level = None;
+        }
+
         let dummy_literal = Literal::new(PropositionalVariable::new(0), true);
 
         let mut csp_solver = ConstraintSatisfactionSolver {
             state: CSPSolverState::default(),
             assumptions: Vec::default(),
+            variable_bounds: HashMap::default(),
+            scopes: Vec::default(),
+            num_internal_assumptions: 0,
+            hints: Vec::default(),
             assignments_propositional: AssignmentsPropositional::default(),
             clause_allocator: ClauseAllocator::default(),
             assignments_integer: AssignmentsInteger::default(),
             watch_list_cp: WatchListCP::default(),
             watch_list_propositional: WatchListPropositional::default(),
-            propagator_queue: PropagatorQueue::new(5),
+            propagator_queue: PropagatorQueue::new(),
             reason_store: ReasonStore::default(),
             propositional_trail_index: 0,
             last_notified_cp_trail_index: 0,
@@ -461,16 +676,28 @@ impl ConstraintSatisfactionSolver {
             true_literal: dummy_literal,
             false_literal: !dummy_literal,
             conflict_analyser: ResolutionConflictAnalyser::default(),
+            variable_activities: VariableActivities::default(),
             clausal_propagator: ClausalPropagatorType::default(),
             learned_clause_manager: LearnedClauseManager::new(learning_options),
-            restart_strategy: RestartStrategy::new(solver_options.restart_options),
+            restart_strategy: Box::new(RestartStrategy::new(solver_options.restart_options)),
+            decisions: vec![],
             cp_propagators: PropagatorStore::default(),
+            pending_constraints: PendingConstraints::default(),
             counters: SolverStatistics::default(),
             internal_parameters: solver_options,
             analysis_result: ConflictAnalysisResult::default(),
             variable_names: VariableNames::default(),
             nogood_step_ids: KeyedVec::default(),
             unit_nogood_step_ids: HashMap::default(),
+            event_log: None,
+            num_clauses: 0,
+            conflict_limit: None,
+            decision_middlewares: DecisionMiddlewares::default(),
+            symmetries: vec![],
+            pending_symmetric_images: vec![],
+            nogood_exchange: None,
+            pending_imported_clauses: vec![],
+            lookahead_ranking: None,
         };
 
         // we introduce a dummy variable set to true at the root level
@@ -487,6 +714,8 @@ impl ConstraintSatisfactionSolver {
 
         let result = csp_solver.add_clause([true_literal]);
         pumpkin_assert_simple!(result.is_ok());
+        // The dummy clause is not part of the model
+        csp_solver.num_clauses = 0;
 
         csp_solver
     }
@@ -512,7 +741,13 @@ impl ConstraintSatisfactionSolver {
 
         let start_time = Instant::now();
 
-        self.initialise(assumptions);
+        let mut internal_assumptions = self
+            .variable_bound_assumptions()
+            .chain(self.scopes.iter().map(|scope| scope.activation_literal))
+            .collect::<Vec<_>>();
+        self.num_internal_assumptions = internal_assumptions.len();
+        internal_assumptions.extend_from_slice(assumptions);
+        self.initialise(&internal_assumptions);
         let result = self.solve_internal(termination, brancher);
 
         self.counters.engine_statistics.time_spent_in_solver +=
@@ -521,6 +756,557 @@ impl ConstraintSatisfactionSolver {
         result
     }
 
+    /// Solves under the provided assumptions similar to
+    /// [`ConstraintSatisfactionSolver::solve_under_assumptions`], but gives up (returning
+    /// [`CSPSolverExecutionFlag::Timeout`]) after `conflict_budget` conflicts.
+    pub(crate) fn solve_under_assumptions_with_conflict_budget(
+        &mut self,
+        assumptions: &[Literal],
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+        conflict_budget: u64,
+    ) -> CSPSolverExecutionFlag {
+        self.conflict_limit = Some(self.counters.engine_statistics.num_conflicts + conflict_budget);
+        let result = self.solve_under_assumptions(assumptions, termination, brancher);
+        self.conflict_limit = None;
+
+        result
+    }
+
+    /// Restricts the domain of `domain` to `[lower_bound, upper_bound]` in every subsequent call to
+    /// solve, replacing the bounds which were previously set for it.
+    ///
+    /// The bounds are not posted at the root but are assumed before the search starts; the
+    /// propagators and learned clauses thus remain valid when the bounds are relaxed again (see
+    /// [`ConstraintSatisfactionSolver::reset_variable_bounds`]). As a consequence, the bounds are
+    /// not reflected by the root domain of `domain` outside of solving. A core which is extracted
+    /// after solving under assumptions is relative to these bounds; the bounds themselves are
+    /// never part of it (see [`ConstraintSatisfactionSolver::extract_clausal_core`]).
+    pub(crate) fn set_variable_bounds(
+        &mut self,
+        domain: DomainId,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) {
+        let _ = self
+            .variable_bounds
+            .insert(domain, (lower_bound, upper_bound));
+    }
+
+    /// Removes the bounds which were set for `domain` using
+    /// [`ConstraintSatisfactionSolver::set_variable_bounds`].
+    pub(crate) fn reset_variable_bounds(&mut self, domain: DomainId) {
+        let _ = self.variable_bounds.remove(&domain);
+    }
+
+    /// Returns the assumptions which impose the bounds set through
+    /// [`ConstraintSatisfactionSolver::set_variable_bounds`]; bounds which are implied by the
+    /// initial domain of a variable are skipped.
+    fn variable_bound_assumptions(&self) -> impl Iterator<Item = Literal> + '_ {
+        self.variable_bounds
+            .iter()
+            .flat_map(|(&domain, &(lower_bound, upper_bound))| {
+                [
+                    predicate!(domain >= lower_bound),
+                    predicate!(domain <= upper_bound),
+                ]
+            })
+            .map(|predicate| self.get_literal(predicate))
+            .filter(|&literal| literal != self.assignments_propositional.true_literal)
+    }
+
+    /// Opens a new scope; the clauses and propagators which are added until the scope is closed
+    /// (see [`ConstraintSatisfactionSolver::pop_scope`]) are conditioned on a fresh activation
+    /// literal, which is assumed in every call to solve while the scope is open.
+    ///
+    /// Since the activation literals are assumptions, a learned clause which depends on the
+    /// clauses or propagators of a scope contains the negation of its activation literal; the
+    /// activation literal thus tags the learned clauses with the scope they depend on.
+    pub(crate) fn push_scope(&mut self) {
+        let activation_literal = Literal::new(self.create_new_propositional_variable(None), true);
+        self.scopes.push(Scope {
+            activation_literal,
+            num_propagators: self.cp_propagators.num_propagators(),
+        });
+    }
+
+    /// Closes the innermost scope and returns whether a scope was open.
+    ///
+    /// The activation literal of the scope is permanently falsified, after which the clauses which
+    /// contain its negation (i.e. the clauses which were added within the scope and the learned
+    /// clauses which depend on it) are deleted, and the propagators which were added since the
+    /// scope was opened are removed. The variables which were created within the scope are kept;
+    /// they are no longer constrained by the removed clauses and propagators.
+    pub(crate) fn pop_scope(&mut self) -> bool {
+        pumpkin_assert_simple!(
+            self.get_decision_level() == 0,
+            "Scopes can only be closed at the root"
+        );
+        let Some(scope) = self.scopes.pop() else {
+            return false;
+        };
+
+        // This only fails if the solver is already infeasible without the scope
+        if self
+            .add_permanent_clause(vec![!scope.activation_literal])
+            .is_err()
+        {
+            return true;
+        }
+
+        self.clausal_propagator.remove_clauses_containing(
+            !scope.activation_literal,
+            &self.assignments_propositional,
+            &mut self.clause_allocator,
+        );
+        self.learned_clause_manager
+            .remove_deleted_clauses(&self.clause_allocator);
+
+        // All propagators which were added since the scope was opened belong to the scope (or to
+        // a nested scope, which is already closed); propagators which are posted by other
+        // propagators while the scope is open are redundant and can be removed as well. Note that
+        // the reasons of root-level propagations may refer to the removed propagators, but these
+        // are never used.
+        pumpkin_assert_simple!(self.propagator_queue.is_empty());
+        self.cp_propagators.truncate(scope.num_propagators);
+        self.watch_list_cp
+            .remove_propagators_from(scope.num_propagators);
+        self.watch_list_propositional
+            .remove_propagators_from(scope.num_propagators);
+
+        true
+    }
+
+    /// Returns whether the solver adds assumptions of its own (i.e. the variable bounds or the
+    /// scope literals) in a call to solve; a conclusion which is reached under these assumptions
+    /// does not hold for the model itself.
+    pub(crate) fn has_internal_assumptions(&self) -> bool {
+        !self.scopes.is_empty() || self.variable_bound_assumptions().next().is_some()
+    }
+
+    /// Returns the activation literal of the innermost open scope, if any.
+    pub(crate) fn get_scope_literal(&self) -> Option<Literal> {
+        self.scopes.last().map(|scope| scope.activation_literal)
+    }
+
+    /// Returns the number of conflicts which the optimiser may spend on destructive
+    /// lower-bounding; this is 0 if a proof is being logged.
+    pub(crate) fn get_destructive_bounding_conflict_budget(&self) -> u64 {
+        if self.internal_parameters.proof_log.is_active() {
+            0
+        } else {
+            self.internal_parameters
+                .destructive_bounding_conflict_budget
+        }
+    }
+
+    /// Returns the number of conflicts which the optimiser may spend on probing the hints; this is
+    /// 0 if a proof is being logged.
+    pub(crate) fn get_hint_probe_conflict_budget(&self) -> u64 {
+        if self.internal_parameters.proof_log.is_active() {
+            0
+        } else {
+            self.internal_parameters.hint_probe_conflict_budget
+        }
+    }
+
+    /// Suggests `value` for `domain`, replacing the value which was previously suggested for it.
+    ///
+    /// The hints are soft: the branchers which are created by the solver (e.g.
+    /// [`ConstraintSatisfactionSolver::default_brancher_over_all_propositional_variables`]) prefer
+    /// the suggested values until a solution is found, but the hints are never enforced.
+    pub(crate) fn add_hint(&mut self, domain: DomainId, value: i32) {
+        match self.hints.iter_mut().find(|(other, _)| *other == domain) {
+            Some(hint) => *hint = (domain, value),
+            None => self.hints.push((domain, value)),
+        }
+    }
+
+    /// Returns the hints which were added using [`ConstraintSatisfactionSolver::add_hint`].
+    pub(crate) fn get_hints(&self) -> &[(DomainId, i32)] {
+        &self.hints
+    }
+
+    /// Returns the preferred values of the propositional variables which encode the domains of
+    /// the hinted variables; i.e. the values of the predicates over the unfixed part of the
+    /// domain if the variable takes its suggested value.
+    fn get_hinted_propositional_values(&self) -> Vec<(PropositionalVariable, bool)> {
+        let mut values = self
+            .hints
+            .iter()
+            .flat_map(|&(domain, value)| {
+                let lower_bound = self.assignments_integer.get_lower_bound(domain);
+                let upper_bound = self.assignments_integer.get_upper_bound(domain);
+                (lower_bound..=upper_bound).flat_map(move |bound| {
+                    [
+                        (predicate![domain >= bound], bound <= value),
+                        (predicate![domain == bound], bound == value),
+                    ]
+                })
+            })
+            .map(|(predicate, is_true)| {
+                let literal = self.get_literal(predicate);
+                (
+                    literal.get_propositional_variable(),
+                    literal.is_positive() == is_true,
+                )
+            })
+            .filter(|&(variable, _)| {
+                !self
+                    .assignments_propositional
+                    .is_variable_assigned(variable)
+            })
+            .collect::<Vec<_>>();
+
+        // Predicates can share a propositional variable (e.g. `[x >= u]` and `[x == u]` for the
+        // upper-bound `u`), which then have the same value
+        values.sort_by_key(|&(variable, _)| variable.index());
+        values.dedup_by_key(|&mut (variable, _)| variable);
+        values
+    }
+
+    /// Returns the options for polishing solutions during optimisation; polishing is disabled if a
+    /// proof is being logged.
+    pub(crate) fn get_solution_polishing_options(&self) -> SolutionPolishingOptions {
+        if self.internal_parameters.proof_log.is_active() {
+            SolutionPolishingOptions {
+                conflict_budget: 0,
+                ..self.internal_parameters.solution_polishing
+            }
+        } else {
+            self.internal_parameters.solution_polishing
+        }
+    }
+
+    /// Returns the assumptions which fix every variable to its value in the provided `solution`,
+    /// except for the `window_size` variables with the highest conflict activity; variables which
+    /// are fixed at the root or whose value in the solution is no longer in their domain (e.g. the
+    /// objective after it has been strengthened) are left free as well.
+    pub(crate) fn get_polishing_assumptions(
+        &self,
+        solution: &Solution,
+        window_size: usize,
+    ) -> Vec<Literal> {
+        let mut candidates = self
+            .assignments_integer
+            .get_domains()
+            .filter(|&domain_id| {
+                !self.assignments_integer.is_domain_assigned(domain_id)
+                    && self
+                        .assignments_integer
+                        .is_value_in_domain(domain_id, solution.get_integer_value(domain_id))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|&first, &second| {
+            self.variable_activities
+                .integer_activity(second)
+                .total_cmp(&self.variable_activities.integer_activity(first))
+        });
+
+        candidates
+            .into_iter()
+            .skip(window_size)
+            .map(|domain_id| {
+                self.get_literal(predicate![
+                    domain_id == solution.get_integer_value(domain_id)
+                ])
+            })
+            .collect()
+    }
+
+    /// Returns a summary of the search performed by the solver so far.
+    pub fn get_search_statistics(&self) -> SearchStatistics {
+        self.counters.engine_statistics.into()
+    }
+
+    /// Returns a summary of the model in the solver, see [`ModelStatistics`].
+    pub fn get_model_statistics(&self) -> ModelStatistics {
+        let mut statistics = ModelStatistics {
+            num_integer_variables: self.assignments_integer.num_domains() as usize,
+            num_propositional_variables: self
+                .assignments_propositional
+                .num_propositional_variables() as usize,
+            num_clauses: self.num_clauses,
+            ..Default::default()
+        };
+
+        for propagator in self.cp_propagators.iter_propagators() {
+            *statistics
+                .constraints_by_type
+                .entry(propagator.name().to_owned())
+                .or_default() += 1;
+        }
+
+        let mut num_occurrences = 0;
+        let mut num_unconstrained = 0;
+        for domain_id in self.assignments_integer.get_domains() {
+            *statistics
+                .integer_variables_by_domain_size
+                .entry(self.assignments_integer.get_domain_size(domain_id))
+                .or_default() += 1;
+
+            let num_watching_propagators = self.watch_list_cp.num_watching_propagators(domain_id);
+            num_occurrences += num_watching_propagators;
+            num_unconstrained += usize::from(num_watching_propagators == 0);
+        }
+
+        if statistics.num_integer_variables > 0 {
+            statistics.average_constraints_per_integer_variable =
+                num_occurrences as f64 / statistics.num_integer_variables as f64;
+            statistics.fraction_unconstrained_integer_variables =
+                num_unconstrained as f64 / statistics.num_integer_variables as f64;
+        }
+
+        statistics
+    }
+
+    /// Returns the number of propagators which have been added to the solver.
+    pub(crate) fn num_propagators(&self) -> usize {
+        self.cp_propagators.num_propagators()
+    }
+
+    /// Creates the assignment at the root in which the provided integer variables and literals are
+    /// additionally fixed to the provided values; returns [`None`] if one of the values is not in
+    /// the root domain.
+    pub(crate) fn assignment_from_values(
+        &self,
+        integer_values: &[(DomainId, i32)],
+        literal_values: &[(Literal, bool)],
+    ) -> Option<Solution> {
+        let mut assignments_integer = self.assignments_integer.clone();
+        let mut assignments_propositional = self.assignments_propositional.clone();
+
+        let mut predicate_values = vec![];
+        for &(domain_id, value) in integer_values {
+            if !assignments_integer.is_value_in_domain(domain_id, value) {
+                return None;
+            }
+            if !assignments_integer.is_domain_assigned(domain_id) {
+                assignments_integer
+                    .make_assignment(domain_id, value, None)
+                    .ok()?;
+            }
+
+            // The literals of the predicates over the variable take the values which are implied
+            // by its value
+            let initial_lower_bound = assignments_integer.get_initial_lower_bound(domain_id);
+            let lower_bound_literals = &self
+                .variable_literal_mappings
+                .domain_to_lower_bound_literals[domain_id];
+            let equality_literals =
+                &self.variable_literal_mappings.domain_to_equality_literals[domain_id];
+            for (offset, (&lower_bound_literal, &equality_literal)) in lower_bound_literals
+                .iter()
+                .zip(equality_literals.iter())
+                .enumerate()
+            {
+                let bound = initial_lower_bound + offset as i32;
+                predicate_values.push((lower_bound_literal, value >= bound));
+                predicate_values.push((equality_literal, value == bound));
+            }
+        }
+        // The constraints of the open scopes are active
+        let scope_values = self
+            .scopes
+            .iter()
+            .map(|scope| (scope.activation_literal, true));
+        for (literal, value) in literal_values
+            .iter()
+            .copied()
+            .chain(scope_values)
+            .chain(predicate_values)
+        {
+            let literal = if value { literal } else { !literal };
+            if assignments_propositional.is_literal_assigned_false(literal) {
+                return None;
+            }
+            if assignments_propositional.is_literal_unassigned(literal) {
+                assignments_propositional.enqueue_decision_literal(literal);
+            }
+        }
+
+        Some(Solution::new(
+            assignments_propositional,
+            assignments_integer,
+        ))
+    }
+
+    /// Propagates the propagator with the given id from scratch on the provided (complete)
+    /// assignment, and returns the explanation of the conflict if the assignment violates it; the
+    /// explanation is empty if the propagator only reports an empty domain.
+    pub(crate) fn check_propagator(
+        &self,
+        propagator_id: PropagatorId,
+        assignments_integer: &AssignmentsInteger,
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> Option<Vec<Predicate>> {
+        let mut assignments_integer = assignments_integer.clone();
+        let mut assignments_propositional = assignments_propositional.clone();
+        let mut reason_store = Default::default();
+
+        let context = PropagationContextMut::new(
+            &mut assignments_integer,
+            &mut reason_store,
+            &mut assignments_propositional,
+            propagator_id,
+        );
+        match self.cp_propagators[propagator_id].debug_propagate_from_scratch(context) {
+            Ok(()) => None,
+            Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) => {
+                Some(explanation.into_iter().collect())
+            }
+            Err(_) => Some(vec![]),
+        }
+    }
+
+    /// Returns the first of the provided clauses which is violated by the provided (complete)
+    /// assignment, or [`None`] if the assignment satisfies them.
+    ///
+    /// The literals which are not assigned (e.g. the auxiliary literals of a decomposition) are
+    /// set by unit propagation over the clauses; a clause which is falsified after propagation
+    /// means that the assignment cannot be extended to satisfy all of them.
+    pub(crate) fn check_clauses<'a>(
+        clauses: &[&'a [Literal]],
+        assignments_propositional: &AssignmentsPropositional,
+    ) -> Option<&'a [Literal]> {
+        let mut implied_literals = HashSet::default();
+        let value = |literal: Literal, implied_literals: &HashSet<Literal>| {
+            if assignments_propositional.is_literal_assigned_true(literal)
+                || implied_literals.contains(&literal)
+            {
+                Some(true)
+            } else if assignments_propositional.is_literal_assigned_false(literal)
+                || implied_literals.contains(&!literal)
+            {
+                Some(false)
+            } else {
+                None
+            }
+        };
+
+        loop {
+            let mut has_propagated = false;
+            for &clause in clauses {
+                let mut unassigned_literals = clause
+                    .iter()
+                    .filter(|&&literal| value(literal, &implied_literals) != Some(false));
+                match (unassigned_literals.next(), unassigned_literals.next()) {
+                    (None, _) => return Some(clause),
+                    (Some(&literal), None) if value(literal, &implied_literals).is_none() => {
+                        let _ = implied_literals.insert(literal);
+                        has_propagated = true;
+                    }
+                    _ => {}
+                }
+            }
+
+            if !has_propagated {
+                return None;
+            }
+        }
+    }
+
+    /// Returns the description of the propagator in the syntax of
+    /// [`ConstraintSatisfactionSolver::dump_pretty`].
+    pub(crate) fn describe_propagator(&self, propagator_id: PropagatorId) -> String {
+        let scope = self
+            .assignments_integer
+            .get_domains()
+            .filter(|&domain_id| {
+                self.watch_list_cp
+                    .watching_propagators(domain_id)
+                    .any(|watching| watching == propagator_id)
+            })
+            .collect::<Vec<_>>();
+
+        self.describe_propagator_with_scope(propagator_id, &scope)
+    }
+
+    /// Returns the [`Propagator::describe`] of the propagator, or its name applied to the variables
+    /// in its `scope` if it does not provide a description.
+    fn describe_propagator_with_scope(
+        &self,
+        propagator_id: PropagatorId,
+        scope: &[DomainId],
+    ) -> String {
+        let propagator = &self.cp_propagators[propagator_id];
+        let description = propagator.describe().unwrap_or_else(|| {
+            let scope = scope
+                .iter()
+                .map(|domain_id| domain_id.to_string())
+                .join(", ");
+            format!("{}({scope})", propagator.name())
+        });
+
+        self.variable_names.substitute_int_names(&description)
+    }
+
+    /// Returns the model in the solver in a readable syntax which resembles MiniZinc; see
+    /// [`Solver::dump_pretty`].
+    pub(crate) fn dump_pretty(&self) -> String {
+        let mut dump = String::new();
+
+        // The variables which are watched by each propagator, in order of their identifiers
+        let mut scopes: HashMap<PropagatorId, Vec<DomainId>> = HashMap::default();
+        for domain_id in self.assignments_integer.get_domains() {
+            for propagator_id in self.watch_list_cp.watching_propagators(domain_id) {
+                scopes.entry(propagator_id).or_default().push(domain_id);
+            }
+
+            let lower_bound = self.assignments_integer.get_lower_bound(domain_id);
+            let upper_bound = self.assignments_integer.get_upper_bound(domain_id);
+            let domain = if self.assignments_integer.get_number_of_holes(domain_id) == 0 {
+                format!("{lower_bound}..{upper_bound}")
+            } else {
+                let values = (lower_bound..=upper_bound)
+                    .filter(|&value| {
+                        self.assignments_integer
+                            .is_value_in_domain(domain_id, value)
+                    })
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", values.join(", "))
+            };
+            let name = self.variable_names.get_int_name_or_default(domain_id);
+            let _ = writeln!(dump, "var {domain}: {name};");
+        }
+
+        for index in 0..self.num_propagators() {
+            let propagator_id = PropagatorId::create_from_index(index);
+            let scope = scopes.get(&propagator_id).map_or(&[][..], |scope| scope);
+            let description = self.describe_propagator_with_scope(propagator_id, scope);
+            let _ = writeln!(dump, "constraint {description};");
+        }
+
+        for &clause_reference in self.clausal_propagator.permanent_clauses.iter() {
+            let clause = self.clause_allocator.get_clause(clause_reference);
+            let literals = clause.get_literal_slice();
+            if clause.is_learned() || clause.is_deleted() || self.is_encoding_clause(literals) {
+                continue;
+            }
+
+            let _ = writeln!(dump, "constraint {};", self.display_with(literals));
+        }
+
+        dump
+    }
+
+    /// Returns whether the clause only relates predicates over a single integer variable, in
+    /// which case it is part of the encoding of its domain rather than of the model.
+    fn is_encoding_clause(&self, clause: &[Literal]) -> bool {
+        let mut domains = clause.iter().map(|&literal| {
+            self.variable_literal_mappings
+                .get_predicates(literal)
+                .next()
+                .map(|predicate| predicate.get_domain())
+        });
+
+        match domains.next() {
+            Some(Some(domain_id)) => domains.all(|other| other == Some(domain_id)),
+            _ => false,
+        }
+    }
+
     pub fn default_brancher_over_all_propositional_variables(&self) -> DefaultBrancher {
         #[allow(deprecated)]
         let variables = self
@@ -532,13 +1318,45 @@ impl ConstraintSatisfactionSolver {
             variable_selector: Vsids::new(&variables),
             value_selector: SolutionGuidedValueSelector::new(
                 &variables,
-                Vec::new(),
+                self.get_hinted_propositional_values(),
                 PhaseSaving::new(&variables),
             ),
             variable_type: PhantomData,
         }
     }
 
+    pub fn vsids_brancher_over_all_integer_variables(&self) -> IntegerVsidsBrancher {
+        let variables = self.assignments_integer.get_domains().collect::<Vec<_>>();
+
+        IndependentVariableValueBrancher {
+            variable_selector: Vsids::new(&variables),
+            value_selector: SolutionGuidedValueSelector::new(
+                &variables,
+                self.hints.clone(),
+                InDomainMin,
+            ),
+            variable_type: PhantomData,
+        }
+    }
+
+    pub fn activity_based_brancher_over_all_integer_variables(&self) -> ActivityBasedBrancher {
+        let variables = self.assignments_integer.get_domains().collect::<Vec<_>>();
+        let options = self.internal_parameters.activity_based_search;
+
+        IndependentVariableValueBrancher {
+            variable_selector: LastConflict::new(
+                ActivityBased::new(&variables, options.decay_factor),
+                options.last_conflict_size,
+            ),
+            value_selector: SolutionGuidedValueSelector::new(
+                &variables,
+                self.hints.clone(),
+                InDomainMin,
+            ),
+            variable_type: PhantomData,
+        }
+    }
+
     pub fn get_state(&self) -> &CSPSolverState {
         &self.state
     }
@@ -570,8 +1388,8 @@ impl ConstraintSatisfactionSolver {
         name: Option<String>,
     ) -> DomainId {
         assert!(
-            !self.state.is_inconsistent(),
-            "Variables cannot be created in an inconsistent state"
+            !self.state.conflicting(),
+            "Variables cannot be created in a conflicting state"
         );
 
         let domain = self.variable_literal_mappings.create_new_domain(
@@ -592,6 +1410,25 @@ impl ConstraintSatisfactionSolver {
         domain
     }
 
+    /// Creates `num_variables` integer variables with the domain [`lower_bound`, `upper_bound`].
+    ///
+    /// This is equivalent to calling [`ConstraintSatisfactionSolver::create_new_integer_variable`]
+    /// `num_variables` times, but the space for the variables is reserved up front.
+    pub fn create_new_integer_variables(
+        &mut self,
+        num_variables: usize,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Vec<DomainId> {
+        self.assignments_integer.reserve(num_variables);
+        self.watch_list_cp.reserve(num_variables);
+        self.variable_literal_mappings.reserve(num_variables);
+
+        (0..num_variables)
+            .map(|_| self.create_new_integer_variable(lower_bound, upper_bound, None))
+            .collect()
+    }
+
     /// Creates an integer variable with a domain containing only the values in `values`
     pub fn create_new_integer_variable_sparse(
         &mut self,
@@ -656,6 +1493,10 @@ impl ConstraintSatisfactionSolver {
     ///     will return an empty vector.
     ///   - If the assumptions are inconsistent, i.e. both literal x and !x are assumed, an error is
     ///     returned, with the literal being one of the inconsistent assumptions.
+    ///   - The core is relative to the variable bounds (see
+    ///     [`ConstraintSatisfactionSolver::set_variable_bounds`]) and the open scopes (see
+    ///     [`ConstraintSatisfactionSolver::push_scope`]); the assumptions which impose these are
+    ///     never part of the core.
     ///
     /// # Example usage
     /// ```rust
@@ -723,6 +1564,7 @@ impl ConstraintSatisfactionSolver {
             counters: &mut self.counters,
             learned_clause_manager: &mut self.learned_clause_manager,
             nogood_step_ids: &self.nogood_step_ids,
+            variable_activities: &mut self.variable_activities,
         };
 
         let core = self
@@ -733,7 +1575,60 @@ impl ConstraintSatisfactionSolver {
             self.restore_state_at_root(brancher);
         }
 
-        core
+        self.remove_internal_assumptions_from_core(core)
+    }
+
+    /// Removes the assumptions which were added by the solver itself (i.e. the variable bounds and
+    /// the scope literals) from the provided core, such that it only contains user assumptions.
+    fn remove_internal_assumptions_from_core(
+        &self,
+        core: CoreExtractionResult,
+    ) -> CoreExtractionResult {
+        let internal_assumptions = &self.assumptions[..self.num_internal_assumptions];
+        let is_internal = |literal: &Literal| internal_assumptions.contains(literal);
+
+        match core {
+            CoreExtractionResult::Core(core) => CoreExtractionResult::Core(
+                core.into_iter()
+                    .filter(|literal| !is_internal(literal))
+                    .collect(),
+            ),
+            // If one of the two conflicting assumptions is internal, then the other one is a
+            // core by itself
+            CoreExtractionResult::ConflictingAssumption(literal) => {
+                match (is_internal(&literal), is_internal(&!literal)) {
+                    (false, false) => CoreExtractionResult::ConflictingAssumption(literal),
+                    (true, false) => CoreExtractionResult::Core(vec![!literal]),
+                    (false, true) => CoreExtractionResult::Core(vec![literal]),
+                    (true, true) => CoreExtractionResult::Core(vec![]),
+                }
+            }
+        }
+    }
+
+    /// Notifies the [`RestartPolicy`] that a new incumbent has been found during optimisation.
+    pub(crate) fn notify_new_incumbent(&mut self) {
+        self.restart_strategy.notify_new_incumbent()
+    }
+
+    /// Extracts a core using [`ConstraintSatisfactionSolver::extract_clausal_core`] which is then
+    /// minimised using semantic minimisation; the minimised core is a subset of the original core.
+    pub(crate) fn extract_minimised_clausal_core(
+        &mut self,
+        brancher: &mut impl Brancher,
+    ) -> CoreExtractionResult {
+        match self.extract_clausal_core(brancher) {
+            // A core consisting of a single assumption cannot be minimised any further
+            CoreExtractionResult::Core(core) if core.len() > 1 => {
+                CoreExtractionResult::Core(self.conflict_analyser.minimise_core(
+                    &core,
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                    &self.variable_literal_mappings,
+                ))
+            }
+            result => result,
+        }
     }
 
     #[allow(unused)]
@@ -758,6 +1653,7 @@ impl ConstraintSatisfactionSolver {
             counters: &mut self.counters,
             learned_clause_manager: &mut self.learned_clause_manager,
             nogood_step_ids: &self.nogood_step_ids,
+            variable_activities: &mut self.variable_activities,
         };
 
         self.conflict_analyser
@@ -812,6 +1708,22 @@ impl ConstraintSatisfactionSolver {
         variable.upper_bound(&self.assignments_integer)
     }
 
+    /// Get the number of values in the domain of the given variable.
+    pub fn get_domain_size(&self, variable: &impl IntegerVariable) -> usize {
+        variable.size(&self.assignments_integer)
+    }
+
+    /// Get the number of values between the bounds of the given [`DomainId`] which are not in its
+    /// domain.
+    pub fn get_number_of_holes(&self, domain_id: DomainId) -> usize {
+        self.assignments_integer.get_number_of_holes(domain_id)
+    }
+
+    /// Get the domain of the given [`DomainId`] as a sorted list of disjoint ranges.
+    pub fn get_domain_ranges(&self, domain_id: DomainId) -> Vec<RangeInclusive<i32>> {
+        self.assignments_integer.get_domain_ranges(domain_id)
+    }
+
     /// Determine whether `value` is in the domain of `variable`.
     pub fn integer_variable_contains(&self, variable: &impl IntegerVariable, value: i32) -> bool {
         variable.contains(&self.assignments_integer, value)
@@ -841,20 +1753,83 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
-    #[deprecated = "users of the solvers should not have to access solver fields"]
-    pub(crate) fn get_propositional_assignments(&self) -> &AssignmentsPropositional {
-        &self.assignments_propositional
+    /// Start recording the decisions and domain events which occur during search into an
+    /// [`EventLog`]; any previously recorded events are discarded. The log can be retrieved using
+    /// [`ConstraintSatisfactionSolver::take_event_log`].
+    ///
+    /// Together with the [`ReplayBrancher`], this allows checking that the solver behaves
+    /// deterministically, at the cost of storing every event.
+    pub fn record_events(&mut self) {
+        self.event_log = Some(EventLog::default());
     }
 
-    pub fn restore_state_at_root(&mut self, brancher: &mut impl Brancher) {
-        if !self.assignments_propositional.is_at_the_root_level() {
-            self.backtrack(0, brancher);
-            self.state.declare_ready();
-        }
+    /// Returns the events recorded since [`ConstraintSatisfactionSolver::record_events`] was
+    /// called, and stops recording. If events were not being recorded, [`None`] is returned.
+    pub fn take_event_log(&mut self) -> Option<EventLog> {
+        self.event_log.take()
     }
 
-    fn synchronise_propositional_trail_based_on_integer_trail(&mut self) -> Option<ConflictInfo> {
-        // for each entry on the integer trail, we now add the equivalent propositional
+    /// Registers a [`DecisionMiddleware`] which is applied to every subsequent decision of the
+    /// brancher, after the middlewares which were registered before it.
+    ///
+    /// Since the decisions which are applied may differ from the decisions of the brancher, the
+    /// trail is not reused upon restarting (see [`RestartOptions::reuse_trail`]) once a middleware
+    /// has been registered.
+    pub fn add_decision_middleware(&mut self, middleware: impl DecisionMiddleware + 'static) {
+        self.decision_middlewares.add(Box::new(middleware));
+    }
+
+    /// Replaces the [`RestartPolicy`] which determines when the solver restarts; by default, the
+    /// policy is configured by [`SatisfactionSolverOptions::restart_options`].
+    pub fn set_restart_policy(&mut self, restart_policy: impl RestartPolicy + 'static) {
+        self.restart_strategy = Box::new(restart_policy);
+    }
+
+    /// Connects the solver to the other workers of a portfolio, with which it shares learned
+    /// clauses; see [`NogoodExchange`].
+    pub(crate) fn set_nogood_exchange(&mut self, nogood_exchange: NogoodExchange) {
+        self.nogood_exchange = Some(nogood_exchange);
+    }
+
+    /// Declares a symmetry of the model, of which the images of learned clauses are learned as
+    /// well (see [`SatisfactionSolverOptions::max_symmetric_images`]).
+    ///
+    /// The symmetry should map every solution to a solution (with the same objective value when
+    /// optimising); otherwise, solutions may be removed.
+    pub(crate) fn add_symmetry(&mut self, symmetry: VariableSymmetry) {
+        self.symmetries.push(symmetry);
+    }
+
+    /// Get the conflict activity of the given integer variable; the more often the variable has
+    /// appeared in recent conflicts, the higher its activity.
+    pub fn get_integer_variable_activity(&self, domain: DomainId) -> f64 {
+        self.variable_activities.integer_activity(domain)
+    }
+
+    /// Get the conflict activity of the given propositional variable; the more often the variable
+    /// has appeared in recent conflicts, the higher its activity.
+    pub fn get_propositional_variable_activity(&self, variable: PropositionalVariable) -> f64 {
+        self.variable_activities.propositional_activity(variable)
+    }
+
+    #[deprecated = "users of the solvers should not have to access solver fields"]
+    pub(crate) fn get_propositional_assignments(&self) -> &AssignmentsPropositional {
+        &self.assignments_propositional
+    }
+
+    pub fn restore_state_at_root(&mut self, brancher: &mut impl Brancher) {
+        if !self.assignments_propositional.is_at_the_root_level() {
+            self.backtrack(0, brancher);
+            self.state.declare_ready();
+        } else if self.state.timeout() {
+            // The search can be interrupted before any decision is made, in which case the solver
+            // should still be able to solve again
+            self.state.declare_ready();
+        }
+    }
+
+    fn synchronise_propositional_trail_based_on_integer_trail(&mut self) -> Option<ConflictInfo> {
+        // for each entry on the integer trail, we now add the equivalent propositional
         // representation on the propositional trail  note that only one literal per
         // predicate will be stored      since the clausal propagator will propagate other
         // literals to ensure that the meaning of the literal is respected          e.g.,
@@ -925,6 +1900,8 @@ impl ConstraintSatisfactionSolver {
 
         if self.assignments_integer.num_domains() == 0 {
             self.sat_trail_synced_position = self.assignments_propositional.num_trail_entries();
+            // The propagators which watch literals still have to be notified
+            let _ = self.process_domain_events();
             return Ok(());
         }
 
@@ -983,6 +1960,11 @@ impl ConstraintSatisfactionSolver {
         );
         self.state.declare_solving();
         assumptions.clone_into(&mut self.assumptions);
+        self.lookahead_ranking = None;
+
+        if self.get_decision_level() == 0 {
+            self.post_pending_constraints();
+        }
     }
 
     fn solve_internal(
@@ -996,6 +1978,14 @@ impl ConstraintSatisfactionSolver {
                 return CSPSolverExecutionFlag::Timeout;
             }
 
+            if self
+                .conflict_limit
+                .is_some_and(|limit| self.counters.engine_statistics.num_conflicts >= limit)
+            {
+                self.state.declare_timeout();
+                return CSPSolverExecutionFlag::Timeout;
+            }
+
             self.learned_clause_manager
                 .shrink_learned_clause_database_if_needed(
                     &self.assignments_propositional,
@@ -1003,9 +1993,18 @@ impl ConstraintSatisfactionSolver {
                     &mut self.clausal_propagator,
                 );
 
-            self.propagate_enqueued();
+            if self.state.no_conflict() {
+                self.propagate_enqueued();
+            }
 
             if self.state.no_conflict() {
+                if self.internal_parameters.lookahead_budget > 0
+                    && self.lookahead_ranking.is_none()
+                    && self.get_decision_level() == 0
+                {
+                    self.compute_lookahead_ranking(brancher);
+                }
+
                 self.declare_new_decision_level();
 
                 // Restarts should only occur after a new decision level has been declared to
@@ -1013,8 +2012,14 @@ impl ConstraintSatisfactionSolver {
                 // place. Since one assumption is posted per decision level, all assumptions are
                 // assigned when the decision level is strictly larger than the number of
                 // assumptions.
-                if self.restart_strategy.should_restart() {
-                    self.restart_during_search(brancher);
+                if self.restart_strategy.should_restart() && self.restart_during_search(brancher) {
+                    if self.state.is_infeasible() {
+                        return CSPSolverExecutionFlag::Infeasible;
+                    }
+
+                    // The constraints which were posted during the restart first need to be
+                    // propagated at the root
+                    continue;
                 }
 
                 let branching_result = self.enqueue_next_decision(brancher);
@@ -1024,6 +2029,10 @@ impl ConstraintSatisfactionSolver {
             }
             // conflict
             else {
+                if let Some(event_log) = self.event_log.as_mut() {
+                    event_log.push(LoggedEvent::Conflict);
+                }
+
                 if self.assignments_propositional.is_at_the_root_level() {
                     if self.assumptions.is_empty() {
                         // Only complete the proof when _not_ solving under assumptions. It is
@@ -1041,6 +2050,7 @@ impl ConstraintSatisfactionSolver {
                 self.resolve_conflict(brancher);
 
                 self.learned_clause_manager.decay_clause_activities();
+                self.variable_activities.decay();
 
                 brancher.on_conflict()
             }
@@ -1058,36 +2068,152 @@ impl ConstraintSatisfactionSolver {
             }
             Ok(())
         } else {
-            let decided_predicate = brancher.next_decision(&mut SelectionContext::new(
-                &self.assignments_integer,
-                &self.assignments_propositional,
-                &mut self.internal_parameters.random_generator,
-            ));
-            if let Some(predicate) = decided_predicate {
+            let mut last_skipped_predicate = None;
+            loop {
+                let decided_predicate = brancher.next_decision(
+                    &mut SelectionContext::new(
+                        &self.assignments_integer,
+                        &self.assignments_propositional,
+                        &mut self.internal_parameters.random_generator,
+                    )
+                    .with_lookahead_ranking(self.lookahead_ranking.as_ref()),
+                );
+                let Some(decided_predicate) = decided_predicate else {
+                    self.state.declare_solution_found();
+                    return Err(CSPSolverExecutionFlag::Feasible);
+                };
+
+                // A vetoed decision is replaced by its negation; the brancher would otherwise
+                // propose the same decision again, as it is not aware of the veto.
+                let predicate = match self.decision_middlewares.on_decision(
+                    decided_predicate,
+                    &mut SelectionContext::new(
+                        &self.assignments_integer,
+                        &self.assignments_propositional,
+                        &mut self.internal_parameters.random_generator,
+                    )
+                    .with_lookahead_ranking(self.lookahead_ranking.as_ref()),
+                ) {
+                    Some(predicate) => {
+                        if predicate != decided_predicate {
+                            self.counters.engine_statistics.num_rewritten_decisions += 1;
+                        }
+                        predicate
+                    }
+                    None => {
+                        self.counters.engine_statistics.num_vetoed_decisions += 1;
+                        !decided_predicate
+                    }
+                };
+
+                let decision_literal = match predicate {
+                    Predicate::IntegerPredicate(integer_predicate) => {
+                        self.variable_literal_mappings.get_literal(
+                            integer_predicate,
+                            &self.assignments_propositional,
+                            &self.assignments_integer,
+                        )
+                    }
+                    bool_predicate => bool_predicate
+                        .get_literal_of_bool_predicate(self.assignments_propositional.true_literal)
+                        .unwrap(),
+                };
+
+                // The brancher can return a predicate which is already satisfied or falsified
+                // (e.g. when it relies on stale information); rather than creating a decision
+                // level without a decision, the brancher is asked for another decision. If the
+                // brancher returns the same assigned predicate twice in a row, then it does not
+                // take the assignment into account at all and asking again would never end.
+                if self
+                    .assignments_propositional
+                    .is_literal_assigned(decision_literal)
+                {
+                    assert!(
+                        last_skipped_predicate != Some(decided_predicate),
+                        "the brancher repeatedly proposed the assigned decision {decided_predicate:?}"
+                    );
+                    last_skipped_predicate = Some(decided_predicate);
+                    self.counters.engine_statistics.num_skipped_decisions += 1;
+                    continue;
+                }
+
                 self.counters.engine_statistics.num_decisions += 1;
+                trace!("Decision {}", self.display_with(&predicate));
+                if let Some(event_log) = self.event_log.as_mut() {
+                    event_log.push(LoggedEvent::Decision(predicate));
+                }
+                self.decisions.push(predicate);
                 self.assignments_propositional
-                    .enqueue_decision_literal(match predicate {
-                        Predicate::IntegerPredicate(integer_predicate) => {
-                            self.variable_literal_mappings.get_literal(
-                                integer_predicate,
-                                &self.assignments_propositional,
-                                &self.assignments_integer,
-                            )
-                        }
-                        bool_predicate => bool_predicate
-                            .get_literal_of_bool_predicate(
-                                self.assignments_propositional.true_literal,
-                            )
-                            .unwrap(),
-                    });
-                Ok(())
-            } else {
-                self.state.declare_solution_found();
-                Err(CSPSolverExecutionFlag::Feasible)
+                    .enqueue_decision_literal(decision_literal);
+                return Ok(());
             }
         }
     }
 
+    /// Probes both halves of the domains of (at most
+    /// [`SatisfactionSolverOptions::lookahead_budget`]) unfixed integer variables at the root, and
+    /// stores the resulting [`LookaheadRanking`]; the solver is at the root again afterwards.
+    fn compute_lookahead_ranking(&mut self, brancher: &mut impl Brancher) {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+
+        let candidates = self
+            .assignments_integer
+            .get_domains()
+            .filter(|&domain_id| !self.assignments_integer.is_domain_assigned(domain_id))
+            .take(self.internal_parameters.lookahead_budget)
+            .collect::<Vec<_>>();
+
+        let ranking = candidates
+            .into_iter()
+            .map(|domain_id| {
+                let lower_bound = self.assignments_integer.get_lower_bound(domain_id);
+                let upper_bound = self.assignments_integer.get_upper_bound(domain_id);
+                let midpoint = lower_bound + (upper_bound - lower_bound) / 2;
+
+                let lower_half = predicate![domain_id <= midpoint];
+                let upper_half = predicate![domain_id >= midpoint + 1];
+                let lower_impact = self.probe(lower_half, brancher);
+                let upper_impact = self.probe(upper_half, brancher);
+
+                let score = (1.0 + lower_impact) * (1.0 + upper_impact);
+                if lower_impact <= upper_impact {
+                    (lower_half, score)
+                } else {
+                    (upper_half, score)
+                }
+            })
+            .collect();
+
+        self.lookahead_ranking = Some(LookaheadRanking::new(ranking));
+    }
+
+    /// Propagates `predicate` at a new decision level and returns the number of domain changes it
+    /// causes, or infinity if it leads to a conflict; the solver backtracks to the root afterwards.
+    fn probe(&mut self, predicate: Predicate, brancher: &mut impl Brancher) -> f64 {
+        self.counters.engine_statistics.num_lookahead_probes += 1;
+
+        let num_trail_entries = self.assignments_integer.num_trail_entries();
+        let literal = self.get_literal(predicate);
+
+        self.declare_new_decision_level();
+        self.assignments_propositional
+            .enqueue_decision_literal(literal);
+        self.propagate_enqueued();
+
+        let impact = if self.state.conflicting() {
+            f64::INFINITY
+        } else {
+            (self.assignments_integer.num_trail_entries() - num_trail_entries) as f64
+        };
+
+        self.backtrack(0, brancher);
+        if self.state.conflicting() {
+            self.state.declare_solving();
+        }
+
+        impact
+    }
+
     /// Returns true if the assumption was successfully enqueued, and false otherwise
     pub(crate) fn enqueue_assumption_literal(&mut self, assumption_literal: Literal) -> bool {
         // Case 1: the assumption is unassigned, assign it
@@ -1139,14 +2265,56 @@ impl ConstraintSatisfactionSolver {
     fn resolve_conflict(&mut self, brancher: &mut impl Brancher) {
         pumpkin_assert_moderate!(self.state.conflicting());
 
-        self.analysis_result = self.compute_learned_clause(brancher);
+        // Notify the brancher of the propagator which caused the conflict; an empty domain is
+        // reported as a propagation of the clausal propagator with the reason of the propagator
+        let conflicting_propagator = match self.state.get_conflict_info() {
+            StoredConflictInfo::Explanation { propagator, .. } => Some(*propagator),
+            StoredConflictInfo::Propagation { reference, .. } if reference.is_cp_reason() => {
+                Some(self.reason_store.get_propagator(reference.get_reason_ref()))
+            }
+            _ => None,
+        };
+        if let Some(propagator) = conflicting_propagator {
+            brancher.on_propagator_conflict(self.watch_list_cp.scope(propagator));
+        }
+
+        // Conflicts while assigning the assumptions are always analysed, since the decision
+        // clause would contain only assumptions
+        let decision_level = self.get_decision_level();
+        if self
+            .learned_clause_manager
+            .learns_at_decision_level(decision_level)
+            || decision_level <= self.assumptions.len()
+        {
+            let export_implication_graph = self
+                .internal_parameters
+                .implication_graph_export
+                .as_ref()
+                .is_some_and(|export| {
+                    export.conflict == self.counters.engine_statistics.num_conflicts
+                });
+            if export_implication_graph {
+                self.conflict_analyser.record_implication_graph();
+            }
+
+            self.compute_learned_clause(brancher);
+
+            if export_implication_graph {
+                self.export_implication_graph();
+            }
+        } else {
+            self.compute_decision_clause()
+        }
 
         self.process_learned_clause(brancher);
 
         self.state.declare_solving();
     }
 
-    fn compute_learned_clause(&mut self, brancher: &mut impl Brancher) -> ConflictAnalysisResult {
+    /// Computes the 1UIP clause and stores it in
+    /// [`ConstraintSatisfactionSolver::analysis_result`]; the buffer of the previous result is
+    /// reused.
+    fn compute_learned_clause(&mut self, brancher: &mut impl Brancher) {
         let mut conflict_analysis_context = ConflictAnalysisContext {
             propagator_store: &self.cp_propagators,
             assumptions: &self.assumptions,
@@ -1163,12 +2331,77 @@ impl ConstraintSatisfactionSolver {
             counters: &mut self.counters,
             learned_clause_manager: &mut self.learned_clause_manager,
             nogood_step_ids: &self.nogood_step_ids,
+            variable_activities: &mut self.variable_activities,
         };
-        self.conflict_analyser
-            .compute_1uip(&mut conflict_analysis_context)
+        let result = self
+            .conflict_analyser
+            .compute_1uip(&mut conflict_analysis_context);
+        self.analysis_result.clone_from(result);
+    }
+
+    /// Writes the implication graph which was recorded during the analysis of the current conflict
+    /// to the file in [`SatisfactionSolverOptions::implication_graph_export`].
+    fn export_implication_graph(&mut self) {
+        let Some(implication_graph) = self.conflict_analyser.take_implication_graph() else {
+            return;
+        };
+        let Some(export) = self.internal_parameters.implication_graph_export.as_ref() else {
+            return;
+        };
+
+        let result = File::create(&export.path).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            implication_graph.write_dot(&mut writer, |literal| {
+                self.display_with(&literal).to_string()
+            })?;
+            std::io::Write::flush(&mut writer)
+        });
+
+        if let Err(error) = result {
+            warn!(
+                "Failed to write the implication graph to {}: {error}",
+                export.path.display()
+            );
+        }
+    }
+
+    /// Computes the clause consisting of the negated decisions (with the most recent decision at
+    /// index 0), which is asserting after backtracking to the previous decision. Learning this
+    /// clause instead of the 1UIP clause corresponds to chronological backtracking.
+    ///
+    /// Similar to [`ConstraintSatisfactionSolver::compute_learned_clause`], the clause is stored in
+    /// [`ConstraintSatisfactionSolver::analysis_result`].
+    fn compute_decision_clause(&mut self) {
+        let assignments = &self.assignments_propositional;
+        let learned_literals = &mut self.analysis_result.learned_literals;
+
+        learned_literals.clear();
+        learned_literals.extend(
+            (0..assignments.num_trail_entries())
+                .rev()
+                .map(|trail_index| assignments.get_trail_entry(trail_index))
+                .filter(|&literal| {
+                    assignments.is_literal_decision(literal)
+                        && !assignments.is_literal_root_assignment(literal)
+                })
+                .map(|decision| !decision),
+        );
+
+        self.analysis_result.backjump_level = learned_literals.get(1).map_or(0, |&literal| {
+            assignments.get_literal_assignment_level(literal)
+        });
     }
 
     fn process_learned_clause(&mut self, brancher: &mut impl Brancher) {
+        trace!(
+            "Learned clause {}",
+            self.display_with(self.analysis_result.learned_literals.as_slice())
+        );
+
+        if !self.internal_parameters.proof_log.is_active() {
+            self.queue_symmetric_images();
+        }
+
         let proof_step_id = self
             .internal_parameters
             .proof_log
@@ -1181,6 +2414,11 @@ impl ConstraintSatisfactionSolver {
             // the trail
             self.restart_strategy
                 .notify_conflict(1, self.assignments_propositional.num_trail_entries());
+            self.export_learned_clause(1);
+            self.counters
+                .learned_clause_statistics
+                .lbd_distribution
+                .add(1);
 
             self.backtrack(0, brancher);
 
@@ -1227,18 +2465,54 @@ impl ConstraintSatisfactionSolver {
 
             self.restart_strategy
                 .notify_conflict(lbd, *num_variables_assigned_before_conflict);
+            self.export_learned_clause(lbd);
+            self.counters
+                .learned_clause_statistics
+                .lbd_distribution
+                .add(lbd);
         }
     }
+
+    /// Computes the images of the learned clause under the declared symmetries (up to
+    /// [`SatisfactionSolverOptions::max_symmetric_images`]), which are added to the solver at the
+    /// next restart.
+    fn queue_symmetric_images(&mut self) {
+        let images = self
+            .symmetries
+            .iter()
+            .filter_map(|symmetry| {
+                symmetry.image_of_clause(
+                    &self.analysis_result.learned_literals,
+                    &self.variable_literal_mappings,
+                    &self.assignments_propositional,
+                    &self.assignments_integer,
+                )
+            })
+            .take(self.internal_parameters.max_symmetric_images);
+
+        self.pending_symmetric_images.extend(images);
+    }
+
     /// Performs a restart during the search process; it is only called when it has been determined
     /// to be necessary by the [`ConstraintSatisfactionSolver::restart_strategy`]. A 'restart'
     /// differs from backtracking to level zero in that a restart backtracks to decision level
     /// zero and then performs additional operations, e.g., clean up learned clauses, adjust
     /// restart frequency, etc.
     ///
-    /// This method will also increase the decision level after backtracking.
+    /// If [`RestartOptions::reuse_trail`] is set, then the solver only backtracks to the level of
+    /// the last decision which the brancher would make again (see
+    /// [`Brancher::num_reusable_decisions`]); the assumptions are then kept as well.
     ///
-    /// Returns true if a restart took place and false otherwise.
-    fn restart_during_search(&mut self, brancher: &mut impl Brancher) {
+    /// This method will also increase the decision level after backtracking, unless constraints
+    /// were posted upon restarting (see [`ConstraintSatisfactionSolver::post_pending_constraints`])
+    /// or symmetric images of learned clauses were added (see
+    /// [`ConstraintSatisfactionSolver::add_symmetry`]) or clauses were imported from other workers
+    /// (see [`ConstraintSatisfactionSolver::set_nogood_exchange`]).
+    ///
+    /// Returns true if constraints were posted during the restart and false otherwise; in the
+    /// former case, the solver remains at the root level so that the new constraints can be
+    /// propagated before the next decision is made.
+    fn restart_during_search(&mut self, brancher: &mut impl Brancher) -> bool {
         pumpkin_assert_simple!(
             self.are_all_assumptions_assigned(),
             "Sanity check: restarts should not trigger whilst assigning assumptions"
@@ -1246,22 +2520,188 @@ impl ConstraintSatisfactionSolver {
 
         // no point backtracking past the assumption level
         if self.get_decision_level() <= self.assumptions.len() {
-            return;
+            return false;
         }
 
-        if brancher.is_restart_pointless() {
+        self.import_shared_clauses();
+
+        if brancher.is_restart_pointless()
+            && self.pending_constraints.is_empty()
+            && !self.has_pending_clauses()
+        {
             // If the brancher is static then there is no point in restarting as it would make the
             // exact same decision
-            return;
+            return false;
         }
 
         self.counters.engine_statistics.num_restarts += 1;
+        if let Some(event_log) = self.event_log.as_mut() {
+            event_log.push(LoggedEvent::Restart);
+        }
 
-        self.backtrack(0, brancher);
+        let restart_level = self.compute_restart_level(brancher);
+        self.counters.engine_statistics.num_reused_decisions +=
+            restart_level.saturating_sub(self.assumptions.len()) as u64;
+        self.backtrack(restart_level, brancher);
 
         self.restart_strategy.notify_restart();
+        self.learned_clause_manager
+            .on_restart(&mut self.clause_allocator);
+        brancher.on_restart();
+
+        if !self.pending_constraints.is_empty() || self.has_pending_clauses() {
+            self.add_pending_clauses();
+            if self.state.no_conflict() && !self.state.is_infeasible() {
+                self.post_pending_constraints();
+            }
+            return true;
+        }
 
         self.declare_new_decision_level();
+
+        false
+    }
+
+    /// Returns the decision level to which the solver backtracks upon a restart; this is the root
+    /// level unless the trail is reused (see [`RestartOptions::reuse_trail`]). Constraints which
+    /// are posted upon restarting need to be added at the root, so the trail is not reused in that
+    /// case.
+    fn compute_restart_level(&mut self, brancher: &mut impl Brancher) -> usize {
+        if !self.internal_parameters.restart_options.reuse_trail
+            || !self.pending_constraints.is_empty()
+            || self.has_pending_clauses()
+            || !self.decision_middlewares.is_empty()
+        {
+            return 0;
+        }
+
+        let num_reusable_decisions = brancher.num_reusable_decisions(
+            &mut SelectionContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                &mut self.internal_parameters.random_generator,
+            ),
+            &self.decisions,
+        );
+        pumpkin_assert_simple!(num_reusable_decisions <= self.decisions.len());
+
+        self.assumptions.len() + num_reusable_decisions
+    }
+
+    /// Returns whether there are symmetric images of learned clauses or imported clauses which
+    /// should be added at the next restart.
+    fn has_pending_clauses(&self) -> bool {
+        !self.pending_symmetric_images.is_empty() || !self.pending_imported_clauses.is_empty()
+    }
+
+    /// Adds the symmetric images of learned clauses and the clauses imported from other workers
+    /// which were queued since the last restart as permanent clauses; this should only be called at
+    /// the root level.
+    ///
+    /// If one of the clauses is falsified at the root, then the solver is declared infeasible.
+    fn add_pending_clauses(&mut self) {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+
+        self.counters.learned_clause_statistics.num_symmetric_images +=
+            self.pending_symmetric_images.len() as u64;
+        self.counters.learned_clause_statistics.num_imported_clauses +=
+            self.pending_imported_clauses.len() as u64;
+
+        let pending_symmetric_images = std::mem::take(&mut self.pending_symmetric_images);
+        let pending_imported_clauses = std::mem::take(&mut self.pending_imported_clauses);
+        for clause in pending_symmetric_images
+            .into_iter()
+            .chain(pending_imported_clauses)
+        {
+            let result = self.clausal_propagator.add_permanent_clause(
+                clause,
+                &mut self.assignments_propositional,
+                &mut self.clause_allocator,
+            );
+
+            if result.is_err() {
+                self.state.declare_infeasible();
+                return;
+            }
+        }
+    }
+
+    /// Shares the clause which was just learned with the other workers of the portfolio (if any),
+    /// provided that its quality is sufficient and all of its literals correspond to integer
+    /// predicates.
+    fn export_learned_clause(&self, lbd: u32) {
+        let Some(nogood_exchange) = self.nogood_exchange.as_ref() else {
+            return;
+        };
+        let learned_literals = &self.analysis_result.learned_literals;
+        if !nogood_exchange.should_export(lbd, learned_literals.len()) {
+            return;
+        }
+
+        let predicates = learned_literals
+            .iter()
+            .map(|&literal| {
+                self.variable_literal_mappings
+                    .get_predicates(literal)
+                    .next()
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(predicates) = predicates {
+            nogood_exchange.export(predicates);
+        }
+    }
+
+    /// Queues the clauses which were published by the other workers of the portfolio (if any) since
+    /// the previous import; they are added at the next restart.
+    fn import_shared_clauses(&mut self) {
+        let Some(nogood_exchange) = self.nogood_exchange.as_mut() else {
+            return;
+        };
+
+        let imported = nogood_exchange.import();
+        for predicates in imported {
+            let clause = predicates
+                .into_iter()
+                .map(|predicate| self.get_literal(predicate.into()))
+                .collect();
+            self.pending_imported_clauses.push(clause);
+        }
+    }
+
+    /// Adds the constraints which were requested by propagators during search (see
+    /// [`PropagationContextMut::post_at_next_restart`]) to the solver; this should only be called
+    /// at the root level. The new propagators are enqueued but propagation is left to the caller.
+    ///
+    /// If the initialisation of one of the new propagators detects a conflict then the solver is
+    /// put in a conflicting state and the remaining constraints are discarded, as the problem is
+    /// infeasible.
+    fn post_pending_constraints(&mut self) {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+
+        for propagator in self.pending_constraints.drain() {
+            let propagator_id = self.cp_propagators.alloc(propagator, None);
+            let propagator = &mut self.cp_propagators[propagator_id];
+
+            let mut initialisation_context = PropagatorInitialisationContext::new(
+                &mut self.watch_list_cp,
+                &mut self.watch_list_propositional,
+                propagator_id,
+                &self.assignments_integer,
+                &self.assignments_propositional,
+            );
+
+            if let Err(conjunction) = propagator.initialise_at_root(&mut initialisation_context) {
+                self.state
+                    .declare_conflict(StoredConflictInfo::Explanation {
+                        conjunction,
+                        propagator: propagator_id,
+                    });
+                return;
+            }
+
+            self.propagator_queue
+                .enqueue_propagator(propagator_id, propagator.priority());
+        }
     }
 
     pub(crate) fn backtrack(&mut self, backtrack_level: usize, brancher: &mut impl Brancher) {
@@ -1319,6 +2759,8 @@ impl ConstraintSatisfactionSolver {
         self.last_notified_cp_trail_index = self.assignments_integer.num_trail_entries();
 
         self.reason_store.synchronise(backtrack_level);
+        self.decisions
+            .truncate(backtrack_level.saturating_sub(self.assumptions.len()));
         //  note that variable_literal_mappings sync should be called after the sat/cp data
         // structures backtrack
         self.synchronise_assignments();
@@ -1432,12 +2874,14 @@ impl ConstraintSatisfactionSolver {
         let propagator = &mut self.cp_propagators[propagator_id];
 
         let propagation_status = {
-            let context = PropagationContextMut::new(
+            let mut context = PropagationContextMut::new(
                 &mut self.assignments_integer,
                 &mut self.reason_store,
                 &mut self.assignments_propositional,
                 propagator_id,
             );
+            context.with_watch_lists(&mut self.watch_list_cp, &mut self.watch_list_propositional);
+            context.with_pending_constraints(&mut self.pending_constraints);
 
             propagator.propagate(context)
         };
@@ -1471,6 +2915,13 @@ impl ConstraintSatisfactionSolver {
             Ok(()) => {
                 let _ = self.process_domain_events();
 
+                // The propagator returned early, it is resumed when it is popped again
+                let propagator = &self.cp_propagators[propagator_id];
+                if propagator.has_pending_work() {
+                    self.propagator_queue
+                        .enqueue_propagator(propagator_id, propagator.priority());
+                }
+
                 PropagationStatusOneStepCP::PropagationHappened
             }
         };
@@ -1544,67 +2995,78 @@ impl ConstraintSatisfactionSolver {
                 })
                 .collect::<Vec<_>>();
 
-            // The proof inference for the propagation `R -> l` is `R /\ ~l -> false`.
-            let inference_premises = premises.iter().copied().chain(std::iter::once(!propagated));
-            let _ = self
-                .internal_parameters
-                .proof_log
-                .log_inference(tag, inference_premises, None);
-
-            // Since inference steps are only related to the nogood they directly precede,
-            // facts derived at the root are also logged as nogoods so they can be used in the
-            // derivation of other nogoods.
-            //
-            // In case we are logging hints, we must therefore identify what proof steps contribute
-            // to the derivation of the current nogood, and therefore are in the premise of the
-            // previously logged inference. These proof steps are necessarily unit nogoods, and
-            // therefore we recursively look up which unit nogoods are involved in the premise of
-            // the inference.
-
-            let mut to_explain = VecDeque::from(premises);
-
-            while let Some(premise) = to_explain.pop_front() {
-                pumpkin_assert_simple!(self
-                    .assignments_propositional
-                    .is_literal_assigned_true(premise));
+            self.log_root_inference_to_proof(tag, premises, propagated);
+        }
+    }
 
-                if premise == self.true_literal {
-                    continue;
-                }
+    /// Logs the inference `premises -> propagated`, where the premises are true at the root, to
+    /// the proof, followed by the unit nogood which adds `propagated` to the root-level knowledge.
+    fn log_root_inference_to_proof(
+        &mut self,
+        tag: Option<NonZero<u32>>,
+        premises: Vec<Literal>,
+        propagated: Literal,
+    ) {
+        // The proof inference for the propagation `R -> l` is `R /\ ~l -> false`.
+        let inference_premises = premises.iter().copied().chain(std::iter::once(!propagated));
+        let _ = self
+            .internal_parameters
+            .proof_log
+            .log_inference(tag, inference_premises, None);
+
+        // Since inference steps are only related to the nogood they directly precede,
+        // facts derived at the root are also logged as nogoods so they can be used in the
+        // derivation of other nogoods.
+        //
+        // In case we are logging hints, we must therefore identify what proof steps contribute
+        // to the derivation of the current nogood, and therefore are in the premise of the
+        // previously logged inference. These proof steps are necessarily unit nogoods, and
+        // therefore we recursively look up which unit nogoods are involved in the premise of
+        // the inference.
+
+        let mut to_explain = VecDeque::from(premises);
+
+        while let Some(premise) = to_explain.pop_front() {
+            pumpkin_assert_simple!(self
+                .assignments_propositional
+                .is_literal_assigned_true(premise));
 
-                if let Some(step_id) = self.unit_nogood_step_ids.get(&premise) {
-                    self.internal_parameters.proof_log.add_propagation(*step_id);
-                } else {
-                    let reason = self
-                        .assignments_propositional
-                        .get_literal_reason_constraint(premise);
+            if premise == self.true_literal {
+                continue;
+            }
 
-                    // If the reason were a CP propagation, then `self.unit_nogood_step_ids` would
-                    // have contained `premise`.
-                    assert!(
-                        reason.is_clause(),
-                        "a propagation would have been logged as a nogood"
-                    );
+            if let Some(step_id) = self.unit_nogood_step_ids.get(&premise) {
+                self.internal_parameters.proof_log.add_propagation(*step_id);
+            } else {
+                let reason = self
+                    .assignments_propositional
+                    .get_literal_reason_constraint(premise);
 
-                    let clause_ref = reason.as_clause_reference();
-                    let premises = self.clause_allocator[clause_ref]
-                        .get_literal_slice()
-                        .iter()
-                        .skip(1)
-                        .map(|&lit| !lit);
-                    to_explain.extend(premises);
-                }
+                // If the reason were a CP propagation, then `self.unit_nogood_step_ids` would
+                // have contained `premise`.
+                assert!(
+                    reason.is_clause(),
+                    "a propagation would have been logged as a nogood"
+                );
+
+                let clause_ref = reason.as_clause_reference();
+                let premises = self.clause_allocator[clause_ref]
+                    .get_literal_slice()
+                    .iter()
+                    .skip(1)
+                    .map(|&lit| !lit);
+                to_explain.extend(premises);
             }
+        }
 
-            // Log the nogood which adds the root-level knowledge to the proof.
-            let nogood_step_id = self
-                .internal_parameters
-                .proof_log
-                .log_learned_clause([propagated]);
+        // Log the nogood which adds the root-level knowledge to the proof.
+        let nogood_step_id = self
+            .internal_parameters
+            .proof_log
+            .log_learned_clause([propagated]);
 
-            if let Ok(nogood_step_id) = nogood_step_id {
-                let _ = self.unit_nogood_step_ids.insert(propagated, nogood_step_id);
-            }
+        if let Ok(nogood_step_id) = nogood_step_id {
+            let _ = self.unit_nogood_step_ids.insert(propagated, nogood_step_id);
         }
     }
 
@@ -1656,30 +3118,37 @@ impl ConstraintSatisfactionSolver {
     }
 
     /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
-    /// through propagation, this will return `false`. If not, this returns `true`.
+    /// through propagation, this will return [`ConstraintOperationError::InfeasiblePropagator`]
+    /// and the solver is marked as infeasible.
     ///
     /// The caller should ensure the solver is in the root state before calling this, either
     /// because no call to [`Self::solve()`] has been made, or because
     /// [`Self::restore_state_at_root()`] was called.
     ///
-    /// If the solver is already in a conflicting state, i.e. a previous call to this method
-    /// already returned `false`, calling this again will not alter the solver in any way, and
-    /// `false` will be returned again.
+    /// If the solver is already infeasible, i.e. a previous constraint was found to be infeasible
+    /// at the root, calling this again will not alter the solver in any way, and
+    /// [`ConstraintOperationError::InfeasibleState`] is returned.
+    ///
+    /// If [`SatisfactionSolverOptions::checked_arithmetic`] is enabled and the arithmetic of the
+    /// propagator may overflow (see [`Propagator::may_overflow`]), the propagator is not added and
+    /// [`ConstraintOperationError::IntegerOverflow`] is returned.
     pub fn add_propagator(
         &mut self,
         propagator_to_add: impl Propagator + 'static,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
         if self.state.is_inconsistent() {
-            return Err(ConstraintOperationError::InfeasiblePropagator);
+            return Err(ConstraintOperationError::InfeasibleState);
         }
 
-        pumpkin_assert_simple!(
-            propagator_to_add.priority() <= 3,
-            "The propagator priority exceeds 3.
-             Currently we only support values up to 3,
-             but this can easily be changed if there is a good reason."
-        );
+        if self.internal_parameters.checked_arithmetic
+            && propagator_to_add.may_overflow(PropagationContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+            ))
+        {
+            return Err(ConstraintOperationError::IntegerOverflow);
+        }
 
         let new_propagator_id = self.cp_propagators.alloc(Box::new(propagator_to_add), tag);
 
@@ -1716,6 +3185,7 @@ impl ConstraintSatisfactionSolver {
             } else {
                 self.complete_proof();
                 let _ = self.conclude_proof_unsat();
+                self.state.declare_infeasible();
                 Err(ConstraintOperationError::InfeasiblePropagator)
             }
         }
@@ -1726,19 +3196,109 @@ impl ConstraintSatisfactionSolver {
     /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
     /// returned. Subsequent calls to this method will always return an error, and no
     /// modification of the solver will take place.
+    ///
+    /// Within a scope (see [`ConstraintSatisfactionSolver::push_scope`]), the clause is
+    /// conditioned on the activation literal of the innermost scope; if the clause falsifies it,
+    /// then [`ConstraintOperationError::InfeasibleScope`] is returned.
     pub fn add_clause(
         &mut self,
         literals: impl IntoIterator<Item = Literal>,
     ) -> Result<(), ConstraintOperationError> {
-        pumpkin_assert_moderate!(!self.state.is_infeasible_under_assumptions());
-        pumpkin_assert_moderate!(self.is_propagation_complete());
-
-        if self.state.is_infeasible() {
-            return Err(ConstraintOperationError::InfeasibleState);
+        let mut literals: Vec<Literal> = literals.into_iter().collect();
+        // A clause which is added within a scope only holds while the scope is open
+        let Some(scope_literal) = self.get_scope_literal() else {
+            return self.add_permanent_clause(literals);
+        };
+        if !literals.contains(&!scope_literal) {
+            literals.push(!scope_literal);
         }
 
-        let literals: Vec<Literal> = literals.into_iter().collect();
-
+        self.add_permanent_clause(literals)?;
+        if self
+            .assignments_propositional
+            .is_literal_assigned_false(scope_literal)
+        {
+            return Err(ConstraintOperationError::InfeasibleScope);
+        }
+
+        Ok(())
+    }
+
+    /// Sets `literal` at the root, where it is implied by the `premises` (which are true at the
+    /// root) through reasoning which is done outside of the propagators (e.g. a bound on the
+    /// objective which follows from several constraints).
+    ///
+    /// In contrast to [`ConstraintSatisfactionSolver::add_clause`], the literal is not added as a
+    /// constraint, but it is learned in the same way as a root-level propagation: the implication
+    /// is logged to the proof as an inference (without a constraint tag) which justifies the unit
+    /// nogood.
+    pub(crate) fn learn_root_literal(
+        &mut self,
+        premises: Vec<Literal>,
+        literal: Literal,
+    ) -> Result<(), ConstraintOperationError> {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+        pumpkin_assert_moderate!(premises.iter().all(|&premise| self
+            .assignments_propositional
+            .is_literal_assigned_true(premise)));
+
+        if self.state.is_inconsistent() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+        if self
+            .assignments_propositional
+            .is_literal_assigned_true(literal)
+        {
+            return Ok(());
+        }
+
+        if self.internal_parameters.proof_log.is_logging_inferences() {
+            self.log_root_inference_to_proof(None, premises, literal);
+        } else if let Ok(step_id) = self
+            .internal_parameters
+            .proof_log
+            .log_learned_clause([literal])
+        {
+            let _ = self.unit_nogood_step_ids.insert(literal, step_id);
+        }
+
+        if self
+            .assignments_propositional
+            .is_literal_assigned_false(literal)
+        {
+            let _ = self.internal_parameters.proof_log.log_learned_clause([]);
+            let _ = self.conclude_proof_unsat();
+            self.state.declare_infeasible();
+            return Err(ConstraintOperationError::InfeasibleClause);
+        }
+
+        self.assignments_propositional
+            .enqueue_decision_literal(literal);
+        self.propagate_enqueued();
+
+        if self.state.conflicting() {
+            self.complete_proof();
+            let _ = self.conclude_proof_unsat();
+            self.state.declare_infeasible();
+            return Err(ConstraintOperationError::InfeasibleClause);
+        }
+
+        Ok(())
+    }
+
+    fn add_permanent_clause(
+        &mut self,
+        literals: Vec<Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        pumpkin_assert_moderate!(!self.state.is_infeasible_under_assumptions());
+        pumpkin_assert_moderate!(self.is_propagation_complete());
+
+        if self.state.is_inconsistent() {
+            return Err(ConstraintOperationError::InfeasibleState);
+        }
+
+        self.num_clauses += 1;
+
         let result = self.clausal_propagator.add_permanent_clause(
             literals,
             &mut self.assignments_propositional,
@@ -1752,7 +3312,9 @@ impl ConstraintSatisfactionSolver {
 
         self.propagate_enqueued();
 
-        if self.state.is_infeasible() {
+        if self.state.conflicting() {
+            self.complete_proof();
+            let _ = self.conclude_proof_unsat();
             self.state.declare_infeasible();
             return Err(ConstraintOperationError::InfeasibleClause);
         }
@@ -1776,6 +3338,11 @@ impl ConstraintSatisfactionSolver {
         );
         self.assignments_propositional.get_decision_level()
     }
+
+    /// Returns an adapter which displays `value` using the names of the variables.
+    pub(crate) fn display_with<'a, T: ?Sized>(&'a self, value: &'a T) -> DisplayWith<'a, T> {
+        DisplayWith::new(value, &self.variable_names, &self.variable_literal_mappings)
+    }
 }
 
 #[derive(Default, Debug)]
@@ -1904,6 +3471,17 @@ impl CSPSolverState {
     }
 }
 
+/// A scope which was opened using [`ConstraintSatisfactionSolver::push_scope`].
+#[derive(Clone, Copy, Debug)]
+struct Scope {
+    /// The literal which is assumed while the scope is open; the clauses and propagators of the
+    /// scope are conditioned on it.
+    activation_literal: Literal,
+    /// The number of propagators when the scope was opened; the propagators which are added
+    /// afterwards belong to the scope.
+    num_propagators: usize,
+}
+
 struct DummyBrancher;
 
 impl Brancher for DummyBrancher {
@@ -1914,13 +3492,48 @@ impl Brancher for DummyBrancher {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use super::ConstraintSatisfactionSolver;
     use super::CoreExtractionResult;
+    use super::SatisfactionSolverOptions;
+    use crate::basic_types::moving_averages::MovingAverage;
     use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::basic_types::ConstraintOperationError;
+    use crate::basic_types::ProblemSolution;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::basic_types::Solution;
+    use crate::basic_types::TemporaryFile;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::value_selection::InDomainMin;
+    use crate::branching::variable_selection::InputOrder;
+    use crate::branching::Brancher;
+    use crate::branching::LookaheadRanking;
+    use crate::branching::SelectionContext;
+    use crate::conjunction;
+    use crate::engine::clause_allocators::ClauseInterface;
+    use crate::engine::predicates::integer_predicate::IntegerPredicate;
+    use crate::engine::proof::Format;
+    use crate::engine::proof::ProofLog;
+    use crate::engine::propagation::PropagationContext;
+    use crate::engine::propagation::PropagationContextMut;
+    use crate::engine::propagation::Propagator;
+    use crate::engine::propagation::PropagatorInitialisationContext;
+    use crate::engine::propagation::ReadDomains;
     use crate::engine::reason::ReasonRef;
+    use crate::engine::symmetry::VariableSymmetry;
     use crate::engine::termination::indefinite::Indefinite;
+    use crate::engine::variables::DomainId;
     use crate::engine::variables::Literal;
+    use crate::engine::variables::TransformableVariable;
+    use crate::engine::ImplicationGraphExport;
+    use crate::engine::LearningOptions;
+    use crate::engine::RestartOptions;
     use crate::predicate;
+    use crate::predicates::Predicate;
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
     use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
 
     /// A test propagator which propagates the stored propagations and then reports one of the
@@ -2275,4 +3888,869 @@ mod tests {
         let result = solver.add_propagator(propagator, None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn infeasibility_detected_when_posting_is_remembered() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(2, 5, None);
+        let y = solver.create_new_integer_variable(2, 5, None);
+
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(1)]), 3),
+            None,
+        );
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasiblePropagator)
+        ));
+
+        // The model can still be extended, but every constraint is rejected
+        let z = solver.create_new_integer_variable(0, 5, None);
+        let literal = solver.get_literal(predicate![z >= 1]);
+        assert!(matches!(
+            solver.add_clause([literal]),
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+        assert!(matches!(
+            solver.add_propagator(LinearNotEqualPropagator::new(Box::new([x, z]), 0), None),
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+    }
+
+    #[test]
+    fn propagators_which_may_overflow_are_rejected_with_checked_arithmetic() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                checked_arithmetic: true,
+                ..Default::default()
+            },
+        );
+        let x = solver.create_new_integer_variable(-10, 10, None);
+        let y = solver.create_new_integer_variable(-10, 10, None);
+
+        // The bounds of the view `i32::MAX * x` do not fit in an i32
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(i32::MAX), y.scaled(1)]), 0),
+            None,
+        );
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::IntegerOverflow)
+        ));
+
+        // The bound propagated to `x` would be `i32::MAX + 10`
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(1)]), i32::MAX),
+            None,
+        );
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::IntegerOverflow)
+        ));
+
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(1)]), 100),
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    /// A test propagator which, the first time it is called, requests a constraint
+    /// `sum(terms) >= bound` to be posted upon the next restart.
+    struct LazyLowerBoundPropagator {
+        terms: Vec<DomainId>,
+        bound: i32,
+        has_posted: bool,
+    }
+
+    impl Propagator for LazyLowerBoundPropagator {
+        fn name(&self) -> &str {
+            "LazyLowerBound"
+        }
+
+        fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            if !self.has_posted {
+                context.post_at_next_restart(LinearLessOrEqualPropagator::new(
+                    self.terms.iter().map(|term| term.scaled(-1)).collect(),
+                    -self.bound,
+                ));
+                self.has_posted = true;
+            }
+
+            Ok(())
+        }
+
+        fn debug_propagate_from_scratch(&self, _: PropagationContextMut) -> PropagationStatusCP {
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            _: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            Ok(())
+        }
+    }
+
+    /// A test propagator for the constraint `x_i >= bound` for all `x_i` in `variables` which
+    /// tightens at most one lower-bound per call to `propagate`.
+    struct ChunkedLowerBoundPropagator {
+        variables: Vec<DomainId>,
+        bound: i32,
+        num_calls: Rc<Cell<usize>>,
+        has_pending_work: bool,
+    }
+
+    impl Propagator for ChunkedLowerBoundPropagator {
+        fn name(&self) -> &str {
+            "ChunkedLowerBound"
+        }
+
+        fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+            self.num_calls.set(self.num_calls.get() + 1);
+
+            let unpropagated = self
+                .variables
+                .iter()
+                .filter(|&variable| context.lower_bound(variable) < self.bound)
+                .copied()
+                .collect::<Vec<_>>();
+
+            if let Some(variable) = unpropagated.first() {
+                context.set_lower_bound(variable, self.bound, conjunction!())?;
+            }
+            self.has_pending_work = unpropagated.len() > 1;
+
+            Ok(())
+        }
+
+        fn has_pending_work(&self) -> bool {
+            self.has_pending_work
+        }
+
+        fn synchronise(&mut self, _: PropagationContext) {
+            self.has_pending_work = false;
+        }
+
+        fn debug_propagate_from_scratch(
+            &self,
+            mut context: PropagationContextMut,
+        ) -> PropagationStatusCP {
+            for variable in self.variables.iter() {
+                context.set_lower_bound(variable, self.bound, conjunction!())?;
+            }
+
+            Ok(())
+        }
+
+        fn initialise_at_root(
+            &mut self,
+            _: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn model_statistics_summarise_the_model() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 9, None);
+        let y = solver.create_new_integer_variable(0, 9, None);
+        let z = solver.create_new_integer_variable(0, 4, None);
+        let _ = solver.create_new_integer_variable(1, 5, None);
+
+        let result = solver.add_propagator(
+            LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+            None,
+        );
+        assert!(result.is_ok());
+        let result = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), z.scaled(1)]), 20),
+            None,
+        );
+        assert!(result.is_ok());
+        let a = solver.create_new_propositional_variable(None);
+        let b = solver.create_new_propositional_variable(None);
+        let result = solver.add_clause([Literal::new(a, true), Literal::new(b, false)]);
+        assert!(result.is_ok());
+
+        let statistics = solver.get_model_statistics();
+
+        assert_eq!(statistics.num_integer_variables, 4);
+        assert_eq!(
+            statistics
+                .integer_variables_by_domain_size
+                .iter()
+                .map(|(&size, &count)| (size, count))
+                .collect::<Vec<_>>(),
+            vec![(5, 2), (10, 2)]
+        );
+        assert_eq!(statistics.num_constraints(), 2);
+        assert_eq!(statistics.constraints_by_type.len(), 2);
+        assert_eq!(statistics.num_clauses, 1);
+        assert_eq!(statistics.average_constraints_per_integer_variable, 1.0);
+        assert_eq!(statistics.fraction_unconstrained_integer_variables, 0.25);
+    }
+
+    #[test]
+    fn dump_contains_the_clauses_but_not_the_domain_encoding() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 5, Some("x".to_owned()));
+        let y = solver.create_new_integer_variable(0, 5, None);
+        let b = solver.create_new_propositional_variable(Some("b".to_owned()));
+
+        let x_at_least_3 = solver.get_literal(predicate![x >= 3]);
+        let y_at_most_1 = solver.get_literal(predicate![y <= 1]);
+        let result = solver.add_clause([x_at_least_3, y_at_most_1, Literal::new(b, false)]);
+        assert!(result.is_ok());
+
+        assert_eq!(
+            solver.dump_pretty(),
+            "var 0..5: x;\n\
+             var 0..5: x1;\n\
+             constraint [x >= 3] \\/ [x1 <= 1] \\/ ~b;\n"
+        );
+    }
+
+    #[test]
+    fn propagator_with_pending_work_is_resumed() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let variables = (0..3)
+            .map(|_| solver.create_new_integer_variable(0, 10, None))
+            .collect::<Vec<_>>();
+        let num_calls = Rc::new(Cell::new(0));
+
+        let result = solver.add_propagator(
+            ChunkedLowerBoundPropagator {
+                variables: variables.clone(),
+                bound: 5,
+                num_calls: Rc::clone(&num_calls),
+                has_pending_work: false,
+            },
+            None,
+        );
+        assert!(result.is_ok());
+
+        assert_eq!(num_calls.get(), 3);
+        for variable in variables {
+            assert_eq!(solver.get_lower_bound(&variable), 5);
+        }
+    }
+
+    #[test]
+    fn constraints_posted_during_propagation_are_added_at_the_root() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LazyLowerBoundPropagator {
+                terms: vec![x],
+                bound: 5,
+                has_posted: false,
+            },
+            None,
+        );
+        assert!(result.is_ok());
+        assert_eq!(solver.get_lower_bound(&x), 0);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert!(solver.get_lower_bound(&x) >= 5);
+    }
+
+    #[test]
+    fn infeasible_constraints_posted_during_propagation_lead_to_infeasibility() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LazyLowerBoundPropagator {
+                terms: vec![x, y],
+                bound: 21,
+                has_posted: false,
+            },
+            None,
+        );
+        assert!(result.is_ok());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+    }
+
+    /// Creates a solver with limited clause learning, containing `num_variables` variables with
+    /// the domain `[0, num_values - 1]` which are pairwise not equal.
+    fn pairwise_not_equal_solver(
+        max_learning_decision_level: usize,
+        num_variables: usize,
+        num_values: i32,
+    ) -> (ConstraintSatisfactionSolver, Vec<DomainId>) {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions {
+                max_learning_decision_level: Some(max_learning_decision_level),
+                ..Default::default()
+            },
+            SatisfactionSolverOptions::default(),
+        );
+        let variables = (0..num_variables)
+            .map(|_| solver.create_new_integer_variable(0, num_values - 1, None))
+            .collect::<Vec<_>>();
+
+        for (i, &x) in variables.iter().enumerate() {
+            for &y in &variables[i + 1..] {
+                let result = solver.add_propagator(
+                    LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+        }
+
+        (solver, variables)
+    }
+
+    #[test]
+    fn solutions_are_found_without_clause_learning() {
+        let (mut solver, variables) = pairwise_not_equal_solver(0, 3, 3);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        let mut values = variables
+            .iter()
+            .map(|variable| solver.get_assigned_integer_value(variable).unwrap())
+            .collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn infeasibility_is_proven_with_limited_clause_learning() {
+        for max_learning_decision_level in [0, 2] {
+            let (mut solver, _) = pairwise_not_equal_solver(max_learning_decision_level, 4, 3);
+
+            let mut brancher = solver.default_brancher_over_all_propositional_variables();
+            let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+            assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        }
+    }
+
+    #[test]
+    fn clause_learning_is_not_limited_when_logging_a_proof() {
+        let proof_file = TemporaryFile::new("limited-learning", "drcp");
+        let solver = ConstraintSatisfactionSolver::new(
+            LearningOptions {
+                max_learning_decision_level: Some(0),
+                ..Default::default()
+            },
+            SatisfactionSolverOptions {
+                proof_log: ProofLog::cp(proof_file.path(), Format::Text, false, false)
+                    .expect("the proof can be created"),
+                ..Default::default()
+            },
+        );
+
+        assert!(solver.learned_clause_manager.learns_at_decision_level(1));
+    }
+
+    #[test]
+    fn variable_bounds_can_be_relaxed_after_infeasibility() {
+        let (mut solver, variables) = pairwise_not_equal_solver(usize::MAX, 3, 3);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        // Only two values remain for three variables
+        solver.set_variable_bounds(variables[0], 1, 2);
+        solver.set_variable_bounds(variables[1], 1, 2);
+        solver.set_variable_bounds(variables[2], 0, 2);
+        solver.set_variable_bounds(variables[2], 1, 2);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        solver.restore_state_at_root(&mut brancher);
+
+        solver.reset_variable_bounds(variables[2]);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.get_assigned_integer_value(&variables[2]), Some(0));
+        solver.restore_state_at_root(&mut brancher);
+
+        // The bounds are not imposed at the root
+        assert_eq!(solver.get_lower_bound(&variables[0]), 0);
+    }
+
+    #[test]
+    fn variable_bounds_are_not_part_of_the_core() {
+        let (mut solver, variables) = pairwise_not_equal_solver(usize::MAX, 3, 3);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_variable_bounds(variables[0], 1, 2);
+        solver.set_variable_bounds(variables[1], 1, 2);
+
+        let assumption = solver.get_literal(predicate!(variables[2] >= 1));
+        let flag = solver.solve_under_assumptions(&[assumption], &mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        assert!(is_result_the_same(
+            &solver.extract_clausal_core(&mut brancher),
+            &CoreExtractionResult::Core(vec![assumption])
+        ));
+
+        // The assumption is the negation of the lower bound of the first variable
+        let assumption = solver.get_literal(predicate!(variables[0] <= 0));
+        let flag = solver.solve_under_assumptions(&[assumption], &mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        assert!(is_result_the_same(
+            &solver.extract_clausal_core(&mut brancher),
+            &CoreExtractionResult::Core(vec![assumption])
+        ));
+    }
+
+    #[test]
+    fn clauses_of_closed_scopes_are_deleted() {
+        let (mut solver, variables) = pairwise_not_equal_solver(usize::MAX, 4, 4);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        // Only three values remain for four variables
+        solver.push_scope();
+        let activation_literal = solver.get_scope_literal().unwrap();
+        for variable in &variables {
+            let literal = solver.get_literal(predicate!(variable <= 2));
+            assert!(solver.add_clause([literal]).is_ok());
+        }
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        assert!(solver.counters.engine_statistics.num_conflicts > 0);
+        solver.restore_state_at_root(&mut brancher);
+
+        // Both the clauses of the scope and the clauses learned from them are deleted
+        assert!(solver.pop_scope());
+        assert!(solver
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .all(
+                |&clause_reference| !solver.clause_allocator[clause_reference]
+                    .get_literal_slice()
+                    .contains(&!activation_literal)
+            ));
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+    }
+
+    #[test]
+    fn clauses_of_closed_scopes_are_disabled() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let a = Literal::new(solver.create_new_propositional_variable(None), true);
+        let b = Literal::new(solver.create_new_propositional_variable(None), true);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        assert!(solver.add_clause([a, b]).is_ok());
+
+        solver.push_scope();
+        assert!(solver.add_clause([!a]).is_ok());
+        solver.push_scope();
+        assert!(solver.add_clause([!b]).is_ok());
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        solver.restore_state_at_root(&mut brancher);
+
+        // Only the clause of the outer scope remains
+        assert!(solver.pop_scope());
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert!(solver
+            .assignments_propositional
+            .is_literal_assigned_false(a));
+        solver.restore_state_at_root(&mut brancher);
+
+        assert!(solver.pop_scope());
+        assert!(!solver.pop_scope());
+        assert!(solver.add_clause([!b]).is_ok());
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert!(solver.assignments_propositional.is_literal_assigned_true(a));
+    }
+
+    #[test]
+    fn implication_graph_of_conflict_is_exported() {
+        let graph_file = TemporaryFile::new("implication-graph", "dot");
+        let (mut solver, _) = pairwise_not_equal_solver(usize::MAX, 3, 2);
+        solver.internal_parameters.implication_graph_export = Some(ImplicationGraphExport {
+            conflict: 1,
+            path: graph_file.path().to_path_buf(),
+        });
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+
+        let graph = graph_file.read();
+        assert!(graph.starts_with("digraph implication_graph {"));
+        assert!(
+            graph.contains("shape=box"),
+            "the graph contains the decision"
+        );
+        assert!(graph.contains("-> conflict;"));
+    }
+
+    #[test]
+    fn comparing_learning_schemes_does_not_affect_the_search() {
+        let solve = |compare_learning_schemes: bool| {
+            let (mut solver, _) = pairwise_not_equal_solver(usize::MAX, 5, 4);
+            solver.internal_parameters.compare_learning_schemes = compare_learning_schemes;
+            let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+            let flag = solver.solve(&mut Indefinite, &mut brancher);
+            assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+            solver.counters
+        };
+
+        let without_comparison = solve(false);
+        let with_comparison = solve(true);
+
+        assert_eq!(
+            without_comparison.engine_statistics.num_conflicts,
+            with_comparison.engine_statistics.num_conflicts
+        );
+        assert_eq!(
+            without_comparison.engine_statistics.num_decisions,
+            with_comparison.engine_statistics.num_decisions
+        );
+
+        let statistics = with_comparison.learning_scheme_statistics;
+        assert!(statistics.first_uip.average_length.value() > 0.0);
+        assert!(statistics.last_uip.average_length.value() > 0.0);
+        assert!(statistics.all_decision.average_length.value() > 0.0);
+        assert_eq!(
+            without_comparison
+                .learning_scheme_statistics
+                .first_uip
+                .average_length
+                .value(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn trail_is_reused_upon_restarts() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                restart_options: RestartOptions {
+                    base_interval: 1,
+                    min_num_conflicts_before_first_restart: 0,
+                    reuse_trail: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let variables = (0..6)
+            .map(|_| solver.create_new_integer_variable(0, 4, None))
+            .collect::<Vec<_>>();
+        for (i, &x) in variables.iter().enumerate() {
+            for &y in &variables[i + 1..] {
+                let result = solver.add_propagator(
+                    LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        let statistics = solver.get_search_statistics();
+        assert!(statistics.num_restarts > 0);
+        assert!(statistics.num_reused_decisions > 0);
+    }
+
+    /// A brancher which stores the lookahead ranking which it receives with its first decision,
+    /// and which then leaves the remaining decisions to the default brancher.
+    #[derive(Default)]
+    struct LookaheadRecordingBrancher {
+        ranking: Option<Option<LookaheadRanking>>,
+    }
+
+    impl Brancher for LookaheadRecordingBrancher {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            if self.ranking.is_none() {
+                self.ranking = Some(context.lookahead_ranking().cloned());
+            }
+
+            context
+                .get_domains()
+                .find(|&domain_id| !context.is_integer_fixed(domain_id))
+                .map(|domain_id| predicate![domain_id <= context.lower_bound(domain_id)])
+        }
+    }
+
+    #[test]
+    fn lookahead_ranks_variables_by_propagation_impact() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                lookahead_budget: 2,
+                ..Default::default()
+            },
+        );
+        let x = solver.create_new_integer_variable(0, 9, None);
+        let y = solver.create_new_integer_variable(0, 9, None);
+        let z = solver.create_new_integer_variable(0, 9, None);
+
+        // `y` is equal to `z`, so probing `y` also changes the domain of `z`; with a budget of 2,
+        // only `x` and `y` are probed
+        for (a, b) in [(y, z), (z, y)] {
+            let result = solver.add_propagator(
+                LinearLessOrEqualPropagator::new(Box::new([a.scaled(1), b.scaled(-1)]), 0),
+                None,
+            );
+            assert!(result.is_ok());
+        }
+
+        let mut brancher = LookaheadRecordingBrancher::default();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+
+        let ranking = brancher
+            .ranking
+            .flatten()
+            .expect("the lookahead was performed");
+        assert_eq!(
+            ranking.iter().collect::<Vec<_>>(),
+            vec![(predicate![y <= 4], 9.0), (predicate![x <= 4], 4.0),]
+        );
+        assert_eq!(solver.counters.engine_statistics.num_lookahead_probes, 4);
+    }
+
+    #[test]
+    fn polishing_frees_the_most_active_variables() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 9, None);
+        let y = solver.create_new_integer_variable(0, 9, None);
+        let z = solver.create_new_integer_variable(0, 9, None);
+        let objective = solver.create_new_integer_variable(0, 9, None);
+        let _ = solver.create_new_integer_variable(3, 3, None);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        let solution: Solution = solver.get_solution_reference().into();
+        solver.restore_state_at_root(&mut brancher);
+
+        // The value of the objective in the solution is excluded by the strengthened bound
+        let bound = solver.get_literal(predicate![
+            objective >= solution.get_integer_value(objective) + 1
+        ]);
+        assert!(solver.add_clause([bound]).is_ok());
+        solver.variable_activities.bump_integer(y);
+        solver.variable_activities.bump_integer(y);
+        solver.variable_activities.bump_integer(z);
+
+        let assumptions = solver.get_polishing_assumptions(&solution, 1);
+        assert_eq!(
+            assumptions,
+            vec![
+                solver.get_literal(predicate![z == solution.get_integer_value(z)]),
+                solver.get_literal(predicate![x == solution.get_integer_value(x)]),
+            ]
+        );
+    }
+
+    /// Creates a solver with `num_pigeons` pigeons which have to be placed in different holes, and
+    /// declares that the pigeons are interchangeable.
+    fn symmetric_pigeon_hole(num_pigeons: usize, num_holes: i32) -> ConstraintSatisfactionSolver {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                restart_options: RestartOptions {
+                    base_interval: 1,
+                    min_num_conflicts_before_first_restart: 0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let pigeons = (0..num_pigeons)
+            .map(|_| solver.create_new_integer_variable(0, num_holes - 1, None))
+            .collect::<Vec<_>>();
+        for (i, &x) in pigeons.iter().enumerate() {
+            for &y in &pigeons[i + 1..] {
+                let result = solver.add_propagator(
+                    LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+        }
+
+        let mut rotated = pigeons.clone();
+        rotated.rotate_left(1);
+        solver.add_symmetry(
+            VariableSymmetry::new(vec![pigeons[0], pigeons[1]], vec![pigeons[1], pigeons[0]])
+                .unwrap(),
+        );
+        solver.add_symmetry(VariableSymmetry::new(pigeons, rotated).unwrap());
+
+        solver
+    }
+
+    #[test]
+    fn symmetric_images_of_learned_clauses_are_added() {
+        let mut solver = symmetric_pigeon_hole(6, 5);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        assert!(
+            solver
+                .counters
+                .learned_clause_statistics
+                .num_symmetric_images
+                > 0
+        );
+    }
+
+    #[test]
+    fn symmetric_images_do_not_remove_solutions() {
+        let mut solver = symmetric_pigeon_hole(5, 5);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+    }
+
+    /// A brancher which returns the stored decisions in order, regardless of whether they are
+    /// already assigned.
+    struct SequenceBrancher {
+        decisions: Vec<Predicate>,
+    }
+
+    impl Brancher for SequenceBrancher {
+        fn next_decision(&mut self, _: &mut SelectionContext) -> Option<Predicate> {
+            if self.decisions.is_empty() {
+                None
+            } else {
+                Some(self.decisions.remove(0))
+            }
+        }
+    }
+
+    #[test]
+    fn assigned_decisions_are_skipped() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 1, None);
+
+        let mut brancher = SequenceBrancher {
+            decisions: vec![predicate![x >= 0], predicate![x >= 5], predicate![x >= 1]],
+        };
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.get_lower_bound(&x), 1);
+
+        let statistics = solver.get_search_statistics();
+        assert_eq!(statistics.num_decisions, 1);
+        assert_eq!(statistics.num_skipped_decisions, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the brancher repeatedly proposed the assigned decision")]
+    fn repeatedly_proposing_an_assigned_decision_panics() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 1, None);
+
+        let mut brancher = SequenceBrancher {
+            decisions: vec![predicate![x >= 0]; 3],
+        };
+        let _ = solver.solve(&mut Indefinite, &mut brancher);
+    }
+
+    #[test]
+    fn decision_middleware_rewrites_and_vetoes_decisions() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        // Decisions over `y` are vetoed, and the lower bounds of `x` are rounded up to even values
+        solver.add_decision_middleware(move |decision: Predicate, _: &mut SelectionContext| {
+            match decision {
+                decision if decision.get_domain() == Some(y) => None,
+                Predicate::IntegerPredicate(IntegerPredicate::LowerBound {
+                    domain_id,
+                    lower_bound,
+                }) if domain_id == x => Some(predicate![x >= lower_bound + lower_bound % 2]),
+                decision => Some(decision),
+            }
+        });
+
+        let mut brancher = SequenceBrancher {
+            decisions: vec![predicate![y >= 4], predicate![x >= 3], predicate![x >= 6]],
+        };
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.get_lower_bound(&x), 6);
+        assert_eq!(solver.get_upper_bound(&y), 3);
+
+        let statistics = &solver.counters.engine_statistics;
+        assert_eq!(statistics.num_decisions, 3);
+        assert_eq!(statistics.num_rewritten_decisions, 1);
+        assert_eq!(statistics.num_vetoed_decisions, 1);
+    }
+
+    #[test]
+    fn vetoed_decisions_of_an_integer_brancher_are_not_proposed_again() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        solver.add_decision_middleware(move |decision: Predicate, _: &mut SelectionContext| {
+            (decision.get_domain() != Some(y)).then_some(decision)
+        });
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMin);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        // The decisions `[y <= v]` are vetoed, meaning that `y` ends up at its upper bound
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert_eq!(solver.get_assigned_integer_value(&x), Some(0));
+        assert_eq!(solver.get_assigned_integer_value(&y), Some(10));
+        assert_eq!(solver.counters.engine_statistics.num_vetoed_decisions, 10);
+    }
+
+    #[test]
+    fn vetoed_decisions_of_the_default_brancher_are_not_proposed_again() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let literals = solver.new_literals().take(5).collect::<Vec<_>>();
+        let _ = solver.add_clause(literals.clone());
+
+        let vetoed = literals[0];
+        solver.add_decision_middleware(move |decision: Predicate, _: &mut SelectionContext| {
+            (decision != Predicate::Literal(vetoed)).then_some(decision)
+        });
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        assert!(solver
+            .assignments_propositional
+            .is_literal_assigned_false(vetoed));
+        assert!(literals.iter().all(|&literal| solver
+            .assignments_propositional
+            .is_literal_assigned(literal)));
+    }
 }
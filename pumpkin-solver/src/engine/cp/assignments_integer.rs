@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use crate::basic_types::KeyedVec;
 use crate::basic_types::Trail;
 use crate::engine::cp::event_sink::EventSink;
@@ -10,6 +12,7 @@ use crate::engine::propagation::Propagator;
 use crate::engine::variables::DomainGeneratorIterator;
 use crate::engine::variables::DomainId;
 use crate::predicate;
+use crate::pumpkin_assert_extreme;
 use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
 #[cfg(doc)]
@@ -21,6 +24,9 @@ pub struct AssignmentsInteger {
     trail: Trail<ConstraintProgrammingTrailEntry>,
     /// indicates if value j is in the domain of the integer variable
     domains: KeyedVec<DomainId, IntegerDomainExplicit>,
+    /// The domains which created a checkpoint at each decision level; this allows restoring the
+    /// domains when backtracking without going over the popped trail entries.
+    checkpointed_domains: Trail<DomainId>,
 
     /// Keeps track of the [`IntDomainEvent`]s which occur while propagating/making decisions, this
     /// is used to implement [`Propagator::notify`].
@@ -39,7 +45,8 @@ pub struct EmptyDomain;
 impl AssignmentsInteger {
     /// Increments the current decision level
     pub fn increase_decision_level(&mut self) {
-        self.trail.increase_decision_level()
+        self.trail.increase_decision_level();
+        self.checkpointed_domains.increase_decision_level();
     }
 
     /// Returns the current decision level
@@ -117,6 +124,14 @@ impl AssignmentsInteger {
         id
     }
 
+    /// Reserves capacity for at least `additional` more domains; this avoids repeated
+    /// reallocations when many domains are created using [`AssignmentsInteger::grow`].
+    pub fn reserve(&mut self, additional: usize) {
+        self.domains.reserve(additional);
+        self.events.reserve(additional);
+        self.backtrack_events.reserve(additional);
+    }
+
     /// Returns the domain events which have occurred since the propagators were last notified of
     /// the events.
     pub fn drain_domain_events(&mut self) -> impl Iterator<Item = (IntDomainEvent, DomainId)> + '_ {
@@ -138,9 +153,13 @@ impl AssignmentsInteger {
         self.trail.iter().rev().for_each(|entry| {
             domains[entry.predicate.get_domain()].undo_trail_entry(entry);
         });
+        domains
+            .iter_mut()
+            .for_each(|domain| domain.clear_checkpoints());
         AssignmentsInteger {
             trail: Default::default(),
             domains,
+            checkpointed_domains: Default::default(),
             events: event_sink,
             backtrack_events: backtrack_sink,
         }
@@ -177,6 +196,87 @@ impl AssignmentsInteger {
             .copied()
     }
 
+    /// Returns the lower-bound of the provided [`DomainId`] at the point at which the trail
+    /// contained `trail_position` entries (i.e. before the entry at index `trail_position` was
+    /// applied).
+    ///
+    /// Note that this method goes over the trail entries after `trail_position`, it should thus
+    /// not be used in performance-critical code.
+    pub fn get_lower_bound_at_trail_position(
+        &self,
+        domain_id: DomainId,
+        trail_position: usize,
+    ) -> i32 {
+        self.get_first_entry_from_trail_position(domain_id, trail_position)
+            .map_or(self.get_lower_bound(domain_id), |entry| {
+                entry.old_lower_bound
+            })
+    }
+
+    /// Returns the upper-bound of the provided [`DomainId`] at the point at which the trail
+    /// contained `trail_position` entries (i.e. before the entry at index `trail_position` was
+    /// applied).
+    ///
+    /// Note that this method goes over the trail entries after `trail_position`, it should thus
+    /// not be used in performance-critical code.
+    pub fn get_upper_bound_at_trail_position(
+        &self,
+        domain_id: DomainId,
+        trail_position: usize,
+    ) -> i32 {
+        self.get_first_entry_from_trail_position(domain_id, trail_position)
+            .map_or(self.get_upper_bound(domain_id), |entry| {
+                entry.old_upper_bound
+            })
+    }
+
+    /// Returns whether the provided `value` was in the domain of the provided [`DomainId`] at the
+    /// point at which the trail contained `trail_position` entries (i.e. before the entry at index
+    /// `trail_position` was applied).
+    ///
+    /// Note that this method goes over the entire trail, it should thus not be used in
+    /// performance-critical code.
+    pub fn is_value_in_domain_at_trail_position(
+        &self,
+        domain_id: DomainId,
+        value: i32,
+        trail_position: usize,
+    ) -> bool {
+        let lower_bound = self.get_lower_bound_at_trail_position(domain_id, trail_position);
+        let upper_bound = self.get_upper_bound_at_trail_position(domain_id, trail_position);
+
+        // A value within the bounds can only have been removed by an explicit removal
+        let removal = IntegerPredicate::NotEqual {
+            domain_id,
+            not_equal_constant: value,
+        };
+
+        lower_bound <= value
+            && value <= upper_bound
+            && !self.trail[..trail_position]
+                .iter()
+                .any(|entry| entry.predicate == removal)
+    }
+
+    /// Returns the first entry on the trail at or after `trail_position` which concerns the
+    /// provided [`DomainId`]; the old bounds stored in this entry are the bounds of the domain at
+    /// `trail_position`.
+    fn get_first_entry_from_trail_position(
+        &self,
+        domain_id: DomainId,
+        trail_position: usize,
+    ) -> Option<&ConstraintProgrammingTrailEntry> {
+        pumpkin_assert_simple!(
+            trail_position <= self.num_trail_entries(),
+            "Cannot query trail position {trail_position} as the trail only contains {} entries",
+            self.num_trail_entries()
+        );
+
+        self.trail[trail_position..]
+            .iter()
+            .find(|entry| entry.predicate.get_domain() == domain_id)
+    }
+
     /// Returns the assigned value of the provided [`DomainId`]; this method will panic if the
     /// [`DomainId`] is not assigned
     pub fn get_assigned_value(&self, domain_id: DomainId) -> i32 {
@@ -205,12 +305,57 @@ impl AssignmentsInteger {
         predicates
     }
 
+    /// Returns the domain of the provided [`DomainId`] as a sorted list of disjoint ranges; two
+    /// consecutive ranges are always separated by at least one value which is not in the domain.
+    ///
+    /// This is an alternative to [`AssignmentsInteger::get_domain_description`] which is more
+    /// convenient for displaying domains with many holes. If the domain is empty, then no ranges
+    /// are returned.
+    pub fn get_domain_ranges(&self, domain_id: DomainId) -> Vec<RangeInclusive<i32>> {
+        let domain = &self.domains[domain_id];
+        if domain.lower_bound > domain.upper_bound {
+            return Vec::new();
+        }
+
+        let mut ranges = Vec::new();
+        let mut start = domain.lower_bound;
+        for value in (domain.lower_bound..=domain.upper_bound).skip(1) {
+            if !domain.contains(value) {
+                if domain.contains(value - 1) {
+                    ranges.push(start..=value - 1);
+                }
+            } else if !domain.contains(value - 1) {
+                start = value;
+            }
+        }
+        ranges.push(start..=domain.upper_bound);
+        ranges
+    }
+
     /// Returns whether `value` is in the domain of the provided [`DomainId`]
     pub fn is_value_in_domain(&self, domain_id: DomainId, value: i32) -> bool {
         let domain = &self.domains[domain_id];
         domain.contains(value)
     }
 
+    /// Returns the number of values in the domain of the provided [`DomainId`].
+    ///
+    /// The size is maintained incrementally, so this is a constant time operation regardless of
+    /// the size of the domain. Note that maintaining the size is not free: tightening a bound of a
+    /// domain with holes counts the values which are removed, which is linear in the distance
+    /// between the old and the new bound.
+    pub fn get_domain_size(&self, domain_id: DomainId) -> usize {
+        self.domains[domain_id].size()
+    }
+
+    /// Returns the number of values between the bounds of the provided [`DomainId`] which are not
+    /// in its domain.
+    ///
+    /// Similar to [`AssignmentsInteger::get_domain_size`], this is a constant time operation.
+    pub fn get_number_of_holes(&self, domain_id: DomainId) -> usize {
+        self.domains[domain_id].num_holes()
+    }
+
     /// Returns whether the provided [`DomainId`] is assigned
     pub fn is_domain_assigned(&self, domain_id: DomainId) -> bool {
         self.get_lower_bound(domain_id) == self.get_upper_bound(domain_id)
@@ -249,17 +394,27 @@ impl AssignmentsInteger {
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
 
+        let decision_level = self.trail.get_decision_level();
+        let trail_position = self.trail.len();
+
+        let domain = &mut self.domains[domain_id];
+        if domain.create_checkpoint(decision_level) {
+            self.checkpointed_domains.push(domain_id);
+        }
+        let size_before = domain.size;
+        domain.set_lower_bound(new_lower_bound, &mut self.events);
+        domain.record_fixing(trail_position);
+        let num_removed_values = size_before - domain.size;
+
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            num_removed_values,
             reason,
         });
 
-        let domain = &mut self.domains[domain_id];
-        domain.set_lower_bound(new_lower_bound, &mut self.events);
-
-        domain.verify_consistency()
+        self.domains[domain_id].verify_consistency()
     }
 
     /// Increases the upper-bound of the provided [`DomainId`] to `new_upper_bound` and stores the
@@ -287,17 +442,27 @@ impl AssignmentsInteger {
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
 
+        let decision_level = self.trail.get_decision_level();
+        let trail_position = self.trail.len();
+
+        let domain = &mut self.domains[domain_id];
+        if domain.create_checkpoint(decision_level) {
+            self.checkpointed_domains.push(domain_id);
+        }
+        let size_before = domain.size;
+        domain.set_upper_bound(new_upper_bound, &mut self.events);
+        domain.record_fixing(trail_position);
+        let num_removed_values = size_before - domain.size;
+
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            num_removed_values,
             reason,
         });
 
-        let domain = &mut self.domains[domain_id];
-        domain.set_upper_bound(new_upper_bound, &mut self.events);
-
-        domain.verify_consistency()
+        self.domains[domain_id].verify_consistency()
     }
 
     /// Sets the lower- and upper-bound of the provided [`DomainId`] to `assigned_value` and stores
@@ -347,17 +512,27 @@ impl AssignmentsInteger {
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
 
+        let decision_level = self.trail.get_decision_level();
+        let trail_position = self.trail.len();
+
+        let domain = &mut self.domains[domain_id];
+        if domain.create_checkpoint(decision_level) {
+            self.checkpointed_domains.push(domain_id);
+        }
+        let size_before = domain.size;
+        domain.remove_initial_value(removed_value_from_domain, &mut self.events);
+        domain.record_fixing(trail_position);
+        let num_removed_values = size_before - domain.size;
+
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            num_removed_values,
             reason,
         });
 
-        let domain = &mut self.domains[domain_id];
-        domain.remove_initial_value(removed_value_from_domain, &mut self.events);
-
-        domain.verify_consistency()
+        self.domains[domain_id].verify_consistency()
     }
 
     /// Removes the value `removed_value_from_domain` from the domain of the provided
@@ -382,17 +557,27 @@ impl AssignmentsInteger {
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
 
+        let decision_level = self.trail.get_decision_level();
+        let trail_position = self.trail.len();
+
+        let domain = &mut self.domains[domain_id];
+        if domain.create_checkpoint(decision_level) {
+            self.checkpointed_domains.push(domain_id);
+        }
+        let size_before = domain.size;
+        domain.remove_value(removed_value_from_domain, &mut self.events);
+        domain.record_fixing(trail_position);
+        let num_removed_values = size_before - domain.size;
+
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            num_removed_values,
             reason,
         });
 
-        let domain = &mut self.domains[domain_id];
-        domain.remove_value(removed_value_from_domain, &mut self.events);
-
-        domain.verify_consistency()
+        self.domains[domain_id].verify_consistency()
     }
 
     /// Apply the given [`Predicate`] to the integer domains.
@@ -456,6 +641,12 @@ impl AssignmentsInteger {
     /// [`DomainId`]s and their values which were fixed (i.e. domain of size one) before
     /// backtracking and are unfixed (i.e. domain of two or more values) after synchronisation.
     ///
+    /// The domains are not restored by undoing the popped trail entries one by one; instead, each
+    /// domain which was modified at one of the popped decision levels is reset to the checkpoint
+    /// which it created when it was first modified after `new_decision_level`, and the trail is
+    /// truncated in bulk. The popped trail entries are only gone over to determine the backtrack
+    /// events, which only happens if `is_watching_any_backtrack_events` is true.
+    ///
     /// The `last_notified_trail_index` is used to only create backtrack events for events for
     /// which the propagators have been notified of the "forward" event.
     pub fn synchronise(
@@ -466,50 +657,72 @@ impl AssignmentsInteger {
     ) -> Vec<(DomainId, i32)> {
         let mut unfixed_variables = Vec::new();
 
+        // A domain can occur once for every popped decision level at which it was modified; only
+        // the first occurrence restores the domain, for the other occurrences this is a no-op
+        for domain_id in self.checkpointed_domains.synchronise(new_decision_level) {
+            if let Some(fixing) = self.domains[domain_id].restore_checkpoint(new_decision_level) {
+                if is_watching_any_backtrack_events
+                    && fixing.trail_position < last_notified_trail_index
+                {
+                    // This `domain_id` was unassigned while backtracking
+                    self.backtrack_events
+                        .event_occurred(IntDomainEvent::Assign, domain_id);
+                }
+
+                // Variable used to be fixed but is not after backtracking
+                unfixed_variables.push((domain_id, fixing.value));
+            }
+        }
+
         // Used to calculate the index on the trail of the current entry; we only create the
         // backtrack events for entries for which the notification of the "forward" event has
         // occurred.
         let num_trail_entries_before_synchronisation = self.num_trail_entries();
 
-        self.trail.synchronise(new_decision_level).enumerate().for_each(|(index, entry)| {
+        let popped_entries = self.trail.synchronise(new_decision_level);
+        if !is_watching_any_backtrack_events {
+            return unfixed_variables;
+        }
+
+        for (index, entry) in popped_entries.enumerate() {
             pumpkin_assert_moderate!(
                 !entry.predicate.is_equality_predicate(),
                 "For now we do not expect equality predicates on the trail, since currently equality predicates are split into lower and upper bound predicates."
             );
-            let domain_id = entry.predicate.get_domain();
-
-            let lower_bound_before = self.domains[domain_id].lower_bound;
-            let upper_bound_before = self.domains[domain_id].upper_bound;
-            let fixed_before = upper_bound_before == lower_bound_before;
-
             let trail_index = num_trail_entries_before_synchronisation - index - 1;
-
-            self.domains[domain_id].undo_trail_entry(&entry);
-
-            if fixed_before && self.domains[domain_id].lower_bound != self.domains[domain_id].upper_bound {
-                if is_watching_any_backtrack_events && trail_index < last_notified_trail_index {
-                    // This `domain_id` was unassigned while backtracking
-                    self.backtrack_events.event_occurred(IntDomainEvent::Assign, domain_id);
-                }
-
-                // Variable used to be fixed but is not after backtracking
-                unfixed_variables.push((domain_id, lower_bound_before));
+            if trail_index >= last_notified_trail_index {
+                continue;
             }
 
-            if is_watching_any_backtrack_events && trail_index < last_notified_trail_index {
-                // Now we add the remaining events which can occur while backtracking, note that the case of equality has already been handled!
-                if lower_bound_before != self.domains[domain_id].lower_bound {
-                    self.backtrack_events.event_occurred(IntDomainEvent::LowerBound, domain_id)
-                }
-                if upper_bound_before != self.domains[domain_id].upper_bound {
-                    self.backtrack_events.event_occurred(IntDomainEvent::UpperBound, domain_id)
-                }
-                if matches!(entry.predicate, IntegerPredicate::NotEqual { domain_id: _, not_equal_constant: _ }) {
-                    self.backtrack_events.event_occurred(IntDomainEvent::Removal, domain_id)
+            // Now we add the remaining events which can occur while backtracking, note that the
+            // case of equality has already been handled! Every entry on the trail changed the
+            // domain, which means that the changed bounds can be derived from the entry.
+            let domain_id = entry.predicate.get_domain();
+            match entry.predicate {
+                IntegerPredicate::LowerBound { .. } => self
+                    .backtrack_events
+                    .event_occurred(IntDomainEvent::LowerBound, domain_id),
+                IntegerPredicate::UpperBound { .. } => self
+                    .backtrack_events
+                    .event_occurred(IntDomainEvent::UpperBound, domain_id),
+                IntegerPredicate::NotEqual {
+                    not_equal_constant, ..
+                } => {
+                    if not_equal_constant == entry.old_lower_bound {
+                        self.backtrack_events
+                            .event_occurred(IntDomainEvent::LowerBound, domain_id)
+                    }
+                    if not_equal_constant == entry.old_upper_bound {
+                        self.backtrack_events
+                            .event_occurred(IntDomainEvent::UpperBound, domain_id)
+                    }
+                    self.backtrack_events
+                        .event_occurred(IntDomainEvent::Removal, domain_id)
                 }
+                IntegerPredicate::Equal { .. } => unreachable!(),
             }
+        }
 
-        });
         unfixed_variables
     }
 }
@@ -538,6 +751,8 @@ pub struct ConstraintProgrammingTrailEntry {
     pub old_lower_bound: i32,
     /// The upper-bound before the predicate was applied
     pub old_upper_bound: i32,
+    /// The number of values which were removed from the domain by applying the predicate
+    pub num_removed_values: usize,
     /// Stores the a reference to the reason in the [`ReasonStore`], only makes sense if a
     /// propagation  took place, e.g., does _not_ make sense in the case of a decision or if
     /// the update was due to synchronisation from the propositional trail.
@@ -551,6 +766,11 @@ pub struct ConstraintProgrammingTrailEntry {
 ///
 /// When the domain is in an empty state, `lower_bound > upper_bound` and the state of the
 /// `is_value_in_domain` field is undefined.
+///
+/// To allow backtracking without undoing every individual update, the domain creates a
+/// [`DomainCheckpoint`] the first time that it is modified at a decision level (other than the
+/// root level); restoring the domain to a checkpoint resets the bounds and re-adds the values which
+/// were removed since the checkpoint was created.
 #[derive(Clone, Debug)]
 struct IntegerDomainExplicit {
     id: DomainId,
@@ -564,6 +784,39 @@ struct IntegerDomainExplicit {
     offset: i32,
 
     is_value_in_domain: Box<[bool]>,
+    /// The number of values in the domain; it is maintained upon every update so that it does not
+    /// have to be computed by iterating over the domain. Every trail entry stores the number of
+    /// values it removed, which is added back when the entry is undone.
+    size: usize,
+
+    /// The checkpoints of the domain, in increasing order of decision level; there is at most one
+    /// checkpoint per decision level.
+    checkpoints: Vec<DomainCheckpoint>,
+    /// The values which have been removed from the domain at a non-root decision level, in the
+    /// order in which they were removed.
+    removed_values: Vec<i32>,
+    /// Keeps track of the update which caused the domain to become fixed (if any), this is used to
+    /// determine which variables become unfixed when backtracking.
+    fixing: Option<DomainFixing>,
+}
+
+/// The state of an [`IntegerDomainExplicit`] before it was first modified at `decision_level`.
+#[derive(Clone, Copy, Debug)]
+struct DomainCheckpoint {
+    decision_level: usize,
+    lower_bound: i32,
+    upper_bound: i32,
+    size: usize,
+    /// The number of elements in [`IntegerDomainExplicit::removed_values`] at the time that the
+    /// checkpoint was created.
+    num_removed_values: usize,
+}
+
+/// Indicates that the update at `trail_position` caused the domain to be fixed to `value`.
+#[derive(Clone, Copy, Debug)]
+struct DomainFixing {
+    trail_position: usize,
+    value: i32,
 }
 
 impl IntegerDomainExplicit {
@@ -584,6 +837,10 @@ impl IntegerDomainExplicit {
             initial_upper_bound: upper_bound,
             offset,
             is_value_in_domain: is_value_in_domain.into(),
+            size: size as usize,
+            checkpoints: vec![],
+            removed_values: vec![],
+            fixing: None,
         }
     }
 
@@ -593,6 +850,52 @@ impl IntegerDomainExplicit {
         self.lower_bound <= value && value <= self.upper_bound && self.is_value_in_domain[idx]
     }
 
+    fn size(&self) -> usize {
+        pumpkin_assert_extreme!(self.size == self.count_values_in_domain());
+        self.size
+    }
+
+    fn num_holes(&self) -> usize {
+        if self.lower_bound > self.upper_bound {
+            return 0;
+        }
+
+        (self.upper_bound - self.lower_bound + 1) as usize - self.size()
+    }
+
+    /// Returns the number of values in the domain by iterating over the values between the bounds.
+    fn count_values_in_domain(&self) -> usize {
+        if self.lower_bound > self.upper_bound {
+            return 0;
+        }
+
+        self.is_value_in_domain[self.get_index(self.lower_bound)..=self.get_index(self.upper_bound)]
+            .iter()
+            .filter(|&&is_in_domain| is_in_domain)
+            .count()
+    }
+
+    /// Returns the number of values in `[from, to]` which are in the domain; both values should be
+    /// within the bounds of the domain.
+    ///
+    /// If there are no holes in the domain, then this is a constant time operation, otherwise the
+    /// values in the range are iterated over.
+    fn count_values_between(&self, from: i32, to: i32) -> usize {
+        if from > to {
+            return 0;
+        }
+
+        let width = (self.upper_bound - self.lower_bound + 1) as usize;
+        if self.size == width {
+            return (to - from + 1) as usize;
+        }
+
+        self.is_value_in_domain[self.get_index(from)..=self.get_index(to)]
+            .iter()
+            .filter(|&&is_in_domain| is_in_domain)
+            .count()
+    }
+
     fn remove_initial_value(&mut self, value: i32, events: &mut EventSink) {
         self.initial_removed_values.push(value);
         self.remove_value(value, events)
@@ -607,6 +910,11 @@ impl IntegerDomainExplicit {
 
         if self.is_value_in_domain[idx] {
             events.event_occurred(IntDomainEvent::Removal, self.id);
+            self.size -= 1;
+
+            if !self.checkpoints.is_empty() {
+                self.removed_values.push(value);
+            }
         }
 
         self.is_value_in_domain[idx] = false;
@@ -626,6 +934,11 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::UpperBound, self.id);
 
+        self.size -= if value < self.lower_bound {
+            self.size
+        } else {
+            self.count_values_between(value + 1, self.upper_bound)
+        };
         self.upper_bound = value;
         self.update_upper_bound(events);
 
@@ -641,6 +954,11 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::LowerBound, self.id);
 
+        self.size -= if value > self.upper_bound {
+            self.size
+        } else {
+            self.count_values_between(self.lower_bound, value - 1)
+        };
         self.lower_bound = value;
         self.update_lower_bound(events);
 
@@ -694,6 +1012,86 @@ impl IntegerDomainExplicit {
         }
     }
 
+    /// Creates a checkpoint of the current state of the domain, unless a checkpoint was already
+    /// created at the provided decision level. No checkpoints are created at the root level since
+    /// updates at the root are never undone.
+    ///
+    /// Returns whether a checkpoint was created.
+    fn create_checkpoint(&mut self, decision_level: usize) -> bool {
+        if decision_level == 0 {
+            return false;
+        }
+
+        if let Some(last_checkpoint) = self.checkpoints.last() {
+            if last_checkpoint.decision_level == decision_level {
+                return false;
+            }
+            pumpkin_assert_moderate!(last_checkpoint.decision_level < decision_level);
+        }
+
+        self.checkpoints.push(DomainCheckpoint {
+            decision_level,
+            lower_bound: self.lower_bound,
+            upper_bound: self.upper_bound,
+            size: self.size,
+            num_removed_values: self.removed_values.len(),
+        });
+
+        true
+    }
+
+    /// Should be called after the domain has been updated by the update at `trail_position`.
+    fn record_fixing(&mut self, trail_position: usize) {
+        if self.fixing.is_none() && self.lower_bound == self.upper_bound {
+            self.fixing = Some(DomainFixing {
+                trail_position,
+                value: self.lower_bound,
+            });
+        }
+    }
+
+    /// Restores the domain to its state at `decision_level`; this is a no-op if the domain was not
+    /// modified after `decision_level`.
+    ///
+    /// If the domain was fixed before restoring and is not fixed anymore after restoring, then the
+    /// update which caused the domain to be fixed is returned.
+    fn restore_checkpoint(&mut self, decision_level: usize) -> Option<DomainFixing> {
+        let mut restored_checkpoint = None;
+        while self
+            .checkpoints
+            .last()
+            .is_some_and(|checkpoint| checkpoint.decision_level > decision_level)
+        {
+            restored_checkpoint = self.checkpoints.pop();
+        }
+        let checkpoint = restored_checkpoint?;
+
+        let offset = self.offset;
+        for value in self.removed_values.drain(checkpoint.num_removed_values..) {
+            self.is_value_in_domain[(value + offset) as usize] = true;
+        }
+
+        self.lower_bound = checkpoint.lower_bound;
+        self.upper_bound = checkpoint.upper_bound;
+        self.size = checkpoint.size;
+
+        pumpkin_assert_moderate!(self.debug_bounds_check());
+
+        if self.lower_bound != self.upper_bound {
+            self.fixing.take()
+        } else {
+            None
+        }
+    }
+
+    /// Removes all of the checkpoints (and the information associated with them) without restoring
+    /// the domain.
+    fn clear_checkpoints(&mut self) {
+        self.checkpoints.clear();
+        self.removed_values.clear();
+        self.fixing = None;
+    }
+
     fn undo_trail_entry(&mut self, entry: &ConstraintProgrammingTrailEntry) {
         if let IntegerPredicate::NotEqual {
             domain_id: _,
@@ -706,6 +1104,7 @@ impl IntegerDomainExplicit {
 
         self.lower_bound = entry.old_lower_bound;
         self.upper_bound = entry.old_upper_bound;
+        self.size += entry.num_removed_values;
 
         pumpkin_assert_moderate!(self.debug_bounds_check());
     }
@@ -715,6 +1114,55 @@ impl IntegerDomainExplicit {
 mod tests {
     use super::*;
 
+    #[test]
+    fn domain_ranges_are_sorted_and_disjoint() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+
+        for value in [3, 4, 7] {
+            assignment
+                .remove_value_from_domain(d1, value, None)
+                .expect("non-empty domain");
+        }
+
+        assert_eq!(assignment.get_domain_ranges(d1), vec![1..=2, 5..=6, 8..=10]);
+    }
+
+    #[test]
+    fn domain_ranges_of_a_fixed_domain_consist_of_the_single_value() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+
+        assignment
+            .make_assignment(d1, 4, None)
+            .expect("non-empty domain");
+
+        assert_eq!(assignment.get_domain_ranges(d1), vec![4..=4]);
+    }
+
+    #[test]
+    fn domain_ranges_of_an_empty_domain_are_empty() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+
+        let result = assignment.tighten_lower_bound(d1, 11, None);
+        assert!(result.is_err());
+
+        assert_eq!(assignment.get_domain_ranges(d1), vec![]);
+    }
+
+    #[test]
+    fn domain_ranges_include_the_largest_value() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(i32::MAX - 1, i32::MAX);
+
+        assignment
+            .tighten_lower_bound(d1, i32::MAX, None)
+            .expect("non-empty domain");
+
+        assert_eq!(assignment.get_domain_ranges(d1), vec![i32::MAX..=i32::MAX]);
+    }
+
     #[test]
     fn jump_in_bound_change_lower_and_upper_bound_event_backtrack() {
         let mut assignment = AssignmentsInteger::default();
@@ -1099,6 +1547,55 @@ mod tests {
         assert_eq!(3, domain.upper_bound);
     }
 
+    #[test]
+    fn domain_size_is_maintained_when_updating_and_backtracking() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(0, 9);
+
+        assignment.increase_decision_level();
+        assignment
+            .remove_value_from_domain(d1, 3, None)
+            .expect("non-empty domain");
+        assignment
+            .remove_value_from_domain(d1, 6, None)
+            .expect("non-empty domain");
+        assert_eq!(8, assignment.get_domain_size(d1));
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, 2, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_upper_bound(d1, 7, None)
+            .expect("non-empty domain");
+        assert_eq!(4, assignment.get_domain_size(d1));
+        assert_eq!(2, assignment.get_number_of_holes(d1));
+
+        assignment
+            .remove_value_from_domain(d1, 2, None)
+            .expect("non-empty domain");
+        assert_eq!(3, assignment.get_domain_size(d1));
+
+        let _ = assignment.synchronise(1, false, usize::MAX);
+        assert_eq!(8, assignment.get_domain_size(d1));
+
+        let _ = assignment.synchronise(0, false, usize::MAX);
+        assert_eq!(10, assignment.get_domain_size(d1));
+        assert_eq!(0, assignment.get_number_of_holes(d1));
+    }
+
+    #[test]
+    fn emptying_a_domain_sets_its_size_to_zero() {
+        let mut events = EventSink::default();
+        events.grow();
+
+        let mut domain = IntegerDomainExplicit::new(1, 5, DomainId::new(0));
+        domain.remove_value(3, &mut events);
+        domain.set_lower_bound(6, &mut events);
+
+        assert_eq!(0, domain.size());
+    }
+
     #[test]
     fn undo_removal_at_bounds_indexes_into_values_domain_correctly() {
         let mut assignment = AssignmentsInteger::default();
@@ -1115,6 +1612,124 @@ mod tests {
         assert_eq!(5, assignment.get_upper_bound(d1));
     }
 
+    #[test]
+    fn backtracking_restores_domain_to_state_at_decision_level() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, 2, None)
+            .expect("non-empty domain");
+        assignment
+            .remove_value_from_domain(d1, 5, None)
+            .expect("non-empty domain");
+
+        assignment.increase_decision_level();
+        assignment
+            .remove_value_from_domain(d1, 7, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_upper_bound(d1, 8, None)
+            .expect("non-empty domain");
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, 6, None)
+            .expect("non-empty domain");
+
+        let _ = assignment.synchronise(1, false, usize::MAX);
+
+        assert_eq!(2, assignment.get_lower_bound(d1));
+        assert_eq!(10, assignment.get_upper_bound(d1));
+        assert!(!assignment.is_value_in_domain(d1, 5));
+        assert!(assignment.is_value_in_domain(d1, 7));
+
+        let _ = assignment.synchronise(0, false, usize::MAX);
+
+        assert_eq!(1, assignment.get_lower_bound(d1));
+        assert!(assignment.is_value_in_domain(d1, 5));
+    }
+
+    #[test]
+    fn trail_entries_store_the_number_of_removed_values() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+
+        assignment
+            .remove_value_from_domain(d1, 3, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_lower_bound(d1, 5, None)
+            .expect("non-empty domain");
+        assert_eq!(3, assignment.get_last_entry_on_trail().num_removed_values);
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_upper_bound(d1, 8, None)
+            .expect("non-empty domain");
+        assert_eq!(2, assignment.get_last_entry_on_trail().num_removed_values);
+
+        let empty_clone = assignment.debug_create_empty_clone();
+        assert_eq!(10, empty_clone.get_domain_size(d1));
+        assert_eq!(0, empty_clone.get_number_of_holes(d1));
+    }
+
+    #[test]
+    fn backtracking_only_restores_domains_modified_after_decision_level() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 10);
+        let d2 = assignment.grow(1, 10);
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, 3, None)
+            .expect("non-empty domain");
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_upper_bound(d2, 4, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_upper_bound(d1, 3, None)
+            .expect("non-empty domain");
+
+        let unfixed_variables = assignment.synchronise(1, false, usize::MAX);
+
+        assert_eq!(vec![(d1, 3)], unfixed_variables);
+        assert_eq!(1, assignment.num_trail_entries());
+        assert_eq!(3, assignment.get_lower_bound(d1));
+        assert_eq!(10, assignment.get_upper_bound(d1));
+        assert_eq!(10, assignment.get_upper_bound(d2));
+        assert!(assignment.drain_backtrack_domain_events().next().is_none());
+    }
+
+    #[test]
+    fn backtracking_reports_domains_which_become_unfixed() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 5);
+        let d2 = assignment.grow(1, 5);
+
+        assignment.increase_decision_level();
+        assignment
+            .make_assignment(d1, 3, None)
+            .expect("non-empty domain");
+
+        assignment.increase_decision_level();
+        assignment
+            .make_assignment(d2, 4, None)
+            .expect("non-empty domain");
+        // The domain becoming empty after being fixed should still lead to it being reported
+        let _ = assignment.tighten_lower_bound(d2, 5, None);
+
+        let unfixed = assignment.synchronise(1, false, usize::MAX);
+        assert_eq!(vec![(d2, 4)], unfixed);
+        assert!(assignment.is_domain_assigned_to_value(d1, 3));
+
+        let unfixed = assignment.synchronise(0, false, usize::MAX);
+        assert_eq!(vec![(d1, 3)], unfixed);
+    }
+
     fn assert_contains_events<DomainEvent: PartialEq + Copy>(
         slice: &[(DomainEvent, DomainId)],
         domain: DomainId,
@@ -33,6 +33,11 @@ impl EventSink {
         let _ = self.present.push(EnumSet::new());
     }
 
+    /// Reserves capacity for at least `additional` more domains.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.present.reserve(additional)
+    }
+
     pub(crate) fn event_occurred(&mut self, event: IntDomainEvent, domain: DomainId) {
         let elem = &mut self.present[domain];
 
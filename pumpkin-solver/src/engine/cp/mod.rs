@@ -14,7 +14,7 @@ pub(crate) use assignments_integer::AssignmentsInteger;
 pub(crate) use assignments_integer::EmptyDomain;
 pub(crate) use propagator_queue::PropagatorQueue;
 pub(crate) use variable_literal_mappings::VariableLiteralMappings;
-pub(crate) use watch_list_cp::IntDomainEvent;
+pub use watch_list_cp::IntDomainEvent;
 pub(crate) use watch_list_cp::WatchListCP;
 pub(crate) use watch_list_cp::Watchers;
 pub(crate) use watch_list_propositional::*;
@@ -23,14 +23,20 @@ pub(crate) use watch_list_propositional::*;
 mod tests {
     use assignments_integer::AssignmentsInteger;
 
+    use super::IntDomainEvent;
+    use super::WatchListCP;
+    use super::WatchListPropositional;
     use crate::conjunction;
     use crate::engine::cp::assignments_integer;
+    use crate::engine::propagation::LocalId;
     use crate::engine::propagation::PropagationContextMut;
     use crate::engine::propagation::PropagatorId;
+    use crate::engine::propagation::PropagatorVarId;
     use crate::engine::reason::ReasonStore;
     use crate::engine::variables::Literal;
     use crate::engine::variables::PropositionalVariable;
     use crate::engine::AssignmentsPropositional;
+    use crate::engine::DomainEvents;
 
     #[test]
     fn test_no_update_reason_store_if_no_update_lower_bound() {
@@ -125,4 +131,38 @@ mod tests {
         }
         assert_eq!(reason_store.len(), 0);
     }
+
+    #[test]
+    fn variables_can_be_registered_during_propagation() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let domain = assignments_integer.grow(5, 10);
+        let mut reason_store = ReasonStore::default();
+        let mut assignments_propositional = AssignmentsPropositional::default();
+
+        // The watch list has not been grown for the domain, it should be grown on the fly.
+        let mut watch_list = WatchListCP::default();
+        let mut watch_list_propositional = WatchListPropositional::default();
+        {
+            let mut context = PropagationContextMut::new(
+                &mut assignments_integer,
+                &mut reason_store,
+                &mut assignments_propositional,
+                PropagatorId(2),
+            );
+            context.with_watch_lists(&mut watch_list, &mut watch_list_propositional);
+
+            let _ = context.register(domain, DomainEvents::LOWER_BOUND, LocalId::from(4));
+        }
+
+        assert_eq!(
+            watch_list.get_affected_propagators(IntDomainEvent::LowerBound, domain),
+            &[PropagatorVarId {
+                propagator: PropagatorId(2),
+                variable: LocalId::from(4),
+            }]
+        );
+        assert!(watch_list
+            .get_affected_propagators(IntDomainEvent::UpperBound, domain)
+            .is_empty());
+    }
 }
@@ -0,0 +1,117 @@
+#[cfg(doc)]
+use crate::branching::SelectionContext;
+use crate::engine::variables::HistoricalIntegerVariable;
+use crate::engine::AssignmentsInteger;
+
+/// A read-only view of the integer domains as they were at a point in the past, identified by the
+/// number of entries which the trail contained at that point (see
+/// [`SelectionContext::trail_position`]).
+///
+/// This is useful when the state at an earlier point in the search is required after the fact;
+/// e.g. a brancher or a debugging tool which stores the trail position when making a decision can
+/// later inspect the domains which the decision was based on, as long as the search has not
+/// backtracked past that point.
+///
+/// Note that the queries go over the trail, they should thus not be used in performance-critical
+/// code.
+#[derive(Clone, Copy, Debug)]
+pub struct DomainSnapshotAt<'a> {
+    assignments_integer: &'a AssignmentsInteger,
+    trail_position: usize,
+}
+
+impl<'a> DomainSnapshotAt<'a> {
+    pub(crate) fn new(assignments_integer: &'a AssignmentsInteger, trail_position: usize) -> Self {
+        DomainSnapshotAt {
+            assignments_integer,
+            trail_position,
+        }
+    }
+
+    /// Returns the trail position at which this snapshot was taken.
+    pub fn trail_position(&self) -> usize {
+        self.trail_position
+    }
+
+    /// Returns the lower-bound of the provided variable at the time of the snapshot.
+    pub fn lower_bound<Var: HistoricalIntegerVariable>(&self, var: &Var) -> i32 {
+        var.lower_bound_at_trail_position(self.assignments_integer, self.trail_position)
+    }
+
+    /// Returns the upper-bound of the provided variable at the time of the snapshot.
+    pub fn upper_bound<Var: HistoricalIntegerVariable>(&self, var: &Var) -> i32 {
+        var.upper_bound_at_trail_position(self.assignments_integer, self.trail_position)
+    }
+
+    /// Returns whether the provided value was in the domain of the provided variable at the time
+    /// of the snapshot.
+    pub fn contains<Var: HistoricalIntegerVariable>(&self, var: &Var, value: i32) -> bool {
+        var.contains_at_trail_position(self.assignments_integer, value, self.trail_position)
+    }
+
+    /// Returns whether the domain of the provided variable was a singleton at the time of the
+    /// snapshot.
+    pub fn is_fixed<Var: HistoricalIntegerVariable>(&self, var: &Var) -> bool {
+        self.lower_bound(var) == self.upper_bound(var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::SelectionContext;
+    use crate::engine::variables::TransformableVariable;
+    use crate::engine::AssignmentsInteger;
+    use crate::engine::AssignmentsPropositional;
+
+    #[test]
+    fn snapshot_observes_domains_at_trail_position() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let assignments_propositional = AssignmentsPropositional::default();
+        let mut random = TestRandom::default();
+        let x = assignments_integer.grow(0, 10);
+        let y = assignments_integer.grow(0, 10);
+
+        assignments_integer
+            .tighten_lower_bound(x, 2, None)
+            .expect("non-empty domain");
+        let trail_position = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut random,
+        )
+        .trail_position();
+
+        assignments_integer.increase_decision_level();
+        assignments_integer
+            .remove_value_from_domain(x, 5, None)
+            .expect("non-empty domain");
+        assignments_integer
+            .tighten_upper_bound(x, 8, None)
+            .expect("non-empty domain");
+        assignments_integer
+            .tighten_lower_bound(y, 3, None)
+            .expect("non-empty domain");
+
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut random,
+        );
+        let snapshot = context.domain_snapshot_at(trail_position);
+
+        assert_eq!(snapshot.lower_bound(&x), 2);
+        assert_eq!(snapshot.upper_bound(&x), 10);
+        assert!(snapshot.contains(&x, 5));
+        assert!(!snapshot.contains(&x, 1));
+        assert_eq!(snapshot.lower_bound(&y), 0);
+
+        // Views are supported as well
+        assert_eq!(snapshot.lower_bound(&x.scaled(-1)), -10);
+        assert_eq!(snapshot.upper_bound(&x.scaled(-1)), -2);
+
+        // The current state is unaffected
+        assert_eq!(context.upper_bound(x), 8);
+        assert!(!context.contains(x, 5));
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::Index;
+
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::propagation::LocalId;
+#[cfg(doc)]
+use crate::engine::propagation::Propagator;
+#[cfg(doc)]
+use crate::engine::propagation::PropagatorInitialisationContext;
+
+/// A table which maps the [`LocalId`]s of a [`Propagator`] to the variables which were registered
+/// with those ids.
+///
+/// The ids are handed out by the map itself when a variable is registered through
+/// [`PropagatorInitialisationContext::register_mapped`], which means that they are always dense
+/// and that every [`LocalId`] received in [`Propagator::notify`] can be looked up without the
+/// propagator having to convert it to an index (or worse, to a variable) by hand. Looking up an
+/// id which was not handed out by the map results in a panic with a descriptive message rather
+/// than in a silently wrong variable.
+///
+/// A propagator which uses a [`LocalIdMap`] should register all of its variables through it, as
+/// the ids handed out by the map start at `0`.
+#[derive(Clone, Debug)]
+pub(crate) struct LocalIdMap<Var> {
+    variables: KeyedVec<LocalId, Var>,
+}
+
+impl<Var> Default for LocalIdMap<Var> {
+    fn default() -> Self {
+        LocalIdMap {
+            variables: KeyedVec::default(),
+        }
+    }
+}
+
+impl<Var> LocalIdMap<Var> {
+    /// Returns the number of variables which are registered in the map.
+    pub(crate) fn len(&self) -> usize {
+        self.variables.len()
+    }
+
+    /// Returns the variable registered with the provided [`LocalId`], or [`None`] if the id was
+    /// not handed out by this map.
+    pub(crate) fn get(&self, local_id: LocalId) -> Option<&Var> {
+        if local_id.index() < self.len() {
+            Some(&self.variables[local_id])
+        } else {
+            None
+        }
+    }
+
+    /// Adds the variable to the map, returning the [`LocalId`] which it is registered with.
+    ///
+    /// Note that this does not subscribe the propagator to any events; use
+    /// [`PropagatorInitialisationContext::register_mapped`] for that.
+    pub(crate) fn insert(&mut self, variable: Var) -> LocalId {
+        self.variables.push(variable)
+    }
+}
+
+impl<Var> Index<LocalId> for LocalIdMap<Var> {
+    type Output = Var;
+
+    fn index(&self, local_id: LocalId) -> &Self::Output {
+        self.get(local_id).unwrap_or_else(|| {
+            panic!(
+                "local id {local_id} was not registered in this map, which contains {} variables",
+                self.len()
+            )
+        })
+    }
+}
+
+impl StorageKey for LocalId {
+    fn index(&self) -> usize {
+        self.unpack() as usize
+    }
+
+    fn create_from_index(index: usize) -> Self {
+        LocalId::from(index as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::propagation::PropagatorId;
+    use crate::engine::propagation::PropagatorInitialisationContext;
+    use crate::engine::AssignmentsInteger;
+    use crate::engine::AssignmentsPropositional;
+    use crate::engine::DomainEvents;
+    use crate::engine::WatchListCP;
+    use crate::engine::WatchListPropositional;
+
+    #[test]
+    fn ids_are_handed_out_densely() {
+        let mut map = LocalIdMap::default();
+
+        assert_eq!(map.insert(10), LocalId::from(0));
+        assert_eq!(map.insert(20), LocalId::from(1));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[LocalId::from(0)], 10);
+        assert_eq!(map[LocalId::from(1)], 20);
+    }
+
+    #[test]
+    fn unregistered_ids_are_not_found() {
+        let mut map = LocalIdMap::default();
+        let _ = map.insert(10);
+
+        assert_eq!(map.get(LocalId::from(1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "local id 3 was not registered in this map")]
+    fn indexing_with_unregistered_id_panics() {
+        let map: LocalIdMap<i32> = LocalIdMap::default();
+
+        let _ = map[LocalId::from(3)];
+    }
+
+    #[test]
+    fn registering_through_the_context_fills_the_map() {
+        let mut assignments_integer = AssignmentsInteger::default();
+        let assignments_propositional = AssignmentsPropositional::default();
+        let mut watch_list = WatchListCP::default();
+        let mut watch_list_propositional = WatchListPropositional::default();
+
+        let x = assignments_integer.grow(0, 10);
+        let y = assignments_integer.grow(0, 10);
+        watch_list.grow();
+        watch_list.grow();
+
+        let mut map = LocalIdMap::default();
+        let mut context = PropagatorInitialisationContext::new(
+            &mut watch_list,
+            &mut watch_list_propositional,
+            PropagatorId(0),
+            &assignments_integer,
+            &assignments_propositional,
+        );
+
+        let id_x = context.register_mapped(&mut map, x, DomainEvents::BOUNDS);
+        let id_y = context.register_mapped(&mut map, y, DomainEvents::BOUNDS);
+
+        assert_eq!(map[id_x], x);
+        assert_eq!(map[id_y], y);
+        assert_eq!(context.get_next_local_id(), LocalId::from(2));
+    }
+}
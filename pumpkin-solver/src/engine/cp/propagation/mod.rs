@@ -76,7 +76,10 @@
 //! International Workshop on Constraint Solving and Constraint Logic Programming, 2005, pp.
 //! 118–132.
 
+pub(crate) mod domain_snapshot;
 pub(crate) mod local_id;
+pub(crate) mod local_id_map;
+pub(crate) mod pending_constraints;
 pub(crate) mod propagation_context;
 pub(crate) mod propagator;
 pub(crate) mod propagator_id;
@@ -84,11 +87,15 @@ pub(crate) mod propagator_initialisation_context;
 pub(crate) mod propagator_var_id;
 pub(crate) mod store;
 
+pub use domain_snapshot::DomainSnapshotAt;
 pub(crate) use local_id::LocalId;
+pub(crate) use local_id_map::LocalIdMap;
+pub(crate) use pending_constraints::PendingConstraints;
 pub(crate) use propagation_context::PropagationContext;
 pub(crate) use propagation_context::PropagationContextMut;
 pub(crate) use propagation_context::ReadDomains;
 pub(crate) use propagator::EnqueueDecision;
+pub(crate) use propagator::Priority;
 pub(crate) use propagator::Propagator;
 pub(crate) use propagator_id::PropagatorId;
 pub(crate) use propagator_initialisation_context::PropagatorInitialisationContext;
@@ -0,0 +1,45 @@
+use std::fmt::Debug;
+
+use super::Propagator;
+#[cfg(doc)]
+use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::DebugDyn;
+
+/// Holds the constraints which propagators have requested to be added to the solver (see
+/// [`PropagationContextMut::post_at_next_restart`]).
+///
+/// Since propagators can only be added at the root, the [`ConstraintSatisfactionSolver`] adds
+/// these constraints the next time that it is at the root level; i.e. upon the next restart or at
+/// the start of the next call to solve.
+///
+/// [`PropagationContextMut::post_at_next_restart`]: super::PropagationContextMut::post_at_next_restart
+#[derive(Default)]
+pub(crate) struct PendingConstraints {
+    propagators: Vec<Box<dyn Propagator>>,
+}
+
+impl PendingConstraints {
+    pub(crate) fn push(&mut self, propagator: Box<dyn Propagator>) {
+        self.propagators.push(propagator);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.propagators.is_empty()
+    }
+
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = Box<dyn Propagator>> + '_ {
+        self.propagators.drain(..)
+    }
+}
+
+impl Debug for PendingConstraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let propagators: Vec<_> = self
+            .propagators
+            .iter()
+            .map(|_| DebugDyn::from("Propagator"))
+            .collect();
+
+        write!(f, "{propagators:?}")
+    }
+}
@@ -1,14 +1,25 @@
+use super::LocalId;
+use super::PendingConstraints;
 use super::PropagatorId;
+use super::PropagatorVarId;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::Inconsistency;
 use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::Propagator;
+#[cfg(doc)]
+use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::reason::Reason;
 use crate::engine::reason::ReasonStore;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
 use crate::engine::AssignmentsInteger;
 use crate::engine::AssignmentsPropositional;
+use crate::engine::DomainEvents;
 use crate::engine::EmptyDomain;
+use crate::engine::WatchListCP;
+use crate::engine::WatchListPropositional;
+use crate::engine::Watchers;
+use crate::engine::WatchersPropositional;
 use crate::pumpkin_assert_simple;
 
 /// [`PropagationContext`] is passed to propagators during propagation.
@@ -45,6 +56,13 @@ pub struct PropagationContextMut<'a> {
     propagator: PropagatorId,
 
     reification_literal: Option<Literal>,
+    /// The watch lists which allow the propagator to subscribe to additional variables during
+    /// propagation; see [`PropagationContextMut::with_watch_lists`].
+    watch_list: Option<&'a mut WatchListCP>,
+    watch_list_propositional: Option<&'a mut WatchListPropositional>,
+    /// The constraints which the propagator requests to be added to the solver; see
+    /// [`PropagationContextMut::post_at_next_restart`].
+    pending_constraints: Option<&'a mut PendingConstraints>,
 }
 
 impl<'a> PropagationContextMut<'a> {
@@ -60,9 +78,130 @@ impl<'a> PropagationContextMut<'a> {
             assignments_propositional,
             propagator,
             reification_literal: None,
+            watch_list: None,
+            watch_list_propositional: None,
+            pending_constraints: None,
         }
     }
 
+    /// Provide the buffer of constraints which are added to the solver upon the next restart,
+    /// which allows the propagator to generate new constraints during search (see
+    /// [`PropagationContextMut::post_at_next_restart`]).
+    pub(crate) fn with_pending_constraints(
+        &mut self,
+        pending_constraints: &'a mut PendingConstraints,
+    ) {
+        self.pending_constraints = Some(pending_constraints);
+    }
+
+    /// Requests the solver to add the provided constraint (in the form of a [`Propagator`]) the
+    /// next time that it is at the root level; i.e. upon the next restart or at the start of the
+    /// next call to solve. This allows propagators to lazily generate constraints during search,
+    /// e.g. cuts which are only discovered once a (partial) assignment violates them.
+    ///
+    /// Note that the constraint will not be taken into account before it is added; if the
+    /// current partial assignment violates it, the propagator should still explain the conflict
+    /// itself. Since the posted constraint is not reified, this method cannot be used by a
+    /// propagator which is reified.
+    pub fn post_at_next_restart(&mut self, propagator: impl Propagator + 'static) {
+        pumpkin_assert_simple!(
+            self.reification_literal.is_none(),
+            "cannot post constraints from a reified propagation context"
+        );
+
+        self.pending_constraints
+            .as_deref_mut()
+            .expect("posting constraints during propagation requires the pending constraints")
+            .push(Box::new(propagator));
+    }
+
+    /// Provide the watch lists of the solver to the context, which allows the propagator to
+    /// register for events on additional variables while it is propagating (see
+    /// [`PropagationContextMut::register`]).
+    pub(crate) fn with_watch_lists(
+        &mut self,
+        watch_list: &'a mut WatchListCP,
+        watch_list_propositional: &'a mut WatchListPropositional,
+    ) {
+        self.watch_list = Some(watch_list);
+        self.watch_list_propositional = Some(watch_list_propositional);
+    }
+
+    /// Subscribes the propagator to the given [`DomainEvents`] of a variable which it was not
+    /// registered to during [`Propagator::initialise_at_root`]; e.g. because the variable only
+    /// becomes relevant to the propagator during search.
+    ///
+    /// The registration is permanent (i.e. it is not undone upon backtracking). The same
+    /// requirements on the [`LocalId`] hold as for [`PropagatorInitialisationContext::register`];
+    /// in particular, the [`LocalId`] should not clash with the ids of previously registered
+    /// variables.
+    pub fn register<Var: IntegerVariable>(
+        &mut self,
+        var: Var,
+        domain_events: DomainEvents,
+        local_id: LocalId,
+    ) -> Var {
+        let propagator_var = PropagatorVarId {
+            propagator: self.propagator,
+            variable: local_id,
+        };
+
+        let watch_list = self
+            .watch_list
+            .as_deref_mut()
+            .expect("registering variables during propagation requires access to the watch list");
+        let mut watchers = Watchers::new(propagator_var, watch_list);
+        var.watch_all(&mut watchers, domain_events.get_int_events());
+
+        var
+    }
+
+    /// Subscribes the propagator to the given [`DomainEvents`] of a variable when they are undone
+    /// during backtracking. This is the counterpart of
+    /// [`PropagatorInitialisationContext::register_for_backtrack_events`] during propagation.
+    pub fn register_for_backtrack_events<Var: IntegerVariable>(
+        &mut self,
+        var: Var,
+        domain_events: DomainEvents,
+        local_id: LocalId,
+    ) -> Var {
+        let propagator_var = PropagatorVarId {
+            propagator: self.propagator,
+            variable: local_id,
+        };
+
+        let watch_list = self
+            .watch_list
+            .as_deref_mut()
+            .expect("registering variables during propagation requires access to the watch list");
+        let mut watchers = Watchers::new(propagator_var, watch_list);
+        var.watch_all_backtrack(&mut watchers, domain_events.get_int_events());
+
+        var
+    }
+
+    /// Subscribes the propagator to the given [`DomainEvents`] of a literal. This is the
+    /// counterpart of [`PropagatorInitialisationContext::register_literal`] during propagation.
+    pub fn register_literal(
+        &mut self,
+        var: Literal,
+        domain_events: DomainEvents,
+        local_id: LocalId,
+    ) -> Literal {
+        let propagator_var = PropagatorVarId {
+            propagator: self.propagator,
+            variable: local_id,
+        };
+
+        let watch_list_propositional = self.watch_list_propositional.as_deref_mut().expect(
+            "registering literals during propagation requires access to the propositional watch list",
+        );
+        let mut watchers = WatchersPropositional::new(propagator_var, watch_list_propositional);
+        watchers.watch_all(var, domain_events.get_bool_events());
+
+        var
+    }
+
     /// Apply a reification literal to all the explanations that are passed to the context.
     pub(crate) fn with_reification(&mut self, reification_literal: Literal) {
         pumpkin_assert_simple!(
@@ -163,10 +302,28 @@ pub(crate) trait ReadDomains: HasAssignments {
         var.upper_bound(self.assignments_integer())
     }
 
+    /// Returns the bounds of the given variable as `i64`s; see [`IntegerVariable::bounds_i64`].
+    fn bounds_i64<Var: IntegerVariable>(&self, var: &Var) -> (i64, i64) {
+        var.bounds_i64(self.assignments_integer())
+    }
+
+    /// Returns `true` if a bound of the given variable does not fit in an `i32`, which can be the
+    /// case for views.
+    fn bounds_overflow<Var: IntegerVariable>(&self, var: &Var) -> bool {
+        let (lower_bound, upper_bound) = self.bounds_i64(var);
+        i32::try_from(lower_bound).is_err() || i32::try_from(upper_bound).is_err()
+    }
+
     fn contains<Var: IntegerVariable>(&self, var: &Var, value: i32) -> bool {
         var.contains(self.assignments_integer(), value)
     }
 
+    /// Returns the number of values in the domain of the given variable; see
+    /// [`IntegerVariable::size`].
+    fn size<Var: IntegerVariable>(&self, var: &Var) -> usize {
+        var.size(self.assignments_integer())
+    }
+
     fn describe_domain<Var: IntegerVariable>(&self, var: &Var) -> Vec<Predicate> {
         var.describe_domain(self.assignments_integer())
     }
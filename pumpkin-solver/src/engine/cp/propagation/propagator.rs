@@ -11,14 +11,19 @@ use crate::engine::propagation::propagation_context::PropagationContextMut;
 use crate::engine::BooleanDomainEvent;
 #[cfg(doc)]
 use crate::engine::ConstraintSatisfactionSolver;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
 use crate::predicates::PropositionalConjunction;
 #[cfg(doc)]
 use crate::propagators::clausal::BasicClausalPropagator;
+use crate::pumpkin_assert_simple;
 #[cfg(doc)]
 use crate::pumpkin_asserts::PUMPKIN_ASSERT_ADVANCED;
 #[cfg(doc)]
 use crate::pumpkin_asserts::PUMPKIN_ASSERT_EXTREME;
 use crate::statistics::statistic_logger::StatisticLogger;
+#[cfg(doc)]
+use crate::Solver;
 
 /// All propagators implement the [`Propagator`] trait, with the exception of the
 /// clausal propagator. Structs implementing the trait defines the main propagator logic with
@@ -32,8 +37,22 @@ use crate::statistics::statistic_logger::StatisticLogger;
 /// See the [`crate::engine::cp::propagation`] documentation for more details.
 pub trait Propagator {
     /// Return the name of the propagator, this is a convenience method that is used for printing.
+    ///
+    /// Propagators which wrap another propagator are expected to compose their name with the name
+    /// of the wrapped propagator (e.g. `"Reified(<inner>)"`).
     fn name(&self) -> &str;
 
+    /// Returns the constraint which is enforced by the propagator in a readable algebraic syntax
+    /// (e.g. `2 * (x1) + x2 <= 5`), which is used when the model is displayed (see
+    /// [`Solver::dump_pretty`]). Variables should be displayed with their [`Debug`]
+    /// implementation, so that they can be replaced by their names.
+    ///
+    /// By default [`None`] is returned, in which case the propagator is displayed as its name
+    /// applied to the variables which it watches (e.g. `AllDifferentRegin(x1, x2, x3)`).
+    fn describe(&self) -> Option<String> {
+        None
+    }
+
     /// A propagation method that is used to help debugging.
     ///
     /// This method propagates without relying on internal data structures, hence the immutable
@@ -132,19 +151,34 @@ pub trait Propagator {
     /// By default this function does nothing.
     fn synchronise(&mut self, _context: PropagationContext) {}
 
-    /// Returns the priority of the propagator represented as an integer. Lower values mean higher
-    /// priority and the priority determines the order in which propagators will be asked to
-    /// propagate.
+    /// Returns whether the last call to [`Propagator::propagate`] stopped before the propagator
+    /// reached its fixed point, e.g. because it exceeded its work budget.
+    ///
+    /// This allows expensive propagators to split their propagation into chunks (cooperative
+    /// chunking); a propagator keeps track of its own work budget per call, and once it is
+    /// exceeded, it returns from [`Propagator::propagate`] with pending work. The
+    /// [`ConstraintSatisfactionSolver`] then enqueues the propagator again, such that it is resumed
+    /// in a later iteration of the propagation loop (after the propagators with a higher priority
+    /// have been called). Note that any pending work should be discarded in
+    /// [`Propagator::synchronise`].
+    ///
+    /// By default, propagators always propagate to their fixed point in a single call.
+    fn has_pending_work(&self) -> bool {
+        false
+    }
+
+    /// Returns the [`Priority`] of the propagator, which determines the order in which propagators
+    /// will be asked to propagate.
     ///
-    /// In other words, after the [`BasicClausalPropagator`] has propagated, propagators
-    /// with lower priority values are called before those with higher priority. It is custom
-    /// for simpler propagators to have lower priority values
+    /// In other words, after the [`BasicClausalPropagator`] has propagated, the enqueued
+    /// propagators are called in order of their priority class; e.g. all enqueued
+    /// [`Priority::Unit`] propagators are called before any [`Priority::Linear`] propagator. It is
+    /// custom for simpler (i.e. cheaper) propagators to have a higher priority.
     ///
-    /// By default the priority is set to 3. It is expected that propagator implementations would
-    /// set this value to some appropriate value.
-    fn priority(&self) -> u32 {
-        // setting an arbitrary priority by default
-        3
+    /// By default the priority is set to [`Priority::ExpensiveGlobal`]. It is expected that
+    /// propagator implementations would set this value to some appropriate value.
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
     }
 
     /// Initialises the propagator without performing propagation. This method is called only once
@@ -174,12 +208,63 @@ pub trait Propagator {
         None
     }
 
+    /// Returns whether the arithmetic performed by the propagator may overflow an `i32` given the
+    /// current domains.
+    ///
+    /// This is called at the root by the [`ConstraintSatisfactionSolver`] when the propagator is
+    /// added and checked arithmetic is enabled (see
+    /// [`SatisfactionSolverOptions::checked_arithmetic`]); since domains only shrink during search,
+    /// a propagator for which this returns `false` can safely use unchecked arithmetic.
+    /// Propagators which perform arithmetic on the bounds of their variables should implement it
+    /// conservatively. Note that the bounds of a view can themselves overflow (see
+    /// [`ReadDomains::bounds_overflow`](crate::engine::propagation::ReadDomains::bounds_overflow)),
+    /// so every propagator over integer variables should implement it, even if it does not
+    /// perform any arithmetic itself.
+    ///
+    /// By default, propagators are assumed not to overflow; this is only correct for propagators
+    /// which do not read the bounds of integer variables (e.g. propagators over literals).
+    fn may_overflow(&self, _context: PropagationContext) -> bool {
+        false
+    }
+
     /// Logs statistics of the propagator using the provided [`StatisticLogger`].
     ///
     /// It is recommended to create a struct through the [`create_statistics_struct!`] macro!
+    /// Propagators which wrap another propagator should forward this call to the wrapped
+    /// propagator.
     fn log_statistics(&self, _statistic_logger: StatisticLogger) {}
 }
 
+/// The priority class of a propagator, see [`Propagator::priority`]. The classes are ordered from
+/// the highest priority (i.e. called first) to the lowest priority; within a class, propagators are
+/// called in the order in which they were enqueued.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Propagators over a fixed (small) number of variables which can propagate in (amortised)
+    /// constant time; e.g. binary arithmetic constraints.
+    Unit = 0,
+    /// Propagators whose propagation takes time linear in the number of variables; e.g. linear
+    /// (in)equalities.
+    Linear = 1,
+    /// Global propagators with relatively cheap propagation; e.g. element.
+    CheapGlobal = 2,
+    /// Global propagators with expensive propagation; e.g. cumulative.
+    ExpensiveGlobal = 3,
+}
+
+impl Priority {
+    /// The number of priority classes.
+    pub(crate) const NUM_PRIORITIES: usize = 4;
+
+    /// Returns the index of the priority class, where lower indices correspond to higher
+    /// priorities.
+    pub(crate) fn index(self) -> usize {
+        let index = self as usize;
+        pumpkin_assert_simple!(index < Priority::NUM_PRIORITIES);
+        index
+    }
+}
+
 /// Indicator of what to do when a propagator is notified.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EnqueueDecision {
@@ -2,6 +2,7 @@ use super::propagation_context::HasAssignments;
 use super::PropagationContext;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::LocalIdMap;
 #[cfg(doc)]
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorId;
@@ -114,6 +115,24 @@ impl PropagatorInitialisationContext<'_> {
         var
     }
 
+    /// Subscribes the propagator to the given [`DomainEvents`] of `var`, using a [`LocalId`]
+    /// handed out by the provided [`LocalIdMap`].
+    ///
+    /// This is the bounds-checked alternative to [`PropagatorInitialisationContext::register`];
+    /// rather than choosing the [`LocalId`] itself, the propagator stores `var` in `local_id_map`
+    /// and can later retrieve it with the [`LocalId`] passed to [`Propagator::notify()`].
+    pub(crate) fn register_mapped<Var: IntegerVariable + Clone>(
+        &mut self,
+        local_id_map: &mut LocalIdMap<Var>,
+        var: Var,
+        domain_events: DomainEvents,
+    ) -> LocalId {
+        let local_id = local_id_map.insert(var.clone());
+        let _ = self.register(var, domain_events, local_id);
+
+        local_id
+    }
+
     pub fn register_literal(
         &mut self,
         var: Literal,
@@ -30,6 +30,17 @@ impl PropagatorStore {
         id
     }
 
+    pub(crate) fn num_propagators(&self) -> usize {
+        self.propagators.len()
+    }
+
+    /// Removes the propagators with an identifier of at least `num_propagators`, i.e. the
+    /// propagators which were added after the store contained `num_propagators` propagators.
+    pub(crate) fn truncate(&mut self, num_propagators: usize) {
+        self.propagators.truncate(num_propagators);
+        self.tags.truncate(num_propagators);
+    }
+
     pub(crate) fn get_tag(&self, propagator_id: PropagatorId) -> Option<NonZero<u32>> {
         self.tags[propagator_id]
     }
@@ -2,51 +2,61 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::VecDeque;
 
-use crate::basic_types::HashSet;
+use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::cp::propagation::Priority;
 use crate::engine::cp::propagation::PropagatorId;
 use crate::pumpkin_assert_moderate;
 
+/// The queue of propagators which should be called, where propagators are popped in order of their
+/// [`Priority`] and, within the same priority class, in the order in which they were enqueued.
 #[derive(Debug)]
 pub(crate) struct PropagatorQueue {
+    /// A FIFO queue for every [`Priority`] class, indexed by [`Priority::index`].
     queues: Vec<VecDeque<PropagatorId>>,
-    present_propagators: HashSet<PropagatorId>,
-    present_priorities: BinaryHeap<Reverse<u32>>,
+    /// Indicates for every propagator whether it is currently enqueued; since propagator ids are
+    /// dense, this is cheaper than a hash-based set.
+    is_enqueued: KeyedVec<PropagatorId, bool>,
+    num_enqueued: usize,
+    present_priorities: BinaryHeap<Reverse<Priority>>,
 }
 
 impl PropagatorQueue {
-    pub(crate) fn new(num_priority_levels: u32) -> PropagatorQueue {
+    pub(crate) fn new() -> PropagatorQueue {
         PropagatorQueue {
-            queues: vec![VecDeque::new(); num_priority_levels as usize],
-            present_propagators: HashSet::default(),
+            queues: vec![VecDeque::new(); Priority::NUM_PRIORITIES],
+            is_enqueued: KeyedVec::default(),
+            num_enqueued: 0,
             present_priorities: BinaryHeap::new(),
         }
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.present_propagators.is_empty()
+        self.num_enqueued == 0
     }
 
-    pub(crate) fn enqueue_propagator(&mut self, propagator_id: PropagatorId, priority: u32) {
-        pumpkin_assert_moderate!((priority as usize) < self.queues.len());
-
+    pub(crate) fn enqueue_propagator(&mut self, propagator_id: PropagatorId, priority: Priority) {
         if !self.is_propagator_enqueued(propagator_id) {
-            if self.queues[priority as usize].is_empty() {
+            if self.queues[priority.index()].is_empty() {
                 self.present_priorities.push(Reverse(priority));
             }
-            self.queues[priority as usize].push_back(propagator_id);
-            let _ = self.present_propagators.insert(propagator_id);
+            self.queues[priority.index()].push_back(propagator_id);
+            self.is_enqueued.accomodate(propagator_id, false);
+            self.is_enqueued[propagator_id] = true;
+            self.num_enqueued += 1;
         }
     }
 
     pub(crate) fn pop(&mut self) -> PropagatorId {
         pumpkin_assert_moderate!(!self.is_empty());
 
-        let top_priority = self.present_priorities.peek().unwrap().0 as usize;
+        let top_priority = self.present_priorities.peek().unwrap().0.index();
         pumpkin_assert_moderate!(!self.queues[top_priority].is_empty());
 
         let next_propagator_id = self.queues[top_priority].pop_front().unwrap();
 
-        let _ = self.present_propagators.remove(&next_propagator_id);
+        self.is_enqueued[next_propagator_id] = false;
+        self.num_enqueued -= 1;
 
         if self.queues[top_priority].is_empty() {
             let _ = self.present_priorities.pop();
@@ -57,15 +67,49 @@ impl PropagatorQueue {
 
     pub(crate) fn clear(&mut self) {
         while !self.present_priorities.is_empty() {
-            let priority = self.present_priorities.pop().unwrap().0 as usize;
+            let priority = self.present_priorities.pop().unwrap().0.index();
             pumpkin_assert_moderate!(!self.queues[priority].is_empty());
-            self.queues[priority].clear();
+            for propagator_id in self.queues[priority].drain(..) {
+                self.is_enqueued[propagator_id] = false;
+            }
         }
-        self.present_propagators.clear();
+        self.num_enqueued = 0;
         self.present_priorities.clear();
     }
 
     fn is_propagator_enqueued(&self, propagator_id: PropagatorId) -> bool {
-        self.present_propagators.contains(&propagator_id)
+        propagator_id.index() < self.is_enqueued.len() && self.is_enqueued[propagator_id]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn propagators_are_popped_in_order_of_priority() {
+        let mut queue = PropagatorQueue::new();
+
+        queue.enqueue_propagator(PropagatorId(0), Priority::ExpensiveGlobal);
+        queue.enqueue_propagator(PropagatorId(1), Priority::Linear);
+        queue.enqueue_propagator(PropagatorId(2), Priority::CheapGlobal);
+        queue.enqueue_propagator(PropagatorId(3), Priority::Unit);
+        queue.enqueue_propagator(PropagatorId(4), Priority::Linear);
+        // Enqueueing an already enqueued propagator has no effect
+        queue.enqueue_propagator(PropagatorId(1), Priority::Linear);
+
+        let popped =
+            std::iter::from_fn(|| (!queue.is_empty()).then(|| queue.pop())).collect::<Vec<_>>();
+
+        assert_eq!(
+            popped,
+            vec![
+                PropagatorId(3),
+                PropagatorId(1),
+                PropagatorId(4),
+                PropagatorId(2),
+                PropagatorId(0)
+            ]
+        );
     }
 }
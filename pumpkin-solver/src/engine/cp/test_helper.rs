@@ -24,6 +24,7 @@ use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
 use crate::engine::AssignmentsInteger;
 use crate::engine::AssignmentsPropositional;
+use crate::engine::BooleanDomainEvent;
 use crate::engine::DomainEvents;
 use crate::engine::EmptyDomain;
 use crate::engine::WatchListCP;
@@ -37,6 +38,8 @@ pub(crate) struct TestSolver {
     watch_list: WatchListCP,
     watch_list_propositional: WatchListPropositional,
     next_id: u32,
+    /// The number of entries on the propositional trail of which the propagators were notified.
+    notified_propositional_trail_entries: usize,
 }
 
 type BoxedPropagator = Box<dyn Propagator>;
@@ -70,6 +73,10 @@ impl TestSolver {
 
         let mut propagator: Box<dyn Propagator> = Box::new(propagator);
 
+        // The propagator is initialised with the current assignment, so it should not be notified
+        // of the literals which are already assigned
+        self.notified_propositional_trail_entries =
+            self.assignments_propositional.num_trail_entries();
         propagator.initialise_at_root(&mut PropagatorInitialisationContext::new(
             &mut self.watch_list,
             &mut self.watch_list_propositional,
@@ -153,6 +160,10 @@ impl TestSolver {
             .is_literal_assigned_false(var)
     }
 
+    pub(crate) fn is_literal_true(&self, var: Literal) -> bool {
+        self.assignments_propositional.is_literal_assigned_true(var)
+    }
+
     pub(crate) fn upper_bound(&self, var: DomainId) -> i32 {
         self.assignments_integer.get_upper_bound(var)
     }
@@ -163,12 +174,13 @@ impl TestSolver {
     }
 
     pub(crate) fn propagate(&mut self, propagator: &mut BoxedPropagator) -> PropagationStatusCP {
-        let context = PropagationContextMut::new(
+        let mut context = PropagationContextMut::new(
             &mut self.assignments_integer,
             &mut self.reason_store,
             &mut self.assignments_propositional,
             PropagatorId(0),
         );
+        context.with_watch_lists(&mut self.watch_list, &mut self.watch_list_propositional);
         propagator.propagate(context)
     }
 
@@ -182,12 +194,13 @@ impl TestSolver {
         loop {
             {
                 // Specify the life-times to be able to retrieve the trail entries
-                let context = PropagationContextMut::new(
+                let mut context = PropagationContextMut::new(
                     &mut self.assignments_integer,
                     &mut self.reason_store,
                     &mut self.assignments_propositional,
                     PropagatorId(0),
                 );
+                context.with_watch_lists(&mut self.watch_list, &mut self.watch_list_propositional);
                 propagator.propagate(context)?;
                 self.notify_propagator(propagator);
             }
@@ -215,6 +228,22 @@ impl TestSolver {
                 let _ = propagator.notify(context, propagator_var.variable, event.into());
             }
         }
+
+        for trail_index in self.notified_propositional_trail_entries
+            ..self.assignments_propositional.num_trail_entries()
+        {
+            let literal = self.assignments_propositional.get_trail_entry(trail_index);
+            for (event, affected_literal) in BooleanDomainEvent::get_iterator(literal) {
+                for propagator_var in self
+                    .watch_list_propositional
+                    .get_affected_propagators(event, affected_literal)
+                {
+                    let _ = propagator.notify_literal(context, propagator_var.variable, event);
+                }
+            }
+        }
+        self.notified_propositional_trail_entries =
+            self.assignments_propositional.num_trail_entries();
     }
 
     pub(crate) fn get_reason_int(
@@ -244,6 +273,35 @@ impl TestSolver {
             .expect("reason_ref should not be stale")
     }
 
+    /// Returns a trace of the changes to the integer domains in the order in which they were made.
+    /// Every line contains the predicate which was posted together with its reason, or
+    /// `decision` if it was not posted by a propagator.
+    pub(crate) fn propagation_trace(&mut self) -> String {
+        let mut trace = String::new();
+
+        for trail_index in 0..self.assignments_integer.num_trail_entries() {
+            let entry = self.assignments_integer.get_trail_entry(trail_index);
+
+            let reason = match entry.reason {
+                Some(reason_ref) => {
+                    let context = PropagationContext::new(
+                        &self.assignments_integer,
+                        &self.assignments_propositional,
+                    );
+                    self.reason_store
+                        .get_or_compute(reason_ref, context)
+                        .expect("reason_ref should not be stale")
+                        .to_string()
+                }
+                None => "decision".to_owned(),
+            };
+
+            trace.push_str(&format!("{} <- {reason}\n", entry.predicate));
+        }
+
+        trace
+    }
+
     pub(crate) fn assert_bounds(&self, var: DomainId, lb: i32, ub: i32) {
         let actual_lb = self.lower_bound(var);
         let actual_ub = self.upper_bound(var);
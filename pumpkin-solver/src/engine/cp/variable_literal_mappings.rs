@@ -86,6 +86,28 @@ impl VariableLiteralMappings {
         PropositionalVariable::new(new_variable_index)
     }
 
+    /// Reserves space for at least `additional` more propositional variables in all of the
+    /// structures which are grown by
+    /// [`VariableLiteralMappings::create_new_propositional_variable`].
+    fn reserve_propositional_variables(
+        &mut self,
+        additional: usize,
+        watch_list_propositional: &mut WatchListPropositional,
+        clausal_propagator: &mut ClausalPropagatorType,
+        assignments_propositional: &mut AssignmentsPropositional,
+    ) {
+        clausal_propagator.reserve(additional);
+        watch_list_propositional.reserve(additional);
+        assignments_propositional.reserve(additional);
+        self.literal_to_predicates.reserve(2 * additional);
+    }
+
+    /// Reserves capacity for the literals of at least `additional` more domains.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.domain_to_equality_literals.reserve(additional);
+        self.domain_to_lower_bound_literals.reserve(additional);
+    }
+
     /// Create a new integer variable and tie it to a fresh propositional representation. The given
     /// clausal propagator will be responsible for keeping the propositional representation
     /// consistent.
@@ -132,6 +154,19 @@ impl VariableLiteralMappings {
         assignments_propositional: &mut AssignmentsPropositional,
         clause_allocator: &mut ClauseAllocator,
     ) {
+        // Both the lower-bound and the equality literals require (roughly) one new propositional
+        // variable per value in the domain
+        let domain_size = assignments_integer
+            .get_upper_bound(domain_id)
+            .abs_diff(assignments_integer.get_lower_bound(domain_id))
+            as usize;
+        self.reserve_propositional_variables(
+            2 * domain_size,
+            watch_list_propositional,
+            clausal_propagator,
+            assignments_propositional,
+        );
+
         let lower_bound_literals = self.create_lower_bound_literals(
             domain_id,
             assignments_integer,
@@ -1,7 +1,10 @@
 use enumset::EnumSet;
 use enumset::EnumSetType;
+use itertools::Itertools;
 
 use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorVarId;
 use crate::engine::variables::DomainId;
 
@@ -10,6 +13,9 @@ pub(crate) struct WatchListCP {
     watchers: KeyedVec<DomainId, WatcherCP>, /* contains propagator ids of propagators that
                                               * watch domain changes of the i-th integer
                                               * variable */
+    /// The domains for which each propagator watches (forward) events, in the order in which the
+    /// propagator registered for them.
+    scopes: KeyedVec<PropagatorId, Vec<DomainId>>,
     is_watching_anything: bool,
     is_watching_any_backtrack_events: bool,
 }
@@ -42,6 +48,19 @@ impl WatchListCP {
         let _ = self.watchers.push(WatcherCP::default());
     }
 
+    /// Reserves capacity for at least `additional` more domains.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.watchers.reserve(additional)
+    }
+
+    /// Ensures that the watch list has an entry for the provided domain; this allows propagators
+    /// to register for variables which the watch list has not been explicitly grown for.
+    fn accomodate(&mut self, domain: DomainId) {
+        while self.watchers.len() <= domain.index() {
+            self.grow();
+        }
+    }
+
     pub(crate) fn is_watching_anything(&self) -> bool {
         self.is_watching_anything
     }
@@ -50,6 +69,59 @@ impl WatchListCP {
         self.is_watching_any_backtrack_events
     }
 
+    /// Returns the number of distinct propagators which watch (forward) events of the provided
+    /// domain.
+    pub(crate) fn num_watching_propagators(&self, domain: DomainId) -> usize {
+        self.watching_propagators(domain).count()
+    }
+
+    /// Returns the distinct propagators which watch (forward) events of the provided domain.
+    pub(crate) fn watching_propagators(
+        &self,
+        domain: DomainId,
+    ) -> impl Iterator<Item = PropagatorId> + '_ {
+        let watcher =
+            (domain.index() < self.watchers.len()).then(|| &self.watchers[domain].forward_watcher);
+
+        watcher
+            .into_iter()
+            .flat_map(|watcher| {
+                watcher
+                    .assign_watchers
+                    .iter()
+                    .chain(watcher.lower_bound_watchers.iter())
+                    .chain(watcher.upper_bound_watchers.iter())
+                    .chain(watcher.removal_watchers.iter())
+            })
+            .map(|propagator_var| propagator_var.propagator)
+            .unique()
+    }
+
+    /// Returns the domains for which the provided propagator watches (forward) events.
+    pub(crate) fn scope(&self, propagator: PropagatorId) -> &[DomainId] {
+        if propagator.index() < self.scopes.len() {
+            &self.scopes[propagator]
+        } else {
+            &[]
+        }
+    }
+
+    /// Removes the watchers of the propagators with an identifier of at least `num_propagators`;
+    /// see [`PropagatorStore::truncate`].
+    ///
+    /// [`PropagatorStore::truncate`]: super::propagation::store::PropagatorStore::truncate
+    pub(crate) fn remove_propagators_from(&mut self, num_propagators: usize) {
+        for watcher in self.watchers.iter_mut() {
+            watcher
+                .forward_watcher
+                .retain(|propagator_var| propagator_var.propagator.index() < num_propagators);
+            watcher
+                .backtrack_watcher
+                .retain(|propagator_var| propagator_var.propagator.index() < num_propagators);
+        }
+        self.scopes.truncate(num_propagators);
+    }
+
     pub(crate) fn get_affected_propagators(
         &self,
         event: IntDomainEvent,
@@ -91,6 +163,19 @@ impl<'a> Watchers<'a> {
 
     pub(crate) fn watch_all(&mut self, domain: DomainId, events: EnumSet<IntDomainEvent>) {
         self.watch_list.is_watching_anything = true;
+        self.watch_list.accomodate(domain);
+
+        // A propagator registers for the events of a domain contiguously, so it already watches
+        // the domain if and only if it is the last watcher of any of its events
+        let propagator = self.propagator_var.propagator;
+        if !self.watch_list.watchers[domain]
+            .forward_watcher
+            .is_last_watched_by(propagator)
+        {
+            self.watch_list.scopes.accomodate(propagator, Vec::new());
+            self.watch_list.scopes[propagator].push(domain);
+        }
+
         let watcher = &mut self.watch_list.watchers[domain];
 
         for event in events {
@@ -113,6 +198,7 @@ impl<'a> Watchers<'a> {
         events: EnumSet<IntDomainEvent>,
     ) {
         self.watch_list.is_watching_any_backtrack_events = true;
+        self.watch_list.accomodate(domain);
         let watcher = &mut self.watch_list.watchers[domain];
 
         for event in events {
@@ -144,3 +230,29 @@ struct Watcher {
     assign_watchers: Vec<PropagatorVarId>,
     removal_watchers: Vec<PropagatorVarId>,
 }
+
+impl Watcher {
+    /// Returns whether the provided propagator is the last propagator which registered for any of
+    /// the events.
+    fn is_last_watched_by(&self, propagator: PropagatorId) -> bool {
+        [
+            &self.lower_bound_watchers,
+            &self.upper_bound_watchers,
+            &self.assign_watchers,
+            &self.removal_watchers,
+        ]
+        .into_iter()
+        .any(|watchers| {
+            watchers
+                .last()
+                .is_some_and(|propagator_var| propagator_var.propagator == propagator)
+        })
+    }
+
+    fn retain(&mut self, mut predicate: impl FnMut(&PropagatorVarId) -> bool) {
+        self.lower_bound_watchers.retain(&mut predicate);
+        self.upper_bound_watchers.retain(&mut predicate);
+        self.assign_watchers.retain(&mut predicate);
+        self.removal_watchers.retain(&mut predicate);
+    }
+}
@@ -2,6 +2,7 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::basic_types::KeyedVec;
+use crate::basic_types::StorageKey;
 use crate::engine::propagation::PropagatorVarId;
 use crate::engine::variables::Literal;
 
@@ -53,10 +54,38 @@ impl WatchListPropositional {
         let _ = self.watchers.push(WatcherPropositional::default());
     }
 
+    /// Reserves space for at least `additional` more propositional variables.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.watchers.reserve(2 * additional);
+    }
+
+    /// Ensures that the watch list has an entry for the provided literal; this allows propagators
+    /// to register for literals which the watch list has not been explicitly grown for.
+    fn accomodate(&mut self, literal: Literal) {
+        while self.watchers.len() <= literal.index() {
+            self.grow();
+        }
+    }
+
     pub(crate) fn is_watching_anything(&self) -> bool {
         self.is_watching_anything
     }
 
+    /// Removes the watchers of the propagators with an identifier of at least `num_propagators`;
+    /// see [`WatchListCP::remove_propagators_from`].
+    ///
+    /// [`WatchListCP::remove_propagators_from`]: super::WatchListCP::remove_propagators_from
+    pub(crate) fn remove_propagators_from(&mut self, num_propagators: usize) {
+        for watcher in self.watchers.iter_mut() {
+            watcher
+                .assigned_true_watchers
+                .retain(|propagator_var| propagator_var.propagator.index() < num_propagators);
+            watcher
+                .assigned_false_watchers
+                .retain(|propagator_var| propagator_var.propagator.index() < num_propagators);
+        }
+    }
+
     pub(crate) fn get_affected_propagators(
         &self,
         event: BooleanDomainEvent,
@@ -84,6 +113,7 @@ impl<'a> WatchersPropositional<'a> {
 
     pub(crate) fn watch_all(&mut self, domain: Literal, events: EnumSet<BooleanDomainEvent>) {
         self.watch_list.is_watching_anything = true;
+        self.watch_list.accomodate(domain);
         let watcher = &mut self.watch_list.watchers[domain];
 
         for event in events {
@@ -0,0 +1,184 @@
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::IntDomainEvent;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A single entry of an [`EventLog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoggedEvent {
+    /// The brancher decided on the provided predicate.
+    Decision(Predicate),
+    /// The provided domain event was processed by the propagators watching the domain.
+    DomainEvent {
+        domain: DomainId,
+        event: IntDomainEvent,
+    },
+    /// A conflict was encountered.
+    Conflict,
+    /// The solver restarted.
+    Restart,
+}
+
+/// A log of the decisions and domain events which occurred during search, in the order in which
+/// they occurred; see [`Solver::record_events`].
+///
+/// Given the same model and the same decisions, the solver should perform exactly the same
+/// propagations. The decisions in a log can be replayed against a fresh solver using a
+/// [`ReplayBrancher`], after which the two logs can be compared using
+/// [`EventLog::first_divergence`]; any difference points to nondeterminism in the solver (e.g.
+/// due to the iteration order of hash-based containers).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventLog {
+    events: Vec<LoggedEvent>,
+}
+
+impl EventLog {
+    pub(crate) fn push(&mut self, event: LoggedEvent) {
+        self.events.push(event);
+    }
+
+    /// Returns the recorded events in the order in which they occurred.
+    pub fn events(&self) -> &[LoggedEvent] {
+        &self.events
+    }
+
+    /// Returns the index of the first event at which `self` and `other` differ, or [`None`] if
+    /// the logs are identical.
+    pub fn first_divergence(&self, other: &EventLog) -> Option<usize> {
+        self.events
+            .iter()
+            .zip(other.events.iter())
+            .position(|(a, b)| a != b)
+            .or_else(|| {
+                (self.events.len() != other.events.len())
+                    .then_some(self.events.len().min(other.events.len()))
+            })
+    }
+}
+
+/// A [`Brancher`] which makes the decisions recorded in an [`EventLog`], in the same order; it
+/// also restarts at the same points in the search as the solver which recorded the log.
+///
+/// Note that the log should be replayed on a solver with the same model (i.e. the same variables
+/// and constraints, added in the same order) and with the same options as the solver which
+/// recorded it.
+#[derive(Debug)]
+pub struct ReplayBrancher {
+    /// The recorded decisions in reverse order, together with whether a restart took place before
+    /// the decision was made.
+    decisions: Vec<(Predicate, bool)>,
+}
+
+impl ReplayBrancher {
+    /// Creates a [`ReplayBrancher`] which replays the decisions (and restarts) in the provided
+    /// [`EventLog`].
+    pub fn new(log: &EventLog) -> Self {
+        let mut decisions = Vec::new();
+        let mut restart_before_decision = false;
+
+        for event in log.events() {
+            match event {
+                LoggedEvent::Decision(predicate) => {
+                    decisions.push((*predicate, restart_before_decision));
+                    restart_before_decision = false;
+                }
+                LoggedEvent::Restart => restart_before_decision = true,
+                LoggedEvent::DomainEvent { .. } | LoggedEvent::Conflict => {}
+            }
+        }
+
+        decisions.reverse();
+
+        ReplayBrancher { decisions }
+    }
+}
+
+impl Brancher for ReplayBrancher {
+    fn next_decision(&mut self, _context: &mut SelectionContext) -> Option<Predicate> {
+        self.decisions.pop().map(|(predicate, _)| predicate)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        !self
+            .decisions
+            .last()
+            .is_some_and(|&(_, restart_before_decision)| restart_before_decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::engine::termination::indefinite::Indefinite;
+    use crate::engine::variables::TransformableVariable;
+    use crate::engine::ConstraintSatisfactionSolver;
+    use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
+
+    fn create_pigeon_hole_instance(num_holes: i32) -> ConstraintSatisfactionSolver {
+        let mut solver = ConstraintSatisfactionSolver::default();
+
+        let pigeons = (0..=num_holes)
+            .map(|_| solver.create_new_integer_variable(1, num_holes, None))
+            .collect::<Vec<_>>();
+
+        for (index, &pigeon) in pigeons.iter().enumerate() {
+            for &other in &pigeons[index + 1..] {
+                let result = solver.add_propagator(
+                    LinearNotEqualPropagator::new(
+                        Box::new([pigeon.scaled(1), other.scaled(-1)]),
+                        0,
+                    ),
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+        }
+
+        solver
+    }
+
+    #[test]
+    fn replaying_a_log_results_in_the_same_log() {
+        let mut solver = create_pigeon_hole_instance(4);
+        solver.record_events();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        let log = solver.take_event_log().expect("events were recorded");
+
+        assert!(log
+            .events()
+            .iter()
+            .any(|event| matches!(event, LoggedEvent::Conflict)));
+
+        let mut replay_solver = create_pigeon_hole_instance(4);
+        replay_solver.record_events();
+        let flag = replay_solver.solve(&mut Indefinite, &mut ReplayBrancher::new(&log));
+        assert!(matches!(flag, CSPSolverExecutionFlag::Infeasible));
+        let replayed_log = replay_solver
+            .take_event_log()
+            .expect("events were recorded");
+
+        assert_eq!(log.first_divergence(&replayed_log), None);
+    }
+
+    #[test]
+    fn first_divergence_is_detected() {
+        let mut log = EventLog::default();
+        log.push(LoggedEvent::Conflict);
+        let mut other = log.clone();
+
+        log.push(LoggedEvent::Restart);
+        assert_eq!(log.first_divergence(&other), Some(1));
+
+        other.push(LoggedEvent::Conflict);
+        assert_eq!(log.first_divergence(&other), Some(1));
+
+        other = log.clone();
+        assert_eq!(log.first_divergence(&other), None);
+    }
+}
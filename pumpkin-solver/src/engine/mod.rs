@@ -2,17 +2,23 @@ pub(crate) mod conflict_analysis;
 pub(crate) mod constraint_satisfaction_solver;
 pub(crate) mod cp;
 mod debug_helper;
+pub(crate) mod event_log;
+pub(crate) mod model_statistics;
+pub(crate) mod nogood_exchange;
 pub(crate) mod predicates;
 mod preprocessor;
 pub(crate) mod proof;
 pub mod rp_engine;
 mod sat;
 mod solver_statistics;
+pub(crate) mod symmetry;
 pub(crate) mod termination;
 pub(crate) mod variables;
 
+pub use conflict_analysis::ImplicationGraphExport;
 pub(crate) use constraint_satisfaction_solver::ConstraintSatisfactionSolver;
 pub use constraint_satisfaction_solver::SatisfactionSolverOptions;
+pub use constraint_satisfaction_solver::SolutionPolishingOptions;
 pub(crate) use cp::VariableLiteralMappings;
 pub(crate) use cp::*;
 pub(crate) use debug_helper::DebugDyn;
@@ -20,3 +26,4 @@ pub(crate) use debug_helper::DebugHelper;
 pub(crate) use domain_events::DomainEvents;
 pub(crate) use preprocessor::Preprocessor;
 pub use sat::*;
+pub use solver_statistics::SearchStatistics;
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+#[cfg(doc)]
+use crate::Solver;
+
+/// A summary of the model in a [`Solver`], retrievable before solving through
+/// [`Solver::model_statistics`].
+///
+/// This can be used to select a configuration of the solver based on the characteristics of the
+/// model (e.g. by an automated portfolio selector).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModelStatistics {
+    /// The number of integer variables in the model.
+    pub num_integer_variables: usize,
+    /// The number of integer variables per domain size, in increasing order of domain size.
+    pub integer_variables_by_domain_size: BTreeMap<usize, usize>,
+    /// The number of propositional variables in the model; note that this includes the
+    /// propositional variables used for the encoding of the integer domains.
+    pub num_propositional_variables: usize,
+    /// The number of constraints (i.e. propagators) per type, identified by the name of the
+    /// propagator.
+    pub constraints_by_type: BTreeMap<String, usize>,
+    /// The number of clauses which have been added to the model.
+    pub num_clauses: usize,
+    /// The average number of constraints in which an integer variable occurs.
+    pub average_constraints_per_integer_variable: f64,
+    /// The fraction of integer variables which do not occur in any constraint.
+    pub fraction_unconstrained_integer_variables: f64,
+}
+
+impl ModelStatistics {
+    /// Returns the total number of constraints (i.e. propagators) in the model.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints_by_type.values().sum()
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
+
+/// A clause which was published to a [`NogoodExchange`]; it is represented as a disjunction of
+/// [`IntegerPredicate`]s since the literals of the workers do not necessarily coincide.
+#[derive(Clone, Debug)]
+struct SharedClause {
+    worker: usize,
+    predicates: Vec<IntegerPredicate>,
+}
+
+/// Allows the workers of a portfolio, which solve the same model, to share clauses with each
+/// other; every worker holds its own [`NogoodExchange`] but the published clauses are stored in a
+/// log which is shared between all workers.
+///
+/// A worker publishes its learned clauses with a sufficiently low LBD (see
+/// [`NogoodExchange::should_export`]) and imports the clauses which were published by the other
+/// workers upon restarting. Since a worker imports the clauses in the order in which they were
+/// published, a clause which is only valid under previously published clauses (e.g. a bound on the
+/// objective) is never imported without those clauses.
+#[derive(Clone, Debug)]
+pub(crate) struct NogoodExchange {
+    worker: usize,
+    clauses: Arc<Mutex<Vec<SharedClause>>>,
+    /// The number of clauses in the shared log which have been imported by this worker.
+    num_imported: usize,
+    max_lbd: u32,
+    max_length: usize,
+}
+
+impl NogoodExchange {
+    /// Creates an exchange for each of `num_workers` workers, which share the learned clauses with
+    /// an LBD of at most `max_lbd` and a length of at most `max_length`.
+    pub(crate) fn create_for_workers(
+        num_workers: usize,
+        max_lbd: u32,
+        max_length: usize,
+    ) -> Vec<NogoodExchange> {
+        let clauses = Arc::new(Mutex::new(vec![]));
+
+        (0..num_workers)
+            .map(|worker| NogoodExchange {
+                worker,
+                clauses: Arc::clone(&clauses),
+                num_imported: 0,
+                max_lbd,
+                max_length,
+            })
+            .collect()
+    }
+
+    /// Returns whether a learned clause with the provided LBD and length should be shared.
+    pub(crate) fn should_export(&self, lbd: u32, length: usize) -> bool {
+        lbd <= self.max_lbd && length <= self.max_length
+    }
+
+    /// Publishes the clause consisting of the provided predicates to the other workers.
+    pub(crate) fn export(&self, predicates: Vec<IntegerPredicate>) {
+        self.clauses
+            .lock()
+            .expect("a worker panicked while holding the lock")
+            .push(SharedClause {
+                worker: self.worker,
+                predicates,
+            });
+    }
+
+    /// Returns the clauses which were published by the other workers since the previous import.
+    pub(crate) fn import(&mut self) -> Vec<Vec<IntegerPredicate>> {
+        let clauses = self
+            .clauses
+            .lock()
+            .expect("a worker panicked while holding the lock");
+
+        let imported = clauses[self.num_imported..]
+            .iter()
+            .filter(|clause| clause.worker != self.worker)
+            .map(|clause| clause.predicates.clone())
+            .collect();
+        self.num_imported = clauses.len();
+
+        imported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::variables::DomainId;
+    use crate::predicate;
+
+    #[test]
+    fn workers_import_the_clauses_of_other_workers_once() {
+        let mut exchanges = NogoodExchange::create_for_workers(2, 2, 10);
+        let x = DomainId::new(0);
+        let clause: Vec<IntegerPredicate> = vec![
+            predicate![x >= 2].try_into().unwrap(),
+            predicate![x <= 0].try_into().unwrap(),
+        ];
+
+        exchanges[0].export(clause.clone());
+
+        assert!(exchanges[0].import().is_empty());
+        assert_eq!(exchanges[1].import(), vec![clause]);
+        assert!(exchanges[1].import().is_empty());
+    }
+}
@@ -5,6 +5,8 @@ use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
 use crate::engine::VariableLiteralMappings;
+use crate::variable_names::DisplayWith;
+use crate::Solver;
 
 /// This structure is oftentimes used to represent propagations, explanations or decisions.
 ///
@@ -50,6 +52,26 @@ impl Predicate {
     pub fn is_integer_predicate(&self) -> bool {
         matches!(self, Predicate::IntegerPredicate(_))
     }
+
+    /// Returns an adapter which displays the predicate using the names of the variables in the
+    /// `solver`, rather than their internal identifiers.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::predicates::Predicate;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_named_bounded_integer(0, 10, "x");
+    /// let b = solver.new_named_literal("b");
+    ///
+    /// let predicate: Predicate = predicate![x >= 5];
+    /// assert_eq!(predicate.display_with(&solver).to_string(), "[x >= 5]");
+    /// assert_eq!(Predicate::from(!b).display_with(&solver).to_string(), "~b");
+    /// ```
+    pub fn display_with<'a>(&'a self, solver: &'a Solver) -> DisplayWith<'a, Predicate> {
+        solver.get_satisfaction_solver().display_with(self)
+    }
 }
 
 impl std::ops::Not for Predicate {
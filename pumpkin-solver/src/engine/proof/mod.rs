@@ -2,11 +2,15 @@ mod dimacs;
 mod proof_literals;
 
 use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
 use std::num::NonZero;
 use std::num::NonZeroU64;
 use std::path::Path;
 use std::path::PathBuf;
 
+use drcp_format::reader::DrcpError;
+use drcp_format::trimming::trim;
 use drcp_format::writer::ProofWriter;
 pub use drcp_format::Format;
 
@@ -14,6 +18,7 @@ use self::dimacs::DimacsProof;
 use self::proof_literals::ProofLiterals;
 use super::variables::Literal;
 use super::VariableLiteralMappings;
+use crate::statistics::log_statistic;
 use crate::variable_names::VariableNames;
 #[cfg(doc)]
 use crate::Solver;
@@ -52,11 +57,26 @@ impl ProofLog {
                 writer,
                 log_inferences,
                 definitions_path,
+                proof_path: file_path.to_path_buf(),
+                trim: false,
                 propagation_order_hint: if log_hints { Some(vec![]) } else { None },
             }),
         })
     }
 
+    /// Removes the steps which are not needed to derive the conclusion from the proof once it is
+    /// concluded (see [`drcp_format::trimming::trim`]).
+    ///
+    /// Trimming relies on the propagation hints, so it only has an effect on a CP proof logger
+    /// which logs hints.
+    pub fn with_trimming(mut self) -> ProofLog {
+        if let Some(ProofImpl::CpProof { trim, .. }) = self.internal_proof.as_mut() {
+            *trim = true;
+        }
+
+        self
+    }
+
     /// Create a dimacs proof logger.
     pub fn dimacs(file_path: &Path) -> std::io::Result<ProofLog> {
         let file = File::create(file_path)?;
@@ -152,9 +172,15 @@ impl ProofLog {
             Some(ProofImpl::CpProof {
                 writer,
                 definitions_path,
+                proof_path,
+                trim,
                 ..
             }) => {
                 let literals = writer.unsat()?;
+                if trim {
+                    trim_proof(&proof_path)?;
+                }
+
                 let file = File::create(definitions_path)?;
                 literals.write(file, variable_names, variable_literal_mapping)
             }
@@ -175,9 +201,15 @@ impl ProofLog {
             Some(ProofImpl::CpProof {
                 writer,
                 definitions_path,
+                proof_path,
+                trim,
                 ..
             }) => {
                 let literals = writer.optimal(objective_bound)?;
+                if trim {
+                    trim_proof(&proof_path)?;
+                }
+
                 let file = File::create(definitions_path)?;
                 literals.write(file, variable_names, variable_literal_mapping)
             }
@@ -190,6 +222,11 @@ impl ProofLog {
         }
     }
 
+    /// Returns whether a proof is being logged.
+    pub(crate) fn is_active(&self) -> bool {
+        self.internal_proof.is_some()
+    }
+
     pub(crate) fn is_logging_inferences(&self) -> bool {
         matches!(
             self.internal_proof,
@@ -207,9 +244,32 @@ enum ProofImpl {
         writer: ProofWriter<File, ProofLiterals>,
         log_inferences: bool,
         definitions_path: PathBuf,
+        proof_path: PathBuf,
+        // Whether the proof is trimmed once it is concluded.
+        trim: bool,
         // If propagation hints are enabled, this is a buffer used to record propagations in the
         // order they can be applied to derive the next nogood.
         propagation_order_hint: Option<Vec<NonZeroU64>>,
     },
     DimacsProof(DimacsProof<File>),
 }
+
+/// Trim the concluded proof at `proof_path` in place.
+///
+/// The untrimmed proof is moved aside while the trimmed proof is written, and removed afterwards.
+fn trim_proof(proof_path: &Path) -> std::io::Result<()> {
+    let untrimmed_path = proof_path.with_extension("untrimmed");
+    std::fs::rename(proof_path, &untrimmed_path)?;
+
+    let source = BufReader::new(File::open(&untrimmed_path)?);
+    let sink = BufWriter::new(File::create(proof_path)?);
+    let statistics = trim(source, sink).map_err(|error| match error {
+        DrcpError::Io(error) => error,
+        error => std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+    })?;
+
+    log_statistic("numProofSteps", statistics.num_steps);
+    log_statistic("numTrimmedProofSteps", statistics.num_removed_steps);
+
+    std::fs::remove_file(untrimmed_path)
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::io::Write;
 use std::num::NonZeroI32;
 use std::num::NonZeroU32;
@@ -53,7 +54,12 @@ impl ProofLiterals {
             let atomics = variable_names
                 .get_propositional_name(variable)
                 .into_iter()
-                .map(|name| AtomicConstraint::Bool(BoolAtomicConstraint { name, value: true }))
+                .map(|name| {
+                    AtomicConstraint::Bool(BoolAtomicConstraint {
+                        name: Cow::Borrowed(name),
+                        value: true,
+                    })
+                })
                 .chain(
                     predicates
                         .map(|predicate| integer_predicate_to_atomic(predicate, variable_names)),
@@ -80,15 +86,13 @@ impl ProofLiterals {
 fn integer_predicate_to_atomic(
     predicate: IntegerPredicate,
     variable_names: &VariableNames,
-) -> AtomicConstraint<&str> {
+) -> AtomicConstraint<Cow<'_, str>> {
     match predicate {
         IntegerPredicate::LowerBound {
             domain_id,
             lower_bound,
         } => AtomicConstraint::Int(IntAtomicConstraint {
-            name: variable_names
-                .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+            name: variable_names.get_int_name_or_default(domain_id),
             comparison: Comparison::GreaterThanEqual,
             value: lower_bound.into(),
         }),
@@ -96,9 +100,7 @@ fn integer_predicate_to_atomic(
             domain_id,
             upper_bound,
         } => AtomicConstraint::Int(IntAtomicConstraint {
-            name: variable_names
-                .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+            name: variable_names.get_int_name_or_default(domain_id),
             comparison: Comparison::LessThanEqual,
             value: upper_bound.into(),
         }),
@@ -106,9 +108,7 @@ fn integer_predicate_to_atomic(
             domain_id,
             not_equal_constant,
         } => AtomicConstraint::Int(IntAtomicConstraint {
-            name: variable_names
-                .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+            name: variable_names.get_int_name_or_default(domain_id),
             comparison: Comparison::NotEqual,
             value: not_equal_constant.into(),
         }),
@@ -116,9 +116,7 @@ fn integer_predicate_to_atomic(
             domain_id,
             equality_constant,
         } => AtomicConstraint::Int(IntAtomicConstraint {
-            name: variable_names
-                .get_int_name(domain_id)
-                .expect("integer domain is unnamed"),
+            name: variable_names.get_int_name_or_default(domain_id),
             comparison: Comparison::Equal,
             value: equality_constant.into(),
         }),
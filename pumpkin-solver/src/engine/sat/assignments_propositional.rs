@@ -55,6 +55,11 @@ impl AssignmentsPropositional {
             .push(PropositionalAssignmentInfo::Unassigned);
     }
 
+    /// Reserves space for at least `additional` more propositional variables.
+    pub fn reserve(&mut self, additional: usize) {
+        self.assignment_info.reserve(additional);
+    }
+
     pub fn num_propositional_variables(&self) -> u32 {
         self.assignment_info.len() as u32
     }
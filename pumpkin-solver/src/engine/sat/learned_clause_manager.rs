@@ -30,6 +30,16 @@ pub struct LearningOptions {
     /// The treshold which specifies whether a learned clause database is considered to be with
     /// "High" LBD or "Low" LBD. Learned clauses with high LBD will be considered for removal.
     pub lbd_threshold: u32,
+    /// The maximum decision level at which clauses are learned from conflicts. If a conflict
+    /// occurs at a deeper decision level, then the solver instead learns the clause consisting of
+    /// the negated decisions; this corresponds to chronological backtracking (i.e. the last
+    /// decision is negated, as in classical depth-first search).
+    ///
+    /// If [`None`], then clauses are learned at every decision level, and `Some(0)` disables
+    /// clause learning entirely. Note that the clauses consisting of the negated decisions cannot
+    /// be checked by a proof checker, meaning that proof logging is not supported when clause
+    /// learning is limited; if a proof is logged, then this limit is ignored.
+    pub max_learning_decision_level: Option<usize>,
 }
 
 impl Default for LearningOptions {
@@ -40,6 +50,7 @@ impl Default for LearningOptions {
             num_high_lbd_learned_clauses_max: 4000,
             high_lbd_learned_clause_sorting_strategy: LearnedClauseSortingStrategy::Activity,
             lbd_threshold: 5,
+            max_learning_decision_level: None,
         }
     }
 }
@@ -72,6 +83,12 @@ struct LearnedClauses {
 
 // todo explain the learned clause removal strategy
 
+/// Manages the learned clauses of the solver.
+///
+/// Every learned nogood is stored as a clause over [`Literal`]s (integer predicates are
+/// represented by the literals which encode them), which is propagated by the
+/// [`ClausalPropagator`] using the two-watched literal scheme. Nogoods over Boolean variables
+/// therefore do not require a separate representation.
 #[derive(Debug)]
 pub(crate) struct LearnedClauseManager {
     learned_clauses: LearnedClauses,
@@ -88,6 +105,14 @@ impl LearnedClauseManager {
         }
     }
 
+    /// Returns whether a clause should be learned from a conflict at the provided decision level
+    /// (see [`LearningOptions::max_learning_decision_level`]).
+    pub(crate) fn learns_at_decision_level(&self, decision_level: usize) -> bool {
+        self.parameters
+            .max_learning_decision_level
+            .is_none_or(|max_decision_level| decision_level <= max_decision_level)
+    }
+
     pub(crate) fn add_learned_clause(
         &mut self,
         learned_clause_literals: Vec<Literal>,
@@ -141,6 +166,23 @@ impl LearnedClauseManager {
         self.remove_high_lbd_clauses(assignments, clause_allocator, clausal_propagator);
     }
 
+    /// Called upon a restart; the clauses in the high LBD group which have since obtained a low
+    /// LBD are promoted so that they are protected from being removed.
+    pub(crate) fn on_restart(&mut self, clause_allocator: &mut ClauseAllocator) {
+        self.promote_high_lbd_clauses(clause_allocator);
+    }
+
+    /// Drops the references to the learned clauses which were deleted outside of the manager
+    /// (see [`ClausalPropagator::remove_clauses_containing`]).
+    pub(crate) fn remove_deleted_clauses(&mut self, clause_allocator: &ClauseAllocator) {
+        self.learned_clauses
+            .low_lbd
+            .retain(|&clause_reference| !clause_allocator[clause_reference].is_deleted());
+        self.learned_clauses
+            .high_lbd
+            .retain(|&clause_reference| !clause_allocator[clause_reference].is_deleted());
+    }
+
     fn remove_high_lbd_clauses(
         &mut self,
         assignments: &AssignmentsPropositional,
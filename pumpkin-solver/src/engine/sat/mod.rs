@@ -10,4 +10,6 @@ pub(crate) use learned_clause_manager::LearnedClauseManager;
 pub use learned_clause_manager::LearnedClauseSortingStrategy;
 pub use learned_clause_manager::LearningOptions;
 pub use restart_strategy::RestartOptions;
+pub use restart_strategy::RestartPolicy;
 pub(crate) use restart_strategy::RestartStrategy;
+pub use restart_strategy::SequenceRestarts;
@@ -8,7 +8,15 @@ use crate::basic_types::sequence_generators::GeometricSequence;
 use crate::basic_types::sequence_generators::LubySequence;
 use crate::basic_types::sequence_generators::SequenceGenerator;
 use crate::basic_types::sequence_generators::SequenceGeneratorType;
+#[cfg(doc)]
+use crate::branching::value_selection::PhaseSaving;
+#[cfg(doc)]
+use crate::branching::value_selection::SolutionGuidedValueSelector;
+#[cfg(doc)]
+use crate::branching::Brancher;
 use crate::pumpkin_assert_simple;
+#[cfg(doc)]
+use crate::Solver;
 
 /// The options which are used by the solver to determine when a restart should occur.
 ///
@@ -44,6 +52,9 @@ use crate::pumpkin_assert_simple;
 ///
 /// \[5\] M. Luby, A. Sinclair, and D. Zuckerman, ‘Optimal speedup of Las Vegas algorithms’,
 /// Information Processing Letters, vol. 47, no. 4, pp. 173–180, 1993.
+///
+/// \[6\] P. van der Tak, A. Ramos, and M. Heule, ‘Reusing the assignment trail in CDCL solvers’,
+/// Journal on Satisfiability, Boolean Modeling and Computation, vol. 7, no. 4, pp. 133–138, 2011.
 #[derive(Debug, Clone, Copy)]
 pub struct RestartOptions {
     /// Decides the sequence based on which the restarts are performed.
@@ -78,6 +89,17 @@ pub struct RestartOptions {
     pub geometric_coef: Option<f64>,
     /// Determines whether restarts should be able to occur
     pub no_restarts: bool,
+    /// Determines whether the solver reuses (part of) the trail upon a restart \[6\]; rather than
+    /// backtracking to the root, the solver keeps the decisions which the [`Brancher`] indicates
+    /// it would make again (see [`Brancher::num_reusable_decisions`]), which avoids propagating
+    /// these decisions again.
+    pub reuse_trail: bool,
+    /// Determines whether the restart schedule starts over whenever a new incumbent is found
+    /// during optimisation; the solver then restarts frequently while searching around the new
+    /// incumbent, which the solution-guided value selectors (e.g.
+    /// [`SolutionGuidedValueSelector`]) and [`PhaseSaving`] use as their phases. This also lifts
+    /// the restriction imposed by [`RestartOptions::min_num_conflicts_before_first_restart`].
+    pub restart_on_new_incumbent: bool,
 }
 
 impl Default for RestartOptions {
@@ -92,6 +114,8 @@ impl Default for RestartOptions {
             num_assigned_window: 5000,
             geometric_coef: None,
             no_restarts: false,
+            reuse_trail: false,
+            restart_on_new_incumbent: false,
         }
     }
 }
@@ -132,6 +156,10 @@ pub(crate) struct RestartStrategy {
     number_of_blocked_restarts: u64,
     /// Determines whether restarts should be able to occur
     no_restarts: bool,
+    /// The options which were used to create the strategy; the restart sequence is created anew
+    /// from these options whenever a new incumbent is found (see
+    /// [`RestartOptions::restart_on_new_incumbent`]).
+    options: RestartOptions,
 }
 
 impl Default for RestartStrategy {
@@ -140,23 +168,131 @@ impl Default for RestartStrategy {
     }
 }
 
+/// Decides when the solver restarts; the default policy is the Glucose-style strategy which is
+/// configured using [`RestartOptions`], a different policy can be installed using
+/// [`Solver::set_restart_policy`].
+///
+/// Upon a restart, the solver backtracks (see [`RestartOptions::reuse_trail`]) and notifies the
+/// [`Brancher`] (see [`Brancher::on_restart`]) and the learned clause database.
+pub trait RestartPolicy: Debug {
+    /// Returns whether the solver should restart; this is asked before every decision which
+    /// follows a conflict.
+    ///
+    /// Note that the solver can still decide not to restart, e.g. when the [`Brancher`] indicates
+    /// that restarting is pointless (see [`Brancher::is_restart_pointless`]).
+    fn should_restart(&self) -> bool;
+
+    /// Called after every conflict with the LBD of the learned clause and the number of literals
+    /// which were assigned when the conflict was found.
+    fn notify_conflict(&mut self, lbd: u32, num_literals_on_trail: usize);
+
+    /// Called after the solver has restarted.
+    fn notify_restart(&mut self);
+
+    /// Called when the solver has found a new incumbent during optimisation; at this point, the
+    /// solver has backtracked to the root.
+    fn notify_new_incumbent(&mut self) {}
+}
+
+/// Creates the generator of the sequence of the provided type.
+fn create_sequence_generator(
+    sequence_generator_type: SequenceGeneratorType,
+    base_interval: u64,
+    geometric_coef: Option<f64>,
+) -> Box<dyn SequenceGenerator> {
+    match sequence_generator_type {
+        SequenceGeneratorType::Constant => Box::new(ConstantSequence::new(base_interval as i64)),
+        SequenceGeneratorType::Geometric => Box::new(GeometricSequence::new(
+            base_interval as i64,
+            geometric_coef.expect(
+                "Using the geometric sequence for restarts, but the parameter restarts-geometric-coef is not defined.",
+            ),
+        )),
+        SequenceGeneratorType::Luby => Box::new(LubySequence::new(base_interval as i64)),
+    }
+}
+
+/// Returns the next number of conflicts until a restart from the sequence generator.
+fn next_restart_interval(sequence_generator: &mut dyn SequenceGenerator) -> u64 {
+    sequence_generator.next().try_into().expect(
+        "Expected restart generator to generate a positive value but it generated a negative one",
+    )
+}
+
+/// A [`RestartPolicy`] which restarts purely based on a sequence of conflict limits (e.g. Luby
+/// restarts \[1\]), without taking the quality of the learned clauses into account; the `i`-th
+/// restart happens after the `i`-th element of the sequence (multiplied by the base interval)
+/// number of conflicts since the previous restart.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::Solver;
+/// # use pumpkin_solver::options::SequenceGeneratorType;
+/// # use pumpkin_solver::options::SequenceRestarts;
+/// let mut solver = Solver::default();
+/// // Restart after 100, 100, 200, 100, 100, 200, 400, ... conflicts
+/// solver.set_restart_policy(SequenceRestarts::new(
+///     SequenceGeneratorType::Luby,
+///     100,
+///     None,
+/// ));
+/// ```
+///
+/// # Bibliography
+/// \[1\] M. Luby, A. Sinclair, and D. Zuckerman, ‘Optimal speedup of Las Vegas algorithms’,
+/// Information Processing Letters, vol. 47, no. 4, pp. 173–180, 1993.
+#[derive(Debug)]
+pub struct SequenceRestarts {
+    sequence_generator: Box<dyn SequenceGenerator>,
+    number_of_conflicts_encountered_since_restart: u64,
+    number_of_conflicts_until_restart: u64,
+}
+
+impl SequenceRestarts {
+    /// Creates the policy; the `geometric_coef` is required for the
+    /// [`SequenceGeneratorType::Geometric`] sequence (see [`RestartOptions::geometric_coef`]).
+    pub fn new(
+        sequence_generator_type: SequenceGeneratorType,
+        base_interval: u64,
+        geometric_coef: Option<f64>,
+    ) -> Self {
+        let mut sequence_generator =
+            create_sequence_generator(sequence_generator_type, base_interval, geometric_coef);
+        let number_of_conflicts_until_restart = next_restart_interval(sequence_generator.as_mut());
+
+        SequenceRestarts {
+            sequence_generator,
+            number_of_conflicts_encountered_since_restart: 0,
+            number_of_conflicts_until_restart,
+        }
+    }
+}
+
+impl RestartPolicy for SequenceRestarts {
+    fn should_restart(&self) -> bool {
+        self.number_of_conflicts_encountered_since_restart >= self.number_of_conflicts_until_restart
+    }
+
+    fn notify_conflict(&mut self, _lbd: u32, _num_literals_on_trail: usize) {
+        self.number_of_conflicts_encountered_since_restart += 1;
+    }
+
+    fn notify_restart(&mut self) {
+        self.number_of_conflicts_encountered_since_restart = 0;
+        self.number_of_conflicts_until_restart =
+            next_restart_interval(self.sequence_generator.as_mut());
+    }
+}
+
 impl RestartStrategy {
     pub(crate) fn new(options: RestartOptions) -> Self {
-        let mut sequence_generator: Box<dyn SequenceGenerator> =
-            match options.sequence_generator_type {
-                SequenceGeneratorType::Constant => Box::new(ConstantSequence::new(
-                    options.base_interval as i64,
-                )),
-                SequenceGeneratorType::Geometric => Box::new(GeometricSequence::new(
-                    options.base_interval as i64,
-                    options.geometric_coef.expect(
-                        "Using the geometric sequence for restarts, but the parameter restarts-geometric-coef is not defined.",
-                    ),
-                )),
-                SequenceGeneratorType::Luby => Box::new(LubySequence::new(options.base_interval as i64)),
-            };
-
-        let number_of_conflicts_until_restart = sequence_generator.next().try_into().expect("Expected restart generator to generate a positive value but it generated a negative one");
+        let mut sequence_generator = create_sequence_generator(
+            options.sequence_generator_type,
+            options.base_interval,
+            options.geometric_coef,
+        );
+
+        let number_of_conflicts_until_restart = next_restart_interval(sequence_generator.as_mut());
 
         RestartStrategy {
             sequence_generator,
@@ -176,9 +312,24 @@ impl RestartStrategy {
             number_of_restarts: 0,
             number_of_blocked_restarts: 0,
             no_restarts: options.no_restarts,
+            options,
         }
     }
 
+    /// Resets the values related to determining whether a restart takes place; this method should
+    /// be called whenever a restart has taken place or should have taken place and was blocked.
+    fn reset_values(&mut self) {
+        pumpkin_assert_simple!(!self.no_restarts);
+
+        self.number_of_conflicts_until_restart =
+            next_restart_interval(self.sequence_generator.as_mut());
+        self.number_of_conflicts_encountered_since_restart = 0;
+        self.lbd_short_term_moving_average
+            .adapt(self.number_of_conflicts_until_restart);
+    }
+}
+
+impl RestartPolicy for RestartStrategy {
     /// Determines whether the restart strategy indicates that a restart should take place; the
     /// strategy considers three conditions (in this order):
     /// - If no restarts have taken place yet then a restart can only take place if the number of
@@ -193,7 +344,7 @@ impl RestartStrategy {
     ///   [`RestartOptions::lbd_coef`], this condition determines whether the solver is learning
     ///   "bad" clauses based on the LBD; if it is learning "sufficiently bad" clauses then a
     ///   restart will be performed.
-    pub(crate) fn should_restart(&self) -> bool {
+    fn should_restart(&self) -> bool {
         if self.no_restarts {
             return false;
         }
@@ -222,7 +373,7 @@ impl RestartStrategy {
     /// Notifies the restart strategy that a conflict has taken place so that it can adjust its
     /// internal values, this method has the additional responsibility of checking whether a restart
     /// should be blocked based on whether the solver is "sufficiently close" to finding a solution.
-    pub(crate) fn notify_conflict(&mut self, lbd: u32, num_literals_on_trail: usize) {
+    fn notify_conflict(&mut self, lbd: u32, num_literals_on_trail: usize) {
         if self.no_restarts {
             // Restarts cannot occur so we should store no information
             return;
@@ -257,22 +408,69 @@ impl RestartStrategy {
 
     /// Notifies the restart strategy that a restart has taken place so that it can adjust its
     /// internal values
-    pub(crate) fn notify_restart(&mut self) {
+    fn notify_restart(&mut self) {
         pumpkin_assert_simple!(!self.no_restarts);
 
         self.number_of_restarts += 1;
         self.reset_values()
     }
 
-    /// Resets the values related to determining whether a restart takes place; this method should
-    /// be called whenever a restart has taken place or should have taken place and was blocked.
-    fn reset_values(&mut self) {
-        pumpkin_assert_simple!(!self.no_restarts);
+    /// Starts the restart sequence over if [`RestartOptions::restart_on_new_incumbent`] is set;
+    /// the LBD averages are kept since they describe the problem rather than the incumbent.
+    fn notify_new_incumbent(&mut self) {
+        if self.no_restarts || !self.options.restart_on_new_incumbent {
+            return;
+        }
 
-        self.number_of_conflicts_until_restart =
-            self.sequence_generator.next().try_into().expect("Expected restart generator to generate a positive value but it generated a negative one");
-        self.number_of_conflicts_encountered_since_restart = 0;
-        self.lbd_short_term_moving_average
-            .adapt(self.number_of_conflicts_until_restart);
+        self.sequence_generator = create_sequence_generator(
+            self.options.sequence_generator_type,
+            self.options.base_interval,
+            self.options.geometric_coef,
+        );
+        self.minimum_number_of_conflicts_before_first_restart = 0;
+        self.reset_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_restarts_follow_the_luby_sequence() {
+        let mut policy = SequenceRestarts::new(SequenceGeneratorType::Luby, 2, None);
+
+        let mut intervals = vec![];
+        for _ in 0..5 {
+            let mut num_conflicts = 0;
+            while !policy.should_restart() {
+                policy.notify_conflict(10, 0);
+                num_conflicts += 1;
+            }
+            policy.notify_restart();
+            intervals.push(num_conflicts);
+        }
+
+        assert_eq!(intervals, vec![2, 2, 4, 2, 2]);
+    }
+
+    #[test]
+    fn new_incumbent_starts_the_restart_sequence_over() {
+        let mut strategy = RestartStrategy::new(RestartOptions {
+            sequence_generator_type: SequenceGeneratorType::Luby,
+            base_interval: 3,
+            min_num_conflicts_before_first_restart: 1000,
+            lbd_coef: 1.0,
+            restart_on_new_incumbent: true,
+            ..Default::default()
+        });
+
+        strategy.notify_new_incumbent();
+
+        for _ in 0..3 {
+            assert!(!strategy.should_restart());
+            strategy.notify_conflict(4, 0);
+        }
+        assert!(strategy.should_restart());
     }
 }
@@ -1,5 +1,14 @@
+use std::time::Duration;
+
 use crate::basic_types::moving_averages::CumulativeMovingAverage;
+use crate::basic_types::moving_averages::MovingAverage;
 use crate::create_statistics_struct;
+#[cfg(doc)]
+use crate::engine::RestartOptions;
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
 
 create_statistics_struct!(
     /// Structure responsible for storing several statistics of the solving process of the
@@ -8,15 +17,57 @@ create_statistics_struct!(
         /// Core statistics of the solver engine (e.g. the number of decisions)
         engine_statistics: EngineStatistics,
         /// The statistics related to clause learning
-        learned_clause_statistics: LearnedClauseStatistics
+        learned_clause_statistics: LearnedClauseStatistics,
+        /// The comparison of the nogoods which different learning schemes derive from the same
+        /// conflicts; these are only recorded if
+        /// [`SatisfactionSolverOptions::compare_learning_schemes`] is enabled
+        learning_scheme_statistics: LearningSchemeStatistics
     }
 );
 
+create_statistics_struct!(
+    /// The comparison of the nogoods which different learning schemes derive from the same
+    /// conflicts
+    LearningSchemeStatistics {
+        /// The nogoods which are learned (i.e. first UIP, after minimisation)
+        first_uip: NogoodStatistics,
+        /// The nogoods which resolve all literals at the conflict level up to the decision
+        last_uip: NogoodStatistics,
+        /// The nogoods which consist of decisions only
+        all_decision: NogoodStatistics,
+});
+
+create_statistics_struct!(
+    /// The statistics of the nogoods which are derived by a single learning scheme
+    NogoodStatistics {
+        /// The average number of literals of the nogoods
+        average_length: CumulativeMovingAverage,
+        /// The average LBD of the nogoods
+        average_lbd: CumulativeMovingAverage,
+        /// The average decision level to which the nogoods would backjump
+        average_backjump_level: CumulativeMovingAverage,
+});
+
 create_statistics_struct!(
     /// Core statistics of the solver engine (e.g. the number of decisions)
     EngineStatistics {
         /// The number of decisions taken by the solver
         num_decisions: u64,
+        /// The number of decisions returned by the brancher which were skipped since they were
+        /// already satisfied or falsified
+        num_skipped_decisions: u64,
+        /// The number of decisions returned by the brancher which were replaced by a decision
+        /// middleware
+        num_rewritten_decisions: u64,
+        /// The number of decisions returned by the brancher which were vetoed by a decision
+        /// middleware
+        num_vetoed_decisions: u64,
+        /// The number of predicates which were propagated by the lookahead at the root (see
+        /// [`SatisfactionSolverOptions::lookahead_budget`])
+        num_lookahead_probes: u64,
+        /// The number of decisions which were kept upon restarting (see
+        /// [`RestartOptions::reuse_trail`])
+        num_reused_decisions: u64,
         /// The number of conflicts encountered by the solver
         num_conflicts: u64,
         /// The number of times the solver has restarted
@@ -36,10 +87,129 @@ create_statistics_struct!(
         average_number_of_removed_literals_recursive: CumulativeMovingAverage,
         /// The average number of literals removed by semantic minimisation during conflict analysis
         average_number_of_removed_literals_semantic: CumulativeMovingAverage,
+        /// The average length of the learned clauses before semantic minimisation
+        average_length_before_semantic_minimisation: CumulativeMovingAverage,
+        /// The maximum length of the learned clauses before semantic minimisation
+        max_length_before_semantic_minimisation: u64,
+        /// The average length of the learned clauses after semantic minimisation
+        average_length_after_semantic_minimisation: CumulativeMovingAverage,
+        /// The maximum length of the learned clauses after semantic minimisation
+        max_length_after_semantic_minimisation: u64,
         /// The number of learned clauses which have a size of 1
         num_unit_clauses_learned: u64,
         /// The average length of the learned clauses
         average_learned_clause_length: CumulativeMovingAverage,
         /// The average number of levels which have been backtracked by the solver (e.g. when a learned clause is created)
          average_backtrack_amount: CumulativeMovingAverage,
+        /// The number of symmetric images of learned clauses which were added (see
+        /// [`SatisfactionSolverOptions::max_symmetric_images`])
+        num_symmetric_images: u64,
+        /// The number of clauses which were imported from the other workers of a portfolio
+        num_imported_clauses: u64,
+        /// The number of learned clauses per LBD
+        lbd_distribution: LbdDistribution,
 });
+
+impl NogoodStatistics {
+    /// Records a nogood with the provided length, LBD and backjump level.
+    pub(crate) fn add(&mut self, length: usize, lbd: u32, backjump_level: usize) {
+        self.average_length.add_term(length as u64);
+        self.average_lbd.add_term(lbd as u64);
+        self.average_backjump_level.add_term(backjump_level as u64);
+    }
+}
+
+/// The number of learned clauses per literal block distance (LBD); the clauses with an LBD of at
+/// least [`LbdDistribution::NUM_BUCKETS`] are counted together.
+#[derive(Default, Debug, Copy, Clone)]
+pub(crate) struct LbdDistribution {
+    num_clauses: [u64; LbdDistribution::NUM_BUCKETS],
+}
+
+impl LbdDistribution {
+    const NUM_BUCKETS: usize = 10;
+
+    /// Records a learned clause with the provided LBD.
+    pub(crate) fn add(&mut self, lbd: u32) {
+        let bucket = (lbd as usize).clamp(1, Self::NUM_BUCKETS) - 1;
+        self.num_clauses[bucket] += 1;
+    }
+}
+
+impl Statistic for LbdDistribution {
+    fn log(&self, statistic_logger: StatisticLogger) {
+        for (bucket, num_clauses) in self.num_clauses.iter().enumerate() {
+            let lbd = bucket + 1;
+            if lbd < Self::NUM_BUCKETS {
+                statistic_logger
+                    .attach_to_prefix(lbd)
+                    .log_statistic(num_clauses);
+            } else {
+                statistic_logger
+                    .attach_to_prefix(format!("{lbd}_or_more"))
+                    .log_statistic(num_clauses);
+            }
+        }
+    }
+}
+
+/// A summary of the search performed by the solver, e.g. returned as part of
+/// [`SatisfactionResult::Unknown`] to indicate how much progress was made before the search was
+/// stopped, and as part of [`SatisfactionResult::Unsatisfiable`] to indicate how much search was
+/// required to prove that no solution exists; the statistics accumulated over all solves can be
+/// retrieved using [`Solver::search_statistics`].
+///
+/// [`Solver::search_statistics`]: crate::Solver::search_statistics
+/// [`SatisfactionResult::Unknown`]: crate::results::SatisfactionResult::Unknown
+/// [`SatisfactionResult::Unsatisfiable`]: crate::results::SatisfactionResult::Unsatisfiable
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchStatistics {
+    /// The number of decisions taken by the solver
+    pub num_decisions: u64,
+    /// The number of decisions returned by the brancher which were skipped since they were
+    /// already satisfied or falsified; a large number can indicate that the brancher relies on
+    /// stale information
+    pub num_skipped_decisions: u64,
+    /// The number of decisions which were kept upon restarting since the brancher would have made
+    /// them again (see [`RestartOptions::reuse_trail`])
+    pub num_reused_decisions: u64,
+    /// The number of conflicts encountered by the solver
+    pub num_conflicts: u64,
+    /// The number of times the solver has restarted
+    pub num_restarts: u64,
+    /// The number of (integer) propagations made by the solver
+    pub num_propagations: u64,
+    /// The amount of time which is spent in the solver
+    pub time_spent_in_solver: Duration,
+}
+
+impl From<EngineStatistics> for SearchStatistics {
+    fn from(statistics: EngineStatistics) -> Self {
+        SearchStatistics {
+            num_decisions: statistics.num_decisions,
+            num_skipped_decisions: statistics.num_skipped_decisions,
+            num_reused_decisions: statistics.num_reused_decisions,
+            num_conflicts: statistics.num_conflicts,
+            num_restarts: statistics.num_restarts,
+            num_propagations: statistics.num_propagations,
+            time_spent_in_solver: Duration::from_millis(statistics.time_spent_in_solver),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_lbds_share_the_last_bucket() {
+        let mut distribution = LbdDistribution::default();
+        distribution.add(1);
+        distribution.add(3);
+        distribution.add(3);
+        distribution.add(10);
+        distribution.add(25);
+
+        assert_eq!(distribution.num_clauses, [1, 0, 2, 0, 0, 0, 0, 0, 0, 2]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::constraints::validation::check_lengths;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
+use crate::engine::AssignmentsInteger;
+use crate::engine::AssignmentsPropositional;
+use crate::engine::VariableLiteralMappings;
+use crate::InvalidArgument;
+
+/// A symmetry of the model which permutes integer variables; the variables which are not mentioned
+/// by the symmetry are mapped to themselves.
+///
+/// The symmetry is used to learn the symmetric images of learned clauses (see
+/// [`VariableSymmetry::image_of_clause`]), which are implied by the model if the permutation maps
+/// every solution to a solution.
+#[derive(Debug, Clone)]
+pub(crate) struct VariableSymmetry {
+    mapping: HashMap<DomainId, DomainId>,
+}
+
+impl VariableSymmetry {
+    /// Creates the symmetry which maps `variables[i]` to `images[i]`; `images` should be a
+    /// permutation of `variables`.
+    pub(crate) fn new(
+        variables: Vec<DomainId>,
+        images: Vec<DomainId>,
+    ) -> Result<VariableSymmetry, InvalidArgument> {
+        check_lengths(("variables", variables.len()), &[("images", images.len())])?;
+
+        let domain: HashSet<DomainId> = variables.iter().copied().collect();
+        let image: HashSet<DomainId> = images.iter().copied().collect();
+        if domain.len() != variables.len() || image != domain {
+            return Err(InvalidArgument::NotAPermutation {
+                argument: "images",
+                reference: "variables",
+            });
+        }
+
+        Ok(VariableSymmetry {
+            mapping: variables
+                .into_iter()
+                .zip(images)
+                .filter(|(variable, image)| variable != image)
+                .collect(),
+        })
+    }
+
+    /// Returns the variable to which `domain_id` is mapped.
+    pub(crate) fn image(&self, domain_id: DomainId) -> DomainId {
+        self.mapping.get(&domain_id).copied().unwrap_or(domain_id)
+    }
+
+    /// Returns the image of the `clause` under the symmetry, in which the literals which are linked
+    /// to an [`IntegerPredicate`] are replaced by the literal of the same predicate over the image
+    /// of its variable; the other literals are mapped to themselves.
+    ///
+    /// Returns [`None`] if the image contains the same literals as the `clause`, in which case it
+    /// does not need to be learned again.
+    pub(crate) fn image_of_clause(
+        &self,
+        clause: &[Literal],
+        variable_literal_mappings: &VariableLiteralMappings,
+        assignments_propositional: &AssignmentsPropositional,
+        assignments_integer: &AssignmentsInteger,
+    ) -> Option<Vec<Literal>> {
+        let image: Vec<Literal> = clause
+            .iter()
+            .map(|&literal| {
+                let Some(predicate) = variable_literal_mappings.get_predicates(literal).next()
+                else {
+                    return literal;
+                };
+
+                variable_literal_mappings.get_literal(
+                    self.image_of_predicate(predicate),
+                    assignments_propositional,
+                    assignments_integer,
+                )
+            })
+            .collect();
+
+        (!image.iter().all(|literal| clause.contains(literal))).then_some(image)
+    }
+
+    fn image_of_predicate(&self, predicate: IntegerPredicate) -> IntegerPredicate {
+        let domain_id = self.image(predicate.get_domain());
+
+        match predicate {
+            IntegerPredicate::LowerBound { lower_bound, .. } => IntegerPredicate::LowerBound {
+                domain_id,
+                lower_bound,
+            },
+            IntegerPredicate::UpperBound { upper_bound, .. } => IntegerPredicate::UpperBound {
+                domain_id,
+                upper_bound,
+            },
+            IntegerPredicate::NotEqual {
+                not_equal_constant, ..
+            } => IntegerPredicate::NotEqual {
+                domain_id,
+                not_equal_constant,
+            },
+            IntegerPredicate::Equal {
+                equality_constant, ..
+            } => IntegerPredicate::Equal {
+                domain_id,
+                equality_constant,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn images_which_are_not_a_permutation_are_rejected() {
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+        let z = DomainId::new(2);
+
+        assert!(VariableSymmetry::new(vec![x, y], vec![y, x]).is_ok());
+        assert_eq!(
+            VariableSymmetry::new(vec![x, y], vec![y, z]).unwrap_err(),
+            InvalidArgument::NotAPermutation {
+                argument: "images",
+                reference: "variables"
+            }
+        );
+        assert_eq!(
+            VariableSymmetry::new(vec![x, x], vec![x, x]).unwrap_err(),
+            InvalidArgument::NotAPermutation {
+                argument: "images",
+                reference: "variables"
+            }
+        );
+        assert!(matches!(
+            VariableSymmetry::new(vec![x, y], vec![y]),
+            Err(InvalidArgument::InconsistentLengths { .. })
+        ));
+    }
+
+    #[test]
+    fn variables_are_permuted() {
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+        let z = DomainId::new(2);
+        let unmapped = DomainId::new(3);
+
+        let symmetry = VariableSymmetry::new(vec![x, y, z], vec![y, z, x]).unwrap();
+
+        assert_eq!(symmetry.image(x), y);
+        assert_eq!(symmetry.image(y), z);
+        assert_eq!(symmetry.image(z), x);
+        assert_eq!(symmetry.image(unmapped), unmapped);
+    }
+}
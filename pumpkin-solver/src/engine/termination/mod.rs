@@ -23,3 +23,45 @@ impl<T: TerminationCondition> TerminationCondition for Option<T> {
         }
     }
 }
+
+impl<T: TerminationCondition + ?Sized> TerminationCondition for &mut T {
+    fn should_stop(&mut self) -> bool {
+        (**self).should_stop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Triggers once it has been polled the provided number of times.
+    struct StopAfterPolls(usize);
+
+    impl TerminationCondition for StopAfterPolls {
+        fn should_stop(&mut self) -> bool {
+            self.0 = self.0.saturating_sub(1);
+            self.0 == 0
+        }
+    }
+
+    fn poll(mut termination: impl TerminationCondition) -> bool {
+        termination.should_stop()
+    }
+
+    #[test]
+    fn mutable_reference_shares_the_state_of_the_termination_condition() {
+        let mut termination = StopAfterPolls(3);
+
+        assert!(!poll(&mut termination));
+        assert!(!poll(&mut termination));
+        assert_eq!(termination.0, 1);
+        assert!(poll(&mut termination));
+    }
+
+    #[test]
+    fn mutable_reference_to_a_trait_object_is_a_termination_condition() {
+        let mut termination: Box<dyn TerminationCondition> = Box::new(StopAfterPolls(1));
+
+        assert!(poll(termination.as_mut()));
+    }
+}
@@ -8,6 +8,7 @@ use crate::engine::predicates::predicate::Predicate;
 use crate::engine::predicates::predicate_constructor::PredicateConstructor;
 use crate::engine::reason::ReasonRef;
 use crate::engine::variables::DomainId;
+use crate::engine::variables::HistoricalIntegerVariable;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::AssignmentsInteger;
 use crate::engine::EmptyDomain;
@@ -71,6 +72,26 @@ where
         }
     }
 
+    fn bounds_i64(&self, assignment: &AssignmentsInteger) -> (i64, i64) {
+        let (lower_bound, upper_bound) = self.inner.bounds_i64(assignment);
+        let map = |value: i64| self.scale as i64 * value + self.offset as i64;
+
+        if self.scale < 0 {
+            (map(upper_bound), map(lower_bound))
+        } else {
+            (map(lower_bound), map(upper_bound))
+        }
+    }
+
+    fn size(&self, assignment: &AssignmentsInteger) -> usize {
+        if self.scale == 0 {
+            // Every value of the inner variable is mapped onto the offset
+            usize::from(self.inner.size(assignment) > 0)
+        } else {
+            self.inner.size(assignment)
+        }
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
         if (value - self.offset) % self.scale == 0 {
             let inverted = self.invert(value, Rounding::Up);
@@ -165,6 +186,62 @@ where
     }
 }
 
+impl<View> HistoricalIntegerVariable for AffineView<View>
+where
+    View: HistoricalIntegerVariable,
+{
+    fn lower_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32 {
+        if self.scale < 0 {
+            self.map(
+                self.inner
+                    .upper_bound_at_trail_position(assignment, trail_position),
+            )
+        } else {
+            self.map(
+                self.inner
+                    .lower_bound_at_trail_position(assignment, trail_position),
+            )
+        }
+    }
+
+    fn upper_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32 {
+        if self.scale < 0 {
+            self.map(
+                self.inner
+                    .lower_bound_at_trail_position(assignment, trail_position),
+            )
+        } else {
+            self.map(
+                self.inner
+                    .upper_bound_at_trail_position(assignment, trail_position),
+            )
+        }
+    }
+
+    fn contains_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        value: i32,
+        trail_position: usize,
+    ) -> bool {
+        if (value - self.offset) % self.scale == 0 {
+            let inverted = self.invert(value, Rounding::Up);
+            self.inner
+                .contains_at_trail_position(assignment, inverted, trail_position)
+        } else {
+            false
+        }
+    }
+}
+
 impl<View> TransformableVariable<AffineView<View>> for AffineView<View>
 where
     View: IntegerVariable,
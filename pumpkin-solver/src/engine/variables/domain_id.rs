@@ -6,6 +6,7 @@ use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::reason::ReasonRef;
 use crate::engine::variables::AffineView;
+use crate::engine::variables::HistoricalIntegerVariable;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::AssignmentsInteger;
 use crate::engine::EmptyDomain;
@@ -36,6 +37,10 @@ impl IntegerVariable for DomainId {
         assignment.get_upper_bound(*self)
     }
 
+    fn size(&self, assignment: &AssignmentsInteger) -> usize {
+        assignment.get_domain_size(*self)
+    }
+
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
         assignment.is_value_in_domain(*self, value)
     }
@@ -84,6 +89,33 @@ impl IntegerVariable for DomainId {
     }
 }
 
+impl HistoricalIntegerVariable for DomainId {
+    fn lower_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32 {
+        assignment.get_lower_bound_at_trail_position(*self, trail_position)
+    }
+
+    fn upper_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32 {
+        assignment.get_upper_bound_at_trail_position(*self, trail_position)
+    }
+
+    fn contains_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        value: i32,
+        trail_position: usize,
+    ) -> bool {
+        assignment.is_value_in_domain_at_trail_position(*self, value, trail_position)
+    }
+}
+
 impl TransformableVariable<AffineView<DomainId>> for DomainId {
     fn scaled(&self, scale: i32) -> AffineView<DomainId> {
         AffineView::new(*self, scale, 0)
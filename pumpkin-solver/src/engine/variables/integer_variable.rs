@@ -1,10 +1,18 @@
+use std::fmt::Debug;
+
 use enumset::EnumSet;
 
 use super::TransformableVariable;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::predicates::predicate_constructor::PredicateConstructor;
+#[cfg(doc)]
+use crate::engine::propagation::DomainSnapshotAt;
+#[cfg(doc)]
+use crate::engine::propagation::Propagator;
 use crate::engine::reason::ReasonRef;
+#[cfg(doc)]
+use crate::engine::variables::DomainId;
 use crate::engine::AssignmentsInteger;
 use crate::engine::EmptyDomain;
 use crate::engine::IntDomainEvent;
@@ -13,8 +21,13 @@ use crate::engine::Watchers;
 /// A trait specifying the required behaviour of an integer variable such as retrieving a
 /// lower-bound ([`IntegerVariable::lower_bound`]) or adjusting the bounds
 /// ([`IntegerVariable::set_lower_bound`]).
+///
+/// The [`Debug`] implementation of a variable is used to display it (e.g. in
+/// [`Propagator::describe`]), and should mention the [`DomainId`] in the form `x<id>`. Note that
+/// [`Debug`] is a supertrait since version 0.2.0; implementors which do not implement it have to
+/// add it.
 pub trait IntegerVariable:
-    Clone + PredicateConstructor<Value = i32> + TransformableVariable<Self::AffineView>
+    Clone + Debug + PredicateConstructor<Value = i32> + TransformableVariable<Self::AffineView>
 {
     type AffineView: IntegerVariable;
 
@@ -24,6 +37,32 @@ pub trait IntegerVariable:
     /// Get the upper bound of the variable.
     fn upper_bound(&self, assignment: &AssignmentsInteger) -> i32;
 
+    /// Get the bounds of the variable as `i64`s.
+    ///
+    /// Unlike [`IntegerVariable::lower_bound`] and [`IntegerVariable::upper_bound`], this does not
+    /// overflow if a view maps the bounds outside of the range of an `i32`; this can be used to
+    /// detect such overflows.
+    fn bounds_i64(&self, assignment: &AssignmentsInteger) -> (i64, i64) {
+        (
+            self.lower_bound(assignment) as i64,
+            self.upper_bound(assignment) as i64,
+        )
+    }
+
+    /// Get the number of values in the domain of the variable.
+    ///
+    /// By default, the values between the bounds are checked one by one; the size of a
+    /// [`DomainId`] is maintained by the [`AssignmentsInteger`], so variables which are based on
+    /// a [`DomainId`] should override this method to use it.
+    fn size(&self, assignment: &AssignmentsInteger) -> usize {
+        let lower_bound = self.lower_bound(assignment);
+        let upper_bound = self.upper_bound(assignment);
+
+        (lower_bound..=upper_bound)
+            .filter(|&value| self.contains(assignment, value))
+            .count()
+    }
+
     /// Determine whether the value is in the domain of this variable.
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool;
 
@@ -67,3 +106,36 @@ pub trait IntegerVariable:
     /// Decode a domain event for this variable.
     fn unpack_event(&self, event: OpaqueDomainEvent) -> IntDomainEvent;
 }
+
+/// An [`IntegerVariable`] of which the domain can be queried as it was at a point in the past,
+/// identified by the number of entries which the trail contained at that point; this is used by
+/// the [`DomainSnapshotAt`].
+///
+/// This is a separate trait such that implementors of [`IntegerVariable`] are not required to
+/// support these queries.
+pub trait HistoricalIntegerVariable: IntegerVariable {
+    /// Get the lower bound of the variable at the point at which the trail contained
+    /// `trail_position` entries.
+    fn lower_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32;
+
+    /// Get the upper bound of the variable at the point at which the trail contained
+    /// `trail_position` entries.
+    fn upper_bound_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        trail_position: usize,
+    ) -> i32;
+
+    /// Determine whether the value was in the domain of this variable at the point at which the
+    /// trail contained `trail_position` entries.
+    fn contains_at_trail_position(
+        &self,
+        assignment: &AssignmentsInteger,
+        value: i32,
+        trail_position: usize,
+    ) -> bool;
+}
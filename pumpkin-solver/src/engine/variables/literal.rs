@@ -1,6 +1,8 @@
 use crate::basic_types::StorageKey;
 use crate::engine::variables::PropositionalVariable;
 use crate::pumpkin_assert_moderate;
+use crate::variable_names::DisplayWith;
+use crate::Solver;
 
 /// A boolean variable in the solver; represents a [`PropositionalVariable`] but with a certain
 /// polarity (i.e. it is either the positive [`PropositionalVariable`] or its negation).
@@ -38,6 +40,13 @@ impl Literal {
         pumpkin_assert_moderate!(Literal { code }.to_u32() == literal_code);
         Literal { code }
     }
+
+    /// Returns an adapter which displays the literal using the names of the variables in the
+    /// `solver`; a literal which is linked to an integer predicate is displayed as that
+    /// predicate.
+    pub fn display_with<'a>(&'a self, solver: &'a Solver) -> DisplayWith<'a, Literal> {
+        solver.get_satisfaction_solver().display_with(self)
+    }
 }
 
 impl std::ops::Not for Literal {
@@ -14,6 +14,7 @@ mod transformable_variable;
 pub use affine_view::AffineView;
 pub(crate) use domain_generator_iterator::DomainGeneratorIterator;
 pub use domain_id::DomainId;
+pub use integer_variable::HistoricalIntegerVariable;
 pub use integer_variable::IntegerVariable;
 pub use literal::Literal;
 pub use propositional_variable::PropositionalVariable;
@@ -294,6 +294,8 @@ use crate::branching::Brancher;
 #[cfg(doc)]
 use crate::termination::TerminationCondition;
 
+#[cfg(feature = "bench")]
+pub mod bench;
 pub mod branching;
 pub mod constraints;
 pub mod statistics;
@@ -309,7 +311,11 @@ mod api;
 
 pub use api::*;
 
+pub use crate::api::solver::ActivityBasedBrancher;
 pub use crate::api::solver::DefaultBrancher;
+pub use crate::api::solver::IntegerVsidsBrancher;
 pub use crate::api::solver::Solver;
 pub use crate::basic_types::ConstraintOperationError;
+pub use crate::basic_types::InvalidArgument;
 pub use crate::basic_types::Random;
+pub use crate::basic_types::SolverError;
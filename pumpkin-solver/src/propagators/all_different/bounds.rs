@@ -0,0 +1,248 @@
+use std::rc::Rc;
+
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Bounds-consistent propagator for the constraint `all_different([x_1, \ldots, x_n])`.
+///
+/// The propagator detects Hall intervals; i.e. intervals `[a, b]` which contain the domains of
+/// exactly `b - a + 1` variables. No other variable can take a value in such an interval, and
+/// thus their bounds are moved out of it. If an interval contains the domains of more variables
+/// than it has values, then the constraint is violated.
+///
+/// This is cheaper than [`AllDifferentReginPropagator`](super::AllDifferentReginPropagator), but
+/// it ignores holes in the domains and only tightens bounds.
+#[derive(Clone, Debug)]
+pub(crate) struct AllDifferentBoundsPropagator<Var> {
+    variables: Rc<[Var]>,
+}
+
+impl<Var: IntegerVariable + 'static> AllDifferentBoundsPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>) -> Self {
+        AllDifferentBoundsPropagator {
+            variables: variables.into(),
+        }
+    }
+
+    /// Returns the predicates which state that the domains of the variables in the interval are
+    /// contained in it.
+    fn explain_interval(&self, bounds: &[(i32, i32)], lower: i32, upper: i32) -> Vec<Predicate> {
+        self.variables
+            .iter()
+            .zip(bounds)
+            .filter(|(_, &(lower_bound, upper_bound))| lower_bound >= lower && upper_bound <= upper)
+            .flat_map(|(variable, _)| {
+                [predicate![variable >= lower], predicate![variable <= upper]]
+            })
+            .collect()
+    }
+
+    /// Finds a Hall interval and moves the bounds of the other variables out of it; returns
+    /// whether a bound was tightened, in which case new Hall intervals may have been created.
+    fn propagate_hall_interval(
+        &self,
+        context: &mut PropagationContextMut,
+        bounds: &[(i32, i32)],
+    ) -> Result<bool, Inconsistency> {
+        let mut by_upper_bound = (0..bounds.len()).collect::<Vec<_>>();
+        by_upper_bound.sort_by_key(|&variable| bounds[variable].1);
+
+        let mut lower_bounds = bounds
+            .iter()
+            .map(|&(lower_bound, _)| lower_bound)
+            .collect::<Vec<_>>();
+        lower_bounds.sort_unstable();
+        lower_bounds.dedup();
+
+        // Considering the largest lower bounds first leads to smaller Hall intervals, and thus
+        // to smaller explanations
+        for &lower in lower_bounds.iter().rev() {
+            let mut num_contained = 0_i64;
+            for (position, &variable) in by_upper_bound.iter().enumerate() {
+                let (lower_bound, upper) = bounds[variable];
+                if lower_bound >= lower {
+                    num_contained += 1;
+                }
+
+                // Only consider the interval once all variables with this upper bound are counted
+                let is_last_with_upper_bound = match by_upper_bound.get(position + 1) {
+                    Some(&next) => bounds[next].1 != upper,
+                    None => true,
+                };
+                if !is_last_with_upper_bound || upper < lower {
+                    continue;
+                }
+
+                let num_values = upper as i64 - lower as i64 + 1;
+                if num_contained > num_values {
+                    return Err(self.explain_interval(bounds, lower, upper).into());
+                }
+                if num_contained < num_values {
+                    continue;
+                }
+
+                let explanation = self.explain_interval(bounds, lower, upper);
+                let mut has_tightened = false;
+                for (other, &(lower_bound, upper_bound)) in bounds.iter().enumerate() {
+                    let variable = &self.variables[other];
+                    if lower_bound >= lower && upper_bound <= upper {
+                        continue;
+                    }
+
+                    if lower_bound >= lower && lower_bound <= upper {
+                        let mut reason = explanation.clone();
+                        reason.push(predicate![variable >= lower]);
+                        context.set_lower_bound(
+                            variable,
+                            upper + 1,
+                            PropositionalConjunction::from(reason),
+                        )?;
+                        has_tightened = true;
+                    } else if upper_bound >= lower && upper_bound <= upper {
+                        let mut reason = explanation.clone();
+                        reason.push(predicate![variable <= upper]);
+                        context.set_upper_bound(
+                            variable,
+                            lower - 1,
+                            PropositionalConjunction::from(reason),
+                        )?;
+                        has_tightened = true;
+                    }
+                }
+
+                if has_tightened {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for AllDifferentBoundsPropagator<Var> {
+    fn name(&self) -> &str {
+        "AllDifferentBounds"
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::CheapGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, variable) in self.variables.iter().enumerate() {
+            let _ = context.register(
+                variable.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The sizes of the Hall intervals are computed using `i64`s, and a bound is only moved
+        // past an interval which lies strictly within the bounds of the variable
+        self.variables
+            .iter()
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        loop {
+            let bounds = self
+                .variables
+                .iter()
+                .map(|variable| (context.lower_bound(variable), context.upper_bound(variable)))
+                .collect::<Vec<_>>();
+
+            if !self.propagate_hall_interval(&mut context, &bounds)? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn bounds_are_moved_out_of_hall_intervals() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(2, 5);
+        let w = solver.new_variable(0, 1);
+
+        let mut propagator = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y, z, w].into()))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(z, 3, 5);
+        solver.assert_bounds(w, 0, 0);
+
+        let reason = solver.get_reason_int(predicate![z >= 3].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1])
+        );
+    }
+
+    #[test]
+    fn holes_are_not_taken_into_account() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+        solver.remove(x, 2).expect("no empty domains");
+        solver.remove(y, 2).expect("no empty domains");
+
+        let mut propagator = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y, z].into()))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(z, 1, 3);
+    }
+
+    #[test]
+    fn overfull_interval_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 2);
+
+        let result = solver.new_propagator(AllDifferentBoundsPropagator::new([x, y, z].into()));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1] & [z <= 2])
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Contains the propagators for the [all-different](https://sofdem.github.io/gccat/gccat/Calldifferent.html)
+//! constraint, which states that all variables take a distinct value.
+//!
+//! Two propagators are available (see [`AllDifferentPropagationMethod`]):
+//! - [`AllDifferentReginPropagator`] which achieves domain consistency using the matching-based
+//!   filtering algorithm by Régin \[1\].
+//! - [`AllDifferentBoundsPropagator`] which only reasons over the bounds of the variables using
+//!   Hall intervals; this is cheaper but also weaker.
+//!
+//! Both propagators explain their propagations and conflicts using Hall sets; i.e. a set of
+//! variables which together can take exactly as many values (or, for a conflict, fewer values)
+//! as there are variables in the set.
+//!
+//! # Bibliography
+//! \[1\] J.-C. Régin, ‘A filtering algorithm for constraints of difference in CSPs’, in
+//! Proceedings of the Twelfth National Conference on Artificial Intelligence (AAAI-94), 1994, pp.
+//! 362–367.
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+mod bounds;
+mod regin;
+
+pub(crate) use bounds::AllDifferentBoundsPropagator;
+pub(crate) use regin::AllDifferentReginPropagator;
+
+/// The propagation method which is used for the all-different constraint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AllDifferentPropagationMethod {
+    /// Removes every value which cannot be part of a solution of the constraint (i.e. achieves
+    /// domain consistency) using the matching-based algorithm by Régin.
+    #[default]
+    DomainConsistency,
+    /// Only tightens the bounds of the variables based on Hall intervals (i.e. achieves bounds
+    /// consistency); this is cheaper than [`AllDifferentPropagationMethod::DomainConsistency`].
+    BoundsConsistency,
+}
+
+impl Display for AllDifferentPropagationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllDifferentPropagationMethod::DomainConsistency => write!(f, "domain-consistency"),
+            AllDifferentPropagationMethod::BoundsConsistency => write!(f, "bounds-consistency"),
+        }
+    }
+}
+
+/// Returns the predicates which state that the domains of all `variables` are contained in
+/// `values`, which should be sorted in increasing order.
+///
+/// This is used to explain a Hall set; the bounds of `values` are used for every variable, and
+/// every gap in `values` is explained by a disequality.
+fn explain_domains_within<'a, Var: IntegerVariable + 'a>(
+    variables: impl IntoIterator<Item = &'a Var>,
+    values: &[i32],
+) -> Vec<Predicate> {
+    let (Some(&min_value), Some(&max_value)) = (values.first(), values.last()) else {
+        return vec![];
+    };
+
+    let mut explanation = Vec::new();
+    for variable in variables {
+        explanation.push(predicate![variable >= min_value]);
+        explanation.push(predicate![variable <= max_value]);
+
+        for gap in values.windows(2) {
+            for value in gap[0] + 1..gap[1] {
+                explanation.push(predicate![variable != value]);
+            }
+        }
+    }
+
+    explanation
+}
@@ -0,0 +1,490 @@
+use std::rc::Rc;
+
+use super::explain_domains_within;
+use crate::basic_types::HashMap;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Domain-consistent propagator for the constraint `all_different([x_1, \ldots, x_n])` based on
+/// the matching-based filtering algorithm by Régin.
+///
+/// The propagator maintains a maximum matching between the variables and the values in their
+/// domains; if no matching covers all variables then the constraint is violated. Otherwise, a
+/// value `v` is removed from the domain of `x_i` if the edge `(x_i, v)` does not occur in any
+/// maximum matching, which is determined using the strongly connected components of the residual
+/// graph.
+///
+/// A removal of `v` from `x_i` is explained by the Hall set of variables which are forced to take
+/// all values reachable from `v`, and a conflict is explained by a set of variables which have
+/// fewer values in the union of their domains than there are variables.
+#[derive(Clone, Debug)]
+pub(crate) struct AllDifferentReginPropagator<Var> {
+    variables: Rc<[Var]>,
+    /// The value to which every variable was matched in the previous call; this matching is
+    /// repaired (rather than recomputed) upon the next call.
+    matching: Box<[Option<i32>]>,
+}
+
+impl<Var: IntegerVariable + 'static> AllDifferentReginPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>) -> Self {
+        let matching = vec![None; variables.len()].into_boxed_slice();
+        AllDifferentReginPropagator {
+            variables: variables.into(),
+            matching,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for AllDifferentReginPropagator<Var> {
+    fn name(&self) -> &str {
+        "AllDifferentRegin"
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, variable) in self.variables.iter().enumerate() {
+            let _ = context.register(
+                variable.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(index as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        propagate_domain_consistency(&self.variables, &mut self.matching, context)
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.variables
+            .iter()
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        let mut matching = vec![None; self.variables.len()];
+        propagate_domain_consistency(&self.variables, &mut matching, context)
+    }
+}
+
+/// The bipartite graph between the variables and the values in their domains, together with a
+/// matching.
+///
+/// Variables are identified by their index in the array of variables, and values by their index
+/// in [`ValueGraph::values`].
+struct ValueGraph {
+    /// The values which occur in at least one domain, in increasing order.
+    values: Vec<i32>,
+    /// For every variable, the indices of the values in its domain.
+    domains: Vec<Vec<usize>>,
+    /// For every variable, the index of the value it is matched to.
+    variable_to_value: Vec<Option<usize>>,
+    /// For every value, the index of the variable it is matched to.
+    value_to_variable: Vec<Option<usize>>,
+}
+
+impl ValueGraph {
+    fn new<Var: IntegerVariable>(
+        context: &PropagationContextMut,
+        variables: &[Var],
+        matching: &[Option<i32>],
+    ) -> Self {
+        let domains_as_values = variables
+            .iter()
+            .map(|variable| {
+                (context.lower_bound(variable)..=context.upper_bound(variable))
+                    .filter(|&value| context.contains(variable, value))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut values = domains_as_values.concat();
+        values.sort_unstable();
+        values.dedup();
+        let value_indices = values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| (value, index))
+            .collect::<HashMap<_, _>>();
+
+        let domains = domains_as_values
+            .iter()
+            .map(|domain| domain.iter().map(|value| value_indices[value]).collect())
+            .collect();
+
+        let mut graph = ValueGraph {
+            variable_to_value: vec![None; variables.len()],
+            value_to_variable: vec![None; values.len()],
+            values,
+            domains,
+        };
+
+        // Keep the part of the previous matching which is still valid
+        for (variable, matched_value) in matching.iter().enumerate() {
+            let Some(&value) = matched_value.and_then(|value| value_indices.get(&value)) else {
+                continue;
+            };
+            if graph.domains[variable].contains(&value) && graph.value_to_variable[value].is_none()
+            {
+                graph.variable_to_value[variable] = Some(value);
+                graph.value_to_variable[value] = Some(variable);
+            }
+        }
+
+        graph
+    }
+
+    /// Attempts to match `variable` by finding an augmenting path (using depth-first search).
+    ///
+    /// Every value which is visited during the search is marked in `visited`; if no augmenting
+    /// path exists, the visited values are all matched to variables whose domains are contained
+    /// in the visited values.
+    fn augment(&mut self, variable: usize, visited: &mut [bool]) -> bool {
+        for index in 0..self.domains[variable].len() {
+            let value = self.domains[variable][index];
+            if visited[value] {
+                continue;
+            }
+            visited[value] = true;
+
+            let can_match = match self.value_to_variable[value] {
+                None => true,
+                Some(other_variable) => self.augment(other_variable, visited),
+            };
+            if can_match {
+                self.variable_to_value[variable] = Some(value);
+                self.value_to_variable[value] = Some(variable);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn num_nodes(&self) -> usize {
+        // Every variable and value is a node, in addition to a sink node
+        self.domains.len() + self.values.len() + 1
+    }
+
+    fn value_node(&self, value: usize) -> usize {
+        self.domains.len() + value
+    }
+
+    fn sink_node(&self) -> usize {
+        self.domains.len() + self.values.len()
+    }
+
+    /// Returns the successors of every node in the residual graph; the non-matching edges are
+    /// directed from the variables to the values, and the matching edges from the values to the
+    /// variables. The free values have an edge to the sink, which has an edge to every matched
+    /// value; this ensures that edges on an alternating path starting at a free value are part
+    /// of a cycle.
+    fn residual_graph(&self) -> Vec<Vec<usize>> {
+        let mut successors = vec![vec![]; self.num_nodes()];
+
+        for (variable, domain) in self.domains.iter().enumerate() {
+            successors[variable].extend(
+                domain
+                    .iter()
+                    .filter(|&&value| self.variable_to_value[variable] != Some(value))
+                    .map(|&value| self.value_node(value)),
+            );
+        }
+
+        for (value, matched_variable) in self.value_to_variable.iter().enumerate() {
+            let value_node = self.value_node(value);
+            match matched_variable {
+                Some(variable) => {
+                    successors[value_node].push(*variable);
+                    successors[self.sink_node()].push(value_node);
+                }
+                None => successors[value_node].push(self.sink_node()),
+            }
+        }
+
+        successors
+    }
+}
+
+fn propagate_domain_consistency<Var: IntegerVariable + 'static>(
+    variables: &[Var],
+    matching: &mut [Option<i32>],
+    mut context: PropagationContextMut,
+) -> PropagationStatusCP {
+    let mut graph = ValueGraph::new(&context, variables, matching);
+
+    for variable in 0..variables.len() {
+        if graph.variable_to_value[variable].is_some() {
+            continue;
+        }
+
+        let mut visited = vec![false; graph.values.len()];
+        if !graph.augment(variable, &mut visited) {
+            // The variables which were encountered in the search together have fewer values
+            // than there are variables
+            let hall_values = (0..graph.values.len())
+                .filter(|&value| visited[value])
+                .collect::<Vec<_>>();
+            let hall_variables = std::iter::once(variable).chain(
+                hall_values
+                    .iter()
+                    .filter_map(|&value| graph.value_to_variable[value]),
+            );
+
+            return Err(explain_hall_set(variables, &graph, hall_variables, &hall_values).into());
+        }
+    }
+
+    for (variable, matched_value) in matching.iter_mut().enumerate() {
+        *matched_value = graph.variable_to_value[variable].map(|value| graph.values[value]);
+    }
+
+    let successors = graph.residual_graph();
+    let components = strongly_connected_components(&successors);
+
+    let mut explanations: HashMap<usize, Rc<Vec<Predicate>>> = HashMap::default();
+    for (variable, domain) in graph.domains.iter().enumerate() {
+        for &value in domain {
+            let value_node = graph.value_node(value);
+            if graph.variable_to_value[variable] == Some(value)
+                || components[variable] == components[value_node]
+            {
+                continue;
+            }
+
+            let explanation = Rc::clone(explanations.entry(value).or_insert_with(|| {
+                let (hall_variables, hall_values) = reachable_from(&graph, &successors, value_node);
+                Rc::new(explain_hall_set(
+                    variables,
+                    &graph,
+                    hall_variables,
+                    &hall_values,
+                ))
+            }));
+            context.remove(
+                &variables[variable],
+                graph.values[value],
+                move |_context: PropagationContext| explanation.as_ref().clone().into(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the variables and the values (as indices) which are reachable from the provided node
+/// in the residual graph.
+fn reachable_from(
+    graph: &ValueGraph,
+    successors: &[Vec<usize>],
+    node: usize,
+) -> (Vec<usize>, Vec<usize>) {
+    let mut is_reached = vec![false; successors.len()];
+    is_reached[node] = true;
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        for &successor in &successors[node] {
+            if !is_reached[successor] {
+                is_reached[successor] = true;
+                stack.push(successor);
+            }
+        }
+    }
+
+    let variables = (0..graph.domains.len())
+        .filter(|&variable| is_reached[variable])
+        .collect();
+    let values = (0..graph.values.len())
+        .filter(|&value| is_reached[graph.value_node(value)])
+        .collect();
+    (variables, values)
+}
+
+fn explain_hall_set<Var: IntegerVariable>(
+    variables: &[Var],
+    graph: &ValueGraph,
+    hall_variables: impl IntoIterator<Item = usize>,
+    hall_values: &[usize],
+) -> Vec<Predicate> {
+    let values = hall_values
+        .iter()
+        .map(|&value| graph.values[value])
+        .collect::<Vec<_>>();
+
+    explain_domains_within(
+        hall_variables
+            .into_iter()
+            .map(|variable| &variables[variable]),
+        &values,
+    )
+}
+
+/// Computes the strongly connected components of the provided graph using (an iterative version
+/// of) Tarjan's algorithm; returns, for every node, the identifier of its component.
+fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<usize> {
+    let num_nodes = successors.len();
+    let mut index = vec![usize::MAX; num_nodes];
+    let mut low_link = vec![0; num_nodes];
+    let mut is_on_stack = vec![false; num_nodes];
+    let mut component = vec![usize::MAX; num_nodes];
+
+    let mut next_index = 0;
+    let mut num_components = 0;
+    let mut stack = Vec::new();
+    // Contains the nodes which are being visited, together with the position of the next
+    // successor to consider
+    let mut call_stack = Vec::new();
+
+    for root in 0..num_nodes {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        call_stack.push((root, 0));
+        while let Some((node, successor_position)) = call_stack.pop() {
+            if successor_position == 0 {
+                index[node] = next_index;
+                low_link[node] = next_index;
+                next_index += 1;
+                stack.push(node);
+                is_on_stack[node] = true;
+            } else {
+                // Returned from visiting the previous successor
+                let successor = successors[node][successor_position - 1];
+                low_link[node] = low_link[node].min(low_link[successor]);
+            }
+
+            let next_unvisited =
+                successors[node][successor_position..]
+                    .iter()
+                    .position(|&successor| {
+                        if index[successor] == usize::MAX {
+                            return true;
+                        }
+                        if is_on_stack[successor] {
+                            low_link[node] = low_link[node].min(index[successor]);
+                        }
+                        false
+                    });
+
+            if let Some(offset) = next_unvisited {
+                let position = successor_position + offset;
+                call_stack.push((node, position + 1));
+                call_stack.push((successors[node][position], 0));
+                continue;
+            }
+
+            if low_link[node] == index[node] {
+                while let Some(member) = stack.pop() {
+                    is_on_stack[member] = false;
+                    component[member] = num_components;
+                    if member == node {
+                        break;
+                    }
+                }
+                num_components += 1;
+            }
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn values_outside_of_maximum_matchings_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 4);
+
+        let mut propagator = solver
+            .new_propagator(AllDifferentReginPropagator::new([x, y, z].into()))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(z, 3, 4);
+        solver.assert_bounds(x, 1, 2);
+
+        let reason = solver.get_reason_int(predicate![z != 2].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2])
+        );
+    }
+
+    #[test]
+    fn holes_are_created_and_explained() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+        solver.remove(x, 2).expect("no empty domains");
+        solver.remove(y, 2).expect("no empty domains");
+
+        let mut propagator = solver
+            .new_propagator(AllDifferentReginPropagator::new([x, y, z].into()))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(z, 2, 2);
+    }
+
+    #[test]
+    fn hall_set_violation_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 2);
+        let w = solver.new_variable(1, 10);
+
+        let result = solver.new_propagator(AllDifferentReginPropagator::new([w, x, y, z].into()));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        for variable in [x, y, z] {
+            assert!(explanation.contains(predicate![variable <= 2]));
+        }
+        assert!(explanation
+            .iter()
+            .all(|predicate| predicate.get_domain() != Some(w)));
+    }
+
+    #[test]
+    fn strongly_connected_components_are_found() {
+        // 0 -> 1 -> 2 -> 0, 2 -> 3, 3 -> 4 -> 3
+        let successors = vec![vec![1], vec![2], vec![0, 3], vec![4], vec![3]];
+        let components = strongly_connected_components(&successors);
+
+        assert_eq!(components[0], components[1]);
+        assert_eq!(components[1], components[2]);
+        assert_eq!(components[3], components[4]);
+        assert_ne!(components[0], components[3]);
+    }
+}
@@ -0,0 +1,350 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::predicates::Predicate;
+
+/// Propagator for the [among](https://sofdem.github.io/gccat/gccat/Camong.html) constraint, which
+/// states that `count` is equal to the number of variables `x_i` which take a value in the set of
+/// `values`; the [count](https://sofdem.github.io/gccat/gccat/Ccount.html) constraint is the
+/// special case in which there is a single value.
+///
+/// A variable is certainly in the set if its domain is a subset of the set, and it is certainly not
+/// in the set if its domain does not intersect the set. The bounds of `count` are updated based on
+/// the number of variables which are certainly (not) in the set. If the bounds of `count` require
+/// all remaining variables to be in the set (respectively not in the set), then the values outside
+/// of the set (respectively inside the set) are removed from their domains.
+#[derive(Clone, Debug)]
+pub(crate) struct AmongPropagator<Var, CountVar> {
+    variables: Box<[Var]>,
+    /// The values in the set, sorted in increasing order and without duplicates
+    values: Box<[i32]>,
+    count: CountVar,
+}
+
+/// Whether a variable certainly takes a value in the set, certainly does not take a value in the
+/// set, or whether this is not yet known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Membership {
+    In,
+    NotIn,
+    Unknown,
+}
+
+impl<Var, CountVar> AmongPropagator<Var, CountVar>
+where
+    Var: IntegerVariable,
+    CountVar: IntegerVariable,
+{
+    pub(crate) fn new(
+        variables: Box<[Var]>,
+        values: impl IntoIterator<Item = i32>,
+        count: CountVar,
+    ) -> Self {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+
+        AmongPropagator {
+            variables,
+            values: values.into(),
+            count,
+        }
+    }
+
+    fn is_in_set(&self, value: i32) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+
+    fn membership(&self, context: PropagationContext, variable: &Var) -> Membership {
+        let lower_bound = context.lower_bound(variable);
+        let upper_bound = context.upper_bound(variable);
+
+        let contains_value_in_set = self
+            .values
+            .iter()
+            .any(|&value| context.contains(variable, value));
+        if !contains_value_in_set {
+            return Membership::NotIn;
+        }
+
+        // Since the values in the set are sorted, a value which is not in the set is encountered
+        // after at most `values.len() + 1` values of the domain
+        let contains_value_not_in_set = (lower_bound..=upper_bound)
+            .filter(|&value| context.contains(variable, value))
+            .any(|value| !self.is_in_set(value));
+        if contains_value_not_in_set {
+            Membership::Unknown
+        } else {
+            Membership::In
+        }
+    }
+
+    /// The reason for the domain of `variable` being a subset of the set; these are its bounds
+    /// together with the holes between its bounds which are not in the set.
+    fn in_set_reason(&self, context: PropagationContext, variable: &Var) -> Vec<Predicate> {
+        let lower_bound = context.lower_bound(variable);
+        let upper_bound = context.upper_bound(variable);
+
+        let mut reason = vec![
+            predicate![variable >= lower_bound],
+            predicate![variable <= upper_bound],
+        ];
+        reason.extend(
+            (lower_bound..=upper_bound)
+                .filter(|&value| !self.is_in_set(value))
+                .map(|value| predicate![variable != value]),
+        );
+        reason
+    }
+
+    /// The reason for the domain of `variable` not intersecting the set; the values in the set
+    /// which lie outside the bounds of `variable` are explained by its bounds, while the others
+    /// are explained by holes.
+    fn not_in_set_reason(&self, context: PropagationContext, variable: &Var) -> Vec<Predicate> {
+        let lower_bound = context.lower_bound(variable);
+        let upper_bound = context.upper_bound(variable);
+
+        let mut reason = vec![];
+        if self
+            .values
+            .first()
+            .is_some_and(|&value| value < lower_bound)
+        {
+            reason.push(predicate![variable >= lower_bound]);
+        }
+        if self.values.last().is_some_and(|&value| value > upper_bound) {
+            reason.push(predicate![variable <= upper_bound]);
+        }
+        reason.extend(
+            self.values
+                .iter()
+                .filter(|&&value| lower_bound <= value && value <= upper_bound)
+                .map(|&value| predicate![variable != value]),
+        );
+        reason
+    }
+
+    /// The reason consisting of the explanations of the variables with the given membership.
+    fn membership_reason(
+        &self,
+        context: PropagationContext,
+        memberships: &[Membership],
+        membership: Membership,
+    ) -> Vec<Predicate> {
+        self.variables
+            .iter()
+            .zip(memberships)
+            .filter(|&(_, &variable_membership)| variable_membership == membership)
+            .flat_map(|(variable, _)| match membership {
+                Membership::In => self.in_set_reason(context, variable),
+                Membership::NotIn => self.not_in_set_reason(context, variable),
+                Membership::Unknown => unreachable!("unknown memberships have no reason"),
+            })
+            .collect()
+    }
+}
+
+impl<Var, CountVar> Propagator for AmongPropagator<Var, CountVar>
+where
+    Var: IntegerVariable,
+    CountVar: IntegerVariable,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables.iter().enumerate().for_each(|(i, variable)| {
+            let _ = context.register(
+                variable.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(i as u32),
+            );
+        });
+        let _ = context.register(
+            self.count.clone(),
+            DomainEvents::BOUNDS,
+            LocalId::from(self.variables.len() as u32),
+        );
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "Among"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        context.bounds_overflow(&self.count)
+            || self
+                .variables
+                .iter()
+                .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let memberships = self
+            .variables
+            .iter()
+            .map(|variable| self.membership(context.as_readonly(), variable))
+            .collect::<Vec<_>>();
+
+        let num_in = memberships
+            .iter()
+            .filter(|&&membership| membership == Membership::In)
+            .count() as i32;
+        let num_not_in = memberships
+            .iter()
+            .filter(|&&membership| membership == Membership::NotIn)
+            .count() as i32;
+        let num_possibly_in = self.variables.len() as i32 - num_not_in;
+
+        if context.lower_bound(&self.count) < num_in {
+            let reason =
+                self.membership_reason(context.as_readonly(), &memberships, Membership::In);
+            context.set_lower_bound(&self.count, num_in, PropositionalConjunction::from(reason))?;
+        }
+
+        if context.upper_bound(&self.count) > num_possibly_in {
+            let reason =
+                self.membership_reason(context.as_readonly(), &memberships, Membership::NotIn);
+            context.set_upper_bound(
+                &self.count,
+                num_possibly_in,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+
+        let num_unknown = num_possibly_in - num_in;
+        if num_unknown == 0 {
+            return Ok(());
+        }
+
+        if context.lower_bound(&self.count) == num_possibly_in {
+            // All of the variables which can take a value in the set are required to do so
+            let mut reason =
+                self.membership_reason(context.as_readonly(), &memberships, Membership::NotIn);
+            reason.push(predicate![self.count >= num_possibly_in]);
+            let reason = PropositionalConjunction::from(reason);
+
+            for (variable, _) in self
+                .variables
+                .iter()
+                .zip(&memberships)
+                .filter(|&(_, &membership)| membership == Membership::Unknown)
+            {
+                for value in context.lower_bound(variable)..=context.upper_bound(variable) {
+                    if !self.is_in_set(value) && context.contains(variable, value) {
+                        context.remove(variable, value, reason.clone())?;
+                    }
+                }
+            }
+        } else if context.upper_bound(&self.count) == num_in {
+            // None of the variables which are not yet known to take a value in the set can do so
+            let mut reason =
+                self.membership_reason(context.as_readonly(), &memberships, Membership::In);
+            reason.push(predicate![self.count <= num_in]);
+            let reason = PropositionalConjunction::from(reason);
+
+            for (variable, _) in self
+                .variables
+                .iter()
+                .zip(&memberships)
+                .filter(|&(_, &membership)| membership == Membership::Unknown)
+            {
+                for &value in self.values.iter() {
+                    if context.contains(variable, value) {
+                        context.remove(variable, value, reason.clone())?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn bounds_of_count_are_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(5, 6);
+        let z = solver.new_variable(2, 5);
+        let count = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(AmongPropagator::new([x, y, z].into(), [1, 2, 3], count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 1, 2);
+        let reason = solver.get_reason_int(predicate![count >= 1].try_into().unwrap());
+        assert_eq!(conjunction!([x >= 1] & [x <= 2]), *reason);
+        let reason = solver.get_reason_int(predicate![count <= 2].try_into().unwrap());
+        assert_eq!(conjunction!([y >= 5]), *reason);
+    }
+
+    #[test]
+    fn variables_are_forced_into_the_set() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(0, 4);
+        let count = solver.new_variable(2, 2);
+
+        let _ = solver
+            .new_propagator(AmongPropagator::new([x, y].into(), [1, 2, 3], count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 1, 3);
+        let reason = solver.get_reason_int(predicate![y != 0].try_into().unwrap());
+        assert_eq!(conjunction!([count >= 2]), *reason);
+    }
+
+    #[test]
+    fn variables_are_forced_out_of_the_set() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(0, 4);
+        let count = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(AmongPropagator::new([x, y].into(), [2], count))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(y, 2));
+        solver.assert_bounds(count, 1, 1);
+        let reason = solver.get_reason_int(predicate![y != 2].try_into().unwrap());
+        assert_eq!(conjunction!([x >= 2] & [x <= 2] & [count <= 1]), *reason);
+    }
+
+    #[test]
+    fn conflict_is_detected_when_too_many_variables_are_in_the_set() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(2, 2);
+        let count = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(AmongPropagator::new([x, y].into(), [2], count));
+        assert!(result.is_err());
+    }
+}
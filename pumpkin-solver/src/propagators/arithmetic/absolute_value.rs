@@ -3,6 +3,8 @@ use crate::conjunction;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
@@ -10,8 +12,10 @@ use crate::engine::variables::IntegerVariable;
 
 /// Propagator for `absolute = |signed|`, where `absolute` and `signed` are integer variables.
 ///
-/// The propagator is bounds consistent wrt signed. That means that if `signed \in {-2, -1, 1, 2}`,
-/// the propagator will not propagate `[absolute >= 1]`.
+/// The propagator is domain consistent; next to propagating the bounds, it removes the values of
+/// `absolute` for which neither the value nor its negation is in the domain of `signed` (e.g. if
+/// `signed \in {-2, -1, 1, 2}` then it propagates `[absolute != 0]`), and it removes the values of
+/// `signed` for which the absolute value is not in the domain of `absolute`.
 #[derive(Clone, Debug)]
 pub(crate) struct AbsoluteValuePropagator<VA, VB> {
     signed: VA,
@@ -29,24 +33,37 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), crate::predicates::PropositionalConjunction> {
-        let _ = context.register(self.signed.clone(), DomainEvents::BOUNDS, LocalId::from(0));
+        let _ = context.register(self.signed.clone(), DomainEvents::ANY_INT, LocalId::from(0));
         let _ = context.register(
             self.absolute.clone(),
-            DomainEvents::BOUNDS,
+            DomainEvents::ANY_INT,
             LocalId::from(1),
         );
 
         Ok(())
     }
 
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Unit
     }
 
     fn name(&self) -> &str {
         "IntAbs"
     }
 
+    fn describe(&self) -> Option<String> {
+        Some(format!("abs({:?}) == {:?}", self.signed, self.absolute))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The bounds of both variables are negated during propagation, which overflows for
+        // `i32::MIN`
+        context.bounds_overflow(&self.signed)
+            || context.bounds_overflow(&self.absolute)
+            || context.lower_bound(&self.signed) == i32::MIN
+            || context.lower_bound(&self.absolute) == i32::MIN
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -61,7 +78,7 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
         //   when the `signed` is negative).
         // - `signed` is not sign-fixed (i.e. `lower_bound <= 0` and `upper_bound >= 0`), in which
         //   case the lower bound of `absolute` cannot be tightened without looking into specific
-        //   domain values for `signed`, which we don't do.
+        //   domain values for `signed`, which is done when removing the unsupported values below.
         let signed_lb = context.lower_bound(&self.signed);
         let signed_ub = context.upper_bound(&self.signed);
 
@@ -114,6 +131,35 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
             )?;
         }
 
+        // A value `v` of `absolute` is only supported if `signed` can take `v` or `-v`.
+        for value in context.lower_bound(&self.absolute)..=context.upper_bound(&self.absolute) {
+            if context.contains(&self.absolute, value)
+                && !context.contains(&self.signed, value)
+                && !context.contains(&self.signed, -value)
+            {
+                let reason = if value == 0 {
+                    conjunction!([self.signed != 0])
+                } else {
+                    conjunction!([self.signed != value] & [self.signed != -value])
+                };
+                context.remove(&self.absolute, value, reason)?;
+            }
+        }
+
+        // A value `v` of `signed` is only supported if `absolute` can take `|v|`. Note that this
+        // cannot remove a support of a value of `absolute`, hence the propagation is idempotent.
+        for value in context.lower_bound(&self.signed)..=context.upper_bound(&self.signed) {
+            if context.contains(&self.signed, value)
+                && !context.contains(&self.absolute, value.abs())
+            {
+                context.remove(
+                    &self.signed,
+                    value,
+                    conjunction!([self.absolute != value.abs()]),
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -122,6 +168,7 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
 mod tests {
     use super::*;
     use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
 
     #[test]
     fn absolute_bounds_are_propagated_at_initialise() {
@@ -206,4 +253,45 @@ mod tests {
 
         solver.assert_bounds(signed, 3, 5);
     }
+
+    #[test]
+    fn holes_in_signed_are_propagated_to_absolute() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-3, 3);
+        let absolute = solver.new_variable(0, 5);
+        solver.remove(signed, 0).expect("non-empty domain");
+        solver.remove(signed, 2).expect("non-empty domain");
+        solver.remove(signed, -2).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(AbsoluteValuePropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        solver.assert_bounds(absolute, 1, 3);
+        assert!(!solver.contains(absolute, 2));
+
+        let reason = solver.get_reason_int(predicate![absolute != 2].try_into().unwrap());
+        assert_eq!(conjunction!([signed != 2] & [signed != -2]), *reason);
+    }
+
+    #[test]
+    fn holes_in_absolute_are_propagated_to_signed() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-3, 3);
+        let absolute = solver.new_variable(0, 3);
+        solver.remove(absolute, 1).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(AbsoluteValuePropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(signed, 1));
+        assert!(!solver.contains(signed, -1));
+        assert!(solver.contains(signed, 0));
+
+        let reason = solver.get_reason_int(predicate![signed != -1].try_into().unwrap());
+        assert_eq!(conjunction!([absolute != 1]), *reason);
+    }
 }
@@ -0,0 +1,294 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for the constraint `lhs + offset = rhs`, where `lhs` and `rhs` are integer variables
+/// and `offset` is an integer constant.
+///
+/// The propagator is domain consistent; next to making the bounds of both sides equal, the holes
+/// in the domain of one side are mirrored in the domain of the other side. To avoid scanning both
+/// domains every time the propagator is called, it keeps track of which sides have changed since
+/// the last call and only mirrors the holes of those sides.
+#[derive(Clone, Debug)]
+pub(crate) struct BinaryEqualsPropagator<VX, VY> {
+    lhs: VX,
+    rhs: VY,
+    offset: i32,
+    /// Whether `lhs` has changed since the last time its holes were mirrored in `rhs`
+    lhs_has_changed: bool,
+    /// Whether `rhs` has changed since the last time its holes were mirrored in `lhs`
+    rhs_has_changed: bool,
+}
+
+impl<VX, VY> BinaryEqualsPropagator<VX, VY> {
+    pub(crate) fn new(lhs: VX, rhs: VY, offset: i32) -> Self {
+        BinaryEqualsPropagator {
+            lhs,
+            rhs,
+            offset,
+            lhs_has_changed: true,
+            rhs_has_changed: true,
+        }
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable> BinaryEqualsPropagator<VX, VY> {
+    /// Makes the bounds of both sides equal; since a bound can move past holes in the domain, this
+    /// is repeated until neither of the bounds changes.
+    fn propagate_bounds(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        loop {
+            let lhs_lower_bound = context.lower_bound(&self.lhs);
+            let lhs_upper_bound = context.upper_bound(&self.lhs);
+            let rhs_lower_bound = context.lower_bound(&self.rhs);
+            let rhs_upper_bound = context.upper_bound(&self.rhs);
+
+            if lhs_lower_bound + self.offset == rhs_lower_bound
+                && lhs_upper_bound + self.offset == rhs_upper_bound
+            {
+                return Ok(());
+            }
+
+            context.set_lower_bound(
+                &self.rhs,
+                lhs_lower_bound + self.offset,
+                conjunction!([self.lhs >= lhs_lower_bound]),
+            )?;
+            context.set_upper_bound(
+                &self.rhs,
+                lhs_upper_bound + self.offset,
+                conjunction!([self.lhs <= lhs_upper_bound]),
+            )?;
+            context.set_lower_bound(
+                &self.lhs,
+                rhs_lower_bound - self.offset,
+                conjunction!([self.rhs >= rhs_lower_bound]),
+            )?;
+            context.set_upper_bound(
+                &self.lhs,
+                rhs_upper_bound - self.offset,
+                conjunction!([self.rhs <= rhs_upper_bound]),
+            )?;
+        }
+    }
+
+    /// Removes the values from `rhs` which correspond to holes in `lhs`; this assumes that the
+    /// bounds of both sides are equal, in which case no bounds are changed.
+    fn mirror_holes_of_lhs(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for value in context.lower_bound(&self.lhs)..=context.upper_bound(&self.lhs) {
+            if !context.contains(&self.lhs, value)
+                && context.contains(&self.rhs, value + self.offset)
+            {
+                context.remove(
+                    &self.rhs,
+                    value + self.offset,
+                    conjunction!([self.lhs != value]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the values from `lhs` which correspond to holes in `rhs`; this assumes that the
+    /// bounds of both sides are equal, in which case no bounds are changed.
+    fn mirror_holes_of_rhs(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for value in context.lower_bound(&self.rhs)..=context.upper_bound(&self.rhs) {
+            if !context.contains(&self.rhs, value)
+                && context.contains(&self.lhs, value - self.offset)
+            {
+                context.remove(
+                    &self.lhs,
+                    value - self.offset,
+                    conjunction!([self.rhs != value]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable> Propagator for BinaryEqualsPropagator<VX, VY> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.lhs.clone(), DomainEvents::ANY_INT, LocalId::from(0));
+        let _ = context.register(self.rhs.clone(), DomainEvents::ANY_INT, LocalId::from(1));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        "BinaryEq"
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!(
+            "{:?} == {:?}",
+            self.lhs.offset(self.offset),
+            self.rhs
+        ))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The offset is added to the values of `lhs` and subtracted from the values of `rhs`
+        context.bounds_overflow(&self.lhs)
+            || context.bounds_overflow(&self.rhs)
+            || [
+                context.lower_bound(&self.lhs) as i64 + self.offset as i64,
+                context.upper_bound(&self.lhs) as i64 + self.offset as i64,
+                context.lower_bound(&self.rhs) as i64 - self.offset as i64,
+                context.upper_bound(&self.rhs) as i64 - self.offset as i64,
+            ]
+            .into_iter()
+            .any(|value| i32::try_from(value).is_err())
+    }
+
+    fn notify(
+        &mut self,
+        _context: PropagationContext,
+        local_id: LocalId,
+        _event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        if local_id.unpack() == 0 {
+            self.lhs_has_changed = true;
+        } else {
+            self.rhs_has_changed = true;
+        }
+
+        EnqueueDecision::Enqueue
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_bounds(&mut context)?;
+
+        if self.lhs_has_changed {
+            self.mirror_holes_of_lhs(&mut context)?;
+        }
+        if self.rhs_has_changed {
+            self.mirror_holes_of_rhs(&mut context)?;
+        }
+
+        self.lhs_has_changed = false;
+        self.rhs_has_changed = false;
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_bounds(&mut context)?;
+        self.mirror_holes_of_lhs(&mut context)?;
+        self.mirror_holes_of_rhs(&mut context)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn bounds_are_made_equal() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(4, 10);
+
+        let _ = solver
+            .new_propagator(BinaryEqualsPropagator::new(x, y, 2))
+            .expect("non-empty domain");
+
+        solver.assert_bounds(x, 2, 5);
+        solver.assert_bounds(y, 4, 7);
+
+        let reason = solver.get_reason_int(predicate![x >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([y >= 4]), *reason);
+        let reason = solver.get_reason_int(predicate![y <= 7].try_into().unwrap());
+        assert_eq!(conjunction!([x <= 5]), *reason);
+    }
+
+    #[test]
+    fn holes_are_mirrored() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(1, 5);
+        solver.remove(x, 3).expect("non-empty domain");
+        solver.remove(y, 2).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(BinaryEqualsPropagator::new(x, y, 0))
+            .expect("non-empty domain");
+
+        assert!(!solver.contains(x, 2));
+        assert!(!solver.contains(y, 3));
+
+        let reason = solver.get_reason_int(predicate![y != 3].try_into().unwrap());
+        assert_eq!(conjunction!([x != 3]), *reason);
+        let reason = solver.get_reason_int(predicate![x != 2].try_into().unwrap());
+        assert_eq!(conjunction!([y != 2]), *reason);
+    }
+
+    #[test]
+    fn bounds_moving_past_holes_are_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(2, 5);
+        solver.remove(x, 2).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(BinaryEqualsPropagator::new(x, y, 0))
+            .expect("non-empty domain");
+
+        solver.assert_bounds(x, 3, 5);
+        solver.assert_bounds(y, 3, 5);
+    }
+
+    #[test]
+    fn removal_is_mirrored_after_notification() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 4);
+
+        let mut propagator = solver
+            .new_propagator(BinaryEqualsPropagator::new(x, y, -1))
+            .expect("non-empty domain");
+
+        solver.remove(y, 2).expect("non-empty domain");
+        solver.notify_propagator(&mut propagator);
+        solver.propagate(&mut propagator).expect("non-empty domain");
+
+        assert!(!solver.contains(x, 3));
+        solver.assert_bounds(x, 1, 5);
+    }
+
+    #[test]
+    fn disjoint_domains_lead_to_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(5, 6);
+
+        let result = solver.new_propagator(BinaryEqualsPropagator::new(x, y, 1));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::Literal;
+
+/// Bounds-consistent propagator for `max(lhs, rhs) = maximum`, which optionally also propagates
+/// the comparison literal `b <-> (lhs <= rhs)`.
+///
+/// The comparison literal determines which of the two variables is equal to `maximum`; once it is
+/// assigned, the bounds of `maximum` and the selected variable are made equal, and the other
+/// variable is ordered with respect to it. Conversely, the literal is assigned as soon as the
+/// bounds of `lhs` and `rhs` (possibly tightened through `maximum`) determine their order. Note
+/// that `min(lhs, rhs) = minimum` is propagated by this propagator over the negated views of the
+/// variables.
+#[derive(Clone, Debug)]
+pub(crate) struct BinaryMaximumPropagator<VX, VY, VZ> {
+    lhs: VX,
+    rhs: VY,
+    maximum: VZ,
+    comparison: Option<Literal>,
+}
+
+impl<VX, VY, VZ> BinaryMaximumPropagator<VX, VY, VZ> {
+    pub(crate) fn new(lhs: VX, rhs: VY, maximum: VZ, comparison: Option<Literal>) -> Self {
+        BinaryMaximumPropagator {
+            lhs,
+            rhs,
+            maximum,
+            comparison,
+        }
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable, VZ: IntegerVariable>
+    BinaryMaximumPropagator<VX, VY, VZ>
+{
+    /// Propagates `maximum = selected` and `other <= selected` (or `other < selected` if `strict`
+    /// holds), given that the comparison literal is assigned such that `selected` is the maximum.
+    fn propagate_selected<Selected: IntegerVariable, Other: IntegerVariable>(
+        context: &mut PropagationContextMut,
+        selected: &Selected,
+        other: &Other,
+        maximum: &VZ,
+        strict: bool,
+        comparison: Predicate,
+    ) -> PropagationStatusCP {
+        let difference = if strict { 1 } else { 0 };
+
+        let selected_upper_bound = context.upper_bound(selected);
+        context.set_upper_bound(
+            maximum,
+            selected_upper_bound,
+            PropositionalConjunction::from(vec![
+                predicate![selected <= selected_upper_bound],
+                comparison,
+            ]),
+        )?;
+        context.set_upper_bound(
+            other,
+            selected_upper_bound - difference,
+            PropositionalConjunction::from(vec![
+                predicate![selected <= selected_upper_bound],
+                comparison,
+            ]),
+        )?;
+
+        let maximum_lower_bound = context.lower_bound(maximum);
+        context.set_lower_bound(
+            selected,
+            maximum_lower_bound,
+            PropositionalConjunction::from(vec![
+                predicate![maximum >= maximum_lower_bound],
+                comparison,
+            ]),
+        )?;
+
+        let other_lower_bound = context.lower_bound(other);
+        context.set_lower_bound(
+            selected,
+            other_lower_bound + difference,
+            PropositionalConjunction::from(vec![
+                predicate![other >= other_lower_bound],
+                comparison,
+            ]),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable, VZ: IntegerVariable> Propagator
+    for BinaryMaximumPropagator<VX, VY, VZ>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.lhs.clone(), DomainEvents::BOUNDS, LocalId::from(0));
+        let _ = context.register(self.rhs.clone(), DomainEvents::BOUNDS, LocalId::from(1));
+        let _ = context.register(self.maximum.clone(), DomainEvents::BOUNDS, LocalId::from(2));
+        if let Some(comparison) = self.comparison {
+            let _ = context.register_literal(comparison, DomainEvents::ANY_BOOL, LocalId::from(3));
+        }
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        "BinaryMaximum"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        context.bounds_overflow(&self.lhs)
+            || context.bounds_overflow(&self.rhs)
+            || context.bounds_overflow(&self.maximum)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let lhs = &self.lhs;
+        let rhs = &self.rhs;
+        let maximum = &self.maximum;
+
+        // The comparison literal is assigned before any of the bounds are changed, as its reason
+        // should only contain predicates which are already on the trail; if the bounds which are
+        // tightened below determine the order, then this is detected when the propagator is
+        // notified of its own changes
+        if let Some(comparison) = self.comparison {
+            if !context.is_literal_fixed(comparison) {
+                let lhs_lower_bound = context.lower_bound(lhs);
+                let lhs_upper_bound = context.upper_bound(lhs);
+                let rhs_lower_bound = context.lower_bound(rhs);
+                let rhs_upper_bound = context.upper_bound(rhs);
+                if lhs_upper_bound <= rhs_lower_bound {
+                    context.assign_literal(
+                        comparison,
+                        true,
+                        conjunction!([lhs <= lhs_upper_bound] & [rhs >= rhs_lower_bound]),
+                    )?;
+                } else if lhs_lower_bound > rhs_upper_bound {
+                    context.assign_literal(
+                        comparison,
+                        false,
+                        conjunction!([lhs >= lhs_lower_bound] & [rhs <= rhs_upper_bound]),
+                    )?;
+                }
+            }
+        }
+
+        // The maximum is at least as large as either of the variables, and at most the largest of
+        // their upper bounds
+        let lhs_lower_bound = context.lower_bound(lhs);
+        context.set_lower_bound(
+            maximum,
+            lhs_lower_bound,
+            conjunction!([lhs >= lhs_lower_bound]),
+        )?;
+        let rhs_lower_bound = context.lower_bound(rhs);
+        context.set_lower_bound(
+            maximum,
+            rhs_lower_bound,
+            conjunction!([rhs >= rhs_lower_bound]),
+        )?;
+
+        let lhs_upper_bound = context.upper_bound(lhs);
+        let rhs_upper_bound = context.upper_bound(rhs);
+        context.set_upper_bound(
+            maximum,
+            lhs_upper_bound.max(rhs_upper_bound),
+            conjunction!([lhs <= lhs_upper_bound] & [rhs <= rhs_upper_bound]),
+        )?;
+
+        // Neither of the variables can exceed the maximum
+        let maximum_upper_bound = context.upper_bound(maximum);
+        context.set_upper_bound(
+            lhs,
+            maximum_upper_bound,
+            conjunction!([maximum <= maximum_upper_bound]),
+        )?;
+        context.set_upper_bound(
+            rhs,
+            maximum_upper_bound,
+            conjunction!([maximum <= maximum_upper_bound]),
+        )?;
+
+        // If one of the variables cannot reach the lower bound of the maximum, then the other one
+        // has to be equal to the maximum
+        let maximum_lower_bound = context.lower_bound(maximum);
+        let lhs_upper_bound = context.upper_bound(lhs);
+        if maximum_lower_bound > lhs_upper_bound {
+            context.set_lower_bound(
+                rhs,
+                maximum_lower_bound,
+                conjunction!([maximum >= maximum_lower_bound] & [lhs <= lhs_upper_bound]),
+            )?;
+        }
+        let rhs_upper_bound = context.upper_bound(rhs);
+        if maximum_lower_bound > rhs_upper_bound {
+            context.set_lower_bound(
+                lhs,
+                maximum_lower_bound,
+                conjunction!([maximum >= maximum_lower_bound] & [rhs <= rhs_upper_bound]),
+            )?;
+        }
+
+        match self.comparison {
+            Some(comparison) if context.is_literal_true(comparison) => {
+                Self::propagate_selected(&mut context, rhs, lhs, maximum, false, comparison.into())
+            }
+            Some(comparison) if context.is_literal_false(comparison) => Self::propagate_selected(
+                &mut context,
+                lhs,
+                rhs,
+                maximum,
+                true,
+                (!comparison).into(),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn bounds_of_maximum_are_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 4);
+        let y = solver.new_variable(3, 6);
+        let z = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(BinaryMaximumPropagator::new(x, y, z, None))
+            .expect("no empty domains");
+
+        solver.assert_bounds(z, 3, 6);
+        let reason = solver.get_reason_int(predicate![z <= 6].try_into().unwrap());
+        assert_eq!(conjunction!([x <= 4] & [y <= 6]), *reason);
+    }
+
+    #[test]
+    fn other_variable_is_equal_to_maximum() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 4);
+        let y = solver.new_variable(0, 10);
+        let z = solver.new_variable(5, 8);
+
+        let _ = solver
+            .new_propagator(BinaryMaximumPropagator::new(x, y, z, None))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 5, 8);
+        let reason = solver.get_reason_int(predicate![y >= 5].try_into().unwrap());
+        assert_eq!(conjunction!([z >= 5] & [x <= 4]), *reason);
+    }
+
+    #[test]
+    fn comparison_literal_is_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 4);
+        let y = solver.new_variable(4, 6);
+        let z = solver.new_variable(0, 10);
+        let b = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(BinaryMaximumPropagator::new(x, y, z, Some(b)))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_true(b));
+        let reason = solver.get_reason_bool(b, true);
+        assert_eq!(conjunction!([x <= 4] & [y >= 4]), *reason);
+    }
+
+    #[test]
+    fn false_comparison_literal_selects_lhs() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 7);
+        let y = solver.new_variable(3, 6);
+        let z = solver.new_variable(0, 5);
+        let b = solver.new_literal();
+        solver.set_literal(b, false);
+
+        let mut propagator = solver
+            .new_propagator(BinaryMaximumPropagator::new(x, y, z, Some(b)))
+            .expect("no empty domains");
+        solver
+            .propagate_until_fixed_point(&mut propagator)
+            .expect("no empty domains");
+
+        // `x > y >= 3` and `x = z <= 5`
+        solver.assert_bounds(x, 4, 5);
+        solver.assert_bounds(y, 3, 4);
+        solver.assert_bounds(z, 4, 5);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for the constraint `lhs + offset != rhs`, where `lhs` and `rhs` are integer variables
+/// and `offset` is an integer constant.
+///
+/// The propagator is only woken up when one of the two variables is fixed, after which the single
+/// value which the other variable cannot take is removed from its domain. Compared to posting the
+/// constraint as a linear disequality (see
+/// [`LinearNotEqualPropagator`](crate::propagators::linear_not_equal::LinearNotEqualPropagator)),
+/// this avoids maintaining the sum of the fixed terms.
+#[derive(Clone, Debug)]
+pub(crate) struct BinaryNotEqualsPropagator<VX, VY> {
+    lhs: VX,
+    rhs: VY,
+    offset: i32,
+}
+
+impl<VX, VY> BinaryNotEqualsPropagator<VX, VY> {
+    pub(crate) fn new(lhs: VX, rhs: VY, offset: i32) -> Self {
+        BinaryNotEqualsPropagator { lhs, rhs, offset }
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable> Propagator for BinaryNotEqualsPropagator<VX, VY> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.lhs.clone(), DomainEvents::ASSIGN, LocalId::from(0));
+        let _ = context.register(self.rhs.clone(), DomainEvents::ASSIGN, LocalId::from(1));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        "BinaryNe"
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!(
+            "{:?} != {:?}",
+            self.lhs.offset(self.offset),
+            self.rhs
+        ))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The offset is added to the value of `lhs` and subtracted from the value of `rhs`
+        context.bounds_overflow(&self.lhs)
+            || context.bounds_overflow(&self.rhs)
+            || [
+                context.lower_bound(&self.lhs) as i64 + self.offset as i64,
+                context.upper_bound(&self.lhs) as i64 + self.offset as i64,
+                context.lower_bound(&self.rhs) as i64 - self.offset as i64,
+                context.upper_bound(&self.rhs) as i64 - self.offset as i64,
+            ]
+            .into_iter()
+            .any(|value| i32::try_from(value).is_err())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        if context.is_fixed(&self.lhs) {
+            let value = context.lower_bound(&self.lhs);
+            if context.contains(&self.rhs, value + self.offset) {
+                context.remove(
+                    &self.rhs,
+                    value + self.offset,
+                    conjunction!([self.lhs == value]),
+                )?;
+            }
+        }
+
+        if context.is_fixed(&self.rhs) {
+            let value = context.lower_bound(&self.rhs);
+            if context.contains(&self.lhs, value - self.offset) {
+                context.remove(
+                    &self.lhs,
+                    value - self.offset,
+                    conjunction!([self.rhs == value]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn value_is_removed_from_rhs() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(1, 5);
+
+        let _ = solver
+            .new_propagator(BinaryNotEqualsPropagator::new(x, y, 1))
+            .expect("non-empty domain");
+
+        solver.assert_bounds(y, 1, 5);
+        assert!(!solver.contains(y, 3));
+        let reason = solver.get_reason_int(predicate![y != 3].try_into().unwrap());
+        assert_eq!(conjunction!([x == 2]), *reason);
+    }
+
+    #[test]
+    fn value_is_removed_from_lhs() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(4, 4);
+
+        let _ = solver
+            .new_propagator(BinaryNotEqualsPropagator::new(x, y, 1))
+            .expect("non-empty domain");
+
+        assert!(!solver.contains(x, 3));
+        let reason = solver.get_reason_int(predicate![x != 3].try_into().unwrap());
+        assert_eq!(conjunction!([y == 4]), *reason);
+    }
+
+    #[test]
+    fn propagator_is_woken_up_when_variable_is_fixed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+
+        let mut propagator = solver
+            .new_propagator(BinaryNotEqualsPropagator::new(x, y, 0))
+            .expect("non-empty domain");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 3);
+        solver.propagate(&mut propagator).expect("non-empty domain");
+
+        solver.assert_bounds(y, 1, 2);
+    }
+
+    #[test]
+    fn conflict_is_detected_when_both_sides_are_fixed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 2);
+        let y = solver.new_variable(4, 4);
+
+        let result = solver.new_propagator(BinaryNotEqualsPropagator::new(x, y, 2));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// A propagator for `x * y = z` which propagates the bounds of all three variables, regardless of
+/// their signs.
+///
+/// The bounds of `z` are the extremes of the products of the bounds of `x` and `y`. To derive the
+/// bounds of `x` (and symmetrically `y`), the domain of `y` is split into its negative and its
+/// positive part; on each part `x = z / y` is monotone, so the bounds of `x` on that part are
+/// given by the quotients of the bounds. The new bounds of `x` are the hull of both parts, where a
+/// part without any integer quotient is skipped. Note that if both `y` and `z` can be 0, then `x`
+/// can take any value.
+///
+/// Compared to [`IntegerMultiplicationPropagator`], which only reasons about the bounds if the
+/// variables are non-negative, this propagator is bounds consistent over the rationals.
+///
+/// [`IntegerMultiplicationPropagator`]: super::integer_multiplication::IntegerMultiplicationPropagator
+#[derive(Clone, Debug)]
+pub(crate) struct BoundsConsistentMultiplicationPropagator<VX, VY, VZ> {
+    x: VX,
+    y: VY,
+    z: VZ,
+}
+
+impl<VX, VY, VZ> BoundsConsistentMultiplicationPropagator<VX, VY, VZ> {
+    pub(crate) fn new(x: VX, y: VY, z: VZ) -> Self {
+        BoundsConsistentMultiplicationPropagator { x, y, z }
+    }
+}
+
+impl<VX, VY, VZ> Propagator for BoundsConsistentMultiplicationPropagator<VX, VY, VZ>
+where
+    VX: IntegerVariable,
+    VY: IntegerVariable,
+    VZ: IntegerVariable,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.x.clone(), DomainEvents::BOUNDS, LocalId::from(0));
+        let _ = context.register(self.y.clone(), DomainEvents::BOUNDS, LocalId::from(1));
+        let _ = context.register(self.z.clone(), DomainEvents::BOUNDS, LocalId::from(2));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        "IntTimesBounds"
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!("{:?} * {:?} == {:?}", self.x, self.y, self.z))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The products and quotients are computed using `i64`s, only the bounds themselves
+        // should fit in an `i32`
+        context.bounds_overflow(&self.x)
+            || context.bounds_overflow(&self.y)
+            || context.bounds_overflow(&self.z)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        // The bounds of `z` are the extremes of the products of the bounds of `x` and `y`
+        let (x_min, x_max) = context.bounds_i64(x);
+        let (y_min, y_max) = context.bounds_i64(y);
+        let products = [x_min * y_min, x_min * y_max, x_max * y_min, x_max * y_max];
+        let reason = conjunction!(
+            [x >= x_min as i32] & [x <= x_max as i32] & [y >= y_min as i32] & [y <= y_max as i32]
+        );
+        set_bounds(
+            &mut context,
+            z,
+            *products.iter().min().unwrap(),
+            *products.iter().max().unwrap(),
+            reason,
+        )?;
+
+        propagate_factor(&mut context, x, y, z)?;
+        propagate_factor(&mut context, y, x, z)?;
+
+        Ok(())
+    }
+}
+
+/// Propagates the bounds of `factor` in `factor * other = product` by splitting `other` on its
+/// sign.
+fn propagate_factor<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    factor: &VA,
+    other: &VB,
+    product: &VC,
+) -> PropagationStatusCP {
+    let (other_min, other_max) = context.bounds_i64(other);
+    let (product_min, product_max) = context.bounds_i64(product);
+
+    // If both `other` and `product` can be 0, then any value of `factor` is supported
+    if other_min <= 0 && other_max >= 0 && product_min <= 0 && product_max >= 0 {
+        return Ok(());
+    }
+
+    let parts = [
+        (other_min, i64::min(other_max, -1)),
+        (i64::max(other_min, 1), other_max),
+    ];
+    let bounds = parts
+        .into_iter()
+        .filter(|(part_min, part_max)| part_min <= part_max)
+        .filter_map(|(part_min, part_max)| {
+            let quotients = [
+                (product_min, part_min),
+                (product_min, part_max),
+                (product_max, part_min),
+                (product_max, part_max),
+            ];
+            let lower_bound = quotients
+                .iter()
+                .map(|&(numerator, denominator)| div_ceil(numerator, denominator))
+                .min()
+                .unwrap();
+            let upper_bound = quotients
+                .iter()
+                .map(|&(numerator, denominator)| div_floor(numerator, denominator))
+                .max()
+                .unwrap();
+            (lower_bound <= upper_bound).then_some((lower_bound, upper_bound))
+        })
+        .reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)));
+
+    let reason = conjunction!(
+        [other >= other_min as i32]
+            & [other <= other_max as i32]
+            & [product >= product_min as i32]
+            & [product <= product_max as i32]
+    );
+    match bounds {
+        Some((lower_bound, upper_bound)) => {
+            set_bounds(context, factor, lower_bound, upper_bound, reason)
+        }
+        // No value of `other` divides any value of `product`
+        None => Err(reason.into()),
+    }
+}
+
+/// Tightens the bounds of `var` to `[lower_bound, upper_bound]` with the same reason.
+fn set_bounds<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    var: &Var,
+    lower_bound: i64,
+    upper_bound: i64,
+    reason: PropositionalConjunction,
+) -> PropagationStatusCP {
+    // A bound which does not fit in an `i32` does not restrict the domain
+    if let Ok(lower_bound) = i32::try_from(lower_bound) {
+        context.set_lower_bound(var, lower_bound, reason.clone())?;
+    } else if lower_bound > 0 {
+        return Err(reason.into());
+    }
+    if let Ok(upper_bound) = i32::try_from(upper_bound) {
+        context.set_upper_bound(var, upper_bound, reason)?;
+    } else if upper_bound < 0 {
+        return Err(reason.into());
+    }
+
+    Ok(())
+}
+
+/// Computes `floor(numerator / denominator)` for a non-zero `denominator`.
+fn div_floor(numerator: i64, denominator: i64) -> i64 {
+    let quotient = numerator / denominator;
+    if numerator % denominator != 0 && (numerator < 0) != (denominator < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// Computes `ceil(numerator / denominator)` for a non-zero `denominator`.
+fn div_ceil(numerator: i64, denominator: i64) -> i64 {
+    let quotient = numerator / denominator;
+    if numerator % denominator != 0 && (numerator < 0) == (denominator < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn bounds_of_the_product_use_all_signs() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-3, 2);
+        let y = solver.new_variable(-4, 5);
+        let z = solver.new_variable(-100, 100);
+
+        let _ = solver
+            .new_propagator(BoundsConsistentMultiplicationPropagator::new(x, y, z))
+            .expect("no empty domains");
+
+        solver.assert_bounds(z, -15, 12);
+        let reason = solver.get_reason_int(predicate![z >= -15].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x >= -3] & [x <= 2] & [y >= -4] & [y <= 5]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn factor_is_split_on_the_sign_of_the_other_factor() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-100, 100);
+        let y = solver.new_variable(-2, 3);
+        let z = solver.new_variable(6, 12);
+
+        let _ = solver
+            .new_propagator(BoundsConsistentMultiplicationPropagator::new(x, y, z))
+            .expect("no empty domains");
+
+        // Either `y \in [-2, -1]` and `x \in [-12, -3]`, or `y \in [1, 3]` and `x \in [2, 12]`
+        solver.assert_bounds(x, -12, 12);
+        let reason = solver.get_reason_int(predicate![x <= 12].try_into().unwrap());
+        assert_eq!(
+            conjunction!([y >= -2] & [y <= 3] & [z >= 6] & [z <= 12]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn negative_factors_propagate_the_other_factor() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-100, 100);
+        let y = solver.new_variable(-4, -2);
+        let z = solver.new_variable(-9, 7);
+
+        let _ = solver
+            .new_propagator(BoundsConsistentMultiplicationPropagator::new(x, y, z))
+            .expect("no empty domains");
+
+        // `x = z / y` with `z \in [-9, 7]` and `y \in [-4, -2]`
+        solver.assert_bounds(x, -3, 4);
+        let reason = solver.get_reason_int(predicate![x >= -3].try_into().unwrap());
+        assert_eq!(
+            conjunction!([y >= -4] & [y <= -2] & [z >= -9] & [z <= 7]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn zero_factor_with_non_zero_product_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-5, 5);
+        let y = solver.new_variable(0, 0);
+        let z = solver.new_variable(1, 3);
+
+        let result = solver.new_propagator(BoundsConsistentMultiplicationPropagator::new(x, y, z));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rounding_of_quotients_is_towards_the_domain() {
+        assert_eq!(div_floor(-7, 2), -4);
+        assert_eq!(div_floor(7, -2), -4);
+        assert_eq!(div_floor(-7, -2), 3);
+        assert_eq!(div_ceil(-7, 2), -3);
+        assert_eq!(div_ceil(7, 2), 4);
+        assert_eq!(div_ceil(-7, -2), 4);
+    }
+}
@@ -2,6 +2,8 @@ use crate::basic_types::PropagationStatusCP;
 use crate::conjunction;
 use crate::engine::cp::propagation::propagation_context::ReadDomains;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
@@ -43,14 +45,40 @@ where
     VB: IntegerVariable,
     VC: IntegerVariable,
 {
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Unit
     }
 
     fn name(&self) -> &str {
         "Division"
     }
 
+    fn describe(&self) -> Option<String> {
+        Some(format!(
+            "{:?} div {:?} == {:?}",
+            self.numerator, self.denominator, self.rhs
+        ))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        if context.bounds_overflow(&self.numerator)
+            || context.bounds_overflow(&self.denominator)
+            || context.bounds_overflow(&self.rhs)
+        {
+            return true;
+        }
+
+        // The propagator is applied to (negated) views of the variables, and it computes
+        // `(rhs + 1) * denominator` and `numerator + 1`
+        let magnitude =
+            |(lower_bound, upper_bound): (i64, i64)| lower_bound.abs().max(upper_bound.abs());
+        let numerator = magnitude(context.bounds_i64(&self.numerator));
+        let denominator = magnitude(context.bounds_i64(&self.denominator));
+        let rhs = magnitude(context.bounds_i64(&self.rhs));
+
+        numerator + 1 > i32::MAX as i64 || (rhs + 1) * denominator > i32::MAX as i64
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
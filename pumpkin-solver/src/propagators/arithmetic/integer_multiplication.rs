@@ -1,24 +1,41 @@
+use std::collections::HashSet;
+
 use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
 use crate::conjunction;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::variables::IntegerVariable;
+use crate::predicate;
 use crate::pumpkin_assert_simple;
 
 /// A propagator for maintaining the constraint `a * b = c`. The propagator
 /// (currently) only propagates the signs of the variables, the case where a, b, c >= 0, and detects
 /// a conflict if the variables are fixed.
+///
+/// Optionally, the propagator achieves domain consistency by enumerating the supports of the
+/// values (see [`IntegerMultiplicationPropagator::new_domain_consistent`]); this is only done if
+/// the number of pairs of values of `a` and `b` (and the number of values of `c`) is at most
+/// [`MAX_NUM_ENUMERATED_PAIRS`], otherwise only the bounds reasoning is performed.
 #[derive(Clone, Debug)]
 pub(crate) struct IntegerMultiplicationPropagator<VA, VB, VC> {
     a: VA,
     b: VB,
     c: VC,
+    /// Whether unsupported values are removed from the domains of the variables
+    is_domain_consistent: bool,
 }
 
+/// The maximum number of pairs of values of `a` and `b` for which the supports are enumerated when
+/// the propagator is domain consistent.
+const MAX_NUM_ENUMERATED_PAIRS: i64 = 10_000;
+
 const ID_A: LocalId = LocalId::from(0);
 const ID_B: LocalId = LocalId::from(1);
 const ID_C: LocalId = LocalId::from(2);
@@ -30,7 +47,23 @@ where
     VC: IntegerVariable,
 {
     pub(crate) fn new(a: VA, b: VB, c: VC) -> Self {
-        IntegerMultiplicationPropagator { a, b, c }
+        IntegerMultiplicationPropagator {
+            a,
+            b,
+            c,
+            is_domain_consistent: false,
+        }
+    }
+
+    /// Creates a propagator which, next to the bounds reasoning, removes the values which are not
+    /// part of any solution of `a * b = c` (as long as the domains are small).
+    pub(crate) fn new_domain_consistent(a: VA, b: VB, c: VC) -> Self {
+        IntegerMultiplicationPropagator {
+            a,
+            b,
+            c,
+            is_domain_consistent: true,
+        }
     }
 }
 
@@ -43,7 +76,7 @@ where
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
-    ) -> Result<(), crate::predicates::PropositionalConjunction> {
+    ) -> Result<(), PropositionalConjunction> {
         let _ = context.register(self.a.clone(), DomainEvents::ANY_INT, ID_A);
         let _ = context.register(self.b.clone(), DomainEvents::ANY_INT, ID_B);
         let _ = context.register(self.c.clone(), DomainEvents::ANY_INT, ID_C);
@@ -51,27 +84,129 @@ where
         Ok(())
     }
 
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Unit
     }
 
     fn name(&self) -> &str {
         "IntTimes"
     }
 
-    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
-        perform_propagation(context, &self.a, &self.b, &self.c)
+    fn describe(&self) -> Option<String> {
+        Some(format!("{:?} * {:?} == {:?}", self.a, self.b, self.c))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        if context.bounds_overflow(&self.a)
+            || context.bounds_overflow(&self.b)
+            || context.bounds_overflow(&self.c)
+        {
+            return true;
+        }
+
+        // The products of the bounds of `a` and `b` are computed during propagation
+        let (a_min, a_max) = context.bounds_i64(&self.a);
+        let (b_min, b_max) = context.bounds_i64(&self.b);
+        [a_min * b_min, a_min * b_max, a_max * b_min, a_max * b_max]
+            .into_iter()
+            .any(|product| i32::try_from(product).is_err())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        perform_propagation(&mut context, &self.a, &self.b, &self.c)?;
+
+        if self.is_domain_consistent {
+            let num_pairs = domain_width(&context, &self.a) * domain_width(&context, &self.b);
+            if num_pairs <= MAX_NUM_ENUMERATED_PAIRS
+                && domain_width(&context, &self.c) <= MAX_NUM_ENUMERATED_PAIRS
+            {
+                remove_unsupported_values(&mut context, &self.a, &self.b, &self.c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The number of values between the bounds of the variable.
+fn domain_width<Var: IntegerVariable>(context: &PropagationContextMut, var: &Var) -> i64 {
+    context.upper_bound(var) as i64 - context.lower_bound(var) as i64 + 1
+}
+
+/// The values in the domain of the variable, in increasing order.
+fn domain_values<Var: IntegerVariable>(context: &PropagationContextMut, var: &Var) -> Vec<i32> {
+    (context.lower_bound(var)..=context.upper_bound(var))
+        .filter(|&value| context.contains(var, value))
+        .collect()
+}
+
+/// Removes the values of `a`, `b` and `c` which are not part of any solution of `a * b = c`.
+///
+/// Note that removing the unsupported values of `a` does not remove a support of a value of `b`
+/// (and vice versa), and removing the unsupported values of `c` does not remove any support at
+/// all; hence, a single pass is sufficient.
+fn remove_unsupported_values<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    a: &VA,
+    b: &VB,
+    c: &VC,
+) -> PropagationStatusCP {
+    // A value `v` of `a` is supported if there is a value `w` of `b` such that `v * w` is in the
+    // domain of `c`; the explanation is the domain of `b` together with `c != v * w` for all
+    // the values `w` of `b`.
+    let b_values = domain_values(context, b);
+    for v in domain_values(context, a) {
+        if !b_values.iter().any(|&w| context.contains(c, v * w)) {
+            let mut reason = context.describe_domain(b);
+            reason.extend(b_values.iter().map(|&w| predicate![c != v * w]));
+            context.remove(a, v, PropositionalConjunction::from(reason))?;
+        }
+    }
+
+    let a_values = domain_values(context, a);
+    for w in b_values {
+        if !a_values.iter().any(|&v| context.contains(c, v * w)) {
+            let mut reason = context.describe_domain(a);
+            reason.extend(a_values.iter().map(|&v| predicate![c != v * w]));
+            context.remove(b, w, PropositionalConjunction::from(reason))?;
+        }
+    }
+
+    // A value `z` of `c` is supported if it is the product of a value of `a` and a value of `b`;
+    // the explanation is the domain of `a` together with `b != z / v` for all the values `v` of
+    // `a` which divide `z`.
+    let b_values = domain_values(context, b);
+    let products = a_values
+        .iter()
+        .flat_map(|&v| b_values.iter().map(move |&w| v * w))
+        .collect::<HashSet<_>>();
+    for z in domain_values(context, c) {
+        if !products.contains(&z) {
+            let mut reason = context.describe_domain(a);
+            reason.extend(
+                a_values
+                    .iter()
+                    .filter(|&&v| z.checked_rem(v) == Some(0))
+                    .map(|&v| predicate![b != z / v]),
+            );
+            context.remove(c, z, PropositionalConjunction::from(reason))?;
+        }
     }
+
+    Ok(())
 }
 
 fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable>(
-    mut context: PropagationContextMut,
+    context: &mut PropagationContextMut,
     a: &VA,
     b: &VB,
     c: &VC,
 ) -> PropagationStatusCP {
     // First we propagate the signs
-    propagate_signs(&mut context, a, b, c)?;
+    propagate_signs(context, a, b, c)?;
 
     let a_min = context.lower_bound(a);
     let a_max = context.upper_bound(a);
@@ -360,4 +495,52 @@ mod tests {
         let reason_ub = solver.get_reason_int(predicate![a <= 4].try_into().unwrap());
         assert_eq!(conjunction!([b >= 3] & [c >= 0] & [c <= 12]), *reason_ub);
     }
+
+    #[test]
+    fn domain_consistent_propagator_removes_values_without_support() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(1, 4);
+        let b = solver.new_variable(2, 3);
+        let c = solver.new_variable(0, 12);
+        solver.remove(c, 6).expect("non-empty domain");
+        solver.remove(c, 9).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(IntegerMultiplicationPropagator::new_domain_consistent(
+                a, b, c,
+            ))
+            .expect("no empty domains");
+
+        // `a = 3` is not supported since `3 * 2 = 6` and `3 * 3 = 9` are not in the domain of `c`
+        assert!(!solver.contains(a, 3));
+        let reason = solver.get_reason_int(predicate![a != 3].try_into().unwrap());
+        assert_eq!(
+            conjunction!([b >= 2] & [b <= 3] & [c != 6] & [c != 9]),
+            *reason
+        );
+
+        // The primes larger than 3 cannot be the product of `a` and `b`
+        assert!(!solver.contains(c, 5));
+        assert!(!solver.contains(c, 7));
+        assert!(!solver.contains(c, 11));
+        assert!(solver.contains(c, 8));
+        solver.assert_bounds(c, 2, 12);
+    }
+
+    #[test]
+    fn bounds_propagator_does_not_remove_values_without_support() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(1, 4);
+        let b = solver.new_variable(2, 3);
+        let c = solver.new_variable(0, 12);
+        solver.remove(c, 6).expect("non-empty domain");
+        solver.remove(c, 9).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+            .expect("no empty domains");
+
+        assert!(solver.contains(a, 3));
+        assert!(solver.contains(c, 7));
+    }
 }
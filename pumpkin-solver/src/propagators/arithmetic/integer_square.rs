@@ -0,0 +1,331 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for `x * x = y`, where `x` and `y` are integer variables.
+///
+/// The bounds of `y` are derived from the bounds of `x` after splitting `x` on its sign (e.g. if
+/// `x \in [-3, 2]` then `y \in [0, 9]`), and the bounds of `x` are derived from the bounds of `y`
+/// (e.g. if `y \in [4, 10]` then `x \in [-3, 3]` and `x \notin [-1, 1]`). Next to the bounds, holes
+/// are filtered in both directions: the bounds of `y` are tightened to squares, the square of a
+/// value is removed from `y` if neither the value nor its negation is in the domain of `x`, and the
+/// values of `x` of which the square is not in the domain of `y` are removed. Note that the values
+/// of `y` in between two squares are not removed, as this would create many holes.
+#[derive(Clone, Debug)]
+pub(crate) struct IntegerSquarePropagator<VX, VY> {
+    x: VX,
+    y: VY,
+}
+
+impl<VX, VY> IntegerSquarePropagator<VX, VY> {
+    pub(crate) fn new(x: VX, y: VY) -> Self {
+        IntegerSquarePropagator { x, y }
+    }
+}
+
+impl<VX: IntegerVariable, VY: IntegerVariable> Propagator for IntegerSquarePropagator<VX, VY> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.x.clone(), DomainEvents::ANY_INT, LocalId::from(0));
+        let _ = context.register(self.y.clone(), DomainEvents::ANY_INT, LocalId::from(1));
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        "IntSquare"
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!("{:?} * {:?} == {:?}", self.x, self.x, self.y))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The squares are computed using `i64`s, only the bounds themselves should fit in an `i32`
+        context.bounds_overflow(&self.x) || context.bounds_overflow(&self.y)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let x = &self.x;
+        let y = &self.y;
+
+        // The square is never negative
+        context.set_lower_bound(y, 0, conjunction!())?;
+
+        // The bounds of `y` are determined by the sign of `x`; if `x` can be both negative and
+        // positive, then its lower-bound is only tightened by the hole filtering below.
+        let x_min = context.lower_bound(x);
+        let x_max = context.upper_bound(x);
+        set_upper_bound(
+            &mut context,
+            y,
+            i64::max(square(x_min), square(x_max)),
+            conjunction!([x >= x_min] & [x <= x_max]),
+        )?;
+        if x_min >= 0 {
+            set_lower_bound(&mut context, y, square(x_min), conjunction!([x >= x_min]))?;
+        } else if x_max <= 0 {
+            set_lower_bound(&mut context, y, square(x_max), conjunction!([x <= x_max]))?;
+        }
+
+        // `|x| <= floor(sqrt(y_max))` and `|x| >= ceil(sqrt(y_min))`
+        let y_min = context.lower_bound(y);
+        let y_max = context.upper_bound(y);
+        let root_max = floor_sqrt(y_max);
+        let root_min = ceil_sqrt(y_min);
+        context.set_upper_bound(x, root_max, conjunction!([y <= y_max]))?;
+        context.set_lower_bound(x, -root_max, conjunction!([y <= y_max]))?;
+        if root_min > 0 {
+            // The values in `[-root_min + 1, root_min - 1]` are removed from `x`, which is a bound
+            // change if `x` is sign-fixed and a hole otherwise
+            let x_min = context.lower_bound(x);
+            let x_max = context.upper_bound(x);
+            if x_min > -root_min {
+                context.set_lower_bound(
+                    x,
+                    root_min,
+                    conjunction!([y >= y_min] & [x >= -root_min + 1]),
+                )?;
+            } else if x_max < root_min {
+                context.set_upper_bound(
+                    x,
+                    -root_min,
+                    conjunction!([y >= y_min] & [x <= root_min - 1]),
+                )?;
+            } else {
+                for value in -root_min + 1..root_min {
+                    context.remove(x, value, conjunction!([y >= y_min]))?;
+                }
+            }
+        }
+
+        // A value of `y` is only supported if it is the square of a value of `x`; the bounds of
+        // `y` are tightened to squares, after which the squares without a root in `x` are removed
+        let y_min = context.lower_bound(y);
+        let y_max = context.upper_bound(y);
+        let root_min = ceil_sqrt(y_min);
+        let root_max = floor_sqrt(y_max);
+        set_lower_bound(
+            &mut context,
+            y,
+            square(root_min),
+            conjunction!([y >= y_min]),
+        )?;
+        context.set_upper_bound(y, root_max * root_max, conjunction!([y <= y_max]))?;
+        for root in root_min..=root_max {
+            // The square of a root of a value of `y` fits in an `i32`
+            if context.contains(y, root * root)
+                && !context.contains(x, root)
+                && !context.contains(x, -root)
+            {
+                let reason = if root == 0 {
+                    conjunction!([x != 0])
+                } else {
+                    conjunction!([x != root] & [x != -root])
+                };
+                context.remove(y, root * root, reason)?;
+            }
+        }
+
+        // A value of `x` is only supported if its square is in the domain of `y`; these squares fit
+        // in an `i32`, since `x` is bounded by the root of the upper-bound of `y` at this point
+        for value in context.lower_bound(x)..=context.upper_bound(x) {
+            if context.contains(x, value) && !context.contains(y, value * value) {
+                context.remove(x, value, conjunction!([y != value * value]))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the square of `value`, which does not necessarily fit in an `i32`.
+fn square(value: i32) -> i64 {
+    value as i64 * value as i64
+}
+
+/// Tightens the lower-bound of `var` to `lower_bound`; a lower-bound which exceeds the range of an
+/// `i32` is a conflict.
+fn set_lower_bound<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    var: &Var,
+    lower_bound: i64,
+    reason: PropositionalConjunction,
+) -> PropagationStatusCP {
+    match i32::try_from(lower_bound) {
+        Ok(lower_bound) => {
+            context.set_lower_bound(var, lower_bound, reason)?;
+            Ok(())
+        }
+        Err(_) => Err(reason.into()),
+    }
+}
+
+/// Tightens the upper-bound of `var` to `upper_bound`; an upper-bound which exceeds the range of
+/// an `i32` does not restrict the domain.
+fn set_upper_bound<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    var: &Var,
+    upper_bound: i64,
+    reason: PropositionalConjunction,
+) -> PropagationStatusCP {
+    if let Ok(upper_bound) = i32::try_from(upper_bound) {
+        context.set_upper_bound(var, upper_bound, reason)?;
+    }
+    Ok(())
+}
+
+/// Computes `floor(sqrt(value))` for a non-negative `value`.
+fn floor_sqrt(value: i32) -> i32 {
+    let mut root = (value as f64).sqrt() as i64;
+    // Correct for the rounding errors of the floating-point square root
+    while root * root > value as i64 {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= value as i64 {
+        root += 1;
+    }
+    root as i32
+}
+
+/// Computes `ceil(sqrt(value))` for a non-negative `value`.
+fn ceil_sqrt(value: i32) -> i32 {
+    let root = floor_sqrt(value);
+    if root * root == value {
+        root
+    } else {
+        root + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    #[test]
+    fn bounds_of_y_are_derived_from_the_sign_of_x() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-4, -2);
+        let y = solver.new_variable(-10, 100);
+
+        let _ = solver
+            .new_propagator(IntegerSquarePropagator::new(x, y))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y, 4, 16);
+        let reason = solver.get_reason_int(predicate![y >= 4].try_into().unwrap());
+        assert_eq!(conjunction!([x <= -2]), *reason);
+    }
+
+    #[test]
+    fn lower_bound_of_y_creates_a_hole_in_x() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-5, 5);
+        let y = solver.new_variable(3, 10);
+
+        let _ = solver
+            .new_propagator(IntegerSquarePropagator::new(x, y))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, -3, 3);
+        assert!(!solver.contains(x, -1));
+        assert!(!solver.contains(x, 0));
+        assert!(!solver.contains(x, 1));
+        let reason = solver.get_reason_int(predicate![x != 1].try_into().unwrap());
+        assert_eq!(conjunction!([y >= 3]), *reason);
+
+        // The bounds of `y` are tightened to the squares 4 and 9
+        solver.assert_bounds(y, 4, 9);
+        let reason = solver.get_reason_int(predicate![y <= 9].try_into().unwrap());
+        assert_eq!(conjunction!([y <= 10]), *reason);
+    }
+
+    #[test]
+    fn squares_of_removed_values_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-3, 3);
+        let y = solver.new_variable(0, 9);
+        solver.remove(x, 2).expect("non-empty domain");
+        solver.remove(x, -2).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(IntegerSquarePropagator::new(x, y))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(y, 4));
+        let reason = solver.get_reason_int(predicate![y != 4].try_into().unwrap());
+        assert_eq!(conjunction!([x != 2] & [x != -2]), *reason);
+        assert!(solver.contains(y, 1));
+    }
+
+    #[test]
+    fn unsupported_values_of_x_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-3, 3);
+        let y = solver.new_variable(0, 9);
+        solver.remove(y, 4).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(IntegerSquarePropagator::new(x, y))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(x, 2));
+        assert!(!solver.contains(x, -2));
+        let reason = solver.get_reason_int(predicate![x != -2].try_into().unwrap());
+        assert_eq!(conjunction!([y != 4]), *reason);
+    }
+
+    #[test]
+    fn squares_of_large_bounds_do_not_overflow() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-50000, 50000);
+        let y = solver.new_variable(0, 100);
+
+        let _ = solver
+            .new_propagator(IntegerSquarePropagator::new(x, y))
+            .expect("x = 0 and y = 0 is a solution");
+
+        solver.assert_bounds(x, -10, 10);
+        solver.assert_bounds(y, 0, 100);
+    }
+
+    #[test]
+    fn square_which_exceeds_the_bounds_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(50000, 60000);
+        let y = solver.new_variable(0, 100);
+
+        let result = solver.new_propagator(IntegerSquarePropagator::new(x, y));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_square_fixed_value_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(-10, 10);
+        let y = solver.new_variable(7, 8);
+
+        let result = solver.new_propagator(IntegerSquarePropagator::new(x, y));
+        assert!(result.is_err());
+    }
+}
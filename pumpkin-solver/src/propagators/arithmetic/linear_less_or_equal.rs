@@ -5,6 +5,7 @@ use crate::engine::domain_events::DomainEvents;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -99,6 +100,32 @@ where
         }
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        if self.x.iter().any(|x_i| context.bounds_overflow(x_i)) {
+            return true;
+        }
+
+        // The difference between the bounds is used to update the incremental state
+        if self.x.iter().any(|x_i| {
+            let (lower_bound, upper_bound) = context.bounds_i64(x_i);
+            upper_bound - lower_bound > i32::MAX as i64
+        }) {
+            return true;
+        }
+
+        // The bound which is propagated for `x_i` only decreases as the lower bounds of the other
+        // terms increase, so it is largest at the root
+        let lower_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.lower_bound(x_i) as i64)
+            .sum::<i64>();
+        self.x.iter().any(|x_i| {
+            self.c as i64 - (lower_bound_left_hand_side - context.lower_bound(x_i) as i64)
+                > i32::MAX as i64
+        })
+    }
+
     fn notify(
         &mut self,
         context: PropagationContext,
@@ -126,14 +153,24 @@ where
         self.recalculate_incremental_state(context);
     }
 
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Linear
     }
 
     fn name(&self) -> &str {
         "LinearLeq"
     }
 
+    fn describe(&self) -> Option<String> {
+        let sum = self
+            .x
+            .iter()
+            .map(|term| format!("{term:?}"))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        Some(format!("{sum} <= {}", self.c))
+    }
+
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
             return Err(conjunction.into());
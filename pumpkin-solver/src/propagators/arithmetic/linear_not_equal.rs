@@ -9,6 +9,8 @@ use crate::engine::domain_events::DomainEvents;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::LocalIdMap;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -28,6 +30,8 @@ pub(crate) struct LinearNotEqualPropagator<Var> {
     terms: Rc<[Var]>,
     /// The right-hand side of the sum
     rhs: i32,
+    /// Maps the [`LocalId`]s received in the notification methods to the terms
+    local_ids: LocalIdMap<Var>,
 
     /// The number of fixed terms; note that this constraint can only propagate when there is a
     /// single unfixed variable and can only detect conflicts if all variables are assigned
@@ -50,6 +54,7 @@ where
         LinearNotEqualPropagator {
             terms: terms.into(),
             rhs,
+            local_ids: LocalIdMap::default(),
             number_of_fixed_terms: 0,
             fixed_lhs: 0,
             unfixed_variable_has_been_updated: false,
@@ -62,14 +67,51 @@ impl<Var> Propagator for LinearNotEqualPropagator<Var>
 where
     Var: IntegerVariable + 'static,
 {
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Linear
     }
 
     fn name(&self) -> &str {
         "LinearNe"
     }
 
+    fn describe(&self) -> Option<String> {
+        let sum = self
+            .terms
+            .iter()
+            .map(|term| format!("{term:?}"))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        Some(format!("{sum} != {}", self.rhs))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        if self.terms.iter().any(|x_i| context.bounds_overflow(x_i)) {
+            return true;
+        }
+
+        // The left-hand side is the sum over a subset of the terms, so it is between the sum of
+        // the negative lower bounds and the sum of the positive upper bounds
+        let (minimum_lhs, maximum_lhs) =
+            self.terms
+                .iter()
+                .fold((0_i64, 0_i64), |(minimum, maximum), x_i| {
+                    (
+                        minimum + context.lower_bound(x_i).min(0) as i64,
+                        maximum + context.upper_bound(x_i).max(0) as i64,
+                    )
+                });
+
+        [
+            minimum_lhs,
+            maximum_lhs,
+            self.rhs as i64 - minimum_lhs,
+            self.rhs as i64 - maximum_lhs,
+        ]
+        .into_iter()
+        .any(|value| i32::try_from(value).is_err())
+    }
+
     fn notify(
         &mut self,
         context: PropagationContext,
@@ -79,7 +121,7 @@ where
         // We update the number of fixed variables
         self.number_of_fixed_terms += 1;
         // We update the value of the left-hand side with the value of the newly fixed variable
-        self.fixed_lhs += context.lower_bound(&self.terms[local_id.unpack() as usize]);
+        self.fixed_lhs += context.lower_bound(&self.local_ids[local_id]);
 
         // Either the number of fixed variables is the number of terms - 1 in which case we can
         // propagate if it has not been updated before; if it has been updated then we don't need to
@@ -106,7 +148,7 @@ where
         event: OpaqueDomainEvent,
     ) {
         if matches!(
-            self.terms[local_id.unpack() as usize].unpack_event(event),
+            self.local_ids[local_id].unpack_event(event),
             IntDomainEvent::Assign
         ) {
             pumpkin_assert_simple!(
@@ -123,7 +165,7 @@ where
         } else {
             // A removal has been undone
             pumpkin_assert_moderate!(matches!(
-                self.terms[local_id.unpack() as usize].unpack_event(event),
+                self.local_ids[local_id].unpack_event(event),
                 IntDomainEvent::Removal
             ));
 
@@ -136,14 +178,15 @@ where
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
-        self.terms.iter().enumerate().for_each(|(i, x_i)| {
-            let _ = context.register(x_i.clone(), DomainEvents::ASSIGN, LocalId::from(i as u32));
+        self.terms.iter().for_each(|x_i| {
+            let local_id =
+                context.register_mapped(&mut self.local_ids, x_i.clone(), DomainEvents::ASSIGN);
             let _ = context.register_for_backtrack_events(
                 x_i.clone(),
                 DomainEvents::create_with_int_events(enum_set!(
                     IntDomainEvent::Assign | IntDomainEvent::Removal
                 )),
-                LocalId::from(i as u32),
+                local_id,
             );
         });
 
@@ -4,14 +4,22 @@ use crate::conjunction;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::variables::IntegerVariable;
 use crate::predicate;
 
-/// Bounds-consistent propagator which enforces `max(array) = rhs`. Can be constructed through
+/// Domain-consistent propagator which enforces `max(array) = rhs`. Can be constructed through
 /// [`MaximumConstructor`].
+///
+/// Next to propagating the bounds, the propagator removes the values of `rhs` which are not in the
+/// domain of any element, and it removes a value `v` from an element `a_i` if `rhs` cannot take `v`
+/// and no other element can take a value of `rhs` which is larger than `v` (i.e. assigning `a_i` to
+/// `v` would make it the maximum). Note that `min(array) = rhs` is propagated by this propagator
+/// over the negated views of the variables.
 #[derive(Clone, Debug)]
 pub(crate) struct MaximumPropagator<ElementVar, Rhs> {
     array: Box<[ElementVar]>,
@@ -36,26 +44,37 @@ impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> Propagator
             .cloned()
             .enumerate()
             .for_each(|(idx, var)| {
-                let _ =
-                    context.register(var.clone(), DomainEvents::BOUNDS, LocalId::from(idx as u32));
+                let _ = context.register(
+                    var.clone(),
+                    DomainEvents::ANY_INT,
+                    LocalId::from(idx as u32),
+                );
             });
         let _ = context.register(
             self.rhs.clone(),
-            DomainEvents::BOUNDS,
+            DomainEvents::ANY_INT,
             LocalId::from(self.array.len() as u32),
         );
 
         Ok(())
     }
 
-    fn priority(&self) -> u32 {
-        0
+    fn priority(&self) -> Priority {
+        Priority::Linear
     }
 
     fn name(&self) -> &str {
         "Maximum"
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        context.bounds_overflow(&self.rhs)
+            || self
+                .array
+                .iter()
+                .any(|variable| context.bounds_overflow(variable))
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -130,10 +149,128 @@ impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> Propagator
             }
         }
 
+        // Rule 5.
+        // A value v of a_i which is not in D(rhs) is removed if no other element can take a value
+        // of D(rhs) larger than v; this generalises rule 4 to the holes in the domain of rhs.
+        let (largest_supported, second_largest_supported) = self.largest_supported_values(&context);
+        for (i, var) in self.array.iter().enumerate() {
+            // The largest value of rhs which can be taken by an element other than a_i
+            let largest_supported_by_others = match largest_supported {
+                Some((_, Some(sole_supporter))) if sole_supporter == i => second_largest_supported,
+                Some((value, _)) => Some(value),
+                None => None,
+            };
+
+            for value in context.lower_bound(var)..=context.upper_bound(var) {
+                if context.contains(var, value)
+                    && !context.contains(&self.rhs, value)
+                    && largest_supported_by_others.is_none_or(|largest| value >= largest)
+                {
+                    let reason = self.unsupported_element_value_reason(&context, i, value);
+                    context.remove(var, value, reason)?;
+                }
+            }
+        }
+
+        // Removing values from the elements can increase their lower-bounds, which should be
+        // reflected in rhs before its values are checked for supports.
+        let (max_lb_var, max_lb) = self
+            .array
+            .iter()
+            .map(|var| (var, context.lower_bound(var)))
+            .max_by_key(|&(_, lb)| lb)
+            .expect("the array is non-empty");
+        context.set_lower_bound(&self.rhs, max_lb, conjunction!([max_lb_var >= max_lb]))?;
+
+        // Rule 6.
+        // A value v of rhs is removed if none of the elements can take the value v.
+        for value in context.lower_bound(&self.rhs)..=context.upper_bound(&self.rhs) {
+            if context.contains(&self.rhs, value)
+                && !self.array.iter().any(|var| context.contains(var, value))
+            {
+                let reason: PropositionalConjunction = self
+                    .array
+                    .iter()
+                    .map(|var| predicate![var != value])
+                    .collect();
+                context.remove(&self.rhs, value, reason)?;
+            }
+        }
+
         Ok(())
     }
 }
 
+impl<ElementVar: IntegerVariable, Rhs: IntegerVariable> MaximumPropagator<ElementVar, Rhs> {
+    /// Returns the largest value of `rhs` which is in the domain of an element, together with the
+    /// index of that element if it is the only element containing the value. If there is such a
+    /// sole supporter, then the largest value of `rhs` in the domain of another element is also
+    /// returned.
+    fn largest_supported_values(
+        &self,
+        context: &PropagationContextMut,
+    ) -> (Option<(i32, Option<usize>)>, Option<i32>) {
+        let mut largest_supported = None;
+
+        for value in (context.lower_bound(&self.rhs)..=context.upper_bound(&self.rhs)).rev() {
+            if !context.contains(&self.rhs, value) {
+                continue;
+            }
+
+            let mut supporters = self
+                .array
+                .iter()
+                .enumerate()
+                .filter(|(_, var)| context.contains(*var, value))
+                .map(|(i, _)| i);
+
+            match largest_supported {
+                None => match (supporters.next(), supporters.next()) {
+                    (Some(_), Some(_)) => return (Some((value, None)), None),
+                    (Some(sole_supporter), None) => {
+                        largest_supported = Some((value, Some(sole_supporter)))
+                    }
+                    _ => {}
+                },
+                Some((_, sole_supporter)) => {
+                    if supporters.any(|i| Some(i) != sole_supporter) {
+                        return (largest_supported, Some(value));
+                    }
+                }
+            }
+        }
+
+        (largest_supported, None)
+    }
+
+    /// The reason for removing `value` from the element at `index`; the element cannot be the
+    /// maximum since `rhs` cannot take `value`, and none of the other elements can take a value of
+    /// `rhs` which is larger than `value`.
+    fn unsupported_element_value_reason(
+        &self,
+        context: &PropagationContextMut,
+        index: usize,
+        value: i32,
+    ) -> PropositionalConjunction {
+        let rhs_ub = context.upper_bound(&self.rhs);
+        let mut reason = conjunction!([self.rhs != value] & [self.rhs <= rhs_ub]);
+
+        for larger_value in (value..=rhs_ub).skip(1) {
+            if !context.contains(&self.rhs, larger_value) {
+                reason.add(predicate![self.rhs != larger_value]);
+            } else {
+                self.array
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != index)
+                    .for_each(|(_, var)| reason.add(predicate![var != larger_value]));
+            }
+        }
+
+        reason
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,4 +354,48 @@ mod tests {
         solver.assert_bounds(*array.last().unwrap(), 45, 51);
         solver.assert_bounds(rhs, 45, 51);
     }
+
+    #[test]
+    fn values_of_rhs_which_are_not_in_any_element_are_removed() {
+        let mut solver = TestSolver::default();
+
+        let a = solver.new_variable(1, 5);
+        let b = solver.new_variable(1, 5);
+        let rhs = solver.new_variable(1, 5);
+        solver.remove(a, 3).expect("non-empty domain");
+        solver.remove(b, 3).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(MaximumPropagator::new([a, b].into(), rhs))
+            .expect("no empty domain");
+
+        assert!(!solver.contains(rhs, 3));
+        let reason = solver.get_reason_int(predicate![rhs != 3].try_into().unwrap());
+        assert_eq!(conjunction!([a != 3] & [b != 3]), reason.clone());
+    }
+
+    #[test]
+    fn element_values_which_would_be_the_maximum_are_removed() {
+        let mut solver = TestSolver::default();
+
+        let a = solver.new_variable(1, 6);
+        let b = solver.new_variable(1, 3);
+        let rhs = solver.new_variable(1, 6);
+        solver.remove(rhs, 5).expect("non-empty domain");
+
+        let _ = solver
+            .new_propagator(MaximumPropagator::new([a, b].into(), rhs))
+            .expect("no empty domain");
+
+        // If `a = 5` then `a` would be the maximum, since `b` cannot take a value larger than 5
+        assert!(!solver.contains(a, 5));
+        let reason = solver.get_reason_int(predicate![a != 5].try_into().unwrap());
+        assert_eq!(
+            conjunction!([rhs != 5] & [rhs <= 6] & [b != 6]),
+            reason.clone()
+        );
+
+        // The values of `a` below 5 are supported since `b` can take the value 3
+        assert!(solver.contains(a, 4));
+    }
 }
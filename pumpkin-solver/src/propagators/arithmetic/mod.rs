@@ -1,6 +1,13 @@
 pub(crate) mod absolute_value;
+pub(crate) mod binary_equals;
+pub(crate) mod binary_maximum;
+pub(crate) mod binary_not_equals;
+pub(crate) mod bounds_consistent_multiplication;
 pub(crate) mod division;
 pub(crate) mod integer_multiplication;
+pub(crate) mod integer_square;
 pub(crate) mod linear_less_or_equal;
 pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
+pub(crate) mod pseudo_boolean_less_or_equal;
+pub(crate) mod reified_linear_less_or_equal;
@@ -0,0 +1,291 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::BooleanDomainEvent;
+use crate::predicates::Predicate;
+use crate::variables::Literal;
+
+/// Propagator for the pseudo-Boolean constraint `\sum w_i * l_i <= c` over literals.
+///
+/// The constraint is normalised upon creation such that all weights are positive; a term with a
+/// negative weight `w * l` is rewritten as `-w * !l + w`, and terms with a weight of zero are
+/// dropped. The propagator maintains the sum of the weights of the literals which are assigned
+/// true (the counter), and assigns a literal to false if its weight exceeds the remaining slack.
+/// As the literals are sorted by non-increasing weight, propagation stops at the first literal
+/// which fits in the slack.
+///
+/// Explanations only contain as many of the true literals as are needed for the inference; the
+/// literals with the largest weights are selected first.
+#[derive(Clone, Debug)]
+pub(crate) struct PseudoBooleanLessOrEqualPropagator {
+    /// The literals, sorted by non-increasing weight.
+    literals: Box<[Literal]>,
+    /// The value at index `i` is the (positive) weight of `literals[i]`.
+    weights: Box<[i64]>,
+    c: i64,
+
+    /// The sum of the weights of the literals which are assigned true. This is incremental state.
+    lower_bound_left_hand_side: i64,
+}
+
+impl PseudoBooleanLessOrEqualPropagator {
+    pub(crate) fn new(weights: &[i32], literals: &[Literal], c: i32) -> Self {
+        let mut c = c as i64;
+        let mut terms = weights
+            .iter()
+            .zip(literals.iter())
+            .filter(|(&weight, _)| weight != 0)
+            .map(|(&weight, &literal)| {
+                if weight < 0 {
+                    c -= weight as i64;
+                    (-(weight as i64), !literal)
+                } else {
+                    (weight as i64, literal)
+                }
+            })
+            .collect::<Vec<_>>();
+        terms.sort_by_key(|&(weight, _)| std::cmp::Reverse(weight));
+
+        // incremental state will be properly initialized in `Propagator::initialise_at_root`.
+        PseudoBooleanLessOrEqualPropagator {
+            literals: terms.iter().map(|&(_, literal)| literal).collect(),
+            weights: terms.iter().map(|&(weight, _)| weight).collect(),
+            c,
+            lower_bound_left_hand_side: 0,
+        }
+    }
+
+    fn compute_lower_bound_left_hand_side(&self, context: PropagationContext) -> i64 {
+        self.literals
+            .iter()
+            .zip(self.weights.iter())
+            .filter(|(&literal, _)| context.is_literal_true(literal))
+            .map(|(_, &weight)| weight)
+            .sum()
+    }
+
+    /// Returns the true literals with the largest weights such that their weights sum to more
+    /// than `threshold`.
+    fn explain(&self, context: PropagationContext, threshold: i64) -> PropositionalConjunction {
+        let mut sum = 0;
+        let mut reason: Vec<Predicate> = vec![];
+        for (&literal, &weight) in self.literals.iter().zip(self.weights.iter()) {
+            if sum > threshold {
+                break;
+            }
+
+            if context.is_literal_true(literal) {
+                sum += weight;
+                reason.push(literal.into());
+            }
+        }
+
+        reason.into()
+    }
+
+    fn propagate_with_lower_bound(
+        &self,
+        mut context: PropagationContextMut,
+        lower_bound_left_hand_side: i64,
+    ) -> PropagationStatusCP {
+        if lower_bound_left_hand_side > self.c {
+            return Err(self.explain(context.as_readonly(), self.c).into());
+        }
+
+        let slack = self.c - lower_bound_left_hand_side;
+        for (&literal, &weight) in self.literals.iter().zip(self.weights.iter()) {
+            if weight <= slack {
+                break;
+            }
+
+            if !context.is_literal_fixed(literal) {
+                let reason = self.explain(context.as_readonly(), self.c - weight);
+                context.assign_literal(literal, false, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Propagator for PseudoBooleanLessOrEqualPropagator {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.literals.iter().enumerate().for_each(|(i, &literal)| {
+            let _ = context.register_literal(
+                literal,
+                DomainEvents::ASSIGNED_TRUE,
+                LocalId::from(i as u32),
+            );
+        });
+
+        self.lower_bound_left_hand_side =
+            self.compute_lower_bound_left_hand_side(context.as_readonly());
+
+        if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
+            Err(conjunction)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        (self.lower_bound_left_hand_side > self.c).then(|| self.explain(context, self.c))
+    }
+
+    fn notify_literal(
+        &mut self,
+        _context: PropagationContext,
+        local_id: LocalId,
+        _event: BooleanDomainEvent,
+    ) -> EnqueueDecision {
+        self.lower_bound_left_hand_side += self.weights[local_id.unpack() as usize];
+
+        // Only if the slack is smaller than the largest weight, a literal can be propagated
+        if self.c - self.lower_bound_left_hand_side < self.weights[0] {
+            EnqueueDecision::Enqueue
+        } else {
+            EnqueueDecision::Skip
+        }
+    }
+
+    fn synchronise(&mut self, context: PropagationContext) {
+        self.lower_bound_left_hand_side = self.compute_lower_bound_left_hand_side(context);
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "PseudoBooleanLeq"
+    }
+
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        self.propagate_with_lower_bound(context, self.lower_bound_left_hand_side)
+    }
+
+    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        let lower_bound_left_hand_side =
+            self.compute_lower_bound_left_hand_side(context.as_readonly());
+        self.propagate_with_lower_bound(context, lower_bound_left_hand_side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn literals_exceeding_the_slack_are_assigned_false() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+        let d = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                &[1, 2, 3, 5],
+                &[a, b, c, d],
+                6,
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(c, true);
+        solver
+            .propagate_until_fixed_point(&mut propagator)
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(d));
+        assert!(!solver.is_literal_false(a));
+        assert!(!solver.is_literal_false(b));
+    }
+
+    #[test]
+    fn explanation_contains_largest_true_literals() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+        let d = solver.new_literal();
+        solver.set_literal(a, true);
+        solver.set_literal(b, true);
+        solver.set_literal(c, true);
+
+        let _ = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                &[1, 1, 4, 4],
+                &[a, b, c, d],
+                7,
+            ))
+            .expect("no empty domains");
+
+        // `c` alone already excludes `d`, so `a` and `b` are not part of the explanation
+        assert!(solver.is_literal_false(d));
+        let reason = solver.get_reason_bool(d, false);
+        assert_eq!(PropositionalConjunction::from(vec![c.into()]), *reason);
+    }
+
+    #[test]
+    fn negative_weights_are_normalised() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        solver.set_literal(a, true);
+
+        // `2a - 3b <= 0` states that `a` implies `b`
+        let _ = solver
+            .new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+                &[2, -3],
+                &[a, b],
+                0,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_true(b));
+        let reason = solver.get_reason_bool(b, true);
+        assert_eq!(PropositionalConjunction::from(vec![a.into()]), *reason);
+    }
+
+    #[test]
+    fn conflict_is_detected() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+        solver.set_literal(a, true);
+        solver.set_literal(b, true);
+        solver.set_literal(c, true);
+
+        let result = solver.new_propagator(PseudoBooleanLessOrEqualPropagator::new(
+            &[2, 3, 4],
+            &[a, b, c],
+            6,
+        ));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        // `b` and `c` already exceed the right-hand side
+        assert_eq!(
+            PropositionalConjunction::from(vec![c.into(), b.into()]),
+            explanation
+        );
+    }
+}
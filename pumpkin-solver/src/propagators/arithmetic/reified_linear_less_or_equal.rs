@@ -0,0 +1,331 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::variables::Literal;
+
+/// Propagator for the constraint `r -> \sum x_i <= c` or, if it is fully reified, for the
+/// constraint `r <-> \sum x_i <= c`.
+///
+/// Next to propagating the (negated) linear inequality once `r` is assigned, the reification
+/// literal is propagated based on the bounds of the left-hand side; `r` is set to false if the
+/// lower-bound of the sum exceeds `c` and, if the constraint is fully reified, `r` is set to true
+/// if the upper-bound of the sum does not exceed `c`.
+#[derive(Clone, Debug)]
+pub(crate) struct ReifiedLinearLessOrEqualPropagator<Var> {
+    x: Box<[Var]>,
+    c: i32,
+    reification_literal: Literal,
+    /// Whether the constraint `!r -> \sum x_i > c` is also propagated
+    is_fully_reified: bool,
+}
+
+impl<Var> ReifiedLinearLessOrEqualPropagator<Var>
+where
+    Var: IntegerVariable,
+{
+    pub(crate) fn new(
+        x: Box<[Var]>,
+        c: i32,
+        reification_literal: Literal,
+        is_fully_reified: bool,
+    ) -> Self {
+        ReifiedLinearLessOrEqualPropagator {
+            x,
+            c,
+            reification_literal,
+            is_fully_reified,
+        }
+    }
+
+    /// Propagates `\sum x_i <= c` given the reason for the constraint to hold; this is done by
+    /// updating the upper-bounds of the terms.
+    fn propagate_less_or_equal(
+        &self,
+        context: &mut PropagationContextMut,
+        reification: Predicate,
+    ) -> PropagationStatusCP {
+        let lower_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.lower_bound(x_i) as i64)
+            .sum::<i64>();
+
+        if lower_bound_left_hand_side > self.c as i64 {
+            let mut reason = self.lower_bounds_reason(context.as_readonly(), None);
+            reason.add(reification);
+            return Err(reason.into());
+        }
+
+        for (i, x_i) in self.x.iter().enumerate() {
+            let bound = (self.c as i64
+                - (lower_bound_left_hand_side - context.lower_bound(x_i) as i64))
+                .try_into()
+                .expect("Could not fit the lower-bound of lhs in an i32");
+
+            if context.upper_bound(x_i) > bound {
+                let mut reason = self.lower_bounds_reason(context.as_readonly(), Some(i));
+                reason.add(reification);
+                context.set_upper_bound(x_i, bound, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Propagates `\sum x_i > c` given the reason for the constraint to hold; this is done by
+    /// updating the lower-bounds of the terms.
+    fn propagate_greater_than(
+        &self,
+        context: &mut PropagationContextMut,
+        reification: Predicate,
+    ) -> PropagationStatusCP {
+        let upper_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.upper_bound(x_i) as i64)
+            .sum::<i64>();
+
+        if upper_bound_left_hand_side <= self.c as i64 {
+            let mut reason = self.upper_bounds_reason(context.as_readonly(), None);
+            reason.add(reification);
+            return Err(reason.into());
+        }
+
+        for (i, x_i) in self.x.iter().enumerate() {
+            let bound = (self.c as i64 + 1
+                - (upper_bound_left_hand_side - context.upper_bound(x_i) as i64))
+                .try_into()
+                .expect("Could not fit the upper-bound of lhs in an i32");
+
+            if context.lower_bound(x_i) < bound {
+                let mut reason = self.upper_bounds_reason(context.as_readonly(), Some(i));
+                reason.add(reification);
+                context.set_lower_bound(x_i, bound, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The lower-bounds of the terms, excluding the term at index `excluded` (if provided).
+    fn lower_bounds_reason(
+        &self,
+        context: PropagationContext,
+        excluded: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| Some(j) != excluded)
+            .map(|(_, x_j)| predicate![x_j >= context.lower_bound(x_j)])
+            .collect()
+    }
+
+    /// The upper-bounds of the terms, excluding the term at index `excluded` (if provided).
+    fn upper_bounds_reason(
+        &self,
+        context: PropagationContext,
+        excluded: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| Some(j) != excluded)
+            .map(|(_, x_j)| predicate![x_j <= context.upper_bound(x_j)])
+            .collect()
+    }
+}
+
+impl<Var> Propagator for ReifiedLinearLessOrEqualPropagator<Var>
+where
+    Var: IntegerVariable,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let (events, reification_events) = if self.is_fully_reified {
+            (DomainEvents::BOUNDS, DomainEvents::ANY_BOOL)
+        } else {
+            (DomainEvents::LOWER_BOUND, DomainEvents::ASSIGNED_TRUE)
+        };
+
+        self.x.iter().enumerate().for_each(|(i, x_i)| {
+            let _ = context.register(x_i.clone(), events, LocalId::from(i as u32));
+        });
+        let _ = context.register_literal(
+            self.reification_literal,
+            reification_events,
+            LocalId::from(self.x.len() as u32),
+        );
+
+        Ok(())
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        if self.x.iter().any(|x_i| context.bounds_overflow(x_i)) {
+            return true;
+        }
+
+        // The propagated bounds are largest (respectively smallest) at the root
+        let lower_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.lower_bound(x_i) as i64)
+            .sum::<i64>();
+        let upper_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.upper_bound(x_i) as i64)
+            .sum::<i64>();
+        self.x.iter().any(|x_i| {
+            self.c as i64 - (lower_bound_left_hand_side - context.lower_bound(x_i) as i64)
+                > i32::MAX as i64
+                || self.c as i64 + 1
+                    - (upper_bound_left_hand_side - context.upper_bound(x_i) as i64)
+                    < i32::MIN as i64
+        })
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "ReifiedLinearLeq"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let reification_literal = self.reification_literal;
+
+        if context.is_literal_true(reification_literal) {
+            return self.propagate_less_or_equal(&mut context, reification_literal.into());
+        }
+
+        if context.is_literal_false(reification_literal) {
+            if self.is_fully_reified {
+                return self.propagate_greater_than(&mut context, (!reification_literal).into());
+            }
+            return Ok(());
+        }
+
+        let lower_bound_left_hand_side = self
+            .x
+            .iter()
+            .map(|x_i| context.lower_bound(x_i) as i64)
+            .sum::<i64>();
+        if lower_bound_left_hand_side > self.c as i64 {
+            let reason = self.lower_bounds_reason(context.as_readonly(), None);
+            return context.assign_literal(reification_literal, false, reason);
+        }
+
+        if self.is_fully_reified {
+            let upper_bound_left_hand_side = self
+                .x
+                .iter()
+                .map(|x_i| context.upper_bound(x_i) as i64)
+                .sum::<i64>();
+            if upper_bound_left_hand_side <= self.c as i64 {
+                let reason = self.upper_bounds_reason(context.as_readonly(), None);
+                return context.assign_literal(reification_literal, true, reason);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn reification_literal_is_propagated_in_both_directions() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+        let r = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(ReifiedLinearLessOrEqualPropagator::new(
+                [x, y].into(),
+                7,
+                r,
+                true,
+            ))
+            .expect("no empty domains");
+        assert!(!solver.is_literal_false(r) && !solver.is_literal_true(r));
+
+        let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, y, 2);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert!(solver.is_literal_true(r));
+        let reason = solver.get_reason_bool(r, true);
+        assert_eq!(conjunction!([x <= 5] & [y <= 2]), *reason);
+    }
+
+    #[test]
+    fn negation_is_propagated_when_fully_reified() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+        let r = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(ReifiedLinearLessOrEqualPropagator::new(
+                [x, y].into(),
+                7,
+                r,
+                true,
+            ))
+            .expect("no empty domains");
+
+        solver.set_literal(r, false);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(x, 1, 5);
+        solver.assert_bounds(y, 3, 10);
+        let reason = solver.get_reason_int(predicate![y >= 3].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::from(vec![predicate![x <= 5], Predicate::from(!r)]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn half_reified_constraint_only_falsifies_reification_literal() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(4, 5);
+        let y = solver.new_variable(4, 10);
+        let r = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(ReifiedLinearLessOrEqualPropagator::new(
+                [x, y].into(),
+                7,
+                r,
+                false,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(r));
+        let reason = solver.get_reason_bool(r, false);
+        assert_eq!(conjunction!([x >= 4] & [y >= 4]), *reason);
+        solver.assert_bounds(y, 4, 10);
+    }
+}
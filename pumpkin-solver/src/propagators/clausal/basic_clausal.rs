@@ -1,5 +1,6 @@
 use log::warn;
 
+use super::is_clause_propagating;
 use super::ClausalPropagator;
 use crate::basic_types::ClauseReference;
 use crate::basic_types::ConflictInfo;
@@ -31,6 +32,10 @@ impl ClausalPropagator for BasicClausalPropagator {
         let _ = self.watch_lists.push(vec![]);
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.watch_lists.reserve(2 * additional);
+    }
+
     fn get_literal_propagation_clause_reference(
         &self,
         propagated_literal: Literal,
@@ -324,6 +329,39 @@ impl ClausalPropagator for BasicClausalPropagator {
         remove_clause_from_watchers(&mut self.watch_lists[watched_literal2], clause_reference);
     }
 
+    fn remove_clauses_containing(
+        &mut self,
+        literal: Literal,
+        assignments: &AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    ) {
+        pumpkin_assert_simple!(
+            assignments.is_literal_assigned_true(literal)
+                && assignments.is_literal_root_assignment(literal)
+        );
+
+        let mut permanent_clauses = std::mem::take(&mut self.permanent_clauses);
+        permanent_clauses.retain(|&clause_reference| {
+            // The references to deleted clauses are dropped along the way
+            if clause_allocator[clause_reference].is_deleted() {
+                return false;
+            }
+
+            let clause = clause_allocator[clause_reference].get_literal_slice();
+            if !clause.contains(&literal)
+                || is_clause_propagating(assignments, clause_allocator, clause_reference)
+            {
+                return true;
+            }
+
+            self.remove_clause_from_consideration(clause, clause_reference);
+            clause_allocator.delete_clause(clause_reference);
+
+            false
+        });
+        self.permanent_clauses = permanent_clauses;
+    }
+
     fn debug_check_state(
         &self,
         assignments: &AssignmentsPropositional,
@@ -10,6 +10,9 @@ use crate::pumpkin_assert_simple;
 pub(crate) trait ClausalPropagator {
     fn grow(&mut self);
 
+    /// Reserves space for at least `additional` more propositional variables.
+    fn reserve(&mut self, additional: usize);
+
     fn get_literal_propagation_clause_reference(
         &self,
         propagated_literal: Literal,
@@ -70,6 +73,16 @@ pub(crate) trait ClausalPropagator {
         clause_reference: ClauseReference,
     );
 
+    /// Removes the clauses which contain `literal` from consideration and deletes them; this is
+    /// used for a literal which is true at the root, such that these clauses are satisfied. The
+    /// clauses which are the reason for a propagation are kept.
+    fn remove_clauses_containing(
+        &mut self,
+        literal: Literal,
+        assignments: &AssignmentsPropositional,
+        clause_allocator: &mut ClauseAllocator,
+    );
+
     fn debug_check_state(
         &self,
         assignments: &AssignmentsPropositional,
@@ -0,0 +1,582 @@
+use std::cmp::Reverse;
+
+use super::theta_lambda_tree::ThetaLambdaTree;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::options::CumulativeExplanationType;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
+use crate::propagators::util::register_tasks;
+use crate::propagators::ArgTask;
+use crate::propagators::CumulativeParameters;
+use crate::propagators::CumulativePropagatorOptions;
+use crate::propagators::Direction;
+use crate::pumpkin_assert_simple;
+
+/// [`Propagator`] responsible for using edge-finding to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
+/// constraint.
+///
+/// Edge-finding detects that a task `i` has to end after all tasks in a set Ω end, by noting that
+/// the tasks in `Ω ∪ {i}` cannot all be processed before the latest completion time of Ω; the
+/// earliest start time of `i` is then updated based on the energy which the tasks in (subsets of)
+/// Ω require before their latest completion time. The detection is performed using a
+/// [`ThetaLambdaTree`] in `O(n log n)` and the updates are computed in `O(n^2)`, based on \[1\].
+/// The latest start times are updated by applying the same reasoning to the mirrored tasks.
+///
+/// Like energetic reasoning, this reasoning takes into account the parts of the tasks which are
+/// not mandatory; the propagator is meant to be used in addition to a time-table propagator.
+///
+/// The explanations follow the [`CumulativeExplanationType`]; the naive explanation uses the
+/// current bounds of the tasks, while the other explanation types use the weakest bounds which keep
+/// the tasks within the windows used in the reasoning.
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Edge finding filtering algorithm for discrete cumulative resources in
+/// O(kn log n)’, in International Conference on Principles and Practice of Constraint
+/// Programming, 2009, pp. 802–816.
+#[derive(Debug, Clone)]
+pub(crate) struct EdgeFindingPropagator<Var> {
+    /// Stores the input parameters to the cumulative constraint
+    parameters: CumulativeParameters<Var>,
+}
+
+/// A task as seen in the [`Direction`] in which the reasoning is applied; for the mirrored tasks,
+/// time is reversed such that the start time `s` becomes `-s - processing_time`.
+#[derive(Debug, Clone, Copy)]
+struct EdgeFindingTask {
+    /// The index of the task in the parameters of the propagator
+    index: usize,
+    earliest_start: i64,
+    latest_completion: i64,
+    processing_time: i64,
+    resource_usage: i64,
+}
+
+impl EdgeFindingTask {
+    fn energy(&self) -> i64 {
+        self.processing_time * self.resource_usage
+    }
+}
+
+/// A set of tasks (given by their position) which have to be processed in `[start, end)`.
+#[derive(Debug, Clone)]
+struct TaskWindow {
+    tasks: Vec<usize>,
+    start: i64,
+    end: i64,
+}
+
+impl<Var: IntegerVariable + 'static> EdgeFindingPropagator<Var> {
+    pub(crate) fn new(
+        arg_tasks: &[ArgTask<Var>],
+        capacity: i32,
+        cumulative_options: CumulativePropagatorOptions,
+    ) -> EdgeFindingPropagator<Var> {
+        let tasks = create_tasks(arg_tasks);
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.presence.is_none()),
+            "The edge-finding propagator does not support optional tasks"
+        );
+        EdgeFindingPropagator {
+            parameters: CumulativeParameters::new(tasks, capacity, cumulative_options),
+        }
+    }
+
+    /// Returns the tasks in the given [`Direction`], sorted by non-decreasing earliest start time.
+    fn tasks_in_direction(
+        &self,
+        context: PropagationContext,
+        direction: Direction,
+    ) -> Vec<EdgeFindingTask> {
+        let mut tasks = self
+            .parameters
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(index, task)| {
+                let processing_time = task.processing_time() as i64;
+                let (earliest_start, latest_completion) =
+                    direction.time_window(context, &task.start_variable, processing_time);
+
+                EdgeFindingTask {
+                    index,
+                    earliest_start,
+                    latest_completion,
+                    processing_time,
+                    resource_usage: task.resource_usage() as i64,
+                }
+            })
+            .collect::<Vec<_>>();
+        tasks.sort_by_key(|task| task.earliest_start);
+        tasks
+    }
+
+    /// Returns the predicates which ensure that the tasks in the windows are processed within
+    /// them; a task which occurs in multiple windows is explained by the tightest bounds.
+    fn explain_windows(
+        &self,
+        context: PropagationContext,
+        tasks: &[EdgeFindingTask],
+        windows: &[&TaskWindow],
+        direction: Direction,
+    ) -> Vec<Predicate> {
+        let mut bounds = windows
+            .iter()
+            .flat_map(|window| {
+                window.tasks.iter().map(|&position| {
+                    let task = &tasks[position];
+                    (position, window.start, window.end - task.processing_time)
+                })
+            })
+            .collect::<Vec<_>>();
+        bounds.sort_by_key(|&(position, _, _)| position);
+
+        let mut explanation = vec![];
+        for group in bounds.chunk_by(|a, b| a.0 == b.0) {
+            let position = group[0].0;
+            let start = group.iter().map(|&(_, start, _)| start).max();
+            let latest_start = group.iter().map(|&(_, _, end)| end).min();
+            let (Some(start), Some(latest_start)) = (start, latest_start) else {
+                continue;
+            };
+
+            let variable = &self.parameters.tasks[tasks[position].index].start_variable;
+            match self.parameters.options.explanation_type {
+                CumulativeExplanationType::Naive => explanation.extend([
+                    predicate![variable >= context.lower_bound(variable)],
+                    predicate![variable <= context.upper_bound(variable)],
+                ]),
+                CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => {
+                    explanation.extend([
+                        self.earliest_start_predicate(&tasks[position], start, direction),
+                        self.latest_start_predicate(&tasks[position], latest_start, direction),
+                    ])
+                }
+            }
+        }
+
+        explanation
+    }
+
+    /// Returns the predicate stating that the task starts at or after `start` in the given
+    /// [`Direction`].
+    fn earliest_start_predicate(
+        &self,
+        task: &EdgeFindingTask,
+        start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.earliest_start_predicate(
+            &self.parameters.tasks[task.index].start_variable,
+            task.processing_time,
+            start,
+        )
+    }
+
+    /// Returns the predicate stating that the task starts at or before `latest_start` in the
+    /// given [`Direction`].
+    fn latest_start_predicate(
+        &self,
+        task: &EdgeFindingTask,
+        latest_start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.latest_start_predicate(
+            &self.parameters.tasks[task.index].start_variable,
+            task.processing_time,
+            latest_start,
+        )
+    }
+
+    /// Applies edge-finding to the tasks in the given [`Direction`].
+    fn propagate_direction(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let tasks = self.tasks_in_direction(context.as_readonly(), direction);
+        let capacity = self.parameters.capacity as i64;
+
+        let detections = match detect_precedences(&tasks, capacity) {
+            Ok(detections) => detections,
+            Err(overload) => {
+                return Err(self
+                    .explain_windows(context.as_readonly(), &tasks, &[&overload], direction)
+                    .into())
+            }
+        };
+
+        for (task, detection) in detections {
+            let Some((bound, update)) = update_earliest_start(&tasks, capacity, task, &detection)
+            else {
+                continue;
+            };
+
+            let propagating = &tasks[task];
+            let variable = &self.parameters.tasks[propagating.index].start_variable;
+            let mut reason = self.explain_windows(
+                context.as_readonly(),
+                &tasks,
+                &[&detection, &update],
+                direction,
+            );
+            reason.push(match self.parameters.options.explanation_type {
+                CumulativeExplanationType::Naive => match direction {
+                    Direction::Forward => predicate![variable >= context.lower_bound(variable)],
+                    Direction::Mirrored => predicate![variable <= context.upper_bound(variable)],
+                },
+                CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => {
+                    self.earliest_start_predicate(propagating, detection.start, direction)
+                }
+            });
+
+            direction.set_earliest_start(
+                context,
+                variable,
+                propagating.processing_time,
+                bound,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Performs the detection phase of edge-finding over the tasks (sorted by non-decreasing earliest
+/// start time); returns, for every task `i` which has to end after a set of tasks Ω, the window
+/// of the tasks in Ω which cannot be processed together with `i` before the latest completion time
+/// of Ω (note that `i` itself is not part of the window).
+///
+/// If the tasks overload the resource, the overloaded window is returned as error.
+fn detect_precedences(
+    tasks: &[EdgeFindingTask],
+    capacity: i64,
+) -> Result<Vec<(usize, TaskWindow)>, TaskWindow> {
+    let mut tree = ThetaLambdaTree::new(
+        &tasks
+            .iter()
+            .map(|task| (task.earliest_start, task.energy()))
+            .collect::<Vec<_>>(),
+        capacity,
+    );
+
+    let mut by_latest_completion = (0..tasks.len()).collect::<Vec<_>>();
+    by_latest_completion.sort_by_key(|&position| Reverse(tasks[position].latest_completion));
+
+    let mut detections = vec![];
+    for (index, &position) in by_latest_completion.iter().enumerate() {
+        // Θ contains the tasks which have not been considered yet; i.e. the tasks which complete
+        // at or before the current task
+        let theta = &by_latest_completion[index..];
+        let latest_completion = tasks[position].latest_completion;
+
+        if tree.envelope() > capacity * latest_completion {
+            return Err(overloaded_window(
+                tasks,
+                theta,
+                None,
+                capacity,
+                latest_completion,
+            ));
+        }
+
+        while tree.envelope_lambda() > capacity * latest_completion {
+            let responsible = tree
+                .responsible_envelope()
+                .expect("Θ itself does not overload the resource");
+            detections.push((
+                responsible,
+                overloaded_window(tasks, theta, Some(responsible), capacity, latest_completion),
+            ));
+            tree.remove(responsible);
+        }
+
+        tree.gray(position);
+    }
+
+    Ok(detections)
+}
+
+/// Returns the window starting at the latest earliest start time `t` such that the tasks in
+/// `theta` which start at or after `t` (together with `extra`, if provided) have more energy than
+/// can be processed in `[t, end)`.
+fn overloaded_window(
+    tasks: &[EdgeFindingTask],
+    theta: &[usize],
+    extra: Option<usize>,
+    capacity: i64,
+    end: i64,
+) -> TaskWindow {
+    let mut members = theta.to_vec();
+    members.sort_by_key(|&position| Reverse(tasks[position].earliest_start));
+
+    let mut starts = members
+        .iter()
+        .chain(extra.iter())
+        .map(|&position| tasks[position].earliest_start)
+        .collect::<Vec<_>>();
+    starts.sort_unstable_by_key(|&start| Reverse(start));
+    starts.dedup();
+
+    let mut energy = 0;
+    let mut num_included = 0;
+    for start in starts {
+        while num_included < members.len() && tasks[members[num_included]].earliest_start >= start {
+            energy += tasks[members[num_included]].energy();
+            num_included += 1;
+        }
+
+        let extra_energy = match extra {
+            Some(extra) if tasks[extra].earliest_start >= start => tasks[extra].energy(),
+            Some(_) => continue,
+            None => 0,
+        };
+
+        if capacity * start + energy + extra_energy > capacity * end {
+            members.truncate(num_included);
+            return TaskWindow {
+                tasks: members,
+                start,
+                end,
+            };
+        }
+    }
+
+    unreachable!("the envelope of the tasks exceeds the available energy")
+}
+
+/// Computes the new earliest start time of the task at `position` given that it ends after the
+/// tasks in the `detection` window; returns the bound together with the window which was used to
+/// compute it, if it is larger than the current earliest start time.
+fn update_earliest_start(
+    tasks: &[EdgeFindingTask],
+    capacity: i64,
+    position: usize,
+    detection: &TaskWindow,
+) -> Option<(i64, TaskWindow)> {
+    let task = &tasks[position];
+    let mut members = detection.tasks.clone();
+    members.sort_by_key(|&member| Reverse(tasks[member].earliest_start));
+
+    // Every subset Ω' of the tasks which start at or after `t` requires `rest` energy which cannot
+    // be processed while the task is executing
+    let mut best: Option<(i64, usize, i64, i64)> = None;
+    let mut energy = 0;
+    let mut latest_completion = i64::MIN;
+    for (index, &member) in members.iter().enumerate() {
+        energy += tasks[member].energy();
+        latest_completion = latest_completion.max(tasks[member].latest_completion);
+
+        let start = tasks[member].earliest_start;
+        if members
+            .get(index + 1)
+            .is_some_and(|&next| tasks[next].earliest_start == start)
+        {
+            continue;
+        }
+
+        let rest = energy - (capacity - task.resource_usage) * (latest_completion - start);
+        if rest > 0 {
+            let bound = start + (rest + task.resource_usage - 1) / task.resource_usage;
+            if best.is_none_or(|(best_bound, _, _, _)| bound > best_bound) {
+                best = Some((bound, index + 1, start, latest_completion));
+            }
+        }
+    }
+
+    let (bound, num_included, start, end) = best?;
+    if bound <= task.earliest_start {
+        return None;
+    }
+
+    members.truncate(num_included);
+    Some((
+        bound,
+        TaskWindow {
+            tasks: members,
+            start,
+            end,
+        },
+    ))
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for EdgeFindingPropagator<Var> {
+    fn name(&self) -> &str {
+        "CumulativeEdgeFinding"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        register_tasks(&self.parameters.tasks, context, false);
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_direction(&mut context, Direction::Forward)?;
+        self.propagate_direction(&mut context, Direction::Mirrored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    fn options(explanation_type: CumulativeExplanationType) -> CumulativePropagatorOptions {
+        CumulativePropagatorOptions {
+            explanation_type,
+            ..Default::default()
+        }
+    }
+
+    fn arg_tasks<Var: Clone>(tasks: &[(Var, i32, i32)]) -> Vec<ArgTask<Var>> {
+        tasks
+            .iter()
+            .map(|(start_time, processing_time, resource_usage)| ArgTask {
+                start_time: start_time.clone(),
+                processing_time: *processing_time,
+                resource_usage: *resource_usage,
+                presence: None,
+                processing_time_variable: None,
+                resource_usage_variable: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overload_is_detected() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(0, 2);
+
+        let result = solver.new_propagator(EdgeFindingPropagator::new(
+            &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 1, 1)]),
+            1,
+            options(CumulativeExplanationType::BigStep),
+        ));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 0] & [c <= 3])
+        );
+    }
+
+    #[test]
+    fn task_is_pushed_after_other_tasks() {
+        // Tasks a and b have to be processed in [0, 4), and c cannot be processed together with
+        // them before 4; so c has to end after them
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(0, 4);
+
+        let mut propagator = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 2, 1)]),
+                1,
+                options(CumulativeExplanationType::BigStep),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 4, 4);
+        let reason = solver.get_reason_int(predicate![c >= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 0])
+        );
+    }
+
+    #[test]
+    fn energy_of_tasks_with_larger_resource_usage_is_taken_into_account() {
+        // The resource has capacity 2 and a uses it completely in [0, 5), so b has to start
+        // after a
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 4, 2), (b, 3, 2)]),
+                2,
+                options(CumulativeExplanationType::BigStep),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(b, 4, 10);
+        let reason = solver.get_reason_int(predicate![b >= 4].try_into().unwrap());
+        assert_eq!(*reason, conjunction!([a >= 0] & [a <= 1] & [b >= 0]));
+    }
+
+    #[test]
+    fn naive_explanation_uses_current_bounds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(1, 10);
+
+        let mut propagator = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 4, 2), (b, 3, 2)]),
+                2,
+                options(CumulativeExplanationType::Naive),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(b, 4, 10);
+        let reason = solver.get_reason_int(predicate![b >= 4].try_into().unwrap());
+        assert_eq!(*reason, conjunction!([a >= 0] & [a <= 1] & [b >= 1]));
+    }
+
+    #[test]
+    fn mirrored_tasks_update_upper_bounds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(6, 7);
+        let b = solver.new_variable(0, 7);
+
+        let mut propagator = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 4, 2), (b, 3, 2)]),
+                2,
+                options(CumulativeExplanationType::Pointwise),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        // b has to complete before a starts
+        solver.assert_bounds(b, 0, 4);
+        let reason = solver.get_reason_int(predicate![b <= 4].try_into().unwrap());
+        assert_eq!(*reason, conjunction!([a >= 6] & [a <= 7] & [b <= 8]));
+    }
+}
@@ -0,0 +1,5 @@
+mod edge_finding_propagator;
+mod theta_lambda_tree;
+
+pub(crate) use edge_finding_propagator::*;
+pub(crate) use theta_lambda_tree::ThetaLambdaTree;
@@ -0,0 +1,229 @@
+//! A Theta-Lambda tree (see \[1\]) over the tasks of a cumulative constraint; the tree maintains
+//! the energy envelope of a set of tasks Θ, and the maximum envelope which can be obtained by
+//! adding at most one task from a second set Λ to Θ.
+//!
+//! With a capacity of 1 and the processing times as energies, the envelope is the earliest
+//! completion time of the tasks on a unary resource; this is used by the disjunctive propagator.
+//!
+//! # Bibliography
+//! \[1\] P. Vilím, ‘Edge finding filtering algorithm for discrete cumulative resources in
+//! O(kn log n)’, in International Conference on Principles and Practice of Constraint
+//! Programming, 2009, pp. 802–816.
+
+/// The value of the envelope of an empty set of tasks; it is far from [`i64::MIN`] such that
+/// adding energies to it does not overflow.
+const EMPTY_ENVELOPE: i64 = i64::MIN / 4;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    /// The energy of the tasks in Θ in the subtree
+    energy: i64,
+    /// The envelope of the tasks in Θ in the subtree
+    envelope: i64,
+    /// The energy of the tasks in Θ together with at most one task of Λ in the subtree
+    energy_lambda: i64,
+    /// The envelope of the tasks in Θ together with at most one task of Λ in the subtree
+    envelope_lambda: i64,
+    /// The leaf of the task in Λ which is responsible for [`Node::energy_lambda`]
+    responsible_energy: Option<usize>,
+    /// The leaf of the task in Λ which is responsible for [`Node::envelope_lambda`]
+    responsible_envelope: Option<usize>,
+}
+
+impl Node {
+    const EMPTY: Node = Node {
+        energy: 0,
+        envelope: EMPTY_ENVELOPE,
+        energy_lambda: 0,
+        envelope_lambda: EMPTY_ENVELOPE,
+        responsible_energy: None,
+        responsible_envelope: None,
+    };
+
+    fn combine(left: &Node, right: &Node) -> Node {
+        let (energy_lambda, responsible_energy) =
+            if left.energy_lambda + right.energy >= left.energy + right.energy_lambda {
+                (left.energy_lambda + right.energy, left.responsible_energy)
+            } else {
+                (left.energy + right.energy_lambda, right.responsible_energy)
+            };
+
+        let (envelope_lambda, responsible_envelope) = [
+            (right.envelope_lambda, right.responsible_envelope),
+            (
+                left.envelope + right.energy_lambda,
+                right.responsible_energy,
+            ),
+            (
+                left.envelope_lambda + right.energy,
+                left.responsible_envelope,
+            ),
+        ]
+        .into_iter()
+        .max_by_key(|&(envelope, _)| envelope)
+        .expect("there are three candidates");
+
+        Node {
+            energy: left.energy + right.energy,
+            envelope: (left.envelope + right.energy).max(right.envelope),
+            energy_lambda,
+            envelope_lambda,
+            responsible_energy,
+            responsible_envelope,
+        }
+    }
+}
+
+/// A Theta-Lambda tree whose leaves are the tasks ordered by non-decreasing earliest start time;
+/// tasks are identified by their position in this order.
+///
+/// The envelope of a set of tasks Ω is `max_{Ω' ⊆ Ω} (capacity * est_Ω' + e_Ω')` where `e_Ω'` is
+/// the energy of the tasks in `Ω'`; it is a lower-bound on `capacity` times the earliest
+/// completion time of the tasks in Ω.
+#[derive(Debug, Clone)]
+pub(crate) struct ThetaLambdaTree {
+    nodes: Vec<Node>,
+    /// The index of the first leaf in `nodes`
+    first_leaf: usize,
+    /// The `(capacity * earliest_start_time, energy)` of the tasks
+    leaves: Vec<(i64, i64)>,
+}
+
+impl ThetaLambdaTree {
+    /// Creates a tree in which all tasks are in Θ; the tasks are given as
+    /// `(earliest_start_time, energy)` and should be sorted by non-decreasing earliest start time.
+    pub(crate) fn new(tasks: &[(i64, i64)], capacity: i64) -> ThetaLambdaTree {
+        let mut tree = ThetaLambdaTree::new_empty(tasks, capacity);
+
+        for position in 0..tree.leaves.len() {
+            tree.nodes[tree.first_leaf + position] = tree.theta_leaf(position);
+        }
+        for node in (0..tree.first_leaf).rev() {
+            tree.nodes[node] = Node::combine(&tree.nodes[2 * node + 1], &tree.nodes[2 * node + 2]);
+        }
+
+        tree
+    }
+
+    /// Creates a tree in which the tasks are neither in Θ nor in Λ; see [`ThetaLambdaTree::new`]
+    /// for the format of the tasks.
+    pub(crate) fn new_empty(tasks: &[(i64, i64)], capacity: i64) -> ThetaLambdaTree {
+        let first_leaf = tasks.len().next_power_of_two() - 1;
+        ThetaLambdaTree {
+            nodes: vec![Node::EMPTY; first_leaf + tasks.len().next_power_of_two()],
+            first_leaf,
+            leaves: tasks
+                .iter()
+                .map(|&(earliest_start_time, energy)| (capacity * earliest_start_time, energy))
+                .collect(),
+        }
+    }
+
+    /// The leaf of the task when it is in Θ.
+    fn theta_leaf(&self, position: usize) -> Node {
+        let (start_envelope, energy) = self.leaves[position];
+        Node {
+            energy,
+            envelope: start_envelope + energy,
+            energy_lambda: energy,
+            envelope_lambda: start_envelope + energy,
+            responsible_energy: None,
+            responsible_envelope: None,
+        }
+    }
+
+    /// The envelope of Θ.
+    pub(crate) fn envelope(&self) -> i64 {
+        self.nodes[0].envelope
+    }
+
+    /// The largest envelope of Θ together with at most one task from Λ.
+    pub(crate) fn envelope_lambda(&self) -> i64 {
+        self.nodes[0].envelope_lambda
+    }
+
+    /// The task from Λ which is responsible for [`ThetaLambdaTree::envelope_lambda`], if any.
+    pub(crate) fn responsible_envelope(&self) -> Option<usize> {
+        self.nodes[0].responsible_envelope
+    }
+
+    /// Adds the task to Θ (removing it from Λ if it was part of it).
+    pub(crate) fn insert(&mut self, position: usize) {
+        let leaf = self.theta_leaf(position);
+        self.update_leaf(position, leaf);
+    }
+
+    /// Moves the task from Θ to Λ.
+    pub(crate) fn gray(&mut self, position: usize) {
+        let (start_envelope, energy) = self.leaves[position];
+        self.update_leaf(
+            position,
+            Node {
+                energy: 0,
+                envelope: EMPTY_ENVELOPE,
+                energy_lambda: energy,
+                envelope_lambda: start_envelope + energy,
+                responsible_energy: Some(position),
+                responsible_envelope: Some(position),
+            },
+        );
+    }
+
+    /// Removes the task from both Θ and Λ.
+    pub(crate) fn remove(&mut self, position: usize) {
+        self.update_leaf(position, Node::EMPTY);
+    }
+
+    fn update_leaf(&mut self, position: usize, leaf: Node) {
+        let mut node = self.first_leaf + position;
+        self.nodes[node] = leaf;
+
+        while node > 0 {
+            node = (node - 1) / 2;
+            self.nodes[node] = Node::combine(&self.nodes[2 * node + 1], &self.nodes[2 * node + 2]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope_is_maximised_over_suffixes() {
+        // With capacity 2: 2 * 0 + (4 + 2 + 6) = 12, 2 * 3 + (2 + 6) = 14, 2 * 5 + 6 = 16
+        let tree = ThetaLambdaTree::new(&[(0, 4), (3, 2), (5, 6)], 2);
+
+        assert_eq!(tree.envelope(), 16);
+        assert_eq!(tree.envelope_lambda(), 16);
+        assert_eq!(tree.responsible_envelope(), None);
+    }
+
+    #[test]
+    fn grayed_task_is_responsible_for_lambda_envelope() {
+        let mut tree = ThetaLambdaTree::new(&[(0, 4), (3, 2), (5, 6)], 2);
+
+        tree.gray(2);
+        assert_eq!(tree.envelope(), 8);
+        assert_eq!(tree.envelope_lambda(), 16);
+        assert_eq!(tree.responsible_envelope(), Some(2));
+
+        tree.remove(2);
+        assert_eq!(tree.envelope(), 8);
+        assert_eq!(tree.envelope_lambda(), 8);
+        assert_eq!(tree.responsible_envelope(), None);
+    }
+
+    #[test]
+    fn inserted_tasks_are_added_to_theta() {
+        let mut tree = ThetaLambdaTree::new_empty(&[(0, 4), (3, 2), (5, 6)], 1);
+        assert_eq!(tree.envelope(), EMPTY_ENVELOPE);
+
+        tree.insert(0);
+        tree.insert(1);
+        assert_eq!(tree.envelope(), 6);
+
+        tree.insert(2);
+        assert_eq!(tree.envelope(), 12);
+    }
+}
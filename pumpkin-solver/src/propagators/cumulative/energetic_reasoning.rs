@@ -0,0 +1,382 @@
+//! [`Propagator`] for the Cumulative constraint which uses energetic reasoning; see
+//! [`EnergeticReasoningPropagator`] for more information.
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::options::CumulativeExplanationType;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
+use crate::propagators::util::register_tasks;
+use crate::propagators::ArgTask;
+use crate::propagators::CumulativeParameters;
+use crate::propagators::CumulativePropagatorOptions;
+use crate::pumpkin_assert_simple;
+
+/// [`Propagator`] responsible for using energetic reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
+/// constraint.
+///
+/// For an interval `[t_1, t_2)`, the minimal intersection of a task is the minimum amount of time
+/// during which it executes in the interval, regardless of its start time within its bounds. If
+/// the minimal intersections (multiplied by the resource usage) of all tasks exceed the energy
+/// `capacity * (t_2 - t_1)` of the interval, then the constraint is violated. Otherwise, a task
+/// which would use more than the energy left by the other tasks when it starts at its earliest
+/// (latest) start time is pushed towards the end (start) of the interval.
+///
+/// This reasoning is stronger than time-table reasoning since it also takes into account the
+/// parts of the tasks which are not mandatory, but it is more expensive; the propagator is meant
+/// to be used in addition to a time-table propagator. The considered intervals are those starting
+/// at an earliest or latest start time and ending at an earliest or latest completion time (see
+/// \[1\]).
+///
+/// The explanations follow the [`CumulativeExplanationType`]; the naive explanation uses the
+/// current bounds of the tasks, while the other explanation types use the weakest bounds which
+/// result in the same minimal intersections (the notion of time-points used by the pointwise
+/// explanation is not applicable to intervals).
+///
+/// # Bibliography
+/// \[1\] P. Baptiste, C. Le Pape, and W. Nuijten, ‘Constraint-based scheduling: applying
+/// constraint programming to scheduling problems’, vol. 39. Springer Science & Business Media,
+/// 2001.
+#[derive(Debug, Clone)]
+pub(crate) struct EnergeticReasoningPropagator<Var> {
+    /// Stores the input parameters to the cumulative constraint
+    parameters: CumulativeParameters<Var>,
+}
+
+/// The bounds of a task in the interval which is considered.
+#[derive(Debug, Clone, Copy)]
+struct TaskInInterval {
+    earliest_start: i64,
+    latest_start: i64,
+    processing_time: i64,
+    resource_usage: i64,
+    /// The minimal intersection (in time units) of the task with the interval
+    minimal_intersection: i64,
+}
+
+impl<Var: IntegerVariable + 'static> EnergeticReasoningPropagator<Var> {
+    pub(crate) fn new(
+        arg_tasks: &[ArgTask<Var>],
+        capacity: i32,
+        cumulative_options: CumulativePropagatorOptions,
+    ) -> EnergeticReasoningPropagator<Var> {
+        let tasks = create_tasks(arg_tasks);
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.presence.is_none()),
+            "The energetic reasoning propagator does not support optional tasks"
+        );
+        EnergeticReasoningPropagator {
+            parameters: CumulativeParameters::new(tasks, capacity, cumulative_options),
+        }
+    }
+
+    /// Returns the predicates which ensure that the task with index `task` has (at least) the
+    /// given minimal intersection with the interval `[start, end)`.
+    fn explain_minimal_intersection(
+        &self,
+        task: usize,
+        bounds: &TaskInInterval,
+        (start, end): (i64, i64),
+    ) -> [Predicate; 2] {
+        let variable = &self.parameters.tasks[task].start_variable;
+
+        match self.parameters.options.explanation_type {
+            CumulativeExplanationType::Naive => [
+                predicate![variable >= bounds.earliest_start as i32],
+                predicate![variable <= bounds.latest_start as i32],
+            ],
+            CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => {
+                let intersection = bounds.minimal_intersection;
+                [
+                    predicate![variable >= (start + intersection - bounds.processing_time) as i32],
+                    predicate![variable <= (end - intersection) as i32],
+                ]
+            }
+        }
+    }
+
+    /// Returns the predicates which ensure that the tasks (except for `excluded`) use more than
+    /// `energy_bound` energy in the interval; the tasks with the largest energy are selected
+    /// first, to keep the explanation small.
+    fn explain_energy_exceeding(
+        &self,
+        tasks: &[TaskInInterval],
+        interval: (i64, i64),
+        energy_bound: i64,
+        excluded: Option<usize>,
+    ) -> Vec<Predicate> {
+        let mut contributing = (0..tasks.len())
+            .filter(|&task| Some(task) != excluded && tasks[task].minimal_intersection > 0)
+            .collect::<Vec<_>>();
+        contributing
+            .sort_by_key(|&task| -tasks[task].minimal_intersection * tasks[task].resource_usage);
+
+        let mut explanation = vec![];
+        let mut energy = 0;
+        for task in contributing {
+            if energy > energy_bound {
+                break;
+            }
+            energy += tasks[task].minimal_intersection * tasks[task].resource_usage;
+            explanation.extend(self.explain_minimal_intersection(task, &tasks[task], interval));
+        }
+
+        explanation
+    }
+
+    /// Performs the energetic reasoning over the interval `[start, end)`.
+    fn propagate_interval(
+        &self,
+        context: &mut PropagationContextMut,
+        tasks: &mut [TaskInInterval],
+        (start, end): (i64, i64),
+    ) -> PropagationStatusCP {
+        let mut energy = 0;
+        for task in tasks.iter_mut() {
+            task.minimal_intersection = (end - start)
+                .min(task.processing_time)
+                .min(task.earliest_start + task.processing_time - start)
+                .min(end - task.latest_start)
+                .max(0);
+            energy += task.minimal_intersection * task.resource_usage;
+        }
+
+        let available = self.parameters.capacity as i64 * (end - start);
+        if energy > available {
+            return Err(self
+                .explain_energy_exceeding(tasks, (start, end), available, None)
+                .into());
+        }
+
+        for (index, task) in tasks.iter().enumerate() {
+            // The energy which is left for this task by the other tasks; the task can execute
+            // for at most `max_intersection` time units in the interval
+            let slack = available - energy + task.minimal_intersection * task.resource_usage;
+            let max_intersection = slack / task.resource_usage;
+            let energy_bound = available - (max_intersection + 1) * task.resource_usage;
+            let variable = &self.parameters.tasks[index].start_variable;
+            let explain_propagating_task = |lifted: Predicate, current: Predicate| match self
+                .parameters
+                .options
+                .explanation_type
+            {
+                CumulativeExplanationType::Naive => current,
+                CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => lifted,
+            };
+
+            let intersection_at_earliest_start = (task.earliest_start + task.processing_time)
+                .min(end)
+                - task.earliest_start.max(start);
+            if intersection_at_earliest_start > max_intersection {
+                let mut reason =
+                    self.explain_energy_exceeding(tasks, (start, end), energy_bound, Some(index));
+                reason.push(explain_propagating_task(
+                    predicate![
+                        variable >= (start + max_intersection + 1 - task.processing_time) as i32
+                    ],
+                    predicate![variable >= task.earliest_start as i32],
+                ));
+
+                context.set_lower_bound(
+                    variable,
+                    (end - max_intersection) as i32,
+                    PropositionalConjunction::from(reason),
+                )?;
+            }
+
+            let intersection_at_latest_start =
+                (task.latest_start + task.processing_time).min(end) - task.latest_start.max(start);
+            if intersection_at_latest_start > max_intersection {
+                let mut reason =
+                    self.explain_energy_exceeding(tasks, (start, end), energy_bound, Some(index));
+                reason.push(explain_propagating_task(
+                    predicate![variable <= (end - max_intersection - 1) as i32],
+                    predicate![variable <= task.latest_start as i32],
+                ));
+
+                context.set_upper_bound(
+                    variable,
+                    (start + max_intersection - task.processing_time) as i32,
+                    PropositionalConjunction::from(reason),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for EnergeticReasoningPropagator<Var> {
+    fn name(&self) -> &str {
+        "CumulativeEnergeticReasoning"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        register_tasks(&self.parameters.tasks, context, false);
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let mut tasks = self
+            .parameters
+            .tasks
+            .iter()
+            .map(|task| TaskInInterval {
+                earliest_start: context.lower_bound(&task.start_variable) as i64,
+                latest_start: context.upper_bound(&task.start_variable) as i64,
+                processing_time: task.processing_time() as i64,
+                resource_usage: task.resource_usage() as i64,
+                minimal_intersection: 0,
+            })
+            .collect::<Vec<_>>();
+
+        let mut starts = tasks
+            .iter()
+            .flat_map(|task| [task.earliest_start, task.latest_start])
+            .collect::<Vec<_>>();
+        starts.sort_unstable();
+        starts.dedup();
+
+        let mut ends = tasks
+            .iter()
+            .flat_map(|task| {
+                [
+                    task.earliest_start + task.processing_time,
+                    task.latest_start + task.processing_time,
+                ]
+            })
+            .collect::<Vec<_>>();
+        ends.sort_unstable();
+        ends.dedup();
+
+        for &start in &starts {
+            for &end in ends.iter().filter(|&&end| end > start) {
+                self.propagate_interval(&mut context, &mut tasks, (start, end))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    fn options(explanation_type: CumulativeExplanationType) -> CumulativePropagatorOptions {
+        CumulativePropagatorOptions {
+            explanation_type,
+            ..Default::default()
+        }
+    }
+
+    fn arg_tasks<Var: Clone>(start_times: &[Var], processing_time: i32) -> Vec<ArgTask<Var>> {
+        start_times
+            .iter()
+            .map(|start_time| ArgTask {
+                start_time: start_time.clone(),
+                processing_time,
+                resource_usage: 1,
+                presence: None,
+                processing_time_variable: None,
+                resource_usage_variable: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overload_without_mandatory_parts_is_detected() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 3);
+        let b = solver.new_variable(0, 3);
+        let c = solver.new_variable(0, 3);
+
+        let result = solver.new_propagator(EnergeticReasoningPropagator::new(
+            &arg_tasks(&[a, b, c], 2),
+            1,
+            options(CumulativeExplanationType::BigStep),
+        ));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!([a >= 0] & [a <= 3] & [b >= 0] & [b <= 3] & [c >= 0] & [c <= 3])
+        );
+    }
+
+    #[test]
+    fn task_is_pushed_out_of_full_interval() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(1, 10);
+
+        let mut propagator = solver
+            .new_propagator(EnergeticReasoningPropagator::new(
+                &arg_tasks(&[a, b, c], 2),
+                1,
+                options(CumulativeExplanationType::BigStep),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 4, 10);
+        let reason = solver.get_reason_int(predicate![c >= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= -1])
+        );
+    }
+
+    #[test]
+    fn naive_explanation_uses_current_bounds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(1, 10);
+
+        let mut propagator = solver
+            .new_propagator(EnergeticReasoningPropagator::new(
+                &arg_tasks(&[a, b, c], 2),
+                1,
+                options(CumulativeExplanationType::Naive),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        let reason = solver.get_reason_int(predicate![c >= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 1])
+        );
+    }
+}
@@ -1,6 +1,9 @@
 //! Contains the propagators for the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
-//! constraint, currently it contains solely time-tabling propagators (see
-//! [`crate::propagators::cumulative::time_table`] for an explanation).
+//! constraint, currently it contains time-tabling propagators (see
+//! [`crate::propagators::cumulative::time_table`] for an explanation) and propagators which use
+//! energetic reasoning (see [`EnergeticReasoningPropagator`]), edge-finding (see
+//! [`EdgeFindingPropagator`]) and time-table edge-finding (see
+//! [`TimeTableEdgeFindingPropagator`]).
 //!
 //! # Theoretical
 //!
@@ -10,6 +13,9 @@
 //! - The duration of the [`Task`] `p_i` (which is the same for all resources) which cannot be
 //!   interruped
 //! - The constant resource usage `r_i` of the [`Task`] (which can differ for different resources)
+//! - Optionally, a literal `x_i` indicating whether the [`Task`] is present; an absent [`Task`]
+//!   does not use the resource (this is only supported by the non-incremental time-table
+//!   propagators)
 //!
 //! Oftentimes the following notation is used to denote certain significant time points:
 //! - `EST_i` - The earliest starting time, equal to `lb(s_i)`
@@ -111,9 +117,15 @@
 //!     );
 //! }
 //! ```
+mod edge_finding;
+mod energetic_reasoning;
 mod time_table;
+mod time_table_edge_finding;
+pub(crate) use edge_finding::*;
+pub(crate) use energetic_reasoning::*;
 pub use time_table::CumulativeExplanationType;
 pub(crate) use time_table::*;
+pub(crate) use time_table_edge_finding::*;
 mod options;
 pub use options::*;
 
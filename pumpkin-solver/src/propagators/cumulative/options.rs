@@ -25,20 +25,44 @@ pub struct CumulativeOptions {
     /// are variations of time-tabling. The default is incremental time-tabling reasoning over
     /// intervals.
     pub(crate) propagation_method: CumulativePropagationMethod,
+    /// Determines whether energetic reasoning is used in addition to the propagation method;
+    /// this is stronger than time-tabling alone, but it is also more expensive.
+    pub(crate) energetic_reasoning: bool,
+    /// Determines whether edge-finding is used in addition to the propagation method; like
+    /// energetic reasoning, this takes into account the parts of the tasks which are not
+    /// mandatory.
+    pub(crate) edge_finding: bool,
+    /// Determines whether time-table edge-finding is used in addition to the propagation method;
+    /// this combines the mandatory parts of the tasks with the energy of the tasks which have to
+    /// be processed within a time window.
+    pub(crate) time_table_edge_finding: bool,
     /// The options which are passed to the propagator itself
     pub(crate) propagator_options: CumulativePropagatorOptions,
+    /// Determines whether the cumulative constraint is propagated using a specialised propagator
+    /// rather than using time-tabling when the tasks allow it (e.g. the disjunctive propagator
+    /// when no two tasks can execute at the same time).
+    pub(crate) specialisation: bool,
 }
 
 impl CumulativeOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         allow_holes_in_domain: bool,
         explanation_type: CumulativeExplanationType,
         generate_sequence: bool,
         propagation_method: CumulativePropagationMethod,
         incremental_backtracking: bool,
+        energetic_reasoning: bool,
+        edge_finding: bool,
+        time_table_edge_finding: bool,
+        specialisation: bool,
     ) -> Self {
         Self {
             propagation_method,
+            energetic_reasoning,
+            edge_finding,
+            time_table_edge_finding,
+            specialisation,
             propagator_options: CumulativePropagatorOptions {
                 allow_holes_in_domain,
                 explanation_type,
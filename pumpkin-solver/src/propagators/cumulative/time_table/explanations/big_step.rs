@@ -21,10 +21,12 @@ pub(crate) fn create_big_step_propagation_explanation<Var: IntegerVariable + 'st
         .flat_map(|profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= profile.end - profile_task.processing_time + 1
+                    profile_task.start_variable >= profile.end - profile_task.processing_time() + 1
                 ),
                 predicate!(profile_task.start_variable <= profile.start),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -41,10 +43,12 @@ pub(crate) fn create_big_step_conflict_explanation<Var: IntegerVariable + 'stati
             [
                 predicate!(
                     profile_task.start_variable
-                        >= conflict_profile.end - profile_task.processing_time + 1
+                        >= conflict_profile.end - profile_task.processing_time() + 1
                 ),
                 predicate!(profile_task.start_variable <= conflict_profile.start),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -56,7 +60,7 @@ pub(crate) fn create_big_step_predicate_propagating_task_lower_bound_propagation
 where
     Var: IntegerVariable + 'static,
 {
-    predicate!(task.start_variable >= profile.start + 1 - task.processing_time)
+    predicate!(task.start_variable >= profile.start + 1 - task.processing_time())
 }
 
 pub(crate) fn create_big_step_predicate_propagating_task_upper_bound_propagation<Var>(
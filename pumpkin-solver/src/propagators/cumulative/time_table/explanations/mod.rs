@@ -47,8 +47,8 @@ pub enum CumulativeExplanationType {
     /// means that the explanation only concerns a single profile; the selected time-points for
     /// a propagation of task i are constructed as follows in the case of a lower-bound
     /// propagation: `[profile.start, profile.start + i.process_time, profile.start + (2 *
-    /// i.processing_time), ..., profile.end]`. Thus, if the profile is shorter than
-    /// `i.processing_time`, two explanations are generated for the time-points `profile.start`
+    /// i.processing_time()), ..., profile.end]`. Thus, if the profile is shorter than
+    /// `i.processing_time()`, two explanations are generated for the time-points `profile.start`
     /// and `profile.end`.
     ///
     /// ## Conflicts
@@ -91,7 +91,8 @@ pub(crate) fn create_predicate_propagating_task_lower_bound_propagation<
     }
 }
 
-/// Adds the lower-bound predicate of the propagating task to the provided `explanation`.
+/// Adds the lower-bound predicate of the propagating task (and its [`Task::usage_predicates`]) to
+/// the provided `explanation`.
 pub(crate) fn add_propagating_task_predicate_lower_bound<Var: IntegerVariable + 'static>(
     mut explanation: PropositionalConjunction,
     explanation_type: CumulativeExplanationType,
@@ -107,6 +108,9 @@ pub(crate) fn add_propagating_task_predicate_lower_bound<Var: IntegerVariable +
         profile,
         time_point,
     ));
+    for predicate in task.usage_predicates() {
+        explanation.add(predicate);
+    }
     explanation
 }
 
@@ -135,7 +139,8 @@ pub(crate) fn create_predicate_propagating_task_upper_bound_propagation<
     }
 }
 
-/// Adds the upper-bound predicate of the propagating task to the provided `explanation`.
+/// Adds the upper-bound predicate of the propagating task (and its [`Task::usage_predicates`]) to
+/// the provided `explanation`.
 pub(crate) fn add_propagating_task_predicate_upper_bound<Var: IntegerVariable + 'static>(
     mut explanation: PropositionalConjunction,
     explanation_type: CumulativeExplanationType,
@@ -151,5 +156,8 @@ pub(crate) fn add_propagating_task_predicate_upper_bound<Var: IntegerVariable +
         profile,
         time_point,
     ));
+    for predicate in task.usage_predicates() {
+        explanation.add(predicate);
+    }
     explanation
 }
@@ -29,6 +29,8 @@ pub(crate) fn create_naive_propagation_explanation<Var: IntegerVariable + 'stati
                         <= context.upper_bound(&profile_task.start_variable)
                 ),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -56,6 +58,8 @@ where
                         <= context.upper_bound(&profile_task.start_variable)
                 ),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -1,7 +1,6 @@
 use std::rc::Rc;
 
 use crate::engine::cp::propagation::propagation_context::ReadDomains;
-use crate::engine::propagation::propagation_context::HasAssignments;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::EmptyDomain;
 use crate::options::CumulativeExplanationType;
@@ -10,6 +9,7 @@ use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::explanations::add_propagating_task_predicate_lower_bound;
 use crate::propagators::cumulative::time_table::explanations::add_propagating_task_predicate_upper_bound;
+use crate::propagators::cumulative::time_table::propagation_handler::check_explanation;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
 use crate::pumpkin_assert_extreme;
@@ -41,7 +41,7 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
     // completion time - 1 (this - 1 is necessary since the explanation uses the
     // predicate `[s >= t_l + 1 - p]`, and this predicate holds only if the -1 is added)
     let mut time_point = profiles[current_profile_index].end.min(
-        context.lower_bound(&propagating_task.start_variable) + propagating_task.processing_time
+        context.lower_bound(&propagating_task.start_variable) + propagating_task.processing_time()
             - 1,
     );
     let mut should_exit = false;
@@ -68,9 +68,7 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
                 Some(time_point),
             );
             pumpkin_assert_extreme!(
-                explanation.iter().all(|predicate| context
-                    .assignments_integer()
-                    .does_integer_predicate_hold((*predicate).try_into().unwrap())),
+                check_explanation(&explanation, context.as_readonly()),
                 "All of the predicates in the reason should hold"
             );
             context.set_lower_bound(
@@ -85,7 +83,7 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
         }
 
         // We place the time-point as far as possible
-        time_point += propagating_task.processing_time;
+        time_point += propagating_task.processing_time();
 
         // Then we update the index of the current profile if appropriate
         if time_point > profiles[current_profile_index].end {
@@ -98,7 +96,7 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
                 //
                 // (Note that we could have also set it to
                 // `profiles[current_profile_index + 1].start -
-                // propagating_task.processing_time`)
+                // propagating_task.processing_time()`)
                 time_point = profiles[current_profile_index].end;
             } else {
                 current_profile_index += 1;
@@ -161,7 +159,7 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
                         profiles[current_profile_index].end
                     );
 
-        if time_point - propagating_task.processing_time
+        if time_point - propagating_task.processing_time()
             < context.upper_bound(&propagating_task.start_variable)
         {
             let explanation = add_propagating_task_predicate_upper_bound(
@@ -176,14 +174,12 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
                 Some(time_point),
             );
             pumpkin_assert_extreme!(
-                explanation.iter().all(|predicate| context
-                    .assignments_integer()
-                    .does_integer_predicate_hold((*predicate).try_into().unwrap())),
+                check_explanation(&explanation, context.as_readonly()),
                 "All of the predicates in the reason should hold"
             );
             context.set_upper_bound(
                 &propagating_task.start_variable,
-                time_point - propagating_task.processing_time,
+                time_point - propagating_task.processing_time(),
                 explanation,
             )?;
         }
@@ -192,7 +188,7 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
             break;
         }
 
-        time_point -= propagating_task.processing_time;
+        time_point -= propagating_task.processing_time();
 
         // Then we update the index of the current profile if appropriate
         if time_point < profiles[current_profile_index].start {
@@ -203,7 +199,7 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
                 //
                 // (Note that we could have also set it to
                 // `profiles[current_profile_index - 1].end +
-                // propagating_task.processing_time`)
+                // propagating_task.processing_time()`)
                 time_point = profiles[current_profile_index].start
             } else if current_profile_index == 0 {
                 // We have gone past the first profile, we ensure that we propagate past
@@ -239,10 +235,12 @@ pub(crate) fn create_pointwise_propagation_explanation<Var: IntegerVariable + 's
         .flat_map(move |profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= time_point + 1 - profile_task.processing_time
+                    profile_task.start_variable >= time_point + 1 - profile_task.processing_time()
                 ),
                 predicate!(profile_task.start_variable <= time_point),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -265,10 +263,13 @@ pub(crate) fn create_pointwise_conflict_explanation<Var: IntegerVariable + 'stat
         .flat_map(|profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= middle_point + 1 - profile_task.processing_time
+                    profile_task.start_variable
+                        >= middle_point + 1 - profile_task.processing_time()
                 ),
                 predicate!(profile_task.start_variable <= middle_point),
             ]
+            .into_iter()
+            .chain(profile_task.usage_predicates())
         })
         .collect()
 }
@@ -285,7 +286,7 @@ where
             >= time_point
                 .expect("Expected time-point to be provided to pointwise explanation creation")
                 + 1
-                - task.processing_time
+                - task.processing_time()
     )
 }
 
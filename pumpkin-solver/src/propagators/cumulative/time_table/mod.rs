@@ -73,6 +73,7 @@ mod explanations;
 mod over_interval_incremental_propagator;
 mod per_point_incremental_propagator;
 mod propagation_handler;
+mod statistics;
 mod time_table_over_interval;
 mod time_table_per_point;
 mod time_table_util;
@@ -30,7 +30,7 @@ pub(crate) fn new_profile_before_first_profile<Var: IntegerVariable + 'static>(
             end: profile.start - 1, /* Note that this profile needs to end before the start
                                      * of the current profile, hence the -1 */
             profile_tasks: vec![Rc::clone(task)],
-            height: task.resource_usage,
+            height: task.resource_usage(),
         })
     }
 }
@@ -66,7 +66,7 @@ pub(crate) fn new_profile_between_profiles<Var: IntegerVariable + 'static>(
                 start: previous_profile.end + 1,
                 end: profile.start - 1,
                 profile_tasks: vec![Rc::clone(task)],
-                height: task.resource_usage,
+                height: task.resource_usage(),
             })
         }
     }
@@ -139,7 +139,7 @@ pub(crate) fn overlap_updated_profile<Var: IntegerVariable + 'static>(
             start: new_profile_lower_bound,
             end: new_profile_upper_bound,
             profile_tasks: new_profile_tasks.clone(),
-            height: profile.height + task.resource_usage,
+            height: profile.height + task.resource_usage(),
         };
 
         // We thus create a new profile consisting of the combination of
@@ -148,14 +148,14 @@ pub(crate) fn overlap_updated_profile<Var: IntegerVariable + 'static>(
 
         // A sanity check, there is a new profile to create consisting
         // of a combination of the previous profile and the updated task
-        if profile.height + task.resource_usage + task.resource_usage > capacity {
+        if profile.height + task.resource_usage() + task.resource_usage() > capacity {
             // The addition of the new mandatory part to the profile
             // caused an overflow of the resource
             return Err(ResourceProfile {
                 start: new_profile_lower_bound,
                 end: new_profile_upper_bound,
                 profile_tasks: new_profile_tasks,
-                height: profile.height + task.resource_usage,
+                height: profile.height + task.resource_usage(),
             });
         }
     }
@@ -207,7 +207,7 @@ pub(crate) fn new_part_after_last_profile<Var: IntegerVariable + 'static>(
             start: profile.end + 1,
             end: update_range.end - 1,
             profile_tasks: vec![Rc::clone(task)],
-            height: task.resource_usage,
+            height: task.resource_usage(),
         })
     }
 }
@@ -135,7 +135,7 @@ pub(crate) fn insert_profile_new_mandatory_part<Var: IntegerVariable + 'static>(
             start: update_range.start,
             end: update_range.end - 1,
             profile_tasks: vec![Rc::clone(updated_task)],
-            height: updated_task.resource_usage,
+            height: updated_task.resource_usage(),
         },
     );
 }
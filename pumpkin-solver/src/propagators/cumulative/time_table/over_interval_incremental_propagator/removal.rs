@@ -69,7 +69,7 @@ fn remove_task_from_profile<Var: IntegerVariable + 'static>(
         start,
         end,
         profile_tasks: updated_profile_tasks,
-        height: profile.height - updated_task.resource_usage,
+        height: profile.height - updated_task.resource_usage(),
     }
 }
 
@@ -117,7 +117,7 @@ pub(crate) fn overlap_updated_profile<Var: IntegerVariable + 'static>(
     to_add: &mut Vec<ResourceProfile<Var>>,
     updated_task: &Rc<Task<Var>>,
 ) {
-    if profile.height - updated_task.resource_usage == 0 {
+    if profile.height - updated_task.resource_usage() == 0 {
         // If the removal of this task results in an empty profile then we simply do not add it
         return;
     }
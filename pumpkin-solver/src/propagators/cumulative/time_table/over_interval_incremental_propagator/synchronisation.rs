@@ -101,7 +101,7 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
     // Now we find the tasks in the profile which together overflow the resource
     while resource_usage <= parameters.capacity {
         let task = &conflicting_profile.profile_tasks[index];
-        resource_usage += task.resource_usage;
+        resource_usage += task.resource_usage();
         new_profile.push(Rc::clone(task));
         index += 1;
     }
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 use std::ops::Range;
 use std::rc::Rc;
+use std::time::Instant;
 
 use super::insertion;
 use super::removal;
@@ -8,6 +9,7 @@ use crate::basic_types::PropagationStatusCP;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -22,6 +24,7 @@ use crate::propagators::cumulative::time_table::over_interval_incremental_propag
 use crate::propagators::cumulative::time_table::over_interval_incremental_propagator::synchronisation::find_synchronised_conflict;
 use crate::propagators::cumulative::time_table::over_interval_incremental_propagator::synchronisation::synchronise_time_table;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
+use crate::propagators::cumulative::time_table::statistics::CumulativeStatistics;
 use crate::propagators::cumulative::time_table::time_table_util::backtrack_update;
 use crate::propagators::cumulative::time_table::time_table_util::has_overlap_with_interval;
 use crate::propagators::cumulative::time_table::time_table_util::insert_update;
@@ -30,6 +33,7 @@ use crate::propagators::cumulative::time_table::time_table_util::should_enqueue;
 use crate::propagators::debug_propagate_from_scratch_time_table_interval;
 use crate::propagators::util::check_bounds_equal_at_propagation;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -46,6 +50,8 @@ use crate::propagators::UpdatableStructures;
 use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_extreme;
 use crate::pumpkin_assert_simple;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
 
 /// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
 /// where a time-table is a structure which stores the mandatory resource usage of the tasks at
@@ -88,6 +94,8 @@ pub(crate) struct TimeTableOverIntervalIncrementalPropagator<Var, const SYNCHRON
     /// scratch or not; note that this variable is only used if
     /// [`CumulativePropagatorOptions::incremental_backtracking`] is set to false.
     is_time_table_outdated: bool,
+    /// The statistics which are gathered during propagation
+    statistics: CumulativeStatistics,
 }
 
 impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
@@ -99,8 +107,13 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
         cumulative_options: CumulativePropagatorOptions,
     ) -> TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE> {
         let tasks = create_tasks(arg_tasks);
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.presence.is_none()),
+            "The incremental time-table propagator does not support optional tasks"
+        );
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
+        let statistics = CumulativeStatistics::new(cumulative_options.explanation_type);
 
         TimeTableOverIntervalIncrementalPropagator {
             time_table: Default::default(),
@@ -108,6 +121,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             updatable_structures,
             found_previous_conflict: false,
             is_time_table_outdated: false,
+            statistics,
         }
     }
 
@@ -323,12 +337,9 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             .all(|profile| profile.height <= self.parameters.capacity));
         Ok(())
     }
-}
 
-impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
-    for TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE>
-{
-    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+    /// Updates the time-table and performs propagation based on it.
+    fn propagate_time_table(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         pumpkin_assert_advanced!(
             check_bounds_equal_at_propagation(
                 context.as_readonly(),
@@ -358,8 +369,24 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
             self.time_table.iter(),
             &self.parameters,
             &mut self.updatable_structures,
+            &mut self.statistics,
         )
     }
+}
+
+impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
+    for TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE>
+{
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        let start_time = Instant::now();
+        let result = self.propagate_time_table(context);
+        self.statistics.record_call(start_time, &result);
+        result
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.statistics.log(statistic_logger)
+    }
 
     fn notify(
         &mut self,
@@ -447,14 +474,18 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
         }
     }
 
-    fn priority(&self) -> u32 {
-        3
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
     }
 
     fn name(&self) -> &str {
         "CumulativeTimeTableOverIntervalIncremental"
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -616,11 +647,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -651,11 +688,17 @@ mod tests {
                     start_time: s1,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
                 ArgTask {
                     start_time: s2,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
             ]
             .into_iter()
@@ -698,11 +741,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -736,31 +785,49 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 5,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -787,11 +854,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -833,11 +906,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -886,31 +965,49 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 4,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -962,36 +1059,57 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b1,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b2,
                             processing_time: 3,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 4,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1036,11 +1154,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1086,16 +1210,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1142,11 +1275,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -97,7 +97,7 @@ fn get_minimum_set_of_tasks_which_overflow_capacity<'a, Var: IntegerVariable + '
                 if *resource_usage > parameters.capacity {
                     return false;
                 }
-                *resource_usage += task.resource_usage;
+                *resource_usage += task.resource_usage();
                 true
             })
             .cloned()
@@ -160,6 +160,7 @@ fn sort_profile_based_on_id<Var: IntegerVariable + 'static>(profile: &mut Resour
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
     use std::rc::Rc;
 
     use super::find_synchronised_conflict;
@@ -182,20 +183,29 @@ mod tests {
         let tasks = vec![
             Task {
                 start_variable: x0,
-                processing_time: 2,
-                resource_usage: 2,
+                processing_time: Cell::new(2),
+                resource_usage: Cell::new(2),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             },
             Task {
                 start_variable: x1,
-                processing_time: 2,
-                resource_usage: 2,
+                processing_time: Cell::new(2),
+                resource_usage: Cell::new(2),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             },
             Task {
                 start_variable: x2,
-                processing_time: 2,
-                resource_usage: 1,
+                processing_time: Cell::new(2),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(2),
             },
         ];
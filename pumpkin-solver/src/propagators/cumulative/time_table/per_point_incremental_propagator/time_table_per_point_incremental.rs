@@ -2,11 +2,13 @@ use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::rc::Rc;
+use std::time::Instant;
 
 use crate::basic_types::PropagationStatusCP;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -20,6 +22,7 @@ use crate::propagators::cumulative::time_table::per_point_incremental_propagator
 use crate::propagators::cumulative::time_table::per_point_incremental_propagator::synchronisation::find_synchronised_conflict;
 use crate::propagators::cumulative::time_table::per_point_incremental_propagator::synchronisation::synchronise_time_table;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
+use crate::propagators::cumulative::time_table::statistics::CumulativeStatistics;
 use crate::propagators::cumulative::time_table::time_table_util::backtrack_update;
 use crate::propagators::cumulative::time_table::time_table_util::insert_update;
 use crate::propagators::cumulative::time_table::time_table_util::propagate_based_on_timetable;
@@ -27,6 +30,7 @@ use crate::propagators::cumulative::time_table::time_table_util::should_enqueue;
 use crate::propagators::debug_propagate_from_scratch_time_table_point;
 use crate::propagators::util::check_bounds_equal_at_propagation;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -41,6 +45,9 @@ use crate::propagators::TimeTablePerPointPropagator;
 use crate::propagators::UpdatableStructures;
 use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_extreme;
+use crate::pumpkin_assert_simple;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
 
 /// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
 /// where a time-table is a structure which stores the mandatory resource usage of the tasks at
@@ -84,6 +91,8 @@ pub(crate) struct TimeTablePerPointIncrementalPropagator<Var, const SYNCHRONISE:
     /// scratch or not; note that this variable is only used if
     /// [`CumulativePropagatorOptions::incremental_backtracking`] is set to false.
     is_time_table_outdated: bool,
+    /// The statistics which are gathered during propagation
+    statistics: CumulativeStatistics,
 }
 
 impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
@@ -95,14 +104,20 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
         cumulative_options: CumulativePropagatorOptions,
     ) -> TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE> {
         let tasks = create_tasks(arg_tasks);
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.presence.is_none()),
+            "The incremental time-table propagator does not support optional tasks"
+        );
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
+        let statistics = CumulativeStatistics::new(cumulative_options.explanation_type);
         TimeTablePerPointIncrementalPropagator {
             time_table: BTreeMap::new(),
             parameters,
             updatable_structures,
             found_previous_conflict: false,
             is_time_table_outdated: false,
+            statistics,
         }
     }
 
@@ -131,7 +146,7 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                 .entry(time_point as u32)
                 .or_insert(ResourceProfile::default(time_point));
 
-            current_profile.height += task.resource_usage;
+            current_profile.height += task.resource_usage();
             current_profile.profile_tasks.push(Rc::clone(task));
 
             if current_profile.height > self.parameters.capacity && conflict.is_none() {
@@ -170,7 +185,7 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                     .entry(time_point as u32)
                     .and_modify(|profile| {
                         // We remove the resource usage of the task from the height of the profile
-                        profile.height -= task.resource_usage;
+                        profile.height -= task.resource_usage();
 
                         // If the height of the profile is not equal to 0 then we remove the task
                         // from the profile tasks
@@ -337,12 +352,9 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
             .all(|profile| profile.height <= self.parameters.capacity));
         Ok(())
     }
-}
 
-impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
-    for TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE>
-{
-    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+    /// Updates the time-table and performs propagation based on it.
+    fn propagate_time_table(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         pumpkin_assert_advanced!(
             check_bounds_equal_at_propagation(
                 context.as_readonly(),
@@ -370,8 +382,24 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
             self.time_table.values(),
             &self.parameters,
             &mut self.updatable_structures,
+            &mut self.statistics,
         )
     }
+}
+
+impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
+    for TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE>
+{
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        let start_time = Instant::now();
+        let result = self.propagate_time_table(context);
+        self.statistics.record_call(start_time, &result);
+        result
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.statistics.log(statistic_logger)
+    }
 
     fn notify(
         &mut self,
@@ -457,14 +485,18 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
         }
     }
 
-    fn priority(&self) -> u32 {
-        3
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
     }
 
     fn name(&self) -> &str {
         "CumulativeTimeTablePerPointIncremental"
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -589,11 +621,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -622,11 +660,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -669,11 +713,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -707,31 +757,49 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 5,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -758,11 +826,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -804,11 +878,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -859,31 +939,49 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 4,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -935,36 +1033,57 @@ mod tests {
                             start_time: a,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b1,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: b2,
                             processing_time: 3,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: c,
                             processing_time: 2,
                             resource_usage: 4,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: d,
                             processing_time: 2,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: e,
                             processing_time: 4,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: f,
                             processing_time: 6,
                             resource_usage: 2,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1009,11 +1128,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1061,16 +1186,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1118,11 +1252,17 @@ mod tests {
                             start_time: s1,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 3,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1166,16 +1306,25 @@ mod tests {
                         start_time: s1_scratch,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1213,16 +1362,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1270,16 +1428,25 @@ mod tests {
                         start_time: s1_scratch,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1316,16 +1483,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1376,16 +1552,25 @@ mod tests {
                         start_time: s1_scratch,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1422,16 +1607,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1479,16 +1673,25 @@ mod tests {
                         start_time: s1_scratch,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1525,16 +1728,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1581,16 +1793,25 @@ mod tests {
                         start_time: s1_scratch,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1626,16 +1847,25 @@ mod tests {
                             start_time: s1,
                             processing_time: 2,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s3,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1687,16 +1917,25 @@ mod tests {
                         start_time: s0_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s1_scratch,
                         processing_time: 1,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 1,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1746,16 +1985,25 @@ mod tests {
                             start_time: s0,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s1,
                             processing_time: 1,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 1,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
@@ -1810,16 +2058,25 @@ mod tests {
                         start_time: s0_scratch,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s1_scratch,
                         processing_time: 1,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2_scratch,
                         processing_time: 1,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -1869,16 +2126,25 @@ mod tests {
                             start_time: s0,
                             processing_time: 4,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s1,
                             processing_time: 1,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                         ArgTask {
                             start_time: s2,
                             processing_time: 1,
                             resource_usage: 1,
+                            presence: None,
+                            processing_time_variable: None,
+                            resource_usage_variable: None,
                         },
                     ]
                     .into_iter()
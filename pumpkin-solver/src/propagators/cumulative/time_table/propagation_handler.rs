@@ -6,19 +6,24 @@ use std::rc::Rc;
 use super::explanations::add_propagating_task_predicate_lower_bound;
 use super::explanations::add_propagating_task_predicate_upper_bound;
 use super::explanations::big_step::create_big_step_conflict_explanation;
+use super::explanations::big_step::create_big_step_predicate_propagating_task_lower_bound_propagation;
 use super::explanations::big_step::create_big_step_propagation_explanation;
 use super::explanations::create_predicate_propagating_task_lower_bound_propagation;
 use super::explanations::create_predicate_propagating_task_upper_bound_propagation;
 use super::explanations::naive::create_naive_conflict_explanation;
+use super::explanations::naive::create_naive_predicate_propagating_task_lower_bound_propagation;
 use super::explanations::naive::create_naive_propagation_explanation;
 use super::explanations::pointwise::create_pointwise_conflict_explanation;
 use super::explanations::pointwise::create_pointwise_propagation_explanation;
 use super::CumulativeExplanationType;
+use crate::basic_types::PropagationStatusCP;
 use crate::engine::cp::propagation::propagation_context::ReadDomains;
 use crate::engine::propagation::propagation_context::HasAssignments;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::EmptyDomain;
+use crate::predicate;
+use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::explanations::pointwise;
 use crate::propagators::ResourceProfile;
@@ -36,15 +41,25 @@ pub(crate) struct CumulativePropagationHandler {
     /// explanation and re-use it. Note that this will only be used for
     /// [`CumulativeExplanationType::Naive`] and [`CumulativeExplanationType::BigStep`].
     stored_profile_explanation: OnceCell<Rc<PropositionalConjunction>>,
+    /// The number of times that the domain of a task has been updated by the handler
+    num_propagations: u64,
 }
 
-fn check_explanation(explanation: &PropositionalConjunction, context: PropagationContext) -> bool {
-    explanation.iter().all(|&predicate| {
-        let integer_predicate = predicate.try_into().unwrap();
-
-        context
-            .assignments_integer()
-            .does_integer_predicate_hold(integer_predicate)
+/// Returns whether all of the predicates in the explanation hold; the only [`Predicate::Literal`]s
+/// which occur in explanations are the presence literals of optional tasks.
+pub(crate) fn check_explanation(
+    explanation: &PropositionalConjunction,
+    context: PropagationContext,
+) -> bool {
+    explanation.iter().all(|&predicate| match predicate {
+        Predicate::Literal(literal) => context.is_literal_true(literal),
+        _ => {
+            let integer_predicate = predicate.try_into().unwrap();
+
+            context
+                .assignments_integer()
+                .does_integer_predicate_hold(integer_predicate)
+        }
     })
 }
 
@@ -53,9 +68,15 @@ impl CumulativePropagationHandler {
         Self {
             explanation_type,
             stored_profile_explanation: OnceCell::new(),
+            num_propagations: 0,
         }
     }
 
+    /// The number of times that the domain of a task has been updated by the handler.
+    pub(crate) fn num_propagations(&self) -> u64 {
+        self.num_propagations
+    }
+
     /// Propagates the lower-bound of the `propagating_task` to not conflict with all of the
     /// `profiles` anymore.
     pub(crate) fn propagate_chain_of_lower_bounds_with_explanations<Var>(
@@ -67,6 +88,8 @@ impl CumulativePropagationHandler {
     where
         Var: IntegerVariable + 'static,
     {
+        self.num_propagations += 1;
+
         pumpkin_assert_simple!(!profiles.is_empty());
         match self.explanation_type {
             CumulativeExplanationType::Naive | CumulativeExplanationType::BigStep => {
@@ -129,6 +152,8 @@ impl CumulativePropagationHandler {
     where
         Var: IntegerVariable + 'static,
     {
+        self.num_propagations += 1;
+
         pumpkin_assert_simple!(!profiles.is_empty());
 
         match self.explanation_type {
@@ -166,7 +191,7 @@ impl CumulativePropagationHandler {
                 ));
                 context.set_upper_bound(
                     &propagating_task.start_variable,
-                    profiles[0].start - propagating_task.processing_time,
+                    profiles[0].start - propagating_task.processing_time(),
                     full_explanation,
                 )
             }
@@ -190,6 +215,8 @@ impl CumulativePropagationHandler {
     where
         Var: IntegerVariable + 'static,
     {
+        self.num_propagations += 1;
+
         pumpkin_assert_advanced!(
             context.lower_bound(&propagating_task.start_variable) < profile.end + 1
         );
@@ -209,6 +236,7 @@ impl CumulativePropagationHandler {
                         profile,
                         None,
                     );
+                let usage_propagating_task = propagating_task.usage_predicates();
                 pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
                 context.set_lower_bound(
                     &propagating_task.start_variable,
@@ -216,6 +244,9 @@ impl CumulativePropagationHandler {
                     move |_context: PropagationContext| {
                         let mut reason = (*explanation).clone();
                         reason.add(lower_bound_predicate_propagating_task);
+                        for predicate in usage_propagating_task {
+                            reason.add(predicate);
+                        }
                         reason
                     },
                 )
@@ -240,9 +271,11 @@ impl CumulativePropagationHandler {
     where
         Var: IntegerVariable + 'static,
     {
+        self.num_propagations += 1;
+
         pumpkin_assert_advanced!(
             context.upper_bound(&propagating_task.start_variable)
-                > profile.start - propagating_task.processing_time
+                > profile.start - propagating_task.processing_time()
         );
 
         match self.explanation_type {
@@ -260,13 +293,17 @@ impl CumulativePropagationHandler {
                         profile,
                         None,
                     );
+                let usage_propagating_task = propagating_task.usage_predicates();
                 pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
                 context.set_upper_bound(
                     &propagating_task.start_variable,
-                    profile.start - propagating_task.processing_time,
+                    profile.start - propagating_task.processing_time(),
                     move |_context: PropagationContext| {
                         let mut reason = (*explanation).clone();
                         reason.add(upper_bound_predicate_propagating_task);
+                        for predicate in usage_propagating_task {
+                            reason.add(predicate);
+                        }
                         reason
                     },
                 )
@@ -282,7 +319,8 @@ impl CumulativePropagationHandler {
     }
 
     /// Propagates a hole in the domain; note that this explanation does not contain any of the
-    /// bounds of `propagating_task`.
+    /// bounds of `propagating_task` (only its presence if it is optional and its variable
+    /// processing time and resource usage).
     pub(crate) fn propagate_holes_in_domain<Var>(
         &mut self,
         context: &mut PropagationContextMut,
@@ -292,6 +330,8 @@ impl CumulativePropagationHandler {
     where
         Var: IntegerVariable + 'static,
     {
+        self.num_propagations += 1;
+
         // We go through all of the time-points which cause `task` to overlap
         // with the resource profile
 
@@ -303,7 +343,7 @@ impl CumulativePropagationHandler {
         //   time-point in which case we simply start from the lower-bound of the task.
         let lower_bound_removed_time_points = max(
             context.lower_bound(&propagating_task.start_variable),
-            profile.start - propagating_task.processing_time + 1,
+            profile.start - propagating_task.processing_time() + 1,
         );
 
         // There are also two options for determine the highest value to remove
@@ -329,10 +369,17 @@ impl CumulativePropagationHandler {
                     // explanation type to create the explanations.
                     let explanation = self.get_stored_profile_explanation_or_init(context, profile);
                     pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+                    let usage_propagating_task = propagating_task.usage_predicates();
                     context.remove(
                         &propagating_task.start_variable,
                         time_point,
-                        move |_context: PropagationContext| (*explanation).clone(),
+                        move |_context: PropagationContext| {
+                            let mut reason = (*explanation).clone();
+                            for predicate in usage_propagating_task {
+                                reason.add(predicate);
+                            }
+                            reason
+                        },
                     )?;
                 }
                 CumulativeExplanationType::Pointwise => {
@@ -340,8 +387,8 @@ impl CumulativePropagationHandler {
                     // - Either the time-point is before the start of the profile; in which case the
                     //   explanation for the removal of this time-point is that there is a profile
                     //   point at the point `profile.start..time_point +
-                    //   propagating_task.processing_time - 1`. In this case, we pick the time point
-                    //   plus the processing time value until we reach the middle point of the
+                    //   propagating_task.processing_time() - 1`. In this case, we pick the time
+                    //   point plus the processing time value until we reach the middle point of the
                     //   profile in which case we always pick the middle point (this is an untested
                     //   heuristic in terms of performance).
                     // - Or the time-point is after the start of the profile in which case the
@@ -349,17 +396,20 @@ impl CumulativePropagationHandler {
                     //   together with the propagating task would overflow the capacity)
                     let corresponding_profile_explanation_point = if time_point < profile.start {
                         min(
-                            time_point + propagating_task.processing_time - 1,
+                            time_point + propagating_task.processing_time() - 1,
                             (profile.end - profile.start) / 2 + profile.start,
                         )
                     } else {
                         time_point
                     };
 
-                    let explanation = create_pointwise_propagation_explanation(
+                    let mut explanation = create_pointwise_propagation_explanation(
                         corresponding_profile_explanation_point,
                         profile,
                     );
+                    for predicate in propagating_task.usage_predicates() {
+                        explanation.add(predicate);
+                    }
                     pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
                     context.remove(&propagating_task.start_variable, time_point, explanation)?;
                 }
@@ -369,6 +419,66 @@ impl CumulativePropagationHandler {
         Ok(())
     }
 
+    /// Sets the presence literal of the optional `propagating_task` to false since it overlaps with
+    /// `profile` for every start time in its domain and the resource usage of both would overflow
+    /// the capacity.
+    ///
+    /// For [`CumulativeExplanationType::Pointwise`], the big-step explanation is used since the
+    /// task does not necessarily overlap with a single time-point of the profile for every start
+    /// time.
+    pub(crate) fn propagate_absence_with_explanations<Var>(
+        &mut self,
+        context: &mut PropagationContextMut,
+        profile: &ResourceProfile<Var>,
+        propagating_task: &Rc<Task<Var>>,
+    ) -> PropagationStatusCP
+    where
+        Var: IntegerVariable + 'static,
+    {
+        self.num_propagations += 1;
+
+        let presence = propagating_task
+            .presence
+            .expect("Only the presence of optional tasks can be propagated");
+        pumpkin_assert_advanced!(
+            context.upper_bound(&propagating_task.start_variable) <= profile.end
+                && context.lower_bound(&propagating_task.start_variable)
+                    + propagating_task.processing_time()
+                    > profile.start
+        );
+
+        let mut explanation = match self.explanation_type {
+            CumulativeExplanationType::Naive => {
+                let mut explanation =
+                    create_naive_propagation_explanation(profile, context.as_readonly());
+                explanation.add(
+                    create_naive_predicate_propagating_task_lower_bound_propagation(
+                        context.as_readonly(),
+                        propagating_task,
+                    ),
+                );
+                explanation
+            }
+            CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => {
+                let mut explanation = create_big_step_propagation_explanation(profile);
+                explanation.add(
+                    create_big_step_predicate_propagating_task_lower_bound_propagation(
+                        propagating_task,
+                        profile,
+                    ),
+                );
+                explanation
+            }
+        };
+        explanation.add(predicate!(propagating_task.start_variable <= profile.end));
+        for predicate in propagating_task.parameter_predicates() {
+            explanation.add(predicate);
+        }
+
+        pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+        context.assign_literal(presence, false, explanation)
+    }
+
     /// Signifies that we are moving to another profile and we cannot re-use the cached explanation
     /// of [`CumulativePropagationHandler::stored_profile_explanation`].
     pub(crate) fn next_profile(&mut self) {
@@ -427,6 +537,7 @@ where
 
 #[cfg(test)]
 pub(crate) mod test_propagation_handler {
+    use std::cell::Cell;
     use std::rc::Rc;
 
     use super::create_conflict_explanation;
@@ -473,8 +584,11 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: Cell::new(4),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             };
 
@@ -502,15 +616,21 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             };
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: Cell::new(4),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             };
 
@@ -550,15 +670,21 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             };
 
             let profile_task_y = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: Cell::new(4),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             };
             let profile_y = ResourceProfile {
@@ -570,8 +696,11 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task_z = Task {
                 start_variable: z,
-                processing_time: 7,
-                resource_usage: 1,
+                processing_time: Cell::new(7),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(2),
             };
             let profile_z = ResourceProfile {
@@ -609,15 +738,21 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             };
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: Cell::new(4),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             };
 
@@ -657,15 +792,21 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             };
 
             let profile_task_y = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: Cell::new(4),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(1),
             };
             let profile_y = ResourceProfile {
@@ -677,8 +818,11 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task_z = Task {
                 start_variable: z,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(2),
             };
             let profile_z = ResourceProfile {
@@ -0,0 +1,63 @@
+use std::time::Instant;
+
+use super::CumulativeExplanationType;
+use crate::basic_types::PropagationStatusCP;
+use crate::create_statistics_struct;
+
+create_statistics_struct!(
+    /// The statistics of a time-table propagator for a single cumulative constraint (i.e. a single
+    /// resource); these can be used to compare the explanation types and propagation modes.
+    CumulativeStatistics {
+        /// The explanation type which is used by the propagator
+        explanation_type: CumulativeExplanationType,
+        /// The number of times that the propagator has been called
+        num_calls: u64,
+        /// The total number of profiles in the time-tables which have been used for propagation
+        num_profiles: u64,
+        /// The number of times that the domain of a task has been updated
+        num_propagations: u64,
+        /// The number of conflicts which have been found by the propagator
+        num_conflicts: u64,
+        /// The time spent by the propagator (in microseconds)
+        time_spent_in_propagation_micros: u64,
+});
+
+impl CumulativeStatistics {
+    pub(crate) fn new(explanation_type: CumulativeExplanationType) -> Self {
+        CumulativeStatistics {
+            explanation_type,
+            ..Default::default()
+        }
+    }
+
+    /// Records a call to the propagator which started at `start_time` and resulted in `result`.
+    pub(crate) fn record_call(&mut self, start_time: Instant, result: &PropagationStatusCP) {
+        self.num_calls += 1;
+        self.num_conflicts += result.is_err() as u64;
+        self.time_spent_in_propagation_micros += start_time.elapsed().as_micros() as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::CumulativeStatistics;
+    use crate::basic_types::Inconsistency;
+    use crate::options::CumulativeExplanationType;
+
+    #[test]
+    fn calls_and_conflicts_are_recorded() {
+        let mut statistics = CumulativeStatistics::new(CumulativeExplanationType::Naive);
+
+        statistics.record_call(Instant::now(), &Ok(()));
+        statistics.record_call(Instant::now(), &Err(Inconsistency::EmptyDomain));
+
+        assert_eq!(statistics.num_calls, 2);
+        assert_eq!(statistics.num_conflicts, 1);
+        assert!(matches!(
+            statistics.explanation_type,
+            CumulativeExplanationType::Naive
+        ));
+    }
+}
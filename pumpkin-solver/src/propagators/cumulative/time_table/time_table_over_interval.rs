@@ -1,4 +1,5 @@
 use std::rc::Rc;
+use std::time::Instant;
 
 use super::time_table_util::propagate_based_on_timetable;
 use super::time_table_util::should_enqueue;
@@ -6,6 +7,7 @@ use crate::basic_types::PropagationStatusCP;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -15,7 +17,9 @@ use crate::engine::variables::IntegerVariable;
 use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
+use crate::propagators::cumulative::time_table::statistics::CumulativeStatistics;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -29,6 +33,8 @@ use crate::propagators::UpdatableStructures;
 use crate::pumpkin_assert_extreme;
 use crate::pumpkin_assert_moderate;
 use crate::pumpkin_assert_simple;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
 
 /// An event storing the start and end of mandatory parts used for creating the time-table
 #[derive(Debug)]
@@ -61,6 +67,8 @@ pub(crate) struct TimeTableOverIntervalPropagator<Var> {
     parameters: CumulativeParameters<Var>,
     /// Stores structures which change during the search; used to store the bounds
     updatable_structures: UpdatableStructures<Var>,
+    /// The statistics which are gathered during propagation
+    statistics: CumulativeStatistics,
 }
 
 /// The type of the time-table used by propagators which use time-table reasoning over intervals.
@@ -79,17 +87,18 @@ impl<Var: IntegerVariable + 'static> TimeTableOverIntervalPropagator<Var> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
+        let statistics = CumulativeStatistics::new(cumulative_options.explanation_type);
 
         TimeTableOverIntervalPropagator {
             is_time_table_empty: true,
             parameters,
             updatable_structures,
+            statistics,
         }
     }
-}
 
-impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropagator<Var> {
-    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+    /// Updates the time-table and performs propagation based on it.
+    fn propagate_time_table(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let time_table =
             create_time_table_over_interval_from_scratch(context.as_readonly(), &self.parameters)?;
         self.is_time_table_empty = time_table.is_empty();
@@ -100,8 +109,22 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
             time_table.iter(),
             &self.parameters,
             &mut self.updatable_structures,
+            &mut self.statistics,
         )
     }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropagator<Var> {
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        let start_time = Instant::now();
+        let result = self.propagate_time_table(context);
+        self.statistics.record_call(start_time, &result);
+        result
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.statistics.log(statistic_logger)
+    }
 
     fn synchronise(&mut self, context: PropagationContext) {
         self.updatable_structures
@@ -114,6 +137,12 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The lower-bound of a variable processing time or resource usage has increased; these
+            // are only taken into account when the time-table is created
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that it could be the case that `is_time_table_empty` is inaccurate here since it
         // wasn't updated in `synchronise`; however, `synchronise` will only remove profiles
@@ -144,14 +173,18 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
         result.decision
     }
 
-    fn priority(&self) -> u32 {
-        3
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
     }
 
     fn name(&self) -> &str {
         "CumulativeTimeTableOverInterval"
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -192,6 +225,10 @@ pub(crate) fn create_time_table_over_interval_from_scratch<
     context: Context,
     parameters: &CumulativeParameters<Var>,
 ) -> Result<OverIntervalTimeTableType<Var>, PropositionalConjunction> {
+    parameters
+        .tasks
+        .iter()
+        .for_each(|task| task.update_parameters(context));
     // First we create a list of all the events (i.e. start and ends of mandatory parts)
     let events = create_events(context, parameters);
 
@@ -216,23 +253,24 @@ fn create_events<Var: IntegerVariable + 'static, Context: ReadDomains + Copy>(
     for task in parameters.tasks.iter() {
         let upper_bound = context.upper_bound(&task.start_variable);
         let lower_bound = context.lower_bound(&task.start_variable);
-        if upper_bound < lower_bound + task.processing_time {
-            // The task has a mandatory part, we need to add the appropriate events to the
+        if upper_bound < lower_bound + task.processing_time() && task.is_present(context) {
+            // The task has a mandatory part (note that optional tasks only have a mandatory part
+            // once they are known to be present), we need to add the appropriate events to the
             // events list
 
             // Thus we first add an event for the start of a mandatory part (with positive
             // resource usage)
             events.push(Event {
                 time_stamp: upper_bound,
-                change_in_resource_usage: task.resource_usage,
+                change_in_resource_usage: task.resource_usage(),
                 task: Rc::clone(task),
             });
 
             // Then we create an event for the end of a mandatory part (with negative resource
             // usage)
             events.push(Event {
-                time_stamp: lower_bound + task.processing_time,
-                change_in_resource_usage: -task.resource_usage,
+                time_stamp: lower_bound + task.processing_time(),
+                change_in_resource_usage: -task.resource_usage(),
                 task: Rc::clone(task),
             });
         }
@@ -430,6 +468,7 @@ pub(crate) fn debug_propagate_from_scratch_time_table_interval<Var: IntegerVaria
         time_table.iter(),
         parameters,
         &mut updatable_structures.recreate_from_context(context.as_readonly(), parameters),
+        &mut CumulativeStatistics::default(),
     )
 }
 
@@ -460,11 +499,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -491,11 +536,17 @@ mod tests {
                     start_time: s1,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
                 ArgTask {
                     start_time: s2,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
             ]
             .into_iter()
@@ -536,11 +587,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -572,31 +629,49 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 5,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -621,11 +696,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -665,11 +746,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -716,31 +803,49 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 4,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -790,36 +895,57 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b1,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b2,
                         processing_time: 3,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 4,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -862,11 +988,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -910,16 +1042,25 @@ mod tests {
                         start_time: s1,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -964,11 +1105,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
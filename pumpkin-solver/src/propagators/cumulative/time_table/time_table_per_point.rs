@@ -4,6 +4,7 @@
 
 use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::time::Instant;
 
 use super::time_table_util::propagate_based_on_timetable;
 use super::time_table_util::should_enqueue;
@@ -12,6 +13,7 @@ use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -20,7 +22,9 @@ use crate::engine::variables::IntegerVariable;
 use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
+use crate::propagators::cumulative::time_table::statistics::CumulativeStatistics;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -29,6 +33,8 @@ use crate::propagators::CumulativePropagatorOptions;
 use crate::propagators::ResourceProfile;
 use crate::propagators::UpdatableStructures;
 use crate::pumpkin_assert_extreme;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
 
 /// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
 /// where a time-table is a structure which stores the mandatory resource usage of the tasks at
@@ -51,6 +57,8 @@ pub(crate) struct TimeTablePerPointPropagator<Var> {
     parameters: CumulativeParameters<Var>,
     /// Stores structures which change during the search; used to store the bounds
     updatable_structures: UpdatableStructures<Var>,
+    /// The statistics which are gathered during propagation
+    statistics: CumulativeStatistics,
 }
 
 /// The type of the time-table used by propagators which use time-table reasoning per time-point;
@@ -72,17 +80,18 @@ impl<Var: IntegerVariable + 'static> TimeTablePerPointPropagator<Var> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
+        let statistics = CumulativeStatistics::new(cumulative_options.explanation_type);
 
         TimeTablePerPointPropagator {
             is_time_table_empty: true,
             parameters,
             updatable_structures,
+            statistics,
         }
     }
-}
 
-impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<Var> {
-    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+    /// Updates the time-table and performs propagation based on it.
+    fn propagate_time_table(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let time_table =
             create_time_table_per_point_from_scratch(context.as_readonly(), &self.parameters)?;
         self.is_time_table_empty = time_table.is_empty();
@@ -93,8 +102,22 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
             time_table.values(),
             &self.parameters,
             &mut self.updatable_structures,
+            &mut self.statistics,
         )
     }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<Var> {
+    fn propagate(&mut self, context: PropagationContextMut) -> PropagationStatusCP {
+        let start_time = Instant::now();
+        let result = self.propagate_time_table(context);
+        self.statistics.record_call(start_time, &result);
+        result
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.statistics.log(statistic_logger)
+    }
 
     fn synchronise(&mut self, context: PropagationContext) {
         self.updatable_structures
@@ -107,6 +130,12 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The lower-bound of a variable processing time or resource usage has increased; these
+            // are only taken into account when the time-table is created
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that it could be the case that `is_time_table_empty` is inaccurate here since it
         // wasn't updated in `synchronise`; however, `synchronise` will only remove profiles
@@ -139,14 +168,18 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
         result.decision
     }
 
-    fn priority(&self) -> u32 {
-        3
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
     }
 
     fn name(&self) -> &str {
         "CumulativeTimeTablePerPoint"
     }
 
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -186,21 +219,26 @@ pub(crate) fn create_time_table_per_point_from_scratch<
     parameters: &CumulativeParameters<Var>,
 ) -> Result<PerPointTimeTableType<Var>, PropositionalConjunction> {
     let mut time_table: PerPointTimeTableType<Var> = PerPointTimeTableType::new();
+    parameters
+        .tasks
+        .iter()
+        .for_each(|task| task.update_parameters(context));
     // First we go over all tasks and determine their mandatory parts
     for task in parameters.tasks.iter() {
         let upper_bound = context.upper_bound(&task.start_variable);
         let lower_bound = context.lower_bound(&task.start_variable);
 
-        if upper_bound < lower_bound + task.processing_time {
-            // There is a mandatory part
-            for i in upper_bound..(lower_bound + task.processing_time) {
+        if upper_bound < lower_bound + task.processing_time() && task.is_present(context) {
+            // There is a mandatory part (note that optional tasks only have a mandatory part once
+            // they are known to be present)
+            for i in upper_bound..(lower_bound + task.processing_time()) {
                 // For every time-point of the mandatory part,
                 //  add the resource usage of the current task to the ResourceProfile and add it
                 // to the profile tasks of the resource
                 let current_profile: &mut ResourceProfile<Var> = time_table
                     .entry(i as u32)
                     .or_insert(ResourceProfile::default(i));
-                current_profile.height += task.resource_usage;
+                current_profile.height += task.resource_usage();
                 current_profile.profile_tasks.push(Rc::clone(task));
 
                 if current_profile.height > parameters.capacity {
@@ -238,6 +276,7 @@ pub(crate) fn debug_propagate_from_scratch_time_table_point<Var: IntegerVariable
         time_table.values(),
         parameters,
         &mut updatable_structures.recreate_from_context(context.as_readonly(), parameters),
+        &mut CumulativeStatistics::default(),
     )
 }
 
@@ -268,11 +307,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -299,11 +344,17 @@ mod tests {
                     start_time: s1,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
                 ArgTask {
                     start_time: s2,
                     processing_time: 4,
                     resource_usage: 1,
+                    presence: None,
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
                 },
             ]
             .into_iter()
@@ -344,11 +395,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -380,31 +437,49 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 5,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -429,11 +504,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -473,11 +554,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -526,31 +613,49 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 4,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -600,36 +705,57 @@ mod tests {
                         start_time: a,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b1,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: b2,
                         processing_time: 3,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: c,
                         processing_time: 2,
                         resource_usage: 4,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: d,
                         processing_time: 2,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: e,
                         processing_time: 4,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: f,
                         processing_time: 6,
                         resource_usage: 2,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -672,11 +798,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -722,16 +854,25 @@ mod tests {
                         start_time: s1,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 2,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s3,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -777,11 +918,17 @@ mod tests {
                         start_time: s1,
                         processing_time: 4,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                     ArgTask {
                         start_time: s2,
                         processing_time: 3,
                         resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
                     },
                 ]
                 .into_iter()
@@ -810,4 +957,174 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn optional_task_only_uses_resource_when_present() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 8);
+        let present = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(TimeTablePerPointPropagator::new(
+                &[
+                    ArgTask {
+                        start_time: s1,
+                        processing_time: 4,
+                        resource_usage: 1,
+                        presence: Some(present),
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                    ArgTask {
+                        start_time: s2,
+                        processing_time: 3,
+                        resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                ]
+                .into_iter()
+                .collect::<Vec<_>>(),
+                1,
+                CumulativePropagatorOptions {
+                    explanation_type: CumulativeExplanationType::Naive,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        assert_eq!(solver.lower_bound(s2), 1);
+
+        solver.set_literal(present, true);
+        let result = solver.propagate(&mut propagator);
+        assert!(result.is_ok());
+        assert_eq!(solver.lower_bound(s2), 5);
+
+        let reason = solver
+            .get_reason_int(predicate!(s2 >= 5).try_into().unwrap())
+            .clone();
+        assert_eq!(
+            PropositionalConjunction::from(vec![
+                predicate!(s2 >= 4),
+                predicate!(s1 >= 1),
+                predicate!(s1 <= 1),
+                Predicate::from(present),
+            ]),
+            reason
+        );
+    }
+
+    #[test]
+    fn optional_task_which_does_not_fit_is_absent() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 3);
+        let present = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(TimeTablePerPointPropagator::new(
+                &[
+                    ArgTask {
+                        start_time: s1,
+                        processing_time: 4,
+                        resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                    ArgTask {
+                        start_time: s2,
+                        processing_time: 3,
+                        resource_usage: 1,
+                        presence: Some(present),
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                ]
+                .into_iter()
+                .collect::<Vec<_>>(),
+                1,
+                CumulativePropagatorOptions {
+                    explanation_type: CumulativeExplanationType::Naive,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        assert!(solver.is_literal_false(present));
+        // The task overlaps with the profile at time point 3 in every schedule; note that the
+        // bounds of an optional task are not propagated
+        assert_eq!(solver.lower_bound(s2), 1);
+
+        let reason = solver.get_reason_bool(present, false).clone();
+        assert_eq!(
+            PropositionalConjunction::from(vec![
+                predicate!(s1 >= 1),
+                predicate!(s1 <= 1),
+                predicate!(s2 >= 1),
+                predicate!(s2 <= 3),
+            ]),
+            reason
+        );
+    }
+
+    #[test]
+    fn increased_variable_resource_usage_propagates() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 8);
+        let r1 = solver.new_variable(1, 2);
+
+        let mut propagator = solver
+            .new_propagator(TimeTablePerPointPropagator::new(
+                &[
+                    ArgTask {
+                        start_time: s1,
+                        processing_time: 4,
+                        resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: Some(r1),
+                    },
+                    ArgTask {
+                        start_time: s2,
+                        processing_time: 3,
+                        resource_usage: 1,
+                        presence: None,
+                        processing_time_variable: None,
+                        resource_usage_variable: None,
+                    },
+                ]
+                .into_iter()
+                .collect::<Vec<_>>(),
+                2,
+                CumulativePropagatorOptions {
+                    explanation_type: CumulativeExplanationType::Naive,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        assert_eq!(solver.lower_bound(s2), 1);
+
+        // The resource usage of the first task is registered with the id offset by three times the
+        // number of tasks
+        let notification_status = solver.increase_lower_bound_and_notify(&mut propagator, 6, r1, 2);
+        assert!(matches!(notification_status, EnqueueDecision::Enqueue));
+        let result = solver.propagate(&mut propagator);
+        assert!(result.is_ok());
+        assert_eq!(solver.lower_bound(s2), 5);
+
+        let reason = solver
+            .get_reason_int(predicate!(s2 >= 5).try_into().unwrap())
+            .clone();
+        assert_eq!(
+            PropositionalConjunction::from(vec![
+                predicate!(s2 >= 4),
+                predicate!(s1 >= 1),
+                predicate!(s1 <= 1),
+                predicate!(r1 >= 2),
+            ]),
+            reason
+        );
+    }
 }
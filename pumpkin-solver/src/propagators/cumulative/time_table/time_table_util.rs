@@ -16,6 +16,7 @@ use crate::engine::propagation::Propagator;
 use crate::engine::propagation::ReadDomains;
 use crate::engine::variables::IntegerVariable;
 use crate::propagators::cumulative::time_table::propagation_handler::CumulativePropagationHandler;
+use crate::propagators::cumulative::time_table::statistics::CumulativeStatistics;
 use crate::propagators::CumulativeParameters;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
@@ -111,7 +112,7 @@ pub(crate) fn has_mandatory_part<Var: IntegerVariable + 'static>(
     task: &Rc<Task<Var>>,
 ) -> bool {
     context.upper_bound(&task.start_variable)
-        < context.lower_bound(&task.start_variable) + task.processing_time
+        < context.lower_bound(&task.start_variable) + task.processing_time()
 }
 
 /// Checks whether a specific task (indicated by id) has a mandatory part which overlaps with the
@@ -127,8 +128,13 @@ pub(crate) fn has_mandatory_part_in_interval<Var: IntegerVariable + 'static>(
         context.upper_bound(&task.start_variable),
     );
     // There exists a mandatory part
-    (upper_bound < (lower_bound + task.processing_time))
-        && has_overlap_with_interval(upper_bound, lower_bound + task.processing_time, start, end)
+    (upper_bound < (lower_bound + task.processing_time()))
+        && has_overlap_with_interval(
+            upper_bound,
+            lower_bound + task.processing_time(),
+            start,
+            end,
+        )
     // Determine whether the mandatory part overlaps with the provided bounds
 }
 
@@ -141,7 +147,7 @@ pub(crate) fn task_has_overlap_with_interval<Var: IntegerVariable + 'static>(
 ) -> bool {
     let (lower_bound, upper_bound) = (
         context.lower_bound(&task.start_variable),
-        context.upper_bound(&task.start_variable) + task.processing_time,
+        context.upper_bound(&task.start_variable) + task.processing_time(),
     ); // The release time of the task and the deadline
     has_overlap_with_interval(lower_bound, upper_bound, start, end)
 }
@@ -205,6 +211,7 @@ pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
     time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
     parameters: &CumulativeParameters<Var>,
     updatable_structures: &mut UpdatableStructures<Var>,
+    statistics: &mut CumulativeStatistics,
 ) -> PropagationStatusCP {
     pumpkin_assert_extreme!(
         debug_check_whether_profiles_are_maximal_and_sorted(time_table.clone()),
@@ -224,12 +231,78 @@ pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
         "All of the fixed tasks should be fixed at this point"
     );
 
-    if parameters.options.generate_sequence {
-        propagate_sequence_of_profiles(context, time_table, updatable_structures, parameters)?;
-    } else {
-        propagate_single_profiles(context, time_table, updatable_structures, parameters)?;
-    }
+    statistics.num_profiles += time_table.clone().count() as u64;
+
+    // We create the structure responsible for propagations and explanations
+    let mut propagation_handler =
+        CumulativePropagationHandler::new(parameters.options.explanation_type);
 
+    let result = propagate_absence_of_optional_tasks(
+        context,
+        time_table.clone(),
+        parameters,
+        &mut propagation_handler,
+    )
+    .and_then(|_| {
+        if parameters.options.generate_sequence {
+            propagate_sequence_of_profiles(
+                context,
+                time_table,
+                updatable_structures,
+                parameters,
+                &mut propagation_handler,
+            )
+        } else {
+            propagate_single_profiles(
+                context,
+                time_table,
+                updatable_structures,
+                parameters,
+                &mut propagation_handler,
+            )
+        }
+    });
+
+    statistics.num_propagations += propagation_handler.num_propagations();
+    result
+}
+
+/// Sets the presence literal of optional tasks (of which it is not yet known whether they are
+/// present) to false if the task cannot be scheduled anymore.
+///
+/// This is the case if there is a profile which, together with the task, overflows the capacity
+/// and with which the task overlaps for every start time in its domain (i.e. `ub(s) <= end` and
+/// `lb(s) + p > start`). Note that the bounds of optional tasks are only propagated once they are
+/// known to be present.
+fn propagate_absence_of_optional_tasks<'a, Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
+    parameters: &CumulativeParameters<Var>,
+    propagation_handler: &mut CumulativePropagationHandler,
+) -> PropagationStatusCP {
+    for task in parameters.tasks.iter() {
+        if !task.is_presence_unassigned(context.as_readonly()) {
+            continue;
+        }
+
+        let lower_bound = context.lower_bound(&task.start_variable);
+        let upper_bound = context.upper_bound(&task.start_variable);
+
+        for profile in time_table.clone() {
+            if profile.start >= lower_bound + task.processing_time() {
+                // The profiles are sorted, none of the subsequent profiles overlap with the task
+                // for every start time
+                break;
+            }
+
+            if profile.height + task.resource_usage() > parameters.capacity
+                && upper_bound <= profile.end
+            {
+                propagation_handler.propagate_absence_with_explanations(context, profile, task)?;
+                break;
+            }
+        }
+    }
     Ok(())
 }
 
@@ -247,12 +320,9 @@ fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
     time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
     updatable_structures: &mut UpdatableStructures<Var>,
     parameters: &CumulativeParameters<Var>,
+    propagation_handler: &mut CumulativePropagationHandler,
 ) -> PropagationStatusCP {
-    // We create the structure responsible for propagations and explanations
-    let mut propagation_handler =
-        CumulativePropagationHandler::new(parameters.options.explanation_type);
-
-    // Then we go over all of the profiles in the time-table
+    // We go over all of the profiles in the time-table
     'profile_loop: for profile in time_table {
         // We indicate to the propagation handler that we cannot re-use an existing profile
         // explanation
@@ -276,7 +346,7 @@ fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
                 }
                 continue;
             }
-            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time {
+            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time() {
                 // The start of the current profile is necessarily after the latest
                 // completion time of the task under consideration The profiles are
                 // sorted by start time (and non-overlapping) so we can remove the task from
@@ -291,6 +361,12 @@ fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
 
             task_index += 1;
 
+            if !task.is_present(context.as_readonly()) {
+                // The bounds of an optional task are only propagated once it is known to be
+                // present
+                continue;
+            }
+
             // We get the updates which are possible (i.e. a lower-bound update, an upper-bound
             // update or a hole in the domain)
             let possible_updates = find_possible_updates(context, &task, profile, parameters);
@@ -330,18 +406,16 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
     time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
     updatable_structures: &UpdatableStructures<Var>,
     parameters: &CumulativeParameters<Var>,
+    propagation_handler: &mut CumulativePropagationHandler,
 ) -> PropagationStatusCP {
-    // We create the structure responsible for propagations and explanations
-    let mut propagation_handler =
-        CumulativePropagationHandler::new(parameters.options.explanation_type);
-
     // We collect the time-table since we will need to index into it
     let time_table = time_table.collect::<Vec<_>>();
 
     // Then we go over all the possible tasks
     for task in updatable_structures.get_unfixed_tasks() {
-        if context.is_fixed(&task.start_variable) {
-            // If the task is fixed then we are not able to propagate it further
+        if context.is_fixed(&task.start_variable) || !task.is_present(context.as_readonly()) {
+            // If the task is fixed then we are not able to propagate it further; the bounds of an
+            // optional task are only propagated once it is known to be present
             continue;
         }
 
@@ -350,7 +424,7 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
         'profile_loop: while profile_index < time_table.len() {
             let profile = time_table[profile_index];
 
-            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time {
+            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time() {
                 // The profiles are sorted, if we cannot update using this one then we cannot update
                 // using the subsequent profiles, we can break from the loop
                 break 'profile_loop;
@@ -458,7 +532,7 @@ fn find_index_last_profile_which_propagates_lower_bound<Var: IntegerVariable + '
     let mut last_index = profile_index + 1;
     while last_index < time_table.len() {
         let next_profile = time_table[last_index];
-        if next_profile.start - time_table[last_index - 1].end >= task.processing_time
+        if next_profile.start - time_table[last_index - 1].end >= task.processing_time()
             || !overflows_capacity_and_is_not_part_of_profile(context, task, next_profile, capacity)
         {
             break;
@@ -483,7 +557,7 @@ fn find_index_last_profile_which_propagates_upper_bound<Var: IntegerVariable + '
     let mut first_index = profile_index - 1;
     loop {
         let previous_profile = time_table[first_index];
-        if time_table[first_index + 1].start - previous_profile.end >= task.processing_time
+        if time_table[first_index + 1].start - previous_profile.end >= task.processing_time()
             || !overflows_capacity_and_is_not_part_of_profile(
                 context,
                 task,
@@ -510,7 +584,7 @@ fn find_index_last_profile_which_propagates_upper_bound<Var: IntegerVariable + '
 ///       [`ResourceProfile`]
 ///     * lb(s) <= end, i.e. the earliest start time is before the end of the [`ResourceProfile`]
 ///
-/// Note: It is assumed that task.resource_usage + height > capacity (i.e. the task has the
+/// Note: It is assumed that task.resource_usage() + height > capacity (i.e. the task has the
 /// potential to overflow the capacity in combination with the profile)
 fn lower_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
     context: PropagationContext,
@@ -519,10 +593,10 @@ fn lower_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
     capacity: i32,
 ) -> bool {
     pumpkin_assert_moderate!(
-        profile.height + task.resource_usage > capacity
+        profile.height + task.resource_usage() > capacity
             && task_has_overlap_with_interval(context, task, profile.start, profile.end)
     , "It is checked whether a task can be propagated while the invariants do not hold - The task should overflow the capacity with the profile");
-    (context.lower_bound(&task.start_variable) + task.processing_time) > profile.start
+    (context.lower_bound(&task.start_variable) + task.processing_time()) > profile.start
         && context.lower_bound(&task.start_variable) <= profile.end
 }
 
@@ -539,9 +613,9 @@ fn upper_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
     capacity: i32,
 ) -> bool {
     pumpkin_assert_moderate!(
-        profile.height + task.resource_usage > capacity
+        profile.height + task.resource_usage() > capacity
     , "It is checked whether a task can be propagated while the invariants do not hold - The task should overflow the capacity with the profile");
-    (context.upper_bound(&task.start_variable) + task.processing_time) > profile.start
+    (context.upper_bound(&task.start_variable) + task.processing_time()) > profile.start
         && context.upper_bound(&task.start_variable) <= profile.end
 }
 
@@ -574,7 +648,7 @@ fn overflows_capacity_and_is_not_part_of_profile<Var: IntegerVariable + 'static>
     profile: &ResourceProfile<Var>,
     capacity: i32,
 ) -> bool {
-    profile.height + task.resource_usage > capacity
+    profile.height + task.resource_usage() > capacity
         && !has_mandatory_part_in_interval(context, task, profile.start, profile.end)
 }
 
@@ -657,7 +731,8 @@ pub(crate) fn backtrack_update<Var: IntegerVariable + 'static>(
     // Stores whether the stored bounds did not include a mandatory part
     let previously_did_not_have_mandatory_part = updatable_structures
         .get_stored_upper_bound(updated_task)
-        >= updatable_structures.get_stored_lower_bound(updated_task) + updated_task.processing_time;
+        >= updatable_structures.get_stored_lower_bound(updated_task)
+            + updated_task.processing_time();
 
     // If the stored bounds are already the same or the previous stored bounds did not include a
     // mandatory part (which means that this task will also not have mandatory part after
@@ -685,6 +760,7 @@ pub(crate) fn backtrack_update<Var: IntegerVariable + 'static>(
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
     use std::rc::Rc;
 
     use super::find_index_last_profile_which_propagates_lower_bound;
@@ -711,8 +787,11 @@ mod tests {
                 end: 6,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: y,
-                    processing_time: 2,
-                    resource_usage: 1,
+                    processing_time: Cell::new(2),
+                    resource_usage: Cell::new(1),
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
+                    presence: None,
                     id: LocalId::from(1),
                 })],
                 height: 1,
@@ -722,8 +801,11 @@ mod tests {
                 end: 8,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: z,
-                    processing_time: 1,
-                    resource_usage: 1,
+                    processing_time: Cell::new(1),
+                    resource_usage: Cell::new(1),
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
+                    presence: None,
                     id: LocalId::from(2),
                 })],
                 height: 1,
@@ -736,8 +818,11 @@ mod tests {
             PropagationContext::new(&assignments_integer, &assignments_propositional),
             &Rc::new(Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             }),
             1,
@@ -760,8 +845,11 @@ mod tests {
                 end: 6,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: y,
-                    processing_time: 2,
-                    resource_usage: 1,
+                    processing_time: Cell::new(2),
+                    resource_usage: Cell::new(1),
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
+                    presence: None,
                     id: LocalId::from(1),
                 })],
                 height: 1,
@@ -771,8 +859,11 @@ mod tests {
                 end: 8,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: z,
-                    processing_time: 1,
-                    resource_usage: 1,
+                    processing_time: Cell::new(1),
+                    resource_usage: Cell::new(1),
+                    processing_time_variable: None,
+                    resource_usage_variable: None,
+                    presence: None,
                     id: LocalId::from(2),
                 })],
                 height: 1,
@@ -785,8 +876,11 @@ mod tests {
             PropagationContext::new(&assignments_integer, &assignments_propositional),
             &Rc::new(Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: Cell::new(6),
+                resource_usage: Cell::new(1),
+                processing_time_variable: None,
+                resource_usage_variable: None,
+                presence: None,
                 id: LocalId::from(0),
             }),
             1,
@@ -0,0 +1,459 @@
+//! [`Propagator`] for the Cumulative constraint which uses time-table edge-finding; see
+//! [`TimeTableEdgeFindingPropagator`] for more information.
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::options::CumulativeExplanationType;
+use crate::predicates::Predicate;
+use crate::propagators::util::create_tasks;
+use crate::propagators::util::may_overflow;
+use crate::propagators::util::register_tasks;
+use crate::propagators::ArgTask;
+use crate::propagators::CumulativeParameters;
+use crate::propagators::CumulativePropagatorOptions;
+use crate::propagators::Direction;
+use crate::pumpkin_assert_simple;
+
+/// [`Propagator`] responsible for using time-table edge-finding (TTEF) to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
+/// constraint.
+///
+/// Time-table edge-finding combines the time-table with energy-based reasoning \[1\]. The energy
+/// required in a window `[begin, end)` consists of the energy of the time-table (i.e. the
+/// mandatory parts of the tasks) in the window together with the energy of the free parts (i.e.
+/// the parts which are not mandatory) of the tasks which have to be processed within the window.
+/// If this energy exceeds `capacity * (end - begin)` then the constraint is violated. Otherwise, a
+/// task which starts in the window but can complete after it is pushed such that the energy which
+/// it uses in the window does not exceed the energy which is left by the other tasks. The
+/// considered windows start at an earliest start time and end at a latest completion time; the
+/// latest start times are updated by applying the same reasoning to the mirrored tasks.
+///
+/// Like edge-finding, this reasoning takes into account the parts of the tasks which are not
+/// mandatory; the propagator is meant to be used in addition to a time-table propagator.
+///
+/// The explanations follow the [`CumulativeExplanationType`]; the naive explanation uses the
+/// current bounds of the tasks, while the other explanation types use the weakest bounds which
+/// result in the same energy in the window (similar to \[2\]).
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Timetable edge finding filtering algorithm for discrete cumulative
+/// resources’, in International Conference on Integration of Artificial Intelligence (AI) and
+/// Operations Research (OR) Techniques in Constraint Programming, 2011, pp. 230–245.
+///
+/// \[2\] A. Schutt and A. Wolf, ‘A new O(n^2 log n) not-first/not-last pruning algorithm for
+/// cumulative resource constraints’, in International Conference on Principles and Practice of
+/// Constraint Programming, 2010, pp. 445–459.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeTableEdgeFindingPropagator<Var> {
+    /// Stores the input parameters to the cumulative constraint
+    parameters: CumulativeParameters<Var>,
+}
+
+/// A task as seen in the [`Direction`] in which the reasoning is applied; for the mirrored tasks,
+/// time is reversed such that the start time `s` becomes `-s - processing_time`.
+#[derive(Debug, Clone, Copy)]
+struct DirectedTask {
+    /// The index of the task in the parameters of the propagator
+    index: usize,
+    earliest_start: i64,
+    latest_completion: i64,
+    processing_time: i64,
+    resource_usage: i64,
+}
+
+impl DirectedTask {
+    fn latest_start(&self) -> i64 {
+        self.latest_completion - self.processing_time
+    }
+
+    fn earliest_completion(&self) -> i64 {
+        self.earliest_start + self.processing_time
+    }
+
+    /// Whether the task has to be processed within `[begin, end)`.
+    fn is_within(&self, begin: i64, end: i64) -> bool {
+        begin <= self.earliest_start && self.latest_completion <= end
+    }
+
+    /// The length of the intersection of the mandatory part of the task with `[begin, end)`.
+    fn mandatory_overlap(&self, begin: i64, end: i64) -> i64 {
+        (self.earliest_completion().min(end) - self.latest_start().max(begin)).max(0)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> TimeTableEdgeFindingPropagator<Var> {
+    pub(crate) fn new(
+        arg_tasks: &[ArgTask<Var>],
+        capacity: i32,
+        cumulative_options: CumulativePropagatorOptions,
+    ) -> TimeTableEdgeFindingPropagator<Var> {
+        let tasks = create_tasks(arg_tasks);
+        pumpkin_assert_simple!(
+            tasks.iter().all(|task| task.presence.is_none()),
+            "The time-table edge-finding propagator does not support optional tasks"
+        );
+        TimeTableEdgeFindingPropagator {
+            parameters: CumulativeParameters::new(tasks, capacity, cumulative_options),
+        }
+    }
+
+    /// Returns the tasks in the given [`Direction`].
+    fn tasks_in_direction(
+        &self,
+        context: PropagationContext,
+        direction: Direction,
+    ) -> Vec<DirectedTask> {
+        self.parameters
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(index, task)| {
+                let processing_time = task.processing_time() as i64;
+                let (earliest_start, latest_completion) =
+                    direction.time_window(context, &task.start_variable, processing_time);
+
+                DirectedTask {
+                    index,
+                    earliest_start,
+                    latest_completion,
+                    processing_time,
+                    resource_usage: task.resource_usage() as i64,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the predicate stating that the task starts at or after `start` in the given
+    /// [`Direction`].
+    fn earliest_start_predicate(
+        &self,
+        task: &DirectedTask,
+        start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.earliest_start_predicate(
+            &self.parameters.tasks[task.index].start_variable,
+            task.processing_time,
+            start,
+        )
+    }
+
+    /// Returns the predicate stating that the task starts at or before `latest_start` in the
+    /// given [`Direction`].
+    fn latest_start_predicate(
+        &self,
+        task: &DirectedTask,
+        latest_start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.latest_start_predicate(
+            &self.parameters.tasks[task.index].start_variable,
+            task.processing_time,
+            latest_start,
+        )
+    }
+
+    /// Returns the predicates which ensure that the tasks (except for `excluded`) use at least the
+    /// energy which is attributed to them in the window `[begin, end)`; i.e. the tasks which are
+    /// processed within the window remain so, and the mandatory parts of the other tasks keep
+    /// (at least) their overlap with the window.
+    fn explain_window(
+        &self,
+        tasks: &[DirectedTask],
+        (begin, end): (i64, i64),
+        excluded: Option<usize>,
+        direction: Direction,
+    ) -> Vec<Predicate> {
+        let mut explanation = vec![];
+
+        for (position, task) in tasks.iter().enumerate() {
+            if Some(position) == excluded {
+                continue;
+            }
+
+            let bounds = if task.is_within(begin, end) {
+                (begin, end - task.processing_time)
+            } else {
+                let overlap = task.mandatory_overlap(begin, end);
+                if overlap == 0 {
+                    continue;
+                }
+                (
+                    task.earliest_completion().min(end) - task.processing_time,
+                    task.latest_start().max(begin),
+                )
+            };
+
+            let (earliest_start, latest_start) = match self.parameters.options.explanation_type {
+                CumulativeExplanationType::Naive => (task.earliest_start, task.latest_start()),
+                CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => bounds,
+            };
+            explanation.extend([
+                self.earliest_start_predicate(task, earliest_start, direction),
+                self.latest_start_predicate(task, latest_start, direction),
+            ]);
+        }
+
+        explanation
+    }
+
+    /// Applies time-table edge-finding to the tasks in the given [`Direction`].
+    fn propagate_direction(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let tasks = self.tasks_in_direction(context.as_readonly(), direction);
+        let capacity = self.parameters.capacity as i64;
+
+        let mut begins = tasks
+            .iter()
+            .map(|task| task.earliest_start)
+            .collect::<Vec<_>>();
+        begins.sort_unstable();
+        begins.dedup();
+        let mut ends = tasks
+            .iter()
+            .map(|task| task.latest_completion)
+            .collect::<Vec<_>>();
+        ends.sort_unstable();
+        ends.dedup();
+
+        for &begin in &begins {
+            for &end in ends.iter().filter(|&&end| end > begin) {
+                // The energy of the time-table in the window, together with the energy of the free
+                // parts of the tasks which have to be processed within the window
+                let energy = tasks
+                    .iter()
+                    .map(|task| {
+                        if task.is_within(begin, end) {
+                            task.processing_time * task.resource_usage
+                        } else {
+                            task.mandatory_overlap(begin, end) * task.resource_usage
+                        }
+                    })
+                    .sum::<i64>();
+
+                let available_energy = capacity * (end - begin);
+                if energy > available_energy {
+                    return Err(PropositionalConjunction::from(self.explain_window(
+                        &tasks,
+                        (begin, end),
+                        None,
+                        direction,
+                    ))
+                    .into());
+                }
+
+                for (position, task) in tasks.iter().enumerate() {
+                    // Only the tasks which start in the window but can complete after it are
+                    // considered; note that the mandatory part of the task itself is not taken
+                    // into account, since it moves along with the task
+                    if task.earliest_start < begin
+                        || task.earliest_start >= end
+                        || task.latest_completion <= end
+                    {
+                        continue;
+                    }
+
+                    let energy_left = available_energy - energy
+                        + task.mandatory_overlap(begin, end) * task.resource_usage;
+                    let required_energy =
+                        task.processing_time.min(end - task.earliest_start) * task.resource_usage;
+                    if required_energy <= energy_left {
+                        continue;
+                    }
+
+                    let bound = end - energy_left / task.resource_usage;
+                    let variable = &self.parameters.tasks[task.index].start_variable;
+
+                    let mut reason =
+                        self.explain_window(&tasks, (begin, end), Some(position), direction);
+                    reason.push(match self.parameters.options.explanation_type {
+                        CumulativeExplanationType::Naive => {
+                            self.earliest_start_predicate(task, task.earliest_start, direction)
+                        }
+                        CumulativeExplanationType::BigStep
+                        | CumulativeExplanationType::Pointwise => {
+                            self.earliest_start_predicate(task, begin, direction)
+                        }
+                    });
+
+                    direction.set_earliest_start(
+                        context,
+                        variable,
+                        task.processing_time,
+                        bound,
+                        PropositionalConjunction::from(reason),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TimeTableEdgeFindingPropagator<Var> {
+    fn name(&self) -> &str {
+        "CumulativeTimeTableEdgeFinding"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        may_overflow(context, &self.parameters.tasks)
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        register_tasks(&self.parameters.tasks, context, false);
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_direction(&mut context, Direction::Forward)?;
+        self.propagate_direction(&mut context, Direction::Mirrored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    fn options(explanation_type: CumulativeExplanationType) -> CumulativePropagatorOptions {
+        CumulativePropagatorOptions {
+            explanation_type,
+            ..Default::default()
+        }
+    }
+
+    fn arg_tasks<Var: Clone>(tasks: &[(Var, i32, i32)]) -> Vec<ArgTask<Var>> {
+        tasks
+            .iter()
+            .map(|(start_time, processing_time, resource_usage)| ArgTask {
+                start_time: start_time.clone(),
+                processing_time: *processing_time,
+                resource_usage: *resource_usage,
+                presence: None,
+                processing_time_variable: None,
+                resource_usage_variable: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn overload_is_detected() {
+        // Tasks a, b and c all have to be processed in [0, 4)
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(1, 2);
+
+        let result = solver.new_propagator(TimeTableEdgeFindingPropagator::new(
+            &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 2, 1)]),
+            1,
+            options(CumulativeExplanationType::BigStep),
+        ));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 0] & [c <= 2])
+        );
+    }
+
+    #[test]
+    fn task_is_pushed_by_time_table_and_free_parts() {
+        // Tasks a and b have to be processed in [0, 5), which leaves room for only one unit of c
+        // in this window
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(2, 3);
+        let c = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(TimeTableEdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 2, 1)]),
+                1,
+                options(CumulativeExplanationType::BigStep),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 4, 10);
+        let reason = solver.get_reason_int(predicate![c >= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 3] & [b >= 0] & [b <= 3] & [c >= 0])
+        );
+    }
+
+    #[test]
+    fn naive_explanation_uses_current_bounds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(2, 3);
+        let c = solver.new_variable(1, 10);
+
+        let mut propagator = solver
+            .new_propagator(TimeTableEdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 2, 1)]),
+                1,
+                options(CumulativeExplanationType::Naive),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 4, 10);
+        let reason = solver.get_reason_int(predicate![c >= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 1] & [b >= 2] & [b <= 3] & [c >= 1])
+        );
+    }
+
+    #[test]
+    fn mirrored_tasks_update_upper_bounds() {
+        // Tasks a and b have to be processed in [6, 10), so c has to complete before 6
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(8, 8);
+        let b = solver.new_variable(6, 7);
+        let c = solver.new_variable(0, 8);
+
+        let mut propagator = solver
+            .new_propagator(TimeTableEdgeFindingPropagator::new(
+                &arg_tasks(&[(a, 2, 1), (b, 2, 1), (c, 2, 1)]),
+                1,
+                options(CumulativeExplanationType::Pointwise),
+            ))
+            .expect("no empty domains");
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 0, 4);
+        let reason = solver.get_reason_int(predicate![c <= 4].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([a <= 8] & [a >= 6] & [b <= 8] & [b >= 6] & [c <= 8])
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::EmptyDomain;
+use crate::predicate;
+use crate::predicates::Predicate;
+
+/// Whether the reasoning of a scheduling propagator is applied to the tasks themselves (updating
+/// the earliest start times) or to the mirrored tasks (updating the latest start times).
+///
+/// For the mirrored tasks, time is reversed such that the start time `s` of a task becomes
+/// `-s - processing_time`; this allows the same reasoning to be used for both bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Forward,
+    Mirrored,
+}
+
+impl Direction {
+    /// Returns the earliest start time and the latest completion time in this direction of a task
+    /// with the given start time and processing time.
+    pub(crate) fn time_window<Var: IntegerVariable>(
+        self,
+        context: PropagationContext,
+        start_time: &Var,
+        processing_time: i64,
+    ) -> (i64, i64) {
+        let lower_bound = context.lower_bound(start_time) as i64;
+        let upper_bound = context.upper_bound(start_time) as i64;
+        match self {
+            Direction::Forward => (lower_bound, upper_bound + processing_time),
+            Direction::Mirrored => (-upper_bound - processing_time, -lower_bound),
+        }
+    }
+
+    /// Returns the predicate stating that the task starts at or after `start` in this direction.
+    pub(crate) fn earliest_start_predicate<Var: IntegerVariable>(
+        self,
+        start_time: &Var,
+        processing_time: i64,
+        start: i64,
+    ) -> Predicate {
+        match self {
+            Direction::Forward => predicate![start_time >= start as i32],
+            Direction::Mirrored => predicate![start_time <= (-start - processing_time) as i32],
+        }
+    }
+
+    /// Returns the predicate stating that the task starts at or before `latest_start` in this
+    /// direction.
+    pub(crate) fn latest_start_predicate<Var: IntegerVariable>(
+        self,
+        start_time: &Var,
+        processing_time: i64,
+        latest_start: i64,
+    ) -> Predicate {
+        match self {
+            Direction::Forward => predicate![start_time <= latest_start as i32],
+            Direction::Mirrored => {
+                predicate![start_time >= (-latest_start - processing_time) as i32]
+            }
+        }
+    }
+
+    /// Updates the earliest start time in this direction of the task to `bound`; for the mirrored
+    /// tasks, this updates the upper bound of the start time.
+    pub(crate) fn set_earliest_start<Var: IntegerVariable>(
+        self,
+        context: &mut PropagationContextMut,
+        start_time: &Var,
+        processing_time: i64,
+        bound: i64,
+        reason: PropositionalConjunction,
+    ) -> Result<(), EmptyDomain> {
+        match self {
+            Direction::Forward => context.set_lower_bound(start_time, bound as i32, reason),
+            Direction::Mirrored => {
+                context.set_upper_bound(start_time, (-bound - processing_time) as i32, reason)
+            }
+        }
+    }
+}
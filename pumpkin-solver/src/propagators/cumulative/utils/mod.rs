@@ -2,6 +2,9 @@
 //! constraint which are generalisable enough to be useful for different types of cumulative
 //! propagators
 
+mod direction;
+pub(crate) use direction::*;
+
 mod structs;
 pub(crate) use structs::*;
 
@@ -82,10 +82,10 @@ impl<Var> UpdatedTaskInfo<Var> {
     pub(crate) fn get_mandatory_part_adjustments(&self) -> MandatoryPartAdjustments {
         // We get the previous mandatory part
         let previous_mandatory_part =
-            self.old_upper_bound..self.old_lower_bound + self.task.processing_time;
+            self.old_upper_bound..self.old_lower_bound + self.task.processing_time();
         // We also get the new mandatory part
         let new_mandatory_part =
-            self.new_upper_bound..self.new_lower_bound + self.task.processing_time;
+            self.new_upper_bound..self.new_lower_bound + self.task.processing_time();
 
         if previous_mandatory_part.is_empty() && new_mandatory_part.is_empty() {
             // If both are empty then no adjustments should be made
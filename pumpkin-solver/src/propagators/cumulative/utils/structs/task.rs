@@ -1,19 +1,38 @@
+use std::cell::Cell;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::rc::Rc;
 
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
 
-/// Structure which stores the variables related to a task; for now, only the start times are
-/// assumed to be variable
+/// Structure which stores the variables related to a task; next to the start time and the presence
+/// of optional tasks, the processing time and resource usage of a task can be variable.
+///
+/// The processing time and resource usage of a task are read through [`Task::processing_time`] and
+/// [`Task::resource_usage`]; if they are variable, these return the lower bounds of the variables
+/// at the last call to [`Task::update_parameters`], which the propagators supporting variable tasks
+/// call before they (re)construct their time-table.
 pub(crate) struct Task<Var> {
     /// The variable representing the start time of a task
     pub(crate) start_variable: Var,
     /// The processing time of the `start_variable` (also referred to as duration of a task)
-    pub(crate) processing_time: i32,
+    pub(crate) processing_time: Cell<i32>,
     /// How much of the resource the given task uses during its non-preemptive execution
-    pub(crate) resource_usage: i32,
+    pub(crate) resource_usage: Cell<i32>,
+    /// The variable representing the processing time of the task; if it is [`None`] then the
+    /// processing time is constant
+    pub(crate) processing_time_variable: Option<Var>,
+    /// The variable representing the resource usage of the task; if it is [`None`] then the
+    /// resource usage is constant
+    pub(crate) resource_usage_variable: Option<Var>,
+    /// The literal indicating whether an optional task is present; if it is [`None`] then the
+    /// task is always present
+    pub(crate) presence: Option<Literal>,
     /// The [`LocalId`] of the task
     pub(crate) id: LocalId,
 }
@@ -21,17 +40,104 @@ pub(crate) struct Task<Var> {
 impl<Var> Debug for Task<Var> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Task")
-            .field("processing_time", &self.processing_time)
-            .field("resource_usage", &self.resource_usage)
+            .field("processing_time", &self.processing_time.get())
+            .field("resource_usage", &self.resource_usage.get())
+            .field("presence", &self.presence)
             .field("local_id", &self.id)
             .finish()
     }
 }
 
+impl<Var> Task<Var> {
+    /// Returns the processing time of the task (see [`Task`] for variable processing times).
+    pub(crate) fn processing_time(&self) -> i32 {
+        self.processing_time.get()
+    }
+
+    /// Returns the resource usage of the task (see [`Task`] for variable resource usages).
+    pub(crate) fn resource_usage(&self) -> i32 {
+        self.resource_usage.get()
+    }
+}
+
 impl<Var: IntegerVariable + 'static> Task<Var> {
     pub(crate) fn get_id(task: &Rc<Task<Var>>) -> usize {
         task.id.unpack() as usize
     }
+
+    /// Sets the variable processing time and resource usage of the task to the lower bounds of
+    /// their variables.
+    pub(crate) fn update_parameters(&self, context: impl ReadDomains + Copy) {
+        if let Some(processing_time_variable) = &self.processing_time_variable {
+            self.processing_time
+                .set(context.lower_bound(processing_time_variable));
+        }
+        if let Some(resource_usage_variable) = &self.resource_usage_variable {
+            self.resource_usage
+                .set(context.lower_bound(resource_usage_variable));
+        }
+    }
+
+    /// Returns the largest processing time which the task can have.
+    pub(crate) fn max_processing_time(&self, context: impl ReadDomains) -> i32 {
+        self.processing_time_variable
+            .as_ref()
+            .map_or(self.processing_time(), |variable| {
+                context.upper_bound(variable)
+            })
+    }
+
+    /// Returns the largest resource usage which the task can have.
+    pub(crate) fn max_resource_usage(&self, context: impl ReadDomains) -> i32 {
+        self.resource_usage_variable
+            .as_ref()
+            .map_or(self.resource_usage(), |variable| {
+                context.upper_bound(variable)
+            })
+    }
+
+    /// Returns whether the task is known to be present; this is always the case for tasks which
+    /// are not optional.
+    pub(crate) fn is_present(&self, context: impl ReadDomains) -> bool {
+        self.presence
+            .is_none_or(|presence| context.is_literal_true(presence))
+    }
+
+    /// Returns whether it is not (yet) known whether the optional task is present.
+    pub(crate) fn is_presence_unassigned(&self, context: impl ReadDomains) -> bool {
+        self.presence
+            .is_some_and(|presence| !context.is_literal_fixed(presence))
+    }
+
+    /// Returns the [`Predicate`] which indicates that the task is present if it is optional.
+    pub(crate) fn presence_predicate(&self) -> Option<Predicate> {
+        self.presence.map(Predicate::from)
+    }
+
+    /// Returns the [`Predicate`]s which state that the variable processing time and resource usage
+    /// are at least the values which are used by the propagator.
+    pub(crate) fn parameter_predicates(&self) -> impl Iterator<Item = Predicate> + '_ {
+        let processing_time = self
+            .processing_time_variable
+            .as_ref()
+            .map(|variable| predicate![variable >= self.processing_time()]);
+        let resource_usage = self
+            .resource_usage_variable
+            .as_ref()
+            .map(|variable| predicate![variable >= self.resource_usage()]);
+
+        processing_time.into_iter().chain(resource_usage)
+    }
+
+    /// Returns the [`Predicate`]s which should be added to explanations which rely on the resource
+    /// usage of the task; these are its presence (if it is optional) and the
+    /// [`Task::parameter_predicates`].
+    pub(crate) fn usage_predicates(&self) -> Vec<Predicate> {
+        self.presence_predicate()
+            .into_iter()
+            .chain(self.parameter_predicates())
+            .collect()
+    }
 }
 
 impl<Var: IntegerVariable + 'static> Hash for Task<Var> {
@@ -58,4 +164,13 @@ pub(crate) struct ArgTask<Var> {
     pub(crate) processing_time: i32,
     /// How much of the resource the given task uses during its non-preemptive execution
     pub(crate) resource_usage: i32,
+    /// The literal indicating whether the task is present; if it is [`None`] then the task is
+    /// always present
+    pub(crate) presence: Option<Literal>,
+    /// The variable representing the processing time; if it is set, then
+    /// [`processing_time`][ArgTask::processing_time] is a lower bound on it
+    pub(crate) processing_time_variable: Option<Var>,
+    /// The variable representing the resource usage; if it is set, then
+    /// [`resource_usage`][ArgTask::resource_usage] is a lower bound on it
+    pub(crate) resource_usage_variable: Option<Var>,
 }
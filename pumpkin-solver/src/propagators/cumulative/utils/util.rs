@@ -1,6 +1,7 @@
 //! Contains common methods for all of the propagators of the cumulative constraint; this includes
 //! methods for propagating but also methods related to creating the
 //! input parameters.
+use std::cell::Cell;
 use std::rc::Rc;
 
 use enumset::enum_set;
@@ -18,24 +19,28 @@ use crate::propagators::Task;
 /// Based on the [`ArgTask`]s which are passed, it creates and returns [`Task`]s which have been
 /// registered for [`DomainEvents`].
 ///
-/// It sorts [`Task`]s on non-decreasing resource usage and removes [`Task`]s with resource usage 0.
+/// It sorts [`Task`]s on non-increasing resource usage and removes [`Task`]s with resource usage 0
+/// (unless their resource usage is variable).
 pub(crate) fn create_tasks<Var: IntegerVariable + 'static>(
     arg_tasks: &[ArgTask<Var>],
 ) -> Vec<Task<Var>> {
-    // We order the tasks by non-decreasing resource usage, this allows certain optimizations
+    // We order the tasks by non-increasing resource usage, this allows certain optimizations
     let mut ordered_tasks = arg_tasks.to_vec();
     ordered_tasks.sort_by(|a, b| b.resource_usage.cmp(&a.resource_usage));
 
     let mut id = 0;
     ordered_tasks
-        .iter()
+        .into_iter()
         .filter_map(|x| {
             // We only add tasks which have a non-zero resource usage
-            if x.resource_usage > 0 {
+            if x.resource_usage > 0 || x.resource_usage_variable.is_some() {
                 let return_value = Some(Task {
-                    start_variable: x.start_time.clone(),
-                    processing_time: x.processing_time,
-                    resource_usage: x.resource_usage,
+                    start_variable: x.start_time,
+                    processing_time: Cell::new(x.processing_time),
+                    resource_usage: Cell::new(x.resource_usage),
+                    processing_time_variable: x.processing_time_variable,
+                    resource_usage_variable: x.resource_usage_variable,
+                    presence: x.presence,
                     id: LocalId::from(id),
                 });
 
@@ -48,12 +53,37 @@ pub(crate) fn create_tasks<Var: IntegerVariable + 'static>(
         .collect::<Vec<Task<Var>>>()
 }
 
+/// Registers the start variables of the [`Task`]s (using the id of the task as [`LocalId`]) and
+/// the presence literals of the optional [`Task`]s (using the id of the task offset by the number
+/// of tasks as [`LocalId`]).
+///
+/// The variable processing times and resource usages are registered for lower-bound events using
+/// the id of the task offset by two and three times the number of tasks respectively.
 pub(crate) fn register_tasks<Var: IntegerVariable + 'static>(
     tasks: &[Rc<Task<Var>>],
     context: &mut PropagatorInitialisationContext<'_>,
     register_backtrack: bool,
 ) {
     tasks.iter().for_each(|task| {
+        if let Some(presence) = task.presence {
+            let _ = context.register_literal(
+                presence,
+                DomainEvents::ANY_BOOL,
+                LocalId::from(tasks.len() as u32 + task.id.unpack()),
+            );
+        }
+        for (offset, variable) in [
+            (2, &task.processing_time_variable),
+            (3, &task.resource_usage_variable),
+        ] {
+            if let Some(variable) = variable {
+                let _ = context.register(
+                    variable.clone(),
+                    DomainEvents::LOWER_BOUND,
+                    LocalId::from(offset * tasks.len() as u32 + task.id.unpack()),
+                );
+            }
+        }
         let _ = context.register(
             task.start_variable.clone(),
             DomainEvents::create_with_int_events(enum_set!(
@@ -102,3 +132,83 @@ pub(crate) fn check_bounds_equal_at_propagation<Var: IntegerVariable + 'static>(
             )
     })
 }
+
+/// Returns whether the arithmetic of a cumulative propagator over the provided [`Task`]s may
+/// overflow an `i32`; this is the case if the (latest) completion time of a task or the sum of the
+/// resource usages does not fit, taking into account the largest values of variable processing
+/// times and resource usages.
+pub(crate) fn may_overflow<Var: IntegerVariable + 'static>(
+    context: PropagationContext,
+    tasks: &[Rc<Task<Var>>],
+) -> bool {
+    let is_outside_horizon = tasks.iter().any(|task| {
+        let (lower_bound, upper_bound) = context.bounds_i64(&task.start_variable);
+        context.bounds_overflow(&task.start_variable)
+            || lower_bound - (task.max_processing_time(context) as i64) < i32::MIN as i64
+            || upper_bound + task.max_processing_time(context) as i64 > i32::MAX as i64
+    });
+
+    let total_resource_usage = tasks
+        .iter()
+        .map(|task| task.max_resource_usage(context) as i64)
+        .sum::<i64>();
+
+    is_outside_horizon || total_resource_usage > i32::MAX as i64
+}
+
+/// Computes a lower-bound on the makespan (i.e. the latest end time) of the provided tasks, given
+/// as `(earliest_start_time, processing_time, resource_usage)`, based on their energy.
+///
+/// For every earliest start time `t`, the tasks which cannot start before `t` have to be
+/// processed after `t`; since at most `resource_capacity` units of the resource are available at
+/// every point in time, the makespan is at least `t + ⌈energy / resource_capacity⌉` where
+/// `energy` is the sum of `processing_time * resource_usage` over these tasks. Additionally, the
+/// makespan is at least the earliest end time of every task.
+pub(crate) fn energetic_makespan_lower_bound(
+    tasks: impl IntoIterator<Item = (i32, i32, i32)>,
+    resource_capacity: i32,
+) -> i64 {
+    let mut tasks = tasks.into_iter().collect::<Vec<_>>();
+    tasks.sort_by_key(|&(earliest_start_time, _, _)| std::cmp::Reverse(earliest_start_time));
+
+    let mut lower_bound = i64::MIN;
+    let mut energy = 0_i64;
+    for (index, &(earliest_start_time, processing_time, resource_usage)) in tasks.iter().enumerate()
+    {
+        let earliest_start_time = earliest_start_time as i64;
+        lower_bound = lower_bound.max(earliest_start_time + processing_time as i64);
+        energy += processing_time as i64 * resource_usage as i64;
+
+        // Only consider the energy once all tasks with this earliest start time are included
+        let is_last_with_start_time = match tasks.get(index + 1) {
+            Some(&(next_start_time, _, _)) => next_start_time as i64 != earliest_start_time,
+            None => true,
+        };
+        if is_last_with_start_time && resource_capacity > 0 {
+            let duration = (energy + resource_capacity as i64 - 1) / resource_capacity as i64;
+            lower_bound = lower_bound.max(earliest_start_time + duration);
+        }
+    }
+
+    lower_bound
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn makespan_lower_bound_considers_energy_after_every_start_time() {
+        // Capacity 2: all tasks together have energy 4 + 4 + 12 = 20, which requires 10 time
+        // units from time 0; the tasks starting at 5 have energy 12, which requires 6 time units
+        // from time 5
+        let tasks = [(0, 2, 2), (0, 4, 1), (5, 3, 2), (5, 3, 2)];
+        assert_eq!(energetic_makespan_lower_bound(tasks, 2), 11);
+    }
+
+    #[test]
+    fn makespan_lower_bound_is_at_least_the_earliest_end_times() {
+        let tasks = [(0, 1, 1), (3, 10, 1)];
+        assert_eq!(energetic_makespan_lower_bound(tasks, 5), 13);
+    }
+}
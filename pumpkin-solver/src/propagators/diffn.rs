@@ -0,0 +1,353 @@
+//! Contains the propagator for the [Diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html)
+//! constraint in two dimensions, which states that no two rectangles overlap.
+//!
+//! Every rectangle `i` has an origin `(x_i, y_i)`, which is given by variables, and a fixed width
+//! `w_i` and height `h_i`. Two rectangles `i` and `j` do not overlap if one of them is placed
+//! completely to the left of or below the other one; i.e. if
+//! `x_i + w_i <= x_j \/ x_j + w_j <= x_i \/ y_i + h_i <= y_j \/ y_j + h_j <= y_i`. Note that this
+//! also applies to rectangles with a width or height of zero.
+//!
+//! The propagator (see [`DiffnPropagator`]) reasons over every pair of rectangles; additionally,
+//! the projection of the rectangles onto either dimension can be propagated as a cumulative
+//! constraint (see [`crate::constraints::diffn_with_options`]).
+use enumset::enum_set;
+
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::IntDomainEvent;
+use crate::predicate;
+use crate::predicates::Predicate;
+
+/// The options of the diffn constraint (see [`crate::constraints::diffn_with_options`]).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DiffnOptions {
+    /// Whether the projections of the rectangles onto both axes are additionally propagated as
+    /// cumulative constraints. This is stronger than the pairwise reasoning when many rectangles
+    /// compete for the same area, but it is also more expensive.
+    pub cumulative_relaxations: bool,
+}
+
+/// The rectangle which is passed as argument to the [`DiffnPropagator`].
+#[derive(Clone, Debug)]
+pub(crate) struct ArgRectangle<Var> {
+    /// The [`IntegerVariable`] representing the x-coordinate of the origin of the rectangle
+    pub(crate) x: Var,
+    /// The [`IntegerVariable`] representing the y-coordinate of the origin of the rectangle
+    pub(crate) y: Var,
+    /// The size of the rectangle along the x-axis
+    pub(crate) width: i32,
+    /// The size of the rectangle along the y-axis
+    pub(crate) height: i32,
+}
+
+/// [`Propagator`] for the [Diffn](https://sofdem.github.io/gccat/gccat/Cdiffn.html) constraint,
+/// which uses pairwise reasoning (see the [module documentation](crate::propagators::diffn)).
+///
+/// For every pair of rectangles, it is determined which of the four ways of separating them (`i`
+/// left of `j`, `j` left of `i`, `i` below `j` and `j` below `i`) are still possible given the
+/// bounds of the origins. If none of them is possible, then the rectangles overlap; if only one of
+/// them is possible, then it is enforced.
+#[derive(Clone, Debug)]
+pub(crate) struct DiffnPropagator<Var> {
+    rectangles: Box<[ArgRectangle<Var>]>,
+}
+
+/// One of the ways in which two rectangles can be separated: the rectangle `before` is placed
+/// completely before the rectangle `after` along one of the axes; i.e. `before + size <= after`.
+struct Separation<'a, Var> {
+    before: &'a Var,
+    size: i32,
+    after: &'a Var,
+}
+
+impl<Var: IntegerVariable> Separation<'_, Var> {
+    fn is_possible(&self, context: PropagationContext) -> bool {
+        context.lower_bound(self.before) as i64 + self.size as i64
+            <= context.upper_bound(self.after) as i64
+    }
+
+    /// Explains why the separation is not possible; the upper-bound of `after` is lifted to the
+    /// largest value which still prevents it.
+    fn explain_impossible(&self, context: PropagationContext) -> [Predicate; 2] {
+        let lower_bound = context.lower_bound(self.before);
+        let latest_after = (lower_bound as i64 + self.size as i64 - 1).min(i32::MAX as i64) as i32;
+        [
+            predicate![self.before >= lower_bound],
+            predicate![self.after <= latest_after],
+        ]
+    }
+}
+
+impl<Var: IntegerVariable + 'static> DiffnPropagator<Var> {
+    pub(crate) fn new(rectangles: &[ArgRectangle<Var>]) -> DiffnPropagator<Var> {
+        DiffnPropagator {
+            rectangles: rectangles.into(),
+        }
+    }
+
+    /// Returns the four ways in which the rectangles `i` and `j` can be separated.
+    fn separations(&self, i: usize, j: usize) -> [Separation<'_, Var>; 4] {
+        let first = &self.rectangles[i];
+        let second = &self.rectangles[j];
+        [
+            Separation {
+                before: &first.x,
+                size: first.width,
+                after: &second.x,
+            },
+            Separation {
+                before: &second.x,
+                size: second.width,
+                after: &first.x,
+            },
+            Separation {
+                before: &first.y,
+                size: first.height,
+                after: &second.y,
+            },
+            Separation {
+                before: &second.y,
+                size: second.height,
+                after: &first.y,
+            },
+        ]
+    }
+
+    /// Propagates the pair of rectangles `i` and `j`; returns whether a bound was changed.
+    fn propagate_pair(
+        &self,
+        context: &mut PropagationContextMut,
+        i: usize,
+        j: usize,
+    ) -> Result<bool, Inconsistency> {
+        let separations = self.separations(i, j);
+
+        let mut possible = separations
+            .iter()
+            .enumerate()
+            .filter(|(_, separation)| separation.is_possible(context.as_readonly()));
+        let Some((enforced, separation)) = possible.next() else {
+            return Err(separations
+                .iter()
+                .flat_map(|separation| separation.explain_impossible(context.as_readonly()))
+                .collect::<PropositionalConjunction>()
+                .into());
+        };
+        if possible.next().is_some() {
+            return Ok(false);
+        }
+
+        let reason = separations
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != enforced)
+            .flat_map(|(_, separation)| separation.explain_impossible(context.as_readonly()))
+            .collect::<Vec<_>>();
+
+        // As the separation is possible, both of these bounds lie within the domains
+        let lower_bound_before = context.lower_bound(separation.before);
+        let upper_bound_after = context.upper_bound(separation.after);
+        let earliest_after = lower_bound_before + separation.size;
+        let latest_before = upper_bound_after - separation.size;
+
+        let mut has_changed = false;
+        if earliest_after > context.lower_bound(separation.after) {
+            let mut after_reason = reason.clone();
+            after_reason.push(predicate![separation.before >= lower_bound_before]);
+            context.set_lower_bound(
+                separation.after,
+                earliest_after,
+                PropositionalConjunction::from(after_reason),
+            )?;
+            has_changed = true;
+        }
+        if latest_before < context.upper_bound(separation.before) {
+            let mut before_reason = reason;
+            before_reason.push(predicate![separation.after <= upper_bound_after]);
+            context.set_upper_bound(
+                separation.before,
+                latest_before,
+                PropositionalConjunction::from(before_reason),
+            )?;
+            has_changed = true;
+        }
+
+        Ok(has_changed)
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DiffnPropagator<Var> {
+    fn name(&self) -> &str {
+        "Diffn"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.rectangles.iter().any(|rectangle| {
+            context.bounds_overflow(&rectangle.x)
+                || context.bounds_overflow(&rectangle.y)
+                || context.upper_bound(&rectangle.x) as i64 + rectangle.width as i64
+                    > i32::MAX as i64
+                || context.upper_bound(&rectangle.y) as i64 + rectangle.height as i64
+                    > i32::MAX as i64
+        })
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let num_rectangles = self.rectangles.len() as u32;
+        let events = DomainEvents::create_with_int_events(enum_set!(
+            IntDomainEvent::LowerBound | IntDomainEvent::UpperBound | IntDomainEvent::Assign
+        ));
+        for (index, rectangle) in self.rectangles.iter().enumerate() {
+            let _ = context.register(rectangle.x.clone(), events, LocalId::from(index as u32));
+            let _ = context.register(
+                rectangle.y.clone(),
+                events,
+                LocalId::from(num_rectangles + index as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // Enforcing a separation can make another separation impossible, so the pairs are
+        // propagated until no bound changes
+        let mut has_changed = true;
+        while has_changed {
+            has_changed = false;
+            for i in 0..self.rectangles.len() {
+                for j in i + 1..self.rectangles.len() {
+                    has_changed |= self.propagate_pair(&mut context, i, j)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    fn arg_rectangles<Var: Clone>(rectangles: &[(Var, Var, i32, i32)]) -> Vec<ArgRectangle<Var>> {
+        rectangles
+            .iter()
+            .map(|(x, y, width, height)| ArgRectangle {
+                x: x.clone(),
+                y: y.clone(),
+                width: *width,
+                height: *height,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn only_possible_separation_is_enforced() {
+        let mut solver = TestSolver::default();
+        let x_a = solver.new_variable(0, 2);
+        let y_a = solver.new_variable(0, 0);
+        let x_b = solver.new_variable(0, 5);
+        let y_b = solver.new_variable(0, 1);
+
+        // `b` cannot be below `a` (or the other way around) and cannot be left of `a`
+        let _ = solver
+            .new_propagator(DiffnPropagator::new(&arg_rectangles(&[
+                (x_a, y_a, 2, 3),
+                (x_b, y_b, 4, 2),
+            ])))
+            .expect("no empty domains");
+
+        assert_eq!(solver.lower_bound(x_b), 2);
+        assert_eq!(solver.upper_bound(x_a), 2);
+        assert_eq!(solver.upper_bound(x_b), 5);
+
+        let reason = solver.get_reason_int(predicate![x_b >= 2].try_into().unwrap());
+        assert_eq!(
+            conjunction!(
+                [x_b >= 0]
+                    & [x_a <= 3]
+                    & [y_a >= 0]
+                    & [y_b <= 2]
+                    & [y_b >= 0]
+                    & [y_a <= 1]
+                    & [x_a >= 0]
+            ),
+            *reason
+        );
+    }
+
+    #[test]
+    fn overlap_is_detected() {
+        let mut solver = TestSolver::default();
+        let x_a = solver.new_variable(0, 1);
+        let y_a = solver.new_variable(0, 1);
+        let x_b = solver.new_variable(0, 1);
+        let y_b = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(DiffnPropagator::new(&arg_rectangles(&[
+            (x_a, y_a, 2, 2),
+            (x_b, y_b, 2, 2),
+        ])));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!(
+                [x_a >= 0]
+                    & [x_b <= 1]
+                    & [x_b >= 0]
+                    & [x_a <= 1]
+                    & [y_a >= 0]
+                    & [y_b <= 1]
+                    & [y_b >= 0]
+                    & [y_a <= 1]
+            )
+        );
+    }
+
+    #[test]
+    fn rectangles_which_can_be_separated_in_several_ways_are_not_propagated() {
+        let mut solver = TestSolver::default();
+        let x_a = solver.new_variable(0, 4);
+        let y_a = solver.new_variable(0, 4);
+        let x_b = solver.new_variable(0, 4);
+        let y_b = solver.new_variable(0, 4);
+
+        let _ = solver
+            .new_propagator(DiffnPropagator::new(&arg_rectangles(&[
+                (x_a, y_a, 2, 2),
+                (x_b, y_b, 2, 2),
+            ])))
+            .expect("no empty domains");
+
+        for variable in [x_a, y_a, x_b, y_b] {
+            assert_eq!(solver.lower_bound(variable), 0);
+            assert_eq!(solver.upper_bound(variable), 4);
+        }
+    }
+}
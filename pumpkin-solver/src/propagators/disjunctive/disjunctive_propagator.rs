@@ -0,0 +1,610 @@
+use std::cmp::Reverse;
+
+use enumset::enum_set;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::IntDomainEvent;
+use crate::predicates::Predicate;
+use crate::propagators::Direction;
+use crate::propagators::ThetaLambdaTree;
+use crate::variables::Literal;
+
+/// The task which is passed as argument to the [`DisjunctivePropagator`].
+#[derive(Clone, Debug)]
+pub(crate) struct ArgDisjunctiveTask<Var> {
+    /// The [`IntegerVariable`] representing the start time of the task
+    pub(crate) start_time: Var,
+    /// The processing time of the task
+    pub(crate) processing_time: i32,
+    /// The literal indicating whether the task is present; if it is [`None`] then the task is
+    /// always present
+    pub(crate) presence: Option<Literal>,
+}
+
+/// [`Propagator`] for the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// constraint, which uses overload checking and detectable precedences (see the
+/// [module documentation](crate::propagators::disjunctive)).
+///
+/// Overload checking detects that the present tasks which have to be processed in a window do not
+/// fit in it; an optional task which does not fit in such a window together with the present
+/// tasks is made absent. Detectable precedences update the earliest start time of a task `i` to
+/// the earliest completion time of the present tasks `j` for which `lst_j < ect_i` (which cannot
+/// be processed after `i`); an optional task which cannot start before its latest start time is
+/// made absent. The latest start times are updated by applying the same reasoning to the mirrored
+/// tasks.
+///
+/// The bounds of an optional task are only updated once it is known to be present.
+#[derive(Clone, Debug)]
+pub(crate) struct DisjunctivePropagator<Var> {
+    tasks: Box<[ArgDisjunctiveTask<Var>]>,
+}
+
+/// A task as seen in the [`Direction`] in which the reasoning is applied; for the mirrored tasks,
+/// time is reversed such that the start time `s` becomes `-s - processing_time`.
+#[derive(Debug, Clone, Copy)]
+struct DirectedTask {
+    /// The index of the task in the tasks of the propagator
+    index: usize,
+    earliest_start: i64,
+    latest_completion: i64,
+    processing_time: i64,
+    /// Whether it is not (yet) known whether the task is present
+    is_optional: bool,
+}
+
+impl DirectedTask {
+    fn earliest_completion(&self) -> i64 {
+        self.earliest_start + self.processing_time
+    }
+
+    fn latest_start(&self) -> i64 {
+        self.latest_completion - self.processing_time
+    }
+}
+
+impl<Var: IntegerVariable + 'static> DisjunctivePropagator<Var> {
+    pub(crate) fn new(tasks: &[ArgDisjunctiveTask<Var>]) -> DisjunctivePropagator<Var> {
+        DisjunctivePropagator {
+            // Tasks without a processing time cannot overlap with other tasks
+            tasks: tasks
+                .iter()
+                .filter(|task| task.processing_time > 0)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns the tasks which are not absent in the given [`Direction`], sorted by non-decreasing
+    /// earliest start time.
+    fn tasks_in_direction(
+        &self,
+        context: PropagationContext,
+        direction: Direction,
+    ) -> Vec<DirectedTask> {
+        let mut tasks = self
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| {
+                task.presence
+                    .is_none_or(|presence| !context.is_literal_false(presence))
+            })
+            .map(|(index, task)| {
+                let processing_time = task.processing_time as i64;
+                let (earliest_start, latest_completion) =
+                    direction.time_window(context, &task.start_time, processing_time);
+
+                DirectedTask {
+                    index,
+                    earliest_start,
+                    latest_completion,
+                    processing_time,
+                    is_optional: task
+                        .presence
+                        .is_some_and(|presence| !context.is_literal_fixed(presence)),
+                }
+            })
+            .collect::<Vec<_>>();
+        tasks.sort_by_key(|task| task.earliest_start);
+        tasks
+    }
+
+    /// Returns the predicate stating that the task starts at or after `start` in the given
+    /// [`Direction`].
+    fn earliest_start_predicate(
+        &self,
+        task: &DirectedTask,
+        start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.earliest_start_predicate(
+            &self.tasks[task.index].start_time,
+            task.processing_time,
+            start,
+        )
+    }
+
+    /// Returns the predicate stating that the task starts at or before `latest_start` in the
+    /// given [`Direction`].
+    fn latest_start_predicate(
+        &self,
+        task: &DirectedTask,
+        latest_start: i64,
+        direction: Direction,
+    ) -> Predicate {
+        direction.latest_start_predicate(
+            &self.tasks[task.index].start_time,
+            task.processing_time,
+            latest_start,
+        )
+    }
+
+    /// Returns the predicates which ensure that the provided tasks are present and start in
+    /// `[start, latest_start(task)]`.
+    fn explain_tasks(
+        &self,
+        tasks: &[DirectedTask],
+        members: &[usize],
+        start: i64,
+        latest_start: impl Fn(&DirectedTask) -> i64,
+        direction: Direction,
+    ) -> Vec<Predicate> {
+        members
+            .iter()
+            .flat_map(|&position| {
+                let task = &tasks[position];
+                [
+                    Some(self.earliest_start_predicate(task, start, direction)),
+                    Some(self.latest_start_predicate(task, latest_start(task), direction)),
+                    self.tasks[task.index].presence.map(Predicate::from),
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Applies overload checking; the present tasks which complete before a time point should fit
+    /// before it, and an optional task which does not fit together with them is made absent.
+    fn check_overload(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        // Overload checking is symmetric, so it only has to be applied in one direction
+        let direction = Direction::Forward;
+        let tasks = self.tasks_in_direction(context.as_readonly(), direction);
+
+        let mut tree = ThetaLambdaTree::new(&leaves(&tasks), 1);
+        for (position, task) in tasks.iter().enumerate() {
+            if task.is_optional {
+                tree.gray(position);
+            }
+        }
+
+        let mut by_latest_completion = (0..tasks.len()).collect::<Vec<_>>();
+        by_latest_completion.sort_by_key(|&position| Reverse(tasks[position].latest_completion));
+
+        for (index, &position) in by_latest_completion.iter().enumerate() {
+            let latest_completion = tasks[position].latest_completion;
+            let latest_start = |task: &DirectedTask| latest_completion - task.processing_time;
+            // Θ contains the present tasks which complete at or before the current task, and Λ
+            // contains the optional tasks which do so
+            let theta = by_latest_completion[index..]
+                .iter()
+                .copied()
+                .filter(|&other| !tasks[other].is_optional)
+                .collect::<Vec<_>>();
+
+            if tree.envelope() > latest_completion {
+                let (start, members) = find_window(&tasks, &theta, None, latest_completion + 1);
+                return Err(self
+                    .explain_tasks(&tasks, &members, start, latest_start, direction)
+                    .into());
+            }
+
+            while tree.envelope_lambda() > latest_completion {
+                let responsible = tree
+                    .responsible_envelope()
+                    .expect("Θ itself does not overload the resource");
+                let (start, members) =
+                    find_window(&tasks, &theta, Some(responsible), latest_completion + 1);
+
+                let optional = &tasks[responsible];
+                let mut reason =
+                    self.explain_tasks(&tasks, &members, start, latest_start, direction);
+                reason.extend([
+                    self.earliest_start_predicate(optional, start, direction),
+                    self.latest_start_predicate(optional, latest_start(optional), direction),
+                ]);
+
+                let presence = self.tasks[optional.index]
+                    .presence
+                    .expect("Only optional tasks are in Λ");
+                context.assign_literal(presence, false, PropositionalConjunction::from(reason))?;
+                tree.remove(responsible);
+            }
+
+            tree.remove(position);
+        }
+
+        Ok(())
+    }
+
+    /// Applies detectable precedences to the tasks in the given [`Direction`].
+    fn propagate_detectable_precedences(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let tasks = self.tasks_in_direction(context.as_readonly(), direction);
+
+        let mut tree = ThetaLambdaTree::new_empty(&leaves(&tasks), 1);
+        let mut is_in_theta = vec![false; tasks.len()];
+
+        let mut by_earliest_completion = (0..tasks.len()).collect::<Vec<_>>();
+        by_earliest_completion.sort_by_key(|&position| tasks[position].earliest_completion());
+        let mut by_latest_start = (0..tasks.len())
+            .filter(|&position| !tasks[position].is_optional)
+            .collect::<Vec<_>>();
+        by_latest_start.sort_by_key(|&position| tasks[position].latest_start());
+
+        // The bounds are only updated after all tasks have been considered, such that the order
+        // of the tasks remains valid
+        let mut updates = vec![];
+        let mut num_in_theta = 0;
+        for &position in by_earliest_completion.iter() {
+            let earliest_completion = tasks[position].earliest_completion();
+            // Θ contains the present tasks which have to start before the current task completes;
+            // these cannot be processed after it
+            while num_in_theta < by_latest_start.len()
+                && tasks[by_latest_start[num_in_theta]].latest_start() < earliest_completion
+            {
+                tree.insert(by_latest_start[num_in_theta]);
+                is_in_theta[by_latest_start[num_in_theta]] = true;
+                num_in_theta += 1;
+            }
+
+            if is_in_theta[position] {
+                tree.remove(position);
+            }
+            let bound = tree.envelope();
+            if is_in_theta[position] {
+                tree.insert(position);
+            }
+
+            if bound > tasks[position].earliest_start {
+                updates.push((position, bound));
+            }
+        }
+
+        for (position, bound) in updates {
+            let task = &tasks[position];
+            let predecessors = by_latest_start
+                .iter()
+                .copied()
+                .filter(|&other| {
+                    other != position && tasks[other].latest_start() < task.earliest_completion()
+                })
+                .collect::<Vec<_>>();
+            let (start, members) = find_window(&tasks, &predecessors, None, bound);
+
+            let mut reason = self.explain_tasks(
+                &tasks,
+                &members,
+                start,
+                |_| task.earliest_completion() - 1,
+                direction,
+            );
+            reason.push(self.earliest_start_predicate(task, task.earliest_start, direction));
+
+            let arg_task = &self.tasks[task.index];
+            if task.is_optional {
+                // The task cannot start before its latest start time if it is present
+                if bound > task.latest_start() {
+                    reason.push(self.latest_start_predicate(task, task.latest_start(), direction));
+                    let presence = arg_task.presence.expect("The task is optional");
+                    context.assign_literal(
+                        presence,
+                        false,
+                        PropositionalConjunction::from(reason),
+                    )?;
+                }
+                continue;
+            }
+
+            reason.extend(arg_task.presence.map(Predicate::from));
+            direction.set_earliest_start(
+                context,
+                &arg_task.start_time,
+                task.processing_time,
+                bound,
+                PropositionalConjunction::from(reason),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the leaves of a [`ThetaLambdaTree`] over the tasks; on a unary resource, the energy of
+/// a task is its processing time.
+fn leaves(tasks: &[DirectedTask]) -> Vec<(i64, i64)> {
+    tasks
+        .iter()
+        .map(|task| (task.earliest_start, task.processing_time))
+        .collect()
+}
+
+/// Returns the latest earliest start time `t` such that the tasks in `members` which start at or
+/// after `t` (together with `extra`, which is required to start at or after `t`) cannot complete
+/// before `bound`, together with these tasks (excluding `extra`).
+fn find_window(
+    tasks: &[DirectedTask],
+    members: &[usize],
+    extra: Option<usize>,
+    bound: i64,
+) -> (i64, Vec<usize>) {
+    let mut members = members.to_vec();
+    members.sort_by_key(|&position| Reverse(tasks[position].earliest_start));
+
+    let mut starts = members
+        .iter()
+        .chain(extra.iter())
+        .map(|&position| tasks[position].earliest_start)
+        .collect::<Vec<_>>();
+    starts.sort_unstable_by_key(|&start| Reverse(start));
+    starts.dedup();
+
+    let mut processing_time = 0;
+    let mut num_included = 0;
+    for start in starts {
+        while num_included < members.len() && tasks[members[num_included]].earliest_start >= start {
+            processing_time += tasks[members[num_included]].processing_time;
+            num_included += 1;
+        }
+
+        let extra_processing_time = match extra {
+            Some(extra) if tasks[extra].earliest_start >= start => tasks[extra].processing_time,
+            Some(_) => continue,
+            None => 0,
+        };
+
+        if start + processing_time + extra_processing_time >= bound {
+            members.truncate(num_included);
+            return (start, members);
+        }
+    }
+
+    unreachable!("the earliest completion time of the tasks is at least the bound")
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for DisjunctivePropagator<Var> {
+    fn name(&self) -> &str {
+        "Disjunctive"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        let total_processing_time = self
+            .tasks
+            .iter()
+            .map(|task| task.processing_time as i64)
+            .sum::<i64>();
+
+        self.tasks.iter().any(|task| {
+            let (lower_bound, upper_bound) = context.bounds_i64(&task.start_time);
+            context.bounds_overflow(&task.start_time)
+                || lower_bound - total_processing_time < i32::MIN as i64
+                || upper_bound + total_processing_time > i32::MAX as i64
+        })
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::ExpensiveGlobal
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let num_tasks = self.tasks.len() as u32;
+        for (index, task) in self.tasks.iter().enumerate() {
+            let _ = context.register(
+                task.start_time.clone(),
+                DomainEvents::create_with_int_events(enum_set!(
+                    IntDomainEvent::LowerBound
+                        | IntDomainEvent::UpperBound
+                        | IntDomainEvent::Assign
+                )),
+                LocalId::from(index as u32),
+            );
+            if let Some(presence) = task.presence {
+                let _ = context.register_literal(
+                    presence,
+                    DomainEvents::ANY_BOOL,
+                    LocalId::from(num_tasks + index as u32),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.check_overload(&mut context)?;
+        self.propagate_detectable_precedences(&mut context, Direction::Forward)?;
+        self.propagate_detectable_precedences(&mut context, Direction::Mirrored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    fn arg_tasks<Var: Clone>(
+        tasks: &[(Var, i32, Option<Literal>)],
+    ) -> Vec<ArgDisjunctiveTask<Var>> {
+        tasks
+            .iter()
+            .map(
+                |(start_time, processing_time, presence)| ArgDisjunctiveTask {
+                    start_time: start_time.clone(),
+                    processing_time: *processing_time,
+                    presence: *presence,
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn overload_is_detected() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(0, 2);
+
+        let result = solver.new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+            (a, 2, None),
+            (b, 2, None),
+            (c, 1, None),
+        ])));
+        let Err(Inconsistency::Other(ConflictInfo::Explanation(explanation))) = result else {
+            panic!("expected a conflict explained by the propagator");
+        };
+        assert_eq!(
+            explanation,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 0] & [c <= 3])
+        );
+    }
+
+    #[test]
+    fn optional_task_which_overloads_the_resource_is_absent() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 2);
+        let b = solver.new_variable(0, 2);
+        let c = solver.new_variable(0, 2);
+        let present = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+                (a, 2, None),
+                (b, 2, None),
+                (c, 1, Some(present)),
+            ])))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(present));
+        let reason = solver.get_reason_bool(present, false);
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 0] & [a <= 2] & [b >= 0] & [b <= 2] & [c >= 0] & [c <= 3])
+        );
+    }
+
+    #[test]
+    fn detectable_precedence_updates_earliest_start() {
+        // Task a has to start before b completes, so it cannot be processed after b
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+                (a, 3, None),
+                (b, 2, None),
+            ])))
+            .expect("no empty domains");
+
+        solver.assert_bounds(b, 3, 10);
+        let reason = solver.get_reason_int(predicate![b >= 3].try_into().unwrap());
+        assert_eq!(*reason, conjunction!([a >= 0] & [a <= 1] & [b >= 0]));
+    }
+
+    #[test]
+    fn detectable_precedence_updates_latest_start() {
+        // Task b has to start before a completes, so it has to complete before a starts
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(3, 4);
+        let b = solver.new_variable(0, 4);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+                (a, 3, None),
+                (b, 2, None),
+            ])))
+            .expect("no empty domains");
+
+        solver.assert_bounds(b, 0, 2);
+        let reason = solver.get_reason_int(predicate![b <= 2].try_into().unwrap());
+        assert_eq!(*reason, conjunction!([a <= 4] & [a >= 2] & [b <= 4]));
+    }
+
+    #[test]
+    fn optional_task_which_cannot_follow_its_predecessors_is_absent() {
+        // Task a precedes c if c is present, after which c cannot start before its latest start
+        // time; note that c fits together with a in every window
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(2, 2);
+        let c = solver.new_variable(0, 3);
+        let present = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+                (a, 2, None),
+                (c, 3, Some(present)),
+            ])))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(present));
+        solver.assert_bounds(c, 0, 3);
+        let reason = solver.get_reason_bool(present, false);
+        assert_eq!(
+            *reason,
+            conjunction!([a >= 2] & [a <= 2] & [c >= 0] & [c <= 3])
+        );
+    }
+
+    #[test]
+    fn present_optional_task_is_part_of_the_reason() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(0, 10);
+        let present = solver.new_literal();
+
+        let mut propagator = solver
+            .new_propagator(DisjunctivePropagator::new(&arg_tasks(&[
+                (a, 3, Some(present)),
+                (b, 2, None),
+            ])))
+            .expect("no empty domains");
+        solver.assert_bounds(b, 0, 10);
+
+        solver.set_literal(present, true);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        solver.assert_bounds(b, 3, 10);
+        let reason = solver.get_reason_int(predicate![b >= 3].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            PropositionalConjunction::from(vec![
+                predicate![a >= 0],
+                predicate![a <= 1],
+                predicate![b >= 0],
+                Predicate::from(present),
+            ])
+        );
+    }
+}
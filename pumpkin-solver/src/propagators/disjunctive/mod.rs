@@ -0,0 +1,24 @@
+//! Contains the propagator for the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+//! constraint, which states that no two tasks (on the same unary resource) overlap in time.
+//!
+//! Each task consists of a variable `s_i` representing its start time, a fixed processing time
+//! `p_i` and, optionally, a literal `x_i` indicating whether the task is present; a task which is
+//! absent does not use the resource. Optional tasks allow, for example, flexible job-shop problems
+//! to be modelled, in which every operation is processed by one of several machines.
+//!
+//! The propagator (see [`DisjunctivePropagator`]) uses overload checking and detectable
+//! precedences \[1\]; both rules take the optional tasks into account \[2\], meaning that an
+//! optional task is made absent when it cannot be processed together with the present tasks.
+//!
+//! # Bibliography
+//! \[1\] P. Vilím, ‘O(n log n) filtering algorithms for unary resource constraint’, in
+//! International Conference on Integration of Artificial Intelligence (AI) and Operations Research
+//! (OR) Techniques in Constraint Programming, 2004, pp. 335–347.
+//!
+//! \[2\] P. Vilím, R. Barták, and O. Čepek, ‘Extension of O(n log n) filtering algorithms for the
+//! unary resource constraint to optional activities’, Constraints, vol. 10, no. 4, pp. 403–425,
+//! 2005.
+
+mod disjunctive_propagator;
+
+pub(crate) use disjunctive_propagator::*;
@@ -8,6 +8,7 @@ use crate::conjunction;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -88,8 +89,8 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         // Ensure index is non-negative
         context.set_lower_bound(&self.index, 0, conjunction!())?;
-        // Ensure index <= no. of x_j
-        context.set_upper_bound(&self.index, self.array.len() as i32, conjunction!())?;
+        // Ensure index < no. of x_j
+        context.set_upper_bound(&self.index, self.array.len() as i32 - 1, conjunction!())?;
 
         // For incremental solving: use the doubly linked list data-structure
         if context.is_fixed(&self.index) {
@@ -180,32 +181,48 @@ impl<VX: IntegerVariable + 'static, VI: IntegerVariable, VE: IntegerVariable> Pr
         Ok(())
     }
 
-    fn priority(&self) -> u32 {
-        // Priority higher than int_times/linear_eq/not_eq_propagator because it's much more
+    fn priority(&self) -> Priority {
+        // Lower priority than int_times/linear_eq/not_eq_propagator because it's much more
         //  expensive looping over multiple domains
-        2
+        Priority::CheapGlobal
     }
 
     fn name(&self) -> &str {
         "Element"
     }
 
+    fn describe(&self) -> Option<String> {
+        Some(format!(
+            "{:?}[{:?}] == {:?}",
+            self.array, self.index, self.rhs
+        ))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        context.bounds_overflow(&self.index)
+            || context.bounds_overflow(&self.rhs)
+            || self
+                .array
+                .iter()
+                .any(|variable| context.bounds_overflow(variable))
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
     ) -> PropagationStatusCP {
         // Ensure index is non-negative
         context.set_lower_bound(&self.index, 0, conjunction!())?;
-        // Ensure index <= no. of x_j
-        context.set_upper_bound(&self.index, self.array.len() as i32, conjunction!())?;
+        // Ensure index < no. of x_j
+        context.set_upper_bound(&self.index, self.array.len() as i32 - 1, conjunction!())?;
 
         // Close to duplicate of `propagate` for now, without saving reason stuff...
         if context.is_fixed(&self.index) {
             let i = context.lower_bound(&self.index);
             let x_i = &self.array[i as usize];
 
-            let lb = min(context.lower_bound(&self.rhs), context.lower_bound(x_i));
-            let ub = max(context.upper_bound(&self.rhs), context.upper_bound(x_i));
+            let lb = max(context.lower_bound(&self.rhs), context.lower_bound(x_i));
+            let ub = min(context.upper_bound(&self.rhs), context.upper_bound(x_i));
 
             context.set_lower_bound(&self.rhs, lb, conjunction!())?;
             context.set_lower_bound(x_i, lb, conjunction!())?;
@@ -342,4 +359,58 @@ mod tests {
         // reason for `rhs >= 7` is that `x_1 >= 7`
         assert_eq!(*rhs_reason, conjunction!([index == 1] & [x_1 >= 7]));
     }
+
+    #[test]
+    fn index_is_restricted_to_the_array() {
+        let mut solver = TestSolver::default();
+        let x_0 = solver.new_variable(1, 3);
+        let x_1 = solver.new_variable(2, 4);
+        let index = solver.new_variable(-2, 5);
+        let rhs = solver.new_variable(0, 10);
+        let array = vec![x_0, x_1].into_boxed_slice();
+
+        let mut propagator = solver
+            .new_propagator(ElementPropagator::new(array, index, rhs))
+            .expect("no empty domains");
+
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert_eq!(0, solver.lower_bound(index));
+        assert_eq!(1, solver.upper_bound(index));
+        assert_eq!(1, solver.lower_bound(rhs));
+        assert_eq!(4, solver.upper_bound(rhs));
+    }
+
+    #[test]
+    fn changes_to_array_variables_are_propagated() {
+        let mut solver = TestSolver::default();
+        let x_0 = solver.new_variable(1, 3);
+        let x_1 = solver.new_variable(2, 4);
+        let x_2 = solver.new_variable(3, 5);
+        let index = solver.new_variable(0, 2);
+        let rhs = solver.new_variable(1, 3);
+        let array = vec![x_0, x_1, x_2].into_boxed_slice();
+
+        let mut propagator = solver
+            .new_propagator(ElementPropagator::new(array, index, rhs))
+            .expect("no empty domains");
+
+        solver.propagate(&mut propagator).expect("no empty domains");
+        assert!(solver.contains(index, 1));
+
+        let _ = solver.increase_lower_bound_and_notify(
+            &mut propagator,
+            (ID_X_OFFSET + 1) as i32,
+            x_1,
+            4,
+        );
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert!(!solver.contains(index, 1));
+        let reason = solver.get_reason_int(predicate![index != 1].try_into().unwrap());
+        assert_eq!(
+            *reason,
+            conjunction!([rhs >= 1] & [rhs <= 3] & [x_1 != 1] & [x_1 != 2] & [x_1 != 3])
+        );
+    }
 }
@@ -0,0 +1,156 @@
+//! Regression tests which compare the propagations (and their reasons) performed on a set of small
+//! models against previously recorded traces, which are stored in `tests/golden_traces`. These
+//! serve as an early warning when a change subtly alters the strength or the order of
+//! propagation.
+//!
+//! If a change to a trace is intended, then the traces can be updated by running the tests with
+//! the environment variable `UPDATE_GOLDEN_TRACES` set.
+use std::fs;
+use std::path::PathBuf;
+
+use crate::engine::test_helper::TestSolver;
+use crate::engine::variables::TransformableVariable;
+use crate::propagators::absolute_value::AbsoluteValuePropagator;
+use crate::propagators::element::ElementPropagator;
+use crate::propagators::integer_multiplication::IntegerMultiplicationPropagator;
+use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::propagators::maximum::MaximumPropagator;
+
+fn assert_matches_golden_trace(name: &str, trace: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden_traces")
+        .join(format!("{name}.trace"));
+
+    if std::env::var_os("UPDATE_GOLDEN_TRACES").is_some() {
+        fs::write(&path, trace).expect("could not write the golden trace");
+        return;
+    }
+
+    let golden_trace = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Could not read the golden trace at {}; run the test with UPDATE_GOLDEN_TRACES set to create it",
+            path.display()
+        )
+    });
+
+    assert_eq!(
+        trace, golden_trace,
+        "The propagation trace of '{name}' differs from the golden trace; if this is intended, run the test with UPDATE_GOLDEN_TRACES set to update it"
+    );
+}
+
+#[test]
+fn linear_less_or_equal() {
+    let mut solver = TestSolver::default();
+    let x = solver.new_variable(0, 10);
+    let y = solver.new_variable(0, 10);
+    let z = solver.new_variable(0, 3);
+
+    let mut propagator = solver
+        .new_propagator(LinearLessOrEqualPropagator::new(
+            [x.scaled(1), y.scaled(2), z.scaled(-1)].into(),
+            6,
+        ))
+        .expect("no empty domains");
+
+    let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, y, 2);
+    solver.propagate(&mut propagator).expect("no empty domains");
+    let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 4);
+    solver.propagate(&mut propagator).expect("no empty domains");
+
+    assert_matches_golden_trace("linear_less_or_equal", &solver.propagation_trace());
+}
+
+#[test]
+fn maximum() {
+    let mut solver = TestSolver::default();
+    let a = solver.new_variable(1, 5);
+    let b = solver.new_variable(2, 8);
+    let c = solver.new_variable(0, 3);
+    let rhs = solver.new_variable(0, 6);
+
+    let mut propagator = solver
+        .new_propagator(MaximumPropagator::new([a, b, c].into(), rhs))
+        .expect("no empty domains");
+
+    let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 3, rhs, 4);
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    // A value which none of the inputs can take is removed from the maximum
+    for variable in [a, b, c] {
+        solver.remove(variable, 3).expect("no empty domains");
+    }
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    assert_matches_golden_trace("maximum", &solver.propagation_trace());
+}
+
+#[test]
+fn integer_multiplication() {
+    let mut solver = TestSolver::default();
+    let a = solver.new_variable(1, 6);
+    let b = solver.new_variable(2, 4);
+    let c = solver.new_variable(0, 12);
+
+    let mut propagator = solver
+        .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+        .expect("no empty domains");
+
+    let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, b, 3);
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    assert_matches_golden_trace("integer_multiplication", &solver.propagation_trace());
+}
+
+#[test]
+fn absolute_value() {
+    let mut solver = TestSolver::default();
+    let signed = solver.new_variable(-7, 4);
+    let absolute = solver.new_variable(-2, 10);
+
+    let mut propagator = solver
+        .new_propagator(AbsoluteValuePropagator::new(signed, absolute))
+        .expect("no empty domains");
+
+    let _ = solver.decrease_upper_bound_and_notify(&mut propagator, 1, absolute, 3);
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    // Removing both signed values with absolute value 2 leaves a hole in the absolute value
+    for value in [-2, 2] {
+        solver.remove(signed, value).expect("no empty domains");
+    }
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    // Removing an absolute value leaves holes around 0 in the signed value
+    solver.remove(absolute, 1).expect("no empty domains");
+    solver
+        .propagate_until_fixed_point(&mut propagator)
+        .expect("no empty domains");
+
+    assert_matches_golden_trace("absolute_value", &solver.propagation_trace());
+}
+
+#[test]
+fn element() {
+    let mut solver = TestSolver::default();
+    let array = [(3, 5), (7, 9), (0, 2)].map(|(lb, ub)| solver.new_variable(lb, ub));
+    let index = solver.new_variable(0, 2);
+    let rhs = solver.new_variable(4, 8);
+
+    let _ = solver
+        .new_propagator(ElementPropagator::new(array.into(), index, rhs))
+        .expect("no empty domains");
+
+    assert_matches_golden_trace("element", &solver.propagation_trace());
+}
@@ -0,0 +1,314 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::predicates::Predicate;
+
+/// Propagator for the [lex_less](https://sofdem.github.io/gccat/gccat/Clex_less.html) and
+/// [lex_lesseq](https://sofdem.github.io/gccat/gccat/Clex_lesseq.html) constraints, which state
+/// that the vector `x` is lexicographically smaller than (or equal to) the vector `y`.
+///
+/// The vectors do not need to be of the same length; if `x` is a proper prefix of `y`, then it is
+/// considered to be smaller than `y`.
+///
+/// The propagator is based on the filtering algorithm by Frisch et al. \[1\], which only ever
+/// restricts the first position `alpha` at which `x` and `y` are not yet fixed to the same value.
+/// If `beta` is the first position from which `x` is certainly greater than `y` on the remaining
+/// positions, then `x[alpha] < y[alpha]` is enforced if `beta = alpha + 1`, and `x[alpha] <=
+/// y[alpha]` is enforced otherwise.
+///
+/// Both `alpha` and `beta` are explained in a lifted manner; for the positions before `alpha` it
+/// only matters that `x[i] >= y[i]`, and for the positions starting from `beta` it only matters
+/// that `x[i] >= y[i]` (and `x[i] > y[i]` for the last of them).
+///
+/// # Bibliography
+/// \[1\] A. M. Frisch, B. Hnich, Z. Kiziltan, I. Miguel, and T. Walsh, ‘Global constraints for
+/// lexicographic orderings’, in International Conference on Principles and Practice of
+/// Constraint Programming, 2002, pp. 93–108.
+#[derive(Clone, Debug)]
+pub(crate) struct LexLessPropagator<Var> {
+    x: Box<[Var]>,
+    y: Box<[Var]>,
+    /// Whether the constraint is violated if all positions which both vectors have in common are
+    /// equal; this is the case if `x` is longer than `y`, or if they are of the same length and
+    /// the ordering is strict
+    equal_prefix_is_violating: bool,
+}
+
+/// The positions from which the vector `x` is certainly greater than the vector `y`.
+struct GreaterSuffix {
+    /// The first position of the suffix
+    start: usize,
+    /// The position after the last position which is part of the explanation
+    end: usize,
+}
+
+impl<Var: IntegerVariable> LexLessPropagator<Var> {
+    pub(crate) fn new(x: Box<[Var]>, y: Box<[Var]>, strict: bool) -> Self {
+        let equal_prefix_is_violating = x.len() > y.len() || (strict && x.len() == y.len());
+
+        LexLessPropagator {
+            x,
+            y,
+            equal_prefix_is_violating,
+        }
+    }
+
+    fn num_common_positions(&self) -> usize {
+        self.x.len().min(self.y.len())
+    }
+
+    /// Adds the bounds of `x[index]` and `y[index]` to the reason; these explain both `x[index] >=
+    /// y[index]` and `x[index] > y[index]`, depending on the bounds.
+    fn greater_or_equal_reason(
+        &self,
+        context: PropagationContext,
+        index: usize,
+        reason: &mut Vec<Predicate>,
+    ) {
+        let x_i = &self.x[index];
+        let y_i = &self.y[index];
+        reason.push(predicate![x_i >= context.lower_bound(x_i)]);
+        reason.push(predicate![y_i <= context.upper_bound(y_i)]);
+    }
+
+    /// Returns the first position at which `x` and `y` are not fixed to the same value.
+    fn find_alpha(&self, context: PropagationContext) -> usize {
+        (0..self.num_common_positions())
+            .find(|&index| {
+                !context.is_fixed(&self.x[index])
+                    || !context.is_fixed(&self.y[index])
+                    || context.lower_bound(&self.x[index]) != context.lower_bound(&self.y[index])
+            })
+            .unwrap_or(self.num_common_positions())
+    }
+
+    /// Returns the first position (not before `alpha`) from which `x` is certainly greater than
+    /// `y`, if there is such a position.
+    fn find_beta(&self, context: PropagationContext, alpha: usize) -> Option<GreaterSuffix> {
+        let num_common_positions = self.num_common_positions();
+
+        let mut suffix = self.equal_prefix_is_violating.then_some(GreaterSuffix {
+            start: num_common_positions,
+            end: num_common_positions,
+        });
+
+        for index in (alpha..num_common_positions).rev() {
+            let x_lower_bound = context.lower_bound(&self.x[index]);
+            let y_upper_bound = context.upper_bound(&self.y[index]);
+
+            if x_lower_bound > y_upper_bound {
+                suffix = Some(GreaterSuffix {
+                    start: index,
+                    end: index + 1,
+                });
+            } else if x_lower_bound >= y_upper_bound {
+                if let Some(suffix) = suffix.as_mut().filter(|suffix| suffix.start == index + 1) {
+                    suffix.start = index;
+                }
+            }
+        }
+
+        suffix
+    }
+}
+
+impl<Var: IntegerVariable> Propagator for LexLessPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        // Only the positions which both vectors have in common play a role
+        for index in 0..self.num_common_positions() {
+            let _ = context.register(
+                self.x[index].clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(2 * index as u32),
+            );
+            let _ = context.register(
+                self.y[index].clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(2 * index as u32 + 1),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "LexLess"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.x
+            .iter()
+            .chain(self.y.iter())
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        loop {
+            let alpha = self.find_alpha(context.as_readonly());
+            let beta = self.find_beta(context.as_readonly(), alpha);
+
+            let mut reason = vec![];
+            for index in 0..alpha {
+                self.greater_or_equal_reason(context.as_readonly(), index, &mut reason);
+            }
+            // The suffix only plays a role if it starts at `alpha` or the position after it
+            if let Some(beta) = beta.as_ref().filter(|beta| beta.start <= alpha + 1) {
+                for index in beta.start..beta.end {
+                    self.greater_or_equal_reason(context.as_readonly(), index, &mut reason);
+                }
+            }
+
+            if beta.as_ref().is_some_and(|beta| beta.start == alpha) {
+                return Err(PropositionalConjunction::from(reason).into());
+            }
+            if alpha == self.num_common_positions() {
+                // All positions are equal, and this does not violate the constraint
+                return Ok(());
+            }
+
+            // If `x` is greater than `y` starting from the next position, then `x[alpha]` should
+            // be strictly smaller than `y[alpha]`
+            let difference = if beta.is_some_and(|beta| beta.start == alpha + 1) {
+                1
+            } else {
+                0
+            };
+            let x_alpha = &self.x[alpha];
+            let y_alpha = &self.y[alpha];
+
+            let y_upper_bound = context.upper_bound(y_alpha);
+            let mut upper_bound_reason = reason.clone();
+            upper_bound_reason.push(predicate![y_alpha <= y_upper_bound]);
+            context.set_upper_bound(
+                x_alpha,
+                y_upper_bound - difference,
+                PropositionalConjunction::from(upper_bound_reason),
+            )?;
+
+            let x_lower_bound = context.lower_bound(x_alpha);
+            reason.push(predicate![x_alpha >= x_lower_bound]);
+            context.set_lower_bound(
+                y_alpha,
+                x_lower_bound + difference,
+                PropositionalConjunction::from(reason),
+            )?;
+
+            // If this fixed both variables at `alpha` to the same value, then the next position
+            // should be considered
+            if self.find_alpha(context.as_readonly()) == alpha {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn first_position_is_restricted() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(2, 5), solver.new_variable(0, 9)];
+        let y = [solver.new_variable(0, 4), solver.new_variable(0, 9)];
+
+        let _ = solver
+            .new_propagator(LexLessPropagator::new(x.into(), y.into(), false))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[0], 2, 4);
+        solver.assert_bounds(y[0], 2, 4);
+        let reason = solver.get_reason_int(predicate![x[0] <= 4].try_into().unwrap());
+        assert_eq!(conjunction!([y[0] <= 4]), *reason);
+    }
+
+    #[test]
+    fn strict_ordering_is_enforced_when_suffix_is_greater() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(0, 5), solver.new_variable(4, 5)];
+        let y = [solver.new_variable(0, 5), solver.new_variable(1, 3)];
+
+        let _ = solver
+            .new_propagator(LexLessPropagator::new(x.into(), y.into(), false))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[0], 0, 4);
+        solver.assert_bounds(y[0], 1, 5);
+        let reason = solver.get_reason_int(predicate![y[0] >= 1].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x[1] >= 4] & [y[1] <= 3] & [x[0] >= 0]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn fixed_prefix_moves_to_next_position() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(1, 1), solver.new_variable(0, 5)];
+        let y = [solver.new_variable(1, 1), solver.new_variable(0, 3)];
+
+        let _ = solver
+            .new_propagator(LexLessPropagator::new(x.into(), y.into(), true))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[1], 0, 2);
+        solver.assert_bounds(y[1], 1, 3);
+        let reason = solver.get_reason_int(predicate![x[1] <= 2].try_into().unwrap());
+        assert_eq!(
+            conjunction!([x[0] >= 1] & [y[0] <= 1] & [y[1] <= 3]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn equal_vectors_violate_strict_ordering() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(1, 1), solver.new_variable(2, 2)];
+        let y = [solver.new_variable(1, 1), solver.new_variable(2, 2)];
+
+        let result = solver.new_propagator(LexLessPropagator::new(x.into(), y.into(), true));
+        assert!(result.is_err());
+
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(1, 1), solver.new_variable(2, 2)];
+        let y = [solver.new_variable(1, 1), solver.new_variable(2, 2)];
+
+        let result = solver.new_propagator(LexLessPropagator::new(x.into(), y.into(), false));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn shorter_vector_is_smaller() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(1, 1)];
+        let y = [solver.new_variable(0, 1), solver.new_variable(0, 1)];
+
+        let _ = solver
+            .new_propagator(LexLessPropagator::new(x.into(), y.into(), true))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y[0], 1, 1);
+        solver.assert_bounds(y[1], 0, 1);
+    }
+}
@@ -0,0 +1,364 @@
+use std::rc::Rc;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `variable \in values` (or `variable \notin values` if `is_member`
+/// is false), where `values` is a constant set.
+///
+/// The propagator is domain consistent; it removes the values which are (not) in the set from the
+/// domain of the variable. As these removals hold regardless of the rest of the domain, their
+/// reasons are empty. The work which is done is linear in the number of values of the set within
+/// the bounds of the variable, rather than in the width of its domain; the values between the
+/// values of the set are only visited when some of them are still in the domain.
+///
+/// If the propagator is reified, [`Propagator::detect_inconsistency`] reports whether the domain
+/// only contains values which are not (or only values which are) in the set, such that the
+/// reification literal can be falsified.
+#[derive(Clone, Debug)]
+pub(crate) struct MemberPropagator<Var> {
+    variable: Var,
+    /// The values of the set, sorted in increasing order and without duplicates.
+    values: Rc<[i32]>,
+    is_member: bool,
+}
+
+impl<Var: IntegerVariable> MemberPropagator<Var> {
+    pub(crate) fn new(variable: Var, values: Rc<[i32]>, is_member: bool) -> Self {
+        assert!(
+            values.windows(2).all(|window| window[0] < window[1]),
+            "the values should be sorted and unique"
+        );
+        MemberPropagator {
+            variable,
+            values,
+            is_member,
+        }
+    }
+
+    fn is_in_set(&self, value: i32) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+
+    /// Returns the values of the set which are within the bounds of the variable.
+    fn values_within_bounds(&self, context: PropagationContext) -> &[i32] {
+        let lower_bound = context.lower_bound(&self.variable);
+        let upper_bound = context.upper_bound(&self.variable);
+
+        let first_inside = self.values.partition_point(|&value| value < lower_bound);
+        let first_above = self.values.partition_point(|&value| value <= upper_bound);
+
+        &self.values[first_inside..first_above]
+    }
+
+    /// Returns the number of values of the set which are in the domain of the variable; this is
+    /// linear in the number of values of the set within the bounds, rather than in the width of
+    /// the domain.
+    fn num_values_in_set(&self, context: PropagationContext) -> usize {
+        self.values_within_bounds(context)
+            .iter()
+            .filter(|&&value| context.contains(&self.variable, value))
+            .count()
+    }
+
+    /// Returns the values within the bounds of the variable which are not in the set; these are
+    /// the values strictly between consecutive values of the set (and the bounds).
+    fn values_outside_of_set_within_bounds(
+        &self,
+        context: PropagationContext,
+    ) -> impl Iterator<Item = i32> + '_ {
+        let lower_bound = context.lower_bound(&self.variable);
+        let upper_bound = context.upper_bound(&self.variable);
+        let values_within_bounds = self.values_within_bounds(context);
+
+        // The gaps are the ranges between the (exclusive) ends formed by the values of the set
+        let starts = std::iter::once(lower_bound as i64)
+            .chain(values_within_bounds.iter().map(|&value| value as i64 + 1));
+        let ends = values_within_bounds
+            .iter()
+            .map(|&value| value as i64 - 1)
+            .chain(std::iter::once(upper_bound as i64));
+        starts
+            .zip(ends)
+            .flat_map(|(start, end)| (start..=end).map(|value| value as i32))
+    }
+
+    /// Returns the reason why the variable cannot take any of the values in the set; this consists
+    /// of the removed values of the set within the bounds, and the bounds lifted to the closest
+    /// values of the set outside of them.
+    fn explain_no_value_in_set(&self, context: PropagationContext) -> PropositionalConjunction {
+        let lower_bound = context.lower_bound(&self.variable);
+        let upper_bound = context.upper_bound(&self.variable);
+
+        let first_inside = self.values.partition_point(|&value| value < lower_bound);
+        let first_above = self.values.partition_point(|&value| value <= upper_bound);
+
+        let mut reason: Vec<_> = self.values[first_inside..first_above]
+            .iter()
+            .map(|&value| predicate![self.variable != value])
+            .collect();
+        if first_inside > 0 {
+            reason.push(predicate![
+                self.variable >= self.values[first_inside - 1] + 1
+            ]);
+        }
+        if first_above < self.values.len() {
+            reason.push(predicate![self.variable <= self.values[first_above] - 1]);
+        }
+
+        reason.into()
+    }
+
+    /// Returns the reason why the variable can only take values in the set; this consists of the
+    /// removed values outside of the set within the bounds, and the bounds lifted to the ends of
+    /// the runs of consecutive values of the set which contain them.
+    fn explain_only_values_in_set(&self, context: PropagationContext) -> PropositionalConjunction {
+        let mut lower_bound = context.lower_bound(&self.variable);
+        let mut upper_bound = context.upper_bound(&self.variable);
+
+        // The domain only contains values of the set, so every value outside of the set within
+        // the bounds is a hole of the domain
+        let mut reason: Vec<_> = self
+            .values_outside_of_set_within_bounds(context)
+            .map(|value| predicate![self.variable != value])
+            .collect();
+
+        while lower_bound > i32::MIN && self.is_in_set(lower_bound - 1) {
+            lower_bound -= 1;
+        }
+        while upper_bound < i32::MAX && self.is_in_set(upper_bound + 1) {
+            upper_bound += 1;
+        }
+        reason.push(predicate![self.variable >= lower_bound]);
+        reason.push(predicate![self.variable <= upper_bound]);
+
+        reason.into()
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for MemberPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(
+            self.variable.clone(),
+            DomainEvents::ANY_INT,
+            LocalId::from(0),
+        );
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Unit
+    }
+
+    fn name(&self) -> &str {
+        if self.is_member {
+            "Member"
+        } else {
+            "NotMember"
+        }
+    }
+
+    fn describe(&self) -> Option<String> {
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let operator = if self.is_member { "in" } else { "not in" };
+
+        Some(format!("{:?} {operator} {{{values}}}", self.variable))
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let num_values_in_set = self.num_values_in_set(context);
+        let has_value_in_set = |is_in_set: bool| {
+            if is_in_set {
+                num_values_in_set > 0
+            } else {
+                num_values_in_set < context.size(&self.variable)
+            }
+        };
+
+        if self.is_member && !has_value_in_set(true) {
+            Some(self.explain_no_value_in_set(context))
+        } else if !self.is_member && !has_value_in_set(false) {
+            Some(self.explain_only_values_in_set(context))
+        } else {
+            None
+        }
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The gaps between the values of the set are computed using `i64`s
+        context.bounds_overflow(&self.variable)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        if !self.is_member {
+            let values_within_bounds = self.values_within_bounds(context.as_readonly());
+            for &value in values_within_bounds {
+                context.remove(&self.variable, value, conjunction!())?;
+            }
+
+            return Ok(());
+        }
+
+        let values_within_bounds = self.values_within_bounds(context.as_readonly());
+        let Some(&lower_bound) = values_within_bounds
+            .iter()
+            .find(|&&value| context.contains(&self.variable, value))
+        else {
+            return Err(self.explain_no_value_in_set(context.as_readonly()).into());
+        };
+        let upper_bound = *values_within_bounds
+            .iter()
+            .rev()
+            .find(|&&value| context.contains(&self.variable, value))
+            .expect("there is a value of the set in the domain");
+        context.set_lower_bound(&self.variable, lower_bound, conjunction!())?;
+        context.set_upper_bound(&self.variable, upper_bound, conjunction!())?;
+
+        // The gaps between the values of the set are only visited if they contain values of the
+        // domain, which is not the case once the propagator has been at its fixpoint
+        if self.num_values_in_set(context.as_readonly()) < context.size(&self.variable) {
+            let values_outside_of_set =
+                self.values_outside_of_set_within_bounds(context.as_readonly());
+            for value in values_outside_of_set {
+                context.remove(&self.variable, value, conjunction!())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+    use crate::propagators::ReifiedPropagator;
+
+    #[test]
+    fn values_outside_of_the_set_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(MemberPropagator::new(x, [2, 4, 7].into(), true))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 2, 7);
+        for value in 2..=7 {
+            assert_eq!(solver.contains(x, value), [2, 4, 7].contains(&value));
+        }
+    }
+
+    #[test]
+    fn values_in_the_set_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+
+        let _ = solver
+            .new_propagator(MemberPropagator::new(x, [0, 3, 8].into(), false))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 1, 5);
+        assert!(!solver.contains(x, 3));
+    }
+
+    #[test]
+    fn propagation_does_not_depend_on_the_width_of_the_domain() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1_000_000);
+        let y = solver.new_variable(0, 1_000_000);
+
+        let _ = solver
+            .new_propagator(MemberPropagator::new(
+                x,
+                [-1, 999_999, 1_000_000].into(),
+                true,
+            ))
+            .expect("no empty domains");
+        let _ = solver
+            .new_propagator(MemberPropagator::new(y, [0, 7].into(), false))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 999_999, 1_000_000);
+        solver.assert_bounds(y, 1, 1_000_000);
+        assert!(!solver.contains(y, 7));
+    }
+
+    #[test]
+    fn reified_member_is_falsified_with_lifted_bounds() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 6);
+        solver.remove(x, 5).expect("non-empty domain");
+        let reification_literal = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                MemberPropagator::new(x, [1, 5, 9].into(), true),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, false);
+        assert_eq!(
+            &PropositionalConjunction::new(vec![
+                predicate![x != 5],
+                predicate![x >= 2],
+                predicate![x <= 8]
+            ]),
+            reason
+        );
+    }
+
+    #[test]
+    fn reified_not_member_is_falsified_by_runs_of_the_set() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(2, 4);
+        solver.remove(x, 3).expect("non-empty domain");
+        let reification_literal = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                MemberPropagator::new(x, [1, 2, 4].into(), false),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, false);
+        assert_eq!(
+            &PropositionalConjunction::new(vec![
+                predicate![x != 3],
+                predicate![x >= 1],
+                predicate![x <= 4]
+            ]),
+            reason
+        );
+    }
+}
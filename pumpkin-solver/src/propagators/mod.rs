@@ -2,14 +2,36 @@
 //!
 //! See the [`crate::engine::cp::propagation`] for info on propagators.
 
+mod all_different;
+mod among;
 pub(crate) mod arithmetic;
 pub(crate) mod clausal;
 mod cumulative;
+pub(crate) mod diffn;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+#[cfg(test)]
+mod golden_traces;
+mod lex_less;
+mod member;
+mod precedence;
 mod reified_propagator;
+mod sorted;
+mod value_precede;
+pub use all_different::AllDifferentPropagationMethod;
+pub(crate) use all_different::*;
+pub(crate) use among::*;
 pub(crate) use arithmetic::*;
 pub use cumulative::CumulativeExplanationType;
 pub use cumulative::CumulativeOptions;
 pub use cumulative::CumulativePropagationMethod;
 pub(crate) use cumulative::*;
+pub use diffn::DiffnOptions;
+pub(crate) use diffn::*;
+pub(crate) use disjunctive::*;
+pub(crate) use lex_less::*;
+pub(crate) use member::*;
+pub(crate) use precedence::*;
 pub(crate) use reified_propagator::*;
+pub(crate) use sorted::*;
+pub(crate) use value_precede::*;
@@ -0,0 +1,388 @@
+//! Contains the propagator for a collection of precedences (i.e. difference constraints) of the
+//! form `x_i + c <= x_j`.
+//!
+//! The precedences form a graph over the variables, in which every precedence `x_i + c <= x_j` is
+//! an arc from `x_i` to `x_j` with length `c`. If the longest path from `x_i` to `x_j` has length
+//! `d`, then `x_i + d <= x_j` is implied by the precedences; the lower bound of `x_i` thus
+//! propagates to the lower bound of `x_j` (and the upper bound of `x_j` to the upper bound of
+//! `x_i`), with an explanation which only contains the bound at the start of the path. A cycle of
+//! positive length implies that the precedences cannot be satisfied.
+//!
+//! The longest paths from (and to) a variable are computed the first time that its bound changes,
+//! and are then reused for the remainder of the search; this is what makes a single propagator
+//! for many precedences faster than posting a linear propagator for every precedence.
+use std::collections::VecDeque;
+
+use enumset::enum_set;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::IntDomainEvent;
+
+/// The precedence `variables[from] + gap <= variables[to]`, which is passed as argument to the
+/// [`PrecedencePropagator`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ArgPrecedence {
+    pub(crate) from: usize,
+    pub(crate) gap: i32,
+    pub(crate) to: usize,
+}
+
+/// The longest paths from (or to) a single variable, as pairs of the index of the other variable
+/// and the length of the path; the variable itself is not included.
+type LongestPaths = Box<[(usize, i64)]>;
+
+/// Propagator for a collection of precedences of the form `x_i + c <= x_j`; see the module
+/// documentation for more information.
+#[derive(Clone, Debug)]
+pub(crate) struct PrecedencePropagator<Var> {
+    variables: Box<[Var]>,
+    /// For every variable, the precedences which start at it as pairs of the end and the gap
+    successors: Box<[Vec<(usize, i32)>]>,
+    /// For every variable, the precedences which end at it as pairs of the start and the gap
+    predecessors: Box<[Vec<(usize, i32)>]>,
+    /// The longest paths from every variable, computed on demand
+    longest_paths_from: Box<[Option<LongestPaths>]>,
+    /// The longest paths to every variable, computed on demand
+    longest_paths_to: Box<[Option<LongestPaths>]>,
+    /// The variables of which the lower bound has changed since the last propagation
+    changed_lower_bounds: Vec<usize>,
+    /// The variables of which the upper bound has changed since the last propagation
+    changed_upper_bounds: Vec<usize>,
+}
+
+impl<Var: IntegerVariable> PrecedencePropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, precedences: &[ArgPrecedence]) -> Self {
+        let mut successors = vec![vec![]; variables.len()];
+        let mut predecessors = vec![vec![]; variables.len()];
+        for precedence in precedences {
+            successors[precedence.from].push((precedence.to, precedence.gap));
+            predecessors[precedence.to].push((precedence.from, precedence.gap));
+        }
+
+        PrecedencePropagator {
+            longest_paths_from: vec![None; variables.len()].into(),
+            longest_paths_to: vec![None; variables.len()].into(),
+            changed_lower_bounds: (0..variables.len()).collect(),
+            changed_upper_bounds: (0..variables.len()).collect(),
+            variables,
+            successors: successors.into(),
+            predecessors: predecessors.into(),
+        }
+    }
+
+    /// Sets the lower bounds of the variables which can be reached from `source` based on the
+    /// lower bound of `source`.
+    fn propagate_lower_bounds_from(
+        &self,
+        context: &mut PropagationContextMut,
+        source: usize,
+        longest_paths: &[(usize, i64)],
+    ) -> PropagationStatusCP {
+        let source_variable = &self.variables[source];
+        let lower_bound = context.lower_bound(source_variable);
+        for &(target, length) in longest_paths {
+            // The bound is only weakened if it does not fit, which is still sound
+            let bound = (lower_bound as i64 + length).clamp(i32::MIN as i64, i32::MAX as i64);
+            if bound > context.lower_bound(&self.variables[target]) as i64 {
+                context.set_lower_bound(
+                    &self.variables[target],
+                    bound as i32,
+                    conjunction!([source_variable >= lower_bound]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the upper bounds of the variables from which `sink` can be reached based on the upper
+    /// bound of `sink`.
+    fn propagate_upper_bounds_to(
+        &self,
+        context: &mut PropagationContextMut,
+        sink: usize,
+        longest_paths: &[(usize, i64)],
+    ) -> PropagationStatusCP {
+        let sink_variable = &self.variables[sink];
+        let upper_bound = context.upper_bound(sink_variable);
+        for &(origin, length) in longest_paths {
+            let bound = (upper_bound as i64 - length).clamp(i32::MIN as i64, i32::MAX as i64);
+            if bound < context.upper_bound(&self.variables[origin]) as i64 {
+                context.set_upper_bound(
+                    &self.variables[origin],
+                    bound as i32,
+                    conjunction!([sink_variable <= upper_bound]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the longest paths from `start` over the `arcs`; this assumes that there are no cycles
+/// of positive length.
+fn longest_paths(arcs: &[Vec<(usize, i32)>], start: usize) -> LongestPaths {
+    let mut lengths: Vec<Option<i64>> = vec![None; arcs.len()];
+    let mut is_queued = vec![false; arcs.len()];
+    let mut queue = VecDeque::from([start]);
+    lengths[start] = Some(0);
+    is_queued[start] = true;
+
+    while let Some(node) = queue.pop_front() {
+        is_queued[node] = false;
+        let length = lengths[node].expect("queued nodes are reached");
+        for &(next, gap) in &arcs[node] {
+            let candidate = length + gap as i64;
+            if lengths[next].is_some_and(|current| current >= candidate) {
+                continue;
+            }
+
+            lengths[next] = Some(candidate);
+            if !is_queued[next] {
+                is_queued[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    lengths
+        .into_iter()
+        .enumerate()
+        .filter_map(|(node, length)| Some((node, length?)))
+        .filter(|&(node, _)| node != start)
+        .collect()
+}
+
+/// Returns whether the graph given by `arcs` contains a cycle of positive length; this is
+/// determined by computing the longest paths from a virtual node which precedes every node.
+fn has_positive_cycle(arcs: &[Vec<(usize, i32)>]) -> bool {
+    let mut lengths = vec![0_i64; arcs.len()];
+    for _ in 0..=arcs.len() {
+        let mut has_changed = false;
+        for (node, node_arcs) in arcs.iter().enumerate() {
+            for &(next, gap) in node_arcs {
+                if lengths[node] + (gap as i64) > lengths[next] {
+                    lengths[next] = lengths[node] + gap as i64;
+                    has_changed = true;
+                }
+            }
+        }
+
+        if !has_changed {
+            return false;
+        }
+    }
+
+    true
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for PrecedencePropagator<Var> {
+    fn name(&self) -> &str {
+        "Precedence"
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        if has_positive_cycle(&self.successors) {
+            return Err(conjunction!());
+        }
+
+        let events = DomainEvents::create_with_int_events(enum_set!(
+            IntDomainEvent::LowerBound | IntDomainEvent::UpperBound | IntDomainEvent::Assign
+        ));
+        for (index, variable) in self.variables.iter().enumerate() {
+            let _ = context.register(variable.clone(), events, LocalId::from(index as u32));
+        }
+
+        Ok(())
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.variables
+            .iter()
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn notify(
+        &mut self,
+        _context: PropagationContext,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        let index = local_id.unpack() as usize;
+        let event = self.variables[index].unpack_event(event);
+        if matches!(event, IntDomainEvent::LowerBound | IntDomainEvent::Assign) {
+            self.changed_lower_bounds.push(index);
+        }
+        if matches!(event, IntDomainEvent::UpperBound | IntDomainEvent::Assign) {
+            self.changed_upper_bounds.push(index);
+        }
+
+        EnqueueDecision::Enqueue
+    }
+
+    fn synchronise(&mut self, _context: PropagationContext) {
+        // After backtracking, the bounds are those of an earlier fixed point of the propagator
+        self.changed_lower_bounds.clear();
+        self.changed_upper_bounds.clear();
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        // A bound can be propagated multiple times if it is found to have changed again, but the
+        // propagator is at a fixed point once the changed bounds of the variables at the start of
+        // all paths have been propagated
+        while let Some(source) = self.changed_lower_bounds.pop() {
+            let longest_paths = self.longest_paths_from[source]
+                .take()
+                .unwrap_or_else(|| longest_paths(&self.successors, source));
+            let result = self.propagate_lower_bounds_from(&mut context, source, &longest_paths);
+            self.longest_paths_from[source] = Some(longest_paths);
+            result?;
+        }
+
+        while let Some(sink) = self.changed_upper_bounds.pop() {
+            let longest_paths = self.longest_paths_to[sink]
+                .take()
+                .unwrap_or_else(|| longest_paths(&self.predecessors, sink));
+            let result = self.propagate_upper_bounds_to(&mut context, sink, &longest_paths);
+            self.longest_paths_to[sink] = Some(longest_paths);
+            result?;
+        }
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        for index in 0..self.variables.len() {
+            self.propagate_lower_bounds_from(
+                &mut context,
+                index,
+                &longest_paths(&self.successors, index),
+            )?;
+            self.propagate_upper_bounds_to(
+                &mut context,
+                index,
+                &longest_paths(&self.predecessors, index),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_helper::TestSolver;
+    use crate::predicate;
+
+    fn precedences(precedences: &[(usize, i32, usize)]) -> Vec<ArgPrecedence> {
+        precedences
+            .iter()
+            .map(|&(from, gap, to)| ArgPrecedence { from, gap, to })
+            .collect()
+    }
+
+    #[test]
+    fn bounds_are_propagated_along_the_longest_path() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 20);
+        let b = solver.new_variable(0, 20);
+        let c = solver.new_variable(0, 20);
+
+        // The path `a -> b -> c` is longer than the arc `a -> c`
+        let _ = solver
+            .new_propagator(PrecedencePropagator::new(
+                [a, b, c].into(),
+                &precedences(&[(0, 3, 1), (1, 4, 2), (0, 5, 2)]),
+            ))
+            .expect("no empty domains");
+
+        assert_eq!(solver.lower_bound(b), 3);
+        assert_eq!(solver.lower_bound(c), 7);
+        assert_eq!(solver.upper_bound(a), 13);
+        assert_eq!(solver.upper_bound(b), 16);
+
+        // The explanation only contains the bound at the start of the path
+        let reason = solver.get_reason_int(predicate![c >= 7].try_into().unwrap());
+        assert_eq!(conjunction!([a >= 0]), reason.clone());
+        let reason = solver.get_reason_int(predicate![a <= 13].try_into().unwrap());
+        assert_eq!(conjunction!([c <= 20]), reason.clone());
+    }
+
+    #[test]
+    fn changed_bounds_are_propagated_incrementally() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 20);
+        let b = solver.new_variable(0, 20);
+        let c = solver.new_variable(0, 20);
+
+        let mut propagator = solver
+            .new_propagator(PrecedencePropagator::new(
+                [a, b, c].into(),
+                &precedences(&[(0, 2, 1), (1, 2, 2)]),
+            ))
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, b, 10);
+        solver.propagate(&mut propagator).expect("no empty domains");
+
+        assert_eq!(solver.lower_bound(c), 12);
+        let reason = solver.get_reason_int(predicate![c >= 12].try_into().unwrap());
+        assert_eq!(conjunction!([b >= 10]), reason.clone());
+    }
+
+    #[test]
+    fn positive_cycle_is_detected_at_the_root() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 20);
+        let b = solver.new_variable(0, 20);
+
+        let result = solver.new_propagator(PrecedencePropagator::new(
+            [a, b].into(),
+            &precedences(&[(0, 2, 1), (1, -1, 0)]),
+        ));
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Other(ConflictInfo::Explanation(_)))
+        ));
+    }
+
+    #[test]
+    fn conflicting_bounds_are_detected() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(5, 20);
+        let b = solver.new_variable(0, 6);
+
+        let result = solver.new_propagator(PrecedencePropagator::new(
+            [a, b].into(),
+            &precedences(&[(0, 2, 1)]),
+        ));
+        assert!(result.is_err());
+    }
+}
@@ -4,6 +4,7 @@ use crate::basic_types::PropagationStatusCP;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
@@ -12,6 +13,7 @@ use crate::engine::propagation::ReadDomains;
 use crate::engine::BooleanDomainEvent;
 use crate::engine::DomainEvents;
 use crate::predicates::PropositionalConjunction;
+use crate::statistics::statistic_logger::StatisticLogger;
 use crate::variables::Literal;
 
 /// Propagator for the constraint `r -> p`, where `r` is a Boolean literal and `p` is an arbitrary
@@ -21,6 +23,10 @@ use crate::variables::Literal;
 /// the propagator implements [`Propagator::detect_inconsistency`], the result of that method may
 /// be used to propagate `r` to false. If that method is not implemented, `r` will never be
 /// propagated to false.
+///
+/// Note that [`Propagator::detect_inconsistency`] is also checked whenever the wrapped propagator
+/// would have been enqueued while `r` is unassigned; if an inconsistency is detected, then the
+/// reified propagator enqueues itself so that `r` is falsified as early as possible.
 #[derive(Clone, Debug)]
 pub(crate) struct ReifiedPropagator<WrappedPropagator> {
     propagator: WrappedPropagator,
@@ -112,10 +118,14 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         Ok(())
     }
 
-    fn priority(&self) -> u32 {
+    fn priority(&self) -> Priority {
         self.propagator.priority()
     }
 
+    fn has_pending_work(&self) -> bool {
+        self.propagator.has_pending_work()
+    }
+
     fn synchronise(&mut self, context: PropagationContext) {
         // We remove the inconsistency upon backtracking since it might be invalid now
         self.inconsistency = None;
@@ -145,6 +155,22 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         &self.name
     }
 
+    fn describe(&self) -> Option<String> {
+        self.propagator
+            .describe()
+            .map(|description| format!("{} -> ({description})", self.reification_literal))
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.propagator.may_overflow(context)
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        // The prefix already contains the composed name, so the statistics of the wrapped
+        // propagator are logged under the name of the reified propagator
+        self.propagator.log_statistics(statistic_logger)
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -230,6 +256,23 @@ mod tests {
     use crate::predicates::PropositionalConjunction;
     use crate::variables::DomainId;
 
+    #[test]
+    fn name_is_composed_of_the_name_of_the_wrapped_propagator() {
+        let mut solver = TestSolver::default();
+        let reification_literal = solver.new_literal();
+
+        let propagator = ReifiedPropagator::new(
+            GenericPropagator::new(
+                |_: PropagationContextMut| Ok(()),
+                |_: PropagationContext| None,
+                |_: &mut PropagatorInitialisationContext| Ok(()),
+            ),
+            reification_literal,
+        );
+
+        assert_eq!(propagator.name(), "Reified(Generic Propagator)");
+    }
+
     #[test]
     fn a_detected_inconsistency_is_given_as_reason_for_propagating_reification_literal_to_false() {
         let mut solver = TestSolver::default();
@@ -378,6 +421,41 @@ mod tests {
         assert!(matches!(enqueue, EnqueueDecision::Enqueue))
     }
 
+    #[test]
+    fn inconsistency_detected_upon_notify_propagates_reification_literal_to_false() {
+        let mut solver = TestSolver::default();
+
+        let reification_literal = solver.new_literal();
+        let var = solver.new_variable(1, 5);
+
+        let mut propagator = solver
+            .new_propagator(ReifiedPropagator::new(
+                GenericPropagator::new(
+                    |_: PropagationContextMut| Ok(()),
+                    move |context: PropagationContext| {
+                        if context.is_fixed(&var) {
+                            Some(conjunction!([var == 5]))
+                        } else {
+                            None
+                        }
+                    },
+                    |_: &mut PropagatorInitialisationContext| Ok(()),
+                )
+                .with_variables(&[var]),
+                reification_literal,
+            ))
+            .expect("No conflict expected");
+
+        let enqueue = solver.increase_lower_bound_and_notify(&mut propagator, 0, var, 5);
+        assert!(matches!(enqueue, EnqueueDecision::Enqueue));
+
+        solver.propagate(&mut propagator).expect("no conflict");
+
+        assert!(solver.is_literal_false(reification_literal));
+        let reason = solver.get_reason_bool(reification_literal, false);
+        assert_eq!(reason, &conjunction!([var == 5]));
+    }
+
     struct GenericPropagator<Propagation, ConsistencyCheck, Init> {
         propagation: Propagation,
         consistency_check: ConsistencyCheck,
@@ -0,0 +1,329 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::predicates::Predicate;
+
+/// Propagator for the [sort](https://sofdem.github.io/gccat/gccat/Csort.html) constraint, which
+/// states that `y` is the permutation of `x` which is sorted in non-decreasing order.
+///
+/// The bounds of `y` are narrowed to the sorted bounds of `x` \[1\]: `y[i]` is at least the `i`-th
+/// smallest lower bound of `x` and at most the `i`-th smallest upper bound of `x`, and `y` is kept
+/// non-decreasing. The bounds of `x` are narrowed by counting arguments over the prefixes and
+/// suffixes of `y`; if `c` variables of `x` other than `x[j]` are certainly smaller than `y[c]`,
+/// then these take up all the positions before `c`, so `x[j]` is at least the lower bound of
+/// `y[c]` (and symmetrically for the upper bounds).
+///
+/// All explanations are lifted to the bound which is propagated, rather than using the current
+/// bounds of the variables.
+///
+/// # Bibliography
+/// \[1\] N. Bleuzen-Guernalec and A. Colmerauer, ‘Narrowing a block of sortings in quadratic
+/// time’, in International Conference on Principles and Practice of Constraint Programming, 1997,
+/// pp. 2–16.
+#[derive(Clone, Debug)]
+pub(crate) struct SortedPropagator<Var> {
+    x: Box<[Var]>,
+    y: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> SortedPropagator<Var> {
+    pub(crate) fn new(x: Box<[Var]>, y: Box<[Var]>) -> Self {
+        assert_eq!(x.len(), y.len(), "`x` and `y` should have the same length");
+        SortedPropagator { x, y }
+    }
+
+    /// Returns the indices of `x` sorted by their lower bounds.
+    fn sorted_by_lower_bound(&self, context: PropagationContext) -> Vec<usize> {
+        let mut indices = (0..self.x.len()).collect::<Vec<_>>();
+        indices.sort_by_key(|&index| context.lower_bound(&self.x[index]));
+        indices
+    }
+
+    /// Returns the indices of `x` sorted by their upper bounds.
+    fn sorted_by_upper_bound(&self, context: PropagationContext) -> Vec<usize> {
+        let mut indices = (0..self.x.len()).collect::<Vec<_>>();
+        indices.sort_by_key(|&index| context.upper_bound(&self.x[index]));
+        indices
+    }
+
+    /// Narrows `y[i]` to the `i`-th smallest lower bound and upper bound of `x`.
+    fn propagate_y_from_x(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let by_lower_bound = self.sorted_by_lower_bound(context.as_readonly());
+        for (position, y_i) in self.y.iter().enumerate() {
+            // The variables of `x` from this position onwards are all at least `bound`, so at most
+            // `position` values are smaller than it
+            let bound = context.lower_bound(&self.x[by_lower_bound[position]]);
+            if bound > context.lower_bound(y_i) {
+                let reason: PropositionalConjunction = by_lower_bound[position..]
+                    .iter()
+                    .map(|&index| predicate![self.x[index] >= bound])
+                    .collect();
+                context.set_lower_bound(y_i, bound, reason)?;
+            }
+        }
+
+        let by_upper_bound = self.sorted_by_upper_bound(context.as_readonly());
+        for (position, y_i) in self.y.iter().enumerate() {
+            // The variables of `x` up to this position are all at most `bound`
+            let bound = context.upper_bound(&self.x[by_upper_bound[position]]);
+            if bound < context.upper_bound(y_i) {
+                let reason: PropositionalConjunction = by_upper_bound[..=position]
+                    .iter()
+                    .map(|&index| predicate![self.x[index] <= bound])
+                    .collect();
+                context.set_upper_bound(y_i, bound, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the bounds of `y` are non-decreasing.
+    fn propagate_order_of_y(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for window in self.y.windows(2) {
+            let lower_bound = context.lower_bound(&window[0]);
+            context.set_lower_bound(
+                &window[1],
+                lower_bound,
+                conjunction!([window[0] >= lower_bound]),
+            )?;
+        }
+
+        for window in self.y.windows(2).rev() {
+            let upper_bound = context.upper_bound(&window[1]);
+            context.set_upper_bound(
+                &window[0],
+                upper_bound,
+                conjunction!([window[1] <= upper_bound]),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Narrows the lower bounds of `x`; if the variables of `x` which are certainly smaller than
+    /// the lower bound of `y[position]` fill all positions before `position`, then the other
+    /// variables of `x` are at least that lower bound.
+    fn propagate_x_lower_bounds(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        for position in 0..self.y.len() {
+            let bound = context.lower_bound(&self.y[position]);
+            // Only the first position with a given lower bound needs to be considered
+            if position > 0 && context.lower_bound(&self.y[position - 1]) >= bound {
+                continue;
+            }
+
+            let by_upper_bound = self.sorted_by_upper_bound(context.as_readonly());
+            let num_smaller = by_upper_bound
+                .partition_point(|&index| context.upper_bound(&self.x[index]) < bound);
+            if num_smaller < position {
+                continue;
+            }
+
+            let smaller = &by_upper_bound[..position];
+            let mut reason: Vec<Predicate> = smaller
+                .iter()
+                .map(|&index| predicate![self.x[index] <= bound - 1])
+                .collect();
+            reason.push(predicate![self.y[position] >= bound]);
+
+            for (index, x_j) in self.x.iter().enumerate() {
+                if context.lower_bound(x_j) < bound && !smaller.contains(&index) {
+                    context.set_lower_bound(
+                        x_j,
+                        bound,
+                        PropositionalConjunction::from(reason.clone()),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Narrows the upper bounds of `x`; this is the counterpart of
+    /// [`SortedPropagator::propagate_x_lower_bounds`] for the suffixes of `y`.
+    fn propagate_x_upper_bounds(&self, context: &mut PropagationContextMut) -> PropagationStatusCP {
+        let num_positions = self.y.len();
+        for position in (0..num_positions).rev() {
+            let bound = context.upper_bound(&self.y[position]);
+            if position + 1 < num_positions && context.upper_bound(&self.y[position + 1]) <= bound {
+                continue;
+            }
+
+            let by_lower_bound = self.sorted_by_lower_bound(context.as_readonly());
+            let num_not_greater = by_lower_bound
+                .partition_point(|&index| context.lower_bound(&self.x[index]) <= bound);
+            let num_after = num_positions - position - 1;
+            if num_positions - num_not_greater < num_after {
+                continue;
+            }
+
+            let greater = &by_lower_bound[num_positions - num_after..];
+            let mut reason: Vec<Predicate> = greater
+                .iter()
+                .map(|&index| predicate![self.x[index] >= bound + 1])
+                .collect();
+            reason.push(predicate![self.y[position] <= bound]);
+
+            for (index, x_j) in self.x.iter().enumerate() {
+                if context.upper_bound(x_j) > bound && !greater.contains(&index) {
+                    context.set_upper_bound(
+                        x_j,
+                        bound,
+                        PropositionalConjunction::from(reason.clone()),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable> Propagator for SortedPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        for (index, x_i) in self.x.iter().enumerate() {
+            let _ = context.register(
+                x_i.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+        }
+        for (index, y_i) in self.y.iter().enumerate() {
+            let _ = context.register(
+                y_i.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from((self.x.len() + index) as u32),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "Sorted"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        // The bounds are only moved past values which are strictly within the bounds of another
+        // variable, so only the bounds themselves should fit in an `i32`
+        self.x
+            .iter()
+            .chain(self.y.iter())
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_y_from_x(&mut context)?;
+        self.propagate_order_of_y(&mut context)?;
+        self.propagate_x_lower_bounds(&mut context)?;
+        self.propagate_x_upper_bounds(&mut context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn y_is_narrowed_to_sorted_bounds_of_x() {
+        let mut solver = TestSolver::default();
+        let x = [
+            solver.new_variable(5, 9),
+            solver.new_variable(1, 3),
+            solver.new_variable(2, 8),
+        ];
+        let y = [
+            solver.new_variable(0, 10),
+            solver.new_variable(0, 10),
+            solver.new_variable(0, 10),
+        ];
+
+        let _ = solver
+            .new_propagator(SortedPropagator::new(x.into(), y.into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(y[0], 1, 3);
+        solver.assert_bounds(y[1], 2, 8);
+        solver.assert_bounds(y[2], 5, 9);
+
+        let reason = solver.get_reason_int(predicate![y[1] >= 2].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::new(vec![predicate![x[2] >= 2], predicate![x[0] >= 2]]),
+            *reason
+        );
+        let reason = solver.get_reason_int(predicate![y[1] <= 8].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::new(vec![predicate![x[1] <= 8], predicate![x[2] <= 8]]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn x_is_narrowed_by_the_prefixes_of_y() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(0, 0), solver.new_variable(0, 9)];
+        let y = [solver.new_variable(0, 9), solver.new_variable(5, 9)];
+
+        let _ = solver
+            .new_propagator(SortedPropagator::new(x.into(), y.into()))
+            .expect("no empty domains");
+
+        // `x[0]` takes up the first position, so `x[1]` has to be the second one
+        solver.assert_bounds(x[1], 5, 9);
+        let reason = solver.get_reason_int(predicate![x[1] >= 5].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::new(vec![predicate![x[0] <= 4], predicate![y[1] >= 5]]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn x_is_narrowed_by_the_suffixes_of_y() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(0, 9), solver.new_variable(8, 9)];
+        let y = [solver.new_variable(0, 3), solver.new_variable(0, 9)];
+
+        let _ = solver
+            .new_propagator(SortedPropagator::new(x.into(), y.into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[0], 0, 3);
+        let reason = solver.get_reason_int(predicate![x[0] <= 3].try_into().unwrap());
+        assert_eq!(
+            PropositionalConjunction::new(vec![predicate![x[1] >= 4], predicate![y[0] <= 3]]),
+            *reason
+        );
+    }
+
+    #[test]
+    fn too_many_small_values_are_conflicting() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(0, 1), solver.new_variable(0, 1)];
+        let y = [solver.new_variable(0, 1), solver.new_variable(2, 3)];
+
+        let result = solver.new_propagator(SortedPropagator::new(x.into(), y.into()));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::Priority;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the [value precedence](https://sofdem.github.io/gccat/gccat/Cint_value_precede.html)
+/// constraint, which states that if a variable `x_j` takes the value `t`, then there is a variable
+/// `x_i` with `i < j` which takes the value `s`.
+///
+/// The propagator is domain consistent and is based on the algorithm by Law and Lee \[1\]. Let
+/// `alpha` be the first position at which `s` can be taken; then `t` is removed from the variables
+/// up to and including `alpha`. If no other variable can take `s` before the first variable which
+/// is fixed to `t`, then the variable at position `alpha` is fixed to `s`.
+///
+/// # Bibliography
+/// \[1\] Y. C. Law and J. H. M. Lee, ‘Global constraints for integer and set value precedence’, in
+/// International Conference on Principles and Practice of Constraint Programming, 2004, pp.
+/// 362–376.
+#[derive(Clone, Debug)]
+pub(crate) struct ValuePrecedePropagator<Var> {
+    s: i32,
+    t: i32,
+    variables: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable> ValuePrecedePropagator<Var> {
+    pub(crate) fn new(s: i32, t: i32, variables: Box<[Var]>) -> Self {
+        ValuePrecedePropagator { s, t, variables }
+    }
+
+    fn is_fixed_to(&self, context: &PropagationContextMut, variable: &Var, value: i32) -> bool {
+        context.is_fixed(variable) && context.lower_bound(variable) == value
+    }
+}
+
+impl<Var: IntegerVariable> Propagator for ValuePrecedePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables.iter().enumerate().for_each(|(i, variable)| {
+            let _ = context.register(
+                variable.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(i as u32),
+            );
+        });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> Priority {
+        Priority::Linear
+    }
+
+    fn name(&self) -> &str {
+        "ValuePrecede"
+    }
+
+    fn may_overflow(&self, context: PropagationContext) -> bool {
+        self.variables
+            .iter()
+            .any(|variable| context.bounds_overflow(variable))
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // A value always precedes itself
+        if self.s == self.t {
+            return Ok(());
+        }
+
+        // The reason for `t` not being allowed at a position is that `s` cannot be taken at any of
+        // the positions before it
+        let mut reason = vec![];
+        let mut alpha = None;
+        for (index, variable) in self.variables.iter().enumerate() {
+            if context.contains(variable, self.t) {
+                context.remove(
+                    variable,
+                    self.t,
+                    PropositionalConjunction::from(reason.clone()),
+                )?;
+            }
+
+            if context.contains(variable, self.s) {
+                alpha = Some(index);
+                break;
+            }
+            reason.push(predicate![variable != self.s]);
+        }
+
+        let Some(alpha) = alpha else {
+            return Ok(());
+        };
+
+        // If the first variable which is fixed to `t` is encountered before another variable which
+        // can take `s`, then the variable at `alpha` has to take `s`
+        for variable in self.variables[alpha + 1..].iter() {
+            if context.contains(variable, self.s) {
+                return Ok(());
+            }
+
+            if self.is_fixed_to(&context, variable, self.t) {
+                reason.push(predicate![variable == self.t]);
+                let reason = PropositionalConjunction::from(reason);
+
+                let alpha_variable = &self.variables[alpha];
+                context.set_lower_bound(alpha_variable, self.s, reason.clone())?;
+                context.set_upper_bound(alpha_variable, self.s, reason)?;
+                return Ok(());
+            }
+
+            reason.push(predicate![variable != self.s]);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn value_is_removed_before_first_occurrence() {
+        let mut solver = TestSolver::default();
+        let x = [
+            solver.new_variable(2, 3),
+            solver.new_variable(1, 3),
+            solver.new_variable(1, 3),
+        ];
+
+        let _ = solver
+            .new_propagator(ValuePrecedePropagator::new(1, 2, x.into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[0], 3, 3);
+        assert!(!solver.contains(x[1], 2));
+        assert!(solver.contains(x[2], 2));
+
+        let reason = solver.get_reason_int(predicate![x[1] != 2].try_into().unwrap());
+        assert_eq!(conjunction!([x[0] != 1]), *reason);
+    }
+
+    #[test]
+    fn single_support_is_fixed() {
+        let mut solver = TestSolver::default();
+        let x = [
+            solver.new_variable(1, 3),
+            solver.new_variable(2, 3),
+            solver.new_variable(2, 2),
+        ];
+
+        let _ = solver
+            .new_propagator(ValuePrecedePropagator::new(1, 2, x.into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x[0], 1, 1);
+        let reason = solver.get_reason_int(predicate![x[0] <= 1].try_into().unwrap());
+        assert_eq!(conjunction!([x[1] != 1] & [x[2] == 2]), *reason);
+    }
+
+    #[test]
+    fn conflict_is_detected() {
+        let mut solver = TestSolver::default();
+        let x = [solver.new_variable(3, 3), solver.new_variable(2, 2)];
+
+        let result = solver.new_propagator(ValuePrecedePropagator::new(1, 2, x.into()));
+        assert!(result.is_err());
+    }
+}
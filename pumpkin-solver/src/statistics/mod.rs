@@ -13,6 +13,7 @@ pub use statistic_logging::StatisticOptions;
 
 #[cfg(doc)]
 use crate::create_statistics_struct;
+pub use crate::engine::model_statistics::ModelStatistics;
 #[cfg(doc)]
 use crate::Solver;
 
@@ -1,6 +1,17 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use crate::basic_types::HashMap;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::predicates::integer_predicate::IntegerPredicate;
+use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
+use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
+use crate::engine::VariableLiteralMappings;
+#[cfg(doc)]
+use crate::Solver;
 
 #[derive(Debug, Default)]
 pub(crate) struct VariableNames {
@@ -22,6 +33,46 @@ impl VariableNames {
         self.integers.get(&domain_id).map(|s| s.as_str())
     }
 
+    /// Get the name associated with a domain id, or the default name of the domain id (e.g.
+    /// `x3`) if it is unnamed.
+    pub(crate) fn get_int_name_or_default(&self, domain_id: DomainId) -> Cow<'_, str> {
+        self.get_int_name(domain_id)
+            .map_or_else(|| Cow::Owned(domain_id.to_string()), Cow::Borrowed)
+    }
+
+    /// Replaces the default names of the domain ids in `text` (e.g. `x3`) by the names which are
+    /// associated with them; unnamed domain ids keep their default names.
+    pub(crate) fn substitute_int_names(&self, text: &str) -> String {
+        let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_';
+
+        let mut substituted = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(position) = rest.find('x') {
+            substituted.push_str(&rest[..position]);
+            let candidate = &rest[position + 1..];
+            let num_digits = candidate
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(candidate.len());
+
+            let is_separate_token = num_digits > 0
+                && !substituted.ends_with(is_identifier_char)
+                && !candidate[num_digits..].starts_with(is_identifier_char);
+            match candidate[..num_digits].parse::<u32>() {
+                Ok(id) if is_separate_token => {
+                    substituted.push_str(&self.get_int_name_or_default(DomainId::new(id)));
+                    rest = &candidate[num_digits..];
+                }
+                _ => {
+                    substituted.push('x');
+                    rest = candidate;
+                }
+            }
+        }
+        substituted.push_str(rest);
+
+        substituted
+    }
+
     /// Add a name to the propositional variable. This will override existing the name if it
     /// exists.
     pub(crate) fn add_propositional(&mut self, variable: PropositionalVariable, name: String) {
@@ -34,3 +85,271 @@ impl VariableNames {
         let _ = self.integers.insert(integer, name);
     }
 }
+
+/// Displays a value (e.g. a [`Predicate`]) using the names which were given to the variables when
+/// they were created, rather than their internal identifiers; see [`Predicate::display_with`].
+///
+/// Unnamed variables are displayed in the same way as by the [`Display`] implementation of the
+/// value itself, and literals which are linked to an [`IntegerPredicate`] are displayed as that
+/// predicate.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayWith<'a, T: ?Sized> {
+    value: &'a T,
+    variable_names: &'a VariableNames,
+    variable_literal_mappings: &'a VariableLiteralMappings,
+}
+
+impl<'a, T: ?Sized> DisplayWith<'a, T> {
+    pub(crate) fn new(
+        value: &'a T,
+        variable_names: &'a VariableNames,
+        variable_literal_mappings: &'a VariableLiteralMappings,
+    ) -> Self {
+        DisplayWith {
+            value,
+            variable_names,
+            variable_literal_mappings,
+        }
+    }
+
+    /// Returns the adapter which displays `value` with the same names.
+    fn with<U: ?Sized>(&self, value: &'a U) -> DisplayWith<'a, U> {
+        DisplayWith::new(value, self.variable_names, self.variable_literal_mappings)
+    }
+
+    fn int_name(&self, domain_id: DomainId) -> Cow<'a, str> {
+        self.variable_names.get_int_name_or_default(domain_id)
+    }
+}
+
+impl Display for DisplayWith<'_, IntegerPredicate> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = self.int_name(self.value.get_domain());
+        match *self.value {
+            IntegerPredicate::LowerBound { lower_bound, .. } => {
+                write!(f, "[{name} >= {lower_bound}]")
+            }
+            IntegerPredicate::UpperBound { upper_bound, .. } => {
+                write!(f, "[{name} <= {upper_bound}]")
+            }
+            IntegerPredicate::NotEqual {
+                not_equal_constant, ..
+            } => write!(f, "[{name} != {not_equal_constant}]"),
+            IntegerPredicate::Equal {
+                equality_constant, ..
+            } => write!(f, "[{name} == {equality_constant}]"),
+        }
+    }
+}
+
+impl Display for DisplayWith<'_, Literal> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let literal = *self.value;
+        if let Some(predicate) = self
+            .variable_literal_mappings
+            .get_predicates(literal)
+            .next()
+        {
+            return self.with(&predicate).fmt(f);
+        }
+
+        if literal.is_negative() {
+            write!(f, "~")?;
+        }
+        let variable = literal.get_propositional_variable();
+        match self.variable_names.get_propositional_name(variable) {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{variable}"),
+        }
+    }
+}
+
+/// A slice of literals is displayed as a clause (e.g. a learned nogood, in its clausal form).
+impl Display for DisplayWith<'_, [Literal]> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.value.is_empty() {
+            return write!(f, "false");
+        }
+
+        for (index, literal) in self.value.iter().enumerate() {
+            if index > 0 {
+                write!(f, " \\/ ")?;
+            }
+            self.with(literal).fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for DisplayWith<'_, Predicate> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Predicate::IntegerPredicate(integer_predicate) => self.with(integer_predicate).fmt(f),
+            Predicate::Literal(literal) => self.with(literal).fmt(f),
+            Predicate::False => write!(f, "false"),
+            Predicate::True => write!(f, "true"),
+        }
+    }
+}
+
+/// The lower and upper bound of the same variable in a conjunction are displayed together (e.g.
+/// `[2 <= x <= 5]`, or `[x == 2]` if they are equal).
+impl Display for DisplayWith<'_, PropositionalConjunction> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let predicates = self.value.iter().copied().collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return write!(f, "{{empty}}");
+        }
+
+        let mut is_first = true;
+        for (index, predicate) in predicates.iter().enumerate() {
+            // The index of the other bound, the domain, and its lower and upper bound
+            let paired_bounds = match *predicate {
+                Predicate::IntegerPredicate(IntegerPredicate::LowerBound {
+                    domain_id,
+                    lower_bound,
+                }) => find_upper_bound(&predicates, domain_id)
+                    .map(|(other, upper_bound)| (other, domain_id, lower_bound, upper_bound)),
+                Predicate::IntegerPredicate(IntegerPredicate::UpperBound {
+                    domain_id,
+                    upper_bound,
+                }) => find_lower_bound(&predicates, domain_id)
+                    .map(|(other, lower_bound)| (other, domain_id, lower_bound, upper_bound)),
+                _ => None,
+            };
+
+            // A pair of bounds is displayed at the position of the first of the two
+            if paired_bounds.is_some_and(|(other, ..)| other < index) {
+                continue;
+            }
+
+            if !is_first {
+                write!(f, "; ")?;
+            }
+            is_first = false;
+
+            match paired_bounds {
+                Some((_, domain_id, lower_bound, upper_bound)) => {
+                    let name = self.int_name(domain_id);
+                    if lower_bound == upper_bound {
+                        write!(f, "[{name} == {lower_bound}]")?;
+                    } else {
+                        write!(f, "[{lower_bound} <= {name} <= {upper_bound}]")?;
+                    }
+                }
+                None => self.with(predicate).fmt(f)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the index and value of the first lower bound on `domain_id` in `predicates`.
+fn find_lower_bound(predicates: &[Predicate], domain_id: DomainId) -> Option<(usize, i32)> {
+    predicates
+        .iter()
+        .enumerate()
+        .find_map(|(index, predicate)| match *predicate {
+            Predicate::IntegerPredicate(IntegerPredicate::LowerBound {
+                domain_id: other,
+                lower_bound,
+            }) if other == domain_id => Some((index, lower_bound)),
+            _ => None,
+        })
+}
+
+/// Returns the index and value of the first upper bound on `domain_id` in `predicates`.
+fn find_upper_bound(predicates: &[Predicate], domain_id: DomainId) -> Option<(usize, i32)> {
+    predicates
+        .iter()
+        .enumerate()
+        .find_map(|(index, predicate)| match *predicate {
+            Predicate::IntegerPredicate(IntegerPredicate::UpperBound {
+                domain_id: other,
+                upper_bound,
+            }) if other == domain_id => Some((index, upper_bound)),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VariableNames;
+    use crate::engine::variables::DomainId;
+    use crate::predicate;
+    use crate::predicates::Predicate;
+    use crate::predicates::PropositionalConjunction;
+    use crate::Solver;
+
+    #[test]
+    fn bounds_in_conjunction_are_displayed_together() {
+        let mut solver = Solver::default();
+        let x = solver.new_named_bounded_integer(0, 10, "x");
+        let y = solver.new_named_bounded_integer(0, 10, "y");
+
+        let conjunction = PropositionalConjunction::new(vec![
+            predicate![x >= 2],
+            predicate![y != 3],
+            predicate![x <= 5],
+            predicate![y >= 4],
+            predicate![y <= 4],
+        ]);
+
+        assert_eq!(
+            conjunction.display_with(&solver).to_string(),
+            "[2 <= x <= 5]; [y != 3]; [y == 4]"
+        );
+    }
+
+    #[test]
+    fn default_names_in_text_are_substituted() {
+        let mut variable_names = VariableNames::default();
+        variable_names.add_integer(DomainId::new(0), "x".to_owned());
+        variable_names.add_integer(DomainId::new(2), "z".to_owned());
+
+        assert_eq!(
+            variable_names.substitute_int_names("2 * (x2) + x0 + x1 <= x0"),
+            "2 * (z) + x + x1 <= x"
+        );
+        // Other identifiers which contain the default name are left alone
+        assert_eq!(
+            variable_names.substitute_int_names("max_x0(xx2, x2y, x)"),
+            "max_x0(xx2, x2y, x)"
+        );
+    }
+
+    #[test]
+    fn unnamed_variables_keep_their_default_names() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let b = solver.new_literal();
+
+        let predicate: Predicate = predicate![x <= 3];
+        assert_eq!(
+            predicate.display_with(&solver).to_string(),
+            predicate.to_string()
+        );
+        assert_eq!((!b).display_with(&solver).to_string(), (!b).to_string());
+    }
+
+    #[test]
+    fn literals_linked_to_predicates_are_displayed_as_predicates() {
+        let mut solver = Solver::default();
+        let x = solver.new_named_bounded_integer(0, 10, "x");
+        let literal = solver.get_literal(predicate![x >= 4]);
+
+        assert_eq!(literal.display_with(&solver).to_string(), "[x >= 4]");
+        assert_eq!((!literal).display_with(&solver).to_string(), "[x <= 3]");
+
+        let clause = [literal, solver.get_literal(predicate![x == 1])];
+        assert_eq!(
+            solver
+                .get_satisfaction_solver()
+                .display_with(clause.as_slice())
+                .to_string(),
+            "[x >= 4] \\/ [x == 1]"
+        );
+    }
+}
@@ -0,0 +1,684 @@
+#![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
+
+//! Cross-checks the global constraints which have a dedicated propagator against their
+//! decompositions; when a new propagator is added for a constraint which can also be decomposed,
+//! a test should be added here.
+
+mod helpers;
+use helpers::decomposition::cross_check_decomposition;
+use helpers::decomposition::random_domains;
+use helpers::decomposition::Instance;
+use pumpkin_solver::constraints;
+use pumpkin_solver::options::CardinalityEncoding;
+use pumpkin_solver::options::CumulativeExplanationType;
+use pumpkin_solver::options::CumulativeOptions;
+use pumpkin_solver::options::CumulativePropagationMethod;
+use pumpkin_solver::options::DiffnOptions;
+use pumpkin_solver::predicate;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::variables::Literal;
+use pumpkin_solver::variables::TransformableVariable;
+use pumpkin_solver::ConstraintOperationError;
+use pumpkin_solver::Solver;
+use rand::Rng;
+
+/// Posts the clause which states that `literal` holds if and only if one of `literals` holds.
+fn post_disjunction(
+    solver: &mut Solver,
+    literal: Literal,
+    literals: &[Literal],
+) -> Result<(), ConstraintOperationError> {
+    for &disjunct in literals {
+        solver.add_clause([!disjunct, literal])?;
+    }
+    solver.add_clause(literals.iter().copied().chain([!literal]))
+}
+
+#[test]
+fn all_different() {
+    cross_check_decomposition(
+        |rng| {
+            let num_variables = rng.gen_range(2..=4);
+            Instance {
+                domains: random_domains(rng, num_variables, 0, 4, 3),
+                constants: vec![],
+            }
+        },
+        |solver, variables, _| {
+            solver
+                .add_constraint(constraints::all_different(variables.to_vec()))
+                .post()
+        },
+        |solver, variables, _| {
+            for (i, &lhs) in variables.iter().enumerate() {
+                for &rhs in &variables[i + 1..] {
+                    solver
+                        .add_constraint(constraints::binary_not_equals(lhs, rhs))
+                        .post()?;
+                }
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn binary_equals() {
+    cross_check_decomposition(
+        |rng| Instance {
+            domains: random_domains(rng, 2, -2, 4, 4),
+            constants: vec![rng.gen_range(-1..=1)],
+        },
+        |solver, variables, instance| {
+            solver
+                .add_constraint(constraints::binary_equals(
+                    variables[0].offset(instance.constants[0]),
+                    variables[1].scaled(1),
+                ))
+                .post()
+        },
+        |solver, variables, instance| {
+            solver
+                .add_constraint(constraints::equals(
+                    [variables[0].scaled(1), variables[1].scaled(-1)],
+                    -instance.constants[0],
+                ))
+                .post()
+        },
+    );
+}
+
+#[test]
+fn among() {
+    cross_check_decomposition(
+        |rng| {
+            let num_variables = rng.gen_range(1..=3);
+            let mut domains = random_domains(rng, num_variables, 0, 4, 3);
+            // The last variable is the count
+            domains.push((
+                rng.gen_range(0..=1),
+                rng.gen_range(1..=num_variables as i32),
+            ));
+            Instance {
+                domains,
+                constants: (0..=4).filter(|_| rng.gen_bool(0.4)).collect(),
+            }
+        },
+        |solver, variables, instance| {
+            let (count, variables) = variables.split_last().unwrap();
+            solver
+                .add_constraint(constraints::among(
+                    *count,
+                    variables.to_vec(),
+                    instance.constants.clone(),
+                ))
+                .post()
+        },
+        |solver, variables, instance| {
+            let (count, variables) = variables.split_last().unwrap();
+
+            // Every variable is linked to a 0-1 variable indicating whether it takes a value in
+            // the set, and the count is the sum of these variables
+            let mut terms = vec![count.scaled(-1)];
+            for &variable in variables {
+                let is_in_set = solver.new_bounded_integer(0, 1);
+                let in_set_literals = instance
+                    .constants
+                    .iter()
+                    .map(|&value| solver.get_literal(predicate![variable == value]))
+                    .collect::<Vec<_>>();
+                let is_in_set_literal = solver.get_literal(predicate![is_in_set >= 1]);
+                post_disjunction(solver, is_in_set_literal, &in_set_literals)?;
+
+                terms.push(is_in_set.scaled(1));
+            }
+
+            solver.add_constraint(constraints::equals(terms, 0)).post()
+        },
+    );
+}
+
+#[test]
+fn lex_less() {
+    cross_check_lex(true);
+}
+
+#[test]
+fn lex_lesseq() {
+    cross_check_lex(false);
+}
+
+fn cross_check_lex(strict: bool) {
+    fn post_native(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        instance: &Instance,
+    ) -> Result<(), ConstraintOperationError> {
+        let (x, y) = variables.split_at(variables.len() / 2);
+        if instance.constants[0] == 1 {
+            solver
+                .add_constraint(constraints::lex_less(x.to_vec(), y.to_vec()))
+                .post()
+        } else {
+            solver
+                .add_constraint(constraints::lex_lesseq(x.to_vec(), y.to_vec()))
+                .post()
+        }
+    }
+
+    fn post_decomposition(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        instance: &Instance,
+    ) -> Result<(), ConstraintOperationError> {
+        let (x, y) = variables.split_at(variables.len() / 2);
+
+        // The literal `prefix_is_equal` states that the positions before the current one are equal
+        let mut prefix_is_equal = solver.get_true_literal();
+        for (&x_i, &y_i) in x.iter().zip(y) {
+            solver
+                .add_constraint(constraints::binary_less_than_or_equals(x_i, y_i))
+                .implied_by(prefix_is_equal)?;
+
+            let is_equal = solver.new_literal();
+            solver
+                .add_constraint(constraints::binary_equals(x_i, y_i))
+                .reify(is_equal)?;
+
+            let next_prefix_is_equal = solver.new_literal();
+            solver.add_clause([!next_prefix_is_equal, prefix_is_equal])?;
+            solver.add_clause([!next_prefix_is_equal, is_equal])?;
+            solver.add_clause([!prefix_is_equal, !is_equal, next_prefix_is_equal])?;
+            prefix_is_equal = next_prefix_is_equal;
+        }
+
+        if instance.constants[0] == 1 {
+            solver.add_clause([!prefix_is_equal])?;
+        }
+        Ok(())
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let length = rng.gen_range(1..=3);
+            Instance {
+                domains: random_domains(rng, 2 * length, 0, 3, 3),
+                constants: vec![i32::from(strict)],
+            }
+        },
+        post_native,
+        post_decomposition,
+    );
+}
+
+#[test]
+fn value_precede() {
+    cross_check_decomposition(
+        |rng| {
+            let s = rng.gen_range(0..=2);
+            let num_variables = rng.gen_range(1..=4);
+            Instance {
+                domains: random_domains(rng, num_variables, 0, 2, 3),
+                constants: vec![s, (s + rng.gen_range(1..=2)) % 3],
+            }
+        },
+        |solver, variables, instance| {
+            solver
+                .add_constraint(constraints::value_precede(
+                    instance.constants[0],
+                    instance.constants[1],
+                    variables.to_vec(),
+                ))
+                .post()
+        },
+        |solver, variables, instance| {
+            let s = instance.constants[0];
+            let t = instance.constants[1];
+
+            // If a variable takes `t`, then one of the earlier variables takes `s`
+            for (j, &x_j) in variables.iter().enumerate() {
+                let clause = variables[..j]
+                    .iter()
+                    .map(|&x_i| solver.get_literal(predicate![x_i == s]))
+                    .chain([!solver.get_literal(predicate![x_j == t])])
+                    .collect::<Vec<_>>();
+                solver.add_clause(clause)?;
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn binary_maximum() {
+    cross_check_binary_extremum(true);
+}
+
+#[test]
+fn binary_minimum() {
+    cross_check_binary_extremum(false);
+}
+
+fn cross_check_binary_extremum(is_maximum: bool) {
+    // The variables are `[lhs, rhs, extremum, comparison]`, where the comparison is a 0-1 variable
+    fn comparison_literal(solver: &mut Solver, variables: &[DomainId]) -> Literal {
+        let comparison = variables[3];
+        solver.get_literal(predicate![comparison >= 1])
+    }
+
+    fn post_native(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        instance: &Instance,
+    ) -> Result<(), ConstraintOperationError> {
+        let comparison = comparison_literal(solver, variables);
+        if instance.constants[0] == 1 {
+            solver
+                .add_constraint(constraints::binary_maximum(
+                    variables[0],
+                    variables[1],
+                    variables[2],
+                    Some(comparison),
+                ))
+                .post()
+        } else {
+            solver
+                .add_constraint(constraints::binary_minimum(
+                    variables[0],
+                    variables[1],
+                    variables[2],
+                    Some(comparison),
+                ))
+                .post()
+        }
+    }
+
+    fn post_decomposition(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        instance: &Instance,
+    ) -> Result<(), ConstraintOperationError> {
+        let comparison = comparison_literal(solver, variables);
+        solver
+            .add_constraint(constraints::binary_less_than_or_equals(
+                variables[0],
+                variables[1],
+            ))
+            .reify(comparison)?;
+
+        if instance.constants[0] == 1 {
+            solver
+                .add_constraint(constraints::maximum(
+                    [variables[0], variables[1]],
+                    variables[2],
+                ))
+                .post()
+        } else {
+            solver
+                .add_constraint(constraints::minimum(
+                    [variables[0], variables[1]],
+                    variables[2],
+                ))
+                .post()
+        }
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let mut domains = random_domains(rng, 3, 0, 5, 4);
+            domains.push((0, 1));
+            Instance {
+                domains,
+                constants: vec![i32::from(is_maximum)],
+            }
+        },
+        post_native,
+        post_decomposition,
+    );
+}
+
+#[test]
+fn boolean_less_than_or_equals() {
+    cross_check_decomposition(
+        |rng| {
+            let num_variables = rng.gen_range(1..=4);
+            Instance {
+                domains: vec![(0, 1); num_variables],
+                constants: (0..=num_variables).map(|_| rng.gen_range(-3..=4)).collect(),
+            }
+        },
+        |solver, variables, instance| {
+            // The last constant is the right-hand side, the others are the weights
+            let (rhs, weights) = instance.constants.split_last().unwrap();
+            let literals = variables
+                .iter()
+                .map(|&variable| solver.get_literal(predicate![variable >= 1]))
+                .collect::<Vec<_>>();
+            solver
+                .add_constraint(constraints::boolean_less_than_or_equals(
+                    weights.to_vec(),
+                    literals,
+                    *rhs,
+                ))
+                .post()
+        },
+        |solver, variables, instance| {
+            let (rhs, weights) = instance.constants.split_last().unwrap();
+            // Terms with a weight of zero are left out, as the linear propagators do not support
+            // them
+            let terms = variables
+                .iter()
+                .zip(weights)
+                .filter(|(_, &weight)| weight != 0)
+                .map(|(&variable, &weight)| variable.scaled(weight))
+                .collect::<Vec<_>>();
+            solver
+                .add_constraint(constraints::less_than_or_equals(terms, *rhs))
+                .post()
+        },
+    );
+}
+
+#[test]
+fn at_most_k_sequential_counter() {
+    cross_check_at_most_k(CardinalityEncoding::SequentialCounter);
+}
+
+#[test]
+fn at_most_k_totalizer() {
+    cross_check_at_most_k(CardinalityEncoding::Totalizer);
+}
+
+/// Checks an encoding of at-most-k against the propagator; the encodings are unit propagation
+/// complete, so they should be exactly as strong.
+fn cross_check_at_most_k(encoding: CardinalityEncoding) {
+    // The constants are `[k, is_totalizer]`
+    fn post(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        k: i32,
+        encoding: CardinalityEncoding,
+    ) -> Result<(), ConstraintOperationError> {
+        let literals = variables
+            .iter()
+            .map(|&variable| solver.get_literal(predicate![variable >= 1]))
+            .collect::<Vec<_>>();
+        solver
+            .add_constraint(constraints::at_most_k_with_options(
+                literals, k as u32, encoding,
+            ))
+            .post()
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let num_variables = rng.gen_range(1..=5);
+            Instance {
+                domains: random_domains(rng, num_variables, 0, 1, 2),
+                constants: vec![
+                    rng.gen_range(0..=num_variables as i32),
+                    i32::from(encoding == CardinalityEncoding::Totalizer),
+                ],
+            }
+        },
+        |solver, variables, instance| {
+            let encoding = if instance.constants[1] == 1 {
+                CardinalityEncoding::Totalizer
+            } else {
+                CardinalityEncoding::SequentialCounter
+            };
+            post(solver, variables, instance.constants[0], encoding)
+        },
+        |solver, variables, instance| {
+            post(
+                solver,
+                variables,
+                instance.constants[0],
+                CardinalityEncoding::Propagator,
+            )
+        },
+    );
+}
+
+#[test]
+fn diffn() {
+    cross_check_diffn(false);
+}
+
+#[test]
+fn diffn_with_cumulative_relaxations() {
+    cross_check_diffn(true);
+}
+
+fn cross_check_diffn(cumulative_relaxations: bool) {
+    // The variables are `x ++ y` and the constants are `widths ++ heights ++ [relaxations]`
+    fn rectangles<'a>(
+        variables: &'a [DomainId],
+        instance: &'a Instance,
+    ) -> (&'a [DomainId], &'a [DomainId], &'a [i32], &'a [i32]) {
+        let num_rectangles = variables.len() / 2;
+        let (x, y) = variables.split_at(num_rectangles);
+        let (widths, heights) = instance.constants[..2 * num_rectangles].split_at(num_rectangles);
+        (x, y, widths, heights)
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let num_rectangles = rng.gen_range(1..=3);
+            let mut constants = (0..2 * num_rectangles)
+                .map(|_| rng.gen_range(0..=3))
+                .collect::<Vec<_>>();
+            constants.push(i32::from(cumulative_relaxations));
+            Instance {
+                domains: random_domains(rng, 2 * num_rectangles, 0, 4, 3),
+                constants,
+            }
+        },
+        |solver, variables, instance| {
+            let (x, y, widths, heights) = rectangles(variables, instance);
+            solver
+                .add_constraint(constraints::diffn_with_options(
+                    x.to_vec(),
+                    y.to_vec(),
+                    widths.to_vec(),
+                    heights.to_vec(),
+                    DiffnOptions {
+                        cumulative_relaxations: *instance.constants.last().unwrap() == 1,
+                    },
+                ))
+                .post()
+        },
+        |solver, variables, instance| {
+            let (x, y, widths, heights) = rectangles(variables, instance);
+
+            // Every pair of rectangles is separated along one of the axes
+            for i in 0..x.len() {
+                for j in i + 1..x.len() {
+                    let separations = [
+                        (x[i], widths[i], x[j]),
+                        (x[j], widths[j], x[i]),
+                        (y[i], heights[i], y[j]),
+                        (y[j], heights[j], y[i]),
+                    ];
+                    let mut clause = vec![];
+                    for (before, size, after) in separations {
+                        let is_separated = solver.new_literal();
+                        solver
+                            .add_constraint(constraints::binary_less_than_or_equals(
+                                before.offset(size),
+                                after.scaled(1),
+                            ))
+                            .implied_by(is_separated)?;
+                        clause.push(is_separated);
+                    }
+                    solver.add_clause(clause)?;
+                }
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn specialised_cumulative() {
+    // The constants are `durations ++ resource_requirements ++ [capacity]`
+    fn tasks(instance: &Instance) -> (&[i32], &[i32], i32) {
+        let num_tasks = instance.constants.len() / 2;
+        let (durations, rest) = instance.constants.split_at(num_tasks);
+        let (resource_requirements, capacity) = rest.split_at(num_tasks);
+        (durations, resource_requirements, capacity[0])
+    }
+
+    fn post_cumulative(
+        solver: &mut Solver,
+        variables: &[DomainId],
+        instance: &Instance,
+        specialisation: bool,
+    ) -> Result<(), ConstraintOperationError> {
+        let (durations, resource_requirements, capacity) = tasks(instance);
+        solver
+            .add_constraint(constraints::cumulative_with_options(
+                variables.to_vec(),
+                durations.to_vec(),
+                resource_requirements.to_vec(),
+                capacity,
+                CumulativeOptions::new(
+                    false,
+                    CumulativeExplanationType::default(),
+                    false,
+                    CumulativePropagationMethod::default(),
+                    false,
+                    false,
+                    false,
+                    false,
+                    specialisation,
+                ),
+            ))
+            .post()
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let num_tasks = rng.gen_range(1..=3);
+            // Unit durations and (unary) resources with unit requirements are specialised
+            let unit_durations = rng.gen_bool(0.5);
+            let mut constants = (0..num_tasks)
+                .map(|_| {
+                    if unit_durations {
+                        1
+                    } else {
+                        rng.gen_range(0..=3)
+                    }
+                })
+                .collect::<Vec<_>>();
+            let capacity = rng.gen_range(1..=2);
+            constants.extend((0..num_tasks).map(|_| rng.gen_range(0..=capacity)));
+            constants.push(capacity);
+            Instance {
+                domains: random_domains(rng, num_tasks, 0, 4, 3),
+                constants,
+            }
+        },
+        |solver, variables, instance| post_cumulative(solver, variables, instance, true),
+        // Time-tabling is used as the reference of the specialised propagation
+        |solver, variables, instance| post_cumulative(solver, variables, instance, false),
+    );
+}
+
+#[test]
+fn precedences() {
+    // The constants are triples `(from, gap, to)`, of which `from` and `to` index the variables
+    fn arcs<'a>(
+        variables: &'a [DomainId],
+        instance: &'a Instance,
+    ) -> impl Iterator<Item = (DomainId, i32, DomainId)> + 'a {
+        instance.constants.chunks(3).map(|arc| {
+            (
+                variables[arc[0] as usize],
+                arc[1],
+                variables[arc[2] as usize],
+            )
+        })
+    }
+
+    cross_check_decomposition(
+        |rng| {
+            let num_variables = rng.gen_range(2..=4);
+            let num_arcs = rng.gen_range(1..=5);
+            let constants = (0..num_arcs)
+                .flat_map(|_| {
+                    [
+                        rng.gen_range(0..num_variables) as i32,
+                        rng.gen_range(-2..=3),
+                        rng.gen_range(0..num_variables) as i32,
+                    ]
+                })
+                .collect();
+            Instance {
+                domains: random_domains(rng, num_variables, 0, 8, 3),
+                constants,
+            }
+        },
+        |solver, variables, instance| {
+            solver
+                .add_constraint(constraints::precedences(arcs(variables, instance)))
+                .post()
+        },
+        |solver, variables, instance| {
+            for (from, gap, to) in arcs(variables, instance) {
+                solver
+                    .add_constraint(constraints::binary_less_than_or_equals(
+                        from.offset(gap),
+                        to.scaled(1),
+                    ))
+                    .post()?;
+            }
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn sorted() {
+    cross_check_decomposition(
+        |rng| {
+            let num_values = rng.gen_range(1..=3);
+            Instance {
+                domains: random_domains(rng, 2 * num_values, 0, 5, 2),
+                constants: vec![],
+            }
+        },
+        |solver, variables, _| {
+            let (x, y) = variables.split_at(variables.len() / 2);
+            solver
+                .add_constraint(constraints::sorted(x.to_vec(), y.to_vec()))
+                .post()
+        },
+        |solver, variables, _| {
+            let (x, y) = variables.split_at(variables.len() / 2);
+
+            for window in y.windows(2) {
+                solver
+                    .add_constraint(constraints::binary_less_than_or_equals(
+                        window[0], window[1],
+                    ))
+                    .post()?;
+            }
+
+            // `x[i]` is placed at position `positions[i]` of `y`
+            let positions = x
+                .iter()
+                .map(|_| solver.new_bounded_integer(0, x.len() as i32 - 1))
+                .collect::<Vec<_>>();
+            solver
+                .add_constraint(constraints::all_different(positions.clone()))
+                .post()?;
+            for (&x_i, &position) in x.iter().zip(&positions) {
+                solver
+                    .add_constraint(constraints::element(position, y.to_vec(), x_i))
+                    .post()?;
+            }
+            Ok(())
+        },
+    );
+}
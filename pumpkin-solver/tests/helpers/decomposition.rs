@@ -0,0 +1,153 @@
+//! Utilities to cross-check global constraints which have a dedicated propagator against a
+//! decomposition into simpler constraints.
+//!
+//! For every randomly generated instance, the constraint is posted once natively and once as its
+//! decomposition in a fresh solver. It is then checked that:
+//! - the dedicated propagator is at least as strong as the decomposition at the root, i.e. the
+//!   domain of every variable is a subset of the domain obtained by the decomposition, and
+//! - both models have exactly the same set of solutions (projected onto the variables of the
+//!   instance, as the decomposition can introduce auxiliary variables).
+
+use std::collections::BTreeSet;
+
+use pumpkin_solver::predicate;
+use pumpkin_solver::results::solution_iterator::IteratedSolution;
+use pumpkin_solver::results::ProblemSolution;
+use pumpkin_solver::termination::Indefinite;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::ConstraintOperationError;
+use pumpkin_solver::Solver;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// The number of random instances which are checked for every constraint.
+const NUM_INSTANCES: usize = 100;
+
+/// A random instance of a global constraint.
+#[derive(Clone, Debug)]
+pub(crate) struct Instance {
+    /// The domains of the variables, given as inclusive bounds
+    pub(crate) domains: Vec<(i32, i32)>,
+    /// The constant arguments of the constraint (e.g. the set of values of `among`)
+    pub(crate) constants: Vec<i32>,
+}
+
+/// Posts (a model of) the constraint for the given [`Instance`] over the given variables, which
+/// correspond to [`Instance::domains`].
+pub(crate) type PostConstraint =
+    fn(&mut Solver, &[DomainId], &Instance) -> Result<(), ConstraintOperationError>;
+
+/// The domains of the variables at the root after posting a model of the constraint, or `None` if
+/// a conflict was detected at the root.
+type RootDomains = Option<Vec<BTreeSet<i32>>>;
+
+/// Generates the domains of `num_variables` variables, where every domain is an interval which
+/// lies within `[minimum, maximum]` and contains at most `max_size` values.
+pub(crate) fn random_domains(
+    rng: &mut SmallRng,
+    num_variables: usize,
+    minimum: i32,
+    maximum: i32,
+    max_size: i32,
+) -> Vec<(i32, i32)> {
+    (0..num_variables)
+        .map(|_| {
+            let lower_bound = rng.gen_range(minimum..=maximum);
+            let upper_bound = (lower_bound + rng.gen_range(0..max_size)).min(maximum);
+            (lower_bound, upper_bound)
+        })
+        .collect()
+}
+
+/// Checks the native model of a constraint against its decomposition on [`NUM_INSTANCES`] random
+/// instances; see the module documentation for the properties which are checked.
+///
+/// The instances are generated from a fixed seed, so failures are reproducible.
+pub(crate) fn cross_check_decomposition(
+    generate: impl Fn(&mut SmallRng) -> Instance,
+    native: PostConstraint,
+    decomposition: PostConstraint,
+) {
+    let mut rng = SmallRng::seed_from_u64(42);
+
+    for _ in 0..NUM_INSTANCES {
+        let instance = generate(&mut rng);
+
+        let (native_domains, native_solutions) = solve(&instance, native);
+        let (decomposition_domains, decomposition_solutions) = solve(&instance, decomposition);
+
+        assert!(
+            is_at_least_as_strong(&native_domains, &decomposition_domains),
+            "the native propagation is weaker than the decomposition for {instance:?}:\n\
+             native: {native_domains:?}\n\
+             decomposition: {decomposition_domains:?}"
+        );
+        assert_eq!(
+            native_solutions, decomposition_solutions,
+            "the solutions of the native constraint and the decomposition differ for {instance:?}"
+        );
+    }
+}
+
+/// Posts the model in a fresh solver and returns the root domains and all solutions.
+fn solve(instance: &Instance, post: PostConstraint) -> (RootDomains, BTreeSet<Vec<i32>>) {
+    let mut solver = Solver::default();
+    let variables = instance
+        .domains
+        .iter()
+        .map(|&(lower_bound, upper_bound)| solver.new_bounded_integer(lower_bound, upper_bound))
+        .collect::<Vec<_>>();
+
+    if post(&mut solver, &variables, instance).is_err() {
+        return (None, BTreeSet::new());
+    }
+
+    let domains = variables
+        .iter()
+        .map(|&variable| {
+            (solver.lower_bound(&variable)..=solver.upper_bound(&variable))
+                .filter(|&value| {
+                    let literal = solver.get_literal(predicate![variable == value]);
+                    solver.get_literal_value(literal) != Some(false)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut solutions = BTreeSet::new();
+    let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    let mut termination = Indefinite;
+    let mut solution_iterator = solver.get_solution_iterator(&mut brancher, &mut termination);
+    loop {
+        match solution_iterator.next_solution() {
+            IteratedSolution::Solution(solution) => {
+                let _ = solutions.insert(
+                    variables
+                        .iter()
+                        .map(|&variable| solution.get_integer_value(variable))
+                        .collect(),
+                );
+            }
+            IteratedSolution::Finished | IteratedSolution::Unsatisfiable => break,
+            IteratedSolution::Unknown => panic!("the search should not be interrupted"),
+        }
+    }
+
+    (Some(domains), solutions)
+}
+
+fn is_at_least_as_strong(native: &RootDomains, decomposition: &RootDomains) -> bool {
+    match (native, decomposition) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(native), Some(decomposition)) => {
+            native
+                .iter()
+                .zip(decomposition)
+                .all(|(native_domain, decomposition_domain)| {
+                    native_domain.is_subset(decomposition_domain)
+                })
+        }
+    }
+}
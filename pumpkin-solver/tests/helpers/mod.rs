@@ -1,7 +1,9 @@
 //! Crate to run integration tests for the solver.
 #![allow(dead_code)]
 
+pub(crate) mod decomposition;
 pub(crate) mod flatzinc;
+pub(crate) mod models;
 
 use std::fs::File;
 use std::path::Path;
@@ -185,6 +187,30 @@ pub(crate) fn run_mzn_test<const ORDERED: bool>(instance_name: &str, folder_name
     run_mzn_test_with_options::<ORDERED>(instance_name, folder_name, vec![], "")
 }
 
+/// Asserts that enumerating all solutions of the instance reports exactly `expected` solutions;
+/// unlike [`run_mzn_test`], duplicate solutions are counted.
+pub(crate) fn assert_num_solutions(instance_name: &str, folder_name: &str, expected: usize) {
+    let instance_path = format!(
+        "{}/tests/{folder_name}/{instance_name}.fzn",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let files = run_solver_with_options(instance_path, false, ["-a"], Some("count"));
+
+    let output = std::fs::read_to_string(files.log_file).expect("Failed to read solver output");
+    assert!(
+        output.trim_end().ends_with("=========="),
+        "The enumeration of {instance_name} did not finish"
+    );
+
+    // Every solution is terminated by a separator
+    assert_eq!(
+        output.matches("----------").count(),
+        expected,
+        "Unexpected number of solutions for {instance_name}"
+    );
+}
+
 pub(crate) fn check_statistic_equality(
     instance_name: &str,
     folder_name: &str,
@@ -216,14 +242,8 @@ pub(crate) fn check_statistic_equality(
     let output_second =
         std::fs::read_to_string(files_second.log_file).expect("Failed to read solver output");
 
-    let filtered_output_first = output_first
-        .lines()
-        .filter(|line| line.starts_with("%%%mzn-stat") && !line.contains("imeSpentInSolver"))
-        .collect::<Vec<&str>>();
-    let filtered_output_second = output_second
-        .lines()
-        .filter(|line| line.starts_with("%%%mzn-stat") && !line.contains("imeSpentInSolver"))
-        .collect::<Vec<&str>>();
+    let filtered_output_first = filter_statistics(&output_first);
+    let filtered_output_second = filter_statistics(&output_second);
     assert_eq!(
         filtered_output_first,
         filtered_output_second,
@@ -243,6 +263,20 @@ pub(crate) fn check_statistic_equality(
     )
 }
 
+/// Retains the statistics which should be equal between two runs; the timing statistics are
+/// removed, as are the statistics of the cumulative propagators (since the compared runs use
+/// different propagators for the same constraint).
+fn filter_statistics(output: &str) -> Vec<&str> {
+    output
+        .lines()
+        .filter(|line| {
+            line.starts_with("%%%mzn-stat")
+                && !line.contains("imeSpentInSolver")
+                && !line.starts_with("%%%mzn-stat: cumulative")
+        })
+        .collect()
+}
+
 pub(crate) fn run_mzn_test_with_options<const ORDERED: bool>(
     instance_name: &str,
     folder_name: &str,
@@ -0,0 +1,208 @@
+//! A small DSL for testing models end-to-end through the public API of the [`Solver`].
+//!
+//! A [`TestModel`] is built by creating variables and posting constraints, after which one of the
+//! assertions (e.g. [`TestModel::assert_num_solutions`] or [`TestModel::assert_optimum`]) solves
+//! it and checks the result:
+//!
+//! ```ignore
+//! let mut model = TestModel::new("two different values");
+//! let variables = model.new_variables(2, 0, 1);
+//! model.post(constraints::all_different(variables));
+//! model.assert_num_solutions(2);
+//! ```
+
+use std::collections::BTreeSet;
+
+use pumpkin_solver::constraints::Constraint;
+use pumpkin_solver::results::solution_iterator::IteratedSolution;
+use pumpkin_solver::results::OptimisationResult;
+use pumpkin_solver::results::ProblemSolution;
+use pumpkin_solver::results::SatisfactionResult;
+use pumpkin_solver::results::Solution;
+use pumpkin_solver::termination::Indefinite;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::Solver;
+
+/// The direction in which the objective of a [`TestModel`] is optimised.
+#[derive(Clone, Copy, Debug)]
+enum Objective {
+    Minimise(DomainId),
+    Maximise(DomainId),
+}
+
+/// A model which is solved and checked by one of its assertions.
+///
+/// The solutions of the model are projected onto its decision variables (see
+/// [`TestModel::new_variable`]); the auxiliary variables (see
+/// [`TestModel::new_auxiliary_variable`]) are thus not taken into account when counting solutions.
+pub(crate) struct TestModel {
+    name: &'static str,
+    solver: Solver,
+    variables: Vec<DomainId>,
+    objective: Option<Objective>,
+}
+
+impl TestModel {
+    /// Creates an empty model; the name is used in the messages of failed assertions.
+    pub(crate) fn new(name: &'static str) -> TestModel {
+        TestModel {
+            name,
+            solver: Solver::default(),
+            variables: vec![],
+            objective: None,
+        }
+    }
+
+    /// Creates a decision variable with the domain `[lower_bound, upper_bound]`.
+    pub(crate) fn new_variable(&mut self, lower_bound: i32, upper_bound: i32) -> DomainId {
+        let variable = self.new_auxiliary_variable(lower_bound, upper_bound);
+        self.variables.push(variable);
+        variable
+    }
+
+    /// Creates `num_variables` decision variables with the domain `[lower_bound, upper_bound]`.
+    pub(crate) fn new_variables(
+        &mut self,
+        num_variables: usize,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Vec<DomainId> {
+        (0..num_variables)
+            .map(|_| self.new_variable(lower_bound, upper_bound))
+            .collect()
+    }
+
+    /// Creates a variable with the domain `[lower_bound, upper_bound]` which is not part of the
+    /// solutions of the model.
+    pub(crate) fn new_auxiliary_variable(
+        &mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> DomainId {
+        self.solver.new_bounded_integer(lower_bound, upper_bound)
+    }
+
+    /// Posts the constraint, panicking if it is found to be infeasible at the root; use
+    /// [`TestModel::assert_unsatisfiable`] for models which are expected to be infeasible.
+    pub(crate) fn post(&mut self, constraint: impl Constraint) {
+        if let Err(error) = self.solver.add_constraint(constraint).post() {
+            panic!("failed to post a constraint of '{}': {error}", self.name);
+        }
+    }
+
+    /// Minimises the provided variable when the model is solved.
+    pub(crate) fn minimise(&mut self, objective: DomainId) {
+        self.objective = Some(Objective::Minimise(objective));
+    }
+
+    /// Maximises the provided variable when the model is solved.
+    pub(crate) fn maximise(&mut self, objective: DomainId) {
+        self.objective = Some(Objective::Maximise(objective));
+    }
+
+    /// Asserts that the model has exactly `expected` solutions.
+    pub(crate) fn assert_num_solutions(mut self, expected: usize) {
+        let mut solutions = BTreeSet::new();
+        let mut brancher = self
+            .solver
+            .default_brancher_over_all_propositional_variables();
+        let mut termination = Indefinite;
+        let mut solution_iterator = self
+            .solver
+            .get_solution_iterator(&mut brancher, &mut termination);
+        loop {
+            match solution_iterator.next_solution() {
+                IteratedSolution::Solution(solution) => {
+                    let _ = solutions.insert(project(&self.variables, &solution));
+                }
+                IteratedSolution::Finished | IteratedSolution::Unsatisfiable => break,
+                IteratedSolution::Unknown => {
+                    panic!("the search of '{}' was interrupted", self.name)
+                }
+            }
+        }
+
+        assert_eq!(
+            solutions.len(),
+            expected,
+            "unexpected number of solutions for '{}'",
+            self.name
+        );
+    }
+
+    /// Asserts that the model has a solution for which `check` holds; the values of the decision
+    /// variables are provided in the order in which the variables were created.
+    pub(crate) fn assert_solution(mut self, check: impl FnOnce(&[i32]) -> bool) {
+        let mut brancher = self
+            .solver
+            .default_brancher_over_all_propositional_variables();
+        match self.solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                let values = project(&self.variables, &solution);
+                assert!(
+                    check(&values),
+                    "the solution {values:?} of '{}' is invalid",
+                    self.name
+                );
+            }
+            SatisfactionResult::Unsatisfiable(_) => panic!("'{}' is unsatisfiable", self.name),
+            SatisfactionResult::Unknown(_) => {
+                panic!("the search of '{}' was interrupted", self.name)
+            }
+        }
+    }
+
+    /// Asserts that the model has no solutions, either because a constraint is found to be
+    /// infeasible when it is posted or during search.
+    pub(crate) fn assert_unsatisfiable(mut self) {
+        let mut brancher = self
+            .solver
+            .default_brancher_over_all_propositional_variables();
+        let result = self.solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(
+            matches!(result, SatisfactionResult::Unsatisfiable(_)),
+            "'{}' is not unsatisfiable",
+            self.name
+        );
+    }
+
+    /// Asserts that the optimal value of the objective is `expected`; it returns the values of the
+    /// decision variables in the optimal solution.
+    pub(crate) fn assert_optimum(mut self, expected: i32) -> Vec<i32> {
+        let mut brancher = self
+            .solver
+            .default_brancher_over_all_propositional_variables();
+        let (result, objective) = match self.objective {
+            Some(Objective::Minimise(objective)) => (
+                self.solver
+                    .minimise(&mut brancher, &mut Indefinite, objective),
+                objective,
+            ),
+            Some(Objective::Maximise(objective)) => (
+                self.solver
+                    .maximise(&mut brancher, &mut Indefinite, objective),
+                objective,
+            ),
+            None => panic!("'{}' does not have an objective", self.name),
+        };
+
+        let OptimisationResult::Optimal(solution) = result else {
+            panic!("no optimal solution was found for '{}'", self.name);
+        };
+        assert_eq!(
+            solution.get_integer_value(objective),
+            expected,
+            "unexpected optimal value for '{}'",
+            self.name
+        );
+        project(&self.variables, &solution)
+    }
+}
+
+/// Returns the values of the provided variables in the solution.
+fn project(variables: &[DomainId], solution: &Solution) -> Vec<i32> {
+    variables
+        .iter()
+        .map(|&variable| solution.get_integer_value(variable))
+        .collect()
+}
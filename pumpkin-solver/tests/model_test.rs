@@ -0,0 +1,190 @@
+#![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
+
+//! End-to-end tests of classic problems, which are modelled through the public API of the solver
+//! using the [`TestModel`] DSL; they double as examples of how to model these problems.
+
+mod helpers;
+use helpers::models::TestModel;
+use pumpkin_solver::constraints;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::variables::TransformableVariable;
+
+/// Places `n` queens on an `n x n` board such that no two queens attack each other; the variable
+/// of a column is the row of the queen in that column.
+fn n_queens(n: usize) -> TestModel {
+    let mut model = TestModel::new("n-queens");
+    let queens = model.new_variables(n, 0, n as i32 - 1);
+
+    model.post(constraints::all_different(queens.clone()));
+    model.post(constraints::all_different(
+        queens
+            .iter()
+            .enumerate()
+            .map(|(column, queen)| queen.offset(column as i32))
+            .collect::<Vec<_>>(),
+    ));
+    model.post(constraints::all_different(
+        queens
+            .iter()
+            .enumerate()
+            .map(|(column, queen)| queen.offset(-(column as i32)))
+            .collect::<Vec<_>>(),
+    ));
+
+    model
+}
+
+#[test]
+fn n_queens_has_the_known_number_of_solutions() {
+    n_queens(4).assert_num_solutions(2);
+    n_queens(6).assert_num_solutions(4);
+    n_queens(8).assert_num_solutions(92);
+}
+
+#[test]
+fn three_queens_is_unsatisfiable() {
+    n_queens(3).assert_unsatisfiable();
+}
+
+/// Finds a ruler with `num_marks` marks (the first at 0) such that all distances between pairs of
+/// marks are different, minimising the length of the ruler (i.e. the position of the last mark).
+fn golomb_ruler(num_marks: usize, max_length: i32) -> (TestModel, Vec<DomainId>) {
+    let mut model = TestModel::new("golomb ruler");
+    let marks = model.new_variables(num_marks, 0, max_length);
+
+    model.post(constraints::equals([marks[0]], 0));
+    for pair in marks.windows(2) {
+        model.post(constraints::binary_less_than(pair[0], pair[1]));
+    }
+
+    let mut distances = vec![];
+    for (i, &first) in marks.iter().enumerate() {
+        for &second in &marks[i + 1..] {
+            let distance = model.new_auxiliary_variable(1, max_length);
+            model.post(constraints::equals(
+                [second.scaled(1), first.scaled(-1), distance.scaled(-1)],
+                0,
+            ));
+            distances.push(distance);
+        }
+    }
+    model.post(constraints::all_different(distances.clone()));
+
+    // Break the mirror symmetry: the first distance is smaller than the last one
+    model.post(constraints::binary_less_than(
+        distances[0],
+        distances[distances.len() - 1],
+    ));
+
+    model.minimise(marks[num_marks - 1]);
+    (model, marks)
+}
+
+#[test]
+fn golomb_ruler_has_the_known_optimal_length() {
+    let (model, _) = golomb_ruler(5, 20);
+    let marks = model.assert_optimum(11);
+
+    let mut distances = vec![];
+    for (i, first) in marks.iter().enumerate() {
+        for second in &marks[i + 1..] {
+            distances.push(second - first);
+        }
+    }
+    distances.sort_unstable();
+    distances.dedup();
+    assert_eq!(
+        distances.len(),
+        10,
+        "the distances of {marks:?} are not unique"
+    );
+}
+
+/// Schedules the operations of the jobs, which have to be processed in order, on machines which
+/// can process one operation at a time, minimising the makespan; every job consists of a list of
+/// `(machine, duration)` pairs.
+fn jobshop(jobs: &[&[(usize, i32)]], horizon: i32) -> TestModel {
+    let mut model = TestModel::new("jobshop");
+    let num_machines = jobs
+        .iter()
+        .flat_map(|job| job.iter().map(|&(machine, _)| machine + 1))
+        .max()
+        .unwrap_or(0);
+    let makespan = model.new_auxiliary_variable(0, horizon);
+
+    let mut machine_operations = vec![(vec![], vec![]); num_machines];
+    for job in jobs {
+        let start_times = model.new_variables(job.len(), 0, horizon);
+
+        for (index, &(machine, duration)) in job.iter().enumerate() {
+            // The next operation of the job starts after this operation has finished, and the last
+            // operation finishes before the makespan
+            let next = start_times.get(index + 1).copied().unwrap_or(makespan);
+            model.post(constraints::less_than_or_equals(
+                [start_times[index].scaled(1), next.scaled(-1)],
+                -duration,
+            ));
+
+            machine_operations[machine].0.push(start_times[index]);
+            machine_operations[machine].1.push(duration);
+        }
+    }
+
+    for (start_times, durations) in machine_operations {
+        model.post(constraints::disjunctive(start_times, durations));
+    }
+
+    model.minimise(makespan);
+    model
+}
+
+#[test]
+fn jobshop_has_the_known_optimal_makespan() {
+    let jobs: [&[(usize, i32)]; 3] = [
+        &[(0, 3), (1, 2), (2, 2)],
+        &[(0, 2), (2, 1), (1, 4)],
+        &[(1, 4), (2, 3)],
+    ];
+
+    let _ = jobshop(&jobs, 30).assert_optimum(11);
+}
+
+/// Fills an `n x n` square with the numbers `1` to `n^2` such that all rows, columns and both
+/// diagonals sum to the same value.
+fn magic_square(n: usize) -> TestModel {
+    let mut model = TestModel::new("magic square");
+    let num_cells = (n * n) as i32;
+    let magic_sum = (n as i32) * (num_cells + 1) / 2;
+
+    let cells = model.new_variables(n * n, 1, num_cells);
+    model.post(constraints::all_different(cells.clone()));
+
+    let cell = |row: usize, column: usize| cells[row * n + column];
+    let mut lines: Vec<Vec<DomainId>> = vec![];
+    for i in 0..n {
+        lines.push((0..n).map(|column| cell(i, column)).collect());
+        lines.push((0..n).map(|row| cell(row, i)).collect());
+    }
+    lines.push((0..n).map(|i| cell(i, i)).collect());
+    lines.push((0..n).map(|i| cell(i, n - 1 - i)).collect());
+
+    for line in lines {
+        model.post(constraints::equals(line, magic_sum));
+    }
+
+    model
+}
+
+#[test]
+fn magic_square_has_the_known_number_of_solutions() {
+    // The unique 3 x 3 magic square up to rotations and reflections
+    magic_square(3).assert_num_solutions(8);
+}
+
+#[test]
+fn magic_square_of_size_four_is_found() {
+    magic_square(4).assert_solution(|cells| {
+        let row_sum = |row: usize| cells[row * 4..(row + 1) * 4].iter().sum::<i32>();
+        (0..4).all(|row| row_sum(row) == 34)
+    });
+}
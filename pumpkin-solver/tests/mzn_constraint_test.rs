@@ -1,6 +1,7 @@
 #![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
 
 mod helpers;
+use helpers::assert_num_solutions;
 use helpers::check_statistic_equality;
 use helpers::run_mzn_test_with_options;
 use pumpkin_macros::cumulative;
@@ -30,6 +31,12 @@ mzn_test!(int_lin_le);
 mzn_test!(int_lin_le_reif);
 mzn_test!(int_lin_eq);
 mzn_test!(int_lin_eq_reif);
+mzn_test!(aggregated_int_lin_le);
+
+#[test]
+fn aggregated_int_lin_le_reports_every_solution_once() {
+    assert_num_solutions("aggregated_int_lin_le", "mzn_constraints", 6);
+}
 
 mzn_test!(int_eq);
 mzn_test!(int_eq_reif);
@@ -79,4 +86,47 @@ cumulative_synchronised!(
     time_table_over_interval_incremental_synchronised
 );
 
+mzn_test!(
+    cumulative_time_table_edge_finding_naive,
+    "cumulative",
+    vec![
+        "--cumulative-time-table-edge-finding",
+        "--cumulative-explanation-type",
+        "naive"
+    ]
+);
+mzn_test!(
+    cumulative_time_table_edge_finding_big_step,
+    "cumulative",
+    vec![
+        "--cumulative-time-table-edge-finding",
+        "--cumulative-explanation-type",
+        "big-step"
+    ]
+);
+mzn_test!(
+    cumulative_time_table_edge_finding_pointwise,
+    "cumulative",
+    vec![
+        "--cumulative-time-table-edge-finding",
+        "--cumulative-explanation-type",
+        "pointwise"
+    ]
+);
+
 mzn_test!(all_different);
+
+mzn_test!(among);
+mzn_test!(count_eq);
+mzn_test!(diffn);
+mzn_test!(
+    diffn_cumulative_relaxations,
+    "diffn",
+    vec!["--diffn-cumulative-relaxations"]
+);
+mzn_test!(lex_less);
+mzn_test!(lex_lesseq);
+mzn_test!(sort);
+mzn_test!(value_precede);
+mzn_test!(value_precede_chain);
+mzn_test!(cumulative_var);
@@ -6,6 +6,7 @@ mod helpers;
 
 use helpers::run_solution_checker;
 use helpers::run_solver;
+use helpers::run_solver_with_options;
 use helpers::Checker;
 use helpers::CheckerOutput;
 use helpers::Files;
@@ -17,6 +18,30 @@ macro_rules! test_wcnf_instance {
             run_wcnf_test(stringify!($name), $optimal_objective);
         }
     };
+
+    ($name:ident, $optimal_objective:literal, $algorithm:literal) => {
+        #[test]
+        fn $name() {
+            run_wcnf_test_with_options(
+                stringify!($name),
+                $optimal_objective,
+                ["--maxsat-algorithm", $algorithm],
+                $algorithm,
+            );
+        }
+    };
+
+    ($name:ident, $optimal_objective:literal, strategy = $strategy:literal) => {
+        #[test]
+        fn $name() {
+            run_wcnf_test_with_options(
+                stringify!($name),
+                $optimal_objective,
+                ["--optimisation-strategy", $strategy],
+                $strategy,
+            );
+        }
+    };
 }
 
 test_wcnf_instance!(simple, 1);
@@ -29,6 +54,29 @@ test_wcnf_instance!(normalized_g9x3, 7);
 test_wcnf_instance!(normalized_g9x9, 20);
 test_wcnf_instance!(ram_k3_n9, 1);
 
+mod core_guided {
+    use super::*;
+
+    test_wcnf_instance!(simple, 1, "core-guided");
+    test_wcnf_instance!(karate, 4, "core-guided");
+    test_wcnf_instance!(riskmap, 9, "core-guided");
+    test_wcnf_instance!(johnson8_2_4, 24, "core-guided");
+    test_wcnf_instance!(normalized_g9x3, 7, "core-guided");
+    test_wcnf_instance!(normalized_g9x9, 20, "core-guided");
+    test_wcnf_instance!(ram_k3_n9, 1, "core-guided");
+}
+
+mod lower_bounding {
+    use super::*;
+
+    test_wcnf_instance!(simple, 1, strategy = "lower-bounding");
+    test_wcnf_instance!(karate, 4, strategy = "lower-bounding");
+    test_wcnf_instance!(riskmap, 9, strategy = "lower-bounding");
+    test_wcnf_instance!(johnson8_2_4, 24, strategy = "lower-bounding");
+    test_wcnf_instance!(normalized_g9x3, 7, strategy = "lower-bounding");
+    test_wcnf_instance!(ram_k3_n9, 1, strategy = "lower-bounding");
+}
+
 struct MaxSATChecker {
     expected_objective: u64,
 }
@@ -62,3 +110,18 @@ fn run_wcnf_test(instance_name: &str, expected_objective: u64) {
 
     run_solution_checker(files, MaxSATChecker { expected_objective });
 }
+
+fn run_wcnf_test_with_options<'a>(
+    instance_name: &str,
+    expected_objective: u64,
+    options: impl IntoIterator<Item = &'a str>,
+    prefix: &str,
+) {
+    let instance_path = format!(
+        "{}/tests/wcnf/{instance_name}.wcnf",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let files = run_solver_with_options(instance_path, false, options, Some(prefix));
+
+    run_solution_checker(files, MaxSATChecker { expected_objective });
+}